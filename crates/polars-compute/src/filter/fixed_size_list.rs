@@ -0,0 +1,93 @@
+use arrow::array::{Array, FixedSizeListArray, PrimitiveArray};
+use arrow::bitmap::utils::SlicesIterator;
+use arrow::bitmap::{Bitmap, BitmapBuilder};
+use arrow::types::NativeType;
+
+use super::boolean::filter_boolean_kernel;
+
+/// Filter a [`FixedSizeListArray`] with primitive values.
+///
+/// Every run of consecutive selected rows is copied from the values (and inner validity) in one
+/// go, instead of filtering the values element by element with a mask expanded by the width.
+pub(super) fn filter_primitive<T: NativeType>(
+    array: &FixedSizeListArray,
+    mask: &Bitmap,
+) -> FixedSizeListArray {
+    assert_eq!(array.len(), mask.len());
+    let width = array.size();
+    let inner = array
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap();
+    let inner_values = inner.values().as_slice();
+    let inner_validity = inner.validity();
+
+    let num_rows = mask.set_bits();
+    let mut values = Vec::with_capacity(num_rows * width);
+    let mut validity = inner_validity.map(|_| BitmapBuilder::with_capacity(num_rows * width));
+    for (start, len) in SlicesIterator::new(mask) {
+        let (start, len) = (start * width, len * width);
+        values.extend_from_slice(&inner_values[start..start + len]);
+        if let (Some(builder), Some(inner_validity)) = (validity.as_mut(), inner_validity) {
+            builder.subslice_extend_from_bitmap(inner_validity, start, len);
+        }
+    }
+
+    let values = PrimitiveArray::new(
+        inner.dtype().clone(),
+        values.into(),
+        validity.and_then(BitmapBuilder::into_opt_validity),
+    );
+    let outer_validity = array
+        .validity()
+        .map(|validity| filter_boolean_kernel(validity, mask));
+    FixedSizeListArray::new(
+        array.dtype().clone(),
+        num_rows,
+        values.boxed(),
+        outer_validity,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::datatypes::{ArrowDataType, Field};
+    use rand::prelude::*;
+
+    use super::*;
+    use crate::filter::filter_with_builder;
+
+    fn random_array(rng: &mut StdRng, len: usize, width: usize) -> FixedSizeListArray {
+        let values = (0..len * width)
+            .map(|_| rng.random_bool(0.8).then(|| rng.random::<i32>()))
+            .collect::<PrimitiveArray<i32>>();
+        let validity = (0..len).map(|_| rng.random_bool(0.8)).collect::<Bitmap>();
+        let dtype = ArrowDataType::FixedSizeList(
+            Box::new(Field::new("item".into(), ArrowDataType::Int32, true)),
+            width,
+        );
+        FixedSizeListArray::new(dtype, len, values.boxed(), Some(validity))
+    }
+
+    #[test]
+    fn test_filter_fixed_size_list_matches_builder() {
+        let mut rng = StdRng::seed_from_u64(0xdeadbeef);
+        for width in [0, 1, 3, 128] {
+            for selectivity in [0.01, 0.5, 0.99] {
+                let array = random_array(&mut rng, 300, width);
+                // Also filter a slice, so the values and validities start at an offset.
+                let sliced = array.clone().sliced(7, 250);
+                for array in [array, sliced] {
+                    let mask = (0..array.len())
+                        .map(|_| rng.random_bool(selectivity))
+                        .collect::<Bitmap>();
+                    let out = filter_primitive::<i32>(&array, &mask);
+                    let expected = filter_with_builder(&array, &mask);
+                    assert_eq!(out.len(), mask.set_bits());
+                    assert_eq!(&out as &dyn Array, expected.as_ref());
+                }
+            }
+        }
+    }
+}
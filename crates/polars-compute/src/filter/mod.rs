@@ -1,5 +1,6 @@
 //! Contains operators to filter arrays such as [`filter`].
 mod boolean;
+mod fixed_size_list;
 mod primitive;
 mod scalar;
 
@@ -8,7 +9,8 @@ mod avx512;
 
 use arrow::array::builder::{ArrayBuilder, ShareStrategy, make_builder};
 use arrow::array::{
-    Array, BinaryViewArray, BooleanArray, PrimitiveArray, Utf8ViewArray, new_empty_array,
+    Array, BinaryViewArray, BooleanArray, FixedSizeListArray, PrimitiveArray, Utf8ViewArray,
+    new_empty_array,
 };
 use arrow::bitmap::Bitmap;
 use arrow::bitmap::utils::SlicesIterator;
@@ -97,14 +99,23 @@ pub fn filter_with_bitmap(array: &dyn Array, mask: &Bitmap) -> Box<dyn Array> {
             }
             .boxed()
         },
-        _ => {
-            let iter = SlicesIterator::new(mask);
-            let mut mutable = make_builder(array.dtype());
-            mutable.reserve(iter.slots());
-            iter.for_each(|(start, len)| {
-                mutable.subslice_extend(array, start, len, ShareStrategy::Always)
-            });
-            mutable.freeze()
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            match array.values().dtype().to_physical_type() {
+                Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+                    fixed_size_list::filter_primitive::<$T>(array, mask).boxed()
+                }),
+                _ => filter_with_builder(array, mask),
+            }
         },
+        _ => filter_with_builder(array, mask),
     }
 }
+
+fn filter_with_builder(array: &dyn Array, mask: &Bitmap) -> Box<dyn Array> {
+    let iter = SlicesIterator::new(mask);
+    let mut mutable = make_builder(array.dtype());
+    mutable.reserve(iter.slots());
+    iter.for_each(|(start, len)| mutable.subslice_extend(array, start, len, ShareStrategy::Always));
+    mutable.freeze()
+}
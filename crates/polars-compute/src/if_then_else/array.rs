@@ -1,13 +1,62 @@
 use arrow::array::builder::{ShareStrategy, StaticArrayBuilder, make_builder};
-use arrow::array::{Array, ArrayCollectIterExt, FixedSizeListArray, FixedSizeListArrayBuilder};
-use arrow::bitmap::Bitmap;
+use arrow::array::{
+    Array, ArrayCollectIterExt, FixedSizeListArray, FixedSizeListArrayBuilder, PrimitiveArray,
+};
+use arrow::bitmap::{Bitmap, BitmapBuilder};
+use arrow::datatypes::PhysicalType;
+use arrow::types::NativeType;
+use arrow::with_match_primitive_type_full;
 
-use super::{IfThenElseKernel, if_then_else_extend};
+use super::{IfThenElseKernel, if_then_else_extend, if_then_else_validity};
+
+/// Select whole rows of two [`FixedSizeListArray`]s with primitive values.
+///
+/// Every bit of the mask is repeated `width` times, so the rows can be selected directly on the
+/// flat values with the primitive kernel.
+fn if_then_else_primitive<T: NativeType>(
+    mask: &Bitmap,
+    if_true: &FixedSizeListArray,
+    if_false: &FixedSizeListArray,
+) -> FixedSizeListArray
+where
+    PrimitiveArray<T>: IfThenElseKernel,
+{
+    let width = if_true.size();
+    let mut values_mask = BitmapBuilder::with_capacity(mask.len() * width);
+    values_mask.subslice_extend_each_repeated_from_bitmap(mask, 0, mask.len(), width);
+    let values_mask = values_mask.freeze();
+
+    let values_of = |arr: &FixedSizeListArray| -> PrimitiveArray<T> {
+        arr.values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T>>()
+            .unwrap()
+            .clone()
+    };
+    let values =
+        PrimitiveArray::<T>::if_then_else(&values_mask, &values_of(if_true), &values_of(if_false))
+            .to(if_true.values().dtype().clone());
+
+    let validity = if_then_else_validity(mask, if_true.validity(), if_false.validity());
+    FixedSizeListArray::new(
+        if_true.dtype().clone(),
+        mask.len(),
+        values.boxed(),
+        validity,
+    )
+}
 
 impl IfThenElseKernel for FixedSizeListArray {
     type Scalar<'a> = Box<dyn Array>;
 
     fn if_then_else(mask: &Bitmap, if_true: &Self, if_false: &Self) -> Self {
+        assert_eq!(if_true.size(), if_false.size());
+        if let PhysicalType::Primitive(primitive) = if_true.values().dtype().to_physical_type() {
+            return with_match_primitive_type_full!(primitive, |$T| {
+                if_then_else_primitive::<$T>(mask, if_true, if_false)
+            });
+        }
+
         let inner_dt = if_true.dtype().inner_dtype().unwrap();
         let mut builder =
             FixedSizeListArrayBuilder::new(if_true.dtype().clone(), make_builder(inner_dt));
@@ -162,6 +162,43 @@ impl CovState {
         }
     }
 
+    fn clear_zero_weight_nan(&mut self) {
+        // Clear NaNs due to division by zero.
+        if self.weight == 0.0 {
+            self.mean_x = 0.0;
+            self.mean_y = 0.0;
+            self.dp_xy = 0.0;
+        }
+    }
+
+    pub fn insert_pair(&mut self, x: f64, y: f64) {
+        // Just a specialized version of
+        // self.combine(&Self { weight: 1.0, mean_x: x, mean_y: y, dp_xy: 0.0 })
+        let new_weight = self.weight + 1.0;
+        let delta_mean_x = x - self.mean_x;
+        let delta_mean_y = y - self.mean_y;
+        let new_mean_x = self.mean_x + delta_mean_x / new_weight;
+        self.dp_xy += (x - new_mean_x) * delta_mean_y;
+        self.weight = new_weight;
+        self.mean_x = new_mean_x;
+        self.mean_y += delta_mean_y / new_weight;
+        self.clear_zero_weight_nan();
+    }
+
+    pub fn remove_pair(&mut self, x: f64, y: f64) {
+        // Just a specialized version of
+        // self.combine(&Self { weight: -1.0, mean_x: x, mean_y: y, dp_xy: 0.0 })
+        let new_weight = self.weight - 1.0;
+        let delta_mean_x = x - self.mean_x;
+        let delta_mean_y = y - self.mean_y;
+        let new_mean_x = self.mean_x - delta_mean_x / new_weight;
+        self.dp_xy -= (x - new_mean_x) * delta_mean_y;
+        self.weight = new_weight;
+        self.mean_x = new_mean_x;
+        self.mean_y -= delta_mean_y / new_weight;
+        self.clear_zero_weight_nan();
+    }
+
     pub fn combine(&mut self, other: &Self) {
         if other.weight == 0.0 {
             return;
@@ -220,6 +257,47 @@ impl PearsonState {
         }
     }
 
+    fn clear_zero_weight_nan(&mut self) {
+        // Clear NaNs due to division by zero.
+        if self.weight == 0.0 {
+            *self = Self::default();
+        }
+    }
+
+    pub fn insert_pair(&mut self, x: f64, y: f64) {
+        // Just a specialized version of
+        // self.combine(&Self { weight: 1.0, mean_x: x, mean_y: y, ..Default::default() })
+        let new_weight = self.weight + 1.0;
+        let delta_mean_x = x - self.mean_x;
+        let delta_mean_y = y - self.mean_y;
+        let new_mean_x = self.mean_x + delta_mean_x / new_weight;
+        let new_mean_y = self.mean_y + delta_mean_y / new_weight;
+        self.dp_xx += (x - new_mean_x) * delta_mean_x;
+        self.dp_xy += (x - new_mean_x) * delta_mean_y;
+        self.dp_yy += (y - new_mean_y) * delta_mean_y;
+        self.weight = new_weight;
+        self.mean_x = new_mean_x;
+        self.mean_y = new_mean_y;
+        self.clear_zero_weight_nan();
+    }
+
+    pub fn remove_pair(&mut self, x: f64, y: f64) {
+        // Just a specialized version of
+        // self.combine(&Self { weight: -1.0, mean_x: x, mean_y: y, ..Default::default() })
+        let new_weight = self.weight - 1.0;
+        let delta_mean_x = x - self.mean_x;
+        let delta_mean_y = y - self.mean_y;
+        let new_mean_x = self.mean_x - delta_mean_x / new_weight;
+        let new_mean_y = self.mean_y - delta_mean_y / new_weight;
+        self.dp_xx -= (x - new_mean_x) * delta_mean_x;
+        self.dp_xy -= (x - new_mean_x) * delta_mean_y;
+        self.dp_yy -= (y - new_mean_y) * delta_mean_y;
+        self.weight = new_weight;
+        self.mean_x = new_mean_x;
+        self.mean_y = new_mean_y;
+        self.clear_zero_weight_nan();
+    }
+
     pub fn combine(&mut self, other: &Self) {
         if other.weight == 0.0 {
             return;
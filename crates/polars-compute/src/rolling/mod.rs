@@ -15,6 +15,7 @@ use arrow::array::{ArrayRef, PrimitiveArray};
 use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::types::NativeType;
 pub use mean::MeanWindow;
+pub use moment::{rolling_corr, rolling_cov, rolling_ewm_std, rolling_ewm_var};
 use num_traits::{Bounded, Float, NumCast, One, Zero};
 use polars_utils::float::IsFloat;
 #[cfg(feature = "serde")]
@@ -23,6 +24,8 @@ use strum_macros::IntoStaticStr;
 pub use sum::SumWindow;
 use window::*;
 
+use crate::ewm::EWMOptions;
+
 type Start = usize;
 type End = usize;
 type Idx = usize;
@@ -46,7 +49,7 @@ pub enum QuantileMethod {
 #[deprecated(note = "use QuantileMethod instead")]
 pub type QuantileInterpolOptions = QuantileMethod;
 
-#[derive(Clone, Copy, Debug, PartialEq, Hash)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub enum RollingFnParams {
@@ -55,6 +58,9 @@ pub enum RollingFnParams {
     Rank {
         method: RollingRankMethod,
         seed: Option<u64>,
+        /// Assign the smallest rank to the largest value.
+        #[cfg_attr(feature = "serde", serde(default))]
+        descending: bool,
     },
     Skew {
         bias: bool,
@@ -63,6 +69,90 @@ pub enum RollingFnParams {
         fisher: bool,
         bias: bool,
     },
+    /// Variance where every position in the window has its own weight.
+    WeightedVar {
+        weights: Vec<f64>,
+        ddof: u8,
+    },
+    /// Mean where every position in the window has its own weight.
+    WeightedMean {
+        weights: Vec<f64>,
+    },
+    /// Variance where the weight of a position in the window halves every `halflife` positions
+    /// away from the end of the window.
+    ExpWeightedVar {
+        halflife: f64,
+        ddof: u8,
+    },
+    /// Variance of all values so far, where the weight of a value decays by a factor `1 - alpha`
+    /// with every later position. There is no window, see [`rolling_ewm_var`].
+    ///
+    /// Use [`RollingFnParams::ewm_var`] to derive these from a span or center of mass.
+    EwmVar {
+        alpha: f64,
+        adjust: bool,
+        ignore_nulls: bool,
+        ddof: u8,
+    },
+}
+
+impl Hash for RollingFnParams {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Quantile(params) => params.hash(state),
+            Self::Var(params) => params.hash(state),
+            Self::Rank {
+                method,
+                seed,
+                descending,
+            } => {
+                method.hash(state);
+                seed.hash(state);
+                descending.hash(state);
+            },
+            Self::Skew { bias } => bias.hash(state),
+            Self::Kurtosis { fisher, bias } => {
+                fisher.hash(state);
+                bias.hash(state);
+            },
+            Self::WeightedVar { weights, ddof } => {
+                weights.iter().for_each(|w| w.to_bits().hash(state));
+                ddof.hash(state);
+            },
+            Self::WeightedMean { weights } => {
+                weights.iter().for_each(|w| w.to_bits().hash(state));
+            },
+            Self::ExpWeightedVar { halflife, ddof } => {
+                halflife.to_bits().hash(state);
+                ddof.hash(state);
+            },
+            Self::EwmVar {
+                alpha,
+                adjust,
+                ignore_nulls,
+                ddof,
+            } => {
+                alpha.to_bits().hash(state);
+                adjust.hash(state);
+                ignore_nulls.hash(state);
+                ddof.hash(state);
+            },
+        }
+    }
+}
+
+impl RollingFnParams {
+    /// The [`RollingFnParams::EwmVar`] parameters for the `alpha`, `adjust` and `ignore_nulls` of
+    /// `options`, where `bias` means a `ddof` of 0.
+    pub fn ewm_var(options: &EWMOptions) -> Self {
+        Self::EwmVar {
+            alpha: options.alpha,
+            adjust: options.adjust,
+            ignore_nulls: options.ignore_nulls,
+            ddof: if options.bias { 0 } else { 1 },
+        }
+    }
 }
 
 fn det_offsets(i: Idx, window_size: WindowSize, _len: Len) -> (usize, usize) {
@@ -1,15 +1,31 @@
+use std::collections::VecDeque;
+
+use arrow::compute::utils::combine_validities_and;
 use num_traits::{FromPrimitive, ToPrimitive};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 
 use super::no_nulls::RollingAggWindowNoNulls;
 use super::nulls::RollingAggWindowNulls;
 use super::*;
-use crate::moment::{KurtosisState, SkewState, VarState};
+use crate::moment::{CovState, KurtosisState, PearsonState, SkewState, VarState};
 
 pub trait StateUpdate {
+    /// Whether the contribution of a value depends on its position in the window. The state of
+    /// such a moment is rebuilt for every window instead of being updated incrementally.
+    const POSITIONAL: bool = false;
+    /// The power of the scale of the values with which the result scales, e.g. `2` for a variance
+    /// and `0` for a scale-invariant moment. Used to undo the scaling of [`MomentWindow`].
+    const SCALE_DEGREE: i32;
+
     fn new(params: Option<RollingFnParams>) -> Self;
     fn reset(&mut self);
+    /// Start a window of `len` positions of a positional moment, whose values are then inserted
+    /// in order. `truncated_start` is set if the window is cut short at its start.
+    fn begin_window(&mut self, _len: usize, _truncated_start: bool) {}
     fn insert_one(&mut self, x: f64);
     fn remove_one(&mut self, x: f64);
+    /// Skip the position of a null value in a positional moment.
+    fn skip_one(&mut self) {}
     fn finalize(&self) -> Option<f64>;
 }
 
@@ -19,6 +35,8 @@ pub struct VarianceMoment {
 }
 
 impl StateUpdate for VarianceMoment {
+    const SCALE_DEGREE: i32 = 2;
+
     fn new(params: Option<RollingFnParams>) -> Self {
         let ddof = if let Some(RollingFnParams::Var(params)) = params {
             params.ddof
@@ -53,6 +71,67 @@ impl StateUpdate for VarianceMoment {
     }
 }
 
+/// Coefficient of variation of the values in a window, their standard deviation divided by their
+/// mean. The standard deviation takes the `ddof` of [`RollingFnParams::Var`], like
+/// [`VarianceMoment`], and windows with a mean of zero are null.
+pub struct CvMoment {
+    sum: f64,
+    count: usize,
+    state: VarState,
+    ddof: u8,
+}
+
+impl StateUpdate for CvMoment {
+    const SCALE_DEGREE: i32 = 0;
+
+    fn new(params: Option<RollingFnParams>) -> Self {
+        let ddof = if let Some(RollingFnParams::Var(params)) = params {
+            params.ddof
+        } else {
+            1
+        };
+
+        Self {
+            sum: 0.0,
+            count: 0,
+            state: VarState::default(),
+            ddof,
+        }
+    }
+
+    #[inline(always)]
+    fn reset(&mut self) {
+        self.sum = 0.0;
+        self.count = 0;
+        self.state = VarState::default();
+    }
+
+    #[inline(always)]
+    fn insert_one(&mut self, x: f64) {
+        self.sum += x;
+        self.count += 1;
+        self.state.insert_one(x);
+    }
+
+    #[inline(always)]
+    fn remove_one(&mut self, x: f64) {
+        self.sum -= x;
+        self.count -= 1;
+        self.state.remove_one(x);
+    }
+
+    #[inline(always)]
+    fn finalize(&self) -> Option<f64> {
+        let var = self.state.finalize(self.ddof)?;
+        let mean = self.sum / self.count as f64;
+        if mean == 0.0 {
+            None
+        } else {
+            Some(var.sqrt() / mean)
+        }
+    }
+}
+
 pub struct KurtosisMoment {
     state: KurtosisState,
     fisher: bool,
@@ -60,6 +139,8 @@ pub struct KurtosisMoment {
 }
 
 impl StateUpdate for KurtosisMoment {
+    const SCALE_DEGREE: i32 = 0;
+
     fn new(params: Option<RollingFnParams>) -> Self {
         let (fisher, bias) = if let Some(RollingFnParams::Kurtosis { fisher, bias }) = params {
             (fisher, bias)
@@ -101,6 +182,8 @@ pub struct SkewMoment {
 }
 
 impl StateUpdate for SkewMoment {
+    const SCALE_DEGREE: i32 = 0;
+
     fn new(params: Option<RollingFnParams>) -> Self {
         let bias = if let Some(RollingFnParams::Skew { bias }) = params {
             bias
@@ -135,11 +218,465 @@ impl StateUpdate for SkewMoment {
     }
 }
 
+/// Geometric mean of the values in a window, computed as the exponent of the mean of their
+/// logarithms.
+///
+/// The logarithm is undefined for values that aren't positive, so a window containing one is NaN,
+/// like a window containing a non-finite value.
+#[derive(Default)]
+pub struct GeoMeanMoment {
+    sum_ln: f64,
+    positive_count: usize,
+    non_positive_count: usize,
+}
+
+impl StateUpdate for GeoMeanMoment {
+    const SCALE_DEGREE: i32 = 1;
+
+    fn new(_params: Option<RollingFnParams>) -> Self {
+        Self::default()
+    }
+
+    #[inline(always)]
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    #[inline(always)]
+    fn insert_one(&mut self, x: f64) {
+        if x > 0.0 {
+            self.sum_ln += x.ln();
+            self.positive_count += 1;
+        } else {
+            self.non_positive_count += 1;
+        }
+    }
+
+    #[inline(always)]
+    fn remove_one(&mut self, x: f64) {
+        if x > 0.0 {
+            self.sum_ln -= x.ln();
+            self.positive_count -= 1;
+        } else {
+            self.non_positive_count -= 1;
+        }
+    }
+
+    #[inline(always)]
+    fn finalize(&self) -> Option<f64> {
+        if self.non_positive_count > 0 {
+            Some(f64::NAN)
+        } else if self.positive_count == 0 {
+            None
+        } else {
+            Some((self.sum_ln / self.positive_count as f64).exp())
+        }
+    }
+}
+
+/// The values in a window together with the weight of their position.
+#[derive(Default)]
+struct WeightedWindow {
+    weights: Vec<f64>,
+    /// The position of the next value in `weights`.
+    pos: usize,
+    values: Vec<(f64, f64)>,
+}
+
+impl WeightedWindow {
+    fn new(weights: Vec<f64>) -> Self {
+        Self {
+            weights,
+            ..Default::default()
+        }
+    }
+
+    fn reset(&mut self) {
+        self.pos = 0;
+        self.values.clear();
+    }
+
+    fn begin_window(&mut self, len: usize, truncated_start: bool) {
+        // A window that is cut short at its start lines up with the end of the weights.
+        self.pos = if truncated_start {
+            self.weights.len().saturating_sub(len)
+        } else {
+            0
+        };
+    }
+
+    fn insert_one(&mut self, x: f64) {
+        self.values.push((x, self.weights[self.pos]));
+        self.pos += 1;
+    }
+
+    fn skip_one(&mut self) {
+        self.pos += 1;
+    }
+
+    /// Returns the total weight and the weighted mean.
+    fn mean(&self) -> Option<(f64, f64)> {
+        let total_weight = self.values.iter().map(|(_, w)| w).sum::<f64>();
+        if total_weight == 0.0 {
+            return None;
+        }
+        let mean = self.values.iter().map(|(x, w)| x * w).sum::<f64>() / total_weight;
+        Some((total_weight, mean))
+    }
+
+    /// Returns the weighted variance, or `None` if the window has no weight left after the
+    /// `ddof` correction.
+    fn var(&self, ddof: u8) -> Option<f64> {
+        let (total_weight, mean) = self.mean()?;
+        let (dp, sum_sq_weights) =
+            self.values
+                .iter()
+                .fold((0.0, 0.0), |(dp, sum_sq_weights), (x, w)| {
+                    (dp + w * (x - mean) * (x - mean), sum_sq_weights + w * w)
+                });
+
+        // Reliability weights, which gives the usual ddof correction for equal weights.
+        let denom = total_weight - ddof as f64 * sum_sq_weights / total_weight;
+        if denom <= 0.0 { None } else { Some(dp / denom) }
+    }
+}
+
+pub struct WeightedVarianceMoment {
+    window: WeightedWindow,
+    ddof: u8,
+}
+
+impl StateUpdate for WeightedVarianceMoment {
+    const POSITIONAL: bool = true;
+    const SCALE_DEGREE: i32 = 2;
+
+    fn new(params: Option<RollingFnParams>) -> Self {
+        let Some(RollingFnParams::WeightedVar { weights, ddof }) = params else {
+            unreachable!("expected WeightedVar params");
+        };
+
+        Self {
+            window: WeightedWindow::new(weights),
+            ddof,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.window.reset();
+    }
+
+    fn begin_window(&mut self, len: usize, truncated_start: bool) {
+        self.window.begin_window(len, truncated_start);
+    }
+
+    fn insert_one(&mut self, x: f64) {
+        self.window.insert_one(x);
+    }
+
+    fn remove_one(&mut self, _x: f64) {
+        unreachable!("positional moments are rebuilt for every window");
+    }
+
+    fn skip_one(&mut self) {
+        self.window.skip_one();
+    }
+
+    fn finalize(&self) -> Option<f64> {
+        self.window.var(self.ddof)
+    }
+}
+
+pub struct ExpWeightedVarianceMoment {
+    window: WeightedWindow,
+    halflife: f64,
+    ddof: u8,
+}
+
+impl StateUpdate for ExpWeightedVarianceMoment {
+    const POSITIONAL: bool = true;
+    const SCALE_DEGREE: i32 = 2;
+
+    fn new(params: Option<RollingFnParams>) -> Self {
+        let Some(RollingFnParams::ExpWeightedVar { halflife, ddof }) = params else {
+            unreachable!("expected ExpWeightedVar params");
+        };
+
+        Self {
+            window: WeightedWindow::default(),
+            halflife,
+            ddof,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.window.reset();
+    }
+
+    fn begin_window(&mut self, len: usize, _truncated_start: bool) {
+        // The weights decay with the distance to the end of the window, so they only depend on
+        // its length.
+        if self.window.weights.len() != len {
+            self.window.weights = (0..len)
+                .map(|i| 0.5f64.powf((len - 1 - i) as f64 / self.halflife))
+                .collect();
+        }
+        self.window.begin_window(len, false);
+    }
+
+    fn insert_one(&mut self, x: f64) {
+        self.window.insert_one(x);
+    }
+
+    fn remove_one(&mut self, _x: f64) {
+        unreachable!("positional moments are rebuilt for every window");
+    }
+
+    fn skip_one(&mut self) {
+        self.window.skip_one();
+    }
+
+    fn finalize(&self) -> Option<f64> {
+        self.window.var(self.ddof)
+    }
+}
+
+pub struct WeightedMeanMoment {
+    window: WeightedWindow,
+}
+
+impl StateUpdate for WeightedMeanMoment {
+    const POSITIONAL: bool = true;
+    const SCALE_DEGREE: i32 = 1;
+
+    fn new(params: Option<RollingFnParams>) -> Self {
+        let Some(RollingFnParams::WeightedMean { weights }) = params else {
+            unreachable!("expected WeightedMean params");
+        };
+
+        Self {
+            window: WeightedWindow::new(weights),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.window.reset();
+    }
+
+    fn begin_window(&mut self, len: usize, truncated_start: bool) {
+        self.window.begin_window(len, truncated_start);
+    }
+
+    fn insert_one(&mut self, x: f64) {
+        self.window.insert_one(x);
+    }
+
+    fn remove_one(&mut self, _x: f64) {
+        unreachable!("positional moments are rebuilt for every window");
+    }
+
+    fn skip_one(&mut self) {
+        self.window.skip_one();
+    }
+
+    fn finalize(&self) -> Option<f64> {
+        self.window.mean().map(|(_, mean)| mean)
+    }
+}
+
+/// Exponentially weighted variance of all values so far, see [`RollingFnParams::EwmVar`].
+///
+/// Values are only ever inserted: every insertion first decays the existing weights, and
+/// [`StateUpdate::skip_one`] decays them for a null value unless nulls are ignored.
+pub struct EwmVarianceMoment {
+    /// The total weight, the sum of the squared weights, the weighted mean and the weighted sum
+    /// of squared differences from the mean.
+    weight: f64,
+    weight_sq: f64,
+    mean: f64,
+    dp: f64,
+    alpha: f64,
+    adjust: bool,
+    ignore_nulls: bool,
+    ddof: u8,
+}
+
+impl EwmVarianceMoment {
+    fn decay(&mut self) {
+        let decay = 1.0 - self.alpha;
+        self.weight *= decay;
+        self.weight_sq *= decay * decay;
+        self.dp *= decay;
+    }
+}
+
+impl StateUpdate for EwmVarianceMoment {
+    const SCALE_DEGREE: i32 = 2;
+
+    fn new(params: Option<RollingFnParams>) -> Self {
+        let Some(RollingFnParams::EwmVar {
+            alpha,
+            adjust,
+            ignore_nulls,
+            ddof,
+        }) = params
+        else {
+            unreachable!("expected EwmVar params");
+        };
+
+        Self {
+            weight: 0.0,
+            weight_sq: 0.0,
+            mean: 0.0,
+            dp: 0.0,
+            alpha,
+            adjust,
+            ignore_nulls,
+            ddof,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.weight = 0.0;
+        self.weight_sq = 0.0;
+        self.mean = 0.0;
+        self.dp = 0.0;
+    }
+
+    fn insert_one(&mut self, x: f64) {
+        self.decay();
+        // Without adjustment the first value has full weight, and the weights are normalized to
+        // sum to one after every value.
+        let weight = if self.adjust || self.weight == 0.0 {
+            1.0
+        } else {
+            self.alpha
+        };
+        let new_weight = self.weight + weight;
+        let delta_mean = x - self.mean;
+        self.mean += delta_mean * weight / new_weight;
+        self.dp += weight * delta_mean * (x - self.mean);
+        self.weight = new_weight;
+        self.weight_sq += weight * weight;
+
+        if !self.adjust {
+            self.weight_sq /= self.weight * self.weight;
+            self.dp /= self.weight;
+            self.weight = 1.0;
+        }
+    }
+
+    fn remove_one(&mut self, _x: f64) {
+        unreachable!("exponentially weighted moments never remove values");
+    }
+
+    fn skip_one(&mut self) {
+        if !self.ignore_nulls {
+            self.decay();
+        }
+    }
+
+    fn finalize(&self) -> Option<f64> {
+        if self.weight == 0.0 {
+            return None;
+        }
+        // Reliability weights like for `WeightedVarianceMoment`, which for equal weights is the
+        // `ddof` correction of `VarianceMoment`.
+        let denom = self.weight - self.ddof as f64 * self.weight_sq / self.weight;
+        if denom <= 0.0 {
+            return None;
+        }
+        let var = self.dp / denom;
+        // Variance can't be negative, except through numerical instability. We don't use f64::max
+        // here so we propagate nans.
+        Some(if var < 0.0 { 0.0 } else { var })
+    }
+}
+
+/// Checks the parameters of a positional moment, the weights of which must cover exactly one
+/// window.
+pub(super) fn check_positional_params(
+    params: &Option<RollingFnParams>,
+    window_size: usize,
+) -> PolarsResult<()> {
+    match params {
+        Some(
+            RollingFnParams::WeightedVar { weights, .. }
+            | RollingFnParams::WeightedMean { weights },
+        ) => {
+            polars_ensure!(
+                weights.len() == window_size,
+                ComputeError: "expected {} weights for a window of size {}, got {}",
+                window_size, window_size, weights.len()
+            );
+        },
+        Some(RollingFnParams::ExpWeightedVar { halflife, .. }) => {
+            polars_ensure!(
+                halflife.is_finite() && *halflife > 0.0,
+                ComputeError: "halflife must be a positive finite number, got {}", halflife
+            );
+        },
+        _ => {},
+    }
+    Ok(())
+}
+
+/// Scaled values whose binary exponent is larger than this in magnitude are out of band, see
+/// [`MomentWindow`]. This keeps the fourth powers of the kurtosis well within the normal range.
+const MAX_SCALED_EXPONENT: i32 = 128;
+
+/// The binary exponent of a finite, non-zero `x`, i.e. `floor(log2(|x|))`.
+fn exponent(x: f64) -> i32 {
+    let bits = x.to_bits();
+    match ((bits >> 52) & 0x7ff) as i32 {
+        // Subnormals have their leading bit in the mantissa.
+        0 => -1011 - (bits & ((1 << 52) - 1)).leading_zeros() as i32,
+        biased => biased - 1023,
+    }
+}
+
+/// `2^exp` for an `exp` in the normal range, which is exact.
+fn pow2(exp: i32) -> f64 {
+    debug_assert!((-1022..=1023).contains(&exp));
+    f64::from_bits(((exp + 1023) as u64) << 52)
+}
+
+/// The exponent of the scale for a window whose values have binary exponents in `lo..=hi`.
+///
+/// Windows without extreme values are not scaled. Otherwise the range of exponents is centered in
+/// the band. If it doesn't fit, the largest values are kept in the band, as the smallest values
+/// are negligible next to them.
+fn window_scale_exponent(lo: i32, hi: i32) -> i32 {
+    const MAX: i32 = MAX_SCALED_EXPONENT;
+    let scale_exp = if lo >= -MAX && hi <= MAX {
+        0
+    } else if hi - lo <= 2 * MAX {
+        -(lo + hi).div_euclid(2)
+    } else {
+        MAX - hi
+    };
+    scale_exp.clamp(-1022, 1022)
+}
+
+/// Rolling window over a moment that is updated incrementally.
+///
+/// If the window has values with an extreme exponent, all values are scaled by a power of two
+/// before they are passed to the moment, and the result is scaled back with
+/// [`StateUpdate::SCALE_DEGREE`]. Otherwise the sums of the moment states could leave the normal
+/// range of `f64`, where subnormals lose precision or are flushed to zero by some builds. The scale
+/// follows the range of exponents in the window: once a value is out of band at the current scale,
+/// a new scale is derived from the range and the state of the moment is rebuilt with it.
 pub struct MomentWindow<'a, T, M: StateUpdate> {
     slice: &'a [T],
     validity: Option<&'a Bitmap>,
     moment: M,
-    non_finite_count: usize, // NaN or infinity.
+    /// The values are multiplied by `2^scale_exp`, which is cached in `scale`.
+    scale_exp: i32,
+    scale: f64,
+    /// The indices and exponents of the finite, non-zero values in the window that are a minimum
+    /// or maximum of the window from their position on, so the fronts hold the range of exponents.
+    min_exponents: VecDeque<(usize, i32)>,
+    max_exponents: VecDeque<(usize, i32)>,
+    non_finite_count: usize, // NaN or infinity, also after scaling.
     null_count: usize,
     last_start: usize,
     last_end: usize,
@@ -159,6 +696,10 @@ where
             slice,
             validity,
             moment: M::new(params),
+            scale_exp: 0,
+            scale: 1.0,
+            min_exponents: VecDeque::new(),
+            max_exponents: VecDeque::new(),
             non_finite_count: 0,
             null_count: 0,
             last_start: 0,
@@ -169,44 +710,164 @@ where
     #[inline(always)]
     fn reset(&mut self) {
         self.moment.reset();
+        self.scale_exp = 0;
+        self.scale = 1.0;
+        self.min_exponents.clear();
+        self.max_exponents.clear();
         self.non_finite_count = 0;
         self.null_count = 0;
     }
 
+    /// The value as it is passed to the moment, or `None` if it isn't finite after scaling.
+    #[inline(always)]
+    fn scaled(&self, val: T) -> Option<f64> {
+        let x: f64 = NumCast::from(val).unwrap();
+        let x = x * self.scale;
+        x.is_finite().then_some(x)
+    }
+
     #[inline(always)]
-    fn insert(&mut self, val: T) {
-        if val.is_finite() {
-            self.moment.insert_one(NumCast::from(val).unwrap());
+    fn insert_scaled(&mut self, val: T) {
+        if let Some(x) = self.scaled(val) {
+            self.moment.insert_one(x);
         } else {
             self.moment.insert_one(0.0); // A hack to replicate ddof null behavior.
             self.non_finite_count += 1;
         }
     }
 
+    #[inline(always)]
+    fn insert(&mut self, idx: usize, val: T) {
+        let x: f64 = NumCast::from(val).unwrap();
+        if x.is_finite() && x != 0.0 {
+            let exp = exponent(x);
+            while self.min_exponents.back().is_some_and(|&(_, e)| e >= exp) {
+                self.min_exponents.pop_back();
+            }
+            self.min_exponents.push_back((idx, exp));
+            while self.max_exponents.back().is_some_and(|&(_, e)| e <= exp) {
+                self.max_exponents.pop_back();
+            }
+            self.max_exponents.push_back((idx, exp));
+        }
+        self.insert_scaled(val);
+    }
+
     #[inline(always)]
     fn remove(&mut self, val: T) {
-        if val.is_finite() {
-            self.moment.remove_one(NumCast::from(val).unwrap());
+        if let Some(x) = self.scaled(val) {
+            self.moment.remove_one(x);
         } else {
             self.moment.remove_one(0.0); // A hack to replicate ddof null behavior.
             self.non_finite_count -= 1;
         }
     }
 
+    /// Drop the exponents of the values before `start` from the range of exponents.
     #[inline(always)]
-    fn finalize(&self) -> Option<T> {
-        if self.non_finite_count > 0 {
+    fn remove_exponents_before(&mut self, start: usize) {
+        while self
+            .min_exponents
+            .front()
+            .is_some_and(|&(idx, _)| idx < start)
+        {
+            self.min_exponents.pop_front();
+        }
+        while self
+            .max_exponents
+            .front()
+            .is_some_and(|&(idx, _)| idx < start)
+        {
+            self.max_exponents.pop_front();
+        }
+    }
+
+    /// Rebuild the moment state from the values in the current window.
+    ///
+    /// This is needed when the scale changes, and when the last non-finite value leaves the
+    /// window: those are inserted and removed as `0.0`, which leaves floating point error behind.
+    fn rebuild(&mut self) {
+        self.moment.reset();
+        self.non_finite_count = 0;
+        if M::POSITIONAL {
             self.moment
-                .finalize()
-                .map(|_v| T::from_f64(f64::NAN).unwrap())
-        } else {
-            self.moment.finalize().map(|v| T::from_f64(v).unwrap())
+                .begin_window(self.last_end - self.last_start, self.last_start == 0);
+        }
+        for idx in self.last_start..self.last_end {
+            let valid = self
+                .validity
+                .is_none_or(|validity| unsafe { validity.get_bit_unchecked(idx) });
+            if valid {
+                self.insert_scaled(unsafe { *self.slice.get_unchecked(idx) });
+            } else if M::POSITIONAL {
+                self.moment.skip_one();
+            }
         }
     }
-}
 
-impl<'a, T, M> RollingAggWindowNoNulls<'a, T> for MomentWindow<'a, T, M>
-where
+    /// Derive a new scale from the range of exponents in the window if a value is out of band at
+    /// the current scale, and rebuild the moment state if the scale changes.
+    #[inline(always)]
+    fn rescale(&mut self) {
+        let (Some(&(_, lo)), Some(&(_, hi))) =
+            (self.min_exponents.front(), self.max_exponents.front())
+        else {
+            return;
+        };
+        let in_band = -MAX_SCALED_EXPONENT..=MAX_SCALED_EXPONENT;
+        if in_band.contains(&(lo + self.scale_exp)) && in_band.contains(&(hi + self.scale_exp)) {
+            return;
+        }
+        let scale_exp = window_scale_exponent(lo, hi);
+        if scale_exp != self.scale_exp {
+            self.scale_exp = scale_exp;
+            self.scale = pow2(scale_exp);
+            self.rebuild();
+        }
+    }
+
+    /// Build the state of a positional moment for the window `start..end` from scratch.
+    fn update_positional(&mut self, start: usize, end: usize) -> Option<T> {
+        self.reset();
+        self.last_start = start;
+        self.last_end = end;
+        self.moment.begin_window(end - start, start == 0);
+        for idx in start..end {
+            let valid = self
+                .validity
+                .is_none_or(|validity| unsafe { validity.get_bit_unchecked(idx) });
+            if valid {
+                self.insert(idx, unsafe { *self.slice.get_unchecked(idx) });
+            } else {
+                self.moment.skip_one();
+                self.null_count += 1;
+            }
+        }
+
+        self.rescale();
+        self.finalize()
+    }
+
+    #[inline(always)]
+    fn finalize(&self) -> Option<T> {
+        if self.non_finite_count > 0 {
+            self.moment
+                .finalize()
+                .map(|_v| T::from_f64(f64::NAN).unwrap())
+        } else {
+            self.moment.finalize().map(|v| {
+                // Undo the scaling one factor at a time, so a result in the normal range doesn't
+                // pass through a subnormal or infinite intermediate.
+                let inv_scale = pow2(-self.scale_exp);
+                let v = (0..M::SCALE_DEGREE).fold(v, |v, _| v * inv_scale);
+                T::from_f64(v).unwrap()
+            })
+        }
+    }
+}
+
+impl<'a, T, M> RollingAggWindowNoNulls<'a, T> for MomentWindow<'a, T, M>
+where
     T: NativeType + ToPrimitive + IsFloat + FromPrimitive,
     M: StateUpdate,
 {
@@ -226,22 +887,33 @@ where
     // The start, end range must be in-bounds.
     #[inline]
     unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        if M::POSITIONAL {
+            return self.update_positional(start, end);
+        }
+
         if start >= self.last_end {
             self.reset();
             self.last_start = start;
             self.last_end = start;
         }
 
+        let had_non_finite = self.non_finite_count > 0;
+
         for val in &self.slice[self.last_start..start] {
             self.remove(*val);
         }
+        self.remove_exponents_before(start);
 
-        for val in &self.slice[self.last_end..end] {
-            self.insert(*val);
+        for idx in self.last_end..end {
+            self.insert(idx, unsafe { *self.slice.get_unchecked(idx) });
         }
 
         self.last_start = start;
         self.last_end = end;
+        if had_non_finite && self.non_finite_count == 0 {
+            self.rebuild();
+        }
+        self.rescale();
         self.finalize()
     }
 }
@@ -268,6 +940,10 @@ where
     // The start, end range must be in-bounds.
     #[inline]
     unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        if M::POSITIONAL {
+            return self.update_positional(start, end);
+        }
+
         let validity = unsafe { self.validity.unwrap_unchecked() };
 
         if start >= self.last_end {
@@ -276,6 +952,8 @@ where
             self.last_end = start;
         }
 
+        let had_non_finite = self.non_finite_count > 0;
+
         for idx in self.last_start..start {
             let valid = unsafe { validity.get_bit_unchecked(idx) };
             if valid {
@@ -284,11 +962,12 @@ where
                 self.null_count -= 1;
             }
         }
+        self.remove_exponents_before(start);
 
         for idx in self.last_end..end {
             let valid = unsafe { validity.get_bit_unchecked(idx) };
             if valid {
-                self.insert(unsafe { *self.slice.get_unchecked(idx) });
+                self.insert(idx, unsafe { *self.slice.get_unchecked(idx) });
             } else {
                 self.null_count += 1;
             }
@@ -296,6 +975,10 @@ where
 
         self.last_start = start;
         self.last_end = end;
+        if had_non_finite && self.non_finite_count == 0 {
+            self.rebuild();
+        }
+        self.rescale();
         self.finalize()
     }
 
@@ -304,3 +987,727 @@ where
         ((self.last_end - self.last_start) - self.null_count) >= min_periods
     }
 }
+
+/// The state of a moment of two variables, which is updated with the pairs of values at the same
+/// position in both inputs.
+pub trait PairStateUpdate {
+    fn new(params: Option<RollingFnParams>) -> Self;
+    fn reset(&mut self);
+    fn insert_pair(&mut self, x: f64, y: f64);
+    fn remove_pair(&mut self, x: f64, y: f64);
+    fn finalize(&self) -> Option<f64>;
+}
+
+pub struct CovarianceMoment {
+    state: CovState,
+    ddof: u8,
+}
+
+impl PairStateUpdate for CovarianceMoment {
+    fn new(params: Option<RollingFnParams>) -> Self {
+        let ddof = if let Some(RollingFnParams::Var(params)) = params {
+            params.ddof
+        } else {
+            1
+        };
+
+        Self {
+            state: CovState::default(),
+            ddof,
+        }
+    }
+
+    #[inline(always)]
+    fn reset(&mut self) {
+        self.state = CovState::default();
+    }
+
+    #[inline(always)]
+    fn insert_pair(&mut self, x: f64, y: f64) {
+        self.state.insert_pair(x, y);
+    }
+
+    #[inline(always)]
+    fn remove_pair(&mut self, x: f64, y: f64) {
+        self.state.remove_pair(x, y);
+    }
+
+    #[inline(always)]
+    fn finalize(&self) -> Option<f64> {
+        self.state.finalize(self.ddof)
+    }
+}
+
+pub struct CorrelationMoment {
+    state: PearsonState,
+}
+
+impl PairStateUpdate for CorrelationMoment {
+    fn new(_params: Option<RollingFnParams>) -> Self {
+        Self {
+            state: PearsonState::default(),
+        }
+    }
+
+    #[inline(always)]
+    fn reset(&mut self) {
+        self.state = PearsonState::default();
+    }
+
+    #[inline(always)]
+    fn insert_pair(&mut self, x: f64, y: f64) {
+        self.state.insert_pair(x, y);
+    }
+
+    #[inline(always)]
+    fn remove_pair(&mut self, x: f64, y: f64) {
+        self.state.remove_pair(x, y);
+    }
+
+    #[inline(always)]
+    fn finalize(&self) -> Option<f64> {
+        Some(self.state.finalize())
+    }
+}
+
+/// The counterpart of [`MomentWindow`] for moments of two variables. A pair only enters the
+/// window if both of its values are valid.
+pub struct PairMomentWindow<'a, T, M: PairStateUpdate> {
+    x: &'a [T],
+    y: &'a [T],
+    validity: Option<&'a Bitmap>,
+    moment: M,
+    non_finite_count: usize, // Pairs with a NaN or infinity.
+    null_count: usize,
+    last_start: usize,
+    last_end: usize,
+}
+
+impl<'a, T, M> PairMomentWindow<'a, T, M>
+where
+    T: NativeType + ToPrimitive + IsFloat + FromPrimitive,
+    M: PairStateUpdate,
+{
+    fn new(
+        x: &'a [T],
+        y: &'a [T],
+        validity: Option<&'a Bitmap>,
+        params: Option<RollingFnParams>,
+    ) -> Self {
+        debug_assert_eq!(x.len(), y.len());
+        Self {
+            x,
+            y,
+            validity,
+            moment: M::new(params),
+            non_finite_count: 0,
+            null_count: 0,
+            last_start: 0,
+            last_end: 0,
+        }
+    }
+
+    #[inline(always)]
+    fn reset(&mut self) {
+        self.moment.reset();
+        self.non_finite_count = 0;
+        self.null_count = 0;
+    }
+
+    #[inline(always)]
+    fn is_valid_pair(&self, idx: usize) -> bool {
+        self.validity
+            .is_none_or(|validity| unsafe { validity.get_bit_unchecked(idx) })
+    }
+
+    #[inline(always)]
+    fn pair(&self, idx: usize) -> (T, T) {
+        unsafe { (*self.x.get_unchecked(idx), *self.y.get_unchecked(idx)) }
+    }
+
+    #[inline(always)]
+    fn insert(&mut self, idx: usize) {
+        if !self.is_valid_pair(idx) {
+            self.null_count += 1;
+            return;
+        }
+        let (x, y) = self.pair(idx);
+        if x.is_finite() && y.is_finite() {
+            self.moment
+                .insert_pair(NumCast::from(x).unwrap(), NumCast::from(y).unwrap());
+        } else {
+            self.moment.insert_pair(0.0, 0.0); // A hack to replicate ddof null behavior.
+            self.non_finite_count += 1;
+        }
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, idx: usize) {
+        if !self.is_valid_pair(idx) {
+            self.null_count -= 1;
+            return;
+        }
+        let (x, y) = self.pair(idx);
+        if x.is_finite() && y.is_finite() {
+            self.moment
+                .remove_pair(NumCast::from(x).unwrap(), NumCast::from(y).unwrap());
+        } else {
+            self.moment.remove_pair(0.0, 0.0); // A hack to replicate ddof null behavior.
+            self.non_finite_count -= 1;
+        }
+    }
+
+    /// Rebuild the moment state from the pairs in the current window, see
+    /// [`MomentWindow::rebuild`].
+    fn recompute(&mut self) {
+        self.moment.reset();
+        for idx in self.last_start..self.last_end {
+            if self.is_valid_pair(idx) {
+                let (x, y) = self.pair(idx);
+                self.moment
+                    .insert_pair(NumCast::from(x).unwrap(), NumCast::from(y).unwrap());
+            }
+        }
+    }
+
+    /// # Safety
+    /// The start, end range must be in-bounds.
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        if start >= self.last_end {
+            self.reset();
+            self.last_start = start;
+            self.last_end = start;
+        }
+
+        let had_non_finite = self.non_finite_count > 0;
+
+        for idx in self.last_start..start {
+            self.remove(idx);
+        }
+
+        for idx in self.last_end..end {
+            self.insert(idx);
+        }
+
+        self.last_start = start;
+        self.last_end = end;
+        if had_non_finite && self.non_finite_count == 0 {
+            self.recompute();
+        }
+        self.finalize()
+    }
+
+    #[inline(always)]
+    fn finalize(&self) -> Option<T> {
+        if self.non_finite_count > 0 {
+            self.moment
+                .finalize()
+                .map(|_v| T::from_f64(f64::NAN).unwrap())
+        } else {
+            self.moment.finalize().map(|v| T::from_f64(v).unwrap())
+        }
+    }
+
+    #[inline(always)]
+    fn is_valid(&self, min_periods: usize) -> bool {
+        ((self.last_end - self.last_start) - self.null_count) >= min_periods
+    }
+}
+
+fn rolling_apply_pair_window<T, M>(
+    x: &PrimitiveArray<T>,
+    y: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + ToPrimitive + IsFloat + FromPrimitive,
+    M: PairStateUpdate,
+{
+    polars_ensure!(
+        x.len() == y.len(),
+        ShapeMismatch: "rolling moment of two inputs of different lengths: {} and {}",
+        x.len(), y.len()
+    );
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+
+    let len = x.len();
+    let pair_validity = combine_validities_and(x.validity(), y.validity());
+    let mut window = PairMomentWindow::<T, M>::new(
+        x.values().as_slice(),
+        y.values().as_slice(),
+        pair_validity.as_ref(),
+        params,
+    );
+    let mut validity =
+        create_validity(min_periods, len, window_size, offset_fn).unwrap_or_else(|| {
+            let mut validity = MutableBitmap::with_capacity(len);
+            validity.extend_constant(len, true);
+            validity
+        });
+
+    let out = (0..len)
+        .map(|idx| {
+            let (start, end) = offset_fn(idx, window_size, len);
+            // SAFETY: we are in bounds.
+            match unsafe { window.update(start, end) } {
+                Some(val) if window.is_valid(min_periods) => val,
+                _ => {
+                    // SAFETY: we are in bounds.
+                    unsafe { validity.set_unchecked(idx, false) };
+                    T::default()
+                },
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Box::new(PrimitiveArray::new(
+        T::PRIMITIVE.into(),
+        out.into(),
+        Some(validity.into()),
+    )))
+}
+
+/// Rolling covariance of `x` and `y`, with the `ddof` of [`RollingFnParams::Var`] or 1 by
+/// default. Positions where either value is null are left out of the windows.
+pub fn rolling_cov<T>(
+    x: &PrimitiveArray<T>,
+    y: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + ToPrimitive + IsFloat + FromPrimitive,
+{
+    rolling_apply_pair_window::<T, CovarianceMoment>(x, y, window_size, min_periods, center, params)
+}
+
+/// Rolling Pearson correlation of `x` and `y`. Positions where either value is null are left out
+/// of the windows.
+pub fn rolling_corr<T>(
+    x: &PrimitiveArray<T>,
+    y: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + ToPrimitive + IsFloat + FromPrimitive,
+{
+    rolling_apply_pair_window::<T, CorrelationMoment>(x, y, window_size, min_periods, center, None)
+}
+
+/// Applies an exponentially weighted moment to every prefix of `values`, and maps the results
+/// with `finish`. Positions with fewer than `min_periods` non-null values so far are null.
+fn rolling_apply_ewm<T, M>(
+    values: &PrimitiveArray<T>,
+    min_periods: usize,
+    params: RollingFnParams,
+    finish: fn(f64) -> f64,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + ToPrimitive + FromPrimitive,
+    M: StateUpdate,
+{
+    let RollingFnParams::EwmVar { alpha, .. } = params else {
+        polars_bail!(InvalidOperation: "expected EwmVar parameters, got {:?}", params);
+    };
+    polars_ensure!(
+        alpha > 0.0 && alpha <= 1.0,
+        ComputeError: "alpha must be in (0, 1], got {}", alpha
+    );
+
+    let mut state = M::new(Some(params));
+    let mut non_null_count = 0;
+    let out: PrimitiveArray<T> = values
+        .iter()
+        .map(|opt_v| {
+            match opt_v {
+                Some(v) => {
+                    state.insert_one(v.to_f64().unwrap());
+                    non_null_count += 1;
+                },
+                None => state.skip_one(),
+            }
+            if non_null_count < min_periods.max(1) {
+                return None;
+            }
+            state.finalize().and_then(|v| T::from_f64(finish(v)))
+        })
+        .collect();
+    Ok(Box::new(out))
+}
+
+/// Exponentially weighted variance of every prefix of `values`, given the
+/// [`RollingFnParams::EwmVar`] parameters.
+///
+/// Unlike the other rolling functions there is no window: all values so far contribute. Null
+/// positions carry the variance of the values before them.
+pub fn rolling_ewm_var<T>(
+    values: &PrimitiveArray<T>,
+    min_periods: usize,
+    params: RollingFnParams,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + ToPrimitive + FromPrimitive,
+{
+    rolling_apply_ewm::<T, EwmVarianceMoment>(values, min_periods, params, |v| v)
+}
+
+/// Exponentially weighted standard deviation, the square root of [`rolling_ewm_var`].
+pub fn rolling_ewm_std<T>(
+    values: &PrimitiveArray<T>,
+    min_periods: usize,
+    params: RollingFnParams,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + ToPrimitive + FromPrimitive,
+{
+    rolling_apply_ewm::<T, EwmVarianceMoment>(values, min_periods, params, f64::sqrt)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::datatypes::ArrowDataType;
+
+    use super::*;
+    use crate::ewm::EWMOptions;
+
+    fn to_vec(arr: ArrayRef) -> Vec<Option<f64>> {
+        let arr = arr.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        arr.iter().map(|v| v.copied()).collect()
+    }
+
+    fn assert_close(actual: &[Option<f64>], expected: &[Option<f64>]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            match (a, e) {
+                (Some(a), Some(e)) => assert!((a - e).abs() < 1e-12, "{actual:?} != {expected:?}"),
+                (a, e) => assert_eq!(a, e, "{actual:?} != {expected:?}"),
+            }
+        }
+    }
+
+    fn exact_cov(x: &[f64], y: &[f64]) -> f64 {
+        let n = x.len() as f64;
+        let mean_x = x.iter().sum::<f64>() / n;
+        let mean_y = y.iter().sum::<f64>() / n;
+        let dp = x
+            .iter()
+            .zip(y)
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum::<f64>();
+        dp / (n - 1.0)
+    }
+
+    #[test]
+    fn test_rolling_cov_corr() {
+        let x_values = vec![1.0, 5.0, 3.0, 4.0, -2.0, 7.0, 0.5];
+        let y_values = vec![2.0, 1.0, 3.5, 8.0, 4.0, -1.0, 3.0];
+        let x = PrimitiveArray::from_vec(x_values.clone());
+        let y = PrimitiveArray::from_vec(y_values.clone());
+
+        let mut expected_cov = vec![None, None];
+        let mut expected_corr = vec![None, None];
+        for end in 3..=x_values.len() {
+            let (wx, wy) = (&x_values[end - 3..end], &y_values[end - 3..end]);
+            let cov = exact_cov(wx, wy);
+            expected_cov.push(Some(cov));
+            expected_corr.push(Some(cov / (exact_cov(wx, wx) * exact_cov(wy, wy)).sqrt()));
+        }
+        let cov = to_vec(rolling_cov(&x, &y, 3, 3, false, None).unwrap());
+        assert_close(&cov, &expected_cov);
+        let corr = to_vec(rolling_corr(&x, &y, 3, 3, false).unwrap());
+        assert_close(&corr, &expected_corr);
+
+        // The covariance of a series with itself is its variance.
+        let params = Some(RollingFnParams::Var(RollingVarParams { ddof: 0 }));
+        let cov = to_vec(rolling_cov(&x, &x, 2, 1, true, params.clone()).unwrap());
+        let var = super::super::no_nulls::rolling_var(&x_values, 2, 1, true, None, params);
+        assert_close(&cov, &to_vec(var.unwrap()));
+    }
+
+    #[test]
+    fn test_rolling_cov_nulls() {
+        let x = PrimitiveArray::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let y = PrimitiveArray::new(
+            ArrowDataType::Float64,
+            vec![2.0, 100.0, 6.0, 8.0, 10.0].into(),
+            Some(Bitmap::from(&[true, false, true, true, true])),
+        );
+
+        // The pair with a null `y` doesn't count towards `min_periods`.
+        let cov = to_vec(rolling_cov(&x, &y, 3, 2, false, None).unwrap());
+        assert_close(&cov, &[None, None, Some(4.0), Some(1.0), Some(2.0)]);
+        let corr = to_vec(rolling_corr(&x, &y, 3, 2, false).unwrap());
+        assert_close(&corr, &[None, None, Some(1.0), Some(1.0), Some(1.0)]);
+    }
+
+    #[test]
+    fn test_rolling_cov_non_finite() {
+        let x = PrimitiveArray::from_vec(vec![1.0, 2.0, f64::NAN, 4.0, 5.0, 6.0]);
+        let y = PrimitiveArray::from_vec(vec![1.0, f64::INFINITY, 3.0, 4.0, 5.0, 7.0]);
+
+        // A non-finite value in either input poisons the windows it is in.
+        let cov = to_vec(rolling_cov(&x, &y, 2, 2, false, None).unwrap());
+        assert!(cov[1..4].iter().all(|v| v.unwrap().is_nan()));
+        assert_close(&[cov[0], cov[4], cov[5]], &[None, Some(0.5), Some(1.0)]);
+
+        let corr = to_vec(rolling_corr(&x, &y, 2, 2, false).unwrap());
+        assert!(corr[1..4].iter().all(|v| v.unwrap().is_nan()));
+        assert_close(&[corr[0], corr[4], corr[5]], &[None, Some(1.0), Some(1.0)]);
+    }
+
+    #[test]
+    fn test_rolling_ewm_var() {
+        // Expected values from pandas, with `com=1` or `alpha=0.5`.
+        let xs = PrimitiveArray::from_vec(vec![1.0, 5.0, 7.0, 1.0, 2.0, 1.0, 4.0]);
+        let options = EWMOptions::default().and_com(1.0);
+        let var = to_vec(rolling_ewm_var(&xs, 1, RollingFnParams::ewm_var(&options)).unwrap());
+        assert_close(
+            &var,
+            &[
+                None,
+                Some(8.0),
+                Some(7.428_571_428_571_429),
+                Some(11.542_857_142_857_143),
+                Some(5.883_870_967_741_934_5),
+                Some(3.760_368_663_594_470_6),
+                Some(3.743_532_058_492_688_6),
+            ],
+        );
+        let std = to_vec(rolling_ewm_std(&xs, 1, RollingFnParams::ewm_var(&options)).unwrap());
+        let sqrt_var = var.iter().map(|v| v.map(f64::sqrt)).collect::<Vec<_>>();
+        assert_close(&std, &sqrt_var);
+
+        let options = options.and_adjust(false);
+        let var = to_vec(rolling_ewm_var(&xs, 3, RollingFnParams::ewm_var(&options)).unwrap());
+        assert_close(
+            &var,
+            &[
+                None,
+                None,
+                Some(9.600_000_000_000_001),
+                Some(10.666_666_666_666_666),
+                Some(5.647_058_823_529_411),
+                Some(3.659_824_046_920_821),
+                Some(3.727_472_527_472_527_6),
+            ],
+        );
+        let options = EWMOptions {
+            bias: true,
+            ..options
+        };
+        let var = to_vec(rolling_ewm_var(&xs, 1, RollingFnParams::ewm_var(&options)).unwrap());
+        assert_close(
+            &var,
+            &[
+                Some(0.0),
+                Some(4.0),
+                Some(6.0),
+                Some(7.0),
+                Some(3.75),
+                Some(2.437_5),
+                Some(2.484_375),
+            ],
+        );
+
+        let params = RollingFnParams::EwmVar {
+            alpha: 1.5,
+            adjust: true,
+            ignore_nulls: true,
+            ddof: 1,
+        };
+        assert!(rolling_ewm_var(&xs, 1, params).is_err());
+    }
+
+    #[test]
+    fn test_rolling_ewm_var_nulls() {
+        let ys = PrimitiveArray::new(
+            ArrowDataType::Float64,
+            vec![0.0, 5.0, 7.0, 0.0, 0.0, 1.0, 4.0].into(),
+            Some(Bitmap::from(&[false, true, true, false, false, true, true])),
+        );
+
+        // Null positions carry the variance of the values before them.
+        let mut params = RollingFnParams::ewm_var(&EWMOptions::default());
+        let var = to_vec(rolling_ewm_var(&ys, 1, params.clone()).unwrap());
+        assert_close(
+            &var,
+            &[
+                None,
+                None,
+                Some(2.0),
+                Some(2.0),
+                Some(2.0),
+                Some(12.857_142_857_142_856),
+                Some(5.714_285_714_285_714),
+            ],
+        );
+
+        // Unless nulls are ignored, they decay the weights of the values before them.
+        if let RollingFnParams::EwmVar { ignore_nulls, .. } = &mut params {
+            *ignore_nulls = false;
+        }
+        let var = to_vec(rolling_ewm_var(&ys, 1, params).unwrap());
+        assert_close(
+            &var[5..],
+            &[Some(14.159_999_999_999_997), Some(5.039_513_677_811_549_5)],
+        );
+    }
+
+    #[test]
+    fn test_rolling_ewm_var_matches_ewm_var() {
+        let values = [0.0, 5.0, 7.0, 0.0, 0.0, 1.0, 4.0, -3.0, 0.0, 2.5, 8.0];
+        let validity = [
+            false, true, true, false, false, true, true, true, false, true, true,
+        ];
+        let ys = PrimitiveArray::new(
+            ArrowDataType::Float64,
+            values.to_vec().into(),
+            Some(Bitmap::from(&validity)),
+        );
+
+        for adjust in [false, true] {
+            for ignore_nulls in [false, true] {
+                for bias in [false, true] {
+                    let options = EWMOptions {
+                        alpha: 0.3,
+                        adjust,
+                        bias,
+                        ignore_nulls,
+                        ..Default::default()
+                    };
+                    let var = to_vec(
+                        rolling_ewm_var(&ys, 1, RollingFnParams::ewm_var(&options)).unwrap(),
+                    );
+                    let expected = crate::ewm::ewm_var(
+                        ys.iter().map(|v| v.copied()),
+                        options.alpha,
+                        adjust,
+                        bias,
+                        1,
+                        ignore_nulls,
+                    );
+
+                    // `ewm_var` is null at null positions, and without bias reports a variance of
+                    // zero for a single value, where the rolling variance is undefined.
+                    let mut non_null_count = 0;
+                    for (i, (v, e)) in var.iter().zip(expected.iter()).enumerate() {
+                        if !validity[i] {
+                            continue;
+                        }
+                        non_null_count += 1;
+                        if non_null_count == 1 && !bias {
+                            assert_eq!(*v, None);
+                            continue;
+                        }
+                        let (v, e) = (v.unwrap(), *e.unwrap());
+                        assert!(
+                            (v - e).abs() < 1e-12,
+                            "{options:?}: {var:?} != {expected:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rolling_cv() {
+        use super::super::{no_nulls, nulls};
+
+        let values = vec![1.0, 5.0, 3.0, -4.0, 4.0, 2.0, 7.0, f64::NAN, 6.0, 2.5, 8.0];
+        let arr = PrimitiveArray::new(
+            ArrowDataType::Float64,
+            values.clone().into(),
+            Some(Bitmap::from(&[
+                true, true, false, true, true, true, true, true, true, true, true,
+            ])),
+        );
+        for ddof in [0, 1] {
+            let params = Some(RollingFnParams::Var(RollingVarParams { ddof }));
+
+            let cv = to_vec(no_nulls::rolling_cv(&values, 3, 2, false, params.clone()).unwrap());
+            let std =
+                to_vec(no_nulls::rolling_var(&values, 3, 2, false, None, params.clone()).unwrap())
+                    .into_iter()
+                    .map(|v| v.map(f64::sqrt));
+            let mean = to_vec(no_nulls::rolling_mean(&values, 3, 2, false, None, None).unwrap());
+            let expected = std
+                .zip(mean)
+                .map(|(std, mean)| Some(std? / mean.filter(|mean| *mean != 0.0)?))
+                .collect::<Vec<_>>();
+            // Windows with a NaN are NaN for both.
+            assert!(cv[7..10].iter().all(|v| v.unwrap().is_nan()));
+            assert!(expected[7..10].iter().all(|v| v.unwrap().is_nan()));
+            assert_close(&cv[..7], &expected[..7]);
+            assert_close(&cv[10..], &expected[10..]);
+
+            let cv = to_vec(nulls::rolling_cv(&arr, 3, 2, false, params.clone()));
+            let std = to_vec(nulls::rolling_var(&arr, 3, 2, false, None, params).unwrap())
+                .into_iter()
+                .map(|v| v.map(f64::sqrt));
+            let mean = to_vec(nulls::rolling_mean(&arr, 3, 2, false, None, None));
+            let expected = std
+                .zip(mean)
+                .map(|(std, mean)| Some(std? / mean.filter(|mean| *mean != 0.0)?))
+                .collect::<Vec<_>>();
+            assert_close(&cv[..7], &expected[..7]);
+        }
+
+        // The window `[null, -4, 4]` has a mean of zero.
+        let params = Some(RollingFnParams::Var(RollingVarParams { ddof: 1 }));
+        let cv = to_vec(nulls::rolling_cv(&arr, 3, 2, false, params));
+        assert_eq!(cv[4], None);
+    }
+
+    #[test]
+    fn test_rolling_geo_mean() {
+        use super::super::{no_nulls, nulls};
+
+        let values = vec![
+            1.0,
+            2.0,
+            4.0,
+            8.0,
+            -1.0,
+            3.0,
+            9.0,
+            27.0,
+            0.0,
+            5.0,
+            5.0,
+            f64::NAN,
+        ];
+        let out = to_vec(no_nulls::rolling_geo_mean(&values, 3, 2, false).unwrap());
+        assert_close(&out[..4], &[None, Some(2f64.sqrt()), Some(2.0), Some(4.0)]);
+        // A window with a value that isn't positive or finite is NaN until the value leaves it.
+        assert!(out[4..7].iter().all(|v| v.unwrap().is_nan()));
+        assert_close(&out[7..8], &[Some(9.0)]);
+        assert!(out[8..].iter().all(|v| v.unwrap().is_nan()));
+
+        // Null values don't count towards `min_periods`.
+        let arr = PrimitiveArray::new(
+            ArrowDataType::Float64,
+            vec![2.0, 100.0, 8.0, 4.0, 0.5].into(),
+            Some(Bitmap::from(&[true, false, true, true, true])),
+        );
+        let out = to_vec(nulls::rolling_geo_mean(&arr, 3, 2, false));
+        let expected = [
+            None,
+            None,
+            Some(4.0),
+            Some(32f64.sqrt()),
+            Some(16f64.cbrt()),
+        ];
+        assert_close(&out, &expected);
+    }
+}
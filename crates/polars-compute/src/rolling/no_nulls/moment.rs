@@ -21,6 +21,26 @@ where
         false => det_offsets,
     };
     match weights {
+        None if matches!(params, Some(RollingFnParams::WeightedVar { .. })) => {
+            check_positional_params(&params, window_size)?;
+            rolling_apply_agg_window::<MomentWindow<_, WeightedVarianceMoment>, _, _, _>(
+                values,
+                window_size,
+                min_periods,
+                offset_fn,
+                params,
+            )
+        },
+        None if matches!(params, Some(RollingFnParams::ExpWeightedVar { .. })) => {
+            check_positional_params(&params, window_size)?;
+            rolling_apply_agg_window::<MomentWindow<_, ExpWeightedVarianceMoment>, _, _, _>(
+                values,
+                window_size,
+                min_periods,
+                offset_fn,
+                params,
+            )
+        },
         None => rolling_apply_agg_window::<MomentWindow<_, VarianceMoment>, _, _, _>(
             values,
             window_size,
@@ -51,6 +71,32 @@ where
     }
 }
 
+/// Rolling mean where every position in the window has its own weight, given by
+/// [`RollingFnParams::WeightedMean`]. Windows whose weights sum to zero are null.
+pub fn rolling_weighted_mean<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + IsFloat + ToPrimitive + FromPrimitive + AddAssign,
+{
+    check_positional_params(&params, window_size)?;
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<MomentWindow<_, WeightedMeanMoment>, _, _, _>(
+        values,
+        window_size,
+        min_periods,
+        offset_fn,
+        params,
+    )
+}
+
 pub fn rolling_skew<T>(
     values: &[T],
     window_size: usize,
@@ -97,8 +143,57 @@ where
     )
 }
 
+/// Rolling coefficient of variation, see [`CvMoment`].
+pub fn rolling_cv<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + IsFloat + ToPrimitive + FromPrimitive + AddAssign,
+{
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<MomentWindow<_, CvMoment>, _, _, _>(
+        values,
+        window_size,
+        min_periods,
+        offset_fn,
+        params,
+    )
+}
+
+/// Rolling geometric mean, see [`GeoMeanMoment`]. Windows with a value that isn't positive are NaN.
+pub fn rolling_geo_mean<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + IsFloat + ToPrimitive + FromPrimitive + AddAssign,
+{
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<MomentWindow<_, GeoMeanMoment>, _, _, _>(
+        values,
+        window_size,
+        min_periods,
+        offset_fn,
+        None,
+    )
+}
+
 #[cfg(test)]
 mod test {
+    use arrow::array::StaticArray;
+
     use super::*;
 
     #[test]
@@ -141,9 +236,229 @@ mod test {
                     Some(f64::nan()),
                     Some(f64::nan()),
                     Some(f64::nan()),
-                    Some(0.9999999999999911)
+                    Some(1.0)
                 ]
             )
         );
     }
+
+    #[test]
+    fn test_rolling_var_after_non_finite() {
+        let window_size = 20;
+        let mut values = (0..300)
+            .map(|i| 1e6 + (i as f64 * 0.37).sin())
+            .collect::<Vec<_>>();
+        values[100] = f64::INFINITY;
+
+        let out = rolling_var(&values, window_size, window_size, false, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+
+        // The windows that no longer contain the infinity should match an exact computation.
+        for end in 100 + window_size + 1..=values.len() {
+            let window = &values[end - window_size..end];
+            let mean = window.iter().sum::<f64>() / window_size as f64;
+            let expected = window.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>()
+                / (window_size - 1) as f64;
+            let actual = out.get(end - 1).unwrap();
+            assert!(
+                ((actual - expected) / expected).abs() < 1e-8,
+                "window ending at {end}: {actual} != {expected}"
+            );
+        }
+    }
+
+    fn kahan_sum(values: impl IntoIterator<Item = f64>) -> f64 {
+        let (mut sum, mut compensation) = (0.0, 0.0);
+        for v in values {
+            let y = v - compensation;
+            let t = sum + y;
+            compensation = (t - sum) - y;
+            sum = t;
+        }
+        sum
+    }
+
+    /// The sample variance, skewness and kurtosis of `window`, with the same bias corrections as
+    /// the rolling kernels.
+    fn reference_moments(window: &[f64]) -> (f64, f64, f64) {
+        let n = window.len() as f64;
+        let mean = kahan_sum(window.iter().copied()) / n;
+        let m2 = kahan_sum(window.iter().map(|x| (x - mean) * (x - mean))) / n;
+        // Standardize before taking the higher powers, which would leave the normal range.
+        let z = window.iter().map(|x| (x - mean) / m2.sqrt());
+        let m3 = kahan_sum(z.clone().map(|z| z.powi(3))) / n;
+        let m4 = kahan_sum(z.map(|z| z.powi(4))) / n;
+
+        let var = m2 * n / (n - 1.0);
+        let skew = (n * (n - 1.0)).sqrt() / (n - 2.0) * m3;
+        let kurt = (n - 1.0) / (n - 2.0)
+            * ((n + 1.0) / (n - 3.0) * m4 - 3.0 * (n - 1.0) / (n - 3.0))
+            + 3.0;
+        (var, skew, kurt)
+    }
+
+    /// Check the rolling variance, skewness and kurtosis of `values` against the reference.
+    fn check_moments(values: &[f64], window_size: usize) {
+        let var = rolling_var(values, window_size, window_size, false, None, None).unwrap();
+        let skew = rolling_skew(values, window_size, window_size, false, None).unwrap();
+        let kurt = rolling_kurtosis(values, window_size, window_size, false, None).unwrap();
+        let [var, skew, kurt] = [var, skew, kurt].map(|out| {
+            out.as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap()
+                .clone()
+        });
+
+        for end in window_size..=values.len() {
+            let window = &values[end - window_size..end];
+            let (expected_var, expected_skew, expected_kurt) = reference_moments(window);
+            let (actual_var, actual_skew, actual_kurt) = (
+                var.get(end - 1).unwrap(),
+                skew.get(end - 1).unwrap(),
+                kurt.get(end - 1).unwrap(),
+            );
+            assert!(
+                [actual_var, actual_skew, actual_kurt]
+                    .iter()
+                    .all(|v| v.is_finite()),
+                "window {window:?}"
+            );
+            assert!(
+                ((actual_var - expected_var) / expected_var).abs() < 1e-12,
+                "window {window:?}: {actual_var} != {expected_var}"
+            );
+            assert!(
+                (actual_skew - expected_skew).abs() < 1e-10,
+                "window {window:?}: {actual_skew} != {expected_skew}"
+            );
+            assert!(
+                (actual_kurt - expected_kurt).abs() < 1e-10,
+                "window {window:?}: {actual_kurt} != {expected_kurt}"
+            );
+        }
+    }
+
+    const PATTERN: [f64; 10] = [1.0, 1.5, 0.75, 2.0, 1.25, 0.5, 1.75, 1.125, 1.0, 0.625];
+
+    #[test]
+    fn test_rolling_moments_extreme_exponents() {
+        // The squares of the deviations of the smallest values are close to the subnormal range,
+        // and their higher powers as well as those of the largest values leave the normal range.
+        for exponent in [-500, -200, 0, 200, 500] {
+            let base = 2f64.powi(exponent);
+            let values = PATTERN.iter().map(|x| x * base).collect::<Vec<_>>();
+            check_moments(&values, 5);
+        }
+    }
+
+    #[test]
+    fn test_rolling_moments_mixed_exponents() {
+        // Tiny and large values in the same window. Scaling for the tiny values would make the
+        // large values overflow.
+        let values = PATTERN
+            .iter()
+            .enumerate()
+            .map(|(i, x)| if i % 2 == 0 { x * 1e-300 } else { x * 1e10 })
+            .collect::<Vec<_>>();
+        check_moments(&values, 5);
+
+        // A large value followed by values around one. Once the large value has left the window,
+        // the scale for it would make the higher powers of the remaining values underflow.
+        let values = [2f64.powi(300)]
+            .into_iter()
+            .chain(PATTERN)
+            .chain(PATTERN)
+            .collect::<Vec<_>>();
+        check_moments(&values, 5);
+
+        // The range of exponents is wider than the band, so the smallest values are out of it.
+        let values = [1e-300, 1e100, 2e-300]
+            .into_iter()
+            .chain(PATTERN)
+            .collect::<Vec<_>>();
+        check_moments(&values, 4);
+    }
+
+    #[test]
+    fn test_rolling_weighted_var_mean() {
+        let values = &[1.0, 4.0, 2.0, 8.0, 5.0];
+        let weights = vec![1.0, 2.0, 3.0];
+
+        let params = Some(RollingFnParams::WeightedMean {
+            weights: weights.clone(),
+        });
+        let out = rolling_weighted_mean(values, 3, 1, false, params).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        // Truncated windows at the start line up with the last weights.
+        assert_eq!(
+            out,
+            &[
+                Some(1.0),
+                Some(14.0 / 5.0),
+                Some(15.0 / 6.0),
+                Some(32.0 / 6.0),
+                Some(33.0 / 6.0)
+            ]
+        );
+
+        // Weighted variance with frequency-like weights equals the variance of repeated values.
+        let params = Some(RollingFnParams::WeightedVar {
+            weights: weights.clone(),
+            ddof: 0,
+        });
+        let out = rolling_var(values, 3, 3, false, None, params).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        for end in 3..=values.len() {
+            let repeated = values[end - 3..end]
+                .iter()
+                .zip(&weights)
+                .flat_map(|(x, w)| std::iter::repeat_n(*x, *w as usize))
+                .collect::<Vec<_>>();
+            let n = repeated.len() as f64;
+            let mean = repeated.iter().sum::<f64>() / n;
+            let expected = repeated
+                .iter()
+                .map(|x| (x - mean) * (x - mean))
+                .sum::<f64>()
+                / n;
+            let actual = out.get(end - 1).unwrap();
+            assert!((actual - expected).abs() < 1e-12, "{actual} != {expected}");
+        }
+        assert!(out.get(1).is_none());
+
+        // Equal weights with ddof 1 give the regular sample variance.
+        let params = Some(RollingFnParams::WeightedVar {
+            weights: vec![0.5; 3],
+            ddof: 1,
+        });
+        let weighted = rolling_var(values, 3, 3, false, None, params).unwrap();
+        let plain = rolling_var(
+            values,
+            3,
+            3,
+            false,
+            None,
+            Some(RollingFnParams::Var(RollingVarParams { ddof: 1 })),
+        )
+        .unwrap();
+        let weighted = weighted
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap();
+        let plain = plain
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .unwrap();
+        for (w, p) in weighted.iter().zip(plain.iter()) {
+            match (w, p) {
+                (Some(w), Some(p)) => assert!((w - p).abs() < 1e-12, "{w} != {p}"),
+                (w, p) => assert_eq!(w, p),
+            }
+        }
+
+        // The weights must cover exactly one window.
+        let params = Some(RollingFnParams::WeightedMean { weights });
+        assert!(rolling_weighted_mean(values, 2, 1, false, params).is_err());
+    }
 }
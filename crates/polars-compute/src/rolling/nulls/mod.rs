@@ -96,10 +96,11 @@ where
 
 #[cfg(test)]
 mod test {
-    use arrow::array::{Array, Int32Array};
+    use arrow::array::{Array, Int32Array, StaticArray};
     use arrow::buffer::Buffer;
     use arrow::datatypes::ArrowDataType;
     use polars_utils::min_max::MaxIgnoreNan;
+    use rand::prelude::*;
 
     use super::*;
     use crate::rolling::min_max::MinMaxWindow;
@@ -175,25 +176,25 @@ mod test {
         let arr = get_null_arr();
         let arr = &arr;
 
-        let out = rolling_var(arr, 3, 1, false, None, None);
+        let out = rolling_var(arr, 3, 1, false, None, None).unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
 
         assert_eq!(out, &[None, None, Some(2.0), Some(12.5)]);
 
         let testpars = Some(RollingFnParams::Var(RollingVarParams { ddof: 0 }));
-        let out = rolling_var(arr, 3, 1, false, None, testpars);
+        let out = rolling_var(arr, 3, 1, false, None, testpars.clone()).unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
 
         assert_eq!(out, &[Some(0.0), Some(0.0), Some(1.0), Some(6.25)]);
 
-        let out = rolling_var(arr, 4, 1, false, None, None);
+        let out = rolling_var(arr, 4, 1, false, None, None).unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, None, Some(2.0), Some(6.333333333333334)]);
 
-        let out = rolling_var(arr, 4, 1, false, None, testpars);
+        let out = rolling_var(arr, 4, 1, false, None, testpars).unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(
@@ -202,6 +203,117 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_rolling_weighted_var_mean_nulls() {
+        let arr = get_null_arr();
+        let arr = &arr;
+        let weights = vec![1.0, 2.0, 3.0];
+
+        // Nulls keep their position in the window, so their weight is skipped.
+        let params = Some(RollingFnParams::WeightedMean {
+            weights: weights.clone(),
+        });
+        let out = rolling_weighted_mean(arr, 3, 1, false, params.clone()).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1.0), Some(1.0), Some(-0.5), Some(2.0)]);
+
+        let out = rolling_weighted_mean(arr, 3, 2, false, params).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, Some(-0.5), Some(2.0)]);
+
+        let params = Some(RollingFnParams::WeightedVar { weights, ddof: 0 });
+        let out = rolling_var(arr, 3, 2, false, None, params).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, Some(0.75), Some(6.0)]);
+
+        // The number of weights must match the window size.
+        let params = Some(RollingFnParams::WeightedVar {
+            weights: vec![1.0, 2.0],
+            ddof: 0,
+        });
+        assert!(rolling_var(arr, 3, 2, false, None, params).is_err());
+        let params = Some(RollingFnParams::WeightedMean {
+            weights: vec![1.0, 2.0],
+        });
+        assert!(rolling_weighted_mean(arr, 3, 2, false, params).is_err());
+    }
+
+    #[test]
+    fn test_rolling_exp_weighted_var_nulls() {
+        let mut rng = StdRng::seed_from_u64(0xdeadbeef);
+        let len = 200;
+        let values = (0..len)
+            .map(|_| rng.random_range(-10.0..10.0))
+            .collect::<Vec<f64>>();
+        let validity = (0..len).map(|_| rng.random_bool(0.8)).collect::<Bitmap>();
+        let arr = PrimitiveArray::new(
+            ArrowDataType::Float64,
+            values.into(),
+            Some(validity.clone()),
+        );
+
+        for (window_size, min_periods, center, halflife, ddof) in [
+            (5, 1, false, 1.0, 0),
+            (5, 3, true, 2.5, 1),
+            (12, 4, false, 0.5, 1),
+            (12, 12, true, 100.0, 0),
+        ] {
+            let params = Some(RollingFnParams::ExpWeightedVar { halflife, ddof });
+            let out = rolling_var(&arr, window_size, min_periods, center, None, params).unwrap();
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+
+            let offsets_fn = if center {
+                det_offsets_center
+            } else {
+                det_offsets
+            };
+            for i in 0..len {
+                // Weigh the valid values by their distance to the end of the window.
+                let (start, end) = offsets_fn(i, window_size, len);
+                let window = (start..end)
+                    .filter(|&j| validity.get_bit(j))
+                    .map(|j| {
+                        let weight = 0.5f64.powf((end - 1 - j) as f64 / halflife);
+                        (arr.value(j), weight)
+                    })
+                    .collect::<Vec<_>>();
+
+                let expected = if window.len() < min_periods {
+                    None
+                } else {
+                    let w_sum = window.iter().map(|(_, w)| w).sum::<f64>();
+                    let w_sq_sum = window.iter().map(|(_, w)| w * w).sum::<f64>();
+                    let mean = window.iter().map(|(x, w)| x * w).sum::<f64>() / w_sum;
+                    let dp = window
+                        .iter()
+                        .map(|(x, w)| w * (x - mean) * (x - mean))
+                        .sum::<f64>();
+                    let denom = w_sum - ddof as f64 * w_sq_sum / w_sum;
+                    (denom > 0.0).then(|| dp / denom)
+                };
+
+                match (out.get(i), expected) {
+                    (Some(actual), Some(expected)) => assert!(
+                        (actual - expected).abs() <= 1e-9 * expected.abs().max(1.0),
+                        "index {i}: {actual} != {expected}"
+                    ),
+                    (actual, expected) => assert_eq!(actual, expected, "index {i}"),
+                }
+            }
+        }
+
+        // The halflife must be positive.
+        let params = Some(RollingFnParams::ExpWeightedVar {
+            halflife: 0.0,
+            ddof: 1,
+        });
+        assert!(check_positional_params(&params, 5).is_err());
+        assert!(rolling_var(&arr, 5, 1, false, None, params).is_err());
+    }
+
     #[test]
     fn test_rolling_max_no_nulls() {
         let buf = Buffer::from(vec![1.0, 2.0, 3.0, 4.0]);
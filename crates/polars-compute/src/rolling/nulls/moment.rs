@@ -1,6 +1,7 @@
 #![allow(unsafe_op_in_unsafe_fn)]
 
 use num_traits::{FromPrimitive, ToPrimitive};
+use polars_error::PolarsResult;
 
 pub use super::super::moment::*;
 use super::*;
@@ -12,7 +13,7 @@ pub fn rolling_var<T>(
     center: bool,
     weights: Option<&[f64]>,
     params: Option<RollingFnParams>,
-) -> ArrayRef
+) -> PolarsResult<ArrayRef>
 where
     T: NativeType + ToPrimitive + FromPrimitive + IsFloat + Float,
 {
@@ -24,14 +25,69 @@ where
     } else {
         det_offsets
     };
-    rolling_apply_agg_window::<MomentWindow<_, VarianceMoment>, _, _, _>(
+    let out = match params {
+        Some(RollingFnParams::WeightedVar { .. }) => {
+            check_positional_params(&params, window_size)?;
+            rolling_apply_agg_window::<MomentWindow<_, WeightedVarianceMoment>, _, _, _>(
+                arr.values().as_slice(),
+                arr.validity().as_ref().unwrap(),
+                window_size,
+                min_periods,
+                offsets_fn,
+                params,
+            )
+        },
+        Some(RollingFnParams::ExpWeightedVar { .. }) => {
+            check_positional_params(&params, window_size)?;
+            rolling_apply_agg_window::<MomentWindow<_, ExpWeightedVarianceMoment>, _, _, _>(
+                arr.values().as_slice(),
+                arr.validity().as_ref().unwrap(),
+                window_size,
+                min_periods,
+                offsets_fn,
+                params,
+            )
+        },
+        _ => rolling_apply_agg_window::<MomentWindow<_, VarianceMoment>, _, _, _>(
+            arr.values().as_slice(),
+            arr.validity().as_ref().unwrap(),
+            window_size,
+            min_periods,
+            offsets_fn,
+            params,
+        ),
+    };
+    Ok(out)
+}
+
+/// Rolling mean where every position in the window has its own weight, given by
+/// [`RollingFnParams::WeightedMean`]. Null values keep their position but don't contribute to the
+/// window, and windows whose weights sum to zero are null.
+pub fn rolling_weighted_mean<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + ToPrimitive + FromPrimitive + IsFloat + Float,
+{
+    check_positional_params(&params, window_size)?;
+    let offsets_fn = if center {
+        det_offsets_center
+    } else {
+        det_offsets
+    };
+    let out = rolling_apply_agg_window::<MomentWindow<_, WeightedMeanMoment>, _, _, _>(
         arr.values().as_slice(),
         arr.validity().as_ref().unwrap(),
         window_size,
         min_periods,
         offsets_fn,
         params,
-    )
+    );
+    Ok(out)
 }
 
 pub fn rolling_skew<T>(
@@ -83,3 +139,56 @@ where
         params,
     )
 }
+
+/// Rolling coefficient of variation, see [`CvMoment`]. Null values don't contribute to the
+/// window.
+pub fn rolling_cv<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    T: NativeType + ToPrimitive + FromPrimitive + IsFloat + Float,
+{
+    let offsets_fn = if center {
+        det_offsets_center
+    } else {
+        det_offsets
+    };
+    rolling_apply_agg_window::<MomentWindow<_, CvMoment>, _, _, _>(
+        arr.values().as_slice(),
+        arr.validity().as_ref().unwrap(),
+        window_size,
+        min_periods,
+        offsets_fn,
+        params,
+    )
+}
+
+/// Rolling geometric mean, see [`GeoMeanMoment`]. Null values don't contribute to the window, and
+/// windows with a value that isn't positive are NaN.
+pub fn rolling_geo_mean<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> ArrayRef
+where
+    T: NativeType + ToPrimitive + FromPrimitive + IsFloat + Float,
+{
+    let offsets_fn = if center {
+        det_offsets_center
+    } else {
+        det_offsets
+    };
+    rolling_apply_agg_window::<MomentWindow<_, GeoMeanMoment>, _, _, _>(
+        arr.values().as_slice(),
+        arr.validity().as_ref().unwrap(),
+        window_size,
+        min_periods,
+        offsets_fn,
+        None,
+    )
+}
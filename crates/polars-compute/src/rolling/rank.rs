@@ -16,77 +16,129 @@ where
     fn rank<'a>(&mut self, ost: &OrderStatisticTree<&'a T>, value: &'a T) -> Option<Out>;
 }
 
+/// Whether the ranks are assigned from the largest to the smallest value.
+fn is_descending(params: &RollingFnParams) -> bool {
+    let RollingFnParams::Rank { descending, .. } = params else {
+        unreachable!("expected RollingFnParams::Rank");
+    };
+    *descending
+}
+
+/// The 1-based range of ranks that tied values equal to `value` span in the window.
+fn rank_range<'a, T: NativeType>(
+    ost: &OrderStatisticTree<&'a T>,
+    value: &'a T,
+    descending: bool,
+) -> Option<(usize, usize)> {
+    let rank_range = ost.rank_range(&value).ok()?;
+    let rank_lo = rank_range.start() + 1;
+    let rank_hi = rank_range.end() + 1;
+    if descending {
+        let len = ost.len();
+        Some((len + 1 - rank_hi, len + 1 - rank_lo))
+    } else {
+        Some((rank_lo, rank_hi))
+    }
+}
+
 #[derive(Debug)]
-pub struct RankPolicyAverage;
+pub struct RankPolicyAverage {
+    descending: bool,
+}
 
 impl<T: NativeType> RankPolicy<T, f64> for RankPolicyAverage {
-    fn new(_params: &RollingFnParams) -> Self {
-        Self
+    fn new(params: &RollingFnParams) -> Self {
+        Self {
+            descending: is_descending(params),
+        }
     }
     fn rank<'a>(&mut self, ost: &OrderStatisticTree<&'a T>, value: &'a T) -> Option<f64> {
-        let rank_range = ost.rank_range(&value).ok()?;
-        let rank_lo = (rank_range.start() + 1) as f64;
-        let rank_hi = (rank_range.end() + 1) as f64;
-        Some((rank_lo + rank_hi) / 2.0)
+        let (rank_lo, rank_hi) = rank_range(ost, value, self.descending)?;
+        Some((rank_lo + rank_hi) as f64 / 2.0)
     }
 }
 
 #[derive(Debug)]
-pub struct RankPolicyMin;
+pub struct RankPolicyMin {
+    descending: bool,
+}
 
 impl<T: NativeType> RankPolicy<T, IdxSize> for RankPolicyMin {
-    fn new(_params: &RollingFnParams) -> Self {
-        Self
+    fn new(params: &RollingFnParams) -> Self {
+        Self {
+            descending: is_descending(params),
+        }
     }
     fn rank<'a>(&mut self, ost: &OrderStatisticTree<&'a T>, value: &'a T) -> Option<IdxSize> {
-        Some(IdxSize::try_from(ost.rank_range(&value).ok()?.start() + 1).unwrap())
+        let (rank_lo, _) = rank_range(ost, value, self.descending)?;
+        Some(IdxSize::try_from(rank_lo).unwrap())
     }
 }
 
 #[derive(Debug)]
-pub struct RankPolicyMax;
+pub struct RankPolicyMax {
+    descending: bool,
+}
 
 impl<T: NativeType> RankPolicy<T, IdxSize> for RankPolicyMax {
-    fn new(_params: &RollingFnParams) -> Self {
-        Self
+    fn new(params: &RollingFnParams) -> Self {
+        Self {
+            descending: is_descending(params),
+        }
     }
     fn rank<'a>(&mut self, ost: &OrderStatisticTree<&'a T>, value: &'a T) -> Option<IdxSize> {
-        Some(IdxSize::try_from(*ost.rank_range(&value).ok()?.end() + 1).unwrap())
+        let (_, rank_hi) = rank_range(ost, value, self.descending)?;
+        Some(IdxSize::try_from(rank_hi).unwrap())
     }
 }
 
 #[derive(Debug)]
-pub struct RankPolicyDense;
+pub struct RankPolicyDense {
+    descending: bool,
+}
 
 impl<T: NativeType> RankPolicy<T, IdxSize> for RankPolicyDense {
-    fn new(_params: &RollingFnParams) -> Self {
-        Self
+    fn new(params: &RollingFnParams) -> Self {
+        Self {
+            descending: is_descending(params),
+        }
     }
     fn rank<'a>(&mut self, ost: &OrderStatisticTree<&'a T>, value: &'a T) -> Option<IdxSize> {
-        Some(IdxSize::try_from(ost.rank_unique(&value).ok()? + 1).unwrap())
+        let rank = ost.rank_unique(&value).ok()?;
+        let rank = if self.descending {
+            ost.unique_len() - rank
+        } else {
+            rank + 1
+        };
+        Some(IdxSize::try_from(rank).unwrap())
     }
 }
 
 #[derive(Debug)]
 pub struct RankPolicyRandom {
     rng: SmallRng,
+    descending: bool,
 }
 
 impl<T: NativeType> RankPolicy<T, IdxSize> for RankPolicyRandom {
     fn new(params: &RollingFnParams) -> Self {
-        let RollingFnParams::Rank { seed, .. } = params else {
+        let RollingFnParams::Rank {
+            seed, descending, ..
+        } = params
+        else {
             unreachable!("expected RollingFnParams::Rank");
         };
         let rng = match seed {
             Some(s) => SmallRng::seed_from_u64(*s),
             None => SmallRng::from_os_rng(),
         };
-        Self { rng }
+        Self {
+            rng,
+            descending: *descending,
+        }
     }
     fn rank<'a>(&mut self, ost: &OrderStatisticTree<&'a T>, value: &'a T) -> Option<IdxSize> {
-        let rank_range = ost.rank_range(&value).ok()?;
-        let rank_lo = rank_range.start() + 1;
-        let rank_hi = rank_range.end() + 1;
+        let (rank_lo, rank_hi) = rank_range(ost, value, self.descending)?;
         Some(IdxSize::try_from(self.rng.random_range(rank_lo..=rank_hi)).unwrap())
     }
 }
@@ -93,6 +93,14 @@ impl ArrayChunked {
             .unwrap()
             .clone());
         }
+        // `f` is never called, keep the inner dtype like for the empty case above.
+        if self.null_count() == self.len() {
+            return Ok(ListChunked::full_null_with_dtype(
+                self.name().clone(),
+                self.len(),
+                self.inner_dtype(),
+            ));
+        }
         let mut fast_explode = self.null_count() == 0;
         let mut ca: ListChunked = {
             self.amortized_iter()
@@ -4,9 +4,12 @@ mod iterator;
 
 use std::borrow::Cow;
 
+use arrow::bitmap::Bitmap;
 use either::Either;
 
+use crate::chunked_array::cast::CastOptions;
 use crate::prelude::*;
+use crate::utils::handle_casting_failures;
 
 impl ArrayChunked {
     /// Get the inner data type of the fixed size list.
@@ -21,10 +24,23 @@ impl ArrayChunked {
     /// Panics if the physical representation of `dtype` differs the physical
     /// representation of the existing inner `dtype`.
     pub fn set_inner_dtype(&mut self, dtype: DataType) {
-        assert_eq!(dtype.to_physical(), self.inner_dtype().to_physical());
+        self.try_set_inner_dtype(dtype).unwrap()
+    }
+
+    /// Set the inner data type of the fixed size list.
+    ///
+    /// Errors if the physical representation of `dtype` differs the physical
+    /// representation of the existing inner `dtype`.
+    pub fn try_set_inner_dtype(&mut self, dtype: DataType) -> PolarsResult<()> {
+        polars_ensure!(
+            dtype.to_physical() == self.inner_dtype().to_physical(),
+            SchemaMismatch: "cannot set inner dtype of array to '{}': physical type differs from current inner dtype '{}'",
+            dtype, self.inner_dtype(),
+        );
         let width = self.width();
         let field = Arc::make_mut(&mut self.field);
         field.coerce(DataType::Array(Box::new(dtype), width));
+        Ok(())
     }
 
     pub fn width(&self) -> usize {
@@ -49,21 +65,32 @@ impl ArrayChunked {
             return Cow::Borrowed(self);
         };
 
-        let chunk_len_validity_iter =
-            if physical_repr.chunks().len() == 1 && self.chunks().len() > 1 {
-                // Physical repr got rechunked, rechunk our validity as well.
-                Either::Left(std::iter::once((self.len(), self.rechunk_validity())))
-            } else {
-                // No rechunking, expect the same number of chunks.
-                assert_eq!(self.chunks().len(), physical_repr.chunks().len());
-                Either::Right(
-                    self.chunks()
-                        .iter()
-                        .map(|c| (c.len(), c.validity().cloned())),
-                )
-            };
+        Cow::Owned(self.with_physical_inner(physical_repr))
+    }
 
+    /// Build the physical [`ArrayChunked`] from the physical representation of the inner values.
+    fn with_physical_inner(&self, mut physical_repr: Series) -> ArrayChunked {
         let width = self.width();
+        let aligned = self.chunks().len() == physical_repr.chunks().len()
+            && self
+                .chunks()
+                .iter()
+                .zip(physical_repr.chunks())
+                .all(|(c, values)| c.len() * width == values.len());
+
+        let chunk_len_validity_iter = if aligned {
+            Either::Right(
+                self.chunks()
+                    .iter()
+                    .map(|c| (c.len(), c.validity().cloned())),
+            )
+        } else {
+            // The chunk boundaries differ, e.g. because the physical repr got rechunked. Rechunk
+            // both sides to line them up.
+            physical_repr = physical_repr.rechunk();
+            Either::Left(std::iter::once((self.len(), self.rechunk_validity())))
+        };
+
         let chunks: Vec<_> = chunk_len_validity_iter
             .zip(physical_repr.into_chunks())
             .map(|((len, validity), values)| {
@@ -86,7 +113,7 @@ impl ArrayChunked {
 
         let name = self.name().clone();
         let dtype = DataType::Array(Box::new(self.inner_dtype().to_physical()), width);
-        Cow::Owned(unsafe { ArrayChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype) })
+        unsafe { ArrayChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype) }
     }
 
     /// Convert a non-logical [`ArrayChunked`] back into a logical [`ArrayChunked`] without casting.
@@ -184,12 +211,544 @@ impl ArrayChunked {
         })
     }
 
+    /// Cast the inner values to `dtype`, keeping the width and the outer validity.
+    ///
+    /// Unlike [`ArrayChunked::try_set_inner_dtype`] this performs an actual cast, e.g. from
+    /// `Array[i32, 8]` to `Array[f64, 8]`. With [`CastOptions::Strict`] values that cannot be cast
+    /// raise an error that names the row of the first failure, instead of becoming null.
+    ///
+    /// A nested inner dtype can only be cast to another nested dtype, which casts its own inner
+    /// values in turn.
+    pub fn cast_inner(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<ArrayChunked> {
+        let inner = self.inner_dtype();
+        let is_list_like = |dt: &DataType| dt.is_array() || dt.is_list();
+        polars_ensure!(
+            !is_list_like(inner) || is_list_like(dtype),
+            InvalidOperation: "cannot cast the nested inner dtype {} of an array to {}, the target \
+            dtype must be a list or array as well", inner, dtype
+        );
+
+        // Failures within nested inner values are raised by the generic cast, without the row.
+        if !options.is_strict() || is_list_like(inner) {
+            return self.apply_to_inner(&|s| s.cast_with_options(dtype, options));
+        }
+        let width = self.width();
+        self.apply_to_inner(&|s| {
+            let out = s.cast_with_options(dtype, CastOptions::NonStrict)?;
+            handle_casting_failures(&s, &out).map_err(|err| {
+                let mut idxs = Vec::new();
+                s.find_validity_mismatch(&out, &mut idxs);
+                match idxs.first() {
+                    Some(&idx) => {
+                        let row = idx as usize / width;
+                        err.wrap_msg(|msg| format!("{msg}\n\nThe first failure is in row {row}."))
+                    },
+                    None => err,
+                }
+            })?;
+            Ok(out)
+        })
+    }
+
+    /// Replace the null values inside the sublists with one of the [`FillNullStrategy`]s.
+    ///
+    /// The outer validity is preserved. `Forward` and `Backward` fills operate per row and never
+    /// take values from a neighbouring sublist. `Mean`, `Min` and `Max` are computed over all
+    /// inner values of the non-null rows of the column.
+    pub fn fill_null_inner(&self, strategy: FillNullStrategy) -> PolarsResult<ArrayChunked> {
+        let width = self.width();
+        if width == 0 || self.get_inner().null_count() == 0 {
+            return Ok(self.clone());
+        }
+
+        match strategy {
+            FillNullStrategy::Forward(limit) | FillNullStrategy::Backward(limit) => {
+                let forward = matches!(strategy, FillNullStrategy::Forward(_));
+                self.apply_to_inner(&|s| {
+                    let validity = s.rechunk_validity().unwrap();
+                    let idx = fill_null_inner_gather_idx(&validity, width, limit, forward);
+                    s.take(&idx)
+                })
+            },
+            _ => {
+                // Values under null rows are not part of the column and must not affect the
+                // statistic.
+                let ca = self.propagate_nulls();
+                ca.as_ref()
+                    .unwrap_or(self)
+                    .apply_to_inner(&|s| s.fill_null(strategy))
+            },
+        }
+    }
+
     /// Recurse nested types until we are at the leaf array.
     pub fn get_leaf_array(&self) -> Series {
+        self.get_leaf_array_with_depth().0
+    }
+
+    /// Recurse nested types until we are at the leaf array, also returning the number of
+    /// `Array` levels that were traversed (at least 1).
+    pub fn get_leaf_array_with_depth(&self) -> (Series, usize) {
         let mut current = self.get_inner();
+        let mut depth = 1;
         while let Some(child_array) = current.try_array() {
             current = child_array.get_inner();
+            depth += 1;
+        }
+        (current, depth)
+    }
+
+    /// Collapse one level of nesting, turning `Array[Array[T, k], n]` into `Array[T, n * k]`.
+    ///
+    /// The leaf values of null inner arrays become null, the outer validity is preserved. See
+    /// [`ArrayChunked::get_leaf_array`] to fully recurse instead.
+    pub fn flatten_one_level(&self) -> PolarsResult<ArrayChunked> {
+        let DataType::Array(leaf_dtype, inner_width) = self.inner_dtype() else {
+            polars_bail!(
+                ComputeError: "cannot flatten array with inner dtype '{}': expected a nested array",
+                self.inner_dtype()
+            );
+        };
+        let inner_width = *inner_width;
+        let width = self.width() * inner_width;
+
+        let chunks = self
+            .downcast_iter()
+            .map(|arr| {
+                let inner = arr
+                    .values()
+                    .as_any()
+                    .downcast_ref::<FixedSizeListArray>()
+                    .unwrap();
+                let mut values = inner.values().clone();
+                if let Some(inner_validity) = inner.validity() {
+                    let inner_validity: Bitmap = inner_validity
+                        .iter()
+                        .flat_map(|v| std::iter::repeat_n(v, inner_width))
+                        .collect();
+                    let validity = arrow::compute::utils::combine_validities_and(
+                        values.validity(),
+                        Some(&inner_validity),
+                    );
+                    values = values.with_validity(validity);
+                }
+
+                FixedSizeListArray::new(
+                    FixedSizeListArray::default_datatype(values.dtype().clone(), width),
+                    arr.len(),
+                    values,
+                    arr.validity().cloned(),
+                )
+                .to_boxed()
+            })
+            .collect();
+
+        let dtype = DataType::Array(leaf_dtype.clone(), width);
+        Ok(unsafe { Self::from_chunks_and_dtype_unchecked(self.name().clone(), chunks, dtype) })
+    }
+
+    /// Flatten the arrays into their values, together with the index of the row that every value
+    /// comes from.
+    ///
+    /// Null rows contribute `width` null values, or no values at all if `empty_nulls` is set.
+    pub fn explode_with_index(&self, empty_nulls: bool) -> (Series, IdxCa) {
+        let width = self.width();
+        let inner = self.get_inner();
+
+        let validity = self
+            .rechunk_validity()
+            .filter(|validity| validity.unset_bits() > 0);
+        let Some(validity) = validity else {
+            // Fast path: every row contributes its values as is.
+            let index = (0..self.len() as IdxSize)
+                .flat_map(|row| std::iter::repeat_n(row, width))
+                .collect::<Vec<_>>();
+            return (inner, IdxCa::from_vec(PlSmallStr::EMPTY, index));
+        };
+
+        let mut gather = Vec::with_capacity(inner.len());
+        let mut index = Vec::with_capacity(inner.len());
+        for (row, valid) in validity.iter().enumerate() {
+            if !valid && empty_nulls {
+                continue;
+            }
+            let start = row * width;
+            gather.extend((start..start + width).map(|i| valid.then_some(i as IdxSize)));
+            index.extend(std::iter::repeat_n(row as IdxSize, width));
+        }
+
+        let gather = IdxCa::from_slice_options(PlSmallStr::EMPTY, &gather);
+        // SAFETY: the indices are within the bounds of the inner values.
+        let values = unsafe { inner.take_unchecked(&gather) };
+        (values, IdxCa::from_vec(PlSmallStr::EMPTY, index))
+    }
+}
+
+/// Get the gather indices that forward or backward fill the nulls in `validity` without crossing
+/// the boundaries of the sublists of size `width`.
+fn fill_null_inner_gather_idx(
+    validity: &Bitmap,
+    width: usize,
+    limit: FillNullLimit,
+    forward: bool,
+) -> IdxCa {
+    let limit = limit.map_or(usize::MAX, |limit| limit as usize);
+    let mut idx = vec![None; validity.len()];
+
+    for row_start in (0..validity.len()).step_by(width) {
+        let row = row_start..row_start + width;
+        let mut last_valid = None;
+        let mut fill = |i: usize| {
+            if validity.get_bit(i) {
+                last_valid = Some(i);
+            }
+            idx[i] = last_valid
+                .filter(|&j: &usize| j.abs_diff(i) <= limit)
+                .map(|j| j as IdxSize);
+        };
+
+        if forward {
+            row.for_each(&mut fill);
+        } else {
+            row.rev().for_each(&mut fill);
         }
-        current
+    }
+
+    IdxCa::from_iter_options(PlSmallStr::EMPTY, idx.into_iter())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn array_i32(rows: &[Option<[Option<i32>; 3]>]) -> ArrayChunked {
+        let rows = rows
+            .iter()
+            .map(|row| row.map(|row| Series::new(PlSmallStr::EMPTY, row)))
+            .collect::<Vec<_>>();
+        Series::new(PlSmallStr::from_static("a"), rows)
+            .cast(&DataType::Array(Box::new(DataType::Int32), 3))
+            .unwrap()
+            .array()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn test_fill_null_inner() -> PolarsResult<()> {
+        let ca = array_i32(&[
+            Some([None, Some(1), None]),
+            Some([None, None, Some(2)]),
+            None,
+            Some([Some(3), None, None]),
+        ]);
+
+        let out = ca.fill_null_inner(FillNullStrategy::Forward(None))?;
+        let expected = array_i32(&[
+            Some([None, Some(1), Some(1)]),
+            Some([None, None, Some(2)]),
+            None,
+            Some([Some(3), Some(3), Some(3)]),
+        ]);
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        let out = ca.fill_null_inner(FillNullStrategy::Backward(Some(1)))?;
+        let expected = array_i32(&[
+            Some([Some(1), Some(1), None]),
+            Some([None, Some(2), Some(2)]),
+            None,
+            Some([Some(3), None, None]),
+        ]);
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+
+        let out = ca.fill_null_inner(FillNullStrategy::Zero)?;
+        let expected = array_i32(&[
+            Some([Some(0), Some(1), Some(0)]),
+            Some([Some(0), Some(0), Some(2)]),
+            None,
+            Some([Some(3), Some(0), Some(0)]),
+        ]);
+        assert!(out.into_series().equals_missing(&expected.into_series()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_null_inner_ignores_null_rows() -> PolarsResult<()> {
+        // The second row is null but still has (hidden) non-null values.
+        let ca = array_i32(&[
+            Some([None, Some(1), Some(2)]),
+            Some([Some(100), Some(-100), Some(50)]),
+        ]);
+        let arr = ca.downcast_iter().next().unwrap().clone();
+        let arr = arr.with_validity(Some(Bitmap::from([true, false])));
+        let ca = ArrayChunked::with_chunk(ca.name().clone(), arr);
+
+        for (strategy, fill) in [
+            (FillNullStrategy::Min, 1),
+            (FillNullStrategy::Max, 2),
+            (FillNullStrategy::Mean, 1),
+        ] {
+            let out = ca.fill_null_inner(strategy)?;
+            let expected = array_i32(&[Some([Some(fill), Some(1), Some(2)]), None]);
+            assert!(out.into_series().equals_missing(&expected.into_series()));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_set_inner_dtype() {
+        let mut ca = array_i32(&[Some([Some(1), None, Some(3)])]);
+
+        assert!(ca.try_set_inner_dtype(DataType::String).is_err());
+        assert_eq!(ca.inner_dtype(), &DataType::Int32);
+
+        #[cfg(feature = "dtype-date")]
+        {
+            ca.try_set_inner_dtype(DataType::Date).unwrap();
+            assert_eq!(ca.dtype(), &DataType::Array(Box::new(DataType::Date), 3));
+        }
+    }
+
+    #[cfg(feature = "dtype-datetime")]
+    #[test]
+    fn test_to_physical_repr_unaligned_chunks() -> PolarsResult<()> {
+        let mut ca = array_i32(&[Some([Some(1), None, Some(3)]), None]);
+        ca.append(&array_i32(&[Some([Some(4), Some(5), None])]))?;
+        ca.append(&array_i32(&[
+            Some([None, None, Some(9)]),
+            Some([Some(10), Some(11), Some(12)]),
+        ]))?;
+        let dtype = DataType::Array(
+            Box::new(DataType::Datetime(TimeUnit::Milliseconds, None)),
+            3,
+        );
+        let ca = ca.cast(&dtype)?.array()?.clone();
+        assert!(ca.chunks().len() > 1);
+        let expected = ca.rechunk().to_physical_repr().into_owned().into_series();
+
+        let out = ca.to_physical_repr().into_owned().into_series();
+        assert!(out.equals_missing(&expected));
+
+        // The physical values in chunks with other boundaries than the arrays.
+        let values = ca.get_inner().to_physical_repr().into_owned();
+        let (mut physical_repr, rest) = values.split_at(4);
+        let (middle, last) = rest.split_at(4);
+        physical_repr.append(&middle)?.append(&last)?;
+        assert_eq!(physical_repr.chunks().len(), ca.chunks().len());
+
+        let out = ca.with_physical_inner(physical_repr).into_series();
+        assert_eq!(out.dtype(), expected.dtype());
+        assert!(out.equals_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_flatten_one_level() -> PolarsResult<()> {
+        let inner = array_i32(&[
+            Some([Some(1), Some(2), Some(3)]),
+            None,
+            Some([None, Some(5), Some(6)]),
+            Some([Some(7), Some(8), Some(9)]),
+        ])
+        .into_series();
+        let rows = [Some(inner.slice(0, 2)), None, Some(inner.slice(2, 2))];
+        let nested = Series::new(PlSmallStr::from_static("a"), rows).cast(&DataType::Array(
+            Box::new(DataType::Array(Box::new(DataType::Int32), 3)),
+            2,
+        ))?;
+
+        let out = nested.array()?.flatten_one_level()?;
+        assert_eq!(out.dtype(), &DataType::Array(Box::new(DataType::Int32), 6));
+
+        let rows = [
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [Some(1), Some(2), Some(3), None, None, None],
+            )),
+            None,
+            Some(Series::new(
+                PlSmallStr::EMPTY,
+                [None, Some(5), Some(6), Some(7), Some(8), Some(9)],
+            )),
+        ];
+        let expected = Series::new(PlSmallStr::from_static("a"), rows)
+            .cast(&DataType::Array(Box::new(DataType::Int32), 6))?;
+        assert!(out.into_series().equals_missing(&expected));
+
+        assert!(array_i32(&[None]).flatten_one_level().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_explode_with_index() {
+        let ca = array_i32(&[
+            Some([Some(1), None, Some(3)]),
+            None,
+            Some([Some(4), Some(5), Some(6)]),
+        ]);
+
+        let (values, index) = ca.explode_with_index(false);
+        let expected = array_i32(&[
+            Some([Some(1), None, Some(3)]),
+            Some([None, None, None]),
+            Some([Some(4), Some(5), Some(6)]),
+        ])
+        .get_inner();
+        assert!(values.equals_missing(&expected));
+        assert_eq!(index.cont_slice().unwrap(), &[0, 0, 0, 1, 1, 1, 2, 2, 2]);
+
+        let (values, index) = ca.explode_with_index(true);
+        let expected = Series::new(
+            PlSmallStr::from_static("a"),
+            [Some(1), None, Some(3), Some(4), Some(5), Some(6)],
+        );
+        assert!(values.equals_missing(&expected));
+        assert_eq!(index.cont_slice().unwrap(), &[0, 0, 0, 2, 2, 2]);
+    }
+
+    #[test]
+    fn test_explode_with_index_multiple_chunks() -> PolarsResult<()> {
+        let mut ca = array_i32(&[Some([Some(1), Some(2), Some(3)])]);
+        ca.append(&array_i32(&[None, Some([Some(4), Some(5), Some(6)])]))?;
+        assert_eq!(ca.chunks().len(), 2);
+
+        let (values, index) = ca.explode_with_index(true);
+        let expected = Series::new(PlSmallStr::from_static("a"), [1, 2, 3, 4, 5, 6].map(Some));
+        assert!(values.equals_missing(&expected));
+        assert_eq!(index.rechunk().cont_slice().unwrap(), &[0, 0, 0, 2, 2, 2]);
+
+        let (values, index) = ca.slice(1, 2).explode_with_index(false);
+        assert_eq!(values.null_count(), 3);
+        assert_eq!(index.cont_slice().unwrap(), &[0, 0, 0, 1, 1, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_explode_with_index_width_0() -> PolarsResult<()> {
+        let empty = Series::new_empty(PlSmallStr::EMPTY, &DataType::Int32);
+        let ca = Series::new(PlSmallStr::from_static("a"), [Some(empty), None])
+            .cast(&DataType::Array(Box::new(DataType::Int32), 0))?;
+
+        for empty_nulls in [false, true] {
+            let (values, index) = ca.array()?.explode_with_index(empty_nulls);
+            assert_eq!(values.dtype(), &DataType::Int32);
+            assert!(values.is_empty());
+            assert!(index.is_empty());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_leaf_array_with_depth() -> PolarsResult<()> {
+        let ca = array_i32(&[Some([Some(1), None, Some(3)]), None]);
+        let (leaf, depth) = ca.get_leaf_array_with_depth();
+        assert_eq!(depth, 1);
+        assert_eq!(leaf.dtype(), &DataType::Int32);
+        assert_eq!(leaf.len(), 6);
+
+        let inner = ca.into_series();
+        let nested = Series::new(
+            PlSmallStr::from_static("a"),
+            [Some(inner.slice(0, 1)), Some(inner.slice(1, 1))],
+        )
+        .cast(&DataType::Array(
+            Box::new(DataType::Array(Box::new(DataType::Int32), 3)),
+            1,
+        ))?;
+        let (leaf, depth) = nested.array()?.get_leaf_array_with_depth();
+        assert_eq!(depth, 2);
+        assert!(leaf.equals_missing(&nested.array()?.get_leaf_array()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_inner() -> PolarsResult<()> {
+        let ca = array_i32(&[Some([Some(1), None, Some(3)]), None]);
+
+        let out = ca.cast_inner(&DataType::Float64, CastOptions::Strict)?;
+        assert_eq!(
+            out.dtype(),
+            &DataType::Array(Box::new(DataType::Float64), 3)
+        );
+        let expected = ca
+            .clone()
+            .into_series()
+            .cast(&DataType::Array(Box::new(DataType::Float64), 3))?;
+        assert!(out.into_series().equals_missing(&expected));
+
+        let dtype = DataType::Array(Box::new(DataType::Int32), 2);
+        let expected = ca.get_inner().cast(&dtype).unwrap_err();
+        let err = ca.cast_inner(&dtype, CastOptions::Strict).unwrap_err();
+        assert_eq!(err.to_string(), expected.to_string());
+
+        // 300 doesn't fit in an i8, which must not silently become null.
+        let ca = array_i32(&[
+            Some([Some(1), Some(2), None]),
+            Some([Some(1), Some(300), None]),
+        ]);
+        let err = ca
+            .cast_inner(&DataType::Int8, CastOptions::Strict)
+            .unwrap_err();
+        assert!(err.to_string().contains("The first failure is in row 1."));
+        let out = ca
+            .slice(0, 0)
+            .cast_inner(&DataType::Int8, CastOptions::Strict)?;
+        assert_eq!(out.dtype(), &DataType::Array(Box::new(DataType::Int8), 3));
+
+        // The row is relative to the start of a slice.
+        let err = ca
+            .slice(1, 1)
+            .cast_inner(&DataType::Int8, CastOptions::Strict)
+            .unwrap_err();
+        assert!(err.to_string().contains("The first failure is in row 0."));
+
+        let out = ca.cast_inner(&DataType::Int8, CastOptions::NonStrict)?;
+        let expected = array_i32(&[Some([Some(1), Some(2), None]), Some([Some(1), None, None])]);
+        assert!(
+            out.into_series().equals_missing(
+                &expected
+                    .cast_inner(&DataType::Int8, CastOptions::Strict)?
+                    .into_series()
+            )
+        );
+        let out = ca.cast_inner(&DataType::UInt8, CastOptions::Overflowing)?;
+        assert_eq!(out.get_inner().u8()?.get(4), Some(300u32 as u8));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_inner_nested() -> PolarsResult<()> {
+        let ca = array_i32(&[
+            Some([Some(1), None, Some(3)]),
+            None,
+            Some([None, None, Some(6)]),
+            Some([Some(7), Some(8), Some(9)]),
+        ]);
+        let inner = ca.clone().into_series();
+        let rows = [Some(inner.slice(0, 2)), Some(inner.slice(2, 2))];
+        let nested = Series::new(PlSmallStr::from_static("a"), rows).cast(&DataType::Array(
+            Box::new(DataType::Array(Box::new(DataType::Int32), 3)),
+            2,
+        ))?;
+        let nested = nested.array()?;
+
+        let dtype = DataType::Array(Box::new(DataType::Float64), 3);
+        let out = nested.cast_inner(&dtype, CastOptions::Strict)?;
+        assert_eq!(out.dtype(), &DataType::Array(Box::new(dtype), 2));
+        assert!(
+            out.get_inner().equals_missing(
+                &ca.cast_inner(&DataType::Float64, CastOptions::Strict)?
+                    .into_series()
+            )
+        );
+
+        let err = nested
+            .cast_inner(&DataType::Float64, CastOptions::Strict)
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("the target dtype must be a list or array")
+        );
+        Ok(())
     }
 }
@@ -1,3 +1,4 @@
+use arrow::bitmap::BitmapBuilder;
 use arrow::types::NativeType;
 use polars_utils::pl_str::PlSmallStr;
 
@@ -156,3 +157,127 @@ pub(crate) fn get_fixed_size_list_builder(
     };
     Ok(builder)
 }
+
+/// Builds a numeric [`ArrayChunked`] row by row from a streaming source.
+///
+/// A chunk is sealed every `target_chunk_rows` rows, so only a single chunk is in the process of
+/// being built at any time. This bounds the memory overhead of building the column to the size of
+/// one chunk, instead of collecting all rows up front.
+pub struct ArrayStreamingBuilder<T: PolarsNumericType> {
+    name: PlSmallStr,
+    chunks: Vec<ArrayRef>,
+    values: Vec<T::Native>,
+    validity: Option<BitmapBuilder>,
+    len: usize,
+    width: usize,
+    target_chunk_rows: usize,
+    inner_dtype: DataType,
+}
+
+impl<T: PolarsNumericType> ArrayStreamingBuilder<T> {
+    pub fn new(
+        name: PlSmallStr,
+        inner_dtype: DataType,
+        width: usize,
+        target_chunk_rows: usize,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            inner_dtype.to_physical() == T::get_static_dtype(),
+            SchemaMismatch: "cannot build array of '{}' from values of type '{}'",
+            inner_dtype, T::get_static_dtype(),
+        );
+        polars_ensure!(
+            target_chunk_rows > 0,
+            InvalidOperation: "'target_chunk_rows' must be greater than 0"
+        );
+        Ok(Self {
+            name,
+            chunks: Vec::new(),
+            values: Vec::new(),
+            validity: None,
+            len: 0,
+            width,
+            target_chunk_rows,
+            inner_dtype,
+        })
+    }
+
+    /// Push a row of exactly `width` values.
+    pub fn push_row(&mut self, row: &[T::Native]) -> PolarsResult<()> {
+        polars_ensure!(
+            row.len() == self.width,
+            ShapeMismatch: "cannot push row of length {} into array of width {}",
+            row.len(), self.width,
+        );
+        self.values.extend_from_slice(row);
+        if let Some(validity) = &mut self.validity {
+            validity.push(true);
+        }
+        self.len += 1;
+        self.seal_if_full();
+        Ok(())
+    }
+
+    pub fn push_null(&mut self) {
+        self.values
+            .extend(std::iter::repeat_n(T::Native::default(), self.width));
+        self.validity
+            .get_or_insert_with(|| {
+                let mut validity = BitmapBuilder::new();
+                validity.extend_constant(self.len, true);
+                validity
+            })
+            .push(false);
+        self.len += 1;
+        self.seal_if_full();
+    }
+
+    /// Number of rows pushed so far.
+    pub fn len(&self) -> usize {
+        self.chunks.iter().map(|c| c.len()).sum::<usize>() + self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn seal_if_full(&mut self) {
+        if self.len == self.target_chunk_rows {
+            let capacity = self.values.len();
+            let values = std::mem::replace(&mut self.values, Vec::with_capacity(capacity));
+            self.seal(values);
+        }
+    }
+
+    fn seal(&mut self, values: Vec<T::Native>) {
+        let values = PrimitiveArray::from_vec(values);
+        let validity = self
+            .validity
+            .take()
+            .and_then(|validity| validity.into_opt_validity());
+        let arr = FixedSizeListArray::new(
+            FixedSizeListArray::default_datatype(values.dtype().clone(), self.width),
+            self.len,
+            values.boxed(),
+            validity,
+        );
+        self.chunks.push(arr.boxed());
+        self.len = 0;
+    }
+
+    pub fn finish(mut self) -> ArrayChunked {
+        if self.len > 0 || self.chunks.is_empty() {
+            let values = std::mem::take(&mut self.values);
+            self.seal(values);
+        }
+
+        // SAFETY: physical type matches the logical.
+        unsafe {
+            ChunkedArray::from_chunks_and_dtype(
+                self.name,
+                self.chunks,
+                DataType::Array(Box::new(self.inner_dtype), self.width),
+            )
+        }
+    }
+}
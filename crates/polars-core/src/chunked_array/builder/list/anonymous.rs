@@ -5,6 +5,7 @@ pub struct AnonymousListBuilder<'a> {
     builder: AnonymousBuilder<'a>,
     fast_explode: bool,
     inner_dtype: Option<DataType>,
+    sealed: SealedChunks,
 }
 
 impl Default for AnonymousListBuilder<'_> {
@@ -20,9 +21,21 @@ impl<'a> AnonymousListBuilder<'a> {
             builder: AnonymousBuilder::new(capacity),
             fast_explode: true,
             inner_dtype,
+            sealed: SealedChunks::default(),
         }
     }
 
+    /// Seal the values built so far into a separate chunk every `target_chunk_rows` rows, see
+    /// [`ListBuilderTrait`].
+    pub fn with_target_chunk_rows(mut self, target_chunk_rows: usize) -> PolarsResult<Self> {
+        self.sealed.set_target_chunk_rows(target_chunk_rows)?;
+        Ok(self)
+    }
+
+    fn seal_if_full(&mut self) {
+        seal_anonymous_if_full(&mut self.builder, &mut self.sealed, &self.inner_dtype);
+    }
+
     pub fn append_opt_series(&mut self, opt_s: Option<&'a Series>) -> PolarsResult<()> {
         match opt_s {
             Some(s) => return self.append_series(s),
@@ -43,19 +56,22 @@ impl<'a> AnonymousListBuilder<'a> {
     }
 
     pub fn append_array(&mut self, arr: &'a dyn Array) {
-        self.builder.push(arr)
+        self.builder.push(arr);
+        self.seal_if_full();
     }
 
     #[inline]
     pub fn append_null(&mut self) {
         self.fast_explode = false;
         self.builder.push_null();
+        self.seal_if_full();
     }
 
     #[inline]
     pub fn append_empty(&mut self) {
         self.fast_explode = false;
-        self.builder.push_empty()
+        self.builder.push_empty();
+        self.seal_if_full();
     }
 
     pub fn append_series(&mut self, s: &'a Series) -> PolarsResult<()> {
@@ -70,37 +86,38 @@ impl<'a> AnonymousListBuilder<'a> {
             self.append_empty();
         } else {
             self.builder.push_multiple(s.chunks());
+            self.seal_if_full();
         }
         Ok(())
     }
 
     pub fn finish(&mut self) -> ListChunked {
         // Don't use self from here on out.
-        let slf = std::mem::take(self);
-        if slf.builder.is_empty() {
+        let mut slf = std::mem::take(self);
+        if slf.builder.is_empty() && slf.sealed.chunks.is_empty() {
             ListChunked::full_null_with_dtype(
                 slf.name.clone(),
                 0,
                 &slf.inner_dtype.unwrap_or(DataType::Null),
             )
         } else {
-            let inner_dtype_physical = self
+            // The sealed chunks are built with the inner dtype, the remainder must match them.
+            let inner_dtype = slf
                 .inner_dtype
                 .as_ref()
-                .map(|dt| dt.to_physical().to_arrow(CompatLevel::newest()));
+                .filter(|_| !slf.sealed.chunks.is_empty());
+            let inner_dtype_physical =
+                inner_dtype.map(|dt| dt.to_physical().to_arrow(CompatLevel::newest()));
             let arr = slf.builder.finish(inner_dtype_physical.as_ref()).unwrap();
 
-            let list_dtype_logical = match &self.inner_dtype {
+            let list_dtype_logical = match inner_dtype {
                 None => DataType::from_arrow_dtype(arr.dtype()),
                 Some(dt) => DataType::List(Box::new(dt.clone())),
             };
 
-            let mut ca = ListChunked::with_chunk(PlSmallStr::EMPTY, arr);
-            if slf.fast_explode {
-                ca.set_fast_explode();
-            }
-            ca.field = Arc::new(Field::new(slf.name, list_dtype_logical));
-            ca
+            let chunks = slf.sealed.finish(arr.boxed());
+            let field = Field::new(slf.name, list_dtype_logical);
+            finish_list_chunks(&field, chunks, slf.fast_explode)
         }
     }
 }
@@ -111,6 +128,7 @@ pub struct AnonymousOwnedListBuilder {
     owned: Vec<Series>,
     inner_dtype: Option<DataType>,
     fast_explode: bool,
+    sealed: SealedChunks,
 }
 
 impl Default for AnonymousOwnedListBuilder {
@@ -137,6 +155,7 @@ impl ListBuilderTrait for AnonymousOwnedListBuilder {
             }
             // This make sure that the underlying ArrayRef's are not dropped.
             self.owned.push(s.clone());
+            self.seal_if_full();
         }
         Ok(())
     }
@@ -144,13 +163,14 @@ impl ListBuilderTrait for AnonymousOwnedListBuilder {
     #[inline]
     fn append_null(&mut self) {
         self.fast_explode = false;
-        self.builder.push_null()
+        self.builder.push_null();
+        self.seal_if_full();
     }
 
     fn finish(&mut self) -> ListChunked {
         let inner_dtype = std::mem::take(&mut self.inner_dtype);
         // Don't use self from here on out.
-        let slf = std::mem::take(self);
+        let mut slf = std::mem::take(self);
         let inner_dtype_physical = inner_dtype
             .as_ref()
             .map(|dt| dt.to_physical().to_arrow(CompatLevel::newest()));
@@ -161,12 +181,9 @@ impl ListBuilderTrait for AnonymousOwnedListBuilder {
             Some(dt) => DataType::List(Box::new(dt)),
         };
 
-        let mut ca = ListChunked::with_chunk(PlSmallStr::EMPTY, arr);
-        if slf.fast_explode {
-            ca.set_fast_explode();
-        }
-        ca.field = Arc::new(Field::new(slf.name, list_dtype_logical));
-        ca
+        let chunks = slf.sealed.finish(arr.boxed());
+        let field = Field::new(slf.name, list_dtype_logical);
+        finish_list_chunks(&field, chunks, slf.fast_explode)
     }
 }
 
@@ -178,12 +195,52 @@ impl AnonymousOwnedListBuilder {
             owned: Vec::with_capacity(capacity),
             inner_dtype,
             fast_explode: true,
+            sealed: SealedChunks::default(),
+        }
+    }
+
+    /// Seal the values built so far into a separate chunk every `target_chunk_rows` rows, see
+    /// [`ListBuilderTrait`]. The owned series are released with every sealed chunk.
+    pub fn with_target_chunk_rows(mut self, target_chunk_rows: usize) -> PolarsResult<Self> {
+        self.sealed.set_target_chunk_rows(target_chunk_rows)?;
+        Ok(self)
+    }
+
+    fn seal_if_full(&mut self) {
+        if seal_anonymous_if_full(&mut self.builder, &mut self.sealed, &self.inner_dtype) {
+            // The values are copied into the sealed chunk.
+            self.owned.clear();
         }
     }
 
     #[inline]
     pub fn append_empty(&mut self) {
         self.fast_explode = false;
-        self.builder.push_empty()
+        self.builder.push_empty();
+        self.seal_if_full();
     }
 }
+
+/// Seal the rows of `builder` if it reached the target number of rows of `sealed`.
+///
+/// Rows are only sealed once the inner dtype is known, so that all chunks get the same dtype. Rows
+/// pushed before that are nulls and empty lists, which don't hold any values.
+fn seal_anonymous_if_full(
+    builder: &mut AnonymousBuilder<'_>,
+    sealed: &mut SealedChunks,
+    inner_dtype: &Option<DataType>,
+) -> bool {
+    let Some(inner_dtype) = inner_dtype else {
+        return false;
+    };
+    let len = builder.offsets().len() - 1;
+    if !sealed.is_full(len) {
+        return false;
+    }
+
+    let builder = std::mem::replace(builder, AnonymousBuilder::new(len));
+    let inner_dtype_physical = inner_dtype.to_physical().to_arrow(CompatLevel::newest());
+    let arr = builder.finish(Some(&inner_dtype_physical)).unwrap();
+    sealed.chunks.push(arr.boxed());
+    true
+}
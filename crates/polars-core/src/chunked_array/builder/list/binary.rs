@@ -4,6 +4,7 @@ pub struct ListStringChunkedBuilder {
     builder: LargeListBinViewBuilder<str>,
     field: Field,
     fast_explode: bool,
+    sealed: SealedChunks,
 }
 
 impl ListStringChunkedBuilder {
@@ -16,6 +17,22 @@ impl ListStringChunkedBuilder {
             builder,
             field,
             fast_explode: true,
+            sealed: SealedChunks::default(),
+        }
+    }
+
+    /// Seal the values built so far into a separate chunk every `target_chunk_rows` rows, see
+    /// [`ListBuilderTrait`].
+    pub fn with_target_chunk_rows(mut self, target_chunk_rows: usize) -> PolarsResult<Self> {
+        self.sealed.set_target_chunk_rows(target_chunk_rows)?;
+        Ok(self)
+    }
+
+    #[inline]
+    fn seal_if_full(&mut self) {
+        if self.sealed.is_full(self.builder.len()) {
+            let chunk = self.builder.as_box();
+            self.sealed.chunks.push(chunk);
         }
     }
 
@@ -31,6 +48,7 @@ impl ListStringChunkedBuilder {
         // trusted len, trust the type system
         self.builder.mut_values().extend_trusted_len(iter);
         self.builder.try_push_valid().unwrap();
+        self.seal_if_full();
     }
 
     #[inline]
@@ -40,6 +58,7 @@ impl ListStringChunkedBuilder {
         }
         self.builder.mut_values().extend_values(iter);
         self.builder.try_push_valid().unwrap();
+        self.seal_if_full();
     }
 
     #[inline]
@@ -57,6 +76,7 @@ impl ListStringChunkedBuilder {
             }
         }
         self.builder.try_push_valid().unwrap();
+        self.seal_if_full();
     }
 }
 
@@ -65,6 +85,7 @@ impl ListBuilderTrait for ListStringChunkedBuilder {
     fn append_null(&mut self) {
         self.fast_explode = false;
         self.builder.push_null();
+        self.seal_if_full();
     }
 
     #[inline]
@@ -88,12 +109,19 @@ impl ListBuilderTrait for ListStringChunkedBuilder {
     fn fast_explode(&self) -> bool {
         self.fast_explode
     }
+
+    fn finish(&mut self) -> ListChunked {
+        let last = self.inner_array();
+        let chunks = self.sealed.finish(last);
+        finish_list_chunks(&self.field, chunks, self.fast_explode)
+    }
 }
 
 pub struct ListBinaryChunkedBuilder {
     builder: LargeListBinViewBuilder<[u8]>,
     field: Field,
     fast_explode: bool,
+    sealed: SealedChunks,
 }
 
 impl ListBinaryChunkedBuilder {
@@ -106,6 +134,22 @@ impl ListBinaryChunkedBuilder {
             builder,
             field,
             fast_explode: true,
+            sealed: SealedChunks::default(),
+        }
+    }
+
+    /// Seal the values built so far into a separate chunk every `target_chunk_rows` rows, see
+    /// [`ListBuilderTrait`].
+    pub fn with_target_chunk_rows(mut self, target_chunk_rows: usize) -> PolarsResult<Self> {
+        self.sealed.set_target_chunk_rows(target_chunk_rows)?;
+        Ok(self)
+    }
+
+    #[inline]
+    fn seal_if_full(&mut self) {
+        if self.sealed.is_full(self.builder.len()) {
+            let chunk = self.builder.as_box();
+            self.sealed.chunks.push(chunk);
         }
     }
 
@@ -120,6 +164,7 @@ impl ListBinaryChunkedBuilder {
         // trusted len, trust the type system
         self.builder.mut_values().extend_trusted_len(iter);
         self.builder.try_push_valid().unwrap();
+        self.seal_if_full();
     }
 
     pub fn append_values_iter<'a, I: Iterator<Item = &'a [u8]>>(&mut self, iter: I) {
@@ -128,6 +173,7 @@ impl ListBinaryChunkedBuilder {
         }
         self.builder.mut_values().extend_values(iter);
         self.builder.try_push_valid().unwrap();
+        self.seal_if_full();
     }
 
     pub(crate) fn append(&mut self, ca: &BinaryChunked) {
@@ -144,6 +190,7 @@ impl ListBinaryChunkedBuilder {
             }
         }
         self.builder.try_push_valid().unwrap();
+        self.seal_if_full();
     }
 }
 
@@ -152,6 +199,7 @@ impl ListBuilderTrait for ListBinaryChunkedBuilder {
     fn append_null(&mut self) {
         self.fast_explode = false;
         self.builder.push_null();
+        self.seal_if_full();
     }
 
     fn append_series(&mut self, s: &Series) -> PolarsResult<()> {
@@ -174,4 +222,10 @@ impl ListBuilderTrait for ListBinaryChunkedBuilder {
     fn fast_explode(&self) -> bool {
         self.fast_explode
     }
+
+    fn finish(&mut self) -> ListChunked {
+        let last = self.inner_array();
+        let chunks = self.sealed.finish(last);
+        finish_list_chunks(&self.field, chunks, self.fast_explode)
+    }
 }
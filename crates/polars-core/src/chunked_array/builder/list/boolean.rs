@@ -4,6 +4,7 @@ pub struct ListBooleanChunkedBuilder {
     builder: LargeListBooleanBuilder,
     field: Field,
     fast_explode: bool,
+    sealed: SealedChunks,
 }
 
 impl ListBooleanChunkedBuilder {
@@ -16,6 +17,22 @@ impl ListBooleanChunkedBuilder {
             builder,
             field,
             fast_explode: true,
+            sealed: SealedChunks::default(),
+        }
+    }
+
+    /// Seal the values built so far into a separate chunk every `target_chunk_rows` rows, see
+    /// [`ListBuilderTrait`].
+    pub fn with_target_chunk_rows(mut self, target_chunk_rows: usize) -> PolarsResult<Self> {
+        self.sealed.set_target_chunk_rows(target_chunk_rows)?;
+        Ok(self)
+    }
+
+    #[inline]
+    fn seal_if_full(&mut self) {
+        if self.sealed.is_full(self.builder.len()) {
+            let chunk = self.builder.as_box();
+            self.sealed.chunks.push(chunk);
         }
     }
 
@@ -30,6 +47,7 @@ impl ListBooleanChunkedBuilder {
         // trusted len, trust the type system
         unsafe { values.extend_trusted_len_unchecked(iter) };
         self.builder.try_push_valid().unwrap();
+        self.seal_if_full();
     }
 
     #[inline]
@@ -40,6 +58,7 @@ impl ListBooleanChunkedBuilder {
         let value_builder = self.builder.mut_values();
         value_builder.extend(ca);
         self.builder.try_push_valid().unwrap();
+        self.seal_if_full();
     }
 }
 
@@ -48,6 +67,7 @@ impl ListBuilderTrait for ListBooleanChunkedBuilder {
     fn append_null(&mut self) {
         self.fast_explode = false;
         self.builder.push_null();
+        self.seal_if_full();
     }
 
     #[inline]
@@ -68,4 +88,10 @@ impl ListBuilderTrait for ListBooleanChunkedBuilder {
     fn fast_explode(&self) -> bool {
         self.fast_explode
     }
+
+    fn finish(&mut self) -> ListChunked {
+        let last = self.inner_array();
+        let chunks = self.sealed.finish(last);
+        finish_list_chunks(&self.field, chunks, self.fast_explode)
+    }
 }
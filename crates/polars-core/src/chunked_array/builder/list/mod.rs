@@ -16,6 +16,11 @@ use super::*;
 #[cfg(feature = "object")]
 use crate::chunked_array::object::registry::get_object_builder;
 
+/// A builder of a [`ListChunked`] from series that are appended as rows.
+///
+/// The builders can seal the rows built so far into a separate chunk every `target_chunk_rows`
+/// rows, which bounds the memory overhead of building a large column from a streaming source to
+/// the size of a single chunk. Every chunk but the last then has exactly `target_chunk_rows` rows.
 pub trait ListBuilderTrait {
     fn append_opt_series(&mut self, opt_s: Option<&Series>) -> PolarsResult<()> {
         match opt_s {
@@ -71,6 +76,47 @@ where
     }
 }
 
+/// Chunks sealed by a list builder that has a target number of rows per chunk.
+#[derive(Default)]
+struct SealedChunks {
+    chunks: Vec<ArrayRef>,
+    target_chunk_rows: Option<usize>,
+}
+
+impl SealedChunks {
+    fn set_target_chunk_rows(&mut self, target_chunk_rows: usize) -> PolarsResult<()> {
+        polars_ensure!(
+            target_chunk_rows > 0,
+            InvalidOperation: "'target_chunk_rows' must be greater than 0"
+        );
+        self.target_chunk_rows = Some(target_chunk_rows);
+        Ok(())
+    }
+
+    /// Whether a builder with `len` rows should seal its rows into a chunk.
+    #[inline]
+    fn is_full(&self, len: usize) -> bool {
+        self.target_chunk_rows.is_some_and(|target| len >= target)
+    }
+
+    /// Take the sealed chunks followed by `last`, which is omitted if it is an empty remainder.
+    fn finish(&mut self, last: ArrayRef) -> Vec<ArrayRef> {
+        let mut chunks = std::mem::take(&mut self.chunks);
+        if chunks.is_empty() || !last.is_empty() {
+            chunks.push(last);
+        }
+        chunks
+    }
+}
+
+fn finish_list_chunks(field: &Field, chunks: Vec<ArrayRef>, fast_explode: bool) -> ListChunked {
+    let mut ca = ListChunked::new_with_compute_len(Arc::new(field.clone()), chunks);
+    if fast_explode {
+        ca.set_fast_explode()
+    }
+    ca
+}
+
 type LargePrimitiveBuilder<T> = MutableListArray<i64, MutablePrimitiveArray<T>>;
 type LargeListBinViewBuilder<T> = MutableListArray<i64, MutableBinaryViewArray<T>>;
 type LargeListBooleanBuilder = MutableListArray<i64, MutableBooleanArray>;
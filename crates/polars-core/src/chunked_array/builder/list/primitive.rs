@@ -7,6 +7,7 @@ where
     pub builder: LargePrimitiveBuilder<T::Native>,
     field: Field,
     fast_explode: bool,
+    sealed: SealedChunks,
 }
 
 impl<T> ListPrimitiveChunkedBuilder<T>
@@ -31,6 +32,7 @@ where
             builder,
             field,
             fast_explode: true,
+            sealed: SealedChunks::default(),
         }
     }
 
@@ -51,6 +53,22 @@ where
             builder,
             field,
             fast_explode: true,
+            sealed: SealedChunks::default(),
+        }
+    }
+
+    /// Seal the values built so far into a separate chunk every `target_chunk_rows` rows, see
+    /// [`ListBuilderTrait`].
+    pub fn with_target_chunk_rows(mut self, target_chunk_rows: usize) -> PolarsResult<Self> {
+        self.sealed.set_target_chunk_rows(target_chunk_rows)?;
+        Ok(self)
+    }
+
+    #[inline]
+    fn seal_if_full(&mut self) {
+        if self.sealed.is_full(self.builder.len()) {
+            let chunk = self.builder.as_box();
+            self.sealed.chunks.push(chunk);
         }
     }
 
@@ -63,15 +81,14 @@ where
         if items.is_empty() {
             self.fast_explode = false;
         }
+        self.seal_if_full();
     }
 
     #[inline]
     pub fn append_opt_slice(&mut self, opt_v: Option<&[T::Native]>) {
         match opt_v {
             Some(items) => self.append_slice(items),
-            None => {
-                self.builder.push_null();
-            },
+            None => self.append_null(),
         }
     }
     /// Appends from an iterator over values
@@ -89,6 +106,7 @@ where
         // trusted len, trust the type system
         values.extend_values(iter);
         self.builder.try_push_valid().unwrap();
+        self.seal_if_full();
     }
 
     #[inline]
@@ -100,6 +118,7 @@ where
         }
         values.extend_values(iter);
         self.builder.try_push_valid().unwrap();
+        self.seal_if_full();
     }
 
     /// Appends from an iterator over values
@@ -114,6 +133,7 @@ where
         // trusted len, trust the type system
         unsafe { values.extend_trusted_len_unchecked(iter) };
         self.builder.try_push_valid().unwrap();
+        self.seal_if_full();
     }
 }
 
@@ -125,6 +145,7 @@ where
     fn append_null(&mut self) {
         self.fast_explode = false;
         self.builder.push_null();
+        self.seal_if_full();
     }
 
     #[inline]
@@ -151,6 +172,7 @@ Expected {}, got {}.", self.field.dtype(), s.dtype())
         });
         // overflow of i64 is far beyond polars capable lengths.
         unsafe { self.builder.try_push_valid().unwrap_unchecked() };
+        self.seal_if_full();
         Ok(())
     }
 
@@ -165,4 +187,10 @@ Expected {}, got {}.", self.field.dtype(), s.dtype())
     fn fast_explode(&self) -> bool {
         self.fast_explode
     }
+
+    fn finish(&mut self) -> ListChunked {
+        let last = self.inner_array();
+        let chunks = self.sealed.finish(last);
+        finish_list_chunks(&self.field, chunks, self.fast_explode)
+    }
 }
@@ -16,6 +16,8 @@ pub use boolean::*;
 #[cfg(feature = "dtype-categorical")]
 pub use categorical::*;
 #[cfg(feature = "dtype-array")]
+pub use fixed_size_list::ArrayStreamingBuilder;
+#[cfg(feature = "dtype-array")]
 pub(crate) use fixed_size_list::*;
 pub use list::*;
 pub use null::*;
@@ -248,4 +250,152 @@ mod test {
         assert_eq!(out.len(), 7);
         assert_eq!(out.get(6).unwrap(), AnyValue::Null);
     }
+
+    /// Build `rows` with the builder returned by `new`, in chunks of `target_chunk_rows` rows.
+    fn build_in_chunks<B: ListBuilderTrait>(
+        new: impl Fn() -> B,
+        with_target: impl Fn(B, usize) -> PolarsResult<B>,
+        rows: &[Option<Series>],
+        target_chunk_rows: Option<usize>,
+    ) -> ListChunked {
+        let mut builder = new();
+        if let Some(target_chunk_rows) = target_chunk_rows {
+            builder = with_target(builder, target_chunk_rows).unwrap();
+        }
+        for row in rows {
+            builder.append_opt_series(row.as_ref()).unwrap();
+        }
+        builder.finish()
+    }
+
+    fn check_target_chunk_rows<B: ListBuilderTrait>(
+        new: impl Fn() -> B,
+        with_target: impl Fn(B, usize) -> PolarsResult<B>,
+        rows: &[Option<Series>],
+    ) {
+        let single = build_in_chunks(&new, &with_target, rows, None);
+        let multi = build_in_chunks(&new, &with_target, rows, Some(4));
+        assert_eq!(single.chunks().len(), 1);
+        assert_eq!(multi.chunks().len(), 3);
+        assert_eq!(multi.name(), single.name());
+        assert!(multi.into_series().equals_missing(&single.into_series()));
+
+        // The last chunk is not left empty if the rows align with the chunk size.
+        let multi = build_in_chunks(&new, &with_target, rows, Some(5));
+        assert_eq!(multi.chunks().len(), 2);
+
+        assert!(with_target(new(), 0).is_err());
+    }
+
+    #[test]
+    fn test_list_builders_target_chunk_rows() {
+        let name = PlSmallStr::from_static("a");
+        let rows = |f: &dyn Fn(i32) -> Series| {
+            (0..10)
+                .map(|i| (i % 3 != 0).then(|| f(i)))
+                .collect::<Vec<_>>()
+        };
+
+        let ints = rows(&|i| Series::new(PlSmallStr::EMPTY, vec![i; i as usize % 4]));
+        check_target_chunk_rows(
+            || ListPrimitiveChunkedBuilder::<Int32Type>::new(name.clone(), 10, 10, DataType::Int32),
+            ListPrimitiveChunkedBuilder::with_target_chunk_rows,
+            &ints,
+        );
+        check_target_chunk_rows(
+            || AnonymousOwnedListBuilder::new(name.clone(), 10, Some(DataType::Int32)),
+            AnonymousOwnedListBuilder::with_target_chunk_rows,
+            &ints,
+        );
+
+        let strings =
+            rows(&|i| Series::new(PlSmallStr::EMPTY, vec![i.to_string(); i as usize % 4]));
+        check_target_chunk_rows(
+            || ListStringChunkedBuilder::new(name.clone(), 10, 10),
+            ListStringChunkedBuilder::with_target_chunk_rows,
+            &strings,
+        );
+
+        let binary = strings
+            .iter()
+            .map(|s| s.as_ref().map(|s| s.cast(&DataType::Binary).unwrap()))
+            .collect::<Vec<_>>();
+        check_target_chunk_rows(
+            || ListBinaryChunkedBuilder::new(name.clone(), 10, 10),
+            ListBinaryChunkedBuilder::with_target_chunk_rows,
+            &binary,
+        );
+
+        let bools = rows(&|i| Series::new(PlSmallStr::EMPTY, vec![i % 2 == 0; i as usize % 4]));
+        check_target_chunk_rows(
+            || ListBooleanChunkedBuilder::new(name.clone(), 10, 10),
+            ListBooleanChunkedBuilder::with_target_chunk_rows,
+            &bools,
+        );
+    }
+
+    #[test]
+    fn test_anonymous_list_builder_target_chunk_rows() {
+        let rows = (0..10)
+            .map(|i| (i % 3 != 0).then(|| Series::new(PlSmallStr::EMPTY, vec![i; i as usize % 4])))
+            .collect::<Vec<_>>();
+        let build = |target_chunk_rows: Option<usize>| {
+            let mut builder =
+                AnonymousListBuilder::new(PlSmallStr::from_static("a"), 10, Some(DataType::Int32));
+            if let Some(target_chunk_rows) = target_chunk_rows {
+                builder = builder.with_target_chunk_rows(target_chunk_rows).unwrap();
+            }
+            for row in &rows {
+                builder.append_opt_array(row.as_ref().map(|s| s.chunks()[0].as_ref()));
+            }
+            builder.finish()
+        };
+
+        let single = build(None);
+        let multi = build(Some(4));
+        assert_eq!(multi.chunks().len(), 3);
+        assert!(multi.into_series().equals_missing(&single.into_series()));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-array")]
+    fn test_array_streaming_builder() {
+        let rows = (0..10)
+            .map(|i| (i % 3 != 0).then(|| [i, i + 1]))
+            .collect::<Vec<_>>();
+        let build = |target_chunk_rows: usize| {
+            let mut builder = ArrayStreamingBuilder::<Int32Type>::new(
+                PlSmallStr::from_static("a"),
+                DataType::Int32,
+                2,
+                target_chunk_rows,
+            )
+            .unwrap();
+            for row in &rows {
+                match row {
+                    Some(row) => builder.push_row(row).unwrap(),
+                    None => builder.push_null(),
+                }
+            }
+            assert_eq!(builder.len(), rows.len());
+            builder.finish()
+        };
+
+        let single = build(rows.len());
+        let multi = build(3);
+        assert_eq!(single.chunks().len(), 1);
+        assert_eq!(multi.chunks().len(), 4);
+        assert_eq!(multi.null_count(), 4);
+        assert_eq!(multi.name().as_str(), "a");
+        assert!(multi.into_series().equals_missing(&single.into_series()));
+
+        let new = |dtype: DataType, target_chunk_rows: usize| {
+            ArrayStreamingBuilder::<Int32Type>::new(PlSmallStr::EMPTY, dtype, 2, target_chunk_rows)
+        };
+        let mut builder = new(DataType::Int32, 3).unwrap();
+        assert!(builder.push_row(&[1]).is_err());
+        assert_eq!(builder.finish().len(), 0);
+        assert!(new(DataType::String, 3).is_err());
+        assert!(new(DataType::Int32, 0).is_err());
+    }
 }
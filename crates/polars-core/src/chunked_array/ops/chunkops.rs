@@ -9,7 +9,7 @@ use super::*;
 use crate::chunked_array::flags::StatisticsFlags;
 #[cfg(feature = "object")]
 use crate::chunked_array::object::builder::ObjectChunkedBuilder;
-use crate::utils::slice_offsets;
+use crate::utils::{_split_offsets, slice_offsets};
 
 pub(crate) fn split_at(
     chunks: &[ArrayRef],
@@ -93,6 +93,27 @@ pub(crate) fn slice(
     (new_chunks, new_len)
 }
 
+/// Reorganizes `chunks` into `n` chunks of about equal length, or fewer if there are fewer than
+/// `n` values. Chunks are sliced at the new boundaries, which is zero copy, and only the slices
+/// that together make up a new chunk are concatenated.
+pub(crate) fn rechunk_to_n(chunks: &[ArrayRef], own_length: usize, n: usize) -> Vec<ArrayRef> {
+    let n = n.clamp(1, own_length.max(1));
+    _split_offsets(own_length, n)
+        .into_iter()
+        .map(|(offset, len)| {
+            let (mut parts, _) = slice(chunks, offset as i64, len, own_length);
+            if parts.len() > 1 {
+                parts.retain(|arr| !arr.is_empty());
+            }
+            match parts.len() {
+                0 => chunks[0].sliced(0, 0),
+                1 => parts.pop().unwrap(),
+                _ => concatenate_unchecked(&parts).unwrap(),
+            }
+        })
+        .collect()
+}
+
 // When we deal with arrays and lists we can easily exceed the limit if
 // we take the underlying values array as a Series. This call stack
 // is hard to follow, so for this one case we make an exception
@@ -182,6 +203,20 @@ impl<T: PolarsDataType> ChunkedArray<T> {
         }
     }
 
+    /// Reorganizes the chunks into `n` chunks of about equal length, see
+    /// [`Series::rechunk_to_chunks`].
+    pub fn rechunk_to_chunks(&self, n: usize) -> Self {
+        // Object arrays can't be concatenated.
+        if self.dtype().is_object() {
+            return self.clone();
+        }
+        let chunks = rechunk_to_n(&self.chunks, self.len(), n);
+        let mut ca = unsafe { self.copy_with_chunks(chunks) };
+        use StatisticsFlags as F;
+        ca.retain_flags_from(self, F::IS_SORTED_ANY | F::CAN_FAST_EXPLODE_LIST);
+        ca
+    }
+
     /// Rechunks this ChunkedArray in-place.
     pub fn rechunk_mut(&mut self) {
         if self.chunks.len() > 1 {
@@ -360,7 +395,6 @@ impl<T: PolarsObject> ObjectChunked<T> {
 
 #[cfg(test)]
 mod test {
-    #[cfg(feature = "dtype-categorical")]
     use crate::prelude::*;
 
     #[test]
@@ -375,4 +409,89 @@ mod test {
         let a = a.rechunk();
         assert!(a.cat32().unwrap().get_mapping().num_cats_upper_bound() > 0);
     }
+
+    /// Checks that `s` is rechunked into chunks of `lengths` with the same values.
+    fn check_rechunk_to_chunks(s: &Series, n: usize, lengths: &[usize]) {
+        let out = s.rechunk_to_chunks(n);
+        assert_eq!(out.dtype(), s.dtype());
+        assert_eq!(out.chunk_lengths().collect::<Vec<_>>(), lengths);
+        let mut offset = 0;
+        for (i, &len) in lengths.iter().enumerate() {
+            assert!(
+                out.select_chunk(i)
+                    .equals_missing(&s.slice(offset as i64, len))
+            );
+            offset += len;
+        }
+        assert!(out.equals_missing(s));
+    }
+
+    #[test]
+    fn test_rechunk_to_chunks() {
+        // Many small chunks are coalesced.
+        let mut s = Series::new(PlSmallStr::from_static("a"), &[0i32]);
+        for i in 1..100 {
+            s.append(&Series::new(PlSmallStr::from_static("a"), &[Some(i), None]))
+                .unwrap();
+        }
+        check_rechunk_to_chunks(&s, 4, &[49, 49, 49, 52]);
+
+        // A large chunk is split without copying.
+        let s = Series::new(PlSmallStr::from_static("a"), (0..10).collect::<Vec<i32>>());
+        check_rechunk_to_chunks(&s, 3, &[3, 3, 4]);
+        let out = s.rechunk_to_chunks(3);
+        let ptr = |s: &Series| {
+            s.i32()
+                .unwrap()
+                .downcast_iter()
+                .next()
+                .unwrap()
+                .values()
+                .as_ptr()
+        };
+        assert_eq!(ptr(&out), ptr(&s));
+
+        // There are no more chunks than values.
+        check_rechunk_to_chunks(&s.slice(0, 2), 4, &[1, 1]);
+        check_rechunk_to_chunks(&s.clear(), 4, &[0]);
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-array")]
+    fn test_rechunk_to_chunks_array() {
+        let values = Int32Chunked::from_vec(PlSmallStr::EMPTY, (0..24).collect());
+        let arr = ArrayChunked::from_aligned_values(
+            PlSmallStr::from_static("a"),
+            &DataType::Int32,
+            2,
+            values.chunks().clone(),
+            12,
+        )
+        .into_series();
+
+        // The first chunk is sliced, so its values start at an offset.
+        let mut s = arr.slice(1, 10);
+        s.append(&arr.slice(0, 3)).unwrap();
+        check_rechunk_to_chunks(&s, 3, &[4, 4, 5]);
+        check_rechunk_to_chunks(&s, 2, &[6, 7]);
+    }
+
+    #[test]
+    fn test_rechunk_to_chunks_list_and_frame() {
+        let lists = (0..6)
+            .map(|i| Series::new(PlSmallStr::EMPTY, (0..i).collect::<Vec<i32>>()))
+            .collect::<Vec<_>>();
+        let mut s = Series::new(PlSmallStr::from_static("a"), &lists[..4]).slice(1, 3);
+        s.append(&Series::new(PlSmallStr::from_static("a"), &lists[2..]))
+            .unwrap();
+        check_rechunk_to_chunks(&s, 3, &[2, 2, 3]);
+
+        // The chunks of all columns are aligned.
+        let b = Series::new(PlSmallStr::from_static("b"), (0..7).collect::<Vec<i32>>());
+        let df = DataFrame::new(vec![s.into_column(), b.into_column()]).unwrap();
+        let out = df.rechunk_to_chunks(3);
+        assert!(!out.should_rechunk());
+        assert_eq!(out.first_col_n_chunks(), 3);
+        assert!(out.equals_missing(&df));
+    }
 }
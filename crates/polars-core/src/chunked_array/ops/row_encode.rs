@@ -9,29 +9,25 @@ use crate::prelude::*;
 use crate::utils::_split_offsets;
 
 pub fn encode_rows_vertical_par_unordered(by: &[Column]) -> PolarsResult<BinaryOffsetChunked> {
-    let n_threads = POOL.current_num_threads();
-    let len = by[0].len();
-    let splits = _split_offsets(len, n_threads);
-
-    let chunks = splits.into_par_iter().map(|(offset, len)| {
-        let sliced = by
-            .iter()
-            .map(|s| s.slice(offset as i64, len))
-            .collect::<Vec<_>>();
-        let rows = _get_rows_encoded_unordered(&sliced)?;
-        Ok(rows.into_array())
-    });
-    let chunks = POOL.install(|| chunks.collect::<PolarsResult<Vec<_>>>());
-
-    Ok(BinaryOffsetChunked::from_chunk_iter(
-        PlSmallStr::EMPTY,
-        chunks?,
-    ))
+    encode_rows_vertical_par_unordered_with_contexts(by, None, false)
 }
 
 // Almost the same but broadcast nulls to the row-encoded array.
 pub fn encode_rows_vertical_par_unordered_broadcast_nulls(
     by: &[Column],
+) -> PolarsResult<BinaryOffsetChunked> {
+    encode_rows_vertical_par_unordered_with_contexts(by, None, true)
+}
+
+/// Row encode `by` unordered in parallel.
+///
+/// `ctxts` overrides the [`RowEncodingContext`] of every column, which is otherwise derived from
+/// its dtype, see [`get_row_encoding_context`]. If `broadcast_nulls` is set, a row is null if any
+/// of its values is null.
+pub fn encode_rows_vertical_par_unordered_with_contexts(
+    by: &[Column],
+    ctxts: Option<&[Option<RowEncodingContext>]>,
+    broadcast_nulls: bool,
 ) -> PolarsResult<BinaryOffsetChunked> {
     let n_threads = POOL.current_num_threads();
     let len = by[0].len();
@@ -42,7 +38,10 @@ pub fn encode_rows_vertical_par_unordered_broadcast_nulls(
             .iter()
             .map(|s| s.slice(offset as i64, len))
             .collect::<Vec<_>>();
-        let rows = _get_rows_encoded_unordered(&sliced)?;
+        let rows = _get_rows_encoded_unordered_with_contexts(&sliced, ctxts)?;
+        if !broadcast_nulls {
+            return Ok(rows.into_array());
+        }
 
         let validities = sliced
             .iter()
@@ -104,6 +103,7 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
             Some(RowEncodingContext::Categorical(
                 RowEncodingCategoricalContext {
                     is_enum: matches!(dtype, DataType::Enum(_, _)),
+                    encode_strings: false,
                     mapping: mapping.clone(),
                 },
             ))
@@ -118,8 +118,10 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         DataType::Decimal(precision, _) => Some(RowEncodingContext::Decimal(*precision)),
 
         #[cfg(feature = "dtype-array")]
-        DataType::Array(dtype, _) => get_row_encoding_context(dtype),
-        DataType::List(dtype) => get_row_encoding_context(dtype),
+        DataType::Array(dtype, _) => get_row_encoding_context(dtype)
+            .map(|ctxt| RowEncodingContext::List(Box::new(Some(ctxt)))),
+        DataType::List(dtype) => get_row_encoding_context(dtype)
+            .map(|ctxt| RowEncodingContext::List(Box::new(Some(ctxt)))),
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(fs) => {
             let mut ctxts = Vec::new();
@@ -150,6 +152,32 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
     }
 }
 
+/// Get the [`RowEncodingContext`] for a key that is compared with keys of [`DataType::String`],
+/// e.g. a `Categorical` key in a join with a `String` key.
+///
+/// Categoricals are encoded as their strings, which gives the same unordered rows as the same
+/// strings in a `String` column, without casting the categorical.
+pub fn get_row_encoding_context_as_string(dtype: &DataType) -> Option<RowEncodingContext> {
+    fn encode_strings(ctxt: &mut RowEncodingContext) {
+        match ctxt {
+            RowEncodingContext::Categorical(ctxt) => ctxt.encode_strings = true,
+            // Lists of categoricals are compared with lists of strings.
+            RowEncodingContext::List(values) => {
+                if let Some(values) = values.as_mut() {
+                    encode_strings(values);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    let mut ctxt = get_row_encoding_context(dtype);
+    if let Some(ctxt) = &mut ctxt {
+        encode_strings(ctxt);
+    }
+    ctxt
+}
+
 pub fn encode_rows_unordered(by: &[Column]) -> PolarsResult<BinaryOffsetChunked> {
     let rows = _get_rows_encoded_unordered(by)?;
     Ok(BinaryOffsetChunked::with_chunk(
@@ -159,6 +187,17 @@ pub fn encode_rows_unordered(by: &[Column]) -> PolarsResult<BinaryOffsetChunked>
 }
 
 pub fn _get_rows_encoded_unordered(by: &[Column]) -> PolarsResult<RowsEncoded> {
+    _get_rows_encoded_unordered_with_contexts(by, None)
+}
+
+/// Row encode `by` unordered, with the [`RowEncodingContext`]s in `ctxts_override` instead of the
+/// ones derived from the dtypes if given.
+pub fn _get_rows_encoded_unordered_with_contexts(
+    by: &[Column],
+    ctxts_override: Option<&[Option<RowEncodingContext>]>,
+) -> PolarsResult<RowsEncoded> {
+    debug_assert!(ctxts_override.is_none_or(|ctxts| ctxts.len() == by.len()));
+
     let mut cols = Vec::with_capacity(by.len());
     let mut opts = Vec::with_capacity(by.len());
     let mut ctxts = Vec::with_capacity(by.len());
@@ -167,7 +206,7 @@ pub fn _get_rows_encoded_unordered(by: &[Column]) -> PolarsResult<RowsEncoded> {
     // columns.
     let num_rows = by.first().map_or(0, |c| c.len());
 
-    for by in by {
+    for (i, by) in by.iter().enumerate() {
         debug_assert_eq!(by.len(), num_rows);
 
         let by = by
@@ -177,7 +216,10 @@ pub fn _get_rows_encoded_unordered(by: &[Column]) -> PolarsResult<RowsEncoded> {
         let by = by.as_materialized_series();
         let arr = by.to_physical_repr().rechunk().chunks()[0].to_boxed();
         let opt = RowEncodingOptions::new_unsorted();
-        let ctxt = get_row_encoding_context(by.dtype());
+        let ctxt = match ctxts_override {
+            None => get_row_encoding_context(by.dtype()),
+            Some(ctxts) => ctxts[i].clone(),
+        };
 
         cols.push(arr);
         opts.push(opt);
@@ -221,6 +263,52 @@ pub fn _get_rows_encoded(
     Ok(convert_columns(num_rows, &cols, &opts, &ctxts))
 }
 
+/// Row encode a single struct column where each field has its own sort order.
+///
+/// This sorts the same as sorting by the fields as separate columns, but keeps the struct as one
+/// encoded key.
+#[cfg(feature = "dtype-struct")]
+pub fn _get_rows_encoded_struct_fields(
+    by: &Column,
+    descending: &[bool],
+    nulls_last: &[bool],
+) -> PolarsResult<RowsEncoded> {
+    let DataType::Struct(fields) = by.dtype() else {
+        polars_bail!(InvalidOperation: "expected a struct column, got {}", by.dtype());
+    };
+    polars_ensure!(
+        descending.len() == fields.len() && nulls_last.len() == fields.len(),
+        ShapeMismatch: "expected sort options for {} struct fields, got {} and {}",
+        fields.len(), descending.len(), nulls_last.len()
+    );
+
+    let ctxt = RowEncodingContext::StructWithOptions(
+        fields
+            .iter()
+            .zip(descending.iter().zip(nulls_last))
+            .map(|(f, (&desc, &null_last))| {
+                (
+                    RowEncodingOptions::new_sorted(desc, null_last),
+                    get_row_encoding_context(f.dtype()),
+                )
+            })
+            .collect(),
+    );
+
+    let by = by
+        .trim_lists_to_normalized_offsets()
+        .map_or(Cow::Borrowed(by), Cow::Owned);
+    let by = by.propagate_nulls().map_or(by, Cow::Owned);
+    let by = by.as_materialized_series();
+    let arr = by.to_physical_repr().rechunk().chunks()[0].to_boxed();
+    Ok(convert_columns(
+        by.len(),
+        &[arr],
+        &[RowEncodingOptions::default()],
+        &[Some(ctxt)],
+    ))
+}
+
 pub fn _get_rows_encoded_ca(
     name: PlSmallStr,
     by: &[Column],
@@ -297,3 +385,295 @@ pub fn row_encoding_decode(
         )
     })
 }
+
+/// Decode the first field of an encoded `row` into an [`AnyValue`] without decoding into arrays.
+///
+/// Only the fixed-size numeric, boolean, temporal and decimal types and strings are supported.
+///
+/// # Safety
+/// `row` must be a valid row encoding whose first field has `dtype`, `opt` and `dict`.
+pub unsafe fn decode_first_field_scalar(
+    row: &[u8],
+    dtype: &DataType,
+    opt: RowEncodingOptions,
+    dict: Option<&RowEncodingContext>,
+) -> PolarsResult<AnyValue<'static>> {
+    use polars_row::decode::{decode_first_bool, decode_first_primitive, decode_first_str};
+
+    #[cfg(not(feature = "dtype-decimal"))]
+    let _ = dict;
+
+    macro_rules! primitive {
+        ($to_av:expr) => {
+            unsafe { decode_first_primitive(row, opt) }.map_or(AnyValue::Null, $to_av)
+        };
+    }
+
+    Ok(match dtype {
+        DataType::Null => AnyValue::Null,
+        DataType::Boolean => {
+            unsafe { decode_first_bool(row, opt) }.map_or(AnyValue::Null, AnyValue::Boolean)
+        },
+        DataType::UInt8 => primitive!(AnyValue::UInt8),
+        DataType::UInt16 => primitive!(AnyValue::UInt16),
+        DataType::UInt32 => primitive!(AnyValue::UInt32),
+        DataType::UInt64 => primitive!(AnyValue::UInt64),
+        DataType::UInt128 => primitive!(AnyValue::UInt128),
+        DataType::Int8 => primitive!(AnyValue::Int8),
+        DataType::Int16 => primitive!(AnyValue::Int16),
+        DataType::Int32 => primitive!(AnyValue::Int32),
+        DataType::Int64 => primitive!(AnyValue::Int64),
+        DataType::Int128 => primitive!(AnyValue::Int128),
+        DataType::Float16 => primitive!(AnyValue::Float16),
+        DataType::Float32 => primitive!(AnyValue::Float32),
+        DataType::Float64 => primitive!(AnyValue::Float64),
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => primitive!(AnyValue::Date),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(tu, tz) => {
+            primitive!(|v| AnyValue::DatetimeOwned(v, *tu, tz.clone().map(Arc::new)))
+        },
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(tu) => primitive!(|v| AnyValue::Duration(v, *tu)),
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => primitive!(AnyValue::Time),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(precision, scale) => {
+            debug_assert!(matches!(dict, Some(RowEncodingContext::Decimal(_))));
+            unsafe { polars_row::decode::decode_first_decimal(row, opt, *precision) }
+                .map_or(AnyValue::Null, |v| AnyValue::Decimal(v, *precision, *scale))
+        },
+        DataType::String => unsafe { decode_first_str(row, opt) }
+            .map_or(AnyValue::Null, |v| AnyValue::StringOwned(v.into())),
+        dt => polars_bail!(
+            InvalidOperation: "cannot decode a scalar of type '{dt}' from the row encoding"
+        ),
+    })
+}
+
+/// Decode the first field of the first and last row of `rows` in the encoded sort order.
+///
+/// Returns `None` if there are no rows.
+pub fn decode_first_field_min_max(
+    rows: &RowsEncoded,
+    dtype: &DataType,
+    opt: RowEncodingOptions,
+    dict: Option<&RowEncodingContext>,
+) -> PolarsResult<Option<(AnyValue<'static>, AnyValue<'static>)>> {
+    polars_ensure!(
+        opt.is_ordered(),
+        InvalidOperation: "cannot take the min and max of an unordered row encoding"
+    );
+
+    let mut iter = rows.iter();
+    let Some(first) = iter.next() else {
+        return Ok(None);
+    };
+    let (min, max) = iter.fold((first, first), |(min, max), row| {
+        (min.min(row), max.max(row))
+    });
+
+    // SAFETY: the rows come from a valid row encoding.
+    unsafe {
+        Ok(Some((
+            decode_first_field_scalar(min, dtype, opt, dict)?,
+            decode_first_field_scalar(max, dtype, opt, dict)?,
+        )))
+    }
+}
+
+#[cfg(all(test, feature = "dtype-struct"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rows_encoded_struct_fields() -> PolarsResult<()> {
+        let a = Column::new(
+            "a".into(),
+            [Some("a"), Some("b"), Some("b"), None, Some("a")],
+        );
+        let b = Column::new("b".into(), [Some(2), Some(1), Some(3), Some(1), None]);
+        let s = StructChunked::from_columns("s".into(), 5, &[a.clone(), b.clone()])?.into_column();
+
+        let descending = [true, false];
+        let nulls_last = [false, true];
+        let rows = _get_rows_encoded_struct_fields(&s, &descending, &nulls_last)?;
+        let rows = rows.iter().collect::<Vec<_>>();
+        let mut idxs = (0..5).collect::<Vec<_>>();
+        idxs.sort_by_key(|&i| rows[i]);
+
+        // Must be the same order as sorting by the fields as separate columns.
+        let expected = _get_rows_encoded(&[a, b], &descending, &nulls_last)?;
+        let expected = expected.iter().collect::<Vec<_>>();
+        let mut expected_idxs = (0..5).collect::<Vec<_>>();
+        expected_idxs.sort_by_key(|&i| expected[i]);
+        assert_eq!(idxs, expected_idxs);
+        assert_eq!(idxs, [3, 1, 2, 0, 4]);
+
+        assert!(_get_rows_encoded_struct_fields(&s, &[true], &[false]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_first_field_scalar() -> PolarsResult<()> {
+        let ints = Column::new(
+            "x".into(),
+            [Some(3i64), None, Some(-7), Some(0), Some(120), None],
+        );
+        let mut columns = vec![
+            Column::new(
+                "x".into(),
+                [Some(true), None, Some(false), Some(true), None, Some(false)],
+            ),
+            Column::new(
+                "x".into(),
+                [
+                    Some("b"),
+                    None,
+                    Some(""),
+                    Some("a longer string"),
+                    Some("b"),
+                    None,
+                ],
+            ),
+            Column::full_null("x".into(), 6, &DataType::Null),
+        ];
+        let mut dtypes = vec![
+            DataType::Int32,
+            DataType::Int64,
+            DataType::UInt32,
+            DataType::UInt64,
+            DataType::Float32,
+            DataType::Float64,
+        ];
+        #[cfg(feature = "dtype-i8")]
+        dtypes.push(DataType::Int8);
+        #[cfg(feature = "dtype-i16")]
+        dtypes.push(DataType::Int16);
+        #[cfg(feature = "dtype-u8")]
+        dtypes.push(DataType::UInt8);
+        #[cfg(feature = "dtype-u16")]
+        dtypes.push(DataType::UInt16);
+        #[cfg(feature = "dtype-date")]
+        dtypes.push(DataType::Date);
+        #[cfg(feature = "dtype-datetime")]
+        dtypes.push(DataType::Datetime(TimeUnit::Microseconds, None));
+        #[cfg(feature = "dtype-duration")]
+        dtypes.push(DataType::Duration(TimeUnit::Milliseconds));
+        #[cfg(feature = "dtype-time")]
+        dtypes.push(DataType::Time);
+        #[cfg(feature = "dtype-decimal")]
+        dtypes.extend([DataType::Decimal(10, 2), DataType::Decimal(38, 2)]);
+        for dtype in &dtypes {
+            columns.push(ints.cast(dtype)?);
+        }
+
+        // Another column after the first field must not influence the decoded value.
+        let other = Column::new("y".into(), ["z"; 6]);
+        let opts = [
+            RowEncodingOptions::new_sorted(false, false),
+            RowEncodingOptions::new_sorted(false, true),
+            RowEncodingOptions::new_sorted(true, false),
+            RowEncodingOptions::new_sorted(true, true),
+            RowEncodingOptions::new_unsorted(),
+        ];
+        for column in &columns {
+            let dtype = column.dtype();
+            let dict = get_row_encoding_context(dtype);
+            let fields = [column.field().into_owned(), other.field().into_owned()];
+            for opt in opts {
+                let rows = if opt.is_ordered() {
+                    let descending = opt.contains(RowEncodingOptions::DESCENDING);
+                    let nulls_last = opt.contains(RowEncodingOptions::NULLS_LAST);
+                    _get_rows_encoded(
+                        &[column.clone(), other.clone()],
+                        &[descending, false],
+                        &[nulls_last, false],
+                    )?
+                } else {
+                    _get_rows_encoded_unordered(&[column.clone(), other.clone()])?
+                };
+
+                let encoded =
+                    BinaryOffsetChunked::with_chunk("".into(), unsafe { rows.borrow_array() });
+                let other_opt = if opt.is_ordered() {
+                    RowEncodingOptions::default()
+                } else {
+                    opt
+                };
+                let decoded = row_encoding_decode(&encoded, &fields, &[opt, other_opt])?;
+                let decoded = &decoded.fields_as_series()[0];
+
+                for (i, row) in rows.iter().enumerate() {
+                    let value =
+                        unsafe { decode_first_field_scalar(row, dtype, opt, dict.as_ref())? };
+                    assert_eq!(
+                        value,
+                        decoded.get(i)?.into_static(),
+                        "{dtype} {opt:?} row {i}"
+                    );
+                }
+
+                if !opt.is_ordered() {
+                    assert!(decode_first_field_min_max(&rows, dtype, opt, dict.as_ref()).is_err());
+                    continue;
+                }
+                let (min, max) =
+                    decode_first_field_min_max(&rows, dtype, opt, dict.as_ref())?.unwrap();
+                let mut sorted = rows.iter().collect::<Vec<_>>();
+                sorted.sort();
+                let first =
+                    unsafe { decode_first_field_scalar(sorted[0], dtype, opt, dict.as_ref())? };
+                let last =
+                    unsafe { decode_first_field_scalar(sorted[5], dtype, opt, dict.as_ref())? };
+                assert_eq!((min, max), (first, last), "{dtype} {opt:?}");
+            }
+        }
+
+        let list = Column::new("x".into(), [Series::new("".into(), [1i32])]);
+        let rows = _get_rows_encoded(std::slice::from_ref(&list), &[false], &[false])?;
+        let row = rows.iter().next().unwrap();
+        let opt = RowEncodingOptions::default();
+        assert!(unsafe { decode_first_field_scalar(row, list.dtype(), opt, None) }.is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_rows_encoded_list_of_struct_with_enum() -> PolarsResult<()> {
+        // The categories are not in lexical order, so the rows must be ordered by the enum.
+        let enum_dtype = DataType::from_frozen_categories(FrozenCategories::new(["z", "a"])?);
+        let structs = |cats: &[&str], ints: &[i32]| -> PolarsResult<Series> {
+            let c = Column::new("c".into(), cats).cast(&enum_dtype)?;
+            let i = Column::new("i".into(), ints);
+            Ok(StructChunked::from_columns("".into(), cats.len(), &[c, i])?.into_series())
+        };
+        let list = Column::new(
+            "l".into(),
+            [
+                structs(&["a"], &[1])?,
+                structs(&["z", "a"], &[2, 0])?,
+                structs(&["z"], &[3])?,
+                structs(&["a", "z"], &[0, 0])?,
+            ],
+        );
+        assert!(matches!(
+            get_row_encoding_context(list.dtype()),
+            Some(RowEncodingContext::List(_))
+        ));
+
+        let rows = _get_rows_encoded(std::slice::from_ref(&list), &[false], &[false])?;
+        let sorted_rows = rows.iter().collect::<Vec<_>>();
+        let mut idxs = (0..4).collect::<Vec<_>>();
+        idxs.sort_by_key(|&i| sorted_rows[i]);
+        assert_eq!(idxs, [1, 2, 3, 0]);
+
+        let encoded = BinaryOffsetChunked::with_chunk("".into(), rows.into_array());
+        let opts = [RowEncodingOptions::new_sorted(false, false)];
+        let decoded = row_encoding_decode(&encoded, &[list.field().into_owned()], &opts)?;
+        let decoded = &decoded.fields_as_series()[0];
+        assert_eq!(decoded.dtype(), list.dtype());
+        assert!(decoded.equals_missing(list.as_materialized_series()));
+        Ok(())
+    }
+}
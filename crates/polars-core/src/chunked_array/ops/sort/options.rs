@@ -100,6 +100,12 @@ pub struct SortMultipleOptions {
     pub multithreaded: bool,
     /// Whether maintain the order of equal elements. Default `false`.
     pub maintain_order: bool,
+    /// Whether to break ties between equal elements on their row index. Default `false`.
+    ///
+    /// This gives the same result as `maintain_order`, but adds the row index as an extra
+    /// sort key instead of using a stable sort. This is ignored if `maintain_order` is set.
+    #[cfg_attr(feature = "serde-lazy", serde(default))]
+    pub deterministic: bool,
     /// Limit a sort output, this is for optimization purposes and might be ignored.
     pub limit: Option<IdxSize>,
 }
@@ -123,6 +129,7 @@ impl Default for SortMultipleOptions {
             nulls_last: vec![false],
             multithreaded: true,
             maintain_order: false,
+            deterministic: false,
             limit: None,
         }
     }
@@ -181,6 +188,12 @@ impl SortMultipleOptions {
         self
     }
 
+    /// Whether to break ties between equal elements on their row index. Default `false`.
+    pub fn with_deterministic(mut self, enabled: bool) -> Self {
+        self.deterministic = enabled;
+        self
+    }
+
     /// Reverse the order of sorting for each column.
     pub fn with_order_reversed(mut self) -> Self {
         self.descending.iter_mut().for_each(|x| *x = !*x);
@@ -232,6 +245,7 @@ impl From<&SortOptions> for SortMultipleOptions {
             nulls_last: vec![value.nulls_last],
             multithreaded: value.multithreaded,
             maintain_order: value.maintain_order,
+            deterministic: false,
             limit: value.limit,
         }
     }
@@ -243,7 +257,8 @@ impl From<&SortMultipleOptions> for SortOptions {
             descending: value.descending.first().copied().unwrap_or(false),
             nulls_last: value.nulls_last.first().copied().unwrap_or(false),
             multithreaded: value.multithreaded,
-            maintain_order: value.maintain_order,
+            // A stable sort breaks ties the same way as the row index would.
+            maintain_order: value.maintain_order || value.deterministic,
             limit: value.limit,
         }
     }
@@ -12,6 +12,19 @@ use crate::utils::{align_chunks_binary, align_chunks_ternary};
 const SHAPE_MISMATCH_STR: &str =
     "shapes of `self`, `mask` and `other` are not suitable for `zip_with` operation";
 
+/// Arrays can only be zipped if their rows have the same width.
+#[cfg(feature = "dtype-array")]
+pub(crate) fn ensure_same_array_width(left: &DataType, right: &DataType) -> PolarsResult<()> {
+    if let (DataType::Array(_, left_width), DataType::Array(_, right_width)) = (left, right) {
+        polars_ensure!(
+            left_width == right_width,
+            ShapeMismatch: "cannot `zip_with` arrays of different widths: {} and {}",
+            left_width, right_width
+        );
+    }
+    Ok(())
+}
+
 fn if_then_else_broadcast_mask<T: PolarsDataType>(
     mask: bool,
     if_true: &ChunkedArray<T>,
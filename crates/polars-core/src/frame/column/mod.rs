@@ -1177,6 +1177,15 @@ impl Column {
         }
     }
 
+    /// Reorganizes the chunks into `n` chunks of about equal length, see
+    /// [`Series::rechunk_to_chunks`]. Scalar columns aren't chunked and are returned as is.
+    pub fn rechunk_to_chunks(&self, n: usize) -> Column {
+        match self {
+            Column::Series(s) => s.rechunk_to_chunks(n).into(),
+            Column::Scalar(_) => self.clone(),
+        }
+    }
+
     pub fn explode(&self, options: ExplodeOptions) -> PolarsResult<Column> {
         self.as_materialized_series()
             .explode(options)
@@ -55,6 +55,52 @@ impl Series {
         }
     }
 
+    /// Gather the values of every group into an array of `width` values, in the order of the rows
+    /// within the group.
+    ///
+    /// Errors if a group doesn't have exactly `width` values, which is checked before any values
+    /// are gathered.
+    #[cfg(feature = "dtype-array")]
+    #[doc(hidden)]
+    pub unsafe fn agg_implode_to_array(
+        &self,
+        groups: &GroupsType,
+        width: usize,
+    ) -> PolarsResult<ArrayChunked> {
+        if let Some((i, g)) = groups.iter().enumerate().find(|(_, g)| g.len() != width) {
+            polars_bail!(
+                ShapeMismatch: "cannot implode group {} of {} values at row {} into an array of width {}",
+                i, g.len(), g.first(), width
+            );
+        }
+
+        let values = match groups {
+            GroupsType::Slice { groups, .. }
+                if groups
+                    .iter()
+                    .enumerate()
+                    .all(|(i, [first, _])| *first as usize == i * width) =>
+            {
+                // The groups are consecutive slices, so their values are already in order.
+                self.slice(0, groups.len() * width)
+            },
+            _ => {
+                let mut idx = Vec::with_capacity(groups.len() * width);
+                match groups {
+                    GroupsType::Idx(groups) => {
+                        groups.all().iter().for_each(|g| idx.extend_from_slice(g))
+                    },
+                    GroupsType::Slice { groups, .. } => groups
+                        .iter()
+                        .for_each(|&[first, len]| idx.extend(first..first + len)),
+                }
+                // SAFETY: groups are always in bounds.
+                unsafe { self.take_slice_unchecked(&idx) }
+            },
+        };
+        values.to_array_rows(width, groups.len())
+    }
+
     #[doc(hidden)]
     pub unsafe fn agg_first(&self, groups: &GroupsType) -> Series {
         // Prevent a rechunk for every individual group.
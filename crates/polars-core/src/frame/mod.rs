@@ -628,6 +628,14 @@ impl DataFrame {
         }
     }
 
+    /// Reorganizes the chunks of all columns into `n` aligned chunks of about equal height, see
+    /// [`Series::rechunk_to_chunks`].
+    pub fn rechunk_to_chunks(&self, n: usize) -> Self {
+        let columns = self._apply_columns_par(&|c| c.rechunk_to_chunks(n));
+        // SAFETY: We never adjust the length or names of the columns.
+        unsafe { DataFrame::new_no_checks(self.height(), columns) }
+    }
+
     pub fn _deshare_views_mut(&mut self) {
         // SAFETY: We never adjust the length or names of the columns.
         unsafe {
@@ -2267,8 +2275,8 @@ impl DataFrame {
     /// This is the dispatch of Self::sort, and exists to reduce compile bloat by monomorphization.
     pub fn sort_impl(
         &self,
-        by_column: Vec<Column>,
-        sort_options: SortMultipleOptions,
+        mut by_column: Vec<Column>,
+        mut sort_options: SortMultipleOptions,
         slice: Option<(i64, usize)>,
     ) -> PolarsResult<Self> {
         if by_column.is_empty() {
@@ -2306,6 +2314,25 @@ impl DataFrame {
             return Ok(out);
         }
 
+        // Break ties on the row index, so the unstable sort gives the same result as a stable
+        // sort.
+        if sort_options.deterministic && !sort_options.maintain_order {
+            let n = by_column.len();
+            if sort_options.descending.len() == 1 {
+                sort_options.descending = vec![sort_options.descending[0]; n];
+            }
+            if sort_options.nulls_last.len() == 1 {
+                sort_options.nulls_last = vec![sort_options.nulls_last[0]; n];
+            }
+            by_column.push(Column::new_row_index(
+                PlSmallStr::from_static("__POLARS_SORT_ROW_INDEX"),
+                0,
+                self.height(),
+            )?);
+            sort_options.descending.push(false);
+            sort_options.nulls_last.push(false);
+        }
+
         if let Some((0, k)) = slice {
             if k < self.len() {
                 return self.bottom_k_impl(k, by_column, sort_options);
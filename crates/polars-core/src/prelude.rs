@@ -20,6 +20,8 @@ pub use crate::chunked_array::ChunkedArray;
 #[cfg(feature = "dtype-struct")]
 pub use crate::chunked_array::StructChunked;
 pub use crate::chunked_array::arithmetic::ArithmeticChunked;
+#[cfg(feature = "dtype-array")]
+pub use crate::chunked_array::builder::ArrayStreamingBuilder;
 pub use crate::chunked_array::builder::{
     BinaryChunkedBuilder, BooleanChunkedBuilder, ChunkedBuilder, ListBinaryChunkedBuilder,
     ListBooleanChunkedBuilder, ListBuilderTrait, ListPrimitiveChunkedBuilder,
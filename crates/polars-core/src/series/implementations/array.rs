@@ -59,6 +59,7 @@ impl private::PrivateSeries for SeriesWrap<ArrayChunked> {
 
     #[cfg(feature = "zip_with")]
     fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        crate::chunked_array::ops::zip::ensure_same_array_width(self.dtype(), other.dtype())?;
         ChunkZip::zip_with(&self.0, mask, other.as_ref().as_ref()).map(|ca| ca.into_series())
     }
 
@@ -47,6 +47,7 @@ pub use series_trait::{IsSorted, *};
 
 use crate::POOL;
 use crate::chunked_array::cast::CastOptions;
+use crate::chunked_array::ops::chunkops::rechunk_to_n;
 #[cfg(feature = "zip_with")]
 use crate::series::arithmetic::coerce_lhs_rhs;
 use crate::utils::{Wrap, handle_casting_failures, materialize_dyn_int};
@@ -251,6 +252,32 @@ impl Series {
         new
     }
 
+    /// Reorganizes the chunks into `n` chunks of about equal length, or fewer if the Series has
+    /// fewer than `n` values.
+    ///
+    /// Large chunks are sliced at the new chunk boundaries, and only the slices that together make
+    /// up a new chunk are copied into one. This balances the chunks before processing them in
+    /// parallel. Series of objects keep their chunks.
+    pub fn rechunk_to_chunks(&self, n: usize) -> Self {
+        if self.dtype().is_object() {
+            return self.clone();
+        }
+        let chunks = rechunk_to_n(self.chunks(), self.len(), n);
+
+        let mut new = self.clear();
+        let mut flags = self.get_flags();
+
+        use StatisticsFlags as F;
+        flags &= F::IS_SORTED_ANY | F::CAN_FAST_EXPLODE_LIST;
+
+        // Assign mut so we go through arc only once.
+        let mut_new = new._get_inner_mut();
+        *unsafe { mut_new.chunks_mut() } = chunks;
+        mut_new.compute_len();
+        mut_new._set_flags(flags);
+        new
+    }
+
     pub fn is_sorted_flag(&self) -> IsSorted {
         if self.len() <= 1 {
             return IsSorted::Ascending;
@@ -720,6 +747,8 @@ impl Series {
     /// length inputs.
     #[cfg(feature = "zip_with")]
     pub fn zip_with(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-array")]
+        crate::chunked_array::ops::zip::ensure_same_array_width(self.dtype(), other.dtype())?;
         let (lhs, rhs) = coerce_lhs_rhs(self, other)?;
         lhs.zip_with_same_type(mask, rhs.as_ref())
     }
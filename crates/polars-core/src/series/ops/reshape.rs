@@ -99,6 +99,48 @@ impl Series {
         Ok(ca)
     }
 
+    /// Convert the values of this Series to an ArrayChunked with a length of 1,
+    /// so a Series of `[1, 2, 3]` becomes `[[1, 2, 3]]` for a `width` of 3.
+    ///
+    /// Errors if the length of the Series isn't `width`.
+    #[cfg(feature = "dtype-array")]
+    pub fn implode_to_array(&self, width: usize) -> PolarsResult<ArrayChunked> {
+        polars_ensure!(
+            self.len() == width,
+            ShapeMismatch: "cannot implode {} values into an array of width {}",
+            self.len(), width
+        );
+        self.to_array_rows(width, 1)
+    }
+
+    /// Split the values of this Series into `length` arrays of `width` values, so a Series of
+    /// `[1, 2, 3, 4]` becomes `[[1, 2], [3, 4]]` for a `width` of 2 and a `length` of 2.
+    ///
+    /// Unlike [`Series::reshape_array`], nested values are kept as they are.
+    ///
+    /// Errors if the length of the Series isn't `width * length`.
+    #[cfg(feature = "dtype-array")]
+    pub fn to_array_rows(&self, width: usize, length: usize) -> PolarsResult<ArrayChunked> {
+        polars_ensure!(
+            self.len() == width * length,
+            ShapeMismatch: "cannot split {} values into {} arrays of width {}",
+            self.len(), length, width
+        );
+        let s = self.rechunk();
+        let values = s.array_ref(0).clone();
+        let arrow_dtype = values.dtype().clone().to_fixed_size_list(width, true);
+        let arr = FixedSizeListArray::new(arrow_dtype, length, values, None);
+
+        // SAFETY: the values have the physical type of the inner dtype.
+        Ok(unsafe {
+            ArrayChunked::from_chunks_and_dtype_unchecked(
+                s.name().clone(),
+                vec![arr.boxed()],
+                DataType::Array(Box::new(s.dtype().clone()), width),
+            )
+        })
+    }
+
     #[cfg(feature = "dtype-array")]
     pub fn reshape_array(&self, dimensions: &[ReshapeDimension]) -> PolarsResult<Series> {
         polars_ensure!(
@@ -356,4 +398,27 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "dtype-array")]
+    fn test_to_array_rows() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[1, 2, 3, 4]);
+        let out = s.to_array_rows(2, 2)?;
+        assert_eq!(out.dtype(), &DataType::Array(Box::new(DataType::Int32), 2));
+        assert!(out.get_as_series(1).unwrap().equals(&s.slice(2, 2)));
+        assert!(s.to_array_rows(3, 1).is_err());
+        assert!(s.implode_to_array(3).is_err());
+        assert_eq!(s.implode_to_array(4)?.len(), 1);
+
+        // Nested values are kept as they are.
+        let lists = Series::new("a".into(), &[s.clone(), s.slice(0, 1)]);
+        let out = lists.implode_to_array(2)?;
+        assert_eq!(out.inner_dtype(), lists.dtype());
+
+        // Arrays without values still have a length.
+        let out = s.clear().to_array_rows(0, 3)?;
+        assert_eq!(out.len(), 3);
+
+        Ok(())
+    }
 }
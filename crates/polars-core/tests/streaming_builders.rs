@@ -0,0 +1,115 @@
+//! Memory tests for the chunk-sealing builders.
+//!
+//! These live in their own test binary, as they install a counting global allocator.
+#![cfg(feature = "dtype-array")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use polars_core::prelude::*;
+
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+fn track_alloc(size: usize) {
+    let allocated = ALLOCATED.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(allocated, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            track_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            // Be conservative: count the old and new allocation as live at the same time.
+            track_alloc(new_size);
+            ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+        new_ptr
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the bytes retained by the output and the peak overhead on top of that.
+fn measure<F: FnOnce() -> R, R>(f: F) -> (R, usize, usize) {
+    let baseline = ALLOCATED.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+    let out = f();
+    let retained = ALLOCATED.load(Ordering::Relaxed) - baseline;
+    let peak = PEAK.load(Ordering::Relaxed) - baseline;
+    (out, retained, peak - retained)
+}
+
+#[test]
+fn test_streaming_builders_bounded_memory() {
+    const NUM_ROWS: usize = 10_000_000;
+    const WIDTH: usize = 2;
+    const TARGET_CHUNK_ROWS: usize = 1 << 14;
+    // Values of a single chunk, plus some slack for the list offsets and validity.
+    const CHUNK_BYTES: usize = TARGET_CHUNK_ROWS * (WIDTH * 4 + 8 + 1);
+
+    let (ca, retained, overhead) = measure(|| {
+        let mut builder = ArrayStreamingBuilder::<Int32Type>::new(
+            PlSmallStr::from_static("a"),
+            DataType::Int32,
+            WIDTH,
+            TARGET_CHUNK_ROWS,
+        )
+        .unwrap();
+        for i in 0..NUM_ROWS {
+            if i % 7 == 0 {
+                builder.push_null();
+            } else {
+                let v = i as i32;
+                builder.push_row(&[v, v + 1]).unwrap();
+            }
+        }
+        builder.finish()
+    });
+    assert_eq!(ca.len(), NUM_ROWS);
+    assert!(retained >= NUM_ROWS * WIDTH * 4);
+    assert!(
+        overhead <= 4 * CHUNK_BYTES,
+        "peak overhead of {overhead} bytes exceeds {} bytes",
+        4 * CHUNK_BYTES
+    );
+    drop(ca);
+
+    let (ca, _, overhead) = measure(|| {
+        let mut builder = ListPrimitiveChunkedBuilder::<Int32Type>::new(
+            PlSmallStr::from_static("a"),
+            TARGET_CHUNK_ROWS,
+            TARGET_CHUNK_ROWS * WIDTH,
+            DataType::Int32,
+        )
+        .with_target_chunk_rows(TARGET_CHUNK_ROWS)
+        .unwrap();
+        for i in 0..NUM_ROWS {
+            let v = i as i32;
+            builder.append_slice(&[v, v + 1]);
+        }
+        builder.finish()
+    });
+    assert_eq!(ca.len(), NUM_ROWS);
+    assert!(
+        overhead <= 4 * CHUNK_BYTES,
+        "peak overhead of {overhead} bytes exceeds {} bytes",
+        4 * CHUNK_BYTES
+    );
+}
@@ -1,6 +1,9 @@
+use polars_core::chunked_array::cast::CastOptions;
 use polars_core::error::{PolarsResult, polars_bail, polars_ensure, polars_err};
 use polars_core::prelude::{Column, DataType, ExplodeOptions, IntoColumn, SortOptions};
-use polars_ops::prelude::array::ArrayNameSpace;
+use polars_ops::prelude::array::{
+    ArrayNameSpace, SortedRowsOptions, array_scatter, array_set_at_idx_masked,
+};
 #[cfg(feature = "array_to_struct")]
 use polars_plan::dsl::DslNameGenerator;
 use polars_plan::dsl::{ColumnsUdf, SpecialEq};
@@ -24,6 +27,18 @@ pub fn function_expr_to_udf(func: IRArrayFunction) -> SpecialEq<Arc<dyn ColumnsU
         Var(ddof) => map!(var, ddof),
         Mean => map!(mean),
         Median => map!(median),
+        LeafSum => map!(leaf_sum),
+        LeafMean => map!(leaf_mean),
+        LeafMin => map!(leaf_min),
+        LeafMax => map!(leaf_max),
+        #[cfg(feature = "cum_agg")]
+        CumSum { reverse } => map!(cum_sum, reverse),
+        #[cfg(feature = "cum_agg")]
+        CumProd { reverse } => map!(cum_prod, reverse),
+        #[cfg(feature = "cum_agg")]
+        CumMin { reverse } => map!(cum_min, reverse),
+        #[cfg(feature = "cum_agg")]
+        CumMax { reverse } => map!(cum_max, reverse),
         #[cfg(feature = "array_any_all")]
         Any => map!(any),
         #[cfg(feature = "array_any_all")]
@@ -33,16 +48,23 @@ pub fn function_expr_to_udf(func: IRArrayFunction) -> SpecialEq<Arc<dyn ColumnsU
         ArgMin => map!(arg_min),
         ArgMax => map!(arg_max),
         Get(null_on_oob) => map_as_slice!(get, null_on_oob),
+        Scatter(skip_oob) => map_as_slice!(scatter, skip_oob),
+        SetAtIdxMasked => map_as_slice!(set_at_idx_masked),
         Join(ignore_nulls) => map_as_slice!(join, ignore_nulls),
         #[cfg(feature = "is_in")]
-        Contains { nulls_equal } => map_as_slice!(contains, nulls_equal),
+        Contains {
+            nulls_equal,
+            sorted_rows,
+        } => map_as_slice!(contains, nulls_equal, sorted_rows),
         #[cfg(feature = "array_count")]
-        CountMatches => map_as_slice!(count_matches),
+        CountMatches(sorted_rows) => map_as_slice!(count_matches, sorted_rows),
+        RowsAreSorted { descending } => map!(rows_are_sorted, descending),
         Shift => map_as_slice!(shift),
         Explode(options) => map_as_slice!(explode, options),
         Slice(offset, length) => map!(slice, offset, length),
         #[cfg(feature = "array_to_struct")]
         ToStruct(ng) => map!(arr_to_struct, ng.clone()),
+        CastInner { dtype, options } => map!(cast_inner, &dtype, options),
     }
 }
 
@@ -97,6 +119,42 @@ pub(super) fn median(s: &Column) -> PolarsResult<Column> {
     s.array()?.array_median().map(Column::from)
 }
 
+pub(super) fn leaf_sum(s: &Column) -> PolarsResult<Column> {
+    s.array()?.array_leaf_sum().map(Column::from)
+}
+
+pub(super) fn leaf_mean(s: &Column) -> PolarsResult<Column> {
+    s.array()?.array_leaf_mean().map(Column::from)
+}
+
+pub(super) fn leaf_min(s: &Column) -> PolarsResult<Column> {
+    s.array()?.array_leaf_min().map(Column::from)
+}
+
+pub(super) fn leaf_max(s: &Column) -> PolarsResult<Column> {
+    s.array()?.array_leaf_max().map(Column::from)
+}
+
+#[cfg(feature = "cum_agg")]
+pub(super) fn cum_sum(s: &Column, reverse: bool) -> PolarsResult<Column> {
+    Ok(s.array()?.array_cum_sum(reverse)?.into_column())
+}
+
+#[cfg(feature = "cum_agg")]
+pub(super) fn cum_prod(s: &Column, reverse: bool) -> PolarsResult<Column> {
+    Ok(s.array()?.array_cum_prod(reverse)?.into_column())
+}
+
+#[cfg(feature = "cum_agg")]
+pub(super) fn cum_min(s: &Column, reverse: bool) -> PolarsResult<Column> {
+    Ok(s.array()?.array_cum_min(reverse)?.into_column())
+}
+
+#[cfg(feature = "cum_agg")]
+pub(super) fn cum_max(s: &Column, reverse: bool) -> PolarsResult<Column> {
+    Ok(s.array()?.array_cum_max(reverse)?.into_column())
+}
+
 pub(super) fn unique(s: &Column, stable: bool) -> PolarsResult<Column> {
     let ca = s.array()?;
     let out = if stable {
@@ -145,6 +203,18 @@ pub(super) fn arg_max(s: &Column) -> PolarsResult<Column> {
     Ok(s.array()?.array_arg_max().into_column())
 }
 
+pub(super) fn rows_are_sorted(s: &Column, descending: bool) -> PolarsResult<Column> {
+    Ok(s.array()?.array_rows_are_sorted(descending)?.into_column())
+}
+
+pub(super) fn cast_inner(
+    s: &Column,
+    dtype: &DataType,
+    options: CastOptions,
+) -> PolarsResult<Column> {
+    Ok(s.array()?.cast_inner(dtype, options)?.into_column())
+}
+
 pub(super) fn get(s: &[Column], null_on_oob: bool) -> PolarsResult<Column> {
     let ca = s[0].array()?;
     let index = s[1].cast(&DataType::Int64)?;
@@ -152,6 +222,26 @@ pub(super) fn get(s: &[Column], null_on_oob: bool) -> PolarsResult<Column> {
     ca.array_get(index, null_on_oob).map(Column::from)
 }
 
+pub(super) fn scatter(s: &mut [Column], skip_oob: bool) -> PolarsResult<Column> {
+    // Take the arrays, so that their values can be updated in place if nothing else holds them.
+    let series = std::mem::take(&mut s[0]).take_materialized_series();
+    let ca = series.array()?.clone();
+    drop(series);
+    let index = s[1].cast(&DataType::Int64)?;
+    let index = index.i64().unwrap();
+    let values = s[2].as_materialized_series();
+    array_scatter(ca, index, values, skip_oob).map(|ca| ca.into_column())
+}
+
+pub(super) fn set_at_idx_masked(s: &mut [Column]) -> PolarsResult<Column> {
+    let series = std::mem::take(&mut s[0]).take_materialized_series();
+    let ca = series.array()?.clone();
+    drop(series);
+    let mask = s[1].array()?;
+    let values = s[2].as_materialized_series();
+    array_set_at_idx_masked(ca, mask, values).map(|ca| ca.into_column())
+}
+
 pub(super) fn join(s: &[Column], ignore_nulls: bool) -> PolarsResult<Column> {
     let ca = s[0].array()?;
     let separator = s[1].str()?;
@@ -159,12 +249,25 @@ pub(super) fn join(s: &[Column], ignore_nulls: bool) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "is_in")]
-pub(super) fn contains(s: &[Column], nulls_equal: bool) -> PolarsResult<Column> {
+pub(super) fn contains(
+    s: &[Column],
+    nulls_equal: bool,
+    sorted_rows: Option<SortedRowsOptions>,
+) -> PolarsResult<Column> {
     let array = &s[0];
     let item = &s[1];
     polars_ensure!(matches!(array.dtype(), DataType::Array(_, _)),
         SchemaMismatch: "invalid series dtype: expected `Array`, got `{}`", array.dtype(),
     );
+    if let Some(options) = sorted_rows {
+        let ca = array.array()?;
+        if let Some(mut out) =
+            ca.array_contains_sorted(item.as_materialized_series(), nulls_equal, options)?
+        {
+            out.rename(array.name().clone());
+            return Ok(out.into_column());
+        }
+    }
     let mut ca = polars_ops::series::is_in(
         item.as_materialized_series(),
         array.as_materialized_series(),
@@ -175,7 +278,10 @@ pub(super) fn contains(s: &[Column], nulls_equal: bool) -> PolarsResult<Column>
 }
 
 #[cfg(feature = "array_count")]
-pub(super) fn count_matches(args: &[Column]) -> PolarsResult<Column> {
+pub(super) fn count_matches(
+    args: &[Column],
+    sorted_rows: Option<SortedRowsOptions>,
+) -> PolarsResult<Column> {
     let s = &args[0];
     let element = &args[1];
     polars_ensure!(
@@ -184,6 +290,13 @@ pub(super) fn count_matches(args: &[Column]) -> PolarsResult<Column> {
         element.len()
     );
     let ca = s.array()?;
+    if let Some(options) = sorted_rows {
+        if let Some(out) =
+            ca.array_count_matches_sorted(element.as_materialized_series(), options)?
+        {
+            return Ok(out.into_column());
+        }
+    }
     ca.array_count_matches(element.get(0).unwrap())
         .map(Column::from)
 }
@@ -128,6 +128,45 @@ pub fn null_count<'a>(
     Ok(ac)
 }
 
+#[cfg(feature = "dtype-array")]
+pub fn implode_to_array<'a>(
+    inputs: &[Arc<dyn PhysicalExpr>],
+    df: &DataFrame,
+    groups: &'a GroupPositions,
+    state: &ExecutionState,
+    width: usize,
+) -> PolarsResult<AggregationContext<'a>> {
+    assert_eq!(inputs.len(), 1);
+
+    let mut ac = inputs[0].evaluate_on_groups(df, groups, state)?;
+
+    // Every group has a single value.
+    if let AggState::AggregatedScalar(s) | AggState::LiteralScalar(s) = &mut ac.state {
+        polars_ensure!(
+            width == 1,
+            ShapeMismatch: "cannot implode a single value per group into an array of width {}",
+            width
+        );
+        *s = s
+            .as_materialized_series()
+            .to_array_rows(1, s.len())?
+            .into_column();
+        return Ok(ac);
+    }
+
+    ac.groups();
+    let values = ac.flat_naive();
+    // SAFETY: groups are always in bounds.
+    let out = unsafe {
+        values
+            .as_materialized_series()
+            .agg_implode_to_array(ac.groups.as_ref(), width)?
+    };
+    ac.state = AggState::AggregatedScalar(out.into_column());
+
+    Ok(ac)
+}
+
 pub fn any<'a>(
     inputs: &[Arc<dyn PhysicalExpr>],
     df: &DataFrame,
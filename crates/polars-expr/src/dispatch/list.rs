@@ -64,6 +64,12 @@ pub fn function_expr_to_udf(func: IRListFunction) -> SpecialEq<Arc<dyn ColumnsUd
         Join(ignore_nulls) => map_as_slice!(join, ignore_nulls),
         #[cfg(feature = "dtype-array")]
         ToArray(width) => map!(to_array, width),
+        #[cfg(feature = "dtype-array")]
+        PadToArray {
+            width,
+            side,
+            truncate_side,
+        } => map_as_slice!(pad_to_array, width, side, truncate_side),
         NUnique => map!(n_unique),
         #[cfg(feature = "list_to_struct")]
         ToStruct(names) => map!(to_struct, &names),
@@ -420,6 +426,20 @@ pub(super) fn to_array(s: &Column, width: usize) -> PolarsResult<Column> {
     }
 }
 
+#[cfg(feature = "dtype-array")]
+pub(super) fn pad_to_array(
+    args: &[Column],
+    width: usize,
+    side: polars_ops::prelude::PadSide,
+    truncate_side: polars_ops::prelude::PadSide,
+) -> PolarsResult<Column> {
+    let list = args[0].list()?;
+    let fill = args[1].as_materialized_series();
+    Ok(list
+        .lst_pad_to_array(width, side, truncate_side, fill)?
+        .into_column())
+}
+
 #[cfg(feature = "list_to_struct")]
 pub(super) fn to_struct(s: &Column, names: &Arc<[PlSmallStr]>) -> PolarsResult<Column> {
     use polars_ops::prelude::ToStruct;
@@ -119,6 +119,13 @@ pub(super) fn reshape(c: &Column, dimensions: &[ReshapeDimension]) -> PolarsResu
     c.reshape_array(dimensions)
 }
 
+#[cfg(feature = "dtype-array")]
+pub(super) fn implode_to_array(c: &Column, width: usize) -> PolarsResult<Column> {
+    c.as_materialized_series()
+        .implode_to_array(width)
+        .map(IntoColumn::into_column)
+}
+
 #[cfg(feature = "repeat_by")]
 pub(super) fn repeat_by(s: &[Column]) -> PolarsResult<Column> {
     let by = &s[1];
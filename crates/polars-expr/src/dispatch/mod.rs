@@ -378,6 +378,8 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::RepeatBy => map_as_slice!(misc::repeat_by),
         #[cfg(feature = "dtype-array")]
         F::Reshape(dims) => map!(misc::reshape, &dims),
+        #[cfg(feature = "dtype-array")]
+        F::ImplodeToArray(width) => map!(misc::implode_to_array, width),
         #[cfg(feature = "cutqcut")]
         F::Cut {
             breaks,
@@ -591,12 +593,23 @@ pub fn function_expr_to_groups_udf(func: &IRFunctionExpr) -> Option<SpecialEq<Ar
         },
 
         F::Unique(stable) => wrap_groups!(groups_dispatch::unique, (*stable, v: bool)),
+        #[cfg(feature = "dtype-array")]
+        F::ImplodeToArray(width) => {
+            wrap_groups!(groups_dispatch::implode_to_array, (*width, v: usize))
+        },
         F::FillNullWithStrategy(polars_core::prelude::FillNullStrategy::Forward(limit)) => {
             wrap_groups!(groups_dispatch::forward_fill_null, (*limit, v: Option<IdxSize>))
         },
         F::FillNullWithStrategy(polars_core::prelude::FillNullStrategy::Backward(limit)) => {
             wrap_groups!(groups_dispatch::backward_fill_null, (*limit, v: Option<IdxSize>))
         },
+        #[cfg(feature = "rolling_window")]
+        F::RollingExpr { function, options } if rolling::has_grouped_rolling(function, options) => {
+            SpecialEq::new(Arc::new(rolling::GroupedRolling {
+                function: function.clone(),
+                options: options.clone(),
+            }) as Arc<dyn GroupsUdf>)
+        },
 
         _ => return None,
     })
@@ -1,12 +1,19 @@
+use std::ops::RangeInclusive;
+
+use num_traits::{NumCast, Zero};
 use polars_core::prelude::*;
 use polars_core::with_match_physical_integer_polars_type;
 use polars_ops::series::new_int_range;
+use polars_plan::dsl::RangeNullBehavior;
 
 use super::utils::{ensure_items_contain_exactly_one_value, numeric_ranges_impl_broadcast};
 
-const CAPACITY_FACTOR: usize = 5;
-
-pub(super) fn int_range(s: &[Column], step: i64, dtype: DataType) -> PolarsResult<Column> {
+pub(super) fn int_range(
+    s: &[Column],
+    step: i64,
+    dtype: DataType,
+    null_behavior: RangeNullBehavior,
+) -> PolarsResult<Column> {
     let start = &s[0];
     let end = &s[1];
     let name = start.name();
@@ -18,6 +25,10 @@ pub(super) fn int_range(s: &[Column], step: i64, dtype: DataType) -> PolarsResul
     assert_eq!(start.dtype(), &dtype);
     assert_eq!(end.dtype(), &dtype);
 
+    if null_behavior == RangeNullBehavior::Propagate && (start.has_nulls() || end.has_nulls()) {
+        return Ok(Column::new_empty(name.clone(), &dtype));
+    }
+
     with_match_physical_integer_polars_type!(dtype, |$T| {
         let start_v = get_first_series_value::<$T>(start)?;
         let end_v = get_first_series_value::<$T>(end)?;
@@ -45,34 +56,181 @@ pub(super) fn int_ranges(s: &[Column], dtype: DataType) -> PolarsResult<Column>
     let end = end.i64()?;
     let step = step.i64()?;
 
-    let len = std::cmp::max(start.len(), end.len());
-    let mut builder = ListPrimitiveChunkedBuilder::<Int64Type>::new(
+    // Done by type coercion
+    assert!(dtype.is_integer());
+
+    with_match_physical_integer_polars_type!(dtype, |$T| {
+        int_ranges_impl::<$T>(start, end, step, &dtype)
+    })
+}
+
+/// Build the ranges directly in the target `dtype`, checking up front that every range fits.
+fn int_ranges_impl<T>(
+    start: &Int64Chunked,
+    end: &Int64Chunked,
+    step: &Int64Chunked,
+    dtype: &DataType,
+) -> PolarsResult<Column>
+where
+    T: PolarsIntegerType,
+    ListPrimitiveChunkedBuilder<T>: ListBuilderTrait,
+    RangeInclusive<T::Native>: DoubleEndedIterator<Item = T::Native>,
+{
+    let len = start.len().max(end.len()).max(step.len());
+    let values_capacity = validate_ranges(start, end, step, len)?;
+    let mut builder = ListPrimitiveChunkedBuilder::<T>::new(
         // The name should follow our left hand rule.
         start.name().clone(),
         len,
-        len * CAPACITY_FACTOR,
-        DataType::Int64,
+        values_capacity,
+        dtype.clone(),
     );
 
-    let range_impl =
-        |start, end, step: i64, builder: &mut ListPrimitiveChunkedBuilder<Int64Type>| {
-            match step {
-                1 => builder.append_values_iter_trusted_len(start..end),
-                2.. => builder.append_values_iter_trusted_len((start..end).step_by(step as usize)),
-                _ => builder.append_values_iter_trusted_len(
-                    (end..start)
-                        .step_by(step.unsigned_abs() as usize)
-                        .map(|x| start - (x - end)),
-                ),
-            };
-            Ok(())
+    let range_impl = |start, end, step: i64, builder: &mut ListPrimitiveChunkedBuilder<T>| {
+        let Some(last) = last_range_value(start, end, step) else {
+            builder.append_slice(&[]);
+            return Ok(());
+        };
+        let (Some(first), Some(last)) = (
+            <T::Native as NumCast>::from(start),
+            <T::Native as NumCast>::from(last),
+        ) else {
+            polars_bail!(
+                ComputeError:
+                "`int_ranges` range from {start} to {end} with step {step} cannot be represented as {dtype}"
+            );
         };
 
-    let column = numeric_ranges_impl_broadcast(start, end, step, range_impl, &mut builder)?;
+        let step_size = step.unsigned_abs() as usize;
+        if step > 0 {
+            builder.append_values_iter_trusted_len((first..=last).step_by(step_size));
+        } else {
+            builder.append_values_iter_trusted_len((last..=first).rev().step_by(step_size));
+        }
+        Ok(())
+    };
 
-    if dtype != DataType::Int64 {
-        column.cast(&DataType::List(Box::new(dtype)))
-    } else {
-        Ok(column)
+    numeric_ranges_impl_broadcast(start, end, step, range_impl, &mut builder)
+}
+
+pub(super) fn int_range_array(
+    s: &[Column],
+    len: usize,
+    step: i64,
+    dtype: DataType,
+) -> PolarsResult<Column> {
+    let start = s[0].i64()?;
+
+    // Done by type coercion
+    assert!(dtype.is_integer());
+
+    with_match_physical_integer_polars_type!(dtype, |$T| {
+        int_range_array_impl::<$T>(start, len, step, &dtype)
+    })
+}
+
+/// Build a range of `len` values from every start directly in the target `dtype`. The rows of a
+/// null start are filled with zeros and masked out.
+fn int_range_array_impl<T>(
+    start: &Int64Chunked,
+    len: usize,
+    step: i64,
+    dtype: &DataType,
+) -> PolarsResult<Column>
+where
+    T: PolarsIntegerType,
+{
+    let mut values = Vec::with_capacity(start.len() * len);
+    for opt_start in start.iter() {
+        let Some(start) = opt_start else {
+            values.extend(std::iter::repeat_n(T::Native::zero(), len));
+            continue;
+        };
+        if len == 0 {
+            continue;
+        }
+
+        // The values lie between the first and the last value, so they fit if those do.
+        let last = start as i128 + (len as i128 - 1) * step as i128;
+        polars_ensure!(
+            <T::Native as NumCast>::from(start).is_some()
+                && <T::Native as NumCast>::from(last).is_some(),
+            ComputeError:
+            "`int_range_array` range from {start} with length {len} and step {step} cannot be represented as {dtype}"
+        );
+        values.extend(
+            (0..len as i128)
+                .map(|i| <T::Native as NumCast>::from(start as i128 + i * step as i128).unwrap()),
+        );
     }
+
+    let values = ChunkedArray::<T>::from_vec(PlSmallStr::EMPTY, values);
+    let mut out = ArrayChunked::from_aligned_values(
+        // The name should follow our left hand rule.
+        start.name().clone(),
+        dtype,
+        len,
+        values.chunks().clone(),
+        start.len(),
+    );
+    if let Some(validity) = start.rechunk_validity() {
+        out.set_validity(&validity);
+    }
+    Ok(out.into_column())
+}
+
+/// Check that no row with a valid start and end has a zero step, and get the total number of
+/// values in the ranges.
+///
+/// Columns of length 1 are broadcast to `len`. Mismatching lengths are reported when building the
+/// ranges.
+fn validate_ranges(
+    start: &Int64Chunked,
+    end: &Int64Chunked,
+    step: &Int64Chunked,
+    len: usize,
+) -> PolarsResult<usize> {
+    fn broadcast(ca: &Int64Chunked, len: usize) -> Box<dyn Iterator<Item = Option<i64>> + '_> {
+        if ca.len() == 1 {
+            Box::new(std::iter::repeat_n(ca.get(0), len))
+        } else {
+            Box::new(ca.iter())
+        }
+    }
+
+    let mut values_len = 0usize;
+    for (i, ((start, end), step)) in broadcast(start, len)
+        .zip(broadcast(end, len))
+        .zip(broadcast(step, len))
+        .enumerate()
+    {
+        if let (Some(start), Some(end), Some(step)) = (start, end, step) {
+            polars_ensure!(
+                step != 0,
+                InvalidOperation: "`int_ranges` step must not be zero, got zero step at row {i}"
+            );
+            values_len = values_len.saturating_add(range_len(start, end, step));
+        }
+    }
+    Ok(values_len)
+}
+
+/// Get the number of values in the range from `start` to `end` (exclusive) with a non-zero
+/// `step`. The range is empty if `end` cannot be reached with the sign of `step`.
+fn range_len(start: i64, end: i64, step: i64) -> usize {
+    let (start, end, step) = (start as i128, end as i128, step as i128);
+    let len = if step > 0 {
+        (end - start + step - 1) / step
+    } else {
+        (start - end - step - 1) / -step
+    };
+    len.max(0) as usize
+}
+
+/// Get the last value of the range from `start` to `end` (exclusive) with a non-zero `step`, or
+/// `None` if the range is empty.
+fn last_range_value(start: i64, end: i64, step: i64) -> Option<i64> {
+    let len = range_len(start, end, step);
+    // The last value lies between `start` and `end`, so it fits in an `i64`.
+    (len > 0).then(|| (start as i128 + (len as i128 - 1) * step as i128) as i64)
 }
@@ -14,12 +14,19 @@ mod utils;
 pub fn function_expr_to_udf(func: IRRangeFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
     use IRRangeFunction::*;
     match func {
-        IntRange { step, dtype } => {
-            map_as_slice!(int_range::int_range, step, dtype.clone())
+        IntRange {
+            step,
+            dtype,
+            null_behavior,
+        } => {
+            map_as_slice!(int_range::int_range, step, dtype.clone(), null_behavior)
         },
         IntRanges { dtype } => {
             map_as_slice!(int_range::int_ranges, dtype.clone())
         },
+        IntRangeArray { len, step, dtype } => {
+            map_as_slice!(int_range::int_range_array, len, step, dtype.clone())
+        },
         LinearSpace { closed } => {
             map_as_slice!(linear_space::linear_space, closed)
         },
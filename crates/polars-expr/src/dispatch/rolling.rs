@@ -1,19 +1,31 @@
+use std::borrow::Cow;
 use std::ops::BitAnd;
+use std::sync::Arc;
 
+use arrow::bitmap::bitmask::BitMask;
 use arrow::temporal_conversions::MICROSECONDS_IN_DAY as US_IN_DAY;
-use polars_core::error::PolarsResult;
+use polars_compute::rolling::RollingFnParams;
+use polars_core::error::{PolarsResult, polars_ensure};
+use polars_core::frame::DataFrame;
 use polars_core::prelude::{
-    AnyValue, ChunkCast, Column, DataType, IntoColumn, NamedFrom, RollingOptionsFixedWindow,
-    TimeUnit,
+    AnyValue, BooleanChunked, ChunkCast, Column, DataType, GroupPositions, GroupsType, IntoColumn,
+    NamedFrom, NewChunkedArray, RollingOptionsFixedWindow, TimeUnit,
 };
 use polars_core::scalar::Scalar;
 use polars_core::series::Series;
 #[cfg(feature = "cov")]
 use polars_plan::dsl::RollingCovOptions;
+use polars_plan::plans::IRRollingFunction;
 use polars_plan::prelude::PlanCallback;
 use polars_time::prelude::SeriesOpsTime;
+use polars_utils::IdxSize;
+use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
 
+use super::GroupsUdf;
+use crate::prelude::{AggState, AggregationContext, PhysicalExpr, UpdateGroups};
+use crate::state::ExecutionState;
+
 fn roll_with_temporal_conversion<F: FnOnce(&Series) -> PolarsResult<Series>>(
     s: &Column,
     op: F,
@@ -229,3 +241,229 @@ pub fn rolling_map(
         )
         .map(Column::from)
 }
+
+/// Whether [`GroupedRolling`] can evaluate the rolling `function` with `options`.
+pub fn has_grouped_rolling(
+    function: &IRRollingFunction,
+    options: &RollingOptionsFixedWindow,
+) -> bool {
+    use IRRollingFunction as R;
+    if options.weights.is_some() {
+        return false;
+    }
+    match (function, &options.fn_params) {
+        (R::Min | R::Max | R::Mean | R::Sum, None) => true,
+        (R::Var | R::Std, None | Some(RollingFnParams::Var(_))) => true,
+        (R::Quantile, Some(RollingFnParams::Quantile(_))) => true,
+        _ => false,
+    }
+}
+
+/// Evaluates a fixed window rolling function on all groups at once.
+///
+/// The values are gathered so that every group is contiguous, after which the rolling kernels
+/// run once over all values with windows that are clamped to the bounds of their group. The
+/// results are scattered back to the original order if the groups partition the values.
+pub struct GroupedRolling {
+    pub function: IRRollingFunction,
+    pub options: RollingOptionsFixedWindow,
+}
+
+impl GroupsUdf for GroupedRolling {
+    fn evaluate_on_groups<'a>(
+        &self,
+        inputs: &[Arc<dyn PhysicalExpr>],
+        df: &DataFrame,
+        groups: &'a GroupPositions,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        assert_eq!(inputs.len(), 1);
+        let options = &self.options;
+        polars_ensure!(
+            options.min_periods <= options.window_size,
+            InvalidOperation: "`min_periods` should be <= `window_size`"
+        );
+
+        let mut ac = inputs[0].evaluate_on_groups(df, groups, state)?;
+        let is_scalar = matches!(
+            ac.agg_state(),
+            AggState::AggregatedScalar(_) | AggState::LiteralScalar(_)
+        );
+        ac.set_groups_for_undefined_agg_states();
+        ac.groups();
+        let values = ac.flat_naive().into_owned();
+
+        // The positions of the values in group order, the groups in that order and the windows
+        // within them.
+        let mut gather = Vec::with_capacity(values.len());
+        let mut group_slices = Vec::with_capacity(ac.groups.len());
+        let mut windows = Vec::with_capacity(values.len());
+        let mut push_group = |idx: &mut dyn Iterator<Item = IdxSize>, len: usize| {
+            let offset = gather.len();
+            gather.extend(idx);
+            group_slices.push([offset as IdxSize, len as IdxSize]);
+            windows.extend((0..len).map(|i| {
+                let (start, end) = window_bounds(i, options.window_size, len, options.center);
+                [(offset + start) as IdxSize, (end - start) as IdxSize]
+            }));
+        };
+        match &**ac.groups.as_ref() {
+            GroupsType::Idx(idx) => {
+                for (_, idx) in idx {
+                    push_group(&mut idx.iter().copied(), idx.len());
+                }
+            },
+            GroupsType::Slice { groups, .. } => {
+                for &[start, len] in groups {
+                    push_group(&mut (start..start + len), len as usize);
+                }
+            },
+        }
+
+        let is_identity = gather.iter().enumerate_idx().all(|(i, &g)| i == g);
+        let gathered = if is_identity && gather.len() == values.len() {
+            values.rechunk()
+        } else {
+            unsafe { values.take_slice_unchecked(&gather) }.rechunk()
+        };
+
+        let out = if has_grouped_kernels(gathered.dtype()) {
+            let windows = GroupsType::new_slice(windows, true, true);
+            let out = rolling_windows(&gathered, &self.function, options, &windows)?;
+            mask_min_periods(out, &gathered, windows.unwrap_slice(), options.min_periods)?
+        } else {
+            // Evaluate the groups one by one for the types that the kernels don't cover.
+            let mut out = apply_rolling(&gathered.slice(0, 0), &self.function, options)?;
+            for &[offset, len] in &group_slices {
+                let group = gathered.slice(offset as i64, len as usize);
+                out.append(&apply_rolling(&group, &self.function, options)?)?;
+            }
+            out
+        };
+
+        let partitions_values = !ac.groups.is_overlapping() && gather.len() == values.len();
+        if is_identity && partitions_values && !is_scalar {
+            ac.state = AggState::NotAggregated(out);
+        } else if partitions_values && !is_scalar {
+            let mut scatter = vec![0 as IdxSize; gather.len()];
+            for (i, &g) in gather.iter().enumerate_idx() {
+                scatter[g as usize] = i;
+            }
+            ac.state = AggState::NotAggregated(unsafe { out.take_slice_unchecked(&scatter) });
+        } else {
+            ac.state = AggState::NotAggregated(out);
+            ac.groups =
+                Cow::Owned(GroupsType::new_slice(group_slices, false, true).into_sliceable());
+            ac.update_groups = UpdateGroups::No;
+            ac.set_original_len(false);
+        }
+        Ok(ac)
+    }
+}
+
+/// The bounds of the window of the `i`-th value in a group of `len` values, the same as those
+/// of the rolling kernels.
+fn window_bounds(i: usize, window_size: usize, len: usize, center: bool) -> (usize, usize) {
+    if center {
+        let right_window = window_size.div_ceil(2);
+        (
+            i.saturating_sub(window_size - right_window),
+            std::cmp::min(len, i + right_window),
+        )
+    } else {
+        ((i + 1).saturating_sub(window_size), i + 1)
+    }
+}
+
+fn has_grouped_kernels(dtype: &DataType) -> bool {
+    use DataType as D;
+    matches!(
+        dtype,
+        D::Int8
+            | D::Int16
+            | D::Int32
+            | D::Int64
+            | D::UInt8
+            | D::UInt16
+            | D::UInt32
+            | D::UInt64
+            | D::Float32
+            | D::Float64
+    )
+}
+
+/// Run the rolling kernel of `function` over the overlapping `windows` of `values`.
+fn rolling_windows(
+    values: &Column,
+    function: &IRRollingFunction,
+    options: &RollingOptionsFixedWindow,
+    windows: &GroupsType,
+) -> PolarsResult<Column> {
+    use IRRollingFunction as R;
+    let to_float = || PolarsResult::Ok(Column::from(values.as_materialized_series().to_float()?));
+    let ddof = match &options.fn_params {
+        Some(RollingFnParams::Var(params)) => params.ddof,
+        _ => 1,
+    };
+
+    // SAFETY: the windows are within the bounds of the values.
+    Ok(unsafe {
+        match function {
+            R::Min => values.agg_min(windows),
+            R::Max => values.agg_max(windows),
+            R::Sum => values.agg_sum(windows),
+            R::Mean => to_float()?.agg_mean(windows),
+            R::Var => to_float()?.agg_var(windows, ddof),
+            R::Std => to_float()?.agg_std(windows, ddof),
+            R::Quantile => {
+                let Some(RollingFnParams::Quantile(params)) = &options.fn_params else {
+                    unreachable!()
+                };
+                to_float()?.agg_quantile(windows, params.prob, params.method)
+            },
+            _ => unreachable!(),
+        }
+    })
+}
+
+/// Set the windows of `out` that have less than `min_periods` valid values to null.
+fn mask_min_periods(
+    out: Column,
+    values: &Column,
+    windows: &[[IdxSize; 2]],
+    min_periods: usize,
+) -> PolarsResult<Column> {
+    let validity = values.rechunk_validity();
+    let validity = validity.as_ref().map(BitMask::from_bitmap);
+    let keep = windows.iter().map(|&[start, len]| {
+        let null_count = validity.map_or(0, |validity| {
+            unsafe { validity.sliced_unchecked(start as usize, len as usize) }.unset_bits()
+        });
+        len as usize - null_count >= min_periods
+    });
+    let keep = BooleanChunked::from_iter_values(PlSmallStr::EMPTY, keep);
+    if keep.all() {
+        return Ok(out);
+    }
+    let nulls = Column::full_null(out.name().clone(), out.len(), out.dtype());
+    out.zip_with(&keep, &nulls)
+}
+
+fn apply_rolling(
+    values: &Column,
+    function: &IRRollingFunction,
+    options: &RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    use IRRollingFunction as R;
+    let options = options.clone();
+    match function {
+        R::Min => rolling_min(values, options),
+        R::Max => rolling_max(values, options),
+        R::Mean => rolling_mean(values, options),
+        R::Sum => rolling_sum(values, options),
+        R::Quantile => rolling_quantile(values, options),
+        R::Var => rolling_var(values, options),
+        R::Std => rolling_std(values, options),
+        _ => unreachable!(),
+    }
+}
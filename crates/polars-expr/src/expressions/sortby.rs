@@ -158,6 +158,7 @@ fn sort_by_groups_multiple_by(
                 nulls_last: nulls_last.to_owned(),
                 multithreaded,
                 maintain_order,
+                deterministic: false,
                 limit: None,
             };
 
@@ -179,6 +180,7 @@ fn sort_by_groups_multiple_by(
                 nulls_last: nulls_last.to_owned(),
                 multithreaded,
                 maintain_order,
+                deterministic: false,
                 limit: None,
             };
             let sorted_idx = groups[0]
@@ -6,6 +6,7 @@ use polars_core::prelude::*;
 use polars_core::with_match_categorical_physical_type;
 use polars_core::with_match_physical_numeric_polars_type;
 use polars_utils::IdxSize;
+use polars_utils::bloom_filter::BloomFilter;
 use polars_utils::hashing::HashPartitioner;
 
 use crate::hash_keys::HashKeys;
@@ -54,6 +55,29 @@ pub trait Grouper: Any + Send + Sync {
         probe_matches: &mut Vec<IdxSize>,
     );
 
+    /// Like [`Grouper::probe_partitioned_groupers`], but keys that are ruled out by the bloom
+    /// filter of their partition may skip the lookup in the grouper. The filters must contain the
+    /// hashes of all keys in the respective grouper.
+    ///
+    /// Groupers for which a lookup is cheap ignore the filters.
+    ///
+    /// # Safety
+    /// All groupers must have the same schema, with a filter per grouper.
+    unsafe fn probe_partitioned_groupers_with_filters(
+        &self,
+        groupers: &[Box<dyn Grouper>],
+        filters: &[BloomFilter],
+        keys: &HashKeys,
+        partitioner: &HashPartitioner,
+        invert: bool,
+        probe_matches: &mut Vec<IdxSize>,
+    ) {
+        let _ = filters;
+        unsafe {
+            self.probe_partitioned_groupers(groupers, keys, partitioner, invert, probe_matches)
+        }
+    }
+
     /// Returns for each key if it is found in the groupers. If invert is true
     /// it returns true if it isn't found.
     /// # Safety
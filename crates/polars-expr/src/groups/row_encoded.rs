@@ -1,5 +1,6 @@
 use arrow::array::Array;
 use polars_row::RowEncodingOptions;
+use polars_utils::bloom_filter::BloomFilter;
 use polars_utils::idx_map::bytes_idx_map::{BytesIndexMap, Entry};
 use polars_utils::itertools::Itertools;
 use polars_utils::vec::PushUnchecked;
@@ -125,44 +126,39 @@ impl Grouper for RowEncodedHashGrouper {
         invert: bool,
         probe_matches: &mut Vec<IdxSize>,
     ) {
-        let HashKeys::RowEncoded(keys) = keys else {
-            unreachable!()
-        };
-        assert!(partitioner.num_partitions() == groupers.len());
-
         unsafe {
-            if keys.keys.has_nulls() {
-                for (idx, hash) in keys.hashes.values_iter().enumerate_idx() {
-                    let has_group = if let Some(key) = keys.keys.get_unchecked(idx as usize) {
-                        let p = partitioner.hash_to_partition(*hash);
-                        let dyn_grouper: &dyn Grouper = &**groupers.get_unchecked(p);
-                        let grouper =
-                            &*(dyn_grouper as *const dyn Grouper as *const RowEncodedHashGrouper);
-                        grouper.contains_key(*hash, key)
-                    } else {
-                        false
-                    };
+            probe_partitioned_groupers_impl(
+                groupers,
+                None,
+                keys,
+                partitioner,
+                invert,
+                probe_matches,
+            )
+        }
+    }
 
-                    if has_group != invert {
-                        probe_matches.push(idx);
-                    }
-                }
-            } else {
-                for (idx, (hash, key)) in keys
-                    .hashes
-                    .values_iter()
-                    .zip(keys.keys.values_iter())
-                    .enumerate_idx()
-                {
-                    let p = partitioner.hash_to_partition(*hash);
-                    let dyn_grouper: &dyn Grouper = &**groupers.get_unchecked(p);
-                    let grouper =
-                        &*(dyn_grouper as *const dyn Grouper as *const RowEncodedHashGrouper);
-                    if grouper.contains_key(*hash, key) != invert {
-                        probe_matches.push(idx);
-                    }
-                }
-            }
+    /// # Safety
+    /// All groupers must be a RowEncodedHashGrouper, with a filter per grouper.
+    unsafe fn probe_partitioned_groupers_with_filters(
+        &self,
+        groupers: &[Box<dyn Grouper>],
+        filters: &[BloomFilter],
+        keys: &HashKeys,
+        partitioner: &HashPartitioner,
+        invert: bool,
+        probe_matches: &mut Vec<IdxSize>,
+    ) {
+        assert!(filters.len() == groupers.len());
+        unsafe {
+            probe_partitioned_groupers_impl(
+                groupers,
+                Some(filters),
+                keys,
+                partitioner,
+                invert,
+                probe_matches,
+            )
         }
     }
 
@@ -212,3 +208,58 @@ impl Grouper for RowEncodedHashGrouper {
         self
     }
 }
+
+/// # Safety
+/// All groupers must be a RowEncodedHashGrouper. If given, there must be a filter per grouper.
+unsafe fn probe_partitioned_groupers_impl(
+    groupers: &[Box<dyn Grouper>],
+    filters: Option<&[BloomFilter]>,
+    keys: &HashKeys,
+    partitioner: &HashPartitioner,
+    invert: bool,
+    probe_matches: &mut Vec<IdxSize>,
+) {
+    let HashKeys::RowEncoded(keys) = keys else {
+        unreachable!()
+    };
+    assert!(partitioner.num_partitions() == groupers.len());
+
+    unsafe {
+        let contains_key = |hash: u64, key: &[u8]| {
+            let p = partitioner.hash_to_partition(hash);
+            // The filter rules out most keys that aren't in the grouper, saving us the (more
+            // expensive) lookup in the hash table.
+            if filters.is_some_and(|filters| !filters.get_unchecked(p).may_contain(hash)) {
+                return false;
+            }
+            let dyn_grouper: &dyn Grouper = &**groupers.get_unchecked(p);
+            let grouper = &*(dyn_grouper as *const dyn Grouper as *const RowEncodedHashGrouper);
+            grouper.contains_key(hash, key)
+        };
+
+        if keys.keys.has_nulls() {
+            for (idx, hash) in keys.hashes.values_iter().enumerate_idx() {
+                let has_group = if let Some(key) = keys.keys.get_unchecked(idx as usize) {
+                    contains_key(*hash, key)
+                } else {
+                    false
+                };
+
+                if has_group != invert {
+                    probe_matches.push(idx);
+                }
+            }
+        } else {
+            for (idx, (hash, key)) in keys
+                .hashes
+                .values_iter()
+                .zip(keys.keys.values_iter())
+                .enumerate_idx()
+            {
+                if contains_key(*hash, key) != invert {
+                    probe_matches.push(idx);
+                }
+            }
+        }
+    }
+}
@@ -13,6 +13,9 @@ pub struct BinviewHashHotGrouper {
     table: FixedIndexTable<(u64, View, Vec<u8>)>,
     evicted_key_hashes: Vec<u64>,
     evicted_keys: BinaryViewArrayGenericBuilder<[u8]>,
+    // The number of bytes of the views and non-inline values in evicted_keys.
+    evicted_key_bytes: usize,
+    eviction_flush_threshold: usize,
     null_idx: IdxSize,
 }
 
@@ -22,10 +25,16 @@ impl BinviewHashHotGrouper {
             table: FixedIndexTable::new(max_groups.try_into().unwrap()),
             evicted_key_hashes: Vec::new(),
             evicted_keys: BinaryViewArrayGenericBuilder::new(ArrowDataType::BinaryView),
+            evicted_key_bytes: 0,
+            eviction_flush_threshold: usize::MAX,
             null_idx: IdxSize::MAX,
         }
     }
 
+    fn evicted_byte_size(&self) -> usize {
+        self.evicted_key_hashes.capacity() * size_of::<u64>() + self.evicted_key_bytes
+    }
+
     /// # Safety
     /// The view must be valid for the given buffer set.
     #[inline(always)]
@@ -39,9 +48,11 @@ impl BinviewHashHotGrouper {
         unsafe {
             let mut evict = |ev_h: &u64, ev_view: &View, ev_buffer: &Vec<u8>| {
                 self.evicted_key_hashes.push(*ev_h);
+                self.evicted_key_bytes += size_of::<View>();
                 if ev_view.is_inline() {
                     self.evicted_keys.push_inline_view_ignore_validity(*ev_view);
                 } else {
+                    self.evicted_key_bytes += ev_buffer.len();
                     self.evicted_keys
                         .push_value_ignore_validity(ev_buffer.as_slice());
                 }
@@ -98,7 +109,9 @@ impl BinviewHashHotGrouper {
 
 impl HotGrouper for BinviewHashHotGrouper {
     fn new_empty(&self, max_groups: usize) -> Box<dyn HotGrouper> {
-        Box::new(Self::new(max_groups))
+        let mut new = Self::new(max_groups);
+        new.eviction_flush_threshold = self.eviction_flush_threshold;
+        Box::new(new)
     }
 
     fn num_groups(&self) -> IdxSize {
@@ -191,6 +204,7 @@ impl HotGrouper for BinviewHashHotGrouper {
     fn take_evicted_keys(&mut self) -> HashKeys {
         let hashes = core::mem::take(&mut self.evicted_key_hashes);
         let keys = self.evicted_keys.freeze_reset();
+        self.evicted_key_bytes = 0;
         HashKeys::Binview(BinviewKeys {
             hashes: PrimitiveArray::from_vec(hashes),
             keys,
@@ -198,6 +212,51 @@ impl HotGrouper for BinviewHashHotGrouper {
         })
     }
 
+    fn byte_size(&self) -> usize {
+        let key_bytes: usize = self
+            .table
+            .keys()
+            .iter()
+            .map(|(_, _, buf)| buf.capacity())
+            .sum();
+        self.table.byte_size() + key_bytes + self.evicted_byte_size()
+    }
+
+    fn set_eviction_flush_threshold(&mut self, bytes: usize) {
+        self.eviction_flush_threshold = bytes;
+    }
+
+    fn should_flush_evictions(&self) -> bool {
+        self.evicted_byte_size() > self.eviction_flush_threshold
+    }
+
+    fn tick(&self) -> u64 {
+        self.table.tick()
+    }
+
+    fn evict_stale(&mut self, older_than: u64, evicted_groups: &mut Vec<IdxSize>) {
+        self.table.evict_stale(older_than, |g, (h, view, buffer)| {
+            self.evicted_key_hashes.push(*h);
+            self.evicted_key_bytes += size_of::<View>();
+            if view.is_inline() {
+                // SAFETY: the view is inline, so it doesn't refer to any buffer.
+                unsafe { self.evicted_keys.push_inline_view_ignore_validity(*view) };
+            } else {
+                self.evicted_key_bytes += buffer.len();
+                self.evicted_keys
+                    .push_value_ignore_validity(buffer.as_slice());
+            }
+            evicted_groups.push(g);
+        });
+    }
+
+    fn shrink_to_fit(&mut self) {
+        for (_, _, buffer) in self.table.keys_mut() {
+            buffer.shrink_to_fit();
+        }
+        self.evicted_key_hashes.shrink_to_fit();
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
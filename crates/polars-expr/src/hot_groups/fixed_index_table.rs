@@ -8,8 +8,11 @@ const H2_MULT: u64 = 0xf1357aea2e62a9c5;
 
 #[derive(Clone)]
 struct Slot {
-    tag: u32,
+    // The full hash of the key, so that keys are only compared if their hashes are equal.
+    hash: u64,
     last_access_tag: u32,
+    // The tick at which the key was last inserted or found.
+    last_touched: u64,
     key_index: IdxSize,
 }
 
@@ -20,8 +23,12 @@ pub struct FixedIndexTable<K> {
     slots: Vec<Slot>,
     keys: Vec<K>,
     num_filled_slots: usize, // Possibly different than keys.len() because of push_unmapped_key.
+    // Keys that were evicted from their slots by evict_stale, whose indices are reused before
+    // pushing new keys.
+    stale_key_indices: Vec<IdxSize>,
     shift: u8,
     prng: u64,
+    tick: u64,
 }
 
 impl<K> FixedIndexTable<K> {
@@ -29,8 +36,9 @@ impl<K> FixedIndexTable<K> {
         assert!(num_slots.is_power_of_two());
         assert!(num_slots > 1);
         let empty_slot = Slot {
-            tag: u32::MAX,
+            hash: u64::MAX,
             last_access_tag: u32::MAX,
+            last_touched: 0,
             key_index: IdxSize::MAX,
         };
         Self {
@@ -39,7 +47,9 @@ impl<K> FixedIndexTable<K> {
             num_filled_slots: 0,
             // We add one to the capacity for the null key.
             keys: Vec::with_capacity(1 + num_slots as usize),
+            stale_key_indices: Vec::new(),
             prng: 0,
+            tick: 0,
         }
     }
 
@@ -47,6 +57,13 @@ impl<K> FixedIndexTable<K> {
         self.keys.len()
     }
 
+    /// The number of keys that were inserted so far.
+    ///
+    /// Every key that is inserted or found is marked with the tick at which it was inserted.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
     /// Insert a key which will never be mapped to nor evicted.
     ///
     /// This is useful for permanent entries which are handled externally.
@@ -75,6 +92,8 @@ impl<K> FixedIndexTable<K> {
         V: FnMut(Q, &mut K),
     {
         let tag = hash as u32;
+        let tick = self.tick;
+        self.tick += 1;
         let h1 = (hash >> self.shift) as usize;
         let h2 = (hash.wrapping_mul(H2_MULT) >> self.shift) as usize;
 
@@ -83,11 +102,12 @@ impl<K> FixedIndexTable<K> {
             // why we check both slots at once.
             let s1 = self.slots.get_unchecked(h1);
             let s2 = self.slots.get_unchecked(h2);
-            let s1_delta = s1.tag ^ tag;
-            let s2_delta = s2.tag ^ tag;
+            let s1_delta = s1.hash ^ hash;
+            let s2_delta = s2.hash ^ hash;
             // This check can have false positives (the binary AND of the deltas
-            // happens to be zero by accident), but this is very unlikely (~1/10k)
-            // and harmless if it does. False negatives are impossible. If this
+            // happens to be zero by accident), but this is very unlikely and
+            // harmless if it does, as the hash of the slot is checked again
+            // before comparing the keys. False negatives are impossible. If this
             // branch succeeds we almost surely have a hit, if it fails
             // we're certain we have a miss.
             if s1_delta & s2_delta == 0 {
@@ -96,10 +116,13 @@ impl<K> FixedIndexTable<K> {
                 // majority of cases.
                 let ha = select_unpredictable(s1_delta == 0, h1, h2);
                 let sa = self.slots.get_unchecked_mut(ha);
-                if let Some(sak) = self.keys.get(sa.key_index as usize) {
-                    if eq(&key, sak) {
-                        sa.last_access_tag = tag;
-                        return Some(EvictIdx::new(sa.key_index, false));
+                if sa.hash == hash {
+                    if let Some(sak) = self.keys.get(sa.key_index as usize) {
+                        if eq(&key, sak) {
+                            sa.last_access_tag = tag;
+                            sa.last_touched = tick;
+                            return Some(EvictIdx::new(sa.key_index, false));
+                        }
                     }
                 }
 
@@ -110,35 +133,42 @@ impl<K> FixedIndexTable<K> {
                     if let Some(sbk) = self.keys.get(sb.key_index as usize) {
                         if eq(&key, sbk) {
                             sb.last_access_tag = tag;
+                            sb.last_touched = tick;
                             return Some(EvictIdx::new(sb.key_index, false));
                         }
                     }
                 }
             }
 
-            // Check if we can insert into an empty slot.
+            // Check if we can insert into an empty slot, first the first slot and then the
+            // second.
             let num_keys = self.keys.len() as IdxSize;
             if self.num_filled_slots < self.slots.len() {
-                // Check the first slot.
-                let s1 = self.slots.get_unchecked_mut(h1);
-                if s1.key_index >= num_keys {
-                    s1.tag = tag;
-                    s1.last_access_tag = tag;
-                    s1.key_index = num_keys;
-                    self.keys.push_unchecked(insert(key));
+                let he = if self.slots.get_unchecked(h1).key_index >= num_keys {
+                    Some(h1)
+                } else if self.slots.get_unchecked(h2).key_index >= num_keys {
+                    Some(h2)
+                } else {
+                    None
+                };
+
+                if let Some(he) = he {
+                    let slot = self.slots.get_unchecked_mut(he);
+                    slot.hash = hash;
+                    slot.last_access_tag = tag;
+                    slot.last_touched = tick;
                     self.num_filled_slots += 1;
-                    return Some(EvictIdx::new(s1.key_index, false));
-                }
 
-                // Check the second slot.
-                let s2 = self.slots.get_unchecked_mut(h2);
-                if s2.key_index >= num_keys {
-                    s2.tag = tag;
-                    s2.last_access_tag = tag;
-                    s2.key_index = num_keys;
+                    // The groups of stale keys were already evicted.
+                    if let Some(key_index) = self.stale_key_indices.pop() {
+                        slot.key_index = key_index;
+                        *self.keys.get_unchecked_mut(key_index as usize) = insert(key);
+                        return Some(EvictIdx::new(key_index, false));
+                    }
+
+                    slot.key_index = num_keys;
                     self.keys.push_unchecked(insert(key));
-                    self.num_filled_slots += 1;
-                    return Some(EvictIdx::new(s2.key_index, false));
+                    return Some(EvictIdx::new(num_keys, false));
                 }
             }
 
@@ -148,7 +178,8 @@ impl<K> FixedIndexTable<K> {
             let slot = self.slots.get_unchecked_mut(hr);
 
             if (slot.last_access_tag == tag) | force_insert {
-                slot.tag = tag;
+                slot.hash = hash;
+                slot.last_touched = tick;
                 let evict_key = self.keys.get_unchecked_mut(slot.key_index as usize);
                 evict_insert(key, evict_key);
                 Some(EvictIdx::new(slot.key_index, true))
@@ -159,7 +190,116 @@ impl<K> FixedIndexTable<K> {
         }
     }
 
+    /// Evicts the keys that were not inserted nor found since tick `older_than` from their slots,
+    /// calling `evict` with the index of every evicted key.
+    ///
+    /// The indices of the evicted keys are reused for new keys before any other, without evicting
+    /// them again. Until then the evicted key is kept, but no longer found.
+    pub fn evict_stale<V>(&mut self, older_than: u64, mut evict: V)
+    where
+        V: FnMut(IdxSize, &K),
+    {
+        let num_keys = self.keys.len() as IdxSize;
+        for slot in &mut self.slots {
+            if slot.key_index < num_keys && slot.last_touched < older_than {
+                evict(slot.key_index, &self.keys[slot.key_index as usize]);
+                self.stale_key_indices.push(slot.key_index);
+                slot.hash = u64::MAX;
+                slot.last_access_tag = u32::MAX;
+                slot.key_index = IdxSize::MAX;
+                self.num_filled_slots -= 1;
+            }
+        }
+    }
+
+    /// Looks up a key with a given hash, without modifying the table.
+    ///
+    /// Returns Some(index) if the key is present, None otherwise.
+    pub fn get_key<Q: ?Sized, E>(&self, hash: u64, key: &Q, mut eq: E) -> Option<IdxSize>
+    where
+        E: FnMut(&Q, &K) -> bool,
+    {
+        let h1 = (hash >> self.shift) as usize;
+        let h2 = (hash.wrapping_mul(H2_MULT) >> self.shift) as usize;
+
+        unsafe {
+            for h in [h1, h2] {
+                let slot = self.slots.get_unchecked(h);
+                if slot.hash == hash {
+                    if let Some(k) = self.keys.get(slot.key_index as usize) {
+                        if eq(key, k) {
+                            return Some(slot.key_index);
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// The number of bytes used by the slots and keys of this table, excluding
+    /// any memory the keys themselves own.
+    pub fn byte_size(&self) -> usize {
+        self.slots.capacity() * size_of::<Slot>()
+            + self.keys.capacity() * size_of::<K>()
+            + self.stale_key_indices.capacity() * size_of::<IdxSize>()
+    }
+
     pub fn keys(&self) -> &[K] {
         &self.keys
     }
+
+    pub fn keys_mut(&mut self) -> &mut [K] {
+        &mut self.keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_key_compares_hashes_before_keys() {
+        let mut table = FixedIndexTable::<u64>::new(4);
+        let slots = |hash: u64| {
+            (
+                (hash >> table.shift) as usize,
+                (hash.wrapping_mul(H2_MULT) >> table.shift) as usize,
+            )
+        };
+
+        // Find a hash whose two slots are filled by keys with hashes that differ from it in
+        // disjoint bits, so that the AND of the deltas is zero without either hash matching.
+        let (hash, hash_a, hash_b) = (0..u64::MAX)
+            .map(|i| {
+                let hash = i.wrapping_mul(0x9e3779b97f4a7c15);
+                (hash, hash ^ 1, hash ^ 2)
+            })
+            .find(|&(hash, hash_a, hash_b)| {
+                let (h1, h2) = slots(hash);
+                h1 != h2 && slots(hash_a).0 == h1 && slots(hash_b).0 == h1 && slots(hash_b).1 == h2
+            })
+            .unwrap();
+        let insert = |table: &mut FixedIndexTable<u64>, hash: u64| {
+            table.insert_key(hash, hash, false, |a, b| a == b, |k| k, |k, ev_k| *ev_k = k)
+        };
+        assert_eq!(insert(&mut table, hash_a).unwrap().idx(), 0);
+        assert_eq!(insert(&mut table, hash_b).unwrap().idx(), 1);
+
+        let mut num_eq_calls = 0;
+        let out = table.insert_key(
+            hash,
+            hash,
+            false,
+            |_, _| {
+                num_eq_calls += 1;
+                true
+            },
+            |k| k,
+            |k, ev_k| *ev_k = k,
+        );
+        assert_eq!(num_eq_calls, 0);
+        assert!(out.is_none_or(|g| g.should_evict()));
+        assert_eq!(table.get_key(hash, &hash, |_, _| true), None);
+    }
 }
@@ -32,6 +32,21 @@ pub trait HotGrouper: Any + Send + Sync {
         force_hot: bool,
     );
 
+    /// Looks up the given keys in this HotGrouper without inserting them,
+    /// extending hot_idxs and hot_group_idxs with the indices and groups of
+    /// the keys that are present, and missing_idxs with the indices of the
+    /// others. Neither the groups nor the evicted keys are changed.
+    fn probe_keys(
+        &self,
+        keys: &HashKeys,
+        hot_idxs: &mut Vec<IdxSize>,
+        hot_group_idxs: &mut Vec<EvictIdx>,
+        missing_idxs: &mut Vec<IdxSize>,
+    ) -> PolarsResult<()> {
+        let _ = (keys, hot_idxs, hot_group_idxs, missing_idxs);
+        polars_bail!(InvalidOperation: "probing keys is not supported by this hot grouper")
+    }
+
     /// Get all the current hot keys, in group order.
     fn keys(&self) -> HashKeys;
 
@@ -41,6 +56,34 @@ pub trait HotGrouper: Any + Send + Sync {
     /// Consume all the evicted keys from this HotGrouper.
     fn take_evicted_keys(&mut self) -> HashKeys;
 
+    /// Get the approximate number of bytes used by the table and the evicted
+    /// keys of this HotGrouper.
+    fn byte_size(&self) -> usize;
+
+    /// Set the number of bytes the evicted keys may use before
+    /// should_flush_evictions returns true. Defaults to no limit.
+    fn set_eviction_flush_threshold(&mut self, bytes: usize);
+
+    /// Returns true if the evicted keys use more bytes than the eviction flush
+    /// threshold, and should be consumed with take_evicted_keys.
+    fn should_flush_evictions(&self) -> bool;
+
+    /// Returns the current tick, which advances with every key inserted into
+    /// this HotGrouper.
+    fn tick(&self) -> u64;
+
+    /// Evicts the groups whose keys weren't inserted since the given tick.
+    ///
+    /// Their keys are moved into the evicted keys right away, and their group
+    /// indices are appended to evicted_groups in the same order. The caller
+    /// must evict these groups from its grouped reductions with evict_groups
+    /// before taking the evictions. The groups are reused for new keys before
+    /// any other, without setting the evict bit.
+    fn evict_stale(&mut self, older_than: u64, evicted_groups: &mut Vec<IdxSize>);
+
+    /// Releases the memory allocated but unused by the keys and evicted keys.
+    fn shrink_to_fit(&mut self);
+
     fn as_any(&self) -> &dyn Any;
 }
 
@@ -13,6 +13,7 @@ pub struct RowEncodedHashHotGrouper {
     evicted_key_hashes: Vec<u64>,
     evicted_key_data: Vec<u8>,
     evicted_key_offsets: Offsets<i64>,
+    eviction_flush_threshold: usize,
 }
 
 impl RowEncodedHashHotGrouper {
@@ -23,13 +24,22 @@ impl RowEncodedHashHotGrouper {
             evicted_key_hashes: Vec::new(),
             evicted_key_data: Vec::new(),
             evicted_key_offsets: Offsets::new(),
+            eviction_flush_threshold: usize::MAX,
         }
     }
+
+    fn evicted_byte_size(&self) -> usize {
+        self.evicted_key_hashes.capacity() * size_of::<u64>()
+            + self.evicted_key_data.capacity()
+            + (self.evicted_key_offsets.capacity() + 1) * size_of::<i64>()
+    }
 }
 
 impl HotGrouper for RowEncodedHashHotGrouper {
     fn new_empty(&self, max_groups: usize) -> Box<dyn HotGrouper> {
-        Box::new(Self::new(self.key_schema.clone(), max_groups))
+        let mut new = Self::new(self.key_schema.clone(), max_groups);
+        new.eviction_flush_threshold = self.eviction_flush_threshold;
+        Box::new(new)
     }
 
     fn num_groups(&self) -> IdxSize {
@@ -82,6 +92,37 @@ impl HotGrouper for RowEncodedHashHotGrouper {
         }
     }
 
+    fn probe_keys(
+        &self,
+        keys: &HashKeys,
+        hot_idxs: &mut Vec<IdxSize>,
+        hot_group_idxs: &mut Vec<EvictIdx>,
+        missing_idxs: &mut Vec<IdxSize>,
+    ) -> PolarsResult<()> {
+        let HashKeys::RowEncoded(keys) = keys else {
+            unreachable!()
+        };
+
+        hot_idxs.reserve(keys.hashes.len());
+        hot_group_idxs.reserve(keys.hashes.len());
+        missing_idxs.reserve(keys.hashes.len());
+
+        keys.for_each_hash(|idx, opt_h| unsafe {
+            // Null keys are never in the table.
+            let opt_g = opt_h.and_then(|h| {
+                let key = keys.keys.value_unchecked(idx as usize);
+                self.table.get_key(h, key, |a, b| a == b.1.as_slice())
+            });
+            if let Some(g) = opt_g {
+                hot_idxs.push_unchecked(idx);
+                hot_group_idxs.push_unchecked(EvictIdx::new(g, false));
+            } else {
+                missing_idxs.push_unchecked(idx);
+            }
+        });
+        Ok(())
+    }
+
     fn keys(&self) -> HashKeys {
         unsafe {
             let mut hashes = Vec::with_capacity(self.table.len());
@@ -108,7 +149,322 @@ impl HotGrouper for RowEncodedHashHotGrouper {
         HashKeys::RowEncoded(RowEncodedKeys { hashes, keys })
     }
 
+    fn byte_size(&self) -> usize {
+        let key_bytes: usize = self.table.keys().iter().map(|(_, k)| k.capacity()).sum();
+        self.table.byte_size() + key_bytes + self.evicted_byte_size()
+    }
+
+    fn set_eviction_flush_threshold(&mut self, bytes: usize) {
+        self.eviction_flush_threshold = bytes;
+    }
+
+    fn should_flush_evictions(&self) -> bool {
+        self.evicted_byte_size() > self.eviction_flush_threshold
+    }
+
+    fn tick(&self) -> u64 {
+        self.table.tick()
+    }
+
+    fn evict_stale(&mut self, older_than: u64, evicted_groups: &mut Vec<IdxSize>) {
+        self.table.evict_stale(older_than, |g, (h, k)| {
+            self.evicted_key_hashes.push(*h);
+            self.evicted_key_offsets.try_push(k.len()).unwrap();
+            self.evicted_key_data.extend_from_slice(k);
+            evicted_groups.push(g);
+        });
+    }
+
+    fn shrink_to_fit(&mut self) {
+        for (_, k) in self.table.keys_mut() {
+            k.shrink_to_fit();
+        }
+        self.evicted_key_hashes.shrink_to_fit();
+        self.evicted_key_data.shrink_to_fit();
+        self.evicted_key_offsets.shrink_to_fit();
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY_LEN: usize = 64;
+
+    fn unique_keys(start: u64, n: u64) -> HashKeys {
+        let hashes = (start..start + n)
+            .map(|i| i.wrapping_mul(0x9e3779b97f4a7c15))
+            .collect();
+        let keys = LargeBinaryArray::from_iter_values((start..start + n).map(|i| {
+            let mut key = vec![0u8; KEY_LEN];
+            key[..8].copy_from_slice(&i.to_le_bytes());
+            key
+        }));
+        HashKeys::RowEncoded(RowEncodedKeys {
+            hashes: PrimitiveArray::from_vec(hashes),
+            keys,
+        })
+    }
+
+    #[test]
+    fn test_byte_size_tracks_evictions() {
+        let key_schema = Arc::new(Schema::from_iter([
+            Field::new("a".into(), DataType::Int32),
+            Field::new("b".into(), DataType::String),
+        ]));
+        let mut grouper = RowEncodedHashHotGrouper::new(key_schema, 16);
+        grouper.set_eviction_flush_threshold(10 * KEY_LEN);
+        let empty_size = grouper.byte_size();
+        assert!(!grouper.should_flush_evictions());
+
+        let mut hot_idxs = Vec::new();
+        let mut hot_group_idxs = Vec::new();
+        let mut cold_idxs = Vec::new();
+        let mut last_size = empty_size;
+        for batch in 0..10 {
+            let keys = unique_keys(batch * 100, 100);
+            grouper.insert_keys(
+                &keys,
+                &mut hot_idxs,
+                &mut hot_group_idxs,
+                &mut cold_idxs,
+                true,
+            );
+            let size = grouper.byte_size();
+            assert!(size >= last_size);
+            assert!(size >= empty_size + grouper.num_evictions() * KEY_LEN);
+            last_size = size;
+        }
+        let num_evictions = grouper.num_evictions();
+        assert!(num_evictions > 10);
+        assert!(grouper.should_flush_evictions());
+
+        let evicted = grouper.take_evicted_keys();
+        assert_eq!(evicted.len(), num_evictions);
+        assert_eq!(grouper.num_evictions(), 0);
+        assert!(!grouper.should_flush_evictions());
+        assert_eq!(
+            grouper.byte_size(),
+            empty_size + grouper.num_groups() as usize * KEY_LEN
+        );
+    }
+
+    #[test]
+    fn test_probe_keys_does_not_insert() {
+        let key_schema = Arc::new(Schema::from_iter([
+            Field::new("a".into(), DataType::Int32),
+            Field::new("b".into(), DataType::String),
+        ]));
+        let mut grouper = RowEncodedHashHotGrouper::new(key_schema, 16);
+
+        let mut hot_idxs = Vec::new();
+        let mut hot_group_idxs = Vec::new();
+        let mut cold_idxs = Vec::new();
+        grouper.insert_keys(
+            &unique_keys(0, 8),
+            &mut hot_idxs,
+            &mut hot_group_idxs,
+            &mut cold_idxs,
+            false,
+        );
+        let num_groups = grouper.num_groups();
+        let byte_size = grouper.byte_size();
+
+        let mut probe_hot_idxs = Vec::new();
+        let mut probe_hot_group_idxs = Vec::new();
+        let mut missing_idxs = Vec::new();
+        grouper
+            .probe_keys(
+                &unique_keys(0, 32),
+                &mut probe_hot_idxs,
+                &mut probe_hot_group_idxs,
+                &mut missing_idxs,
+            )
+            .unwrap();
+
+        // Exactly the keys that were inserted as hot are found, in the same groups.
+        assert_eq!(probe_hot_idxs, hot_idxs);
+        let groups = |idxs: &[EvictIdx]| idxs.iter().map(|g| g.idx()).collect::<Vec<_>>();
+        assert_eq!(groups(&probe_hot_group_idxs), groups(&hot_group_idxs));
+        let expected_missing = (0..32)
+            .filter(|i| !hot_idxs.contains(i))
+            .collect::<Vec<IdxSize>>();
+        assert_eq!(missing_idxs, expected_missing);
+
+        assert_eq!(grouper.num_groups(), num_groups);
+        assert_eq!(grouper.num_evictions(), 0);
+        assert_eq!(grouper.byte_size(), byte_size);
+    }
+
+    #[test]
+    fn test_long_keys_with_colliding_hashes() {
+        let key_schema = Arc::new(Schema::from_iter([
+            Field::new("a".into(), DataType::String),
+            Field::new("b".into(), DataType::String),
+        ]));
+        let mut grouper = RowEncodedHashHotGrouper::new(key_schema, 16);
+
+        // Long keys that only differ in their last byte. The first two keys have equal hashes, the
+        // others have hashes that only differ in their lowest bits, and thus share their first slot.
+        let hash = 0x0123456789abcdefu64;
+        let key_data = (0..8u8)
+            .map(|i| {
+                let mut key = vec![0xAB; 300];
+                key[299] = i;
+                key
+            })
+            .collect::<Vec<_>>();
+        let hashes = (0..8u64)
+            .map(|i| if i < 2 { hash } else { hash ^ i })
+            .collect::<Vec<_>>();
+        let keys = HashKeys::RowEncoded(RowEncodedKeys {
+            hashes: PrimitiveArray::from_vec(hashes),
+            keys: LargeBinaryArray::from_iter_values(key_data.iter()),
+        });
+
+        let check_groups = |grouper: &RowEncodedHashHotGrouper,
+                            hot_idxs: &[IdxSize],
+                            hot_group_idxs: &[EvictIdx]| {
+            for (idx, g) in hot_idxs.iter().zip(hot_group_idxs) {
+                assert_eq!(grouper.table.keys()[g.idx()].1, key_data[*idx as usize]);
+            }
+        };
+
+        let mut hot_idxs = Vec::new();
+        let mut hot_group_idxs = Vec::new();
+        let mut cold_idxs = Vec::new();
+        grouper.insert_keys(
+            &keys,
+            &mut hot_idxs,
+            &mut hot_group_idxs,
+            &mut cold_idxs,
+            false,
+        );
+        assert!(hot_group_idxs.iter().all(|g| !g.should_evict()));
+        check_groups(&grouper, &hot_idxs, &hot_group_idxs);
+        assert_eq!(&hot_idxs[..2], &[0, 1]);
+        assert_eq!(hot_idxs.len() + cold_idxs.len(), key_data.len());
+        assert_eq!(grouper.num_groups() as usize, hot_idxs.len());
+
+        let mut probe_hot_idxs = Vec::new();
+        let mut probe_hot_group_idxs = Vec::new();
+        let mut missing_idxs = Vec::new();
+        grouper
+            .probe_keys(
+                &keys,
+                &mut probe_hot_idxs,
+                &mut probe_hot_group_idxs,
+                &mut missing_idxs,
+            )
+            .unwrap();
+        assert_eq!(probe_hot_idxs, hot_idxs);
+        assert_eq!(missing_idxs, cold_idxs);
+        check_groups(&grouper, &probe_hot_idxs, &probe_hot_group_idxs);
+    }
+
+    #[test]
+    fn test_evict_stale_reuses_groups() {
+        let key_schema = Arc::new(Schema::from_iter([
+            Field::new("a".into(), DataType::Int32),
+            Field::new("b".into(), DataType::String),
+        ]));
+        let mut grouper = RowEncodedHashHotGrouper::new(key_schema, 16);
+        let key_bytes = |keys: &HashKeys| {
+            let HashKeys::RowEncoded(keys) = keys else {
+                unreachable!()
+            };
+            let mut keys = keys
+                .keys
+                .values_iter()
+                .map(<[u8]>::to_vec)
+                .collect::<Vec<_>>();
+            keys.sort();
+            keys
+        };
+
+        let mut hot_idxs = Vec::new();
+        let mut first_groups = Vec::new();
+        let mut cold_idxs = Vec::new();
+        let first_wave = unique_keys(0, 4);
+        grouper.insert_keys(
+            &first_wave,
+            &mut hot_idxs,
+            &mut first_groups,
+            &mut cold_idxs,
+            true,
+        );
+        assert_eq!(grouper.tick(), 4);
+
+        // Touching a key of the first wave again keeps it hot.
+        let touched = unique_keys(0, 1);
+        let tick = grouper.tick();
+        grouper.insert_keys(
+            &touched,
+            &mut hot_idxs,
+            &mut first_groups,
+            &mut cold_idxs,
+            true,
+        );
+        let mut evicted_groups = Vec::new();
+        grouper.evict_stale(tick, &mut evicted_groups);
+
+        // The keys that weren't touched come out of the evictions right away, in the order of
+        // their groups.
+        let stale = first_groups[1..4]
+            .iter()
+            .map(|g| g.idx() as IdxSize)
+            .collect::<Vec<_>>();
+        let mut sorted_evicted_groups = evicted_groups.clone();
+        sorted_evicted_groups.sort();
+        assert_eq!(sorted_evicted_groups, stale);
+        assert_eq!(grouper.num_evictions(), 3);
+        assert_eq!(grouper.num_groups(), 4);
+        let evicted = grouper.take_evicted_keys();
+        let HashKeys::RowEncoded(evicted_keys) = &evicted else {
+            unreachable!()
+        };
+        for (key, g) in evicted_keys.keys.values_iter().zip(&evicted_groups) {
+            assert_eq!(key, grouper.table.keys()[*g as usize].1);
+        }
+        assert_eq!(key_bytes(&evicted), key_bytes(&unique_keys(1, 3)));
+
+        // They are no longer found.
+        let (mut probe_hot_idxs, mut probe_groups, mut missing_idxs) = (vec![], vec![], vec![]);
+        grouper
+            .probe_keys(
+                &first_wave,
+                &mut probe_hot_idxs,
+                &mut probe_groups,
+                &mut missing_idxs,
+            )
+            .unwrap();
+        assert_eq!(probe_hot_idxs, [0]);
+        assert_eq!(missing_idxs, [1, 2, 3]);
+
+        let mut second_groups = Vec::new();
+        grouper.insert_keys(
+            &unique_keys(100, 4),
+            &mut hot_idxs,
+            &mut second_groups,
+            &mut cold_idxs,
+            true,
+        );
+        assert!(cold_idxs.is_empty());
+
+        // The stale groups are reused first, without evicting them again.
+        assert!(second_groups.iter().all(|g| !g.should_evict()));
+        let mut reused = second_groups
+            .iter()
+            .map(|g| g.idx() as IdxSize)
+            .filter(|g| stale.contains(g))
+            .collect::<Vec<_>>();
+        reused.sort();
+        assert_eq!(reused, stale);
+        assert_eq!(grouper.num_groups(), 5);
+        assert_eq!(grouper.num_evictions(), 0);
+    }
+}
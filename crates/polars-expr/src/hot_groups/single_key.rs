@@ -13,6 +13,7 @@ pub struct SingleKeyHashHotGrouper<T: PolarsDataType> {
     dtype: DataType,
     table: FixedIndexTable<T::Physical<'static>>,
     evicted_keys: Vec<T::Physical<'static>>,
+    eviction_flush_threshold: usize,
     null_idx: IdxSize,
     random_state: PlRandomState,
 }
@@ -27,6 +28,7 @@ where
             dtype,
             table: FixedIndexTable::new(max_groups.try_into().unwrap()),
             evicted_keys: Vec::new(),
+            eviction_flush_threshold: usize::MAX,
             null_idx: IdxSize::MAX,
             random_state: PlRandomState::default(),
         }
@@ -91,7 +93,9 @@ where
     K: Default + TotalHash + TotalEq + Clone + Send + Sync + 'static,
 {
     fn new_empty(&self, max_groups: usize) -> Box<dyn HotGrouper> {
-        Box::new(Self::new(self.dtype.clone(), max_groups))
+        let mut new = Self::new(self.dtype.clone(), max_groups);
+        new.eviction_flush_threshold = self.eviction_flush_threshold;
+        Box::new(new)
     }
 
     fn num_groups(&self) -> IdxSize {
@@ -172,7 +176,111 @@ where
         self.finalize_keys(keys, false)
     }
 
+    fn byte_size(&self) -> usize {
+        self.table.byte_size() + self.evicted_keys.capacity() * size_of::<K>()
+    }
+
+    fn set_eviction_flush_threshold(&mut self, bytes: usize) {
+        self.eviction_flush_threshold = bytes;
+    }
+
+    fn should_flush_evictions(&self) -> bool {
+        self.evicted_keys.capacity() * size_of::<K>() > self.eviction_flush_threshold
+    }
+
+    fn tick(&self) -> u64 {
+        self.table.tick()
+    }
+
+    fn evict_stale(&mut self, older_than: u64, evicted_groups: &mut Vec<IdxSize>) {
+        self.table.evict_stale(older_than, |g, k| {
+            self.evicted_keys.push(k.clone());
+            evicted_groups.push(g);
+        });
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.evicted_keys.shrink_to_fit();
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hot_groups::row_encoded::RowEncodedHashHotGrouper;
+
+    /// Inserts the keys and returns the hot indices with their groups, numbered in
+    /// order of first appearance as the slots differ between hash functions.
+    fn insert(
+        grouper: &mut dyn HotGrouper,
+        keys: &HashKeys,
+        seen: &mut PlHashMap<IdxSize, IdxSize>,
+    ) -> (Vec<IdxSize>, Vec<IdxSize>) {
+        let mut hot_idxs = Vec::new();
+        let mut hot_group_idxs = Vec::new();
+        let mut cold_idxs = Vec::new();
+        grouper.insert_keys(
+            keys,
+            &mut hot_idxs,
+            &mut hot_group_idxs,
+            &mut cold_idxs,
+            true,
+        );
+        assert!(cold_idxs.is_empty());
+        let groups = hot_group_idxs
+            .iter()
+            .map(|g| {
+                let num_seen = seen.len() as IdxSize;
+                *seen.entry(g.idx() as IdxSize).or_insert(num_seen)
+            })
+            .collect();
+        (hot_idxs, groups)
+    }
+
+    #[test]
+    fn test_groups_match_row_encoded_grouper() {
+        let batches = [
+            vec![Some(3i64), None, Some(1), Some(3), None, Some(-7), Some(1)],
+            vec![
+                Some(1),
+                Some(i64::MAX),
+                None,
+                Some(3),
+                Some(i64::MAX),
+                Some(0),
+            ],
+        ];
+        let key_schema = Arc::new(Schema::from_iter([Field::new("a".into(), DataType::Int64)]));
+        let random_state = PlRandomState::default();
+
+        for null_is_valid in [true, false] {
+            let mut single = new_hash_hot_grouper(key_schema.clone(), 1024);
+            assert!(single.as_any().is::<SingleKeyHashHotGrouper<Int64Type>>());
+            let mut row_encoded = RowEncodedHashHotGrouper::new(key_schema.clone(), 1024);
+            let mut single_seen = PlHashMap::default();
+            let mut row_encoded_seen = PlHashMap::default();
+
+            for batch in &batches {
+                let df = DataFrame::new(vec![Column::new("a".into(), batch)]).unwrap();
+                let single_keys =
+                    HashKeys::from_df(&df, random_state.clone(), null_is_valid, false);
+                let row_keys = HashKeys::from_df(&df, random_state.clone(), null_is_valid, true);
+                assert!(matches!(single_keys, HashKeys::Single(_)));
+
+                assert_eq!(
+                    insert(&mut *single, &single_keys, &mut single_seen),
+                    insert(&mut row_encoded, &row_keys, &mut row_encoded_seen)
+                );
+            }
+            let num_groups = if null_is_valid { 6 } else { 5 };
+            assert_eq!(single.num_groups(), num_groups);
+            assert_eq!(row_encoded.num_groups(), num_groups);
+            assert_eq!(single.num_evictions(), 0);
+            assert_eq!(row_encoded.num_evictions(), 0);
+        }
+    }
+}
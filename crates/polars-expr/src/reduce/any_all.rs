@@ -100,6 +100,17 @@ impl GroupedReduction for AnyIgnoreNullGroupedReduction {
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for &g in group_idxs {
+                let idx = g as usize;
+                self.evicted_values.push(self.values.get_unchecked(idx));
+                self.values.set_unchecked(idx, false);
+            }
+        }
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             values: core::mem::take(&mut self.evicted_values).into_mut(),
@@ -199,6 +210,17 @@ impl GroupedReduction for AllIgnoreNullGroupedReduction {
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for &g in group_idxs {
+                let idx = g as usize;
+                self.evicted_values.push(self.values.get_unchecked(idx));
+                self.values.set_unchecked(idx, true);
+            }
+        }
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             values: core::mem::take(&mut self.evicted_values).into_mut(),
@@ -313,6 +335,19 @@ impl GroupedReduction for AnyKleeneNullGroupedReduction {
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for &g in group_idxs {
+                let idx = g as usize;
+                self.evicted_values.push(self.seen_true.get_unchecked(idx));
+                self.evicted_mask.push(self.seen_null.get_unchecked(idx));
+                self.seen_true.set_unchecked(idx, false);
+                self.seen_null.set_unchecked(idx, false);
+            }
+        }
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             seen_true: core::mem::take(&mut self.evicted_values).into_mut(),
@@ -439,6 +474,19 @@ impl GroupedReduction for AllKleeneNullGroupedReduction {
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for &g in group_idxs {
+                let idx = g as usize;
+                self.evicted_values.push(self.seen_false.get_unchecked(idx));
+                self.evicted_mask.push(self.seen_null.get_unchecked(idx));
+                self.seen_false.set_unchecked(idx, false);
+                self.seen_null.set_unchecked(idx, false);
+            }
+        }
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             seen_false: core::mem::take(&mut self.evicted_values).into_mut(),
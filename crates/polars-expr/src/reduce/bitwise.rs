@@ -226,6 +226,19 @@ impl GroupedReduction for BoolXorGroupedReduction {
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for &g in group_idxs {
+                let idx = g as usize;
+                self.evicted_values.push(self.values.get_unchecked(idx));
+                self.evicted_mask.push(self.mask.get_unchecked(idx));
+                self.values.set_unchecked(idx, false);
+                self.mask.set_unchecked(idx, false);
+            }
+        }
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             values: core::mem::take(&mut self.evicted_values).into_mut(),
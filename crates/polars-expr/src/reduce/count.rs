@@ -101,6 +101,17 @@ impl GroupedReduction for CountReduce {
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for &g in group_idxs {
+                let idx = g as usize;
+                let grp = self.counts.get_unchecked_mut(idx);
+                self.evicted_counts.push(core::mem::take(grp));
+            }
+        }
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             counts: core::mem::take(&mut self.evicted_counts),
@@ -214,6 +225,17 @@ impl GroupedReduction for NullCountReduce {
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for &g in group_idxs {
+                let idx = g as usize;
+                let grp = self.counts.get_unchecked_mut(idx);
+                self.evicted_counts.push(core::mem::take(grp));
+            }
+        }
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             counts: core::mem::take(&mut self.evicted_counts),
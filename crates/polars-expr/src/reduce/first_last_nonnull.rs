@@ -464,6 +464,23 @@ impl<P: NonNullPolicy + 'static> GroupedReduction for GenericFirstLastNonNullGro
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for &g in group_idxs {
+                let idx = g as usize;
+                self.evicted_values.push(core::mem::replace(
+                    self.values.get_unchecked_mut(idx),
+                    AnyValue::Null,
+                ));
+                self.evicted_seqs
+                    .push(core::mem::replace(self.seqs.get_unchecked_mut(idx), 0));
+                self.evicted_seen.push(self.seen.get_unchecked(idx));
+                self.seen.set_unchecked(idx, false);
+            }
+        }
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             in_dtype: self.in_dtype.clone(),
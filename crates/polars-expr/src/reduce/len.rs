@@ -73,6 +73,17 @@ impl GroupedReduction for LenReduce {
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for &g in group_idxs {
+                let idx = g as usize;
+                let grp = self.groups.get_unchecked_mut(idx);
+                self.evictions.push(core::mem::take(grp));
+            }
+        }
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             groups: core::mem::take(&mut self.evictions),
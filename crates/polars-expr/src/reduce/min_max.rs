@@ -398,6 +398,19 @@ impl GroupedReduction for BoolMinGroupedReduction {
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for &g in group_idxs {
+                let idx = g as usize;
+                self.evicted_values.push(self.values.get_unchecked(idx));
+                self.evicted_mask.push(self.mask.get_unchecked(idx));
+                self.values.set_unchecked(idx, true);
+                self.mask.set_unchecked(idx, false);
+            }
+        }
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             values: core::mem::take(&mut self.evicted_values).into_mut(),
@@ -514,6 +527,19 @@ impl GroupedReduction for BoolMaxGroupedReduction {
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for &g in group_idxs {
+                let idx = g as usize;
+                self.evicted_values.push(self.values.get_unchecked(idx));
+                self.evicted_mask.push(self.mask.get_unchecked(idx));
+                self.values.set_unchecked(idx, false);
+                self.mask.set_unchecked(idx, false);
+            }
+        }
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             values: core::mem::take(&mut self.evicted_values).into_mut(),
@@ -728,6 +754,10 @@ impl GroupedReduction for NullGroupedReduction {
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        self.num_evictions += group_idxs.len();
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         let out = Box::new(Self {
             length: self.num_evictions,
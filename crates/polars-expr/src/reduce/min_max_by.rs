@@ -701,6 +701,21 @@ where
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for &g in group_idxs {
+                let idx = g as usize;
+                let grp = self.values.get_unchecked_mut(idx);
+                self.evicted_values
+                    .push(core::mem::replace(grp, self.reducer.init()));
+                self.evicted_mask.push(self.mask.get_unchecked(idx));
+                self.mask.set_unchecked(idx, false);
+            }
+            self.payload.evict_groups(group_idxs);
+        }
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             values: core::mem::take(&mut self.evicted_values),
@@ -103,6 +103,13 @@ pub trait GroupedReduction: Any + Send + Sync {
         group_idxs: &[IdxSize],
     ) -> PolarsResult<()>;
 
+    /// Evicts the given groups and resets them, as if they were updated with
+    /// their evict bit set. The groups are evicted in the given order.
+    ///
+    /// # Safety
+    /// The group_idxs are in-bounds.
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]);
+
     /// Take the accumulated evicted groups.
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction>;
 
@@ -333,6 +340,18 @@ where
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for &g in group_idxs {
+                let idx = g as usize;
+                let grp = self.values.get_unchecked_mut(idx);
+                self.evicted_values
+                    .push(core::mem::replace(grp, self.reducer.init()));
+            }
+        }
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             values: core::mem::take(&mut self.evicted_values),
@@ -471,6 +490,20 @@ where
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for &g in group_idxs {
+                let idx = g as usize;
+                let grp = self.values.get_unchecked_mut(idx);
+                self.evicted_values
+                    .push(core::mem::replace(grp, self.reducer.init()));
+                self.evicted_mask.push(self.mask.get_unchecked(idx));
+                self.mask.set_unchecked(idx, false);
+            }
+        }
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             values: core::mem::take(&mut self.evicted_values),
@@ -559,6 +592,10 @@ impl GroupedReduction for NullGroupedReduction {
         Ok(())
     }
 
+    unsafe fn evict_groups(&mut self, group_idxs: &[IdxSize]) {
+        self.num_evictions += group_idxs.len() as IdxSize;
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             num_groups: core::mem::replace(&mut self.num_evictions, 0),
@@ -14,6 +14,17 @@ impl LazyFrame {
         Ok(lp.display_dot().to_string())
     }
 
+    /// Get a Mermaid flowchart representation of the LogicalPlan.
+    pub fn to_mermaid(&self, optimized: bool) -> PolarsResult<String> {
+        let lp = if optimized {
+            self.clone().to_alp_optimized()
+        } else {
+            self.clone().to_alp()
+        }?;
+
+        Ok(lp.display_mermaid().to_string())
+    }
+
     /// Get a dot language representation of the streaming physical plan.
     #[cfg(feature = "new_streaming")]
     pub fn to_dot_streaming_phys(&self, optimized: bool) -> PolarsResult<String> {
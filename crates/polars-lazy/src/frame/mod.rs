@@ -232,6 +232,18 @@ impl LazyFrame {
         Ok(self.clone().to_alp_optimized()?.describe_tree_format())
     }
 
+    /// Return a String describing the optimized logical plan, together with the decisions the
+    /// optimizer made for that plan, such as the state of the caches inserted by common subplan
+    /// elimination.
+    ///
+    /// Returns `Err` if optimizing the logical plan fails.
+    pub fn describe_optimized_plan_with_report(
+        &self,
+    ) -> PolarsResult<(String, OptimizationReport)> {
+        let (plan, report) = self.clone().to_alp_optimized_with_report()?;
+        Ok((plan.describe(), report))
+    }
+
     /// Return a String describing the logical plan.
     ///
     /// If `optimized` is `true`, explains the optimized plan. If `optimized` is `false`,
@@ -506,6 +518,19 @@ impl LazyFrame {
         Ok(IRPlan::new(node, lp_arena, expr_arena))
     }
 
+    /// Optimize the logical plan and return the decisions the optimizer made for it.
+    ///
+    /// Every optimization creates new cache ids, so the cache ids in the report only refer to the
+    /// cache nodes of the plan returned with it.
+    pub fn to_alp_optimized_with_report(mut self) -> PolarsResult<(IRPlan, OptimizationReport)> {
+        let (mut lp_arena, mut expr_arena) = self.get_arenas();
+        let mut report = OptimizationReport::default();
+        let node =
+            self.optimize_with_report(&mut lp_arena, &mut expr_arena, &mut vec![], &mut report)?;
+
+        Ok((IRPlan::new(node, lp_arena, expr_arena), report))
+    }
+
     pub fn to_alp(mut self) -> PolarsResult<IRPlan> {
         let (mut lp_arena, mut expr_arena) = self.get_arenas();
         let node = to_alp(
@@ -523,6 +548,21 @@ impl LazyFrame {
         lp_arena: &mut Arena<IR>,
         expr_arena: &mut Arena<AExpr>,
         scratch: &mut Vec<Node>,
+    ) -> PolarsResult<Node> {
+        self.optimize_with_report(
+            lp_arena,
+            expr_arena,
+            scratch,
+            &mut OptimizationReport::default(),
+        )
+    }
+
+    fn optimize_with_report(
+        self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        scratch: &mut Vec<Node>,
+        report: &mut OptimizationReport,
     ) -> PolarsResult<Node> {
         #[allow(unused_mut)]
         let mut opt_state = self.opt_state;
@@ -542,6 +582,7 @@ impl LazyFrame {
             expr_arena,
             scratch,
             apply_scan_predicate_to_scan_ir,
+            report,
         )?;
 
         Ok(lp_top)
@@ -2214,6 +2255,7 @@ pub struct JoinBuilder {
     right_on: Vec<Expr>,
     allow_parallel: bool,
     force_parallel: bool,
+    bloom_filter: Option<bool>,
     suffix: Option<PlSmallStr>,
     validation: JoinValidation,
     nulls_equal: bool,
@@ -2231,6 +2273,7 @@ impl JoinBuilder {
             right_on: vec![],
             allow_parallel: true,
             force_parallel: false,
+            bloom_filter: None,
             suffix: None,
             validation: Default::default(),
             nulls_equal: false,
@@ -2294,6 +2337,13 @@ impl JoinBuilder {
         self
     }
 
+    /// Whether the streaming engine builds a bloom filter over the build keys of a semi/anti
+    /// join. By default this is decided based on the size of the build side.
+    pub fn bloom_filter(mut self, bloom_filter: Option<bool>) -> Self {
+        self.bloom_filter = bloom_filter;
+        self
+    }
+
     /// Join on null values. By default null values will never produce matches.
     pub fn join_nulls(mut self, nulls_equal: bool) -> Self {
         self.nulls_equal = nulls_equal;
@@ -2347,6 +2397,7 @@ impl JoinBuilder {
                 JoinOptions {
                     allow_parallel: self.allow_parallel,
                     force_parallel: self.force_parallel,
+                    bloom_filter: self.bloom_filter,
                     args,
                 }
                 .into(),
@@ -2430,6 +2481,7 @@ impl JoinBuilder {
         let options = JoinOptions {
             allow_parallel: self.allow_parallel,
             force_parallel: self.force_parallel,
+            bloom_filter: self.bloom_filter,
             args,
         };
 
@@ -11,9 +11,15 @@ pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
 #[cfg(feature = "polars_cloud_client")]
-pub use polars_plan::client::prepare_cloud_plan;
+pub use polars_plan::client::{
+    CloudPlanOptions, CloudPlanValidation, CloudPlanViolation, prepare_cloud_plan,
+    validate_cloud_plan,
+};
 pub use polars_plan::dsl::AnonymousScanOptions;
-pub use polars_plan::plans::{AnonymousScan, AnonymousScanArgs, Literal, LiteralValue, NULL, Null};
+pub use polars_plan::plans::{
+    AnonymousScan, AnonymousScanArgs, CachePredicatePushdown, CacheStateReport, Literal,
+    LiteralValue, NULL, Null, OptimizationReport,
+};
 pub(crate) use polars_plan::prelude::*;
 pub use polars_plan::prelude::{PlanCallback, UnionArgs};
 #[cfg(feature = "rolling_window_by")]
@@ -20,6 +20,31 @@ fn count_caches(q: LazyFrame) -> usize {
         .count()
 }
 
+/// Optimize `q` and check that the report describes the caches of the returned plan.
+fn optimization_report(q: LazyFrame) -> PolarsResult<OptimizationReport> {
+    let (
+        IRPlan {
+            lp_top, lp_arena, ..
+        },
+        report,
+    ) = q.to_alp_optimized_with_report()?;
+    let cache_ids = lp_arena
+        .iter(lp_top)
+        .filter_map(|(_node, lp)| match lp {
+            IR::Cache { id, .. } => Some(*id),
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>();
+    let reported_ids = report
+        .cache_states
+        .iter()
+        .filter(|state| !state.removed)
+        .map(|state| state.id)
+        .collect::<BTreeSet<_>>();
+    assert_eq!(cache_ids, reported_ids);
+    Ok(report)
+}
+
 #[test]
 fn test_cse_self_joins() -> PolarsResult<()> {
     let lf = scan_foods_ipc();
@@ -345,3 +370,386 @@ fn test_cse_prune_scan_filter_difference() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_cse_cache_shared_conjunct_pushdown() -> PolarsResult<()> {
+    let lf = scan_foods_ipc();
+    let lf = lf.with_column(col("category").str().to_uppercase());
+
+    let shared = col("calories").gt(lit(100));
+    let q = lf
+        .clone()
+        .filter(col("fats_g").gt(2.0).and(shared.clone()))
+        .left_join(
+            lf.filter(shared.and(col("sugars_g").lt(lit(10)))),
+            col("fats_g"),
+            col("fats_g"),
+        )
+        .with_comm_subplan_elim(true);
+
+    // The predicates share a conjunct, so the caches are kept.
+    assert_eq!(count_caches(q.clone()), 2);
+
+    // Only the shared conjunct is pushed below the caches.
+    let IRPlan {
+        lp_top,
+        lp_arena,
+        expr_arena,
+    } = q.clone().to_alp_optimized()?;
+    let mut scan_predicates = 0;
+    let mut filters = 0;
+    for (_node, lp) in lp_arena.iter(lp_top) {
+        match lp {
+            IR::Scan { predicate, .. } => {
+                let predicate = predicate.as_ref().expect("predicate pushed to the scan");
+                let names = aexpr_to_leaf_names(predicate.node(), &expr_arena);
+                assert_eq!(names, ["calories"]);
+                scan_predicates += 1;
+            },
+            IR::Filter { predicate, .. } => {
+                let names = aexpr_to_leaf_names(predicate.node(), &expr_arena);
+                assert!(names == ["fats_g"] || names == ["sugars_g"]);
+                filters += 1;
+            },
+            _ => {},
+        }
+    }
+    assert_eq!(scan_predicates, 2);
+    assert_eq!(filters, 2);
+
+    let sort_options = SortMultipleOptions::default();
+    let out = q
+        .clone()
+        .sort(
+            ["fats_g", "calories", "calories_right"],
+            sort_options.clone(),
+        )
+        .collect()?;
+    let expected = q
+        .with_comm_subplan_elim(false)
+        .sort(["fats_g", "calories", "calories_right"], sort_options)
+        .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_cse_cache_stacked_filters() -> PolarsResult<()> {
+    let lf = scan_foods_ipc();
+    let lf = lf.with_column(col("category").str().to_uppercase());
+
+    let pred_a = col("fats_g").gt(2.0);
+    let pred_b = col("calories").gt(lit(100));
+
+    // Without predicate pushdown before the caches are set, the filters stay stacked above the
+    // caches. Both predicates of every stack must be seen, otherwise the predicates would differ
+    // and the caches would be removed.
+    let q = lf
+        .clone()
+        .filter(pred_a.clone())
+        .filter(pred_b.clone())
+        .left_join(
+            lf.filter(pred_b).filter(pred_a),
+            col("fats_g"),
+            col("fats_g"),
+        )
+        .with_comm_subplan_elim(true)
+        .with_predicate_pushdown(false);
+
+    assert_eq!(count_caches(q.clone()), 2);
+
+    // Both predicates are pushed below the caches.
+    let IRPlan {
+        lp_top,
+        lp_arena,
+        expr_arena,
+    } = q.to_alp_optimized()?;
+    let mut scan_predicates = 0;
+    for (_node, lp) in lp_arena.iter(lp_top) {
+        match lp {
+            IR::Scan {
+                predicate: Some(predicate),
+                ..
+            } => {
+                let names = aexpr_to_leaf_names(predicate.node(), &expr_arena)
+                    .into_iter()
+                    .collect::<BTreeSet<_>>();
+                assert_eq!(names, BTreeSet::from(["calories".into(), "fats_g".into()]));
+                scan_predicates += 1;
+            },
+            IR::Filter { .. } => panic!("expected all filters to be pushed down"),
+            _ => {},
+        }
+    }
+    assert_eq!(scan_predicates, 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_cse_cache_different_filter_chains() -> PolarsResult<()> {
+    let lf = scan_foods_ipc();
+    let lf = lf.with_column(col("category").str().to_uppercase());
+
+    let pred_a = col("fats_g").gt(2.0);
+    let pred_b = col("calories").gt(lit(100));
+
+    // The predicates are the same, but one consumer has `Filter -> SimpleProjection -> Filter`
+    // above its cache and the other has a single combined filter.
+    let q = lf
+        .clone()
+        .filter(pred_a.clone())
+        .select([col("fats_g"), col("calories"), col("category")])
+        .filter(pred_b.clone())
+        .left_join(lf.filter(pred_a.and(pred_b)), col("fats_g"), col("fats_g"))
+        .with_comm_subplan_elim(true)
+        .with_predicate_pushdown(false);
+
+    let sort_options = SortMultipleOptions::default();
+    let out = q
+        .clone()
+        .sort(
+            ["fats_g", "calories", "calories_right"],
+            sort_options.clone(),
+        )
+        .collect()?;
+    let expected = q
+        .with_comm_subplan_elim(false)
+        .sort(["fats_g", "calories", "calories_right"], sort_options)
+        .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_cse_cache_non_elementwise_conjunct() -> PolarsResult<()> {
+    let lf = scan_foods_ipc();
+    let lf = lf.with_column(col("category").str().to_uppercase());
+
+    // The conjuncts are shared, but the first predicate compares with the mean over the rows
+    // that reach it. Pushing the shared conjunct below the caches would change that mean.
+    let shared = col("calories").gt(lit(100));
+    let q = lf
+        .clone()
+        .filter(
+            col("calories")
+                .gt(col("calories").mean())
+                .and(shared.clone()),
+        )
+        .left_join(
+            lf.filter(shared.and(col("sugars_g").lt(lit(10)))),
+            col("fats_g"),
+            col("fats_g"),
+        )
+        .with_comm_subplan_elim(true);
+
+    let sort_options = SortMultipleOptions::default();
+    let out = q
+        .clone()
+        .sort(
+            ["fats_g", "calories", "calories_right"],
+            sort_options.clone(),
+        )
+        .collect()?;
+    let expected = q
+        .with_comm_subplan_elim(false)
+        .sort(["fats_g", "calories", "calories_right"], sort_options)
+        .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_cse_cache_state_report() -> PolarsResult<()> {
+    let lf = scan_foods_ipc();
+    let lf = lf.with_column(col("category").str().to_uppercase());
+
+    // The predicate on the join key is pushed to both sides and ends up above both caches: the
+    // caches are kept and the predicate is pushed from the parent filter.
+    let q = lf
+        .clone()
+        .inner_join(lf.clone(), col("fats_g"), col("fats_g"))
+        .filter(col("fats_g").gt(2.0))
+        .with_comm_subplan_elim(true);
+
+    let report = optimization_report(q.clone())?;
+    let [state] = report.cache_states.as_slice() else {
+        panic!("expected a single cache id, got {report:?}");
+    };
+    assert_eq!(report.cache_state(&state.id), Some(state));
+    assert_eq!(state.num_consumers, 2);
+    assert_eq!(state.projection_union_size, 4);
+    assert_eq!(
+        state.predicate_pushdown,
+        Some(CachePredicatePushdown::ParentFilter)
+    );
+    assert!(!state.removed);
+
+    // Predicates that share a conjunct: the caches are kept and the shared conjunct is pushed
+    // from the cache node.
+    let shared = col("calories").gt(lit(100));
+    let q = lf
+        .clone()
+        .filter(col("fats_g").gt(2.0).and(shared.clone()))
+        .left_join(
+            lf.clone().filter(shared.and(col("sugars_g").lt(lit(10)))),
+            col("fats_g"),
+            col("fats_g"),
+        )
+        .with_comm_subplan_elim(true);
+
+    let report = optimization_report(q.clone())?;
+    let [state] = report.cache_states.as_slice() else {
+        panic!("expected a single cache id, got {report:?}");
+    };
+    assert_eq!(state.num_consumers, 2);
+    assert_eq!(
+        state.predicate_pushdown,
+        Some(CachePredicatePushdown::CacheNode)
+    );
+    assert!(!state.removed);
+
+    // Different predicates: the caches are removed.
+    let q = lf
+        .clone()
+        .filter(col("fats_g").gt(2.0))
+        .left_join(
+            lf.clone().filter(col("fats_g").gt(1.0)),
+            col("fats_g"),
+            col("fats_g"),
+        )
+        .with_comm_subplan_elim(true);
+
+    let report = optimization_report(q.clone())?;
+    let [state] = report.cache_states.as_slice() else {
+        panic!("expected a single cache id, got {report:?}");
+    };
+    assert_eq!(state.num_consumers, 2);
+    assert_eq!(state.predicate_pushdown, None);
+    assert!(state.removed);
+
+    // Without common subplan elimination there are no caches to report.
+    let report = optimization_report(q.with_comm_subplan_elim(false))?;
+    assert!(report.cache_states.is_empty());
+
+    // The caches of one cache id are removed, the caches of the other cache id are kept. The
+    // order in which cache ids are visited is random, so run this a few times to also cover the
+    // case where the removal happens first.
+    let lf2 = scan_foods_ipc().with_column(col("category").str().to_lowercase());
+    for _ in 0..10 {
+        let removed = lf.clone().filter(col("fats_g").gt(2.0)).left_join(
+            lf.clone().filter(col("fats_g").gt(1.0)),
+            col("fats_g"),
+            col("fats_g"),
+        );
+        let kept = lf2
+            .clone()
+            .inner_join(lf2.clone(), col("fats_g"), col("fats_g"))
+            .select([col("fats_g"), col("category_right").alias("kept")]);
+        let q = removed
+            .left_join(kept, col("fats_g"), col("fats_g"))
+            .with_comm_subplan_elim(true);
+
+        let report = optimization_report(q)?;
+        assert_eq!(
+            report
+                .cache_states
+                .iter()
+                .filter(|state| state.removed)
+                .count(),
+            1,
+            "{report:?}"
+        );
+        assert!(
+            report.cache_states.iter().any(|state| !state.removed),
+            "{report:?}"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_cse_cache_state_report_order() -> PolarsResult<()> {
+    let lf = scan_foods_ipc().with_column(col("category").str().to_uppercase());
+    let lf2 = scan_foods_ipc().with_column(col("category").str().to_lowercase());
+
+    // The caches of `lf` are removed, the caches of `lf2` are kept.
+    let removed = || {
+        lf.clone().filter(col("fats_g").gt(2.0)).left_join(
+            lf.clone().filter(col("fats_g").gt(1.0)),
+            col("fats_g"),
+            col("fats_g"),
+        )
+    };
+    let kept = || {
+        lf2.clone()
+            .inner_join(lf2.clone(), col("fats_g"), col("fats_g"))
+            .select([col("fats_g"), col("category_right").alias("kept")])
+    };
+
+    // The cache ids are random, the states are reported in the order the caches are found in
+    // the plan regardless. The inputs of the join are visited right to left.
+    let removed_states = |q: LazyFrame| -> PolarsResult<Vec<bool>> {
+        let report = optimization_report(q.with_comm_subplan_elim(true))?;
+        Ok(report
+            .cache_states
+            .iter()
+            .map(|state| state.removed)
+            .collect())
+    };
+    for _ in 0..10 {
+        let q = removed().left_join(kept(), col("fats_g"), col("fats_g"));
+        assert_eq!(removed_states(q)?, [false, true]);
+
+        let q = kept().left_join(removed(), col("fats_g"), col("fats_g"));
+        assert_eq!(removed_states(q)?, [true, false]);
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cross_join")]
+fn test_cse_cache_projection_through_slice() -> PolarsResult<()> {
+    let lf = scan_foods_ipc().with_column(col("category").str().to_uppercase());
+
+    // The slice above one of the caches must not hide the projection above it, otherwise the
+    // full schema is projected for both caches.
+    let q = lf
+        .clone()
+        .limit(5)
+        .select([col("fats_g")])
+        .cross_join(lf.select([col("sugars_g")]), None)
+        .with_comm_subplan_elim(true);
+
+    let report = optimization_report(q.clone())?;
+    let [state] = report.cache_states.as_slice() else {
+        panic!("expected a single cache id, got {report:?}");
+    };
+    assert_eq!(state.num_consumers, 2);
+    assert_eq!(state.projection_union_size, 2);
+
+    let IRPlan {
+        lp_top, lp_arena, ..
+    } = q.clone().to_alp_optimized()?;
+    for (_node, lp) in lp_arena.iter(lp_top) {
+        if let IR::Cache { input, .. } = lp {
+            let schema = lp_arena.get(*input).schema(&lp_arena);
+            let names = schema
+                .iter_names()
+                .map(|name| name.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(names, ["fats_g", "sugars_g"]);
+        }
+    }
+
+    let out = q.clone().collect()?;
+    let expected = q.with_comm_subplan_elim(false).collect()?;
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
@@ -0,0 +1,182 @@
+//! Sweep that runs expressions against zero-row inputs of every relevant dtype.
+//!
+//! An expression whose output schema resolves for an input dtype must also run on a zero-row input
+//! of that dtype: it must not panic or error, must produce zero rows and must produce the schema
+//! given by the schema resolver. Combinations that the schema resolver rejects are skipped, as the
+//! expression is not defined for that input dtype.
+use std::panic::AssertUnwindSafe;
+
+use super::*;
+
+type ExprConstructor = (&'static str, fn(Expr) -> Expr);
+
+fn empty_input_dtypes() -> Vec<DataType> {
+    vec![
+        DataType::Boolean,
+        DataType::Int32,
+        DataType::Int64,
+        DataType::UInt32,
+        DataType::Float64,
+        DataType::String,
+        DataType::List(Box::new(DataType::Int32)),
+        DataType::List(Box::new(DataType::Float64)),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(Box::new(DataType::Int32), 2),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(Box::new(DataType::Float64), 3),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(Box::new(DataType::Boolean), 2),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(Box::new(DataType::String), 2),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(Box::new(DataType::Int32), 0),
+    ]
+}
+
+fn engines() -> Vec<Engine> {
+    vec![
+        Engine::InMemory,
+        #[cfg(feature = "new_streaming")]
+        Engine::Streaming,
+    ]
+}
+
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Option<T> {
+    std::panic::catch_unwind(AssertUnwindSafe(f)).ok()
+}
+
+/// Run every expression in `exprs` on an empty column of every dtype in `dtypes`.
+fn check_empty_input(dtypes: &[DataType], exprs: &[ExprConstructor]) {
+    let mut failures = vec![];
+
+    for dtype in dtypes {
+        let empty = DataFrame::new(vec![Column::new_empty("a".into(), dtype)]).unwrap();
+
+        for (name, f) in exprs {
+            let mut lf = empty.clone().lazy().select([f(col("a"))]);
+            let case = format!("{name} on {dtype}");
+
+            let schema = match catch_panic(|| lf.collect_schema()) {
+                None => {
+                    failures.push(format!("{case}: schema resolution panicked"));
+                    continue;
+                },
+                // The expression is not defined for this dtype.
+                Some(Err(_)) => continue,
+                Some(Ok(schema)) => schema,
+            };
+
+            for engine in engines() {
+                let case = format!("{case} ({engine:?})");
+                match catch_panic(|| lf.clone().collect_with_engine(engine)) {
+                    None => failures.push(format!("{case}: panicked")),
+                    Some(Err(e)) => failures.push(format!("{case}: {e}")),
+                    Some(Ok(out)) => {
+                        if out.height() != 0 {
+                            failures.push(format!("{case}: got {} rows", out.height()));
+                        }
+                        if out.schema() != &schema {
+                            failures.push(format!(
+                                "{case}: got schema {:?}, expected {:?}",
+                                out.schema(),
+                                schema
+                            ));
+                        }
+                    },
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{failures:#?}");
+}
+
+#[test]
+#[cfg(feature = "rolling_window")]
+fn test_empty_input_rolling() {
+    fn opts() -> RollingOptionsFixedWindow {
+        RollingOptionsFixedWindow {
+            window_size: 2,
+            min_periods: 1,
+            ..Default::default()
+        }
+    }
+
+    check_empty_input(
+        &empty_input_dtypes(),
+        &[
+            ("rolling_min", |e| e.rolling_min(opts())),
+            ("rolling_max", |e| e.rolling_max(opts())),
+            ("rolling_sum", |e| e.rolling_sum(opts())),
+            ("rolling_mean", |e| e.rolling_mean(opts())),
+            ("rolling_median", |e| e.rolling_median(opts())),
+            ("rolling_var", |e| e.rolling_var(opts())),
+            ("rolling_std", |e| e.rolling_std(opts())),
+            #[cfg(feature = "moment")]
+            ("rolling_skew", |e| e.rolling_skew(opts())),
+            #[cfg(feature = "moment")]
+            ("rolling_kurtosis", |e| e.rolling_kurtosis(opts())),
+        ],
+    );
+}
+
+#[test]
+#[cfg(feature = "range")]
+fn test_empty_input_range() {
+    check_empty_input(
+        &empty_input_dtypes(),
+        &[
+            ("int_ranges", |e| {
+                int_ranges(e, lit(3), lit(1), DataType::Int64)
+            }),
+            ("int_ranges_step", |e| {
+                int_ranges(lit(0), lit(3), e, DataType::Int64)
+            }),
+            ("int_range_len", |e| {
+                int_range(lit(0), e.len(), 1, DataType::Int64)
+            }),
+        ],
+    );
+}
+
+#[test]
+#[cfg(feature = "dtype-array")]
+fn test_empty_input_arr() {
+    check_empty_input(
+        &empty_input_dtypes(),
+        &[
+            ("arr.len", |e| e.arr().len()),
+            ("arr.min", |e| e.arr().min()),
+            ("arr.max", |e| e.arr().max()),
+            ("arr.sum", |e| e.arr().sum()),
+            ("arr.mean", |e| e.arr().mean()),
+            ("arr.median", |e| e.arr().median()),
+            ("arr.std", |e| e.arr().std(1)),
+            ("arr.var", |e| e.arr().var(1)),
+            ("arr.unique", |e| e.arr().unique()),
+            ("arr.n_unique", |e| e.arr().n_unique()),
+            ("arr.to_list", |e| e.arr().to_list()),
+            #[cfg(feature = "array_any_all")]
+            ("arr.any", |e| e.arr().any()),
+            #[cfg(feature = "array_any_all")]
+            ("arr.all", |e| e.arr().all()),
+            ("arr.sort", |e| e.arr().sort(SortOptions::default())),
+            ("arr.reverse", |e| e.arr().reverse()),
+            ("arr.arg_min", |e| e.arr().arg_min()),
+            ("arr.arg_max", |e| e.arr().arg_max()),
+            ("arr.get", |e| e.arr().get(lit(0), true)),
+            ("arr.join", |e| e.arr().join(lit(","), true)),
+            #[cfg(feature = "is_in")]
+            ("arr.contains", |e| e.arr().contains(lit(1), true)),
+            #[cfg(feature = "array_count")]
+            ("arr.count_matches", |e| e.arr().count_matches(lit(1))),
+            ("arr.shift", |e| e.arr().shift(lit(1))),
+            ("arr.explode", |e| {
+                e.arr().explode(ExplodeOptions {
+                    empty_as_null: true,
+                    keep_nulls: true,
+                })
+            }),
+        ],
+    );
+}
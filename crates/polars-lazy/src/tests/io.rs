@@ -358,6 +358,41 @@ fn test_parquet_statistics() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_column_statistics_in_ir() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let IRPlan {
+        lp_top, lp_arena, ..
+    } = scan_foods_parquet(false).to_alp()?;
+    let scan = lp_arena.get(lp_top);
+    assert!(matches!(scan, IR::Scan { .. }));
+
+    let stats = scan.scan_column_statistics("calories").unwrap();
+    assert_eq!(stats.null_count, Some(0));
+    let min = stats.min_value.as_ref().unwrap().value().extract::<i64>();
+    let max = stats.max_value.as_ref().unwrap().value().extract::<i64>();
+    assert_eq!((min, max), (Some(20), Some(200)));
+
+    assert!(scan.scan_column_statistics("does_not_exist").is_none());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_csv_column_statistics_in_ir() -> PolarsResult<()> {
+    let IRPlan {
+        lp_top, lp_arena, ..
+    } = scan_foods_csv().to_alp()?;
+    assert!(
+        lp_arena
+            .get(lp_top)
+            .scan_column_statistics("calories")
+            .is_none()
+    );
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_parquet_globbing() -> PolarsResult<()> {
@@ -2,6 +2,8 @@ mod aggregations;
 mod arity;
 #[cfg(all(feature = "strings", feature = "cse"))]
 mod cse;
+#[cfg(any(feature = "rolling_window", feature = "range", feature = "dtype-array"))]
+mod empty_input;
 #[cfg(feature = "parquet")]
 mod io;
 mod logical;
@@ -12,6 +14,8 @@ mod predicate_queries;
 mod projection_queries;
 mod queries;
 mod schema;
+#[cfg(feature = "tracing")]
+mod tracing_spans;
 
 fn get_arenas() -> (Arena<AExpr>, Arena<IR>) {
     let expr_arena = Arena::with_capacity(16);
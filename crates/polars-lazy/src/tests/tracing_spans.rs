@@ -0,0 +1,69 @@
+use std::sync::{Arc, Mutex, OnceLock};
+
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+use super::*;
+
+/// Collects the names of all spans that are created.
+#[derive(Clone, Default)]
+struct SpanNames(Arc<Mutex<Vec<&'static str>>>);
+
+impl Subscriber for SpanNames {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut names = self.0.lock().unwrap();
+        names.push(span.metadata().name());
+        Id::from_u64(names.len() as u64)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+/// The row encoding may run on the thread pool, so the subscriber is installed globally.
+fn span_names() -> &'static SpanNames {
+    static SPAN_NAMES: OnceLock<SpanNames> = OnceLock::new();
+    SPAN_NAMES.get_or_init(|| {
+        let subscriber = SpanNames::default();
+        tracing::subscriber::set_global_default(subscriber.clone()).unwrap();
+        subscriber
+    })
+}
+
+#[test]
+fn test_tracing_spans() -> PolarsResult<()> {
+    let span_names = span_names();
+
+    let out = load_df()
+        .lazy()
+        // Sorting by multiple columns with nulls last row encodes the sort keys.
+        .sort(
+            ["b", "a"],
+            SortMultipleOptions::default().with_nulls_last(true),
+        )
+        .collect()?;
+    assert_eq!(out.height(), 5);
+
+    let names = span_names.0.lock().unwrap();
+    for name in [
+        "projection_predicate_pushdown",
+        "slice_pushdown",
+        "optimize_loop",
+        "expand_datasets",
+        "convert_columns_amortized",
+    ] {
+        assert!(names.contains(&name), "missing span {name}: {names:?}");
+    }
+    Ok(())
+}
@@ -69,13 +69,14 @@ impl FilterExec {
 
     fn execute_impl(
         &mut self,
-        mut df: DataFrame,
+        df: DataFrame,
         state: &mut ExecutionState,
     ) -> PolarsResult<DataFrame> {
         let n_partitions = POOL.current_num_threads();
         // Vertical parallelism.
         if self.streamable && df.height() > 0 {
             if df.first_col_n_chunks() > 1 {
+                let mut df = df.rechunk_to_chunks(n_partitions);
                 let chunks = df.split_chunks().collect::<Vec<_>>();
                 self.execute_chunks(chunks, state)
             } else if df.width() < n_partitions {
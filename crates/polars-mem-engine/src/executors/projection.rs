@@ -28,6 +28,7 @@ impl ProjectionExec {
             && df.height() > POOL.current_num_threads() * 2
             && self.options.run_parallel
         {
+            let mut df = df.rechunk_to_chunks(POOL.current_num_threads());
             let chunks = df.split_chunks().collect::<Vec<_>>();
             let iter = chunks.into_par_iter().map(|mut df| {
                 let selected_cols = evaluate_physical_expressions(
@@ -28,6 +28,7 @@ impl StackExec {
             && df.height() > 0
             && self.options.run_parallel
         {
+            let mut df = df.rechunk_to_chunks(POOL.current_num_threads());
             let chunks = df.split_chunks().collect::<Vec<_>>();
             let iter = chunks.into_par_iter().map(|mut df| {
                 let res = evaluate_physical_expressions(
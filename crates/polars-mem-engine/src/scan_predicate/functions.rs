@@ -403,6 +403,7 @@ where
                 schema: _,
                 reader_schema,
                 row_estimation,
+                column_statistics,
             },
         hive_parts,
         predicate: _,
@@ -457,6 +458,7 @@ where
 
     if selected_path_indices.clone().next() != Some(0) {
         *reader_schema = None;
+        *column_statistics = None;
 
         // Ensure the metadata is unset, otherwise it may incorrectly be used at
         // scan. This is especially important for Parquet as it requires the
@@ -25,9 +25,12 @@ where
     }
 
     let len = arr.size();
-    let iter = (0..values.len()).step_by(len).map(|start| {
+    let iter = (0..arr.len()).map(|i| i * len).map(|start| {
         // SAFETY: start + len is in bound guarded by invariant of FixedSizeListArray
-        let val = unsafe { values.clone().sliced_unchecked(start, len) };
+        let val = maybe_unchecked!(
+            values.clone().sliced(start, len),
+            values.clone().sliced_unchecked(start, len),
+        );
         op(&val)
     });
 
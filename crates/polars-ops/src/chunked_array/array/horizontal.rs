@@ -0,0 +1,165 @@
+use arrow::array::{Array, FixedSizeListArray, PrimitiveArray};
+use arrow::types::NativeType;
+use num_traits::{NumCast, ToPrimitive};
+use polars_compute::min_max::MinMaxKernel;
+use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::float16::pf16;
+
+use super::min_max::AggType;
+use crate::chunked_array::sum::sum_slice;
+
+/// Aggregate the values of every row of `arr` with `agg`, directly on the flat values.
+///
+/// Null values are skipped and null rows aggregate to null.
+fn agg_rows<T, S>(arr: &FixedSizeListArray, agg: impl Fn(&[T]) -> Option<S>) -> PrimitiveArray<S>
+where
+    T: NativeType,
+    S: NativeType,
+{
+    let width = arr.size();
+    let values = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap();
+    let inner_validity = values
+        .validity()
+        .filter(|validity| validity.unset_bits() > 0);
+    let values = values.values().as_slice();
+
+    let mut scratch = Vec::new();
+    (0..arr.len())
+        .map(|i| {
+            if !arr.is_valid(i) {
+                return None;
+            }
+            let start = i * width;
+            // SAFETY: the values of a FixedSizeListArray contain `width` values for every row.
+            let row = maybe_unchecked!(
+                &values[start..start + width],
+                values.get_unchecked(start..start + width),
+            );
+            match inner_validity {
+                None => agg(row),
+                Some(validity) => {
+                    scratch.clear();
+                    scratch.extend(
+                        row.iter()
+                            .enumerate()
+                            .filter(|(j, _)| validity.get_bit(start + j))
+                            .map(|(_, v)| *v),
+                    );
+                    agg(&scratch)
+                },
+            }
+        })
+        .collect_arr()
+}
+
+fn map_chunks<S: NativeType>(
+    ca: &ArrayChunked,
+    f: impl Fn(&FixedSizeListArray) -> PrimitiveArray<S>,
+) -> Series {
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| f(arr).boxed())
+        .collect::<Vec<_>>();
+    Series::try_from((ca.name().clone(), chunks)).unwrap()
+}
+
+fn sum_rows<T, S>(ca: &ArrayChunked) -> Series
+where
+    T: NativeType + ToPrimitive,
+    S: NativeType + NumCast + std::iter::Sum,
+{
+    map_chunks(ca, |arr| {
+        agg_rows::<T, S>(arr, |row| Some(sum_slice::<T, S>(row)))
+    })
+}
+
+fn mean_rows<T, S>(ca: &ArrayChunked, to_out: impl Fn(f64) -> S) -> Series
+where
+    T: NativeType + ToPrimitive,
+    S: NativeType,
+{
+    map_chunks(ca, |arr| {
+        agg_rows::<T, S>(arr, |row| {
+            (!row.is_empty()).then(|| to_out(sum_slice::<T, f64>(row) / row.len() as f64))
+        })
+    })
+}
+
+/// Collapse all levels of nesting of `ca`, so that every row holds all of its leaf values.
+///
+/// A leaf value becomes null if any of the arrays it is nested in is null, see
+/// [`ArrayChunked::flatten_one_level`]. The leaf values themselves are not copied.
+pub(super) fn flatten_to_leaf(ca: &ArrayChunked) -> PolarsResult<ArrayChunked> {
+    let mut ca = ca.clone();
+    while ca.inner_dtype().is_array() {
+        ca = ca.flatten_one_level()?;
+    }
+    Ok(ca)
+}
+
+pub(super) fn sum_horizontal(ca: &ArrayChunked) -> PolarsResult<Series> {
+    use DataType::*;
+    Ok(match ca.inner_dtype() {
+        Int8 => sum_rows::<i8, i64>(ca),
+        Int16 => sum_rows::<i16, i64>(ca),
+        Int32 => sum_rows::<i32, i32>(ca),
+        Int64 => sum_rows::<i64, i64>(ca),
+        Int128 => sum_rows::<i128, i128>(ca),
+        UInt8 => sum_rows::<u8, i64>(ca),
+        UInt16 => sum_rows::<u16, i64>(ca),
+        UInt32 => sum_rows::<u32, u32>(ca),
+        UInt64 => sum_rows::<u64, u64>(ca),
+        UInt128 => sum_rows::<u128, u128>(ca),
+        Float16 => sum_rows::<pf16, pf16>(ca),
+        Float32 => sum_rows::<f32, f32>(ca),
+        Float64 => sum_rows::<f64, f64>(ca),
+        dt => polars_bail!(InvalidOperation: "`sum_horizontal` not supported for arrays of {}", dt),
+    })
+}
+
+pub(super) fn mean_horizontal(ca: &ArrayChunked) -> PolarsResult<Series> {
+    use DataType::*;
+    Ok(match ca.inner_dtype() {
+        Int8 => mean_rows::<i8, f64>(ca, |v| v),
+        Int16 => mean_rows::<i16, f64>(ca, |v| v),
+        Int32 => mean_rows::<i32, f64>(ca, |v| v),
+        Int64 => mean_rows::<i64, f64>(ca, |v| v),
+        Int128 => mean_rows::<i128, f64>(ca, |v| v),
+        UInt8 => mean_rows::<u8, f64>(ca, |v| v),
+        UInt16 => mean_rows::<u16, f64>(ca, |v| v),
+        UInt32 => mean_rows::<u32, f64>(ca, |v| v),
+        UInt64 => mean_rows::<u64, f64>(ca, |v| v),
+        UInt128 => mean_rows::<u128, f64>(ca, |v| v),
+        Float16 => mean_rows::<pf16, pf16>(ca, |v| NumCast::from(v).unwrap()),
+        Float32 => mean_rows::<f32, f32>(ca, |v| v as f32),
+        Float64 => mean_rows::<f64, f64>(ca, |v| v),
+        dt => {
+            polars_bail!(InvalidOperation: "`mean_horizontal` not supported for arrays of {}", dt)
+        },
+    })
+}
+
+pub(super) fn min_max_horizontal(ca: &ArrayChunked, agg_type: AggType) -> PolarsResult<Series> {
+    let inner_dtype = ca.inner_dtype();
+    let physical = inner_dtype.to_physical();
+    polars_ensure!(
+        physical.is_primitive_numeric(),
+        InvalidOperation: "`min_horizontal` and `max_horizontal` not supported for arrays of {}",
+        inner_dtype
+    );
+
+    let out = with_match_physical_numeric_polars_type!(physical, |$T| {
+        type T = <$T as PolarsNumericType>::Native;
+        map_chunks(ca, |arr| match agg_type {
+            AggType::Min => agg_rows::<T, T>(arr, MinMaxKernel::min_ignore_nan_kernel),
+            AggType::Max => agg_rows::<T, T>(arr, MinMaxKernel::max_ignore_nan_kernel),
+        })
+    });
+    // SAFETY: The minimum and maximum are values of the inner physical dtype.
+    unsafe { out.from_physical_unchecked(inner_dtype) }
+}
@@ -27,7 +27,10 @@ where
             .map(|start| {
                 // SAFETY: This value array from a FixedSizeListArray,
                 // we can ensure that `start + width` will not out out range
-                let sliced = unsafe { values.clone().sliced_unchecked(start, width) };
+                let sliced = maybe_unchecked!(
+                    values.clone().sliced(start, width),
+                    values.clone().sliced_unchecked(start, width),
+                );
                 arr_agg(&sliced)
             })
             .collect_arr()
@@ -1,17 +1,37 @@
+/// Evaluate `$unchecked` in an unsafe block, or the equivalent bounds checked `$checked` with the
+/// `checked-kernels` feature.
+macro_rules! maybe_unchecked {
+    ($checked:expr, $unchecked:expr $(,)?) => {{
+        #[cfg(feature = "checked-kernels")]
+        {
+            $checked
+        }
+        #[cfg(not(feature = "checked-kernels"))]
+        {
+            unsafe { $unchecked }
+        }
+    }};
+}
+
 #[cfg(feature = "array_any_all")]
 mod any_all;
 mod count;
 mod dispersion;
 mod get;
+mod horizontal;
 mod join;
 mod min_max;
 mod namespace;
+mod scatter;
+mod sorted_rows;
 mod sum_mean;
 #[cfg(feature = "array_to_struct")]
 mod to_struct;
 
 pub use namespace::ArrayNameSpace;
 use polars_core::prelude::*;
+pub use scatter::{array_scatter, array_set_at_idx_masked};
+pub use sorted_rows::SortedRowsOptions;
 #[cfg(feature = "array_to_struct")]
 pub use to_struct::*;
 
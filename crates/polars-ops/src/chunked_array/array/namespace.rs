@@ -14,7 +14,11 @@ use crate::chunked_array::array::sum_mean::sum_with_nulls;
 use crate::prelude::array::any_all::{array_all, array_any};
 use crate::prelude::array::get::array_get;
 use crate::prelude::array::join::array_join;
-use crate::prelude::array::sum_mean::sum_array_numerical;
+#[cfg(feature = "array_count")]
+use crate::prelude::array::sorted_rows::array_count_matches_sorted;
+use crate::prelude::array::sorted_rows::{
+    SortedRowsOptions, array_contains_sorted, array_rows_are_sorted,
+};
 use crate::series::ArgAgg;
 
 pub fn has_inner_nulls(ca: &ArrayChunked) -> bool {
@@ -27,8 +31,15 @@ pub fn has_inner_nulls(ca: &ArrayChunked) -> bool {
 }
 
 fn get_agg(ca: &ArrayChunked, agg_type: AggType) -> Series {
+    if ca.inner_dtype().to_physical().is_primitive_numeric() {
+        return horizontal::min_max_horizontal(ca, agg_type).unwrap();
+    }
+
     let values = ca.get_inner();
     let width = ca.width();
+    if width == 0 {
+        return Series::full_null(ca.name().clone(), ca.len(), values.dtype());
+    }
     min_max::array_dispatch(ca.name().clone(), &values, width, agg_type)
 }
 
@@ -46,22 +57,104 @@ pub trait ArrayNameSpace: AsArray {
     fn array_sum(&self) -> PolarsResult<Series> {
         let ca = self.as_array();
 
-        if has_inner_nulls(ca) {
-            return sum_with_nulls(ca, ca.inner_dtype());
-        };
-
         match ca.inner_dtype() {
-            DataType::Boolean => Ok(count_boolean_bits(ca).into_series()),
-            dt if dt.is_primitive_numeric() => Ok(sum_array_numerical(ca, dt)),
+            DataType::Boolean if !has_inner_nulls(ca) => Ok(count_boolean_bits(ca).into_series()),
+            dt if dt.is_primitive_numeric() => self.sum_horizontal(),
             dt => sum_with_nulls(ca, dt),
         }
     }
 
     fn array_mean(&self) -> PolarsResult<Series> {
         let ca = self.as_array();
+        if ca.inner_dtype().is_primitive_numeric() {
+            return self.mean_horizontal();
+        }
         dispersion::mean_with_nulls(ca)
     }
 
+    /// Sum the values of every array, skipping null values.
+    ///
+    /// The sums of small integers are `Int64`. Empty arrays sum to zero and null arrays to null.
+    fn sum_horizontal(&self) -> PolarsResult<Series> {
+        horizontal::sum_horizontal(self.as_array())
+    }
+
+    /// The mean of the values of every array, skipping null values.
+    ///
+    /// The means of integers are `Float64`. Arrays without valid values have a null mean.
+    fn mean_horizontal(&self) -> PolarsResult<Series> {
+        horizontal::mean_horizontal(self.as_array())
+    }
+
+    /// The minimum of the values of every array, skipping null and NaN values.
+    fn min_horizontal(&self) -> PolarsResult<Series> {
+        horizontal::min_max_horizontal(self.as_array(), AggType::Min)
+    }
+
+    /// The maximum of the values of every array, skipping null and NaN values.
+    fn max_horizontal(&self) -> PolarsResult<Series> {
+        horizontal::min_max_horizontal(self.as_array(), AggType::Max)
+    }
+
+    /// Sum all leaf values of every (nested) array, like [`ArrayNameSpace::sum_horizontal`].
+    ///
+    /// Values of null inner arrays are skipped, null outer arrays sum to null.
+    fn array_leaf_sum(&self) -> PolarsResult<Series> {
+        horizontal::sum_horizontal(&horizontal::flatten_to_leaf(self.as_array())?)
+    }
+
+    /// The mean of all leaf values of every (nested) array, like
+    /// [`ArrayNameSpace::mean_horizontal`].
+    fn array_leaf_mean(&self) -> PolarsResult<Series> {
+        horizontal::mean_horizontal(&horizontal::flatten_to_leaf(self.as_array())?)
+    }
+
+    /// The minimum of all leaf values of every (nested) array, like
+    /// [`ArrayNameSpace::min_horizontal`].
+    fn array_leaf_min(&self) -> PolarsResult<Series> {
+        let ca = horizontal::flatten_to_leaf(self.as_array())?;
+        horizontal::min_max_horizontal(&ca, AggType::Min)
+    }
+
+    /// The maximum of all leaf values of every (nested) array, like
+    /// [`ArrayNameSpace::max_horizontal`].
+    fn array_leaf_max(&self) -> PolarsResult<Series> {
+        let ca = horizontal::flatten_to_leaf(self.as_array())?;
+        horizontal::min_max_horizontal(&ca, AggType::Max)
+    }
+
+    /// The cumulative sum within every array, see [`cum_sum`](crate::series::cum_sum).
+    #[cfg(feature = "cum_agg")]
+    fn array_cum_sum(&self, reverse: bool) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        let width = ca.width();
+        ca.apply_to_inner(&|s| crate::series::cum_sum_segments(&s, width, reverse))
+    }
+
+    /// The cumulative product within every array, see [`cum_prod`](crate::series::cum_prod).
+    #[cfg(feature = "cum_agg")]
+    fn array_cum_prod(&self, reverse: bool) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        let width = ca.width();
+        ca.apply_to_inner(&|s| crate::series::cum_prod_segments(&s, width, reverse))
+    }
+
+    /// The cumulative minimum within every array, see [`cum_min`](crate::series::cum_min).
+    #[cfg(feature = "cum_agg")]
+    fn array_cum_min(&self, reverse: bool) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        let width = ca.width();
+        ca.apply_to_inner(&|s| crate::series::cum_min_segments(&s, width, reverse))
+    }
+
+    /// The cumulative maximum within every array, see [`cum_max`](crate::series::cum_max).
+    #[cfg(feature = "cum_agg")]
+    fn array_cum_max(&self, reverse: bool) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        let width = ca.width();
+        ca.apply_to_inner(&|s| crate::series::cum_max_segments(&s, width, reverse))
+    }
+
     fn array_median(&self) -> PolarsResult<Series> {
         let ca = self.as_array();
         dispersion::median_with_nulls(ca)
@@ -149,6 +242,42 @@ pub trait ArrayNameSpace: AsArray {
         array_count_matches(ca, element)
     }
 
+    /// Count the values equal to `element` in every row, binary searching the rows as described
+    /// by `options`.
+    ///
+    /// Returns `None` if the rows can't be binary searched for `element`, e.g. because the inner
+    /// dtype isn't numeric.
+    #[cfg(feature = "array_count")]
+    fn array_count_matches_sorted(
+        &self,
+        element: &Series,
+        options: SortedRowsOptions,
+    ) -> PolarsResult<Option<IdxCa>> {
+        let ca = self.as_array();
+        array_count_matches_sorted(ca, element, options)
+    }
+
+    /// Get whether every row contains `item`, binary searching the rows as described by
+    /// `options`. `item` has length 1 or the same length as the array.
+    ///
+    /// Returns `None` if the rows can't be binary searched for `item`, e.g. because the inner
+    /// dtype isn't numeric.
+    fn array_contains_sorted(
+        &self,
+        item: &Series,
+        nulls_equal: bool,
+        options: SortedRowsOptions,
+    ) -> PolarsResult<Option<BooleanChunked>> {
+        let ca = self.as_array();
+        array_contains_sorted(ca, item, nulls_equal, options)
+    }
+
+    /// Get whether the values in every row are sorted. Rows that contain nulls are not sorted.
+    fn array_rows_are_sorted(&self, descending: bool) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_array();
+        array_rows_are_sorted(ca, descending)
+    }
+
     fn array_shift(&self, n: &Series) -> PolarsResult<Series> {
         let ca = self.as_array();
         let n_s = n.cast(&DataType::Int64)?;
@@ -0,0 +1,270 @@
+use arrow::bitmap::MutableBitmap;
+use polars_core::with_match_physical_numeric_polars_type;
+
+use super::*;
+use crate::chunked_array::ChunkedSet;
+
+/// Set the element at `index` of every array to the value of that row in `values`.
+///
+/// `index` and `values` either have a value per row or a single value for all rows. Negative
+/// indices count from the end of the arrays. Rows with a null index are left unchanged, as are
+/// rows with an out of bounds index if `skip_oob` is set. Otherwise an out of bounds index raises.
+///
+/// The values of `ca` are updated in place if they aren't shared, otherwise they are copied.
+pub fn array_scatter(
+    ca: ArrayChunked,
+    index: &Int64Chunked,
+    values: &Series,
+    skip_oob: bool,
+) -> PolarsResult<ArrayChunked> {
+    let width = ca.width();
+    ensure_total_len(&ca, "arr.scatter")?;
+    ensure_row_len("arr.scatter", "index", index.len(), ca.len())?;
+    ensure_row_len("arr.scatter", "values", values.len(), ca.len())?;
+
+    let index = if index.len() == 1 && ca.len() != 1 {
+        index.new_from_index(0, ca.len())
+    } else {
+        index.clone()
+    };
+    let mut positions = Vec::new();
+    let mut value_rows = Vec::new();
+    for (row, idx) in index.iter().enumerate() {
+        let Some(idx) = idx else {
+            continue;
+        };
+        let normalized = if idx < 0 { idx + width as i64 } else { idx };
+        if !(0..width as i64).contains(&normalized) {
+            polars_ensure!(
+                skip_oob,
+                OutOfBounds: "`arr.scatter` index {} is out of bounds for arrays of width {}",
+                idx, width
+            );
+            continue;
+        }
+        positions.push((row * width) as IdxSize + normalized as IdxSize);
+        value_rows.push(row as IdxSize);
+    }
+
+    scatter_inner(ca, &positions, &value_rows, values)
+}
+
+/// Set the elements of every array where `mask` is `true` to the value of that row in `values`.
+///
+/// `mask` is a boolean array column of the same width, and `mask` and `values` either have a
+/// value per row or a single value for all rows. Null masks and null elements of the mask leave
+/// the elements unchanged.
+///
+/// The values of `ca` are updated in place if they aren't shared, otherwise they are copied.
+pub fn array_set_at_idx_masked(
+    ca: ArrayChunked,
+    mask: &ArrayChunked,
+    values: &Series,
+) -> PolarsResult<ArrayChunked> {
+    let width = ca.width();
+    ensure_total_len(&ca, "arr.set_at_idx_masked")?;
+    ensure_row_len("arr.set_at_idx_masked", "mask", mask.len(), ca.len())?;
+    ensure_row_len("arr.set_at_idx_masked", "values", values.len(), ca.len())?;
+    polars_ensure!(
+        mask.inner_dtype().is_bool(),
+        InvalidOperation: "`arr.set_at_idx_masked` mask must be an array of booleans, got: {}",
+        mask.dtype()
+    );
+    polars_ensure!(
+        mask.width() == width,
+        ShapeMismatch: "`arr.set_at_idx_masked` mask has width {} while the arrays have width {}",
+        mask.width(), width
+    );
+
+    let mask = if mask.len() == 1 && ca.len() != 1 {
+        mask.new_from_index(0, ca.len())
+    } else {
+        mask.rechunk().into_owned()
+    };
+    let mask_validity = mask.rechunk_validity();
+    let mask_values = mask.get_inner();
+    let mut positions = Vec::new();
+    let mut value_rows = Vec::new();
+    for (i, set) in mask_values.bool()?.iter().enumerate() {
+        let row = i / width;
+        if set == Some(true) && mask_validity.as_ref().is_none_or(|v| v.get_bit(row)) {
+            positions.push(i as IdxSize);
+            value_rows.push(row as IdxSize);
+        }
+    }
+
+    scatter_inner(ca, &positions, &value_rows, values)
+}
+
+fn ensure_total_len(ca: &ArrayChunked, op: &str) -> PolarsResult<()> {
+    let total_len = ca.len() * ca.width();
+    IdxSize::try_from(total_len).map_err(|_| polars_err!(bigidx, ctx = op, size = total_len))?;
+    Ok(())
+}
+
+fn ensure_row_len(op: &str, name: &str, len: usize, num_rows: usize) -> PolarsResult<()> {
+    polars_ensure!(
+        len == 1 || len == num_rows,
+        ShapeMismatch: "`{}` got {} of length {} while the arrays have length {}",
+        op, name, len, num_rows
+    );
+    Ok(())
+}
+
+/// Write the values of `value_rows` in `values` to the flat `positions` in the values of `ca`.
+fn scatter_inner(
+    ca: ArrayChunked,
+    positions: &[IdxSize],
+    value_rows: &[IdxSize],
+    values: &Series,
+) -> PolarsResult<ArrayChunked> {
+    let name = ca.name().clone();
+    let inner_dtype = ca.inner_dtype().clone();
+    let (width, len) = (ca.width(), ca.len());
+
+    let values = values.strict_cast(&inner_dtype)?;
+    let values = if values.len() == 1 {
+        values.new_from_index(0, value_rows.len())
+    } else {
+        values.take_slice(value_rows)?
+    };
+    let values = values.to_physical_repr().into_owned();
+
+    // Let go of `ca` before mutating its values, so that they can be updated in place if they
+    // aren't shared with anything else.
+    let mut ca = ca;
+    ca.rechunk_mut();
+    let validity = ca.rechunk_validity();
+    let inner = ca.get_inner();
+    drop(ca);
+    let mut phys = inner.to_physical_repr().into_owned();
+    drop(inner);
+
+    let out = match phys.dtype() {
+        dt if dt.is_primitive_numeric() => {
+            with_match_physical_numeric_polars_type!(dt, |$T| {
+                let ca: &mut ChunkedArray<$T> = phys._get_inner_mut().as_mut();
+                let values: &ChunkedArray<$T> = values.as_ref().as_ref();
+                ca.scatter(positions, values)?
+            })
+        },
+        DataType::Boolean => {
+            let ca: &mut BooleanChunked = phys._get_inner_mut().as_mut();
+            ca.scatter(positions, values.bool()?)?
+        },
+        DataType::Binary => {
+            let ca: &mut BinaryChunked = phys._get_inner_mut().as_mut();
+            ca.scatter(positions, values.binary()?)?
+        },
+        DataType::String => {
+            let ca: &mut StringChunked = phys._get_inner_mut().as_mut();
+            ca.scatter(positions, values.str()?)?
+        },
+        _ => {
+            // Other dtypes can't be updated in place, so the new values are selected instead.
+            let mut mask = MutableBitmap::from_len_zeroed(phys.len());
+            let mut take = vec![None; phys.len()];
+            for (i, &position) in positions.iter().enumerate() {
+                mask.set(position as usize, true);
+                take[position as usize] = Some(i as IdxSize);
+            }
+            let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, mask.freeze());
+            let take = IdxCa::from_slice_options(PlSmallStr::EMPTY, &take);
+            values.take(&take)?.zip_with(&mask, &phys)?
+        },
+    };
+    let out = unsafe { out.from_physical_unchecked(&inner_dtype)? };
+
+    let mut out =
+        ArrayChunked::from_aligned_values(name, &inner_dtype, width, out.chunks().clone(), len);
+    if let Some(validity) = validity {
+        out.set_validity(&validity);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn int_arrays(values: &[Option<i64>], width: usize) -> ArrayChunked {
+        let values = Int64Chunked::from_slice_options(PlSmallStr::EMPTY, values);
+        ArrayChunked::from_aligned_values(
+            PlSmallStr::from_static("a"),
+            &DataType::Int64,
+            width,
+            values.chunks().clone(),
+            values.len() / width,
+        )
+    }
+
+    fn assert_arrays_eq(left: &ArrayChunked, right: &ArrayChunked) {
+        assert!(
+            left.clone()
+                .into_series()
+                .equals_missing(&right.clone().into_series())
+        );
+    }
+
+    fn values_ptr(ca: &ArrayChunked) -> *const i64 {
+        let inner = ca.get_inner();
+        inner.i64().unwrap().cont_slice().unwrap().as_ptr()
+    }
+
+    #[test]
+    fn test_array_scatter_in_place() {
+        let ca = int_arrays(&[Some(1), Some(2), Some(3), Some(4), Some(5), Some(6)], 3);
+        let ptr = values_ptr(&ca);
+
+        let index = Int64Chunked::from_slice(PlSmallStr::EMPTY, &[-1]);
+        let values = Series::new(PlSmallStr::EMPTY, &[0i64]);
+        let out = array_scatter(ca, &index, &values, false).unwrap();
+        assert_eq!(values_ptr(&out), ptr);
+        assert_arrays_eq(
+            &out,
+            &int_arrays(&[Some(1), Some(2), Some(0), Some(4), Some(5), Some(0)], 3),
+        );
+    }
+
+    #[test]
+    fn test_array_scatter_copies_shared_values() {
+        let ca = int_arrays(&[Some(1), Some(2), Some(3), Some(4), Some(5), Some(6)], 3);
+        let shared = ca.clone();
+
+        let index = Int64Chunked::from_slice_options(PlSmallStr::EMPTY, &[Some(0), None]);
+        let values = Series::new(PlSmallStr::EMPTY, &[Some(0i64), Some(7)]);
+        let out = array_scatter(ca, &index, &values, false).unwrap();
+        assert_ne!(values_ptr(&out), values_ptr(&shared));
+        assert_arrays_eq(
+            &out,
+            &int_arrays(&[Some(0), Some(2), Some(3), Some(4), Some(5), Some(6)], 3),
+        );
+        assert_arrays_eq(
+            &shared,
+            &int_arrays(&[Some(1), Some(2), Some(3), Some(4), Some(5), Some(6)], 3),
+        );
+    }
+
+    #[test]
+    fn test_array_scatter_validity() {
+        let ca = int_arrays(&[Some(1), None, Some(3), Some(4)], 2);
+
+        // Writing a null over a value and a value over a null.
+        let index = Int64Chunked::from_slice(PlSmallStr::EMPTY, &[1, 0]);
+        let values = Series::new(PlSmallStr::EMPTY, &[Some(2i64), None]);
+        let out = array_scatter(ca, &index, &values, false).unwrap();
+        assert_arrays_eq(&out, &int_arrays(&[Some(1), Some(2), None, Some(4)], 2));
+        assert_eq!(out.get_inner().null_count(), 1);
+    }
+
+    #[test]
+    fn test_array_scatter_out_of_bounds() {
+        let ca = int_arrays(&[Some(1), Some(2), Some(3), Some(4)], 2);
+        let index = Int64Chunked::from_slice(PlSmallStr::EMPTY, &[2, -1]);
+        let values = Series::new(PlSmallStr::EMPTY, &[0i64]);
+
+        assert!(array_scatter(ca.clone(), &index, &values, false).is_err());
+        let out = array_scatter(ca, &index, &values, true).unwrap();
+        assert_arrays_eq(&out, &int_arrays(&[Some(1), Some(2), Some(3), Some(0)], 2));
+    }
+}
@@ -0,0 +1,331 @@
+use std::ops::Range;
+
+use arrow::array::{Array, PrimitiveArray};
+use arrow::bitmap::bitmask::BitMask;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::total_ord::{TotalEq, TotalOrd};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Options for array kernels that may assume that the values in every row are sorted, so that
+/// they can binary search the rows instead of scanning them.
+///
+/// This is only used for primitive numeric inner dtypes, other dtypes always scan the rows.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct SortedRowsOptions {
+    /// Whether the rows are sorted in descending instead of ascending order.
+    pub descending: bool,
+    /// Check that every row is sorted, and scan the rows that aren't.
+    ///
+    /// If `false`, rows that are not sorted give wrong results.
+    pub validate: bool,
+}
+
+fn is_sorted<T: TotalOrd>(values: &[T], descending: bool) -> bool {
+    if descending {
+        values.windows(2).all(|w| w[0].tot_ge(&w[1]))
+    } else {
+        values.windows(2).all(|w| w[0].tot_le(&w[1]))
+    }
+}
+
+/// Get the range of sorted `values` that is equal to `value`.
+fn equal_range<T: TotalOrd>(values: &[T], value: &T, descending: bool) -> Range<usize> {
+    if descending {
+        values.partition_point(|v| v.tot_gt(value))..values.partition_point(|v| v.tot_ge(value))
+    } else {
+        values.partition_point(|v| v.tot_lt(value))..values.partition_point(|v| v.tot_le(value))
+    }
+}
+
+/// Apply `f` to the index, values and, if the row contains nulls, the validity of the values of
+/// every valid row. Null rows are `None`.
+fn map_rows<T, O, F>(ca: &ArrayChunked, mut f: F) -> Vec<Option<O>>
+where
+    T: PolarsNumericType,
+    F: FnMut(usize, &[T::Native], Option<BitMask<'_>>) -> Option<O>,
+{
+    let width = ca.width();
+    let mut out = Vec::with_capacity(ca.len());
+    for arr in ca.downcast_iter() {
+        let values = arr
+            .values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T::Native>>()
+            .unwrap();
+        let validity = values
+            .validity()
+            .filter(|validity| validity.unset_bits() > 0)
+            .map(BitMask::from_bitmap);
+
+        for i in 0..arr.len() {
+            let row_idx = out.len();
+            if !arr.is_valid(i) {
+                out.push(None);
+                continue;
+            }
+            let start = i * width;
+            let row_validity = validity
+                .map(|validity| validity.sliced(start, width))
+                .filter(|validity| validity.unset_bits() > 0);
+            out.push(f(
+                row_idx,
+                &values.values()[start..start + width],
+                row_validity,
+            ));
+        }
+    }
+    out
+}
+
+/// Get whether the values in every row of `ca` are sorted. Rows that contain nulls are not sorted.
+pub(super) fn array_rows_are_sorted(
+    ca: &ArrayChunked,
+    descending: bool,
+) -> PolarsResult<BooleanChunked> {
+    let inner = ca.inner_dtype();
+    polars_ensure!(
+        inner.is_primitive_numeric(),
+        InvalidOperation: "`arr.rows_are_sorted` is only supported for numeric inner dtypes, got {inner}"
+    );
+
+    let ca = physical_array(ca)?;
+    let out = with_match_physical_numeric_polars_type!(ca.inner_dtype(), |$T| {
+        map_rows::<$T, _, _>(&ca, |_, values, validity| {
+            Some(validity.is_none() && is_sorted(values, descending))
+        })
+    });
+    Ok(BooleanChunked::from_iter_options(
+        ca.name().clone(),
+        out.into_iter(),
+    ))
+}
+
+/// Cast `item` to the inner dtype of `ca` if the rows can be binary searched for it without
+/// changing the result.
+fn cast_searchable_item(ca: &ArrayChunked, item: &Series) -> Option<Series> {
+    let inner = ca.inner_dtype();
+    let item_dtype = item.dtype();
+    let is_exact = item_dtype == inner
+        || item_dtype.is_null()
+        || (item_dtype.is_integer() && inner.is_integer());
+    if !inner.is_primitive_numeric() || !is_exact || !(item.len() == 1 || item.len() == ca.len()) {
+        return None;
+    }
+    // Integers that don't fit in the inner dtype can't be found, scan the rows for those.
+    item.strict_cast(&inner.to_physical()).ok()
+}
+
+fn physical_array(ca: &ArrayChunked) -> PolarsResult<ArrayChunked> {
+    let dtype = DataType::Array(Box::new(ca.inner_dtype().to_physical()), ca.width());
+    Ok(ca.cast(&dtype)?.array().unwrap().clone())
+}
+
+/// Like `arr.contains`, but binary searches the rows using `options`.
+///
+/// Returns `None` if the rows can't be binary searched for `item`.
+pub(super) fn array_contains_sorted(
+    ca: &ArrayChunked,
+    item: &Series,
+    nulls_equal: bool,
+    options: SortedRowsOptions,
+) -> PolarsResult<Option<BooleanChunked>> {
+    let Some(item) = cast_searchable_item(ca, item) else {
+        return Ok(None);
+    };
+    let ca = physical_array(ca)?;
+    let SortedRowsOptions {
+        descending,
+        validate,
+    } = options;
+
+    let out = with_match_physical_numeric_polars_type!(ca.inner_dtype(), |$T| {
+        let item: &ChunkedArray<$T> = item.as_ref().as_ref();
+        let item = item.rechunk();
+        let item = item.downcast_as_array();
+        let broadcast = item.len() == 1;
+
+        map_rows::<$T, _, _>(&ca, |i, values, validity| {
+            let Some(item) = item.get(if broadcast { 0 } else { i }) else {
+                return nulls_equal.then(|| validity.is_some());
+            };
+            Some(match validity {
+                Some(validity) => values
+                    .iter()
+                    .zip(validity.iter())
+                    .any(|(v, is_valid)| is_valid && v.tot_eq(&item)),
+                None if validate && !is_sorted(values, descending) => {
+                    values.iter().any(|v| v.tot_eq(&item))
+                },
+                None => !equal_range(values, &item, descending).is_empty(),
+            })
+        })
+    });
+    Ok(Some(BooleanChunked::from_iter_options(
+        ca.name().clone(),
+        out.into_iter(),
+    )))
+}
+
+/// Like `arr.count_matches`, but binary searches the rows using `options`.
+///
+/// Returns `None` if the rows can't be binary searched for `element`.
+#[cfg(feature = "array_count")]
+pub(super) fn array_count_matches_sorted(
+    ca: &ArrayChunked,
+    element: &Series,
+    options: SortedRowsOptions,
+) -> PolarsResult<Option<IdxCa>> {
+    let Some(element) = cast_searchable_item(ca, element) else {
+        return Ok(None);
+    };
+    let ca = physical_array(ca)?;
+    let SortedRowsOptions {
+        descending,
+        validate,
+    } = options;
+
+    let out = with_match_physical_numeric_polars_type!(ca.inner_dtype(), |$T| {
+        let element: &ChunkedArray<$T> = element.as_ref().as_ref();
+        let element = element.get(0);
+
+        map_rows::<$T, _, _>(&ca, |_, values, validity| {
+            let count = match (element, validity) {
+                (None, None) => 0,
+                (None, Some(validity)) => validity.unset_bits(),
+                (Some(element), Some(validity)) => values
+                    .iter()
+                    .zip(validity.iter())
+                    .filter(|(v, is_valid)| *is_valid && (*v).tot_eq(&element))
+                    .count(),
+                (Some(element), None) if validate && !is_sorted(values, descending) => {
+                    values.iter().filter(|&v| v.tot_eq(&element)).count()
+                },
+                (Some(element), None) => equal_range(values, &element, descending).len(),
+            };
+            Some(count as IdxSize)
+        })
+    });
+    Ok(Some(IdxCa::from_iter_options(
+        ca.name().clone(),
+        out.into_iter(),
+    )))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn int_arrays(values: &[Option<i64>], width: usize) -> ArrayChunked {
+        let values = Int64Chunked::from_slice_options(PlSmallStr::EMPTY, values);
+        ArrayChunked::from_aligned_values(
+            PlSmallStr::from_static("a"),
+            &DataType::Int64,
+            width,
+            values.chunks().clone(),
+            values.len() / width,
+        )
+    }
+
+    fn options(descending: bool, validate: bool) -> SortedRowsOptions {
+        SortedRowsOptions {
+            descending,
+            validate,
+        }
+    }
+
+    #[cfg(feature = "array_count")]
+    #[test]
+    fn test_array_count_matches_sorted() {
+        #[rustfmt::skip]
+        let ca = int_arrays(
+            &[
+                Some(1), Some(2), Some(2), Some(3),
+                Some(2), Some(2), Some(2), Some(2),
+                Some(0), Some(1), Some(4), Some(5),
+                Some(1), None, Some(2), Some(2),
+            ],
+            4,
+        );
+        let count = |element: Series, options| {
+            array_count_matches_sorted(&ca, &element, options)
+                .unwrap()
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+
+        let element = Series::new(PlSmallStr::EMPTY, &[2i64]);
+        let expected = [Some(2), Some(4), Some(0), Some(2)];
+        assert_eq!(count(element.clone(), options(false, false)), expected);
+        assert_eq!(count(element, options(false, true)), expected);
+
+        // Other integer types are cast to the inner dtype.
+        let element = Series::new(PlSmallStr::EMPTY, &[1i32]);
+        let expected = [Some(1), Some(0), Some(1), Some(1)];
+        assert_eq!(count(element, options(false, false)), expected);
+
+        // A null element counts the null values.
+        let element = Series::new_null(PlSmallStr::EMPTY, 1);
+        let expected = [Some(0), Some(0), Some(0), Some(1)];
+        assert_eq!(count(element, options(false, false)), expected);
+
+        // Validated rows that aren't sorted in the given order are scanned instead.
+        let element = Series::new(PlSmallStr::EMPTY, &[2i64]);
+        let expected = [Some(2), Some(4), Some(0), Some(2)];
+        assert_eq!(count(element, options(true, true)), expected);
+
+        // Floats can't be cast to an integer inner dtype exactly, so they aren't searched.
+        let element = Series::new(PlSmallStr::EMPTY, &[2.0f64]);
+        assert!(
+            array_count_matches_sorted(&ca, &element, options(false, false))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_array_contains_sorted() {
+        #[rustfmt::skip]
+        let ca = int_arrays(
+            &[
+                Some(5), Some(3), Some(3), Some(1),
+                Some(9), Some(8), Some(7), Some(6),
+                Some(4), None, Some(2), Some(0),
+            ],
+            4,
+        );
+        let contains = |item: Series, nulls_equal, options| {
+            array_contains_sorted(&ca, &item, nulls_equal, options)
+                .unwrap()
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+
+        let item = Series::new(PlSmallStr::EMPTY, &[3i64]);
+        let expected = [Some(true), Some(false), Some(false)];
+        let unvalidated = contains(item.clone(), false, options(true, false));
+        assert_eq!(unvalidated, expected);
+        assert_eq!(contains(item, false, options(true, true)), expected);
+
+        // An item per row.
+        let item = Series::new(PlSmallStr::EMPTY, &[1i64, 6, 4]);
+        let expected = [Some(true), Some(true), Some(true)];
+        assert_eq!(contains(item, false, options(true, false)), expected);
+
+        let item = Series::new_null(PlSmallStr::EMPTY, 1);
+        assert_eq!(
+            contains(item.clone(), true, options(true, false)),
+            [Some(false), Some(false), Some(true)]
+        );
+        assert_eq!(
+            contains(item, false, options(true, false)),
+            [None, None, None]
+        );
+    }
+}
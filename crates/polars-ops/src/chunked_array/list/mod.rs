@@ -7,6 +7,8 @@ mod dispersion;
 mod get;
 mod min_max;
 mod namespace;
+#[cfg(feature = "dtype-array")]
+mod pad;
 #[cfg(feature = "list_sets")]
 mod sets;
 mod sum_mean;
@@ -19,6 +21,8 @@ pub use count::*;
 use count::*;
 pub use get::*;
 pub use namespace::*;
+#[cfg(feature = "dtype-array")]
+pub use pad::PadSide;
 #[cfg(feature = "list_sets")]
 pub use sets::*;
 #[cfg(feature = "list_to_struct")]
@@ -339,6 +339,20 @@ pub trait ListNameSpaceImpl: AsList {
         Ok(self.same_type(out))
     }
 
+    /// Pad or truncate every list to exactly `width` values and return them as an `Array` of that
+    /// width, padding with `fill` at `side` and dropping the values at `truncate_side` of lists
+    /// that are too long. The inner dtype is the supertype of the list values and `fill`.
+    #[cfg(feature = "dtype-array")]
+    fn lst_pad_to_array(
+        &self,
+        width: usize,
+        side: PadSide,
+        truncate_side: PadSide,
+        fill: &Series,
+    ) -> PolarsResult<ArrayChunked> {
+        super::pad::pad_to_array(self.as_list(), width, side, truncate_side, fill)
+    }
+
     fn lst_slice(&self, offset: i64, length: usize) -> ListChunked {
         let ca = self.as_list();
         // SAFETY: `slice` doesn't change the dtype
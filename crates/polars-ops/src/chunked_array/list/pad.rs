@@ -0,0 +1,97 @@
+use arrow::array::FixedSizeListArray;
+use arrow::bitmap::MutableBitmap;
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The side of a list at which [`ListNameSpaceImpl::lst_pad_to_array`] pads or truncates.
+///
+/// [`ListNameSpaceImpl::lst_pad_to_array`]: super::ListNameSpaceImpl::lst_pad_to_array
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum PadSide {
+    #[default]
+    Start,
+    End,
+}
+
+pub(super) fn pad_to_array(
+    ca: &ListChunked,
+    width: usize,
+    side: PadSide,
+    truncate_side: PadSide,
+    fill: &Series,
+) -> PolarsResult<ArrayChunked> {
+    polars_ensure!(
+        fill.len() == 1,
+        ShapeMismatch: "`list.pad_to_array` expects a scalar fill value, got {} values", fill.len()
+    );
+    let inner_dtype = try_get_supertype(ca.inner_dtype(), fill.dtype())?;
+
+    let ca = ca.rechunk();
+    let arr = ca.downcast_as_array();
+    let offsets = arr.offsets().as_slice();
+    let validity = arr.validity();
+
+    // Gather the kept values of every row into the fixed-width slots, padded slots are null.
+    let mut idx = Vec::with_capacity(arr.len() * width);
+    let mut is_pad = MutableBitmap::with_capacity(arr.len() * width);
+    for (i, w) in offsets.windows(2).enumerate() {
+        if validity.is_some_and(|v| !v.get_bit(i)) {
+            idx.extend(std::iter::repeat_n(None, width));
+            is_pad.extend_constant(width, false);
+            continue;
+        }
+
+        let (start, end) = (w[0] as usize, w[1] as usize);
+        let len = (end - start).min(width);
+        let kept = match truncate_side {
+            PadSide::Start => end - len..end,
+            PadSide::End => start..start + len,
+        };
+        let n_pad = width - len;
+
+        if side == PadSide::Start {
+            idx.extend(std::iter::repeat_n(None, n_pad));
+            is_pad.extend_constant(n_pad, true);
+        }
+        idx.extend(kept.map(|i| Some(i as IdxSize)));
+        is_pad.extend_constant(len, false);
+        if side == PadSide::End {
+            idx.extend(std::iter::repeat_n(None, n_pad));
+            is_pad.extend_constant(n_pad, true);
+        }
+    }
+    let idx = IdxCa::from_slice_options(PlSmallStr::EMPTY, &idx);
+
+    let values = Series::try_from((ca.name().clone(), arr.values().clone())).unwrap();
+    // SAFETY: the indices point into the values of the list.
+    let mut values = unsafe {
+        values
+            .take_unchecked(&idx)
+            .from_physical_unchecked(ca.inner_dtype())?
+    }
+    .cast(&inner_dtype)?;
+
+    if fill.null_count() == 0 && is_pad.set_bits() > 0 {
+        let is_pad = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, is_pad.freeze());
+        let fill = fill.cast(&inner_dtype)?.new_from_index(0, values.len());
+        values = fill.zip_with(&is_pad, &values)?;
+    }
+
+    let values = values.rechunk().chunks()[0].clone();
+    let arrow_dtype = FixedSizeListArray::default_datatype(values.dtype().clone(), width);
+    let arr = FixedSizeListArray::new(arrow_dtype, arr.len(), values, validity.cloned());
+
+    // SAFETY: the values have the physical type of `inner_dtype`.
+    let out = unsafe {
+        Series::from_chunks_and_dtype_unchecked(
+            ca.name().clone(),
+            vec![arr.boxed()],
+            &DataType::Array(Box::new(inner_dtype), width),
+        )
+    };
+    Ok(out.array()?.clone())
+}
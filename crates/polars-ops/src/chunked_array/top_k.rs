@@ -277,6 +277,7 @@ fn top_k_by_impl(
         nulls_last: vec![true; by.len()],
         multithreaded,
         maintain_order: false,
+        deterministic: false,
         limit: None,
     };
 
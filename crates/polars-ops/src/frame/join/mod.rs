@@ -34,6 +34,8 @@ use polars_core::POOL;
 #[allow(unused_imports)]
 use polars_core::chunked_array::ops::row_encode::{
     encode_rows_vertical_par_unordered, encode_rows_vertical_par_unordered_broadcast_nulls,
+    encode_rows_vertical_par_unordered_with_contexts, get_row_encoding_context,
+    get_row_encoding_context_as_string,
 };
 use polars_core::hashing::_HASHMAP_INIT_SIZE;
 use polars_core::prelude::*;
@@ -211,11 +213,9 @@ pub trait DataFrameJoinOps: IntoDf {
             }
         }
 
-        if let Some((l, r)) = selected_left
-            .iter()
-            .zip(&selected_right)
-            .find(|(l, r)| l.dtype() != r.dtype())
-        {
+        if let Some((l, r)) = selected_left.iter().zip(&selected_right).find(|(l, r)| {
+            l.dtype() != r.dtype() && !is_categorical_string_pair(l.dtype(), r.dtype())
+        }) {
             polars_bail!(
                 ComputeError:
                     format!(
@@ -225,6 +225,24 @@ pub trait DataFrameJoinOps: IntoDf {
             );
         };
 
+        // A `Categorical` key joined with a `String` key is joined on the strings of the
+        // categories, which only the row encoded keys support.
+        let categorical_string_keys = selected_left
+            .iter()
+            .zip(&selected_right)
+            .any(|(l, r)| is_categorical_string_pair(l.dtype(), r.dtype()));
+        if categorical_string_keys {
+            polars_ensure!(
+                args.how.is_equi() || args.how.is_semi_anti(),
+                InvalidOperation: "{} join does not support joining a Categorical key with a String key; cast the Categorical key to String",
+                args.how
+            );
+            polars_ensure!(
+                !(should_coalesce && args.how == JoinType::Full),
+                InvalidOperation: "coalescing full join does not support joining a Categorical key with a String key; cast the Categorical key to String"
+            );
+        }
+
         #[cfg(feature = "iejoin")]
         if let JoinType::IEJoin = args.how {
             let Some(JoinTypeOptions::IEJoin(options)) = options else {
@@ -248,7 +266,7 @@ pub trait DataFrameJoinOps: IntoDf {
         }
 
         // Single keys.
-        if selected_left.len() == 1 {
+        if selected_left.len() == 1 && !categorical_string_keys {
             let s_left = &selected_left[0];
             let s_right = &selected_right[0];
             let drop_names: Option<Vec<PlSmallStr>> =
@@ -340,9 +358,12 @@ pub trait DataFrameJoinOps: IntoDf {
                 (a.clone(), a)
             } else {
                 // Row encode the keys.
+                let nulls_equal = args.nulls_equal;
                 (
-                    prepare_keys_multiple(&selected_left, args.nulls_equal)?.into_series(),
-                    prepare_keys_multiple(&selected_right, args.nulls_equal)?.into_series(),
+                    prepare_keys_multiple_paired(&selected_left, &selected_right, nulls_equal)?
+                        .into_series(),
+                    prepare_keys_multiple_paired(&selected_right, &selected_left, nulls_equal)?
+                        .into_series(),
                 )
             };
 
@@ -622,20 +643,19 @@ trait DataFrameJoinOpsPrivate: IntoDf {
 impl DataFrameJoinOps for DataFrame {}
 impl DataFrameJoinOpsPrivate for DataFrame {}
 
+fn physical_key(s: &Series) -> Column {
+    let phys = s.to_physical_repr();
+    match phys.dtype() {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => phys.f16().unwrap().to_canonical().into_column(),
+        DataType::Float32 => phys.f32().unwrap().to_canonical().into_column(),
+        DataType::Float64 => phys.f64().unwrap().to_canonical().into_column(),
+        _ => phys.into_owned().into_column(),
+    }
+}
+
 fn prepare_keys_multiple(s: &[Series], nulls_equal: bool) -> PolarsResult<BinaryOffsetChunked> {
-    let keys = s
-        .iter()
-        .map(|s| {
-            let phys = s.to_physical_repr();
-            match phys.dtype() {
-                #[cfg(feature = "dtype-f16")]
-                DataType::Float16 => phys.f16().unwrap().to_canonical().into_column(),
-                DataType::Float32 => phys.f32().unwrap().to_canonical().into_column(),
-                DataType::Float64 => phys.f64().unwrap().to_canonical().into_column(),
-                _ => phys.into_owned().into_column(),
-            }
-        })
-        .collect::<Vec<_>>();
+    let keys = s.iter().map(physical_key).collect::<Vec<_>>();
 
     if nulls_equal {
         encode_rows_vertical_par_unordered(&keys)
@@ -643,6 +663,42 @@ fn prepare_keys_multiple(s: &[Series], nulls_equal: bool) -> PolarsResult<Binary
         encode_rows_vertical_par_unordered_broadcast_nulls(&keys)
     }
 }
+
+/// Whether two join keys are a `Categorical` and a `String` key.
+///
+/// Those are joined on the strings of the categories without casting the categorical, see
+/// [`prepare_keys_multiple_paired`].
+fn is_categorical_string_pair(left: &DataType, right: &DataType) -> bool {
+    (left.is_categorical() && right.is_string()) || (left.is_string() && right.is_categorical())
+}
+
+/// Row encode the keys `s` of one side of a join, where `other` are the keys of the other side.
+///
+/// `Categorical` keys that are joined with `String` keys are encoded as the strings of their
+/// categories, which gives the same rows as the equal strings without materializing them.
+fn prepare_keys_multiple_paired(
+    s: &[Series],
+    other: &[Series],
+    nulls_equal: bool,
+) -> PolarsResult<BinaryOffsetChunked> {
+    let mut ctxts = Vec::with_capacity(s.len());
+    let keys = s
+        .iter()
+        .zip(other)
+        .map(|(s, other)| {
+            if is_categorical_string_pair(s.dtype(), other.dtype()) && !s.dtype().is_string() {
+                ctxts.push(get_row_encoding_context_as_string(s.dtype()));
+                return s.clone().into_column();
+            }
+
+            let key = physical_key(s);
+            ctxts.push(get_row_encoding_context(key.dtype()));
+            key
+        })
+        .collect::<Vec<_>>();
+
+    encode_rows_vertical_par_unordered_with_contexts(&keys, Some(&ctxts), !nulls_equal)
+}
 pub fn private_left_join_multiple_keys(
     a: &DataFrame,
     b: &DataFrame,
@@ -63,9 +63,14 @@ where
     }
 }
 
+/// Scan `ca` with `update`, starting from `init`.
+///
+/// With a `segment_len` the scan starts from `init` again at the start of every consecutive
+/// segment of that many values, e.g. for every row of an array.
 fn cum_scan_numeric<T, F>(
     ca: &ChunkedArray<T>,
     reverse: bool,
+    segment_len: Option<usize>,
     init: T::Native,
     update: F,
 ) -> ChunkedArray<T>
@@ -74,6 +79,25 @@ where
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
     F: Fn(&mut T::Native, Option<T::Native>) -> Option<Option<T::Native>>,
 {
+    if let Some(segment_len) = segment_len
+        && segment_len > 0
+    {
+        debug_assert_eq!(ca.len() % segment_len, 0);
+        // The state holds the number of values left in the current segment.
+        let update = |state: &mut (usize, T::Native), v| {
+            if state.0 == 0 {
+                *state = (segment_len, init);
+            }
+            state.0 -= 1;
+            update(&mut state.1, v)
+        };
+        let out: ChunkedArray<T> = match reverse {
+            false => ca.iter().scan((0, init), update).collect_trusted(),
+            true => ca.iter().rev().scan((0, init), update).collect_reversed(),
+        };
+        return out.with_name(ca.name().clone());
+    }
+
     let out: ChunkedArray<T> = match reverse {
         false => ca.iter().scan(init, update).collect_trusted(),
         true => ca.iter().rev().scan(init, update).collect_reversed(),
@@ -84,6 +108,7 @@ where
 fn cum_max_numeric<T>(
     ca: &ChunkedArray<T>,
     reverse: bool,
+    segment_len: Option<usize>,
     init: Option<T::Native>,
 ) -> ChunkedArray<T>
 where
@@ -96,12 +121,13 @@ where
     } else {
         Bounded::min_value()
     });
-    cum_scan_numeric(ca, reverse, init, det_max)
+    cum_scan_numeric(ca, reverse, segment_len, init, det_max)
 }
 
 fn cum_min_numeric<T>(
     ca: &ChunkedArray<T>,
     reverse: bool,
+    segment_len: Option<usize>,
     init: Option<T::Native>,
 ) -> ChunkedArray<T>
 where
@@ -114,7 +140,7 @@ where
     } else {
         Bounded::max_value()
     });
-    cum_scan_numeric(ca, reverse, init, det_min)
+    cum_scan_numeric(ca, reverse, segment_len, init, det_min)
 }
 
 fn cum_max_bool(ca: &BooleanChunked, reverse: bool, init: Option<bool>) -> BooleanChunked {
@@ -204,6 +230,7 @@ fn cum_min_bool(ca: &BooleanChunked, reverse: bool, init: Option<bool>) -> Boole
 fn cum_sum_numeric<T>(
     ca: &ChunkedArray<T>,
     reverse: bool,
+    segment_len: Option<usize>,
     init: Option<T::Native>,
 ) -> ChunkedArray<T>
 where
@@ -211,19 +238,29 @@ where
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
 {
     let init = init.unwrap_or(T::Native::zero());
-    cum_scan_numeric(ca, reverse, init, det_sum)
+    cum_scan_numeric(ca, reverse, segment_len, init, det_sum)
 }
 
 #[cfg(feature = "dtype-decimal")]
 fn cum_sum_decimal(
     ca: &Int128Chunked,
     reverse: bool,
+    segment_len: Option<usize>,
     init: Option<i128>,
 ) -> PolarsResult<Int128Chunked> {
     use polars_compute::decimal::{DEC128_MAX_PREC, dec128_add};
 
-    let mut value = init.unwrap_or(0);
+    let init = init.unwrap_or(0);
+    let mut value = init;
+    let mut left_in_segment = 0;
     let update = |opt_v| {
+        if let Some(segment_len) = segment_len {
+            if left_in_segment == 0 {
+                left_in_segment = segment_len;
+                value = init;
+            }
+            left_in_segment -= 1;
+        }
         if let Some(v) = opt_v {
             value = dec128_add(value, v, DEC128_MAX_PREC).ok_or_else(
                 || polars_err!(ComputeError: "overflow in decimal addition in cum_sum"),
@@ -234,7 +271,12 @@ fn cum_sum_decimal(
         }
     };
     if reverse {
-        ca.iter().rev().map(update).try_collect_ca_trusted_like(ca)
+        let out: Int128Chunked = ca
+            .iter()
+            .rev()
+            .map(update)
+            .try_collect_ca_trusted_like(ca)?;
+        Ok(out.reverse())
     } else {
         ca.iter().map(update).try_collect_ca_trusted_like(ca)
     }
@@ -243,6 +285,7 @@ fn cum_sum_decimal(
 fn cum_prod_numeric<T>(
     ca: &ChunkedArray<T>,
     reverse: bool,
+    segment_len: Option<usize>,
     init: Option<T::Native>,
 ) -> ChunkedArray<T>
 where
@@ -250,30 +293,45 @@ where
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
 {
     let init = init.unwrap_or(T::Native::one());
-    cum_scan_numeric(ca, reverse, init, det_prod)
+    cum_scan_numeric(ca, reverse, segment_len, init, det_prod)
 }
 
 pub fn cum_prod_with_init(
     s: &Series,
     reverse: bool,
     init: &AnyValue<'static>,
+) -> PolarsResult<Series> {
+    cum_prod_impl(s, reverse, None, init)
+}
+
+/// Get the cumulative product within every consecutive segment of `segment_len` values,
+/// such as the rows of an array, with the same dtypes as [`cum_prod`].
+pub fn cum_prod_segments(s: &Series, segment_len: usize, reverse: bool) -> PolarsResult<Series> {
+    cum_prod_impl(s, reverse, Some(segment_len), &AnyValue::Null)
+}
+
+fn cum_prod_impl(
+    s: &Series,
+    reverse: bool,
+    segment_len: Option<usize>,
+    init: &AnyValue<'static>,
 ) -> PolarsResult<Series> {
     use DataType::*;
     let out = match s.dtype() {
         Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 => {
             let s = s.cast(&Int64)?;
-            cum_prod_numeric(s.i64()?, reverse, init.extract()).into_series()
+            cum_prod_numeric(s.i64()?, reverse, segment_len, init.extract()).into_series()
         },
-        Int64 => cum_prod_numeric(s.i64()?, reverse, init.extract()).into_series(),
-        UInt64 => cum_prod_numeric(s.u64()?, reverse, init.extract()).into_series(),
+        Int64 => cum_prod_numeric(s.i64()?, reverse, segment_len, init.extract()).into_series(),
+        UInt64 => cum_prod_numeric(s.u64()?, reverse, segment_len, init.extract()).into_series(),
         #[cfg(feature = "dtype-i128")]
-        Int128 => cum_prod_numeric(s.i128()?, reverse, init.extract()).into_series(),
+        Int128 => cum_prod_numeric(s.i128()?, reverse, segment_len, init.extract()).into_series(),
         #[cfg(feature = "dtype-u128")]
-        UInt128 => cum_prod_numeric(s.u128()?, reverse, init.extract()).into_series(),
+        UInt128 => cum_prod_numeric(s.u128()?, reverse, segment_len, init.extract()).into_series(),
         #[cfg(feature = "dtype-f16")]
-        Float16 => cum_prod_numeric(s.f16()?, reverse, init.extract()).into_series(),
-        Float32 => cum_prod_numeric(s.f32()?, reverse, init.extract()).into_series(),
-        Float64 => cum_prod_numeric(s.f64()?, reverse, init.extract()).into_series(),
+        Float16 => cum_prod_numeric(s.f16()?, reverse, segment_len, init.extract()).into_series(),
+        Float32 => cum_prod_numeric(s.f32()?, reverse, segment_len, init.extract()).into_series(),
+        Float64 => cum_prod_numeric(s.f64()?, reverse, segment_len, init.extract()).into_series(),
         dt => polars_bail!(opq = cum_prod, dt),
     };
     Ok(out)
@@ -291,42 +349,62 @@ pub fn cum_sum_with_init(
     s: &Series,
     reverse: bool,
     init: &AnyValue<'static>,
+) -> PolarsResult<Series> {
+    cum_sum_impl(s, reverse, None, init)
+}
+
+/// Get the cumulative sum within every consecutive segment of `segment_len` values,
+/// such as the rows of an array, with the same dtypes as [`cum_sum`].
+pub fn cum_sum_segments(s: &Series, segment_len: usize, reverse: bool) -> PolarsResult<Series> {
+    cum_sum_impl(s, reverse, Some(segment_len), &AnyValue::Null)
+}
+
+fn cum_sum_impl(
+    s: &Series,
+    reverse: bool,
+    segment_len: Option<usize>,
+    init: &AnyValue<'static>,
 ) -> PolarsResult<Series> {
     use DataType::*;
     let out = match s.dtype() {
         Boolean => {
             let s = s.cast(&UInt32)?;
-            cum_sum_numeric(s.u32()?, reverse, init.extract()).into_series()
+            cum_sum_numeric(s.u32()?, reverse, segment_len, init.extract()).into_series()
         },
         Int8 | UInt8 | Int16 | UInt16 => {
             let s = s.cast(&Int64)?;
-            cum_sum_numeric(s.i64()?, reverse, init.extract()).into_series()
+            cum_sum_numeric(s.i64()?, reverse, segment_len, init.extract()).into_series()
         },
-        Int32 => cum_sum_numeric(s.i32()?, reverse, init.extract()).into_series(),
-        UInt32 => cum_sum_numeric(s.u32()?, reverse, init.extract()).into_series(),
-        Int64 => cum_sum_numeric(s.i64()?, reverse, init.extract()).into_series(),
-        UInt64 => cum_sum_numeric(s.u64()?, reverse, init.extract()).into_series(),
+        Int32 => cum_sum_numeric(s.i32()?, reverse, segment_len, init.extract()).into_series(),
+        UInt32 => cum_sum_numeric(s.u32()?, reverse, segment_len, init.extract()).into_series(),
+        Int64 => cum_sum_numeric(s.i64()?, reverse, segment_len, init.extract()).into_series(),
+        UInt64 => cum_sum_numeric(s.u64()?, reverse, segment_len, init.extract()).into_series(),
         #[cfg(feature = "dtype-u128")]
-        UInt128 => cum_sum_numeric(s.u128()?, reverse, init.extract()).into_series(),
+        UInt128 => cum_sum_numeric(s.u128()?, reverse, segment_len, init.extract()).into_series(),
         #[cfg(feature = "dtype-i128")]
-        Int128 => cum_sum_numeric(s.i128()?, reverse, init.extract()).into_series(),
+        Int128 => cum_sum_numeric(s.i128()?, reverse, segment_len, init.extract()).into_series(),
         #[cfg(feature = "dtype-f16")]
-        Float16 => cum_sum_numeric(s.f16()?, reverse, init.extract()).into_series(),
-        Float32 => cum_sum_numeric(s.f32()?, reverse, init.extract()).into_series(),
-        Float64 => cum_sum_numeric(s.f64()?, reverse, init.extract()).into_series(),
+        Float16 => cum_sum_numeric(s.f16()?, reverse, segment_len, init.extract()).into_series(),
+        Float32 => cum_sum_numeric(s.f32()?, reverse, segment_len, init.extract()).into_series(),
+        Float64 => cum_sum_numeric(s.f64()?, reverse, segment_len, init.extract()).into_series(),
         #[cfg(feature = "dtype-decimal")]
         Decimal(_precision, scale) => {
             use polars_compute::decimal::DEC128_MAX_PREC;
             let ca = s.decimal().unwrap().physical();
-            cum_sum_decimal(ca, reverse, init.clone().to_physical().extract())?
-                .into_decimal_unchecked(DEC128_MAX_PREC, *scale)
-                .into_series()
+            cum_sum_decimal(
+                ca,
+                reverse,
+                segment_len,
+                init.clone().to_physical().extract(),
+            )?
+            .into_decimal_unchecked(DEC128_MAX_PREC, *scale)
+            .into_series()
         },
         #[cfg(feature = "dtype-duration")]
         Duration(tu) => {
             let s = s.to_physical_repr();
             let ca = s.i64()?;
-            cum_sum_numeric(ca, reverse, init.extract()).cast(&Duration(*tu))?
+            cum_sum_numeric(ca, reverse, segment_len, init.extract()).cast(&Duration(*tu))?
         },
         dt => polars_bail!(opq = cum_sum, dt),
     };
@@ -345,24 +423,48 @@ pub fn cum_min_with_init(
     s: &Series,
     reverse: bool,
     init: &AnyValue<'static>,
+) -> PolarsResult<Series> {
+    cum_min_impl(s, reverse, None, init)
+}
+
+/// Get the cumulative min within every consecutive segment of `segment_len` values,
+/// such as the rows of an array, with the same dtypes as [`cum_min`].
+pub fn cum_min_segments(s: &Series, segment_len: usize, reverse: bool) -> PolarsResult<Series> {
+    cum_min_impl(s, reverse, Some(segment_len), &AnyValue::Null)
+}
+
+fn cum_min_impl(
+    s: &Series,
+    reverse: bool,
+    segment_len: Option<usize>,
+    init: &AnyValue<'static>,
 ) -> PolarsResult<Series> {
     match s.dtype() {
-        DataType::Boolean => {
+        DataType::Boolean if segment_len.is_none() => {
             Ok(cum_min_bool(s.bool()?, reverse, init.extract_bool()).into_series())
         },
+        DataType::Boolean => {
+            let s = s.cast(&DataType::UInt8)?;
+            cum_min_impl(&s, reverse, segment_len, init)?.cast(&DataType::Boolean)
+        },
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(precision, scale) => {
             let ca = s.decimal().unwrap().physical();
-            let out = cum_min_numeric(ca, reverse, init.clone().to_physical().extract())
-                .into_decimal_unchecked(*precision, *scale)
-                .into_series();
+            let out = cum_min_numeric(
+                ca,
+                reverse,
+                segment_len,
+                init.clone().to_physical().extract(),
+            )
+            .into_decimal_unchecked(*precision, *scale)
+            .into_series();
             Ok(out)
         },
         dt if dt.to_physical().is_primitive_numeric() => {
             let s = s.to_physical_repr();
             with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
                 let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-                let out = cum_min_numeric(ca, reverse, init.extract()).into_series();
+                let out = cum_min_numeric(ca, reverse, segment_len, init.extract()).into_series();
                 if dt.is_logical() {
                     out.cast(dt)
                 } else {
@@ -383,24 +485,48 @@ pub fn cum_max_with_init(
     s: &Series,
     reverse: bool,
     init: &AnyValue<'static>,
+) -> PolarsResult<Series> {
+    cum_max_impl(s, reverse, None, init)
+}
+
+/// Get the cumulative max within every consecutive segment of `segment_len` values,
+/// such as the rows of an array, with the same dtypes as [`cum_max`].
+pub fn cum_max_segments(s: &Series, segment_len: usize, reverse: bool) -> PolarsResult<Series> {
+    cum_max_impl(s, reverse, Some(segment_len), &AnyValue::Null)
+}
+
+fn cum_max_impl(
+    s: &Series,
+    reverse: bool,
+    segment_len: Option<usize>,
+    init: &AnyValue<'static>,
 ) -> PolarsResult<Series> {
     match s.dtype() {
-        DataType::Boolean => {
+        DataType::Boolean if segment_len.is_none() => {
             Ok(cum_max_bool(s.bool()?, reverse, init.extract_bool()).into_series())
         },
+        DataType::Boolean => {
+            let s = s.cast(&DataType::UInt8)?;
+            cum_max_impl(&s, reverse, segment_len, init)?.cast(&DataType::Boolean)
+        },
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(precision, scale) => {
             let ca = s.decimal().unwrap().physical();
-            let out = cum_max_numeric(ca, reverse, init.clone().to_physical().extract())
-                .into_decimal_unchecked(*precision, *scale)
-                .into_series();
+            let out = cum_max_numeric(
+                ca,
+                reverse,
+                segment_len,
+                init.clone().to_physical().extract(),
+            )
+            .into_decimal_unchecked(*precision, *scale)
+            .into_series();
             Ok(out)
         },
         dt if dt.to_physical().is_primitive_numeric() => {
             let s = s.to_physical_repr();
             with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
                 let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-                let out = cum_max_numeric(ca, reverse, init.extract()).into_series();
+                let out = cum_max_numeric(ca, reverse, segment_len, init.extract()).into_series();
                 if dt.is_logical() {
                     out.cast(dt)
                 } else {
@@ -1,5 +1,11 @@
 use std::borrow::Cow;
 
+#[cfg(feature = "dtype-array")]
+use arrow::array::{Array, FixedSizeListArray, PrimitiveArray};
+#[cfg(feature = "dtype-array")]
+use arrow::bitmap::MutableBitmap;
+#[cfg(feature = "dtype-array")]
+use num_traits::{NumCast, Zero};
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::prelude::*;
 use polars_core::series::arithmetic::coerce_lhs_rhs;
@@ -133,6 +139,10 @@ fn min_max_binary_columns(left: &Column, right: &Column, min: bool) -> PolarsRes
 
 pub fn max_horizontal(columns: &[Column]) -> PolarsResult<Option<Column>> {
     validate_column_lengths(columns)?;
+    #[cfg(feature = "dtype-array")]
+    if columns.iter().any(|c| c.dtype().is_array()) {
+        return array_horizontal(columns, ArrayHorizontalOp::Max, NullStrategy::Ignore).map(Some);
+    }
 
     let max_fn = |acc: &Column, s: &Column| min_max_binary_columns(acc, s, false);
 
@@ -159,6 +169,10 @@ pub fn max_horizontal(columns: &[Column]) -> PolarsResult<Option<Column>> {
 
 pub fn min_horizontal(columns: &[Column]) -> PolarsResult<Option<Column>> {
     validate_column_lengths(columns)?;
+    #[cfg(feature = "dtype-array")]
+    if columns.iter().any(|c| c.dtype().is_array()) {
+        return array_horizontal(columns, ArrayHorizontalOp::Min, NullStrategy::Ignore).map(Some);
+    }
 
     let min_fn = |acc: &Column, s: &Column| min_max_binary_columns(acc, s, true);
 
@@ -188,6 +202,10 @@ pub fn sum_horizontal(
     null_strategy: NullStrategy,
 ) -> PolarsResult<Option<Column>> {
     validate_column_lengths(columns)?;
+    #[cfg(feature = "dtype-array")]
+    if columns.iter().any(|c| c.dtype().is_array()) {
+        return array_horizontal(columns, ArrayHorizontalOp::Sum, null_strategy).map(Some);
+    }
     let ignore_nulls = null_strategy == NullStrategy::Ignore;
 
     let apply_null_strategy = |s: Series| -> PolarsResult<Series> {
@@ -268,6 +286,10 @@ pub fn mean_horizontal(
     null_strategy: NullStrategy,
 ) -> PolarsResult<Option<Column>> {
     validate_column_lengths(columns)?;
+    #[cfg(feature = "dtype-array")]
+    if columns.iter().any(|c| c.dtype().is_array()) {
+        return array_horizontal(columns, ArrayHorizontalOp::Mean, null_strategy).map(Some);
+    }
 
     let (numeric_columns, non_numeric_columns): (Vec<_>, Vec<_>) = columns.iter().partition(|s| {
         let dtype = s.dtype();
@@ -345,6 +367,200 @@ pub fn mean_horizontal(
     }
 }
 
+#[cfg(feature = "dtype-array")]
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum ArrayHorizontalOp {
+    Sum,
+    Mean,
+    Min,
+    Max,
+}
+
+/// Reduce `Array` columns element-wise into a single `Array` column.
+///
+/// Null rows of an input don't contribute to the output, which is only null at rows where all
+/// inputs are null (or any input is null under [`NullStrategy::Propagate`]). Null elements follow
+/// the semantics of the scalar horizontal functions.
+#[cfg(feature = "dtype-array")]
+fn array_horizontal(
+    columns: &[Column],
+    op: ArrayHorizontalOp,
+    null_strategy: NullStrategy,
+) -> PolarsResult<Column> {
+    let ignore_nulls = null_strategy == NullStrategy::Ignore;
+    let name = columns[0].name().clone();
+    let len = columns.iter().map(|c| c.len()).max().unwrap();
+
+    // Columns of the null dtype are null at every row.
+    let non_null_cols = columns
+        .iter()
+        .filter(|c| c.dtype() != &DataType::Null)
+        .collect::<Vec<_>>();
+
+    let mut width = None;
+    for c in &non_null_cols {
+        let DataType::Array(inner, w) = c.dtype() else {
+            polars_bail!(
+                InvalidOperation: "cannot combine Array and non-Array columns horizontally, found {:?} (dtype={})",
+                c.name(), c.dtype(),
+            );
+        };
+        polars_ensure!(
+            inner.is_primitive_numeric(),
+            InvalidOperation: "horizontal aggregation on Array columns expects a numeric inner dtype, found {:?} (dtype={})",
+            c.name(), c.dtype(),
+        );
+        if let Some(width) = width {
+            polars_ensure!(
+                width == *w,
+                ShapeMismatch: "cannot combine Array columns of different widths ({} and {}) horizontally",
+                width, w,
+            );
+        }
+        width = Some(*w);
+    }
+    let width = width.unwrap();
+
+    let supertype = dtypes_to_supertype(
+        non_null_cols
+            .iter()
+            .map(|c| c.dtype().inner_dtype().unwrap()),
+    )?;
+    let inner_dtype = match op {
+        ArrayHorizontalOp::Mean => match supertype {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => DataType::Float16,
+            DataType::Float32 => DataType::Float32,
+            _ => DataType::Float64,
+        },
+        _ => supertype,
+    };
+    let dtype = DataType::Array(Box::new(inner_dtype.clone()), width);
+
+    if !ignore_nulls && non_null_cols.len() < columns.len() {
+        return Ok(Column::full_null(name, len, &dtype));
+    }
+
+    let arrays = non_null_cols
+        .iter()
+        .map(|c| {
+            let mut c = c.cast(&dtype)?;
+            if c.len() != len {
+                c = c.new_from_index(0, len);
+            }
+            Ok(c.array()?.rechunk().into_owned())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let out = with_match_physical_numeric_polars_type!(&inner_dtype, |$T| {
+        array_horizontal_impl::<$T>(&arrays, &dtype, width, op, ignore_nulls)
+    });
+    Ok(ArrayChunked::with_chunk(name, out).into_column())
+}
+
+#[cfg(feature = "dtype-array")]
+fn array_horizontal_impl<T: PolarsNumericType>(
+    arrays: &[ArrayChunked],
+    dtype: &DataType,
+    width: usize,
+    op: ArrayHorizontalOp,
+    ignore_nulls: bool,
+) -> FixedSizeListArray {
+    let len = arrays.first().map_or(0, |ca| ca.len());
+    let mut values = vec![T::Native::zero(); len * width];
+    // The number of values that were accumulated into each element.
+    let mut counts = vec![0 as IdxSize; len * width];
+    let mut element_null = MutableBitmap::from_len_zeroed(len * width);
+    let mut row_valid = MutableBitmap::from_len_zeroed(len);
+    let mut row_null = MutableBitmap::from_len_zeroed(len);
+
+    let combine = |acc: T::Native, v: T::Native| match op {
+        ArrayHorizontalOp::Sum | ArrayHorizontalOp::Mean => acc + v,
+        ArrayHorizontalOp::Min => {
+            if acc < v {
+                acc
+            } else {
+                v
+            }
+        },
+        ArrayHorizontalOp::Max => {
+            if acc > v {
+                acc
+            } else {
+                v
+            }
+        },
+    };
+
+    for ca in arrays {
+        let arr = ca.downcast_as_array();
+        let inner = arr
+            .values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T::Native>>()
+            .unwrap();
+        let inner_values = inner.values().as_slice();
+        let inner_validity = inner.validity();
+
+        for row in 0..len {
+            if !arr.is_valid(row) {
+                if !ignore_nulls {
+                    row_null.set(row, true);
+                }
+                continue;
+            }
+            row_valid.set(row, true);
+
+            for idx in row * width..(row + 1) * width {
+                if inner_validity.is_some_and(|validity| !validity.get_bit(idx)) {
+                    if !ignore_nulls {
+                        element_null.set(idx, true);
+                    }
+                    continue;
+                }
+
+                let v = inner_values[idx];
+                values[idx] = if counts[idx] == 0 {
+                    v
+                } else {
+                    combine(values[idx], v)
+                };
+                counts[idx] += 1;
+            }
+        }
+    }
+
+    let mut validity = MutableBitmap::with_capacity(len * width);
+    for (idx, (value, count)) in values.iter_mut().zip(&counts).enumerate() {
+        let is_valid = !element_null.get(idx)
+            && match op {
+                // A sum without any values is zero.
+                ArrayHorizontalOp::Sum => true,
+                ArrayHorizontalOp::Mean | ArrayHorizontalOp::Min | ArrayHorizontalOp::Max => {
+                    *count > 0
+                },
+            };
+        if is_valid && op == ArrayHorizontalOp::Mean {
+            *value = *value / NumCast::from(*count).unwrap();
+        }
+        validity.push(is_valid);
+    }
+
+    for row in 0..len {
+        if row_null.get(row) {
+            row_valid.set(row, false);
+        }
+    }
+
+    let inner = PrimitiveArray::from_vec(values).with_validity(validity.into());
+    FixedSizeListArray::new(
+        dtype.to_arrow(CompatLevel::newest()),
+        len,
+        inner.boxed(),
+        row_valid.into(),
+    )
+}
+
 pub fn coalesce_columns(s: &[Column]) -> PolarsResult<Column> {
     // TODO! this can be faster if we have more than two inputs.
     polars_ensure!(!s.is_empty(), NoData: "cannot coalesce empty list");
@@ -19,6 +19,9 @@ where
 {
     use arrow::array::Array;
 
+    if ca.is_empty() {
+        return Ok(ca.clone());
+    }
     let ca = ca.rechunk();
     let arr = ca.downcast_get(0).unwrap();
     let arr = if arr.has_nulls() {
@@ -41,7 +44,7 @@ pub fn rolling_skew(s: &Series, options: RollingOptionsFixedWindow) -> PolarsRes
     let window_size = options.window_size;
     let min_periods = options.min_periods;
     let center = options.center;
-    let params = options.fn_params;
+    let params = options.fn_params.clone();
 
     match s.dtype() {
         #[cfg(feature = "dtype-f16")]
@@ -79,6 +82,9 @@ where
 {
     use arrow::array::Array;
 
+    if ca.is_empty() {
+        return Ok(ca.clone());
+    }
     let ca = ca.rechunk();
     let arr = ca.downcast_get(0).unwrap();
     let arr = if arr.has_nulls() {
@@ -107,7 +113,7 @@ pub fn rolling_kurtosis(s: &Series, options: RollingOptionsFixedWindow) -> Polar
     let window_size = options.window_size;
     let min_periods = options.min_periods;
     let center = options.center;
-    let params = options.fn_params;
+    let params = options.fn_params.clone();
 
     match s.dtype() {
         DataType::Float64 => {
@@ -1,20 +1,149 @@
+use std::fmt;
+
 use polars_core::error::{PolarsResult, polars_err};
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_str::PlSmallStr;
 
+use super::CloudPlanOptions;
 use crate::constants::POLARS_PLACEHOLDER;
 use crate::dsl::{DslPlan, FileScanDsl, ScanSources, SinkType};
+use crate::plans::{DslFunction, FunctionIR, ScanSourcesDisplay};
+
+/// A reason why a [`DslPlan`] is ineligible for execution on Polars Cloud.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CloudPlanViolation {
+    /// The plan does not end with a sink.
+    NoSink,
+    /// A scan of paths on the local file system.
+    LocalScan {
+        /// The sources of the scan, formatted with [`ScanSourcesDisplay`].
+        sources: PlSmallStr,
+        scan_type: &'static str,
+        /// The kinds of the nodes from the root of the plan down to the scan. For nodes with
+        /// multiple inputs, the input that leads to the scan follows the node.
+        breadcrumb: Vec<PlSmallStr>,
+    },
+    OpenedFilesScan,
+    InMemoryBufferScan,
+    AnonymousScan,
+    MemorySink,
+    CallbackSink,
+    SinkMultiple,
+    /// A user-defined function that cannot be serialized.
+    Udf {
+        name: PlSmallStr,
+    },
+    /// A `with_context` node.
+    ExtContext,
+    /// An in-memory `DataFrame` larger than [`CloudPlanOptions::max_inline_dataframe_bytes`].
+    LargeDataFrame {
+        size: usize,
+        limit: usize,
+    },
+}
+
+impl fmt::Display for CloudPlanViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use CloudPlanViolation::*;
+        match self {
+            NoSink => write!(f, "does not contain a sink"),
+            LocalScan {
+                sources,
+                scan_type,
+                breadcrumb,
+            } => {
+                write!(
+                    f,
+                    "contains {scan_type} scan of local file system: {sources}\n    at: "
+                )?;
+                for (i, kind) in breadcrumb.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" > ")?;
+                    }
+                    f.write_str(kind)?;
+                }
+                Ok(())
+            },
+            OpenedFilesScan => write!(f, "contains scan of opened files"),
+            InMemoryBufferScan => write!(f, "contains scan of in-memory buffer"),
+            AnonymousScan => write!(f, "contains anonymous scan"),
+            MemorySink => write!(f, "contains memory sink"),
+            CallbackSink => write!(f, "contains callback sink"),
+            SinkMultiple => write!(f, "contains sink multiple"),
+            Udf { name } => write!(
+                f,
+                "contains user-defined function that cannot be serialized: {name}"
+            ),
+            ExtContext => write!(f, "contains with_context"),
+            LargeDataFrame { size, limit } => write!(
+                f,
+                "contains an in-memory DataFrame of {size} bytes, which exceeds the limit of \
+                 {limit} bytes for inline DataFrames; write it to cloud storage and scan it from \
+                 there instead"
+            ),
+        }
+    }
+}
+
+/// The result of [`validate_cloud_plan`].
+#[derive(Clone, Debug, Default)]
+pub struct CloudPlanValidation {
+    /// Every violation in the plan, in the order in which the nodes are visited.
+    pub violations: Vec<CloudPlanViolation>,
+}
+
+impl CloudPlanValidation {
+    pub fn is_eligible(&self) -> bool {
+        self.violations.is_empty()
+    }
 
-/// Assert that the given [`DslPlan`] is eligible to be executed on Polars Cloud.
-pub(super) fn assert_cloud_eligible(dsl: &DslPlan, allow_local_scans: bool) -> PolarsResult<()> {
-    if std::env::var("POLARS_SKIP_CLIENT_CHECK").as_deref() == Ok("1") {
-        return Ok(());
+    /// Returns an error that lists all violations if the plan is ineligible.
+    pub fn into_result(self) -> PolarsResult<()> {
+        const PREFIX: &str = "logical plan ineligible for execution on Polars Cloud";
+        match self.violations.as_slice() {
+            [] => Ok(()),
+            [violation] => Err(polars_err!(InvalidOperation: "{PREFIX}: {violation}")),
+            violations => {
+                let mut msg = format!("{PREFIX}:");
+                for violation in violations {
+                    msg.push_str(&format!("\n- {violation}"));
+                }
+                Err(polars_err!(InvalidOperation: "{msg}"))
+            },
+        }
     }
+}
+
+/// Check the whole [`DslPlan`] for eligibility to be executed on Polars Cloud.
+///
+/// Unlike [`prepare_cloud_plan`](super::prepare_cloud_plan) this doesn't stop at the first
+/// violation, but reports all of them.
+pub fn validate_cloud_plan(dsl: &DslPlan, options: &CloudPlanOptions) -> CloudPlanValidation {
+    use CloudPlanViolation::*;
+
+    let mut violations = Vec::new();
 
     // Check that the plan ends with a sink.
     if !matches!(dsl, DslPlan::Sink { .. }) {
-        return ineligible_error("does not contain a sink");
+        violations.push(NoSink);
     }
 
-    for plan_node in dsl.into_iter() {
+    // Walk the plan depth-first, keeping track of the path from the root to the current node.
+    let mut breadcrumb: Vec<PlSmallStr> = Vec::new();
+    let mut stack = vec![(dsl, 0, None)];
+    let mut inputs = Vec::new();
+    while let Some((plan_node, depth, input)) = stack.pop() {
+        breadcrumb.truncate(depth);
+        breadcrumb.extend(input);
+        breadcrumb.push(PlSmallStr::from_static(plan_node.kind()));
+
+        plan_node.inputs(&mut inputs);
+        let num_inputs = inputs.len();
+        stack.extend(inputs.drain(..).enumerate().map(|(i, input)| {
+            let label = (num_inputs > 1).then(|| plan_node.input_label(i));
+            (input, breadcrumb.len(), label)
+        }));
+
         match plan_node {
             #[cfg(feature = "python")]
             DslPlan::PythonScan { .. } => (),
@@ -23,57 +152,115 @@ pub(super) fn assert_cloud_eligible(dsl: &DslPlan, allow_local_scans: bool) -> P
             } => {
                 match sources {
                     ScanSources::Paths(addrs) => {
-                        if !allow_local_scans
+                        if !options.allow_local_scans
                             && addrs
                                 .iter()
                                 .any(|p| !p.is_cloud_url() && p.to_str() != POLARS_PLACEHOLDER)
                         {
-                            return ineligible_error("contains scan of local file system");
+                            violations.push(LocalScan {
+                                sources: format_pl_smallstr!("{}", ScanSourcesDisplay(sources)),
+                                scan_type: (&**scan_type).into(),
+                                breadcrumb: breadcrumb.clone(),
+                            });
                         }
                     },
-                    ScanSources::Files(_) => {
-                        return ineligible_error("contains scan of opened files");
-                    },
-                    ScanSources::Buffers(_) => {
-                        return ineligible_error("contains scan of in-memory buffer");
-                    },
+                    ScanSources::Files(_) => violations.push(OpenedFilesScan),
+                    ScanSources::Buffers(_) => violations.push(InMemoryBufferScan),
                 }
 
                 if matches!(&**scan_type, FileScanDsl::Anonymous { .. }) {
-                    return ineligible_error("contains anonymous scan");
+                    violations.push(AnonymousScan);
                 }
             },
             DslPlan::Sink { payload, .. } => {
                 match payload {
-                    SinkType::Memory => {
-                        return ineligible_error("contains memory sink");
-                    },
-                    SinkType::Callback(_) => {
-                        return ineligible_error("contains callback sink");
-                    },
+                    SinkType::Memory => violations.push(MemorySink),
+                    SinkType::Callback(_) => violations.push(CallbackSink),
                     SinkType::File { .. } | SinkType::Partitioned { .. } => {
                         // The sink destination is passed around separately, can't check the
                         // eligibility here.
                     },
                 }
             },
-            DslPlan::SinkMultiple { .. } => {
-                return ineligible_error("contains sink multiple");
+            DslPlan::SinkMultiple { .. } => violations.push(SinkMultiple),
+            DslPlan::DataFrameScan { df, .. } => {
+                let size = df.estimated_size();
+                if size > options.max_inline_dataframe_bytes {
+                    violations.push(LargeDataFrame {
+                        size,
+                        limit: options.max_inline_dataframe_bytes,
+                    });
+                }
+            },
+            // Python functions are serialized with cloudpickle, functions that are already
+            // converted to IR can't be serialized.
+            DslPlan::MapFunction {
+                function: DslFunction::FunctionIR(function),
+                ..
+            } => {
+                let name = match function {
+                    FunctionIR::Opaque { fmt_str, .. } => fmt_str.clone(),
+                    function => PlSmallStr::from_str(&function.to_string()),
+                };
+                violations.push(Udf { name });
             },
+            DslPlan::ExtContext { .. } => violations.push(ExtContext),
             _ => (),
         }
     }
-    Ok(())
-}
-
-fn ineligible_error(message: &str) -> PolarsResult<()> {
-    Err(polars_err!(
-        InvalidOperation:
-        "logical plan ineligible for execution on Polars Cloud: {message}"
-    ))
+    CloudPlanValidation { violations }
 }
 
 impl DslPlan {
+    /// A short name of the kind of node, used to locate nodes in violations.
+    fn kind(&self) -> &'static str {
+        use DslPlan::*;
+        match self {
+            #[cfg(feature = "python")]
+            PythonScan { .. } => "python_scan",
+            Filter { .. } => "filter",
+            Cache { .. } => "cache",
+            Scan { .. } => "scan",
+            DataFrameScan { .. } => "df",
+            Select { .. } => "select",
+            GroupBy { .. } => "group_by",
+            Join { .. } => "join",
+            HStack { .. } => "with_columns",
+            MatchToSchema { .. } => "match_to_schema",
+            PipeWithSchema { .. } => "pipe_with_schema",
+            #[cfg(feature = "pivot")]
+            Pivot { .. } => "pivot",
+            Distinct { .. } => "unique",
+            Sort { .. } => "sort",
+            Slice { .. } => "slice",
+            MapFunction { .. } => "map_function",
+            Union { .. } => "union",
+            HConcat { .. } => "hconcat",
+            ExtContext { .. } => "with_context",
+            Sink { .. } => "sink",
+            SinkMultiple { .. } => "sink_multiple",
+            #[cfg(feature = "merge_sorted")]
+            MergeSorted { .. } => "merge_sorted",
+            IR { .. } => "ir",
+        }
+    }
+
+    /// The name of input `idx` of a node with multiple inputs, in the order of [`Self::inputs`].
+    fn input_label(&self, idx: usize) -> PlSmallStr {
+        use DslPlan::*;
+        match (self, idx) {
+            (Join { .. }, 0) => PlSmallStr::from_static("left input"),
+            (Join { .. }, _) => PlSmallStr::from_static("right input"),
+            #[cfg(feature = "merge_sorted")]
+            (MergeSorted { .. }, 0) => PlSmallStr::from_static("left input"),
+            #[cfg(feature = "merge_sorted")]
+            (MergeSorted { .. }, _) => PlSmallStr::from_static("right input"),
+            (ExtContext { .. }, 0) => PlSmallStr::from_static("input"),
+            (ExtContext { .. }, idx) => format_pl_smallstr!("context {}", idx - 1),
+            (_, idx) => format_pl_smallstr!("input {idx}"),
+        }
+    }
+
     fn inputs<'a>(&'a self, scratch: &mut Vec<&'a DslPlan>) {
         use DslPlan::*;
         match self {
@@ -1,13 +1,35 @@
 mod check;
 
+pub use check::{CloudPlanValidation, CloudPlanViolation, validate_cloud_plan};
 use polars_core::error::PolarsResult;
 
 use crate::dsl::{DslPlan, PlanSerializationContext};
 
+/// Options for [`prepare_cloud_plan`].
+#[derive(Clone, Debug)]
+pub struct CloudPlanOptions {
+    /// Allow scans of the local file system.
+    pub allow_local_scans: bool,
+    /// The maximum estimated size in bytes of an in-memory `DataFrame` that is serialized into
+    /// the plan. Larger `DataFrame`s make the plan ineligible.
+    pub max_inline_dataframe_bytes: usize,
+}
+
+impl Default for CloudPlanOptions {
+    fn default() -> Self {
+        Self {
+            allow_local_scans: false,
+            max_inline_dataframe_bytes: usize::MAX,
+        }
+    }
+}
+
 /// Prepare the given [`DslPlan`] for execution on Polars Cloud.
-pub fn prepare_cloud_plan(dsl: DslPlan, allow_local_scans: bool) -> PolarsResult<Vec<u8>> {
+pub fn prepare_cloud_plan(dsl: DslPlan, options: &CloudPlanOptions) -> PolarsResult<Vec<u8>> {
     // Check the plan for cloud eligibility.
-    check::assert_cloud_eligible(&dsl, allow_local_scans)?;
+    if std::env::var("POLARS_SKIP_CLIENT_CHECK").as_deref() != Ok("1") {
+        validate_cloud_plan(&dsl, options).into_result()?;
+    }
 
     // Serialize the plan.
     let mut writer = Vec::new();
@@ -20,3 +42,179 @@ pub fn prepare_cloud_plan(dsl: DslPlan, allow_local_scans: bool) -> PolarsResult
 
     Ok(writer)
 }
+
+#[cfg(all(test, feature = "ipc"))]
+mod tests {
+    use std::sync::Arc;
+
+    use polars_core::df;
+    use polars_core::prelude::*;
+    use polars_io::ipc::IpcWriterOptions;
+    use polars_utils::plpath::PlPath;
+
+    use super::*;
+    use crate::dsl::{
+        DslBuilder, FileSinkOptions, FileType, JoinOptions, ScanSources, SinkTarget, SinkType,
+        UnifiedSinkArgs, UnionArgs, col, lit,
+    };
+
+    fn sink_df(df: DataFrame) -> DslPlan {
+        sink(DslBuilder::from_existing_df(df).build())
+    }
+
+    fn scan(path: &str) -> PolarsResult<DslBuilder> {
+        DslBuilder::scan_ipc(
+            ScanSources::Paths(FromIterator::from_iter([PlPath::new(path)])),
+            Default::default(),
+            Default::default(),
+        )
+    }
+
+    fn local_scan(path: &str, breadcrumb: &[&str]) -> CloudPlanViolation {
+        CloudPlanViolation::LocalScan {
+            sources: format!("[{path}]").into(),
+            scan_type: "Ipc",
+            breadcrumb: breadcrumb.iter().map(|&kind| kind.into()).collect(),
+        }
+    }
+
+    fn sink(input: DslPlan) -> DslPlan {
+        DslPlan::Sink {
+            input: Arc::new(input),
+            payload: SinkType::File(FileSinkOptions {
+                target: SinkTarget::Path(PlPath::new("s3://bucket/output.ipc")),
+                file_format: Arc::new(FileType::Ipc(IpcWriterOptions::default())),
+                unified_sink_args: UnifiedSinkArgs::default(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_prepare_cloud_plan_inline_dataframe() -> PolarsResult<()> {
+        let df = df!("a" => &[1, 2, 3], "b" => &["x", "y", "z"])?;
+        let options = CloudPlanOptions {
+            max_inline_dataframe_bytes: 1024,
+            ..Default::default()
+        };
+
+        let bytes = prepare_cloud_plan(sink_df(df.clone()), &options)?;
+        let DslPlan::Sink { input, .. } = DslPlan::deserialize_versioned(bytes.as_slice())? else {
+            panic!("expected a sink");
+        };
+        let DslPlan::DataFrameScan { df: out, .. } = input.as_ref() else {
+            panic!("expected a DataFrame scan");
+        };
+        assert!(out.equals_missing(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn test_prepare_cloud_plan_dataframe_too_large() -> PolarsResult<()> {
+        let df = df!("a" => (0..1000i64).collect::<Vec<_>>())?;
+        let options = CloudPlanOptions {
+            max_inline_dataframe_bytes: 1024,
+            ..Default::default()
+        };
+
+        let err = prepare_cloud_plan(sink_df(df), &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "logical plan ineligible for execution on Polars Cloud: contains an in-memory \
+             DataFrame of 8000 bytes, which exceeds the limit of 1024 bytes for inline \
+             DataFrames; write it to cloud storage and scan it from there instead"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_cloud_plan_reports_all_violations() -> PolarsResult<()> {
+        let plan = || -> PolarsResult<DslPlan> {
+            let udf = scan("data-1.ipc")?.map(
+                |df: DataFrame| PolarsResult::Ok(df),
+                Default::default(),
+                None,
+                PlSmallStr::from_static("my_udf"),
+            );
+            Ok(sink(DslPlan::Union {
+                inputs: vec![
+                    udf.build(),
+                    scan("data-2.ipc")?.build(),
+                    scan("s3://bucket/data-3.ipc")?.build(),
+                ],
+                args: UnionArgs::default(),
+            }))
+        };
+        let udf_violation = CloudPlanViolation::Udf {
+            name: "my_udf".into(),
+        };
+
+        let validation = validate_cloud_plan(&plan()?, &CloudPlanOptions::default());
+        let mut violations = validation.violations.clone();
+        violations.sort_by_key(|v| v.to_string());
+        assert_eq!(
+            violations,
+            [
+                local_scan(
+                    "data-1.ipc",
+                    &["sink", "union", "input 0", "map_function", "scan"]
+                ),
+                local_scan("data-2.ipc", &["sink", "union", "input 1", "scan"]),
+                udf_violation.clone(),
+            ]
+        );
+
+        // The error lists every violation.
+        let err = prepare_cloud_plan(plan()?, &CloudPlanOptions::default()).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.starts_with("logical plan ineligible for execution on Polars Cloud:\n- "));
+        for violation in &validation.violations {
+            assert!(msg.contains(&format!("\n- {violation}")));
+        }
+
+        // Local scans can be allowed, the function is never eligible.
+        let options = CloudPlanOptions {
+            allow_local_scans: true,
+            ..Default::default()
+        };
+        let validation = validate_cloud_plan(&plan()?, &options);
+        assert_eq!(validation.violations, [udf_violation]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_cloud_plan_local_scan_breadcrumbs() -> PolarsResult<()> {
+        let left = scan("data-1.ipc")?.filter(col("a").gt(lit(1)));
+        let plan = sink(
+            left.join(
+                scan("data-2.ipc")?.build(),
+                vec![col("a")],
+                vec![col("a")],
+                Arc::new(JoinOptions::default()),
+            )
+            .build(),
+        );
+
+        let validation = validate_cloud_plan(&plan, &CloudPlanOptions::default());
+        assert_eq!(
+            validation.violations,
+            [
+                local_scan("data-2.ipc", &["sink", "join", "right input", "scan"]),
+                local_scan(
+                    "data-1.ipc",
+                    &["sink", "join", "left input", "filter", "scan"]
+                ),
+            ]
+        );
+
+        let err = validation.into_result().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "logical plan ineligible for execution on Polars Cloud:\n\
+             - contains Ipc scan of local file system: [data-2.ipc]\n    \
+             at: sink > join > right input > scan\n\
+             - contains Ipc scan of local file system: [data-1.ipc]\n    \
+             at: sink > join > left input > filter > scan"
+        );
+        Ok(())
+    }
+}
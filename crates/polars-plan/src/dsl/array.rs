@@ -1,4 +1,6 @@
+use polars_core::chunked_array::cast::CastOptions;
 use polars_core::prelude::*;
+use polars_ops::chunked_array::array::SortedRowsOptions;
 
 use crate::dsl::function_expr::ArrayFunction;
 use crate::prelude::*;
@@ -49,6 +51,58 @@ impl ArrayNameSpace {
             .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::Mean))
     }
 
+    /// Compute the sum of all leaf values in every (nested) subarray.
+    pub fn leaf_sum(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::LeafSum))
+    }
+
+    /// Compute the mean of all leaf values in every (nested) subarray.
+    pub fn leaf_mean(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::LeafMean))
+    }
+
+    /// Compute the minimum of all leaf values in every (nested) subarray.
+    pub fn leaf_min(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::LeafMin))
+    }
+
+    /// Compute the maximum of all leaf values in every (nested) subarray.
+    pub fn leaf_max(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::LeafMax))
+    }
+
+    /// Get the cumulative sum of the items in every subarray.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_sum(self, reverse: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::CumSum { reverse }))
+    }
+
+    /// Get the cumulative product of the items in every subarray.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_prod(self, reverse: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::CumProd { reverse }))
+    }
+
+    /// Get the cumulative minimum of the items in every subarray.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_min(self, reverse: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::CumMin { reverse }))
+    }
+
+    /// Get the cumulative maximum of the items in every subarray.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_max(self, reverse: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::CumMax { reverse }))
+    }
+
     /// Compute the median of the items in every subarray.
     pub fn median(self) -> Expr {
         self.0
@@ -120,6 +174,29 @@ impl ArrayNameSpace {
         )
     }
 
+    /// Set the item at `index` in every sub-array to `value`.
+    ///
+    /// Negative indices count from the end of the sub-arrays and rows with a null index are left
+    /// unchanged. Out of bounds indices raise, unless `skip_oob` is set, which leaves those rows
+    /// unchanged as well.
+    pub fn scatter<E: Into<Expr>>(self, index: Expr, value: E, skip_oob: bool) -> Expr {
+        self.0.map_ternary(
+            FunctionExpr::ArrayExpr(ArrayFunction::Scatter(skip_oob)),
+            index,
+            value.into(),
+        )
+    }
+
+    /// Set the items of every sub-array where `mask`, an array of booleans of the same width, is
+    /// `true` to `value`.
+    pub fn set_at_idx_masked<E: Into<Expr>>(self, mask: Expr, value: E) -> Expr {
+        self.0.map_ternary(
+            FunctionExpr::ArrayExpr(ArrayFunction::SetAtIdxMasked),
+            mask,
+            value.into(),
+        )
+    }
+
     /// Join all string items in a sub-array and place a separator between them.
     /// # Error
     /// Raise if inner type of array is not `DataType::String`.
@@ -134,7 +211,29 @@ impl ArrayNameSpace {
     /// Check if the sub-array contains specific element
     pub fn contains<E: Into<Expr>>(self, other: E, nulls_equal: bool) -> Expr {
         self.0.map_binary(
-            FunctionExpr::ArrayExpr(ArrayFunction::Contains { nulls_equal }),
+            FunctionExpr::ArrayExpr(ArrayFunction::Contains {
+                nulls_equal,
+                sorted_rows: None,
+            }),
+            other.into(),
+        )
+    }
+
+    #[cfg(feature = "is_in")]
+    /// Check if the sub-array contains specific element, assuming that every sub-array is sorted.
+    ///
+    /// Sub-arrays that are not sorted give wrong results, unless `options.validate` is set.
+    pub fn contains_sorted<E: Into<Expr>>(
+        self,
+        other: E,
+        nulls_equal: bool,
+        options: SortedRowsOptions,
+    ) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ArrayExpr(ArrayFunction::Contains {
+                nulls_equal,
+                sorted_rows: Some(options),
+            }),
             other.into(),
         )
     }
@@ -143,11 +242,47 @@ impl ArrayNameSpace {
     /// Count how often the value produced by ``element`` occurs.
     pub fn count_matches<E: Into<Expr>>(self, element: E) -> Expr {
         self.0.map_binary(
-            FunctionExpr::ArrayExpr(ArrayFunction::CountMatches),
+            FunctionExpr::ArrayExpr(ArrayFunction::CountMatches(None)),
             element.into(),
         )
     }
 
+    #[cfg(feature = "array_count")]
+    /// Count how often the value produced by ``element`` occurs, assuming that every sub-array is
+    /// sorted.
+    ///
+    /// Sub-arrays that are not sorted give wrong results, unless `options.validate` is set.
+    pub fn count_matches_sorted<E: Into<Expr>>(
+        self,
+        element: E,
+        options: SortedRowsOptions,
+    ) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ArrayExpr(ArrayFunction::CountMatches(Some(options))),
+            element.into(),
+        )
+    }
+
+    /// Check whether the values in every sub-array are sorted.
+    ///
+    /// Sub-arrays that contain nulls are not considered sorted.
+    pub fn rows_are_sorted(self, descending: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::RowsAreSorted {
+                descending,
+            }))
+    }
+
+    /// Cast the values of every sub-array to `dtype`, keeping the width and the validity of the
+    /// sub-arrays.
+    pub fn cast_inner(self, dtype: impl Into<DataTypeExpr>, options: CastOptions) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::CastInner {
+                dtype: dtype.into(),
+                options,
+            }))
+    }
+
     #[cfg(feature = "array_to_struct")]
     pub fn to_struct(self, name_generator: Option<DslNameGenerator>) -> Expr {
         self.0.map_unary(ArrayFunction::ToStruct(name_generator))
@@ -1,10 +1,13 @@
 use std::fmt;
 
+use polars_core::chunked_array::cast::CastOptions;
 use polars_core::prelude::{ExplodeOptions, SortOptions};
+use polars_ops::chunked_array::array::SortedRowsOptions;
 
 use super::FunctionExpr;
+use crate::dsl::DataTypeExpr;
 
-#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Clone, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub enum ArrayFunction {
@@ -20,6 +23,26 @@ pub enum ArrayFunction {
     Var(u8),
     Mean,
     Median,
+    LeafSum,
+    LeafMean,
+    LeafMin,
+    LeafMax,
+    #[cfg(feature = "cum_agg")]
+    CumSum {
+        reverse: bool,
+    },
+    #[cfg(feature = "cum_agg")]
+    CumProd {
+        reverse: bool,
+    },
+    #[cfg(feature = "cum_agg")]
+    CumMin {
+        reverse: bool,
+    },
+    #[cfg(feature = "cum_agg")]
+    CumMax {
+        reverse: bool,
+    },
     #[cfg(feature = "array_any_all")]
     Any,
     #[cfg(feature = "array_any_all")]
@@ -29,18 +52,28 @@ pub enum ArrayFunction {
     ArgMin,
     ArgMax,
     Get(bool),
+    Scatter(bool),
+    SetAtIdxMasked,
     Join(bool),
     #[cfg(feature = "is_in")]
     Contains {
         nulls_equal: bool,
+        sorted_rows: Option<SortedRowsOptions>,
     },
     #[cfg(feature = "array_count")]
-    CountMatches,
+    CountMatches(Option<SortedRowsOptions>),
+    RowsAreSorted {
+        descending: bool,
+    },
     Shift,
     Explode(ExplodeOptions),
     Concat,
     #[cfg(feature = "array_to_struct")]
     ToStruct(Option<super::DslNameGenerator>),
+    CastInner {
+        dtype: DataTypeExpr,
+        options: CastOptions,
+    },
 }
 
 impl fmt::Display for ArrayFunction {
@@ -60,6 +93,18 @@ impl fmt::Display for ArrayFunction {
             Var(_) => "var",
             Mean => "mean",
             Median => "median",
+            LeafSum => "leaf_sum",
+            LeafMean => "leaf_mean",
+            LeafMin => "leaf_min",
+            LeafMax => "leaf_max",
+            #[cfg(feature = "cum_agg")]
+            CumSum { .. } => "cum_sum",
+            #[cfg(feature = "cum_agg")]
+            CumProd { .. } => "cum_prod",
+            #[cfg(feature = "cum_agg")]
+            CumMin { .. } => "cum_min",
+            #[cfg(feature = "cum_agg")]
+            CumMax { .. } => "cum_max",
             #[cfg(feature = "array_any_all")]
             Any => "any",
             #[cfg(feature = "array_any_all")]
@@ -69,15 +114,19 @@ impl fmt::Display for ArrayFunction {
             ArgMin => "arg_min",
             ArgMax => "arg_max",
             Get(_) => "get",
+            Scatter(_) => "scatter",
+            SetAtIdxMasked => "set_at_idx_masked",
             Join(_) => "join",
             #[cfg(feature = "is_in")]
-            Contains { nulls_equal: _ } => "contains",
+            Contains { .. } => "contains",
             #[cfg(feature = "array_count")]
-            CountMatches => "count_matches",
+            CountMatches(_) => "count_matches",
+            RowsAreSorted { .. } => "rows_are_sorted",
             Shift => "shift",
             Explode { .. } => "explode",
             #[cfg(feature = "array_to_struct")]
             ToStruct(_) => "to_struct",
+            CastInner { .. } => "cast_inner",
         };
         write!(f, "arr.{name}")
     }
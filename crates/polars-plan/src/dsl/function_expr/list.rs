@@ -55,6 +55,12 @@ pub enum ListFunction {
     Join(bool),
     #[cfg(feature = "dtype-array")]
     ToArray(usize),
+    #[cfg(feature = "dtype-array")]
+    PadToArray {
+        width: usize,
+        side: PadSide,
+        truncate_side: PadSide,
+    },
     #[cfg(feature = "list_to_struct")]
     ToStruct(Arc<[PlSmallStr]>),
 }
@@ -117,6 +123,8 @@ impl Display for ListFunction {
             Join(_) => "join",
             #[cfg(feature = "dtype-array")]
             ToArray(_) => "to_array",
+            #[cfg(feature = "dtype-array")]
+            PadToArray { .. } => "pad_to_array",
             #[cfg(feature = "list_to_struct")]
             ToStruct(_) => "to_struct",
         };
@@ -60,7 +60,7 @@ pub use self::datetime::TemporalFunction;
 pub use self::extension::ExtensionFunction;
 pub use self::pow::PowFunction;
 #[cfg(feature = "range")]
-pub use self::range::{DateRangeArgs, RangeFunction};
+pub use self::range::{DateRangeArgs, RangeFunction, RangeNullBehavior};
 #[cfg(feature = "rolling_window")]
 pub use self::rolling::RollingFunction;
 #[cfg(feature = "rolling_window_by")]
@@ -159,6 +159,8 @@ pub enum FunctionExpr {
     Kurtosis(bool, bool),
     #[cfg(feature = "dtype-array")]
     Reshape(Vec<ReshapeDimension>),
+    #[cfg(feature = "dtype-array")]
+    ImplodeToArray(usize),
     #[cfg(feature = "repeat_by")]
     RepeatBy,
     ArgUnique,
@@ -607,6 +609,8 @@ impl Hash for FunctionExpr {
             },
             #[cfg(feature = "dtype-array")]
             Reshape(dims) => dims.hash(state),
+            #[cfg(feature = "dtype-array")]
+            ImplodeToArray(width) => width.hash(state),
             #[cfg(feature = "repeat_by")]
             RepeatBy => {},
             #[cfg(feature = "cutqcut")]
@@ -831,6 +835,8 @@ impl Display for FunctionExpr {
             QCut { .. } => "qcut",
             #[cfg(feature = "dtype-array")]
             Reshape(_) => "reshape",
+            #[cfg(feature = "dtype-array")]
+            ImplodeToArray(_) => "implode_to_array",
             #[cfg(feature = "repeat_by")]
             RepeatBy => "repeat_by",
             #[cfg(feature = "rle")]
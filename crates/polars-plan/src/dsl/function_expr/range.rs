@@ -51,6 +51,18 @@ impl DateRangeArgs {
     }
 }
 
+/// What `int_range` does if `start` or `end` is null.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash)]
+pub enum RangeNullBehavior {
+    /// Raise an error.
+    #[default]
+    Raise,
+    /// Produce an empty range.
+    Propagate,
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 #[derive(Clone, PartialEq, Debug, Hash)]
@@ -58,10 +70,17 @@ pub enum RangeFunction {
     IntRange {
         step: i64,
         dtype: DataTypeExpr,
+        null_behavior: RangeNullBehavior,
     },
     IntRanges {
         dtype: DataTypeExpr,
     },
+    /// A range of `len` integers with `step` from every `start`, as a fixed-width array.
+    IntRangeArray {
+        len: usize,
+        step: i64,
+        dtype: DataTypeExpr,
+    },
     LinearSpace {
         closed: ClosedInterval,
     },
@@ -121,6 +140,7 @@ impl fmt::Display for RangeFunction {
         let s = match self {
             IntRange { .. } => "int_range",
             IntRanges { .. } => "int_ranges",
+            IntRangeArray { .. } => "int_range_array",
             LinearSpace { .. } => "linear_space",
             LinearSpaces { .. } => "linear_spaces",
             #[cfg(feature = "dtype-date")]
@@ -13,10 +13,22 @@ pub fn arange(start: Expr, end: Expr, step: i64, dtype: DataType) -> Expr {
 
 /// Generate a range of integers.
 pub fn int_range(start: Expr, end: Expr, step: i64, dtype: impl Into<DataTypeExpr>) -> Expr {
+    int_range_with_null_behavior(start, end, step, dtype, RangeNullBehavior::Raise)
+}
+
+/// Generate a range of integers, choosing what happens if `start` or `end` is null.
+pub fn int_range_with_null_behavior(
+    start: Expr,
+    end: Expr,
+    step: i64,
+    dtype: impl Into<DataTypeExpr>,
+    null_behavior: RangeNullBehavior,
+) -> Expr {
     Expr::n_ary(
         RangeFunction::IntRange {
             step,
             dtype: dtype.into(),
+            null_behavior,
         },
         vec![start, end],
     )
@@ -32,6 +44,20 @@ pub fn int_ranges(start: Expr, end: Expr, step: Expr, dtype: impl Into<DataTypeE
     )
 }
 
+/// Generate a range of `len` integers with `step` from every `start`, as a fixed-width array.
+///
+/// A null `start` produces a null array.
+pub fn int_range_array(start: Expr, len: usize, step: i64, dtype: impl Into<DataTypeExpr>) -> Expr {
+    Expr::n_ary(
+        RangeFunction::IntRangeArray {
+            len,
+            step,
+            dtype: dtype.into(),
+        },
+        vec![start],
+    )
+}
+
 #[cfg(feature = "dtype-date")]
 /// Create a date range column from `start`, `end`, and `interval`, and expressions.
 pub fn date_range(
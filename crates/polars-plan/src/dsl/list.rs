@@ -232,6 +232,30 @@ impl ListNameSpace {
             .map_unary(FunctionExpr::ListExpr(ListFunction::ToArray(width)))
     }
 
+    #[cfg(feature = "dtype-array")]
+    /// Pad or truncate every sublist to `width` values and convert the result into an Array
+    /// column.
+    ///
+    /// Lists shorter than `width` are padded with the scalar `fill` at `side`, lists that are
+    /// longer are truncated by dropping the values at `truncate_side`. The inner data type is the
+    /// supertype of the list values and `fill`.
+    pub fn pad_to_array(
+        self,
+        width: usize,
+        side: PadSide,
+        fill: Expr,
+        truncate_side: PadSide,
+    ) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ListExpr(ListFunction::PadToArray {
+                width,
+                side,
+                truncate_side,
+            }),
+            fill,
+        )
+    }
+
     #[cfg(feature = "list_to_struct")]
     #[allow(clippy::wrong_self_convention)]
     /// Convert this `List` to a `Series` of type `Struct`. The width will be determined according to
@@ -1475,6 +1475,14 @@ impl Expr {
         self.map_unary(FunctionExpr::Reshape(dimensions))
     }
 
+    /// Aggregate the values into an array of `width` values, in their order.
+    ///
+    /// In a group-by context every group must have exactly `width` values, otherwise this raises.
+    #[cfg(feature = "dtype-array")]
+    pub fn implode_to_array(self, width: usize) -> Self {
+        self.map_unary(FunctionExpr::ImplodeToArray(width))
+    }
+
     #[cfg(feature = "ewma")]
     /// Calculate the exponentially-weighted moving average.
     pub fn ewm_mean(self, options: EWMOptions) -> Self {
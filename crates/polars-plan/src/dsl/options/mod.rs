@@ -123,6 +123,8 @@ impl JoinTypeOptionsIR {
 pub struct JoinOptionsIR {
     pub allow_parallel: bool,
     pub force_parallel: bool,
+    /// See [`JoinOptions::bloom_filter`].
+    pub bloom_filter: Option<bool>,
     pub args: JoinArgs,
     pub options: Option<JoinTypeOptionsIR>,
 }
@@ -132,6 +134,7 @@ impl From<JoinOptions> for JoinOptionsIR {
         Self {
             allow_parallel: opts.allow_parallel,
             force_parallel: opts.force_parallel,
+            bloom_filter: opts.bloom_filter,
             args: opts.args,
             options: Default::default(),
         }
@@ -144,6 +147,10 @@ impl From<JoinOptions> for JoinOptionsIR {
 pub struct JoinOptions {
     pub allow_parallel: bool,
     pub force_parallel: bool,
+    /// Whether the streaming engine builds a bloom filter over the build keys of a semi/anti
+    /// join, which is checked before probing the hash table. If `None`, this is decided based on
+    /// the size of the build side.
+    pub bloom_filter: Option<bool>,
     pub args: JoinArgs,
 }
 
@@ -152,6 +159,7 @@ impl Default for JoinOptions {
         JoinOptions {
             allow_parallel: true,
             force_parallel: false,
+            bloom_filter: None,
             // Todo!: make default
             args: JoinArgs::new(JoinType::Left),
         }
@@ -163,6 +171,7 @@ impl From<JoinOptionsIR> for JoinOptions {
         Self {
             allow_parallel: opts.allow_parallel,
             force_parallel: opts.force_parallel,
+            bloom_filter: opts.bloom_filter,
             args: opts.args,
         }
     }
@@ -1,4 +1,4 @@
-use polars_core::utils::slice_offsets;
+use polars_core::utils::{slice_offsets, try_get_supertype};
 use polars_ops::chunked_array::array::*;
 
 use super::*;
@@ -17,6 +17,26 @@ pub enum IRArrayFunction {
     Var(u8),
     Mean,
     Median,
+    LeafSum,
+    LeafMean,
+    LeafMin,
+    LeafMax,
+    #[cfg(feature = "cum_agg")]
+    CumSum {
+        reverse: bool,
+    },
+    #[cfg(feature = "cum_agg")]
+    CumProd {
+        reverse: bool,
+    },
+    #[cfg(feature = "cum_agg")]
+    CumMin {
+        reverse: bool,
+    },
+    #[cfg(feature = "cum_agg")]
+    CumMax {
+        reverse: bool,
+    },
     #[cfg(feature = "array_any_all")]
     Any,
     #[cfg(feature = "array_any_all")]
@@ -26,19 +46,29 @@ pub enum IRArrayFunction {
     ArgMin,
     ArgMax,
     Get(bool),
+    Scatter(bool),
+    SetAtIdxMasked,
     Join(bool),
     #[cfg(feature = "is_in")]
     Contains {
         nulls_equal: bool,
+        sorted_rows: Option<SortedRowsOptions>,
     },
     #[cfg(feature = "array_count")]
-    CountMatches,
+    CountMatches(Option<SortedRowsOptions>),
+    RowsAreSorted {
+        descending: bool,
+    },
     Shift,
     Explode(ExplodeOptions),
     Concat,
     Slice(i64, i64),
     #[cfg(feature = "array_to_struct")]
     ToStruct(Option<DslNameGenerator>),
+    CastInner {
+        dtype: DataType,
+        options: CastOptions,
+    },
 }
 
 impl<'a> FieldsMapper<'a> {
@@ -57,6 +87,10 @@ impl IRArrayFunction {
     pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
         use IRArrayFunction::*;
 
+        if let DataType::Array(inner, _) = mapper.args()[0].dtype() {
+            self.ensure_supported_inner_dtype(inner, mapper.args())?;
+        }
+
         match self {
             Concat => Ok(Field::new(
                 mapper
@@ -83,6 +117,35 @@ impl IRArrayFunction {
             Var(_) => mapper.ensure_is_array()?.var_dtype(),
             Mean => mapper.ensure_is_array()?.moment_dtype(),
             Median => mapper.ensure_is_array()?.moment_dtype(),
+            LeafSum => mapper.ensure_is_array()?.try_map_dtype(|dt| {
+                Ok(match map_array_dtype_to_leaf_dtype(dt)? {
+                    DataType::Int8 | DataType::UInt8 | DataType::Int16 | DataType::UInt16 => {
+                        DataType::Int64
+                    },
+                    dt => dt,
+                })
+            }),
+            LeafMean => mapper.ensure_is_array()?.try_map_dtype(|dt| {
+                Ok(match map_array_dtype_to_leaf_dtype(dt)? {
+                    #[cfg(feature = "dtype-f16")]
+                    DataType::Float16 => DataType::Float16,
+                    DataType::Float32 => DataType::Float32,
+                    _ => DataType::Float64,
+                })
+            }),
+            LeafMin | LeafMax => mapper
+                .ensure_is_array()?
+                .try_map_dtype(map_array_dtype_to_leaf_dtype),
+            #[cfg(feature = "cum_agg")]
+            CumSum { .. } => mapper
+                .ensure_is_array()?
+                .try_map_dtype(|dt| map_array_inner_dtype(dt, super::cum::dtypes::cum_sum)),
+            #[cfg(feature = "cum_agg")]
+            CumProd { .. } => mapper
+                .ensure_is_array()?
+                .try_map_dtype(|dt| map_array_inner_dtype(dt, super::cum::dtypes::cum_prod)),
+            #[cfg(feature = "cum_agg")]
+            CumMin { .. } | CumMax { .. } => mapper.ensure_is_array()?.with_same_dtype(),
             #[cfg(feature = "array_any_all")]
             Any | All => mapper.ensure_is_array()?.with_dtype(DataType::Boolean),
             Sort(_) => mapper.ensure_is_array()?.with_same_dtype(),
@@ -91,11 +154,39 @@ impl IRArrayFunction {
             Get(_) => mapper
                 .ensure_is_array()?
                 .map_to_list_and_array_inner_dtype(),
+            Scatter(_) | SetAtIdxMasked => {
+                let mapper = mapper.ensure_is_array()?;
+                let (selector, value) = (mapper.args()[1].dtype(), mapper.args()[2].dtype());
+                mapper.try_map_dtype(|dt| {
+                    let DataType::Array(inner, width) = dt else {
+                        polars_bail!(InvalidOperation: "expected Array type, got: {dt}")
+                    };
+                    if let Scatter(_) = self {
+                        polars_ensure!(
+                            selector.is_integer() || selector.is_null(),
+                            op = format_args!("`{self}` index"),
+                            selector
+                        );
+                    } else {
+                        let is_mask = matches!(
+                            selector,
+                            DataType::Array(mask, mask_width) if mask.is_bool() && mask_width == width
+                        );
+                        polars_ensure!(
+                            is_mask,
+                            InvalidOperation: "`{self}` mask must be an array of booleans of width {width}, got: {selector}"
+                        );
+                    }
+                    let inner = try_get_supertype(inner, value)?.materialize_unknown(true)?;
+                    Ok(DataType::Array(Box::new(inner), *width))
+                })
+            },
             Join(_) => mapper.ensure_is_array()?.with_dtype(DataType::String),
             #[cfg(feature = "is_in")]
-            Contains { nulls_equal: _ } => mapper.ensure_is_array()?.with_dtype(DataType::Boolean),
+            Contains { .. } => mapper.ensure_is_array()?.with_dtype(DataType::Boolean),
             #[cfg(feature = "array_count")]
-            CountMatches => mapper.ensure_is_array()?.with_dtype(IDX_DTYPE),
+            CountMatches(_) => mapper.ensure_is_array()?.with_dtype(IDX_DTYPE),
+            RowsAreSorted { .. } => mapper.ensure_is_array()?.with_dtype(DataType::Boolean),
             Shift => mapper.ensure_is_array()?.with_same_dtype(),
             Explode { .. } => mapper.ensure_is_array()?.try_map_to_array_inner_dtype(),
             Slice(offset, length) => mapper
@@ -118,7 +209,93 @@ impl IRArrayFunction {
                     .collect::<PolarsResult<Vec<Field>>>()
                     .map(DataType::Struct)
             }),
+            CastInner { dtype, .. } => mapper.ensure_is_array()?.try_map_dtype(|dt| {
+                let DataType::Array(_, width) = dt else {
+                    polars_bail!(InvalidOperation: "expected Array type, got: {dt}")
+                };
+                Ok(DataType::Array(Box::new(dtype.clone()), *width))
+            }),
+        }
+    }
+
+    /// Reject the inner dtypes that the array kernels don't support, so that this is raised when
+    /// resolving the schema instead of when executing the query.
+    #[cfg_attr(not(feature = "array_count"), allow(unused_variables))]
+    fn ensure_supported_inner_dtype(&self, inner: &DataType, args: &[Field]) -> PolarsResult<()> {
+        use IRArrayFunction::*;
+
+        if inner.is_null() || inner.is_unknown() {
+            return Ok(());
+        }
+        match self {
+            Min | Max | RowsAreSorted { .. } => polars_ensure!(
+                inner.is_primitive_numeric(),
+                op = format_args!("`array.{self}`"),
+                inner
+            ),
+            Sum => polars_ensure!(
+                inner.is_bool() || inner.is_primitive_numeric(),
+                op = format_args!("`array.{self}`"),
+                inner
+            ),
+            LeafSum | LeafMean | LeafMin | LeafMax => {
+                let leaf = inner.array_leaf_dtype().unwrap_or(inner);
+                polars_ensure!(
+                    leaf.is_unknown() || leaf.is_primitive_numeric(),
+                    op = format_args!("`array.{self}`"),
+                    leaf
+                )
+            },
+            #[cfg(feature = "cum_agg")]
+            CumSum { .. } => polars_ensure!(
+                inner.is_bool() || inner.is_numeric() || inner.is_duration(),
+                op = format_args!("`array.{self}`"),
+                inner
+            ),
+            #[cfg(feature = "cum_agg")]
+            CumProd { .. } => polars_ensure!(
+                inner.is_bool() || inner.is_primitive_numeric(),
+                op = format_args!("`array.{self}`"),
+                inner
+            ),
+            #[cfg(feature = "cum_agg")]
+            CumMin { .. } | CumMax { .. } => polars_ensure!(
+                inner.is_bool() || inner.to_physical().is_primitive_numeric(),
+                op = format_args!("`array.{self}`"),
+                inner
+            ),
+            Mean | Median | Std(_) | Var(_) => polars_ensure!(
+                inner.is_bool() || inner.is_numeric() || inner.is_duration(),
+                op = format_args!("`array.{self}`"),
+                inner
+            ),
+            #[cfg(feature = "array_any_all")]
+            Any | All => polars_ensure!(
+                inner.is_bool(),
+                ComputeError: "expected boolean elements in array"
+            ),
+            Join(_) => polars_ensure!(
+                inner.is_string(),
+                op = format_args!("`array.{self}`"),
+                got = inner,
+                expected = "String"
+            ),
+            #[cfg(feature = "array_count")]
+            CountMatches(_) => {
+                // The elements are compared with `equal_missing`.
+                let element = args[1].dtype().clone().materialize_unknown(true)?;
+                match (inner, &element) {
+                    (DataType::String, dt) | (dt, DataType::String)
+                        if dt.is_primitive_numeric() =>
+                    {
+                        polars_bail!(ComputeError: "cannot compare string with numeric type ({})", dt)
+                    },
+                    _ => {},
+                }
+            },
+            _ => {},
         }
+        Ok(())
     }
 
     pub fn function_options(&self) -> FunctionOptions {
@@ -127,9 +304,10 @@ impl IRArrayFunction {
             #[cfg(feature = "array_any_all")]
             A::Any | A::All => FunctionOptions::elementwise(),
             #[cfg(feature = "is_in")]
-            A::Contains { nulls_equal: _ } => FunctionOptions::elementwise(),
+            A::Contains { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "array_count")]
-            A::CountMatches => FunctionOptions::elementwise(),
+            A::CountMatches(_) => FunctionOptions::elementwise(),
+            A::RowsAreSorted { .. } => FunctionOptions::elementwise(),
             A::Concat => FunctionOptions::elementwise()
                 .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION),
             A::Length
@@ -143,17 +321,28 @@ impl IRArrayFunction {
             | A::Var(_)
             | A::Mean
             | A::Median
+            | A::LeafSum
+            | A::LeafMean
+            | A::LeafMin
+            | A::LeafMax
             | A::Sort(_)
             | A::Reverse
             | A::ArgMin
             | A::ArgMax
             | A::Get(_)
+            | A::Scatter(_)
+            | A::SetAtIdxMasked
             | A::Join(_)
             | A::Shift
             | A::Slice(_, _) => FunctionOptions::elementwise(),
             A::Explode { .. } => FunctionOptions::row_separable(),
             #[cfg(feature = "array_to_struct")]
             A::ToStruct(_) => FunctionOptions::elementwise(),
+            A::CastInner { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "cum_agg")]
+            A::CumSum { .. } | A::CumProd { .. } | A::CumMin { .. } | A::CumMax { .. } => {
+                FunctionOptions::elementwise()
+            },
         }
     }
 }
@@ -166,6 +355,25 @@ fn map_array_dtype_to_list_dtype(datatype: &DataType) -> PolarsResult<DataType>
     }
 }
 
+fn map_array_dtype_to_leaf_dtype(datatype: &DataType) -> PolarsResult<DataType> {
+    datatype
+        .array_leaf_dtype()
+        .cloned()
+        .ok_or_else(|| polars_err!(ComputeError: "expected array dtype"))
+}
+
+#[cfg(feature = "cum_agg")]
+fn map_array_inner_dtype(
+    datatype: &DataType,
+    func: impl FnOnce(&DataType) -> DataType,
+) -> PolarsResult<DataType> {
+    if let DataType::Array(inner, width) = datatype {
+        Ok(DataType::Array(Box::new(func(inner)), *width))
+    } else {
+        polars_bail!(ComputeError: "expected array dtype")
+    }
+}
+
 fn map_to_array_fixed_length(
     offset: &i64,
     length: &i64,
@@ -203,6 +411,18 @@ impl Display for IRArrayFunction {
             Var(_) => "var",
             Mean => "mean",
             Median => "median",
+            LeafSum => "leaf_sum",
+            LeafMean => "leaf_mean",
+            LeafMin => "leaf_min",
+            LeafMax => "leaf_max",
+            #[cfg(feature = "cum_agg")]
+            CumSum { .. } => "cum_sum",
+            #[cfg(feature = "cum_agg")]
+            CumProd { .. } => "cum_prod",
+            #[cfg(feature = "cum_agg")]
+            CumMin { .. } => "cum_min",
+            #[cfg(feature = "cum_agg")]
+            CumMax { .. } => "cum_max",
             #[cfg(feature = "array_any_all")]
             Any => "any",
             #[cfg(feature = "array_any_all")]
@@ -212,16 +432,20 @@ impl Display for IRArrayFunction {
             ArgMin => "arg_min",
             ArgMax => "arg_max",
             Get(_) => "get",
+            Scatter(_) => "scatter",
+            SetAtIdxMasked => "set_at_idx_masked",
             Join(_) => "join",
             #[cfg(feature = "is_in")]
-            Contains { nulls_equal: _ } => "contains",
+            Contains { .. } => "contains",
             #[cfg(feature = "array_count")]
-            CountMatches => "count_matches",
+            CountMatches(_) => "count_matches",
+            RowsAreSorted { .. } => "rows_are_sorted",
             Shift => "shift",
             Slice(_, _) => "slice",
             Explode { .. } => "explode",
             #[cfg(feature = "array_to_struct")]
             ToStruct(_) => "to_struct",
+            CastInner { .. } => "cast_inner",
         };
         write!(f, "arr.{name}")
     }
@@ -1,5 +1,7 @@
 use polars_core::utils::SuperTypeOptions;
 
+#[cfg(feature = "dtype-array")]
+use super::schema::args_to_supertype;
 use super::*;
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
@@ -56,6 +58,12 @@ pub enum IRListFunction {
     Join(bool),
     #[cfg(feature = "dtype-array")]
     ToArray(usize),
+    #[cfg(feature = "dtype-array")]
+    PadToArray {
+        width: usize,
+        side: PadSide,
+        truncate_side: PadSide,
+    },
     #[cfg(feature = "list_to_struct")]
     ToStruct(Arc<[PlSmallStr]>),
 }
@@ -148,6 +156,13 @@ impl IRListFunction {
             ToArray(width) => mapper
                 .ensure_is_list()?
                 .try_map_dtype(|dt| map_list_dtype_to_array_dtype(dt, *width)),
+            #[cfg(feature = "dtype-array")]
+            PadToArray { width, .. } => {
+                let mapper = mapper.ensure_is_list()?;
+                let inner = mapper.args()[0].dtype().inner_dtype().unwrap();
+                let inner = args_to_supertype(&[inner, mapper.args()[1].dtype()])?;
+                mapper.with_dtype(DataType::Array(Box::new(inner), *width))
+            },
             NUnique => mapper.ensure_is_list()?.with_dtype(IDX_DTYPE),
             #[cfg(feature = "list_to_struct")]
             ToStruct(names) => mapper.try_map_dtype(|dtype| {
@@ -216,6 +231,8 @@ impl IRListFunction {
             L::Any | L::All => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-array")]
             L::ToArray(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "dtype-array")]
+            L::PadToArray { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "list_to_struct")]
             L::ToStruct(_) => FunctionOptions::elementwise(),
         }
@@ -289,6 +306,8 @@ impl Display for IRListFunction {
             Join(_) => "join",
             #[cfg(feature = "dtype-array")]
             ToArray(_) => "to_array",
+            #[cfg(feature = "dtype-array")]
+            PadToArray { .. } => "pad_to_array",
             #[cfg(feature = "list_to_struct")]
             ToStruct(_) => "to_struct",
         };
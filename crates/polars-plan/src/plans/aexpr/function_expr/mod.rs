@@ -173,6 +173,8 @@ pub enum IRFunctionExpr {
     Kurtosis(bool, bool),
     #[cfg(feature = "dtype-array")]
     Reshape(Vec<ReshapeDimension>),
+    #[cfg(feature = "dtype-array")]
+    ImplodeToArray(usize),
     #[cfg(feature = "repeat_by")]
     RepeatBy,
     ArgUnique,
@@ -626,6 +628,8 @@ impl Hash for IRFunctionExpr {
             },
             #[cfg(feature = "dtype-array")]
             Reshape(dims) => dims.hash(state),
+            #[cfg(feature = "dtype-array")]
+            ImplodeToArray(width) => width.hash(state),
             #[cfg(feature = "repeat_by")]
             RepeatBy => {},
             #[cfg(feature = "cutqcut")]
@@ -853,6 +857,8 @@ impl Display for IRFunctionExpr {
             QCut { .. } => "qcut",
             #[cfg(feature = "dtype-array")]
             Reshape(_) => "reshape",
+            #[cfg(feature = "dtype-array")]
+            ImplodeToArray(_) => "implode_to_array",
             #[cfg(feature = "repeat_by")]
             RepeatBy => "repeat_by",
             #[cfg(feature = "rle")]
@@ -1079,6 +1085,8 @@ impl IRFunctionExpr {
                     FunctionOptions::groupwise()
                 }
             },
+            #[cfg(feature = "dtype-array")]
+            F::ImplodeToArray(_) => FunctionOptions::aggregation(),
             #[cfg(feature = "repeat_by")]
             F::RepeatBy => FunctionOptions::elementwise(),
             F::ArgUnique => FunctionOptions::groupwise(),
@@ -8,6 +8,7 @@ use polars_time::{ClosedWindow, Duration};
 use super::{FunctionOptions, IRFunctionExpr};
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 use crate::dsl::function_expr::DateRangeArgs;
+use crate::dsl::function_expr::RangeNullBehavior;
 use crate::plans::aexpr::function_expr::FieldsMapper;
 use crate::prelude::FunctionFlags;
 
@@ -17,10 +18,16 @@ pub enum IRRangeFunction {
     IntRange {
         step: i64,
         dtype: DataType,
+        null_behavior: RangeNullBehavior,
     },
     IntRanges {
         dtype: DataType,
     },
+    IntRangeArray {
+        len: usize,
+        step: i64,
+        dtype: DataType,
+    },
     LinearSpace {
         closed: ClosedInterval,
     },
@@ -100,6 +107,9 @@ impl IRRangeFunction {
         match self {
             IntRange { dtype, .. } => mapper.with_dtype(dtype.clone()),
             IntRanges { dtype } => mapper.with_dtype(DataType::List(Box::new(dtype.clone()))),
+            IntRangeArray { len, dtype, .. } => {
+                mapper.with_dtype(DataType::Array(Box::new(dtype.clone()), *len))
+            },
             LinearSpace { .. } => mapper.with_dtype(map_linspace_dtype(&mapper)?),
             LinearSpaces {
                 closed: _,
@@ -186,7 +196,7 @@ impl IRRangeFunction {
             R::TimeRange { .. } => {
                 FunctionOptions::row_separable().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
             },
-            R::IntRanges { .. } => {
+            R::IntRanges { .. } | R::IntRangeArray { .. } => {
                 FunctionOptions::elementwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
             },
             R::LinearSpaces { .. } => {
@@ -206,6 +216,7 @@ impl Display for IRRangeFunction {
         let s = match self {
             IntRange { .. } => "int_range",
             IntRanges { .. } => "int_ranges",
+            IntRangeArray { .. } => "int_range_array",
             LinearSpace { .. } => "linear_space",
             LinearSpaces { .. } => "linear_spaces",
             #[cfg(feature = "dtype-date")]
@@ -65,6 +65,32 @@ impl IRFunctionExpr {
             #[cfg(feature = "rolling_window")]
             RollingExpr { function, options } => {
                 use IRRollingFunction::*;
+                // Reject the dtypes that the rolling kernels don't support.
+                let dtype = mapper.args()[0].dtype();
+                let is_min_max_dtype =
+                    dtype.is_bool() || dtype.is_primitive_numeric() || dtype.is_temporal();
+                let is_float_dtype = dtype.is_bool() || dtype.is_numeric() || dtype.is_temporal();
+                let unsupported_op = match function {
+                    _ if dtype.is_null() || dtype.is_unknown() => None,
+                    Min if !is_min_max_dtype => Some("rolling_min"),
+                    Max if !is_min_max_dtype => Some("rolling_max"),
+                    Sum if !(dtype.is_bool() || dtype.is_primitive_numeric()) => {
+                        Some("rolling_sum")
+                    },
+                    Mean if !is_float_dtype => Some("rolling_mean"),
+                    Quantile if !is_float_dtype => Some("rolling_quantile"),
+                    Std if !is_float_dtype => Some("rolling_std"),
+                    Var if !is_float_dtype => Some("rolling_var"),
+                    #[cfg(feature = "moment")]
+                    Skew if !dtype.is_primitive_numeric() => Some("`rolling_skew`"),
+                    #[cfg(feature = "moment")]
+                    Kurtosis if !dtype.is_primitive_numeric() => Some("`rolling_kurtosis`"),
+                    _ => None,
+                };
+                if let Some(op) = unsupported_op {
+                    polars_bail!(op = op, dtype);
+                }
+
                 match function {
                     Min | Max => mapper.with_same_dtype(),
                     Mean | Quantile | Std => mapper.moment_dtype(),
@@ -314,6 +340,10 @@ impl IRFunctionExpr {
                 }
                 Ok(wrapped_dtype)
             }),
+            #[cfg(feature = "dtype-array")]
+            ImplodeToArray(width) => {
+                mapper.map_dtype(|dt| DataType::Array(Box::new(dt.clone()), *width))
+            },
             #[cfg(feature = "cutqcut")]
             QCut {
                 include_breaks: false,
@@ -403,14 +433,20 @@ impl IRFunctionExpr {
                 f
             }),
             MeanHorizontal { .. } => mapper.map_to_supertype().map(|mut f| {
-                match f.dtype {
+                let float_dtype = |dtype: &DataType| match dtype {
                     #[cfg(feature = "dtype-f16")]
-                    DataType::Float16 => {},
-                    DataType::Float32 => {},
-                    _ => {
-                        f.dtype = DataType::Float64;
+                    DataType::Float16 => DataType::Float16,
+                    DataType::Float32 => DataType::Float32,
+                    _ => DataType::Float64,
+                };
+                f.dtype = match &f.dtype {
+                    // Array inputs are averaged element-wise.
+                    #[cfg(feature = "dtype-array")]
+                    DataType::Array(inner, width) => {
+                        DataType::Array(Box::new(float_dtype(inner)), *width)
                     },
-                }
+                    dtype => float_dtype(dtype),
+                };
                 f
             }),
             #[cfg(feature = "ewma")]
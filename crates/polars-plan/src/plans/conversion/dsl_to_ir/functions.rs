@@ -56,7 +56,8 @@ pub(super) fn convert_functions(
     let input_is_empty = input.is_empty();
 
     // Converts inputs
-    let e = to_expr_irs(input, ctx)?;
+    #[cfg_attr(not(feature = "dtype-array"), allow(unused_mut))]
+    let mut e = to_expr_irs(input, ctx)?;
     let mut set_elementwise = false;
 
     // Return before converting inputs
@@ -76,6 +77,18 @@ pub(super) fn convert_functions(
                 A::Var(v) => IA::Var(v),
                 A::Mean => IA::Mean,
                 A::Median => IA::Median,
+                A::LeafSum => IA::LeafSum,
+                A::LeafMean => IA::LeafMean,
+                A::LeafMin => IA::LeafMin,
+                A::LeafMax => IA::LeafMax,
+                #[cfg(feature = "cum_agg")]
+                A::CumSum { reverse } => IA::CumSum { reverse },
+                #[cfg(feature = "cum_agg")]
+                A::CumProd { reverse } => IA::CumProd { reverse },
+                #[cfg(feature = "cum_agg")]
+                A::CumMin { reverse } => IA::CumMin { reverse },
+                #[cfg(feature = "cum_agg")]
+                A::CumMax { reverse } => IA::CumMax { reverse },
                 #[cfg(feature = "array_any_all")]
                 A::Any => IA::Any,
                 #[cfg(feature = "array_any_all")]
@@ -85,17 +98,30 @@ pub(super) fn convert_functions(
                 A::ArgMin => IA::ArgMin,
                 A::ArgMax => IA::ArgMax,
                 A::Get(v) => IA::Get(v),
+                A::Scatter(v) => IA::Scatter(v),
+                A::SetAtIdxMasked => IA::SetAtIdxMasked,
                 A::Join(v) => IA::Join(v),
                 #[cfg(feature = "is_in")]
-                A::Contains { nulls_equal } => IA::Contains { nulls_equal },
+                A::Contains {
+                    nulls_equal,
+                    sorted_rows,
+                } => IA::Contains {
+                    nulls_equal,
+                    sorted_rows,
+                },
                 #[cfg(feature = "array_count")]
-                A::CountMatches => IA::CountMatches,
+                A::CountMatches(sorted_rows) => IA::CountMatches(sorted_rows),
+                A::RowsAreSorted { descending } => IA::RowsAreSorted { descending },
                 A::Shift => IA::Shift,
                 A::Explode(options) => IA::Explode(options),
                 A::Concat => IA::Concat,
                 A::Slice(offset, length) => IA::Slice(offset, length),
                 #[cfg(feature = "array_to_struct")]
                 A::ToStruct(ng) => IA::ToStruct(ng),
+                A::CastInner { dtype, options } => IA::CastInner {
+                    dtype: dtype.into_datatype(ctx.schema)?,
+                    options,
+                },
             })
         },
         F::BinaryExpr(binary_function) => {
@@ -218,6 +244,37 @@ pub(super) fn convert_functions(
                 L::Join(v) => IL::Join(v),
                 #[cfg(feature = "dtype-array")]
                 L::ToArray(v) => IL::ToArray(v),
+                #[cfg(feature = "dtype-array")]
+                L::PadToArray {
+                    width,
+                    side,
+                    truncate_side,
+                } => {
+                    polars_ensure!(e[1].is_scalar(ctx.arena), ShapeMismatch: "'fill' must be a scalar value");
+                    let function = IL::PadToArray {
+                        width,
+                        side,
+                        truncate_side,
+                    };
+
+                    // Cast the fill value to the inner dtype of the output, otherwise e.g. an
+                    // integer literal would be materialized as `Int32` and widen the values.
+                    let fields = [
+                        e[0].field(ctx.schema, ctx.arena)?,
+                        e[1].field(ctx.schema, ctx.arena)?,
+                    ];
+                    let out = I::ListExpr(function.clone()).get_field(ctx.schema, &fields)?;
+                    let DataType::Array(inner, _) = out.dtype else {
+                        unreachable!()
+                    };
+                    if fields[1].dtype() != inner.as_ref() {
+                        let fill = AExprBuilder::new_from_node(e[1].node())
+                            .cast(*inner, ctx.arena)
+                            .node();
+                        e[1] = ExprIR::new(fill, e[1].output_name_inner().clone());
+                    }
+                    function
+                },
                 #[cfg(feature = "list_to_struct")]
                 L::ToStruct(list_to_struct_args) => IL::ToStruct(list_to_struct_args),
             })
@@ -621,18 +678,31 @@ pub(super) fn convert_functions(
         F::SearchSorted { side, descending } => I::SearchSorted { side, descending },
         #[cfg(feature = "range")]
         F::Range(range_function) => I::Range(match range_function {
-            RangeFunction::IntRange { step, dtype } => {
+            RangeFunction::IntRange {
+                step,
+                dtype,
+                null_behavior,
+            } => {
                 let dtype = dtype.into_datatype(ctx.schema)?;
                 polars_ensure!(e[0].is_scalar(ctx.arena), ShapeMismatch: "non-scalar start passed to `int_range`");
                 polars_ensure!(e[1].is_scalar(ctx.arena), ShapeMismatch: "non-scalar stop passed to `int_range`");
                 polars_ensure!(dtype.is_integer(), SchemaMismatch: "non-integer `dtype` passed to `int_range`: '{dtype}'");
-                IRRangeFunction::IntRange { step, dtype }
+                IRRangeFunction::IntRange {
+                    step,
+                    dtype,
+                    null_behavior,
+                }
             },
             RangeFunction::IntRanges { dtype } => {
                 let dtype = dtype.into_datatype(ctx.schema)?;
                 polars_ensure!(dtype.is_integer(), SchemaMismatch: "non-integer `dtype` passed to `int_ranges`: '{dtype}'");
                 IRRangeFunction::IntRanges { dtype }
             },
+            RangeFunction::IntRangeArray { len, step, dtype } => {
+                let dtype = dtype.into_datatype(ctx.schema)?;
+                polars_ensure!(dtype.is_integer(), SchemaMismatch: "non-integer `dtype` passed to `int_range_array`: '{dtype}'");
+                IRRangeFunction::IntRangeArray { len, step, dtype }
+            },
             RangeFunction::LinearSpace { closed } => {
                 polars_ensure!(e[0].is_scalar(ctx.arena), ShapeMismatch: "non-scalar start passed to `linear_space`");
                 polars_ensure!(e[1].is_scalar(ctx.arena), ShapeMismatch: "non-scalar end passed to `linear_space`");
@@ -831,6 +901,8 @@ pub(super) fn convert_functions(
         F::Kurtosis(l, r) => I::Kurtosis(l, r),
         #[cfg(feature = "dtype-array")]
         F::Reshape(reshape_dimensions) => I::Reshape(reshape_dimensions),
+        #[cfg(feature = "dtype-array")]
+        F::ImplodeToArray(width) => I::ImplodeToArray(width),
         #[cfg(feature = "repeat_by")]
         F::RepeatBy => I::RepeatBy,
         F::ArgUnique => I::ArgUnique,
@@ -309,6 +309,16 @@ pub fn resolve_join(
                 lnode.set_node(casted_l);
                 rnode.set_node(casted_r);
             }
+        } else if is_categorical_string_pair(&ltype, &rtype)
+            && (options.args.how.is_equi() || options.args.how.is_semi_anti())
+        {
+            // These are joined on the strings of the categories, without casting the categorical
+            // key. A coalescing full join puts both keys in one column though.
+            polars_ensure!(
+                !key_cols_coalesced,
+                SchemaMismatch: "coalescing full join on a Categorical and a String key is not supported - cast `{}`: {} on left or `{}`: {} on right to String",
+                lnode.output_name(), ltype, rnode.output_name(), rtype
+            )
         } else {
             polars_ensure!(
                 ltype == rtype,
@@ -440,6 +450,12 @@ pub fn resolve_join(
     }
 }
 
+/// Whether two join keys are a `Categorical` and a `String` key, which the equi-joins join
+/// without a cast.
+fn is_categorical_string_pair(left: &DataType, right: &DataType) -> bool {
+    (left.is_categorical() && right.is_string()) || (left.is_string() && right.is_categorical())
+}
+
 #[cfg(feature = "iejoin")]
 impl From<InequalityOperator> for Operator {
     fn from(value: InequalityOperator) -> Self {
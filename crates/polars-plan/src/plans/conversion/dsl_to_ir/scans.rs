@@ -257,15 +257,87 @@ pub(super) fn parquet_file_info(
 
     let known_size = if n_sources == 1 { Some(num_rows) } else { None };
 
-    let file_info = FileInfo::new(
+    // Statistics in the footer only describe the first file, so we can only use them if it is
+    // the only file.
+    let column_statistics = (n_sources == 1).then(|| {
+        let reader_schema = reader_schema.clone();
+        let metadata = metadata.clone();
+        ScanColumnStatistics::new(
+            reader_schema.iter_names_cloned().collect::<Vec<_>>(),
+            move |name| parquet_column_statistics(reader_schema.as_ref(), &metadata, name),
+        )
+    });
+
+    let mut file_info = FileInfo::new(
         schema,
         Some(Either::Left(reader_schema)),
         (known_size, num_rows * n_sources),
     );
+    file_info.column_statistics = column_statistics;
 
     Ok((file_info, Some(metadata)))
 }
 
+/// Aggregate the row group statistics in the Parquet footer of column `name`.
+///
+/// This only looks at the metadata and never reads any data pages. Statistics that cannot be
+/// decoded are treated as missing.
+#[cfg(feature = "parquet")]
+fn parquet_column_statistics(
+    reader_schema: &ArrowSchema,
+    metadata: &FileMetadataRef,
+    name: &str,
+) -> Option<ColumnStatistics> {
+    use arrow::array::Array;
+    use polars_parquet::read::statistics::deserialize_all;
+
+    // A scalar is only usable if every row group has a value for the statistic.
+    fn reduce_scalar(
+        values: ArrayRef,
+        field: &ArrowField,
+        reduce: impl Fn(&Series) -> PolarsResult<Scalar>,
+    ) -> Option<Scalar> {
+        if values.null_count() > 0 {
+            return None;
+        }
+        let s = unsafe {
+            Series::_try_from_arrow_unchecked_with_md(
+                PlSmallStr::EMPTY,
+                vec![values],
+                field.dtype(),
+                field.metadata.as_deref(),
+            )
+        }
+        .ok()?;
+        let scalar = reduce(&s).ok()?;
+        (!scalar.is_null()).then_some(scalar)
+    }
+
+    let row_groups = metadata.row_groups.as_slice();
+    let field = reader_schema.get(name)?;
+    // Nested columns span multiple leaf columns, we don't combine those.
+    let &[idx] = row_groups.first()?.columns_idxs_under_root_iter(name)? else {
+        return None;
+    };
+    let stats = deserialize_all(field, row_groups, idx).ok()??;
+
+    let null_count = (stats.null_count.null_count() == 0)
+        .then(|| stats.null_count.values_iter().map(|&v| v as usize).sum());
+    // Distinct counts cannot be combined over row groups.
+    let distinct_count = if row_groups.len() == 1 {
+        stats.distinct_count.get(0).map(|v| v as usize)
+    } else {
+        None
+    };
+
+    Some(ColumnStatistics {
+        null_count,
+        distinct_count,
+        min_value: reduce_scalar(stats.min_value, field, |s| s.min_reduce()),
+        max_value: reduce_scalar(stats.max_value, field, |s| s.max_reduce()),
+    })
+}
+
 pub fn max_metadata_scan_cached() -> usize {
     static MAX_SCANS_METADATA_CACHED: LazyLock<usize> = LazyLock::new(|| {
         let value = std::env::var("POLARS_MAX_CACHED_METADATA_SCANS").map_or(8, |v| {
@@ -552,6 +624,7 @@ impl SourcesToFileInfo {
                                 schema.to_arrow(CompatLevel::newest()),
                             ))),
                             row_estimation: (None, usize::MAX),
+                            column_statistics: None,
                         },
                         FileScanIR::Parquet {
                             options,
@@ -637,6 +710,7 @@ this scan to succeed with an empty DataFrame.",
                             schema: schema.clone(),
                             reader_schema: Some(either::Either::Right(schema)),
                             row_estimation: (None, usize::MAX),
+                            column_statistics: None,
                         }
                     } else {
                         let first_scan_source =
@@ -669,6 +743,7 @@ this scan to succeed with an empty DataFrame.",
                         schema: schema.clone(),
                         reader_schema: Some(either::Either::Right(schema)),
                         row_estimation: (None, usize::MAX),
+                        column_statistics: None,
                     }
                 } else {
                     let first_scan_source =
@@ -711,6 +786,7 @@ this scan to succeed with an empty DataFrame.",
                         schema,
                         reader_schema: Some(either::Either::Right(reader_schema)),
                         row_estimation: (None, usize::MAX),
+                        column_statistics: None,
                     },
                     FileScanIR::PythonDataset {
                         dataset_object,
@@ -730,6 +806,7 @@ this scan to succeed with an empty DataFrame.",
                         schema: schema.clone(),
                         reader_schema: Some(either::Either::Right(schema.clone())),
                         row_estimation: (None, usize::MAX),
+                        column_statistics: None,
                     },
                     FileScanIR::Lines { name },
                 )
@@ -319,6 +319,18 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IA::Var(v) => A::Var(v),
                 IA::Mean => A::Mean,
                 IA::Median => A::Median,
+                IA::LeafSum => A::LeafSum,
+                IA::LeafMean => A::LeafMean,
+                IA::LeafMin => A::LeafMin,
+                IA::LeafMax => A::LeafMax,
+                #[cfg(feature = "cum_agg")]
+                IA::CumSum { reverse } => A::CumSum { reverse },
+                #[cfg(feature = "cum_agg")]
+                IA::CumProd { reverse } => A::CumProd { reverse },
+                #[cfg(feature = "cum_agg")]
+                IA::CumMin { reverse } => A::CumMin { reverse },
+                #[cfg(feature = "cum_agg")]
+                IA::CumMax { reverse } => A::CumMax { reverse },
                 #[cfg(feature = "array_any_all")]
                 IA::Any => A::Any,
                 #[cfg(feature = "array_any_all")]
@@ -328,16 +340,29 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IA::ArgMin => A::ArgMin,
                 IA::ArgMax => A::ArgMax,
                 IA::Get(v) => A::Get(v),
+                IA::Scatter(v) => A::Scatter(v),
+                IA::SetAtIdxMasked => A::SetAtIdxMasked,
                 IA::Join(v) => A::Join(v),
                 #[cfg(feature = "is_in")]
-                IA::Contains { nulls_equal } => A::Contains { nulls_equal },
+                IA::Contains {
+                    nulls_equal,
+                    sorted_rows,
+                } => A::Contains {
+                    nulls_equal,
+                    sorted_rows,
+                },
                 #[cfg(feature = "array_count")]
-                IA::CountMatches => A::CountMatches,
+                IA::CountMatches(sorted_rows) => A::CountMatches(sorted_rows),
+                IA::RowsAreSorted { descending } => A::RowsAreSorted { descending },
                 IA::Shift => A::Shift,
                 IA::Slice(offset, length) => A::Slice(offset, length),
                 IA::Explode(options) => A::Explode(options),
                 #[cfg(feature = "array_to_struct")]
                 IA::ToStruct(ng) => A::ToStruct(ng),
+                IA::CastInner { dtype, options } => A::CastInner {
+                    dtype: dtype.into(),
+                    options,
+                },
             })
         },
         IF::BinaryExpr(f) => {
@@ -441,6 +466,16 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IL::Join(v) => L::Join(v),
                 #[cfg(feature = "dtype-array")]
                 IL::ToArray(v) => L::ToArray(v),
+                #[cfg(feature = "dtype-array")]
+                IL::PadToArray {
+                    width,
+                    side,
+                    truncate_side,
+                } => L::PadToArray {
+                    width,
+                    side,
+                    truncate_side,
+                },
                 #[cfg(feature = "list_to_struct")]
                 IL::ToStruct(list_to_struct_args) => L::ToStruct(list_to_struct_args),
             })
@@ -770,13 +805,23 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::Range(f) => {
             use {IRRangeFunction as IR, RangeFunction as R};
             F::Range(match f {
-                IR::IntRange { step, dtype } => R::IntRange {
+                IR::IntRange {
+                    step,
+                    dtype,
+                    null_behavior,
+                } => R::IntRange {
                     step,
                     dtype: dtype.into(),
+                    null_behavior,
                 },
                 IR::IntRanges { dtype } => R::IntRanges {
                     dtype: dtype.into(),
                 },
+                IR::IntRangeArray { len, step, dtype } => R::IntRangeArray {
+                    len,
+                    step,
+                    dtype: dtype.into(),
+                },
                 IR::LinearSpace { closed } => R::LinearSpace { closed },
                 IR::LinearSpaces {
                     closed,
@@ -930,6 +975,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::Kurtosis(fisher, bias) => F::Kurtosis(fisher, bias),
         #[cfg(feature = "dtype-array")]
         IF::Reshape(dims) => F::Reshape(dims),
+        #[cfg(feature = "dtype-array")]
+        IF::ImplodeToArray(width) => F::ImplodeToArray(width),
         #[cfg(feature = "repeat_by")]
         IF::RepeatBy => F::RepeatBy,
         IF::ArgUnique => F::ArgUnique,
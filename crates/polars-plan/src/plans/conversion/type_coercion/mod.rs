@@ -195,6 +195,16 @@ impl OptimizationRule for TypeCoercionRule {
                 if type_true == type_false {
                     return Ok(None);
                 }
+                #[cfg(feature = "dtype-array")]
+                if let (DataType::Array(_, width_true), DataType::Array(_, width_false)) =
+                    (&type_true, &type_false)
+                {
+                    polars_ensure!(
+                        width_true == width_false,
+                        ShapeMismatch: "`when/then/otherwise` branches are arrays of different widths: {} and {}",
+                        width_true, width_false
+                    );
+                }
                 let st = unpack!(get_supertype(&type_true, &type_false));
                 let st = modify_supertype(st, truthy, falsy, &type_true, &type_false);
 
@@ -423,6 +433,56 @@ impl OptimizationRule for TypeCoercionRule {
                     options,
                 })
             },
+            // `arr.scatter` and `arr.set_at_idx_masked` should only cast the arrays and the value to
+            // the super type of the inner dtype and the value.
+            #[cfg(feature = "dtype-array")]
+            AExpr::Function {
+                function:
+                    ref function @ IRFunctionExpr::ArrayExpr(
+                        IRArrayFunction::Scatter(_) | IRArrayFunction::SetAtIdxMasked,
+                    ),
+                ref input,
+                options,
+            } => {
+                let array_node = input[0].node();
+                let value_node = input[2].node();
+                let (array, type_array) =
+                    unpack!(get_aexpr_and_type(expr_arena, array_node, schema));
+                let (value, type_value) =
+                    unpack!(get_aexpr_and_type(expr_arena, value_node, schema));
+                let DataType::Array(inner, width) = &type_array else {
+                    return Ok(None);
+                };
+
+                unpack!(early_escape(inner, &type_value));
+
+                let super_type = unpack!(get_supertype(inner, &type_value));
+                let super_type = modify_supertype(super_type, array, value, inner, &type_value);
+
+                let function = function.clone();
+                let mut input = input.clone();
+                let array_type = DataType::Array(Box::new(super_type.clone()), *width);
+                cast_expr_ir(
+                    &mut input[0],
+                    &type_array,
+                    &array_type,
+                    expr_arena,
+                    CastOptions::NonStrict,
+                )?;
+                cast_expr_ir(
+                    &mut input[2],
+                    &type_value,
+                    &super_type,
+                    expr_arena,
+                    CastOptions::NonStrict,
+                )?;
+
+                Some(AExpr::Function {
+                    function,
+                    input,
+                    options,
+                })
+            },
             #[cfg(feature = "ewma")]
             AExpr::Function {
                 function:
@@ -852,8 +912,7 @@ See https://github.com/pola-rs/polars/issues/22149 for more information."
             #[cfg(feature = "range")]
             AExpr::Function {
                 function:
-                    ref
-                    function @ IRFunctionExpr::Range(IRRangeFunction::IntRange { step: _, ref dtype }),
+                    ref function @ IRFunctionExpr::Range(IRRangeFunction::IntRange { ref dtype, .. }),
                 ref input,
                 options,
             } => {
@@ -929,6 +988,36 @@ See https://github.com/pola-rs/polars/issues/22149 for more information."
                     options,
                 })
             },
+            #[cfg(feature = "range")]
+            AExpr::Function {
+                function:
+                    ref function @ IRFunctionExpr::Range(IRRangeFunction::IntRangeArray { .. }),
+                ref input,
+                options,
+            } => {
+                let (_, type_start) =
+                    unpack!(get_aexpr_and_type(expr_arena, input[0].node(), schema));
+
+                if type_start == DataType::Int64 {
+                    return Ok(None);
+                }
+
+                let function = function.clone();
+                let mut input = input.clone();
+                cast_expr_ir(
+                    &mut input[0],
+                    &type_start,
+                    &DataType::Int64,
+                    expr_arena,
+                    CastOptions::Strict,
+                )?;
+
+                Some(AExpr::Function {
+                    function,
+                    input,
+                    options,
+                })
+            },
             #[cfg(feature = "moment")]
             AExpr::Function {
                 function: ref function @ (IRFunctionExpr::Skew(..) | IRFunctionExpr::Kurtosis(..)),
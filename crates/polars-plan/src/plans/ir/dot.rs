@@ -1,25 +1,85 @@
 use std::fmt;
+use std::hash::BuildHasher;
 use std::path::PathBuf;
 
-use polars_core::prelude::{InitHashMaps, PlHashSet};
+use polars_core::prelude::{DataType, InitHashMaps, PlHashMap, PlHashSet};
 use polars_core::schema::Schema;
+use polars_utils::aliases::PlFixedStateQuality;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::unique_id::UniqueId;
 use recursive::recursive;
 
-use super::format::ExprIRSliceDisplay;
+use super::format::{ExprIRSliceDisplay, write_ir_non_recursive};
 use crate::prelude::ir::format::ColumnsDisplay;
 use crate::prelude::*;
 
 pub struct IRDotDisplay<'a> {
     lp: IRPlanRef<'a>,
+    config: DotDisplayConfig,
+    syntax: GraphSyntax,
+}
+
+/// Displays the plan as a Mermaid `flowchart`, with the same nodes and labels as [`IRDotDisplay`].
+pub struct IRMermaidDisplay<'a>(IRDotDisplay<'a>);
+
+/// Displays the plan as a JSON document with the same nodes, labels and edges as [`IRDotDisplay`],
+/// for tools that process plans programmatically.
+///
+/// The document has the form `{"nodes": [...], "edges": [...]}`. Every node has an `id`, a `kind`
+/// (see [`IR::name`]) and a `label`, and cache nodes also have a `cache_id`, so that subplans that
+/// are shared can be detected. Every edge has a `from` and `to` node id, in the direction the data
+/// flows, and an optional `label`.
+pub struct IRJsonDisplay<'a>(IRDotDisplay<'a>);
+
+/// Options for [`IRDotDisplay`], [`IRMermaidDisplay`] and [`IRJsonDisplay`].
+#[derive(Clone, Copy, Debug)]
+pub struct DotDisplayConfig {
+    /// Add a tooltip with the output schema of every node. Only Graphviz output has tooltips.
+    pub show_schema: bool,
+    /// The maximum number of columns listed in a schema tooltip.
+    pub max_columns: usize,
+    /// Derive the node ids from their path from the root instead of the traversal order, so the
+    /// output is deterministic for snapshot tests.
+    pub stable_ids: bool,
+    /// Derive the node ids from a hash of the subplan below them, so that a subplan keeps its ids
+    /// when the plan around it changes. Subplans that are equal to one that was already drawn get
+    /// counted ids instead. Takes precedence over `stable_ids`.
+    pub structural_ids: bool,
+    /// Fill the nodes with a color by their operation, e.g. scans green, joins orange and filters
+    /// blue. Off by default, so the output stays the same for users who diff it.
+    pub color_nodes: bool,
+    /// Render every cache as a single node with the number of times it is used and the number of
+    /// columns it holds, instead of rendering the subplan below it. This keeps plans with many
+    /// shared subplans legible.
+    pub collapse_caches: bool,
+}
+
+impl Default for DotDisplayConfig {
+    fn default() -> Self {
+        Self {
+            show_schema: false,
+            max_columns: 16,
+            stable_ids: false,
+            structural_ids: false,
+            color_nodes: false,
+            collapse_caches: false,
+        }
+    }
 }
 
 const INDENT: &str = "  ";
 
+/// The language that the graph of a plan is written in.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum GraphSyntax {
+    Dot,
+    Mermaid,
+}
+
 #[derive(Clone, Copy)]
 enum DotNode {
     Plain(usize),
+    Path(u64),
     Cache(UniqueId),
 }
 
@@ -27,37 +87,314 @@ impl fmt::Display for DotNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             DotNode::Plain(n) => write!(f, "p{n}"),
+            DotNode::Path(h) => write!(f, "p{h:016x}"),
             DotNode::Cache(n) => write!(f, "\"{n}\""),
         }
     }
 }
 
+/// A [`DotNode`] as a Mermaid node id, which can't be quoted.
+struct MermaidNode(DotNode);
+
+impl fmt::Display for MermaidNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            DotNode::Cache(n) => write!(f, "c{}", n.to_string().replace('-', "")),
+            node => node.fmt(f),
+        }
+    }
+}
+
+/// The color that a node is filled with when [`DotDisplayConfig::color_nodes`] is set.
+///
+/// Nodes that don't read, combine, filter or write data are left unfilled. Caches get a color of
+/// their own, so that subplans that are shared stand out.
+fn node_fill_color(ir: &IR) -> Option<&'static str> {
+    use IR::*;
+    Some(match ir {
+        #[cfg(feature = "python")]
+        PythonScan { .. } => "#b7e4c7",
+        Scan { .. } | DataFrameScan { .. } => "#b7e4c7",
+        Join { .. } => "#ffd6a5",
+        #[cfg(feature = "merge_sorted")]
+        MergeSorted { .. } => "#ffd6a5",
+        Filter { .. } => "#bde0fe",
+        GroupBy { .. } => "#fdffb6",
+        Sink { .. } | SinkMultiple { .. } => "#d9d9d9",
+        Cache { .. } => "#e4c1f9",
+        _ => return None,
+    })
+}
+
 #[inline(always)]
-fn write_label<'a, 'b>(
-    f: &'a mut fmt::Formatter<'b>,
+fn write_label(
+    f: &mut fmt::Formatter<'_>,
+    syntax: GraphSyntax,
     id: DotNode,
-    mut w: impl FnMut(&mut EscapeLabel<'a>) -> fmt::Result,
+    fill_color: Option<&str>,
+    mut w: impl FnMut(&mut dyn fmt::Write) -> fmt::Result,
+) -> fmt::Result {
+    match syntax {
+        GraphSyntax::Dot => {
+            write!(f, "{INDENT}{id}[label=\"")?;
+            w(&mut EscapeLabel(&mut *f))?;
+            f.write_str("\"")?;
+            if let Some(color) = fill_color {
+                write!(f, " style=filled fillcolor=\"{color}\"")?;
+            }
+            writeln!(f, "]")
+        },
+        GraphSyntax::Mermaid => {
+            write!(f, "{INDENT}{}[\"", MermaidNode(id))?;
+            w(&mut EscapeMermaidLabel(&mut *f))?;
+            writeln!(f, "\"]")?;
+            if let Some(color) = fill_color {
+                writeln!(f, "{INDENT}style {} fill:{color}", MermaidNode(id))?;
+            }
+            Ok(())
+        },
+    }
+}
+
+fn write_edge(
+    f: &mut fmt::Formatter<'_>,
+    syntax: GraphSyntax,
+    from: DotNode,
+    to: DotNode,
+    label: Option<&str>,
 ) -> fmt::Result {
-    write!(f, "{INDENT}{id}[label=\"")?;
+    use fmt::Write;
+
+    match syntax {
+        GraphSyntax::Dot => {
+            write!(f, "{INDENT}{from} -> {to}")?;
+            if let Some(label) = label {
+                f.write_str("[label=\"")?;
+                EscapeLabel(&mut *f).write_str(label)?;
+                f.write_str("\"]")?;
+            }
+        },
+        GraphSyntax::Mermaid => {
+            write!(f, "{INDENT}{} -->", MermaidNode(from))?;
+            if let Some(label) = label {
+                f.write_str("|\"")?;
+                EscapeMermaidLabel(&mut *f).write_str(label)?;
+                f.write_str("\"|")?;
+            }
+            write!(f, " {}", MermaidNode(to))?;
+        },
+    }
+    writeln!(f)
+}
+
+fn write_schema_tooltip(
+    f: &mut fmt::Formatter<'_>,
+    id: DotNode,
+    schema: &Schema,
+    max_columns: usize,
+) -> fmt::Result {
+    use fmt::Write;
+
+    write!(f, "{INDENT}{id}[tooltip=\"")?;
 
     let mut escaped = EscapeLabel(f);
-    w(&mut escaped)?;
+    for (i, (name, dtype)) in schema.iter().take(max_columns).enumerate() {
+        if i > 0 {
+            escaped.write_char('\n')?;
+        }
+        write!(escaped, "{name}: {dtype}")?;
+    }
+    if schema.len() > max_columns {
+        write!(escaped, "\n... {} more columns", schema.len() - max_columns)?;
+    }
     let EscapeLabel(f) = escaped;
 
-    writeln!(f, "\"]")?;
+    writeln!(f, "\"]")
+}
+
+/// Count how many times every cache is used in the plan below `root`.
+///
+/// Caches in the subplan of a cache are counted once, as that subplan is only executed once.
+/// Hash the subplan below `node` by the descriptions of its nodes, so that equal subplans get the
+/// same hash wherever they are in a plan. Caches are hashed by their input, as their ids are only
+/// unique within a plan.
+#[recursive]
+fn structural_hash(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    hashes: &mut PlHashMap<Node, u64>,
+) -> u64 {
+    if let Some(hash) = hashes.get(&node) {
+        return *hash;
+    }
+
+    let ir = lp_arena.get(node);
+    let mut description = String::new();
+    if matches!(ir, IR::Cache { .. }) {
+        description.push_str("CACHE");
+    } else {
+        let schema = ir.schema(lp_arena);
+        // Writing to a `String` can't fail.
+        write_ir_non_recursive(&mut description, ir, expr_arena, &schema, 0).unwrap();
+    }
+    let inputs = ir
+        .get_inputs()
+        .iter()
+        .map(|input| structural_hash(*input, lp_arena, expr_arena, hashes))
+        .collect::<Vec<_>>();
+
+    let hash = PlFixedStateQuality::with_seed(0).hash_one((description, inputs));
+    hashes.insert(node, hash);
+    hash
+}
+
+/// The ids of the nodes for [`DotDisplayConfig::structural_ids`].
+#[derive(Default)]
+struct StructuralIds {
+    hashes: PlHashMap<Node, u64>,
+    used: PlHashSet<u64>,
+}
+
+impl StructuralIds {
+    /// The id of `node`, unless a node with an equal subplan already has it.
+    fn get(&mut self, node: Node) -> Option<DotNode> {
+        let hash = *self.hashes.get(&node)?;
+        self.used.insert(hash).then_some(DotNode::Path(hash))
+    }
+}
+
+fn count_cache_hits(root: Node, lp_arena: &Arena<IR>) -> PlHashMap<UniqueId, usize> {
+    let mut hits = PlHashMap::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        let ir = lp_arena.get(node);
+        if let IR::Cache { input, id } = ir {
+            let count = hits.entry(*id).or_insert(0);
+            *count += 1;
+            if *count == 1 {
+                stack.push(*input);
+            }
+        } else {
+            ir.copy_inputs(&mut stack);
+        }
+    }
+    hits
+}
+
+/// Receives the nodes and edges of the graph of a plan, see [`IRDotDisplay::walk`].
+trait PlanGraphVisitor {
+    fn visit_edge(&mut self, from: DotNode, to: DotNode, label: Option<&str>) -> fmt::Result;
+
+    fn visit_node(
+        &mut self,
+        id: DotNode,
+        ir: &IR,
+        label: impl FnMut(&mut dyn fmt::Write) -> fmt::Result,
+    ) -> fmt::Result;
+}
+
+/// Writes the graph in a [`GraphSyntax`].
+struct GraphWriter<'a, 'f, 'g> {
+    f: &'f mut fmt::Formatter<'g>,
+    syntax: GraphSyntax,
+    config: DotDisplayConfig,
+    lp_arena: &'a Arena<IR>,
+}
+
+impl PlanGraphVisitor for GraphWriter<'_, '_, '_> {
+    fn visit_edge(&mut self, from: DotNode, to: DotNode, label: Option<&str>) -> fmt::Result {
+        write_edge(self.f, self.syntax, from, to, label)
+    }
+
+    fn visit_node(
+        &mut self,
+        id: DotNode,
+        ir: &IR,
+        label: impl FnMut(&mut dyn fmt::Write) -> fmt::Result,
+    ) -> fmt::Result {
+        let fill_color = if self.config.color_nodes {
+            node_fill_color(ir)
+        } else {
+            None
+        };
+        write_label(self.f, self.syntax, id, fill_color, label)?;
+
+        if self.config.show_schema && self.syntax == GraphSyntax::Dot && !matches!(ir, IR::Invalid)
+        {
+            let schema = ir.schema(self.lp_arena);
+            write_schema_tooltip(self.f, id, &schema, self.config.max_columns)?;
+        }
 
-    Ok(())
+        Ok(())
+    }
 }
 
 impl<'a> IRDotDisplay<'a> {
     pub fn new(lp: IRPlanRef<'a>) -> Self {
-        Self { lp }
+        Self::new_with_config(lp, DotDisplayConfig::default())
+    }
+
+    pub fn new_with_config(lp: IRPlanRef<'a>, config: DotDisplayConfig) -> Self {
+        Self {
+            lp,
+            config,
+            syntax: GraphSyntax::Dot,
+        }
     }
 
     fn with_root(&self, root: Node) -> Self {
         Self {
             lp: self.lp.with_root(root),
+            config: self.config,
+            syntax: self.syntax,
+        }
+    }
+
+    /// Only display the subplan rooted at `node`.
+    ///
+    /// The nodes are numbered from that root, so the output doesn't depend on the rest of the
+    /// plan.
+    pub fn rooted_at(&self, node: Node) -> Self {
+        self.with_root(node)
+    }
+
+    fn walk_root<V: PlanGraphVisitor>(&self, v: &mut V) -> fmt::Result {
+        let mut last = 0;
+        let mut visited_caches = PlHashSet::new();
+        let mut structural_ids = StructuralIds::default();
+        if self.config.structural_ids {
+            structural_hash(
+                self.lp.lp_top,
+                self.lp.lp_arena,
+                self.lp.expr_arena,
+                &mut structural_ids.hashes,
+            );
         }
+        let cache_hits = if self.config.collapse_caches {
+            count_cache_hits(self.lp.lp_top, self.lp.lp_arena)
+        } else {
+            PlHashMap::new()
+        };
+        self.walk(
+            v,
+            None,
+            None,
+            0,
+            &mut last,
+            &mut structural_ids,
+            &mut visited_caches,
+            &cache_hits,
+        )
+    }
+
+    fn write_graph(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.walk_root(&mut GraphWriter {
+            f,
+            syntax: self.syntax,
+            config: self.config,
+            lp_arena: self.lp.lp_arena,
+        })
     }
 
     fn display_expr(&self, expr: &'a ExprIR) -> ExprIRDisplay<'a> {
@@ -71,32 +408,60 @@ impl<'a> IRDotDisplay<'a> {
         }
     }
 
+    /// Walk the plan from its root, passing the nodes and edges of the graph to `v`.
+    ///
+    /// The edge to a node is visited before the subplan of that node, and a node is visited after
+    /// all of its inputs. Caches are only visited the first time they are reached.
     #[recursive]
-    fn _format(
+    #[allow(clippy::too_many_arguments)]
+    fn walk<V: PlanGraphVisitor>(
         &self,
-        f: &mut fmt::Formatter<'_>,
+        v: &mut V,
         parent: Option<DotNode>,
+        edge_label: Option<&str>,
+        path: u64,
         last: &mut usize,
+        structural_ids: &mut StructuralIds,
         visited_caches: &mut PlHashSet<UniqueId>,
+        cache_hits: &PlHashMap<UniqueId, usize>,
     ) -> std::fmt::Result {
         use fmt::Write;
 
         let root = self.lp.root();
         let id = if let IR::Cache { id, .. } = root {
             DotNode::Cache(*id)
+        } else if let Some(id) = structural_ids.get(self.lp.lp_top) {
+            id
+        } else if self.config.stable_ids {
+            DotNode::Path(path)
         } else {
             *last += 1;
             DotNode::Plain(*last)
         };
 
         if let Some(parent) = parent {
-            writeln!(f, "{INDENT}{id} -> {parent}")?;
+            v.visit_edge(id, parent, edge_label)?;
         }
 
+        // The path of a child is the hash of its parent's path and its index among the children.
+        let mut child_paths =
+            (0u64..).map(|i| PlFixedStateQuality::with_seed(0).hash_one((path, i)));
         macro_rules! recurse {
             ($input:expr) => {
-                self.with_root($input)
-                    ._format(f, Some(id), last, visited_caches)?;
+                recurse!($input, None);
+            };
+            ($input:expr, $edge_label:expr) => {
+                let child_path = child_paths.next().unwrap();
+                self.with_root($input).walk(
+                    v,
+                    Some(id),
+                    $edge_label,
+                    child_path,
+                    last,
+                    structural_ids,
+                    visited_caches,
+                    cache_hits,
+                )?;
             };
         }
 
@@ -107,33 +472,42 @@ impl<'a> IRDotDisplay<'a> {
                     recurse!(*input);
                 }
 
-                write_label(f, id, |f| f.write_str("UNION"))?;
+                v.visit_node(id, root, |f| f.write_str("UNION"))?;
             },
             HConcat { inputs, .. } => {
                 for input in inputs {
                     recurse!(*input);
                 }
 
-                write_label(f, id, |f| f.write_str("HCONCAT"))?;
+                v.visit_node(id, root, |f| f.write_str("HCONCAT"))?;
             },
             Cache {
                 input,
                 id: cache_id,
                 ..
             } => {
-                if !visited_caches.contains(cache_id) {
-                    visited_caches.insert(*cache_id);
-
-                    recurse!(*input);
+                if !visited_caches.insert(*cache_id) {
+                    return Ok(());
+                }
 
-                    write_label(f, id, |f| f.write_str("CACHE"))?;
+                if self.config.collapse_caches {
+                    let hits = cache_hits.get(cache_id).copied().unwrap_or(0);
+                    let width = root.schema(self.lp.lp_arena).len();
+                    v.visit_node(id, root, |f| {
+                        write!(f, "CACHE\nhits: {hits}; columns: {width}")
+                    })?;
+                    return Ok(());
                 }
+
+                recurse!(*input);
+
+                v.visit_node(id, root, |f| f.write_str("CACHE"))?;
             },
             Filter { predicate, input } => {
                 recurse!(*input);
 
                 let pred = self.display_expr(predicate);
-                write_label(f, id, |f| write!(f, "FILTER BY {pred}"))?;
+                v.visit_node(id, root, |f| write!(f, "FILTER BY {pred}"))?;
             },
             #[cfg(feature = "python")]
             PythonScan { options } => {
@@ -145,7 +519,7 @@ impl<'a> IRDotDisplay<'a> {
                 let with_columns = NumColumns(options.with_columns.as_ref().map(|s| s.as_ref()));
                 let total_columns = options.schema.len();
 
-                write_label(f, id, |f| {
+                v.visit_node(id, root, |f| {
                     write!(
                         f,
                         "PYTHON SCAN\nπ {with_columns}/{total_columns};\nσ {predicate}"
@@ -159,14 +533,14 @@ impl<'a> IRDotDisplay<'a> {
                 ..
             } => {
                 recurse!(*input);
-                write_label(f, id, |f| write!(f, "π {}/{}", expr.len(), schema.len()))?;
+                v.visit_node(id, root, |f| write!(f, "π {}/{}", expr.len(), schema.len()))?;
             },
             Sort {
                 input, by_column, ..
             } => {
                 let by_column = self.display_exprs(by_column);
                 recurse!(*input);
-                write_label(f, id, |f| write!(f, "SORT BY {by_column}"))?;
+                v.visit_node(id, root, |f| write!(f, "SORT BY {by_column}"))?;
             },
             GroupBy {
                 input, keys, aggs, ..
@@ -174,20 +548,22 @@ impl<'a> IRDotDisplay<'a> {
                 let keys = self.display_exprs(keys);
                 let aggs = self.display_exprs(aggs);
                 recurse!(*input);
-                write_label(f, id, |f| write!(f, "AGG {aggs}\nBY\n{keys}"))?;
+                v.visit_node(id, root, |f| write!(f, "AGG {aggs}\nBY\n{keys}"))?;
             },
             HStack { input, exprs, .. } => {
                 let exprs = self.display_exprs(exprs);
                 recurse!(*input);
-                write_label(f, id, |f| write!(f, "WITH COLUMNS {exprs}"))?;
+                v.visit_node(id, root, |f| write!(f, "WITH COLUMNS {exprs}"))?;
             },
             Slice { input, offset, len } => {
                 recurse!(*input);
-                write_label(f, id, |f| write!(f, "SLICE offset: {offset}; len: {len}"))?;
+                v.visit_node(id, root, |f| {
+                    write!(f, "SLICE offset: {offset}; len: {len}")
+                })?;
             },
             Distinct { input, options, .. } => {
                 recurse!(*input);
-                write_label(f, id, |f| {
+                v.visit_node(id, root, |f| {
                     f.write_str("DISTINCT")?;
 
                     if let Some(subset) = &options.subset {
@@ -216,7 +592,7 @@ impl<'a> IRDotDisplay<'a> {
                 let num_columns = NumColumnsSchema(output_schema.as_ref().map(|p| p.as_ref()));
                 let total_columns = schema.len();
 
-                write_label(f, id, |f| {
+                v.visit_node(id, root, |f| {
                     write!(f, "TABLE\nπ {num_columns}/{total_columns}")
                 })?;
             },
@@ -240,11 +616,13 @@ impl<'a> IRDotDisplay<'a> {
                 let total_columns =
                     file_info.schema.len() - usize::from(unified_scan_args.row_index.is_some());
 
-                write_label(f, id, |f| {
+                v.visit_node(id, root, |f| {
                     write!(f, "{name} SCAN {path}\nπ {with_columns}/{total_columns};",)?;
 
+                    // A predicate on a scan was pushed into it, as opposed to a `FILTER BY`
+                    // node above the scan.
                     if let Some(predicate) = predicate.as_ref() {
-                        write!(f, "\nσ {}", self.display_expr(predicate))?;
+                        write!(f, "\nσ (pushed) {}", self.display_expr(predicate))?;
                     }
 
                     if let Some(row_index) = unified_scan_args.row_index.as_ref() {
@@ -262,48 +640,82 @@ impl<'a> IRDotDisplay<'a> {
                 options,
                 ..
             } => {
-                recurse!(*input_left);
-                recurse!(*input_right);
-
-                write_label(f, id, |f| {
-                    write!(f, "JOIN {}", options.args.how)?;
+                // The keys go on the edges, so it is clear which input contributes which key.
+                let (left_label, right_label) = if left_on.is_empty() {
+                    (None, None)
+                } else {
+                    (
+                        Some(self.display_exprs(left_on).to_string()),
+                        Some(self.display_exprs(right_on).to_string()),
+                    )
+                };
+                recurse!(*input_left, left_label.as_deref());
+                recurse!(*input_right, right_label.as_deref());
 
-                    if !left_on.is_empty() {
-                        let left_on = self.display_exprs(left_on);
-                        let right_on = self.display_exprs(right_on);
-                        write!(f, "\nleft: {left_on};\nright: {right_on}")?
-                    }
-                    Ok(())
-                })?;
+                v.visit_node(id, root, |f| write!(f, "JOIN {}", options.args.how))?;
             },
             MapFunction {
                 input, function, ..
             } => {
                 recurse!(*input);
-                write_label(f, id, |f| write!(f, "{function}"))?;
+                v.visit_node(id, root, |f| write!(f, "{function}"))?;
             },
-            ExtContext { input, .. } => {
+            ExtContext {
+                input,
+                contexts,
+                schema,
+            } => {
                 recurse!(*input);
-                write_label(f, id, |f| f.write_str("EXTERNAL_CONTEXT"))?;
+                for (i, context) in contexts.iter().enumerate() {
+                    recurse!(*context, Some(&format!("context {i}")));
+                }
+
+                let added = ext_context_added_columns(self.lp.lp_arena, *input, contexts, schema);
+                let input_columns = self.lp.lp_arena.get(*input).schema(self.lp.lp_arena).len();
+                v.visit_node(id, root, |f| {
+                    write!(f, "EXTERNAL_CONTEXT\ninput columns: {input_columns}")?;
+                    for (i, columns) in added.iter().enumerate() {
+                        write!(f, "\ncontext {i}: {}", AddedColumnsDisplay(columns))?;
+                    }
+                    Ok(())
+                })?;
             },
             Sink { input, payload, .. } => {
                 recurse!(*input);
 
-                write_label(f, id, |f| {
-                    f.write_str(match payload {
-                        SinkTypeIR::Memory => "SINK (MEMORY)",
-                        SinkTypeIR::Callback { .. } => "SINK (CALLBACK)",
-                        SinkTypeIR::File { .. } => "SINK (FILE)",
-                        SinkTypeIR::Partitioned { .. } => "SINK (PARTITION)",
-                    })
+                v.visit_node(id, root, |f| match payload {
+                    SinkTypeIR::Memory => f.write_str("SINK (MEMORY)"),
+                    SinkTypeIR::Callback { .. } => f.write_str("SINK (CALLBACK)"),
+                    SinkTypeIR::File(options) => {
+                        let format = options.file_format.extension();
+                        let target = SinkTargetDisplay(&options.target);
+                        write!(f, "SINK (FILE)\n{format} {target}")
+                    },
+                    SinkTypeIR::Partitioned(options) => {
+                        let format = options.file_format.extension();
+                        let base_path = options.base_path.display();
+                        write!(f, "SINK (PARTITION)\n{format} [{base_path}]")?;
+
+                        if let PartitionStrategyIR::Keyed { keys, .. } = &options.partition_strategy
+                        {
+                            write!(f, "\nby: {}", self.display_exprs(keys))?;
+                        }
+                        Ok(())
+                    },
                 })?;
             },
             SinkMultiple { inputs } => {
-                for input in inputs {
-                    recurse!(*input);
+                for (i, input) in inputs.iter().enumerate() {
+                    let edge_label = match self.lp.lp_arena.get(*input) {
+                        Sink { payload, .. } => {
+                            format!("sink {i}\n{}", SinkDestinationDisplay(payload))
+                        },
+                        _ => format!("sink {i}"),
+                    };
+                    recurse!(*input, Some(&edge_label));
                 }
 
-                write_label(f, id, |f| f.write_str("SINK MULTIPLE"))?;
+                v.visit_node(id, root, |f| f.write_str("SINK MULTIPLE"))?;
             },
             SimpleProjection { input, columns } => {
                 let num_columns = columns.as_ref().len();
@@ -311,7 +723,7 @@ impl<'a> IRDotDisplay<'a> {
 
                 let columns = ColumnsDisplay(columns.as_ref());
                 recurse!(*input);
-                write_label(f, id, |f| {
+                v.visit_node(id, root, |f| {
                     write!(f, "simple π {num_columns}/{total_columns}\n[{columns}]")
                 })?;
             },
@@ -324,9 +736,23 @@ impl<'a> IRDotDisplay<'a> {
                 recurse!(*input_left);
                 recurse!(*input_right);
 
-                write_label(f, id, |f| write!(f, "MERGE_SORTED ON '{key}'",))?;
+                let left_schema = self.lp.lp_arena.get(*input_left).schema(self.lp.lp_arena);
+                let right_schema = self.lp.lp_arena.get(*input_right).schema(self.lp.lp_arena);
+                let schemas = if left_schema == right_schema {
+                    "same schema"
+                } else {
+                    "different schemas"
+                };
+                v.visit_node(id, root, |f| {
+                    write!(
+                        f,
+                        "MERGE_SORTED ON '{key}'\ncolumns: {} left, {} right; {schemas}",
+                        left_schema.len(),
+                        right_schema.len()
+                    )
+                })?;
             },
-            Invalid => write_label(f, id, |f| f.write_str("INVALID"))?,
+            Invalid => v.visit_node(id, root, |f| f.write_str("INVALID"))?,
         }
 
         Ok(())
@@ -336,6 +762,11 @@ impl<'a> IRDotDisplay<'a> {
 // A few utility structures for formatting
 pub struct PathsDisplay<'a>(pub &'a [PathBuf]);
 pub struct ScanSourcesDisplay<'a>(pub &'a ScanSources);
+pub struct SinkTargetDisplay<'a>(pub &'a SinkTarget);
+/// A short summary of where a sink writes to.
+pub struct SinkDestinationDisplay<'a>(pub &'a SinkTypeIR);
+/// The columns that a context of an [`IR::ExtContext`] adds, see [`ext_context_added_columns`].
+pub struct AddedColumnsDisplay<'a>(pub &'a [(&'a PlSmallStr, &'a DataType)]);
 struct NumColumns<'a>(Option<&'a [PlSmallStr]>);
 struct NumColumnsSchema<'a>(Option<&'a Schema>);
 
@@ -365,6 +796,77 @@ impl fmt::Display for ScanSourcesDisplay<'_> {
     }
 }
 
+impl fmt::Display for SinkTargetDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            SinkTarget::Path(path) => write!(f, "[{}]", path.display()),
+            SinkTarget::Dyn(_) => f.write_str("[dynamic target]"),
+        }
+    }
+}
+
+impl fmt::Display for SinkDestinationDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            SinkTypeIR::Memory => f.write_str("memory"),
+            SinkTypeIR::Callback { .. } => f.write_str("callback"),
+            SinkTypeIR::File(options) => {
+                let format = options.file_format.extension();
+                write!(f, "{format} {}", SinkTargetDisplay(&options.target))
+            },
+            SinkTypeIR::Partitioned(options) => {
+                let format = options.file_format.extension();
+                let base_path = options.base_path.display();
+                write!(f, "partitioned {format} [{base_path}]")
+            },
+        }
+    }
+}
+
+impl fmt::Display for AddedColumnsDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.len() {
+            0 => return f.write_str("0 columns"),
+            1 => f.write_str("1 column [")?,
+            n => write!(f, "{n} columns [")?,
+        }
+        for (i, (name, dtype)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{name}: {dtype}")?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// Get the columns that every context of an [`IR::ExtContext`] adds to the schema of its input.
+///
+/// A column is added by the first context that has it, and only if the input doesn't have it.
+pub fn ext_context_added_columns<'a>(
+    lp_arena: &Arena<IR>,
+    input: Node,
+    contexts: &[Node],
+    schema: &'a Schema,
+) -> Vec<Vec<(&'a PlSmallStr, &'a DataType)>> {
+    let input_schema = lp_arena.get(input).schema(lp_arena);
+    let mut seen = PlHashSet::new();
+    contexts
+        .iter()
+        .map(|context| {
+            let context_schema = lp_arena.get(*context).schema(lp_arena);
+            context_schema
+                .iter_names()
+                .filter(|name| !input_schema.contains(name) && seen.insert((*name).clone()))
+                .filter_map(|name| {
+                    let (_, name, dtype) = schema.get_full(name)?;
+                    Some((name, dtype))
+                })
+                .collect()
+        })
+        .collect()
+}
+
 impl fmt::Display for PathsDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.0.len() {
@@ -430,18 +932,931 @@ impl fmt::Write for EscapeLabel<'_> {
     }
 }
 
+/// Utility structure to write to a [`fmt::Formatter`] whilst escaping the output as a quoted
+/// Mermaid label
+pub struct EscapeMermaidLabel<'a>(pub &'a mut dyn fmt::Write);
+
+impl fmt::Write for EscapeMermaidLabel<'_> {
+    fn write_str(&mut self, mut s: &str) -> fmt::Result {
+        loop {
+            let mut char_indices = s.char_indices();
+
+            // Mermaid labels can't contain quotes and are rendered as HTML, so quotes and markup
+            // are written as entity codes and new lines as line breaks.
+            let f = char_indices.find_map(|(i, c)| match c {
+                '"' => Some((i, "#quot;")),
+                '#' => Some((i, "#35;")),
+                '<' => Some((i, "#lt;")),
+                '>' => Some((i, "#gt;")),
+                '\n' => Some((i, "<br>")),
+                _ => None,
+            });
+
+            let Some((at, to_write)) = f else {
+                break;
+            };
+
+            self.0.write_str(&s[..at])?;
+            self.0.write_str(to_write)?;
+            s = &s[at + 1..];
+        }
+
+        self.0.write_str(s)?;
+
+        Ok(())
+    }
+}
+
+/// Utility structure to write to a [`fmt::Formatter`] whilst escaping the output as the contents of
+/// a JSON string
+pub struct EscapeJsonString<'a>(pub &'a mut dyn fmt::Write);
+
+impl fmt::Write for EscapeJsonString<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            // This escapes quotes, backslashes and control characters
+            if !matches!(c, '"' | '\\') && c >= ' ' {
+                continue;
+            }
+
+            self.0.write_str(&s[start..i])?;
+            match c {
+                '"' => self.0.write_str(r#"\""#)?,
+                '\\' => self.0.write_str(r"\\")?,
+                '\n' => self.0.write_str(r"\n")?,
+                '\r' => self.0.write_str(r"\r")?,
+                '\t' => self.0.write_str(r"\t")?,
+                c => write!(self.0, "\\u{:04x}", u32::from(c))?,
+            }
+            start = i + c.len_utf8();
+        }
+
+        self.0.write_str(&s[start..])
+    }
+}
+
 impl fmt::Display for IRDotDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "digraph polars_query {{")?;
         writeln!(f, "{INDENT}rankdir=\"BT\"")?;
         writeln!(f, "{INDENT}node [fontname=\"Monospace\", shape=\"box\"]")?;
 
-        let mut last = 0;
-        let mut visited_caches = PlHashSet::new();
-        self._format(f, None, &mut last, &mut visited_caches)?;
+        self.write_graph(f)?;
 
         writeln!(f, "}}")?;
 
         Ok(())
     }
 }
+
+impl<'a> IRMermaidDisplay<'a> {
+    pub fn new(lp: IRPlanRef<'a>) -> Self {
+        Self::new_with_config(lp, DotDisplayConfig::default())
+    }
+
+    pub fn new_with_config(lp: IRPlanRef<'a>, config: DotDisplayConfig) -> Self {
+        Self(IRDotDisplay {
+            lp,
+            config,
+            syntax: GraphSyntax::Mermaid,
+        })
+    }
+}
+
+impl fmt::Display for IRMermaidDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "flowchart TD")?;
+        self.0.write_graph(f)
+    }
+}
+
+impl<'a> IRJsonDisplay<'a> {
+    pub fn new(lp: IRPlanRef<'a>) -> Self {
+        Self::new_with_config(lp, DotDisplayConfig::default())
+    }
+
+    /// Only [`DotDisplayConfig::stable_ids`], [`DotDisplayConfig::structural_ids`] and
+    /// [`DotDisplayConfig::collapse_caches`] affect the output.
+    pub fn new_with_config(lp: IRPlanRef<'a>, config: DotDisplayConfig) -> Self {
+        Self(IRDotDisplay {
+            lp,
+            config,
+            syntax: GraphSyntax::Dot,
+        })
+    }
+}
+
+struct JsonNode {
+    id: DotNode,
+    kind: &'static str,
+    label: String,
+}
+
+struct JsonEdge {
+    from: DotNode,
+    to: DotNode,
+    label: Option<String>,
+}
+
+/// Collects the nodes and edges of the graph, in the order they are visited.
+#[derive(Default)]
+struct JsonGraphBuilder {
+    nodes: Vec<JsonNode>,
+    edges: Vec<JsonEdge>,
+}
+
+impl PlanGraphVisitor for JsonGraphBuilder {
+    fn visit_edge(&mut self, from: DotNode, to: DotNode, label: Option<&str>) -> fmt::Result {
+        self.edges.push(JsonEdge {
+            from,
+            to,
+            label: label.map(str::to_string),
+        });
+        Ok(())
+    }
+
+    fn visit_node(
+        &mut self,
+        id: DotNode,
+        ir: &IR,
+        mut label: impl FnMut(&mut dyn fmt::Write) -> fmt::Result,
+    ) -> fmt::Result {
+        let mut buf = String::new();
+        label(&mut buf)?;
+        self.nodes.push(JsonNode {
+            id,
+            kind: ir.name(),
+            label: buf,
+        });
+        Ok(())
+    }
+}
+
+/// Writes `s` as a JSON string.
+fn write_json_str(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    use fmt::Write;
+
+    f.write_char('"')?;
+    EscapeJsonString(&mut *f).write_str(s)?;
+    f.write_char('"')
+}
+
+impl fmt::Display for IRJsonDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut graph = JsonGraphBuilder::default();
+        self.0.walk_root(&mut graph)?;
+
+        // Node ids are written as Mermaid ids, as those are never quoted.
+        f.write_str(r#"{"nodes":["#)?;
+        for (i, node) in graph.nodes.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, r#"{{"id":"{}","kind":"#, MermaidNode(node.id))?;
+            write_json_str(f, node.kind)?;
+            f.write_str(r#","label":"#)?;
+            write_json_str(f, &node.label)?;
+            if let DotNode::Cache(cache_id) = node.id {
+                write!(f, r#","cache_id":"{cache_id}""#)?;
+            }
+            f.write_str("}")?;
+        }
+        f.write_str(r#"],"edges":["#)?;
+        for (i, edge) in graph.edges.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(
+                f,
+                r#"{{"from":"{}","to":"{}","label":"#,
+                MermaidNode(edge.from),
+                MermaidNode(edge.to)
+            )?;
+            match &edge.label {
+                Some(label) => write_json_str(f, label)?,
+                None => f.write_str("null")?,
+            }
+            f.write_str("}")?;
+        }
+        f.write_str("]}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_dot_schema_tooltip() {
+        let mut lp_arena = Arena::new();
+        let expr_arena = Arena::new();
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int32),
+            Field::new("say \"hi\"".into(), DataType::String),
+            Field::new("c".into(), DataType::Float64),
+        ]);
+        let root = lp_arena.add(IR::DataFrameScan {
+            df: Arc::new(DataFrame::empty_with_schema(&schema)),
+            schema: Arc::new(schema),
+            output_schema: None,
+        });
+        let plan = IRPlanRef {
+            lp_top: root,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+
+        let dot = IRDotDisplay::new(plan).to_string();
+        assert!(!dot.contains("tooltip"));
+
+        let config = DotDisplayConfig {
+            show_schema: true,
+            max_columns: 2,
+            ..Default::default()
+        };
+        let dot = IRDotDisplay::new_with_config(plan, config).to_string();
+        assert!(
+            dot.contains(r#"p1[tooltip="a: i32\nsay \"hi\": str\n... 1 more columns"]"#),
+            "{dot}"
+        );
+    }
+
+    #[test]
+    fn test_dot_stable_ids() {
+        let schema = Arc::new(Schema::from_iter([Field::new("a".into(), DataType::Int32)]));
+        let scan = || IR::DataFrameScan {
+            df: Arc::new(DataFrame::empty_with_schema(&schema)),
+            schema: schema.clone(),
+            output_schema: None,
+        };
+        let config = DotDisplayConfig {
+            stable_ids: true,
+            ..Default::default()
+        };
+
+        // The same union, but with the nodes added to the arena in a different order.
+        let mut lp_arena = Arena::new();
+        let inputs = vec![lp_arena.add(scan()), lp_arena.add(scan())];
+        let root = lp_arena.add(IR::Union {
+            inputs,
+            options: Default::default(),
+        });
+        let expr_arena = Arena::new();
+        let plan = IRPlanRef {
+            lp_top: root,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+        let dot = IRDotDisplay::new_with_config(plan, config).to_string();
+
+        let mut lp_arena = Arena::new();
+        lp_arena.add(IR::Invalid);
+        let root = lp_arena.add(IR::Invalid);
+        let inputs = vec![lp_arena.add(scan()), lp_arena.add(scan())];
+        lp_arena.replace(
+            root,
+            IR::Union {
+                inputs,
+                options: Default::default(),
+            },
+        );
+        let plan = IRPlanRef {
+            lp_top: root,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+        assert_eq!(dot, IRDotDisplay::new_with_config(plan, config).to_string());
+
+        // Both scans have a distinct id.
+        let ids = dot
+            .lines()
+            .filter_map(|l| l.trim().strip_suffix("[label=\"TABLE\\nπ */1\"]"))
+            .collect::<PlHashSet<_>>();
+        assert_eq!(ids.len(), 2, "{dot}");
+    }
+
+    #[test]
+    fn test_dot_structural_ids() {
+        let schema = Arc::new(Schema::from_iter([Field::new("a".into(), DataType::Int32)]));
+        let scan = || IR::DataFrameScan {
+            df: Arc::new(DataFrame::empty_with_schema(&schema)),
+            schema: schema.clone(),
+            output_schema: None,
+        };
+        let config = DotDisplayConfig {
+            structural_ids: true,
+            ..Default::default()
+        };
+        let node_ids = |dot: &str| {
+            dot.lines()
+                .filter_map(|l| l.trim().split_once("[label="))
+                .filter(|(id, _)| !id.contains("->"))
+                .map(|(id, label)| (label.to_string(), id.to_string()))
+                .collect::<Vec<_>>()
+        };
+
+        // A filter above a scan.
+        let mut lp_arena = Arena::new();
+        let mut expr_arena = Arena::new();
+        let input = lp_arena.add(scan());
+        let predicate = ExprIR::from_node(expr_arena.add(AExpr::Column("a".into())), &expr_arena);
+        let filter = lp_arena.add(IR::Filter { input, predicate });
+        let plan = IRPlanRef {
+            lp_top: filter,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+        let ids = node_ids(&IRDotDisplay::new_with_config(plan, config).to_string());
+        assert_eq!(ids.len(), 2);
+
+        // The filter and scan keep their ids when a node is added above them.
+        let root = lp_arena.add(IR::Slice {
+            input: filter,
+            offset: 0,
+            len: 1,
+        });
+        let plan = IRPlanRef {
+            lp_top: root,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+        let dot = IRDotDisplay::new_with_config(plan, config).to_string();
+        assert_eq!(node_ids(&dot)[..2], ids, "{dot}");
+
+        // Equal subplans get distinct ids.
+        let inputs = vec![lp_arena.add(scan()), lp_arena.add(scan())];
+        let root = lp_arena.add(IR::Union {
+            inputs,
+            options: Default::default(),
+        });
+        let plan = IRPlanRef {
+            lp_top: root,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+        let dot = IRDotDisplay::new_with_config(plan, config).to_string();
+        let ids = node_ids(&dot)
+            .into_iter()
+            .map(|(_, id)| id)
+            .collect::<PlHashSet<_>>();
+        assert_eq!(ids.len(), 3, "{dot}");
+        assert_eq!(dot, IRDotDisplay::new_with_config(plan, config).to_string());
+    }
+
+    #[test]
+    fn test_dot_rooted_at() {
+        let schema = Schema::from_iter([Field::new("a".into(), DataType::Int32)]);
+        let mut lp_arena = Arena::new();
+        let expr_arena = Arena::new();
+        let scan = lp_arena.add(IR::DataFrameScan {
+            df: Arc::new(DataFrame::empty_with_schema(&schema)),
+            schema: Arc::new(schema),
+            output_schema: None,
+        });
+        let inner = lp_arena.add(IR::Slice {
+            input: scan,
+            offset: 1,
+            len: 2,
+        });
+        let root = lp_arena.add(IR::Slice {
+            input: inner,
+            offset: 0,
+            len: 1,
+        });
+        let plan = IRPlanRef {
+            lp_top: root,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+
+        let dot = IRDotDisplay::new(plan).rooted_at(inner).to_string();
+        assert!(dot.contains("p2 -> p1"), "{dot}");
+        assert!(
+            dot.contains(r#"p1[label="SLICE offset: 1; len: 2"]"#),
+            "{dot}"
+        );
+        assert!(!dot.contains("p3"), "{dot}");
+        assert!(!dot.contains("len: 1"), "{dot}");
+    }
+
+    #[test]
+    fn test_dot_color_nodes() {
+        let schema = Schema::from_iter([Field::new("a".into(), DataType::Boolean)]);
+        let mut lp_arena = Arena::new();
+        let mut expr_arena = Arena::new();
+        let input = lp_arena.add(IR::DataFrameScan {
+            df: Arc::new(DataFrame::empty_with_schema(&schema)),
+            schema: Arc::new(schema),
+            output_schema: None,
+        });
+        let input = lp_arena.add(IR::Cache {
+            input,
+            id: UniqueId::new(),
+        });
+        let predicate = expr_arena.add(AExpr::Column("a".into()));
+        let input = lp_arena.add(IR::Filter {
+            input,
+            predicate: ExprIR::from_node(predicate, &expr_arena),
+        });
+        let root = lp_arena.add(IR::Slice {
+            input,
+            offset: 0,
+            len: 1,
+        });
+        let plan = IRPlanRef {
+            lp_top: root,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+
+        let dot = IRDotDisplay::new(plan).to_string();
+        assert!(!dot.contains("fillcolor"), "{dot}");
+
+        let config = DotDisplayConfig {
+            color_nodes: true,
+            ..Default::default()
+        };
+        let dot = IRDotDisplay::new_with_config(plan, config).to_string();
+        let colors = dot
+            .lines()
+            .filter(|l| l.contains("label="))
+            .map(|l| l.split_once("fillcolor=").map(|(_, color)| color))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            colors,
+            [
+                Some(r##""#b7e4c7"]"##),
+                Some(r##""#e4c1f9"]"##),
+                Some(r##""#bde0fe"]"##),
+                None
+            ],
+            "{dot}"
+        );
+
+        let mermaid = IRMermaidDisplay::new_with_config(plan, config).to_string();
+        assert!(mermaid.contains("style p2 fill:#bde0fe"), "{mermaid}");
+    }
+
+    #[test]
+    fn test_dot_collapse_caches() {
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Boolean),
+            Field::new("b".into(), DataType::Int64),
+        ]);
+        let mut lp_arena = Arena::new();
+        let mut expr_arena = Arena::new();
+        let input = lp_arena.add(IR::DataFrameScan {
+            df: Arc::new(DataFrame::empty_with_schema(&schema)),
+            schema: Arc::new(schema),
+            output_schema: None,
+        });
+        let cache_id = UniqueId::new();
+        let cache = lp_arena.add(IR::Cache {
+            input,
+            id: cache_id,
+        });
+        let predicate = expr_arena.add(AExpr::Column("a".into()));
+        let filter = lp_arena.add(IR::Filter {
+            input: cache,
+            predicate: ExprIR::from_node(predicate, &expr_arena),
+        });
+        let root = lp_arena.add(IR::Union {
+            inputs: vec![filter, cache, cache],
+            options: Default::default(),
+        });
+        let plan = IRPlanRef {
+            lp_top: root,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+
+        let dot = IRDotDisplay::new(plan).to_string();
+        assert!(dot.contains("TABLE"), "{dot}");
+
+        let config = DotDisplayConfig {
+            collapse_caches: true,
+            ..Default::default()
+        };
+        let dot = IRDotDisplay::new_with_config(plan, config).to_string();
+        let expected = format!(
+            r#"digraph polars_query {{
+  rankdir="BT"
+  node [fontname="Monospace", shape="box"]
+  p2 -> p1
+  "{cache_id}" -> p2
+  "{cache_id}"[label="CACHE\nhits: 3; columns: 2"]
+  p2[label="FILTER BY col(\"a\")"]
+  "{cache_id}" -> p1
+  "{cache_id}" -> p1
+  p1[label="UNION"]
+}}
+"#
+        );
+        assert_eq!(dot, expected);
+    }
+
+    #[test]
+    fn test_mermaid_display() {
+        let scan = |fields: &[(&str, DataType)]| {
+            let schema = Schema::from_iter(
+                fields
+                    .iter()
+                    .map(|(name, dtype)| Field::new((*name).into(), dtype.clone())),
+            );
+            IR::DataFrameScan {
+                df: Arc::new(DataFrame::empty_with_schema(&schema)),
+                schema: Arc::new(schema),
+                output_schema: None,
+            }
+        };
+        let mut lp_arena = Arena::new();
+        let mut expr_arena = Arena::new();
+        let input = lp_arena.add(scan(&[("a <#>", DataType::Boolean)]));
+        let predicate = expr_arena.add(AExpr::Column("a <#>".into()));
+        let input = lp_arena.add(IR::Filter {
+            input,
+            predicate: ExprIR::from_node(predicate, &expr_arena),
+        });
+        let contexts = vec![lp_arena.add(scan(&[("b", DataType::Int64)]))];
+        let schema = Schema::from_iter([
+            Field::new("a <#>".into(), DataType::Boolean),
+            Field::new("b".into(), DataType::Int64),
+        ]);
+        let root = lp_arena.add(IR::ExtContext {
+            input,
+            contexts,
+            schema: Arc::new(schema),
+        });
+        let plan = IRPlanRef {
+            lp_top: root,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+
+        let mermaid = plan.display_mermaid().to_string();
+        let expected = r#"flowchart TD
+  p2 --> p1
+  p3 --> p2
+  p3["TABLE<br>π */1"]
+  p2["FILTER BY col(#quot;a #lt;#35;#gt;#quot;)"]
+  p4 -->|"context 0"| p1
+  p4["TABLE<br>π */1"]
+  p1["EXTERNAL_CONTEXT<br>input columns: 1<br>context 0: 1 column [b: i64]"]
+"#;
+        assert_eq!(mermaid, expected);
+    }
+
+    #[test]
+    fn test_json_display() {
+        let schema = Schema::from_iter([Field::new("a".into(), DataType::Boolean)]);
+        let mut lp_arena = Arena::new();
+        let mut expr_arena = Arena::new();
+        let input = lp_arena.add(IR::DataFrameScan {
+            df: Arc::new(DataFrame::empty_with_schema(&schema)),
+            schema: Arc::new(schema),
+            output_schema: None,
+        });
+        let cache_id = UniqueId::new();
+        let cache = lp_arena.add(IR::Cache {
+            input,
+            id: cache_id,
+        });
+        let predicate = expr_arena.add(AExpr::Column("a".into()));
+        let filter = lp_arena.add(IR::Filter {
+            input: cache,
+            predicate: ExprIR::from_node(predicate, &expr_arena),
+        });
+        let root = lp_arena.add(IR::Union {
+            inputs: vec![filter, cache],
+            options: Default::default(),
+        });
+        let plan = IRPlanRef {
+            lp_top: root,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+
+        let c = MermaidNode(DotNode::Cache(cache_id));
+        let expected = format!(
+            r#"{{"nodes":[
+{{"id":"p3","kind":"df","label":"TABLE\nπ */1"}},
+{{"id":"{c}","kind":"cache","label":"CACHE","cache_id":"{cache_id}"}},
+{{"id":"p2","kind":"filter","label":"FILTER BY col(\"a\")"}},
+{{"id":"p1","kind":"union","label":"UNION"}}
+],"edges":[
+{{"from":"p2","to":"p1","label":null}},
+{{"from":"{c}","to":"p2","label":null}},
+{{"from":"p3","to":"{c}","label":null}},
+{{"from":"{c}","to":"p1","label":null}}
+]}}"#
+        )
+        .replace('\n', "");
+        assert_eq!(plan.to_plan_json(), expected);
+    }
+
+    #[test]
+    fn test_escape_json_string() {
+        use std::fmt::Write;
+
+        let mut out = String::new();
+        EscapeJsonString(&mut out)
+            .write_str("say \"hi\"\\\n\t\u{1}π")
+            .unwrap();
+        assert_eq!(out, r#"say \"hi\"\\\n\t\u0001π"#);
+    }
+
+    #[test]
+    fn test_join_key_edge_labels() {
+        let scan = |name: &str| {
+            let schema = Schema::from_iter([Field::new(name.into(), DataType::Int64)]);
+            IR::DataFrameScan {
+                df: Arc::new(DataFrame::empty_with_schema(&schema)),
+                schema: Arc::new(schema),
+                output_schema: None,
+            }
+        };
+        let mut lp_arena = Arena::new();
+        let mut expr_arena = Arena::new();
+        let input_left = lp_arena.add(scan("a"));
+        let input_right = lp_arena.add(scan("b"));
+        let left_on = vec![ExprIR::from_node(
+            expr_arena.add(AExpr::Column("a".into())),
+            &expr_arena,
+        )];
+        let right_on = vec![ExprIR::from_node(
+            expr_arena.add(AExpr::Column("b".into())),
+            &expr_arena,
+        )];
+        let root = lp_arena.add(IR::Join {
+            input_left,
+            input_right,
+            schema: Arc::new(Schema::from_iter([
+                Field::new("a".into(), DataType::Int64),
+                Field::new("b".into(), DataType::Int64),
+            ])),
+            left_on,
+            right_on,
+            options: Arc::new(JoinOptions::default().into()),
+        });
+        let plan = IRPlanRef {
+            lp_top: root,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+
+        let dot = plan.display_dot().to_string();
+        assert!(dot.contains(r#"p2 -> p1[label="[col(\"a\")]"]"#), "{dot}");
+        assert!(dot.contains(r#"p3 -> p1[label="[col(\"b\")]"]"#), "{dot}");
+        assert!(dot.contains(r#"p1[label="JOIN LEFT"]"#), "{dot}");
+
+        let mermaid = plan.display_mermaid().to_string();
+        let expected = r#"flowchart TD
+  p2 -->|"[col(#quot;a#quot;)]"| p1
+  p2["TABLE<br>π */1"]
+  p3 -->|"[col(#quot;b#quot;)]"| p1
+  p3["TABLE<br>π */1"]
+  p1["JOIN LEFT"]
+"#;
+        assert_eq!(mermaid, expected);
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_partitioned_sink_display() {
+        use polars_utils::plpath::PlPath;
+
+        use crate::dsl::sink2::FileProviderType;
+
+        let schema = Arc::new(Schema::from_iter([Field::new("a".into(), DataType::Int32)]));
+        let mut lp_arena = Arena::new();
+        let mut expr_arena = Arena::new();
+        let input = lp_arena.add(IR::DataFrameScan {
+            df: Arc::new(DataFrame::empty_with_schema(&schema)),
+            schema,
+            output_schema: None,
+        });
+        let key = expr_arena.add(AExpr::Column("a".into()));
+        let payload = SinkTypeIR::Partitioned(PartitionedSinkOptionsIR {
+            base_path: PlPath::new("/tmp/out"),
+            file_path_provider: FileProviderType::Hive {
+                extension: "parquet".into(),
+            },
+            partition_strategy: PartitionStrategyIR::Keyed {
+                keys: vec![ExprIR::from_node(key, &expr_arena)],
+                include_keys: true,
+                keys_pre_grouped: false,
+                per_partition_sort_by: vec![],
+            },
+            finish_callback: None,
+            file_format: Arc::new(FileType::Parquet(Default::default())),
+            unified_sink_args: Default::default(),
+            max_rows_per_file: IdxSize::MAX,
+            approximate_bytes_per_file: u64::MAX,
+        });
+        let root = lp_arena.add(IR::Sink { input, payload });
+        let plan = IRPlanRef {
+            lp_top: root,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+
+        let dot = plan.display_dot().to_string();
+        assert!(
+            dot.contains(r#"SINK (PARTITION)\nparquet [/tmp/out]\nby: [col(\"a\")]"#),
+            "{dot}"
+        );
+
+        let explain = plan.display().to_string();
+        assert!(
+            explain.contains(r#"SINK (partition) parquet [/tmp/out] BY [col("a")]"#),
+            "{explain}"
+        );
+    }
+
+    #[test]
+    fn test_ext_context_display() {
+        let scan = |fields: &[(&str, DataType)]| {
+            let schema = Schema::from_iter(
+                fields
+                    .iter()
+                    .map(|(name, dtype)| Field::new((*name).into(), dtype.clone())),
+            );
+            IR::DataFrameScan {
+                df: Arc::new(DataFrame::empty_with_schema(&schema)),
+                schema: Arc::new(schema),
+                output_schema: None,
+            }
+        };
+        let mut lp_arena = Arena::new();
+        let input = lp_arena.add(scan(&[("a", DataType::Int32)]));
+        let contexts = vec![
+            lp_arena.add(scan(&[("a", DataType::Int32), ("b", DataType::Int64)])),
+            lp_arena.add(scan(&[("b", DataType::Int64), ("c", DataType::String)])),
+        ];
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int32),
+            Field::new("b".into(), DataType::Int64),
+            Field::new("c".into(), DataType::String),
+        ]);
+        let root = lp_arena.add(IR::ExtContext {
+            input,
+            contexts,
+            schema: Arc::new(schema),
+        });
+        let expr_arena = Arena::new();
+        let plan = IRPlanRef {
+            lp_top: root,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+
+        let dot = plan.display_dot().to_string();
+        let expected = r#"digraph polars_query {
+  rankdir="BT"
+  node [fontname="Monospace", shape="box"]
+  p2 -> p1
+  p2[label="TABLE\nπ */1"]
+  p3 -> p1[label="context 0"]
+  p3[label="TABLE\nπ */2"]
+  p4 -> p1[label="context 1"]
+  p4[label="TABLE\nπ */2"]
+  p1[label="EXTERNAL_CONTEXT\ninput columns: 1\ncontext 0: 1 column [b: i64]\ncontext 1: 1 column [c: str]"]
+}
+"#;
+        assert_eq!(dot, expected);
+
+        let explain = plan.display().to_string();
+        let expected = r#"EXTERNAL_CONTEXT
+  INPUT:
+    DF ["a"]; PROJECT */1 COLUMNS
+  CONTEXT 0 ADDS 1 column [b: i64]:
+    DF ["a", "b"]; PROJECT */2 COLUMNS
+  CONTEXT 1 ADDS 1 column [c: str]:
+    DF ["b", "c"]; PROJECT */2 COLUMNS
+END EXTERNAL_CONTEXT"#;
+        assert_eq!(explain, expected);
+    }
+
+    #[cfg(feature = "merge_sorted")]
+    #[test]
+    fn test_merge_sorted_display() {
+        let scan = |fields: &[(&str, DataType)]| {
+            let schema = Schema::from_iter(
+                fields
+                    .iter()
+                    .map(|(name, dtype)| Field::new((*name).into(), dtype.clone())),
+            );
+            IR::DataFrameScan {
+                df: Arc::new(DataFrame::empty_with_schema(&schema)),
+                schema: Arc::new(schema),
+                output_schema: None,
+            }
+        };
+        let fields = [("a", DataType::Int32), ("b", DataType::String)];
+        let mut lp_arena = Arena::new();
+        let expr_arena = Arena::new();
+        let left = lp_arena.add(scan(&fields));
+        let right = lp_arena.add(scan(&fields));
+        let other = lp_arena.add(scan(&[("a", DataType::Int32)]));
+        let same = lp_arena.add(IR::MergeSorted {
+            input_left: left,
+            input_right: right,
+            key: "a".into(),
+        });
+        let different = lp_arena.add(IR::MergeSorted {
+            input_left: left,
+            input_right: other,
+            key: "a".into(),
+        });
+
+        let label = |root| {
+            let plan = IRPlanRef {
+                lp_top: root,
+                lp_arena: &lp_arena,
+                expr_arena: &expr_arena,
+            };
+            let dot = plan.display_dot().to_string();
+            dot.lines()
+                .find_map(|l| l.trim().strip_prefix("p1[label="))
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(
+            label(same),
+            r#""MERGE_SORTED ON 'a'\ncolumns: 2 left, 2 right; same schema"]"#
+        );
+        assert_eq!(
+            label(different),
+            r#""MERGE_SORTED ON 'a'\ncolumns: 2 left, 1 right; different schemas"]"#
+        );
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_sink_multiple_display() {
+        use polars_utils::plpath::PlPath;
+
+        let schema = Arc::new(Schema::from_iter([Field::new("a".into(), DataType::Int32)]));
+        let mut lp_arena = Arena::new();
+        let inputs = (0..3)
+            .map(|i| {
+                let input = lp_arena.add(IR::DataFrameScan {
+                    df: Arc::new(DataFrame::empty_with_schema(&schema)),
+                    schema: schema.clone(),
+                    output_schema: None,
+                });
+                let payload = SinkTypeIR::File(FileSinkOptions {
+                    target: SinkTarget::Path(PlPath::new(&format!("/tmp/{i}.parquet"))),
+                    file_format: Arc::new(FileType::Parquet(Default::default())),
+                    unified_sink_args: Default::default(),
+                });
+                lp_arena.add(IR::Sink { input, payload })
+            })
+            .collect();
+        let root = lp_arena.add(IR::SinkMultiple { inputs });
+        let expr_arena = Arena::new();
+        let plan = IRPlanRef {
+            lp_top: root,
+            lp_arena: &lp_arena,
+            expr_arena: &expr_arena,
+        };
+
+        let dot = plan.display_dot().to_string();
+        let expected = r#"digraph polars_query {
+  rankdir="BT"
+  node [fontname="Monospace", shape="box"]
+  p2 -> p1[label="sink 0\nparquet [/tmp/0.parquet]"]
+  p3 -> p2
+  p3[label="TABLE\nπ */1"]
+  p2[label="SINK (FILE)\nparquet [/tmp/0.parquet]"]
+  p4 -> p1[label="sink 1\nparquet [/tmp/1.parquet]"]
+  p5 -> p4
+  p5[label="TABLE\nπ */1"]
+  p4[label="SINK (FILE)\nparquet [/tmp/1.parquet]"]
+  p6 -> p1[label="sink 2\nparquet [/tmp/2.parquet]"]
+  p7 -> p6
+  p7[label="TABLE\nπ */1"]
+  p6[label="SINK (FILE)\nparquet [/tmp/2.parquet]"]
+  p1[label="SINK MULTIPLE"]
+}
+"#;
+        assert_eq!(dot, expected);
+
+        let explain = plan.display().to_string();
+        let expected = r#"SINK_MULTIPLE
+  PLAN 0 -> parquet [/tmp/0.parquet]:
+    SINK (file) parquet [/tmp/0.parquet]
+      DF ["a"]; PROJECT */1 COLUMNS
+  PLAN 1 -> parquet [/tmp/1.parquet]:
+    SINK (file) parquet [/tmp/1.parquet]
+      DF ["a"]; PROJECT */1 COLUMNS
+  PLAN 2 -> parquet [/tmp/2.parquet]:
+    SINK (file) parquet [/tmp/2.parquet]
+      DF ["a"]; PROJECT */1 COLUMNS
+END SINK_MULTIPLE"#;
+        assert_eq!(explain, expected);
+    }
+}
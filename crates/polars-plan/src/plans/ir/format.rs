@@ -7,7 +7,10 @@ use polars_utils::format_list_truncated;
 use polars_utils::slice_enum::Slice;
 use recursive::recursive;
 
-use self::ir::dot::ScanSourcesDisplay;
+use self::ir::dot::{
+    AddedColumnsDisplay, ScanSourcesDisplay, SinkDestinationDisplay, SinkTargetDisplay,
+    ext_context_added_columns,
+};
 use crate::dsl::deletion::DeletionFilesList;
 use crate::prelude::*;
 
@@ -236,11 +239,33 @@ impl<'a> IRDisplay<'a> {
                 // - 2 => actual formatting of plans
                 let sub_sub_indent = sub_indent + 2;
                 for (i, plan) in inputs.iter().enumerate() {
-                    write!(f, "\n{:sub_indent$}PLAN {i}:", "")?;
+                    write!(f, "\n{:sub_indent$}PLAN {i}", "")?;
+                    if let Sink { payload, .. } = self.lp.lp_arena.get(*plan) {
+                        write!(f, " -> {}", SinkDestinationDisplay(payload))?;
+                    }
+                    f.write_str(":")?;
                     self.with_root(*plan)._format(f, sub_sub_indent)?;
                 }
                 write!(f, "\n{:indent$}END SINK_MULTIPLE", "")
             },
+            ExtContext {
+                input,
+                contexts,
+                schema,
+            } => {
+                write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)?;
+
+                let added = ext_context_added_columns(self.lp.lp_arena, *input, contexts, schema);
+                let sub_sub_indent = sub_indent + INDENT_INCREMENT;
+                write!(f, "\n{:sub_indent$}INPUT:", "")?;
+                self.with_root(*input)._format(f, sub_sub_indent)?;
+                for (i, (context, columns)) in contexts.iter().zip(&added).enumerate() {
+                    let columns = AddedColumnsDisplay(columns);
+                    write!(f, "\n{:sub_indent$}CONTEXT {i} ADDS {columns}:", "")?;
+                    self.with_root(*context)._format(f, sub_sub_indent)?;
+                }
+                write!(f, "\n{:indent$}END EXTERNAL_CONTEXT", "")
+            },
             #[cfg(feature = "merge_sorted")]
             MergeSorted {
                 input_left,
@@ -976,14 +1001,28 @@ pub fn write_ir_non_recursive(
             contexts: _,
             schema: _,
         } => write!(f, "{:indent$}EXTERNAL_CONTEXT", ""),
-        IR::Sink { input: _, payload } => {
-            let name = match payload {
-                SinkTypeIR::Memory => "SINK (memory)",
-                SinkTypeIR::Callback { .. } => "SINK (callback)",
-                SinkTypeIR::File { .. } => "SINK (file)",
-                SinkTypeIR::Partitioned { .. } => "SINK (partition)",
-            };
-            write!(f, "{:indent$}{name}", "")
+        IR::Sink { input: _, payload } => match payload {
+            SinkTypeIR::Memory => write!(f, "{:indent$}SINK (memory)", ""),
+            SinkTypeIR::Callback { .. } => write!(f, "{:indent$}SINK (callback)", ""),
+            SinkTypeIR::File(options) => {
+                let format = options.file_format.extension();
+                let target = SinkTargetDisplay(&options.target);
+                write!(f, "{:indent$}SINK (file) {format} {target}", "")
+            },
+            SinkTypeIR::Partitioned(options) => {
+                let format = options.file_format.extension();
+                let base_path = options.base_path.display();
+                write!(f, "{:indent$}SINK (partition) {format} [{base_path}]", "")?;
+
+                if let PartitionStrategyIR::Keyed { keys, .. } = &options.partition_strategy {
+                    let keys = ExprIRSliceDisplay {
+                        exprs: keys,
+                        expr_arena,
+                    };
+                    write!(f, " BY {keys}")?;
+                }
+                Ok(())
+            },
         },
         IR::SinkMultiple { inputs: _ } => write!(f, "{:indent$}SINK_MULTIPLE", ""),
         #[cfg(feature = "merge_sorted")]
@@ -9,7 +9,10 @@ pub mod visualization;
 use std::borrow::Cow;
 use std::fmt;
 
-pub use dot::{EscapeLabel, IRDotDisplay, PathsDisplay, ScanSourcesDisplay};
+pub use dot::{
+    DotDisplayConfig, EscapeJsonString, EscapeLabel, EscapeMermaidLabel, IRDotDisplay,
+    IRJsonDisplay, IRMermaidDisplay, PathsDisplay, ScanSourcesDisplay,
+};
 pub use format::{ExprIRDisplay, IRDisplay, write_group_by, write_ir_non_recursive};
 use polars_core::prelude::*;
 use polars_utils::idx_vec::UnitVec;
@@ -201,6 +204,14 @@ impl IRPlan {
     pub fn display_dot(&self) -> dot::IRDotDisplay<'_> {
         self.as_ref().display_dot()
     }
+
+    pub fn display_mermaid(&self) -> dot::IRMermaidDisplay<'_> {
+        self.as_ref().display_mermaid()
+    }
+
+    pub fn to_plan_json(&self) -> String {
+        self.as_ref().to_plan_json()
+    }
 }
 
 impl<'a> IRPlanRef<'a> {
@@ -224,6 +235,15 @@ impl<'a> IRPlanRef<'a> {
         dot::IRDotDisplay::new(self)
     }
 
+    pub fn display_mermaid(self) -> dot::IRMermaidDisplay<'a> {
+        dot::IRMermaidDisplay::new(self)
+    }
+
+    /// Get the graph of the plan as a JSON document, see [`IRJsonDisplay`].
+    pub fn to_plan_json(self) -> String {
+        dot::IRJsonDisplay::new(self).to_string()
+    }
+
     pub fn describe(self) -> String {
         self.display().to_string()
     }
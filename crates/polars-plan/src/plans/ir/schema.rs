@@ -15,6 +15,16 @@ impl IR {
         }
     }
 
+    /// Get the plan-time statistics of column `name` if this is a scan that has them.
+    ///
+    /// Returns `None` for any other node and for scans of formats that provide no statistics.
+    pub fn scan_column_statistics(&self, name: &str) -> Option<&ColumnStatistics> {
+        match self {
+            IR::Scan { file_info, .. } => file_info.column_statistics(name),
+            _ => None,
+        }
+    }
+
     pub fn name(&self) -> &'static str {
         use IR::*;
         match self {
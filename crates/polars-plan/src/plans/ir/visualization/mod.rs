@@ -318,6 +318,7 @@ impl IRVisualizationDataGenerator<'_> {
                 let JoinOptionsIR {
                     allow_parallel,
                     force_parallel,
+                    bloom_filter,
                     args:
                         JoinArgs {
                             how,
@@ -424,6 +425,7 @@ impl IRVisualizationDataGenerator<'_> {
                         slice: convert_opt_slice(slice),
                         allow_parallel: *allow_parallel,
                         force_parallel: *force_parallel,
+                        bloom_filter: *bloom_filter,
                     },
                 };
 
@@ -451,6 +453,7 @@ impl IRVisualizationDataGenerator<'_> {
                         schema: _,
                         reader_schema: _,
                         row_estimation: _,
+                        column_statistics: _,
                     },
                 predicate,
                 predicate_file_skip_applied,
@@ -618,6 +621,7 @@ impl IRVisualizationDataGenerator<'_> {
                         nulls_last,
                         multithreaded,
                         maintain_order,
+                        deterministic,
                         limit,
                     },
             } => {
@@ -628,6 +632,7 @@ impl IRVisualizationDataGenerator<'_> {
                     nulls_last: nulls_last.clone(),
                     multithreaded: *multithreaded,
                     maintain_order: *maintain_order,
+                    deterministic: *deterministic,
                     #[cfg_attr(feature = "bigidx", expect(clippy::useless_conversion))]
                     limit: limit.map(|x| x.into()),
                 };
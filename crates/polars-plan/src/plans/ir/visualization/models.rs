@@ -111,6 +111,7 @@ pub enum IRNodeProperties {
         slice: Option<(i64, u64)>,
         allow_parallel: bool,
         force_parallel: bool,
+        bloom_filter: Option<bool>,
     },
     CrossJoin {
         maintain_order: MaintainOrderJoin,
@@ -166,6 +167,7 @@ pub enum IRNodeProperties {
         nulls_last: Vec<bool>,
         multithreaded: bool,
         maintain_order: bool,
+        deterministic: bool,
         limit: Option<u64>,
     },
     Union {
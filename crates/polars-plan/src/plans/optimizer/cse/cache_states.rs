@@ -1,5 +1,4 @@
-use std::collections::BTreeMap;
-
+use polars_utils::idx_vec::UnitVec;
 use polars_utils::unique_id::UniqueId;
 
 use super::*;
@@ -28,33 +27,80 @@ fn get_upper_projections(
 
             true
         },
-        // Only filter and projection nodes are allowed, any other node we stop.
+        // A slice doesn't change the schema, so the projection above it determines the columns.
+        Slice { .. } => true,
+        // Only filter, projection and slice nodes are allowed, any other node we stop.
         _ => false,
     }
 }
 
+/// Whether the conjuncts of the predicate can be applied separately, below other filters. This
+/// is not the case if a conjunct depends on the other rows, e.g. `a > a.mean()`.
+fn is_splittable_predicate(
+    predicate: &ExprIR,
+    expr_arena: &Arena<AExpr>,
+    maintain_errors: bool,
+) -> bool {
+    MintermIter::new(predicate.node(), expr_arena).all(|node| {
+        !ExprPushdownGroup::Pushable
+            .update_with_expr_rec(expr_arena.get(node), expr_arena, None)
+            .blocks_pushdown(maintain_errors)
+    })
+}
+
 fn get_upper_predicates(
     parent: Node,
     lp_arena: &Arena<IR>,
-    expr_arena: &mut Arena<AExpr>,
+    expr_arena: &Arena<AExpr>,
     predicate_scratch: &mut Vec<Expr>,
+    maintain_errors: bool,
 ) -> bool {
     let parent = lp_arena.get(parent);
 
     use IR::*;
     match parent {
         Filter { predicate, .. } => {
-            let expr = predicate.to_expr(expr_arena);
-            predicate_scratch.push(expr);
-            false
+            // Shared conjuncts are moved below the cache, so we stop at a filter that cannot be
+            // split. Its conjuncts and the filters above it must see all rows that reach it.
+            if !is_splittable_predicate(predicate, expr_arena, maintain_errors) {
+                return false;
+            }
+            // Split into the conjunctive components, so that predicates that only differ in the
+            // order of their conjunctions are seen as equal.
+            let conjuncts = MintermIter::new(predicate.node(), expr_arena)
+                .map(|node| node_to_expr(node, expr_arena));
+            predicate_scratch.extend(conjuncts);
+            true
         },
         SimpleProjection { .. } => true,
-        // Only filter and projection nodes are allowed, any other node we stop.
+        // Only filter and projection nodes are allowed, any other node we stop. This includes a
+        // slice, as a filter above a slice cannot be pushed below it.
         _ => false,
     }
 }
 
-type TwoParents = [Option<Node>; 2];
+/// The chain of `Filter`, `SimpleProjection` and `Slice` nodes above a node, including the first
+/// ancestor that is none of these. Ordered from the farthest to the nearest ancestor.
+type Ancestors = UnitVec<Node>;
+
+fn is_filter_or_projection(node: Node, lp_arena: &Arena<IR>) -> bool {
+    matches!(
+        lp_arena.get(node),
+        IR::Filter { .. } | IR::SimpleProjection { .. }
+    )
+}
+
+/// Iterate the `Filter` and `SimpleProjection` ancestors, from the nearest to the farthest.
+fn filter_and_projection_ancestors<'a>(
+    ancestors: &'a Ancestors,
+    lp_arena: &'a Arena<IR>,
+) -> impl Iterator<Item = Node> + 'a {
+    ancestors
+        .iter()
+        .rev()
+        .copied()
+        .take_while(|&node| is_filter_or_projection(node, lp_arena))
+}
 
 // 1. This will ensure that all equal caches communicate the amount of columns
 //    they need to project.
@@ -117,7 +163,10 @@ type TwoParents = [Option<Node>; 2];
 // - NO FILTERS: run predicate pd from the cache nodes -> finish
 // - Above the filters the caches are the same -> run predicate pd from the filter node -> finish
 // - There is a cache without predicates above the cache node -> run predicate form the cache nodes -> finish
+// - The predicates above the cache nodes share some conjuncts -> push the shared conjuncts below the
+//   cache nodes, keep the remainder above the cache nodes -> finish
 // - The predicates above the cache nodes are all different -> remove the cache nodes -> finish
+#[allow(clippy::too_many_arguments)]
 pub(super) fn set_cache_states(
     root: Node,
     lp_arena: &mut Arena<IR>,
@@ -126,7 +175,16 @@ pub(super) fn set_cache_states(
     verbose: bool,
     pushdown_maintain_errors: bool,
     new_streaming: bool,
+    report: &mut OptimizationReport,
 ) -> PolarsResult<()> {
+    #[cfg(feature = "tracing")]
+    let span = tracing::debug_span!(
+        "set_cache_states",
+        num_cache_ids = tracing::field::Empty,
+        consumers_per_cache = tracing::field::Empty,
+    )
+    .entered();
+
     let mut stack = Vec::with_capacity(4);
     let mut names_scratch = vec![];
     let mut predicates_scratch = vec![];
@@ -138,21 +196,25 @@ pub(super) fn set_cache_states(
     struct Value {
         // All the children of the cache per cache-id.
         children: Vec<Node>,
-        parents: Vec<TwoParents>,
+        parents: Vec<Ancestors>,
         cache_nodes: Vec<Node>,
         // Union over projected names.
-        names_union: PlHashSet<PlSmallStr>,
-        // Union over predicates.
-        predicate_union: PlHashMap<Expr, u32>,
+        names_union: PlIndexSet<PlSmallStr>,
+        // Union over the conjuncts of the predicates, with the number of caches they are above.
+        predicate_union: PlIndexMap<Expr, u32>,
+        // Number of caches that have a predicate above them.
+        num_filtered: u32,
     }
-    let mut cache_schema_and_children = BTreeMap::new();
+    // Cache ids are random, so the caches are kept in the order in which they are first found to
+    // keep the optimized plan deterministic.
+    let mut cache_schema_and_children = PlIndexMap::<UniqueId, Value>::default();
 
     // Stack frame
     #[derive(Default, Clone)]
     struct Frame {
         current: Node,
         cache_id: Option<UniqueId>,
-        parent: TwoParents,
+        parents: Ancestors,
         previous_cache: Option<UniqueId>,
     }
     let init = Frame {
@@ -175,7 +237,7 @@ pub(super) fn set_cache_states(
             if let Some(cache_id) = frame.cache_id {
                 frame.previous_cache = Some(cache_id)
             }
-            if frame.parent[0].is_some() {
+            if !frame.parents.is_empty() {
                 // Projection pushdown has already run and blocked on cache nodes
                 // the pushed down columns are projected just above this cache
                 // if there were no pushed down column, we just take the current
@@ -187,12 +249,12 @@ pub(super) fn set_cache_states(
                     .entry(*id)
                     .or_insert_with(Value::default);
                 v.children.push(*input);
-                v.parents.push(frame.parent);
+                v.parents.push(frame.parents.clone());
                 v.cache_nodes.push(frame.current);
 
                 let mut found_required_columns = false;
 
-                for parent_node in frame.parent.into_iter().flatten() {
+                for &parent_node in frame.parents.iter().rev() {
                     let keep_going = get_upper_projections(
                         parent_node,
                         lp_arena,
@@ -209,24 +271,28 @@ pub(super) fn set_cache_states(
                     }
                 }
 
-                for parent_node in frame.parent.into_iter().flatten() {
+                for &parent_node in frame.parents.iter().rev() {
                     let keep_going = get_upper_predicates(
                         parent_node,
                         lp_arena,
                         expr_arena,
                         &mut predicates_scratch,
+                        pushdown_maintain_errors,
                     );
-                    if !predicates_scratch.is_empty() {
-                        for pred in predicates_scratch.drain(..) {
-                            let count = v.predicate_union.entry(pred).or_insert(0);
-                            *count += 1;
-                        }
-                    }
-                    // We stop early as we want to find the first predicate node above the cache.
+                    // We collect the predicates of all filters directly above the cache.
                     if !keep_going {
                         break;
                     }
                 }
+                if !predicates_scratch.is_empty() {
+                    v.num_filtered += 1;
+                    // Count every conjunct only once per cache.
+                    let conjuncts = predicates_scratch.drain(..).collect::<PlIndexSet<_>>();
+                    for pred in conjuncts {
+                        let count = v.predicate_union.entry(pred).or_insert(0);
+                        *count += 1;
+                    }
+                }
 
                 // There was no explicit projection and we must take
                 // all columns
@@ -238,9 +304,11 @@ pub(super) fn set_cache_states(
             frame.cache_id = Some(*id);
         };
 
-        // Shift parents.
-        frame.parent[1] = frame.parent[0];
-        frame.parent[0] = Some(frame.current);
+        // Extend the chain of parents or start a new one.
+        if !matches!(lp, Filter { .. } | SimpleProjection { .. } | Slice { .. }) {
+            frame.parents.clear();
+        }
+        frame.parents.push(frame.current);
         for n in scratch.iter() {
             let mut new_frame = frame.clone();
             new_frame.current = *n;
@@ -249,6 +317,16 @@ pub(super) fn set_cache_states(
         scratch.clear();
     }
 
+    #[cfg(feature = "tracing")]
+    if !span.is_disabled() {
+        let consumers = cache_schema_and_children
+            .values()
+            .map(|v| v.cache_nodes.len())
+            .collect::<Vec<_>>();
+        span.record("num_cache_ids", cache_schema_and_children.len());
+        span.record("consumers_per_cache", tracing::field::debug(consumers));
+    }
+
     // # Second pass.
     // we create a subtree where we project the columns
     // just before the cache. Then we do another projection pushdown
@@ -257,14 +335,36 @@ pub(super) fn set_cache_states(
     if !cache_schema_and_children.is_empty() {
         let mut proj_pd = ProjectionPushDown::new();
         let mut pred_pd = PredicatePushDown::new(pushdown_maintain_errors, new_streaming);
-        for (_cache_id, v) in cache_schema_and_children {
+        let mut caches = cache_schema_and_children.into_iter();
+        while let Some((cache_id, v)) = caches.next() {
+            // The conjuncts that are above all caches.
+            let num_caches = v.children.len() as u32;
+            let shared_predicates = v
+                .predicate_union
+                .iter()
+                .filter(|(_, count)| **count == num_caches)
+                .map(|(pred, _)| pred.clone())
+                .collect::<PlIndexSet<_>>();
+
             // # CHECK IF WE NEED TO REMOVE CACHES
-            // If we encounter multiple predicates we remove the cache nodes completely as we don't
-            // want to loose predicate pushdown in favor of scan sharing.
-            if v.predicate_union.len() > 1 {
+            // If we encounter multiple predicates without any common conjunct we remove the cache
+            // nodes completely as we don't want to loose predicate pushdown in favor of scan
+            // sharing.
+            let predicates_differ = v
+                .predicate_union
+                .values()
+                .any(|&count| count != v.num_filtered);
+            if predicates_differ && shared_predicates.is_empty() {
                 if verbose {
                     eprintln!("cache nodes will be removed because predicates don't match")
                 }
+                report.cache_states.push(CacheStateReport {
+                    id: cache_id,
+                    num_consumers: v.children.len(),
+                    projection_union_size: v.names_union.len(),
+                    predicate_pushdown: None,
+                    removed: true,
+                });
                 for ((&child, cache), parents) in
                     v.children.iter().zip(v.cache_nodes).zip(v.parents)
                 {
@@ -274,22 +374,23 @@ pub(super) fn set_cache_states(
                     // Restart predicate and projection pushdown from most top parent.
                     // This to ensure we continue the optimization where it was blocked initially.
                     // We pick up the blocked filter and projection.
-                    let mut node = cache;
-                    for p_node in parents.into_iter().flatten() {
-                        if matches!(
-                            lp_arena.get(p_node),
-                            IR::Filter { .. } | IR::SimpleProjection { .. }
-                        ) {
-                            node = p_node
-                        } else {
-                            break;
-                        }
-                    }
+                    let node = filter_and_projection_ancestors(&parents, lp_arena)
+                        .last()
+                        .unwrap_or(cache);
+
+                    proj_pd.optimize_node(node, lp_arena, expr_arena)?;
+                    pred_pd.optimize_node(node, lp_arena, expr_arena)?;
+                }
 
-                    let lp = lp_arena.take(node);
-                    let lp = proj_pd.optimize(lp, lp_arena, expr_arena)?;
-                    let lp = pred_pd.optimize(lp, lp_arena, expr_arena)?;
-                    lp_arena.replace(node, lp);
+                // The remaining caches are not optimized anymore, but they are still in the plan.
+                for (cache_id, v) in caches {
+                    report.cache_states.push(CacheStateReport {
+                        id: cache_id,
+                        num_consumers: v.children.len(),
+                        projection_union_size: v.names_union.len(),
+                        predicate_pushdown: None,
+                        removed: false,
+                    });
                 }
                 return Ok(());
             }
@@ -303,39 +404,43 @@ pub(super) fn set_cache_states(
                 let first_child = *v.children.first().expect("at least on child");
 
                 let columns = &v.names_union;
-                let child_lp = lp_arena.take(first_child);
-
-                // Make sure we project in the order of the schema
-                // if we don't a union may fail as we would project by the
-                // order we discovered all values.
-                let child_schema = child_lp.schema(lp_arena);
-                let child_schema = child_schema.as_ref();
-                let projection = child_schema
-                    .iter_names()
-                    .flat_map(|name| columns.get(name.as_str()).cloned())
-                    .collect::<Vec<_>>();
-
-                let new_child = lp_arena.add(child_lp);
-
-                let lp = IRBuilder::new(new_child, expr_arena, lp_arena)
-                    .project_simple(projection)
-                    .expect("unique names")
-                    .build();
-
-                let lp = proj_pd.optimize(lp, lp_arena, expr_arena)?;
-                // Optimization can lead to a double projection. Only take the last.
-                let lp = if let IR::SimpleProjection { input, columns } = lp {
-                    let input =
-                        if let IR::SimpleProjection { input: input2, .. } = lp_arena.get(input) {
+                lp_arena.try_substitute(first_child, |child_lp, lp_arena| {
+                    let original = child_lp.clone();
+                    // Make sure we project in the order of the schema
+                    // if we don't a union may fail as we would project by the
+                    // order we discovered all values.
+                    let child_schema = child_lp.schema(lp_arena);
+                    let child_schema = child_schema.as_ref();
+                    let projection = child_schema
+                        .iter_names()
+                        .flat_map(|name| columns.get(name.as_str()).cloned())
+                        .collect::<Vec<_>>();
+
+                    let new_child = lp_arena.add(child_lp);
+
+                    let lp = IRBuilder::new(new_child, expr_arena, lp_arena)
+                        .project_simple(projection)
+                        .expect("unique names")
+                        .build();
+
+                    let lp = proj_pd
+                        .optimize(lp, lp_arena, expr_arena)
+                        .map_err(|err| (original, err))?;
+                    // Optimization can lead to a double projection. Only take the last.
+                    Ok(if let IR::SimpleProjection { input, columns } = lp {
+                        let input = if let IR::SimpleProjection { input: input2, .. } =
+                            lp_arena.get(input)
+                        {
                             *input2
                         } else {
                             input
                         };
-                    IR::SimpleProjection { input, columns }
-                } else {
-                    lp
-                };
-                lp_arena.replace(first_child, lp.clone());
+                        IR::SimpleProjection { input, columns }
+                    } else {
+                        lp
+                    })
+                })?;
+                let lp = lp_arena.get(first_child).clone();
 
                 // Set the remaining children to the same node.
                 for &child in &v.children[1..] {
@@ -344,9 +449,8 @@ pub(super) fn set_cache_states(
             } else {
                 // No upper projections to include, run projection pushdown from cache node.
                 let first_child = *v.children.first().expect("at least on child");
-                let child_lp = lp_arena.take(first_child);
-                let lp = proj_pd.optimize(child_lp, lp_arena, expr_arena)?;
-                lp_arena.replace(first_child, lp.clone());
+                proj_pd.optimize_node(first_child, lp_arena, expr_arena)?;
+                let lp = lp_arena.get(first_child).clone();
 
                 for &child in &v.children[1..] {
                     lp_arena.replace(child, lp.clone());
@@ -357,32 +461,57 @@ pub(super) fn set_cache_states(
             // Run this after projection pushdown, otherwise the predicate columns will not be projected.
 
             // - If all predicates of parent are the same we will restart predicate pushdown from the parent FILTER node.
+            //   The optimized chain is copied to all consumers, so this also requires the chains of
+            //   filters and projections above the caches to be identical.
+            // - If the predicates share some conjuncts, we push those below the cache and keep the
+            //   remainder above the cache. Then we start predicate pushdown from the cache node.
             // - Otherwise we will start predicate pushdown from the cache node.
-            let allow_parent_predicate_pushdown = v.predicate_union.len() == 1 && {
-                let (_pred, count) = v.predicate_union.iter().next().unwrap();
-                *count == v.children.len() as u32
-            };
+            let allow_parent_predicate_pushdown = !shared_predicates.is_empty()
+                && shared_predicates.len() == v.predicate_union.len()
+                && filter_chains_are_equal(&v.parents, lp_arena, expr_arena);
+
+            report.cache_states.push(CacheStateReport {
+                id: cache_id,
+                num_consumers: v.children.len(),
+                projection_union_size: v.names_union.len(),
+                predicate_pushdown: Some(if allow_parent_predicate_pushdown {
+                    CachePredicatePushdown::ParentFilter
+                } else {
+                    CachePredicatePushdown::CacheNode
+                }),
+                removed: false,
+            });
 
             if allow_parent_predicate_pushdown {
-                let parents = *v.parents.first().unwrap();
-                let node = get_filter_node(parents, lp_arena)
+                let parents = v.parents.first().unwrap();
+                let node = get_top_filter_node(parents, lp_arena)
                     .expect("expected filter; this is an optimizer bug");
-                let start_lp = lp_arena.take(node);
-
                 let mut pred_pd = PredicatePushDown::new(pushdown_maintain_errors, new_streaming)
                     .block_at_cache(1);
-                let lp = pred_pd.optimize(start_lp, lp_arena, expr_arena)?;
-                lp_arena.replace(node, lp.clone());
-                for &parents in &v.parents[1..] {
-                    let node = get_filter_node(parents, lp_arena)
+                pred_pd.optimize_node(node, lp_arena, expr_arena)?;
+                let lp = lp_arena.get(node).clone();
+                for parents in &v.parents[1..] {
+                    let node = get_top_filter_node(parents, lp_arena)
                         .expect("expected filter; this is an optimizer bug");
                     lp_arena.replace(node, lp.clone());
                 }
             } else {
                 let child = *v.children.first().unwrap();
-                let child_lp = lp_arena.take(child);
-                let lp = pred_pd.optimize(child_lp, lp_arena, expr_arena)?;
-                lp_arena.replace(child, lp.clone());
+                if !shared_predicates.is_empty() {
+                    let predicate = take_shared_predicates(
+                        &v.parents,
+                        &shared_predicates,
+                        lp_arena,
+                        expr_arena,
+                        pushdown_maintain_errors,
+                    );
+                    lp_arena.substitute(child, |lp, lp_arena| {
+                        let input = lp_arena.add(lp);
+                        IR::Filter { input, predicate }
+                    });
+                }
+                pred_pd.optimize_node(child, lp_arena, expr_arena)?;
+                let lp = lp_arena.get(child).clone();
                 for &child in &v.children[1..] {
                     lp_arena.replace(child, lp.clone());
                 }
@@ -392,9 +521,119 @@ pub(super) fn set_cache_states(
     Ok(())
 }
 
-fn get_filter_node(parents: TwoParents, lp_arena: &Arena<IR>) -> Option<Node> {
-    parents
-        .into_iter()
-        .flatten()
-        .find(|&parent| matches!(lp_arena.get(parent), IR::Filter { .. }))
+/// The `Filter` and `SimpleProjection` ancestors up to and including the farthest `Filter`.
+fn filter_chain(parents: &Ancestors, lp_arena: &Arena<IR>) -> Vec<Node> {
+    let mut chain = filter_and_projection_ancestors(parents, lp_arena).collect::<Vec<_>>();
+    let len = chain
+        .iter()
+        .rposition(|&node| matches!(lp_arena.get(node), IR::Filter { .. }))
+        .map_or(0, |i| i + 1);
+    chain.truncate(len);
+    chain
+}
+
+/// Whether the filter chains above all caches consist of the same filters and projections in the
+/// same order.
+fn filter_chains_are_equal(
+    parents: &[Ancestors],
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let first = filter_chain(&parents[0], lp_arena);
+    parents[1..].iter().all(|parents| {
+        let chain = filter_chain(parents, lp_arena);
+        chain.len() == first.len()
+            && chain
+                .iter()
+                .zip(&first)
+                .all(|(&a, &b)| match (lp_arena.get(a), lp_arena.get(b)) {
+                    (IR::Filter { predicate: a, .. }, IR::Filter { predicate: b, .. }) => {
+                        node_to_expr(a.node(), expr_arena) == node_to_expr(b.node(), expr_arena)
+                    },
+                    (
+                        IR::SimpleProjection { columns: a, .. },
+                        IR::SimpleProjection { columns: b, .. },
+                    ) => a == b,
+                    _ => false,
+                })
+    })
+}
+
+/// Get the farthest `Filter` in the chain of `Filter` and `SimpleProjection` nodes above a cache.
+fn get_top_filter_node(parents: &Ancestors, lp_arena: &Arena<IR>) -> Option<Node> {
+    get_filter_nodes(parents, lp_arena).last()
+}
+
+fn get_filter_nodes<'a>(
+    parents: &'a Ancestors,
+    lp_arena: &'a Arena<IR>,
+) -> impl Iterator<Item = Node> + 'a {
+    filter_and_projection_ancestors(parents, lp_arena)
+        .filter(|&node| matches!(lp_arena.get(node), IR::Filter { .. }))
+}
+
+/// Remove the conjuncts in `shared_predicates` from the filters above the caches and return them
+/// combined into a single predicate. As in [`get_upper_predicates`], this stops at the first filter
+/// that cannot be split.
+///
+/// Filters that have no conjuncts left are removed.
+fn take_shared_predicates(
+    parents: &[Ancestors],
+    shared_predicates: &PlIndexSet<Expr>,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    maintain_errors: bool,
+) -> ExprIR {
+    let mut shared = vec![];
+    let mut seen = PlHashSet::new();
+    for (i, parents) in parents.iter().enumerate() {
+        let filter_nodes = get_filter_nodes(parents, lp_arena)
+            .take_while(|&node| {
+                let IR::Filter { predicate, .. } = lp_arena.get(node) else {
+                    unreachable!()
+                };
+                is_splittable_predicate(predicate, expr_arena, maintain_errors)
+            })
+            .collect::<Vec<_>>();
+        for node in filter_nodes {
+            let IR::Filter { input, predicate } = lp_arena.get(node) else {
+                unreachable!()
+            };
+            let input = *input;
+
+            let mut remainder = vec![];
+            for conjunct in MintermIter::new(predicate.node(), expr_arena) {
+                let expr = node_to_expr(conjunct, expr_arena);
+                if !shared_predicates.contains(&expr) {
+                    remainder.push(conjunct);
+                } else if i == 0 && seen.insert(expr) {
+                    shared.push(conjunct);
+                }
+            }
+
+            match combine_conjuncts(remainder, expr_arena) {
+                Some(predicate) => {
+                    lp_arena.replace(node, IR::Filter { input, predicate });
+                },
+                // Move the input into the place of the filter, so that the input node isn't
+                // duplicated.
+                // The filter is left behind in the slot of the input, where it is unreachable.
+                None => {
+                    lp_arena.substitute(node, |filter, lp_arena| lp_arena.replace(input, filter));
+                },
+            }
+        }
+    }
+    combine_conjuncts(shared, expr_arena).unwrap()
+}
+
+fn combine_conjuncts(conjuncts: Vec<Node>, expr_arena: &mut Arena<AExpr>) -> Option<ExprIR> {
+    let node = conjuncts.into_iter().reduce(|left, right| {
+        expr_arena.add(AExpr::BinaryExpr {
+            left,
+            op: Operator::And,
+            right,
+        })
+    })?;
+    Some(ExprIR::from_node(node, expr_arena))
 }
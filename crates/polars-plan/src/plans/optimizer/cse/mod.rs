@@ -35,6 +35,7 @@ impl CommonSubPlanOptimizer {
         opt_flags: &OptFlags,
         verbose: bool,
         scratch: &mut Vec<Node>,
+        report: &mut OptimizationReport,
     ) -> PolarsResult<Node> {
         let (root, inserted_cache, _) = cse::elim_cmn_subplans(root, ir_arena, expr_arena);
 
@@ -56,6 +57,7 @@ impl CommonSubPlanOptimizer {
                 verbose,
                 pushdown_maintain_errors,
                 opt_flags.new_streaming(),
+                report,
             )?;
         }
 
@@ -21,6 +21,7 @@ mod expand_datasets;
 pub use expand_datasets::ExpandedPythonScan;
 mod predicate_pushdown;
 mod projection_pushdown;
+mod report;
 pub mod set_order;
 mod simplify_expr;
 mod slice_pushdown_expr;
@@ -36,6 +37,7 @@ pub use expand_datasets::ExpandedDataset;
 use polars_core::config::verbose;
 pub use predicate_pushdown::PredicatePushDown;
 pub use projection_pushdown::ProjectionPushDown;
+pub use report::{CachePredicatePushdown, CacheStateReport, OptimizationReport};
 pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
 use slice_pushdown_lp::SlicePushDown;
 pub use sortedness::{IRSorted, are_keys_sorted_any, is_sorted};
@@ -58,10 +60,62 @@ pub trait Optimize {
 // arbitrary constant to reduce reallocation.
 const HASHMAP_SIZE: usize = 16;
 
+/// Run an optimizer pass in a `tracing` span named after the pass that records the number of IR
+/// nodes reachable from `root` before and after the pass.
+macro_rules! traced_pass {
+    ($name:literal, $root:expr, $ir_arena:expr, $pass:block) => {{
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            $name,
+            nodes_before = tracing::field::Empty,
+            nodes_after = tracing::field::Empty,
+        );
+        #[cfg(feature = "tracing")]
+        let _guard = {
+            if !span.is_disabled() {
+                span.record(
+                    "nodes_before",
+                    ArenaLpIter::iter(&*$ir_arena, $root).count(),
+                );
+            }
+            span.enter()
+        };
+
+        let out = $pass;
+
+        #[cfg(debug_assertions)]
+        assert_no_invalid_nodes($name, $root, &*$ir_arena);
+
+        #[cfg(feature = "tracing")]
+        if !span.is_disabled() {
+            span.record("nodes_after", ArenaLpIter::iter(&*$ir_arena, $root).count());
+        }
+        out
+    }};
+}
+
 pub(crate) fn init_hashmap<K, V>(max_len: Option<usize>) -> PlHashMap<K, V> {
     PlHashMap::with_capacity(std::cmp::min(max_len.unwrap_or(HASHMAP_SIZE), HASHMAP_SIZE))
 }
 
+/// Checks that no placeholder left behind by [`Arena::take`] is reachable from `root`.
+#[cfg(debug_assertions)]
+fn assert_no_invalid_nodes(pass: &str, root: Node, ir_arena: &Arena<IR>) {
+    let mut visited = PlHashSet::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node) {
+            continue;
+        }
+        let ir = ir_arena.get(node);
+        assert!(
+            !matches!(ir, IR::Invalid),
+            "optimization pass '{pass}' left an invalid node in the plan"
+        );
+        ir.copy_inputs(&mut stack);
+    }
+}
+
 pub(crate) fn pushdown_maintain_errors() -> bool {
     std::env::var("POLARS_PUSHDOWN_OPT_MAINTAIN_ERRORS").as_deref() == Ok("1")
 }
@@ -76,9 +130,7 @@ pub(super) fn run_projection_predicate_pushdown(
     // Should be run before predicate pushdown.
     if opt_flags.projection_pushdown() {
         let mut projection_pushdown_opt = ProjectionPushDown::new();
-        let ir = ir_arena.take(root);
-        let ir = projection_pushdown_opt.optimize(ir, ir_arena, expr_arena)?;
-        ir_arena.replace(root, ir);
+        projection_pushdown_opt.optimize_node(root, ir_arena, expr_arena)?;
 
         if projection_pushdown_opt.is_count_star {
             let mut count_star_opt = CountStar::new();
@@ -89,9 +141,7 @@ pub(super) fn run_projection_predicate_pushdown(
     if opt_flags.predicate_pushdown() {
         let mut predicate_pushdown_opt =
             PredicatePushDown::new(pushdown_maintain_errors, opt_flags.new_streaming());
-        let ir = ir_arena.take(root);
-        let ir = predicate_pushdown_opt.optimize(ir, ir_arena, expr_arena)?;
-        ir_arena.replace(root, ir);
+        predicate_pushdown_opt.optimize_node(root, ir_arena, expr_arena)?;
     }
 
     Ok(())
@@ -108,6 +158,7 @@ pub fn optimize(
         &mut Arena<IR>,
         &mut Arena<AExpr>,
     ) -> PolarsResult<()>,
+    #[cfg_attr(not(feature = "cse"), allow(unused_variables))] report: &mut OptimizationReport,
 ) -> PolarsResult<Node> {
     #[allow(dead_code)]
     let verbose = verbose();
@@ -176,15 +227,18 @@ pub fn optimize(
                     eprintln!("found multiple sources; run comm_subplan_elim")
                 }
 
-                root = CommonSubPlanOptimizer::new().optimize(
-                    root,
-                    ir_arena,
-                    expr_arena,
-                    pushdown_maintain_errors,
-                    &opt_flags,
-                    verbose,
-                    scratch,
-                )?;
+                traced_pass!("comm_subplan_elim", root, ir_arena, {
+                    root = CommonSubPlanOptimizer::new().optimize(
+                        root,
+                        ir_arena,
+                        expr_arena,
+                        pushdown_maintain_errors,
+                        &opt_flags,
+                        verbose,
+                        scratch,
+                        report,
+                    )?;
+                });
                 false
             } else {
                 true
@@ -197,13 +251,15 @@ pub fn optimize(
     };
 
     if run_pushdowns {
-        run_projection_predicate_pushdown(
-            root,
-            ir_arena,
-            expr_arena,
-            pushdown_maintain_errors,
-            &opt_flags,
-        )?;
+        traced_pass!("projection_predicate_pushdown", root, ir_arena, {
+            run_projection_predicate_pushdown(
+                root,
+                ir_arena,
+                expr_arena,
+                pushdown_maintain_errors,
+                &opt_flags,
+            )?;
+        });
     }
 
     // Make sure its before slice pushdown.
@@ -226,10 +282,9 @@ pub fn optimize(
             false, // maintain_errors
             opt_flags.new_streaming(),
         );
-        let ir = ir_arena.take(root);
-        let ir = slice_pushdown_opt.optimize(ir, ir_arena, expr_arena)?;
-
-        ir_arena.replace(root, ir);
+        traced_pass!("slice_pushdown", root, ir_arena, {
+            slice_pushdown_opt.optimize_node(root, ir_arena, expr_arena)?;
+        });
 
         // Expressions use the stack optimizer.
         rules.push(Box::new(slice_pushdown_opt));
@@ -245,22 +300,28 @@ pub fn optimize(
         rules.push(Box::new(FlattenUnionRule {}));
     }
 
-    root = opt.optimize_loop(&mut rules, expr_arena, ir_arena, root)?;
+    traced_pass!("optimize_loop", root, ir_arena, {
+        root = opt.optimize_loop(&mut rules, expr_arena, ir_arena, root)?;
+    });
 
     if opt_flags.cluster_with_columns() && get_or_init_members!().with_columns_count > 1 {
-        cluster_with_columns::optimize(root, ir_arena, expr_arena)
+        traced_pass!("cluster_with_columns", root, ir_arena, {
+            cluster_with_columns::optimize(root, ir_arena, expr_arena)
+        });
     }
 
     // This one should run (nearly) last as this modifies the projections
     #[cfg(feature = "cse")]
     if comm_subexpr_elim && !get_or_init_members!().has_ext_context {
-        let mut optimizer = CommonSubExprOptimizer::new();
-        let ir_node = IRNode::new_mutate(root);
-
-        root = try_with_ir_arena(ir_arena, expr_arena, |arena| {
-            let rewritten = ir_node.rewrite(&mut optimizer, arena)?;
-            Ok(rewritten.node())
-        })?;
+        traced_pass!("comm_subexpr_elim", root, ir_arena, {
+            let mut optimizer = CommonSubExprOptimizer::new();
+            let ir_node = IRNode::new_mutate(root);
+
+            root = try_with_ir_arena(ir_arena, expr_arena, |arena| {
+                let rewritten = ir_node.rewrite(&mut optimizer, arena)?;
+                Ok(rewritten.node())
+            })?;
+        });
     }
 
     if opt_flags.contains(OptFlags::CHECK_ORDER_OBSERVE) {
@@ -270,34 +331,47 @@ pub fn optimize(
             | members.has_distinct
             | members.has_joins_or_unions
         {
-            match ir_arena.get(root) {
-                IR::SinkMultiple { inputs } => {
-                    let mut roots = inputs.clone();
-                    for root in &mut roots {
-                        if !matches!(ir_arena.get(*root), IR::Sink { .. }) {
-                            *root = ir_arena.add(IR::Sink {
-                                input: *root,
+            traced_pass!("simplify_orderings", root, ir_arena, {
+                match ir_arena.get(root) {
+                    IR::SinkMultiple { inputs } => {
+                        let mut roots = inputs.clone();
+                        for root in &mut roots {
+                            if !matches!(ir_arena.get(*root), IR::Sink { .. }) {
+                                *root = ir_arena.add(IR::Sink {
+                                    input: *root,
+                                    payload: SinkTypeIR::Memory,
+                                });
+                            }
+                        }
+                        set_order::simplify_and_fetch_orderings(&roots, ir_arena, expr_arena);
+                    },
+                    ir => {
+                        let mut tmp_top = root;
+                        if !matches!(ir, IR::Sink { .. }) {
+                            tmp_top = ir_arena.add(IR::Sink {
+                                input: root,
                                 payload: SinkTypeIR::Memory,
                             });
                         }
-                    }
-                    set_order::simplify_and_fetch_orderings(&roots, ir_arena, expr_arena);
-                },
-                ir => {
-                    let mut tmp_top = root;
-                    if !matches!(ir, IR::Sink { .. }) {
-                        tmp_top = ir_arena.add(IR::Sink {
-                            input: root,
-                            payload: SinkTypeIR::Memory,
-                        });
-                    }
-                    _ = set_order::simplify_and_fetch_orderings(&[tmp_top], ir_arena, expr_arena)
-                },
-            }
+                        _ = set_order::simplify_and_fetch_orderings(
+                            &[tmp_top],
+                            ir_arena,
+                            expr_arena,
+                        )
+                    },
+                }
+            });
         }
     }
 
-    expand_datasets::expand_datasets(root, ir_arena, expr_arena, apply_scan_predicate_to_scan_ir)?;
+    traced_pass!("expand_datasets", root, ir_arena, {
+        expand_datasets::expand_datasets(
+            root,
+            ir_arena,
+            expr_arena,
+            apply_scan_predicate_to_scan_ir,
+        )?;
+    });
 
     // During debug we check if the optimizations have not modified the final schema.
     #[cfg(debug_assertions)]
@@ -329,3 +403,66 @@ fn _get_or_init_members<'a>(
         members
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::dsl::{SinkTypeIR, col, lit};
+    use crate::plans::{ExprToIRContext, to_expr_ir};
+
+    #[test]
+    fn test_substitute_with_stale_parent() {
+        let mut ir_arena = Arena::new();
+        let mut expr_arena = Arena::new();
+        let schema = Schema::from_iter([Field::new("a".into(), DataType::Int64)]);
+
+        let scan = ir_arena.add(IR::DataFrameScan {
+            df: Arc::new(DataFrame::empty_with_schema(&schema)),
+            schema: Arc::new(schema.clone()),
+            output_schema: None,
+        });
+        let mut ctx = ExprToIRContext::new(&mut expr_arena, &schema);
+        ctx.allow_unknown = true;
+        let filter = ir_arena.add(IR::Filter {
+            input: scan,
+            predicate: to_expr_ir(col("a").gt_eq(lit(10)), &mut ctx).unwrap(),
+        });
+
+        // Both sinks are parents of the filter, but the rewrite starts from the filter.
+        let root = ir_arena.add(IR::Sink {
+            input: filter,
+            payload: SinkTypeIR::Memory,
+        });
+        let stale = ir_arena.add(IR::Sink {
+            input: filter,
+            payload: SinkTypeIR::Memory,
+        });
+
+        let mut pred_pd = PredicatePushDown::new(false, false);
+        pred_pd
+            .optimize_node(filter, &mut ir_arena, &mut expr_arena)
+            .unwrap();
+
+        // Afterwards both parents see the rewritten filter.
+        for parent in [root, stale] {
+            let input = ir_arena.get(parent).get_inputs()[0];
+            assert!(!matches!(ir_arena.get(input), IR::Invalid));
+            #[cfg(debug_assertions)]
+            assert_no_invalid_nodes("predicate_pushdown", parent, &ir_arena);
+        }
+
+        // A failing rewrite puts the original node back.
+        let IR::Filter { input, predicate } = ir_arena.get(filter) else {
+            panic!("expected filter")
+        };
+        let (input, predicate) = (*input, predicate.node());
+        let out = ir_arena.try_substitute(filter, |ir, _| {
+            Err((ir, polars_err!(ComputeError: "failed")))
+        });
+        assert!(out.is_err());
+        assert!(matches!(
+            ir_arena.get(filter),
+            IR::Filter { input: i, predicate: p } if *i == input && p.node() == predicate
+        ));
+    }
+}
@@ -655,4 +655,19 @@ impl PredicatePushDown {
         let acc_predicates = PlHashMap::new();
         self.push_down(logical_plan, acc_predicates, lp_arena, expr_arena)
     }
+
+    /// Optimize the plan at `node` in place. If this fails, the node is left as it was.
+    pub(crate) fn optimize_node(
+        &mut self,
+        node: Node,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> PolarsResult<()> {
+        lp_arena.try_substitute(node, |lp, lp_arena| {
+            // The node only refers to its inputs, so this doesn't copy the plan below it.
+            let original = lp.clone();
+            self.optimize(lp, lp_arena, expr_arena)
+                .map_err(|err| (original, err))
+        })
+    }
 }
@@ -0,0 +1,46 @@
+use polars_utils::unique_id::UniqueId;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Structured information about the decisions the optimizer made for a query.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OptimizationReport {
+    /// The state of every cache inserted by common subplan elimination, in the order the caches
+    /// were found in the plan.
+    pub cache_states: Vec<CacheStateReport>,
+}
+
+impl OptimizationReport {
+    pub fn cache_state(&self, id: &UniqueId) -> Option<&CacheStateReport> {
+        self.cache_states.iter().find(|state| &state.id == id)
+    }
+}
+
+/// Where predicate pushdown was restarted for the inputs of a cache.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CachePredicatePushdown {
+    /// All consumers have the same predicate, pushdown was restarted from the filter above the
+    /// caches.
+    ParentFilter,
+    /// Pushdown was restarted from the cache node, only the conjuncts shared by all consumers
+    /// were pushed below the cache.
+    CacheNode,
+}
+
+/// The optimizer decisions for the caches that share a single cache id.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CacheStateReport {
+    pub id: UniqueId,
+    /// Number of cache nodes that read from the cached input.
+    pub num_consumers: usize,
+    /// Number of columns in the union of the projections above the caches.
+    pub projection_union_size: usize,
+    /// `None` if the caches were removed, or if they were skipped because the caches of an
+    /// earlier cache id were removed.
+    pub predicate_pushdown: Option<CachePredicatePushdown>,
+    /// Whether the caches were removed because the predicates above them don't match.
+    pub removed: bool,
+}
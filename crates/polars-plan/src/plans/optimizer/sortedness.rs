@@ -496,7 +496,7 @@ pub fn function_expr_sortedness(
             use crate::plans::IRRangeFunction as R;
             match range {
                 // `int_range(0, ..., step=1, dtype=UNSIGNED)`
-                R::IntRange { step: 1, dtype }
+                R::IntRange { step: 1, dtype, .. }
                     if dtype.is_unsigned_integer()
                         && constant_evaluate(inputs[0].node(), arena, schema, 0)??
                             .extract_i64()
@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 use std::ops::Deref;
-use std::sync::Mutex;
+use std::sync::{Mutex, OnceLock};
 
 use arrow::datatypes::ArrowSchemaRef;
 use either::Either;
@@ -47,6 +47,72 @@ pub struct FileInfo {
     /// - known size
     /// - estimated size (set to usize::max if unknown).
     pub row_estimation: (Option<usize>, usize),
+    /// Per-column statistics gathered from file metadata during scan planning.
+    ///
+    /// Only set if they could be obtained without reading data (e.g. from the Parquet footer).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "dsl-schema", schemars(skip))]
+    pub column_statistics: Option<ScanColumnStatistics>,
+}
+
+/// Lazily computed per-column statistics of a scan.
+///
+/// The statistics of a column are only computed the first time they are requested, and a column
+/// whose statistics cannot be obtained (e.g. undecodable metadata) simply has none.
+#[derive(Clone)]
+pub struct ScanColumnStatistics(Arc<ScanColumnStatisticsInner>);
+
+struct ScanColumnStatisticsInner {
+    columns: PlIndexMap<PlSmallStr, OnceLock<Option<ColumnStatistics>>>,
+    #[allow(clippy::type_complexity)]
+    compute: Box<dyn Fn(&str) -> Option<ColumnStatistics> + Send + Sync>,
+}
+
+impl ScanColumnStatistics {
+    pub fn new(
+        names: impl IntoIterator<Item = PlSmallStr>,
+        compute: impl Fn(&str) -> Option<ColumnStatistics> + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(ScanColumnStatisticsInner {
+            columns: names
+                .into_iter()
+                .map(|name| (name, OnceLock::new()))
+                .collect(),
+            compute: Box::new(compute),
+        }))
+    }
+
+    /// Get the statistics of column `name`, computing them if this is the first request.
+    pub fn get(&self, name: &str) -> Option<&ColumnStatistics> {
+        self.0
+            .columns
+            .get(name)?
+            .get_or_init(|| (self.0.compute)(name))
+            .as_ref()
+    }
+}
+
+impl std::fmt::Debug for ScanColumnStatistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let computed = self
+            .0
+            .columns
+            .iter()
+            .filter_map(|(name, stats)| Some((name, stats.get()?.as_ref()?)));
+        f.debug_map().entries(computed).finish()
+    }
+}
+
+/// Statistics of a single column that are known at plan time.
+///
+/// All fields are optional, as file formats only store some of them (if any).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColumnStatistics {
+    pub null_count: Option<usize>,
+    /// Estimate of the number of distinct values.
+    pub distinct_count: Option<usize>,
+    pub min_value: Option<Scalar>,
+    pub max_value: Option<Scalar>,
 }
 
 // Manual default because `row_estimation.1` needs to be `usize::MAX`.
@@ -56,6 +122,7 @@ impl Default for FileInfo {
             schema: Default::default(),
             reader_schema: None,
             row_estimation: (None, usize::MAX),
+            column_statistics: None,
         }
     }
 }
@@ -71,9 +138,15 @@ impl FileInfo {
             schema,
             reader_schema,
             row_estimation,
+            column_statistics: None,
         }
     }
 
+    /// Get the plan-time statistics of column `name`, if they are known.
+    pub fn column_statistics(&self, name: &str) -> Option<&ColumnStatistics> {
+        self.column_statistics.as_ref()?.get(name)
+    }
+
     /// Merge the [`Schema`] of a [`HivePartitions`] with the schema of this [`FileInfo`].
     pub fn update_schema_with_hive_schema(&mut self, hive_schema: SchemaRef) {
         let schema = Arc::make_mut(&mut self.schema);
@@ -322,3 +395,33 @@ pub fn get_input_schema(lp_arena: &Arena<IR>, lp_node: Node) -> Cow<'_, SchemaRe
         lp_arena.get(input).schema(lp_arena)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn test_scan_column_statistics_lazy() {
+        let computed = Arc::new(AtomicUsize::new(0));
+        let stats = ScanColumnStatistics::new(["a".into(), "b".into()], {
+            let computed = computed.clone();
+            move |name| {
+                computed.fetch_add(1, Ordering::Relaxed);
+                // Column "b" stands in for statistics that fail to decode.
+                (name == "a").then(|| ColumnStatistics {
+                    null_count: Some(1),
+                    ..Default::default()
+                })
+            }
+        });
+        assert_eq!(computed.load(Ordering::Relaxed), 0);
+
+        assert_eq!(stats.get("a").unwrap().null_count, Some(1));
+        assert_eq!(stats.get("a").unwrap().null_count, Some(1));
+        assert!(stats.get("b").is_none());
+        assert!(stats.get("c").is_none());
+        assert_eq!(computed.load(Ordering::Relaxed), 2);
+    }
+}
@@ -145,6 +145,8 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::int_ranges))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::int_range_array))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::linear_space))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::linear_spaces))
@@ -436,6 +438,9 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     #[cfg(feature = "polars_cloud_client")]
     m.add_wrapped(wrap_pyfunction!(cloud_client::prepare_cloud_plan))
         .unwrap();
+    #[cfg(feature = "polars_cloud_client")]
+    m.add_wrapped(wrap_pyfunction!(cloud_client::validate_cloud_plan))
+        .unwrap();
     #[cfg(feature = "polars_cloud_server")]
     m.add_wrapped(wrap_pyfunction!(cloud_server::_execute_ir_plan_with_gpu))
         .unwrap();
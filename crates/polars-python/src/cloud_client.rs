@@ -1,3 +1,4 @@
+use polars::prelude::CloudPlanOptions;
 use pyo3::prelude::{Python, *};
 use pyo3::types::PyBytes;
 
@@ -5,14 +6,43 @@ use crate::PyLazyFrame;
 use crate::error::PyPolarsErr;
 
 #[pyfunction]
+#[pyo3(signature = (lf, allow_local_scans, max_inline_dataframe_bytes=None))]
 pub fn prepare_cloud_plan(
     lf: PyLazyFrame,
     allow_local_scans: bool,
+    max_inline_dataframe_bytes: Option<usize>,
     py: Python<'_>,
 ) -> PyResult<Bound<'_, PyBytes>> {
     let plan = lf.ldf.into_inner().logical_plan;
-    let bytes =
-        polars::prelude::prepare_cloud_plan(plan, allow_local_scans).map_err(PyPolarsErr::from)?;
+    let options = cloud_plan_options(allow_local_scans, max_inline_dataframe_bytes);
+    let bytes = polars::prelude::prepare_cloud_plan(plan, &options).map_err(PyPolarsErr::from)?;
 
     Ok(PyBytes::new(py, &bytes))
 }
+
+/// Returns the reasons why the plan is ineligible for execution on Polars Cloud.
+#[pyfunction]
+#[pyo3(signature = (lf, allow_local_scans, max_inline_dataframe_bytes=None))]
+pub fn validate_cloud_plan(
+    lf: PyLazyFrame,
+    allow_local_scans: bool,
+    max_inline_dataframe_bytes: Option<usize>,
+) -> Vec<String> {
+    let plan = lf.ldf.into_inner().logical_plan;
+    let options = cloud_plan_options(allow_local_scans, max_inline_dataframe_bytes);
+    polars::prelude::validate_cloud_plan(&plan, &options)
+        .violations
+        .iter()
+        .map(|violation| violation.to_string())
+        .collect()
+}
+
+fn cloud_plan_options(
+    allow_local_scans: bool,
+    max_inline_dataframe_bytes: Option<usize>,
+) -> CloudPlanOptions {
+    CloudPlanOptions {
+        allow_local_scans,
+        max_inline_dataframe_bytes: max_inline_dataframe_bytes.unwrap_or(usize::MAX),
+    }
+}
@@ -30,7 +30,7 @@ use polars_core::utils::materialize_dyn_int;
 use polars_lazy::prelude::*;
 #[cfg(feature = "parquet")]
 use polars_parquet::write::StatisticsOptions;
-use polars_plan::dsl::ScanSources;
+use polars_plan::dsl::{RangeNullBehavior, ScanSources};
 use polars_utils::compression::{BrotliLevel, GzipLevel, ZstdLevel};
 use polars_utils::mmap::MemSlice;
 use polars_utils::pl_str::PlSmallStr;
@@ -1092,6 +1092,23 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<NullBehavior> {
     }
 }
 
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<RangeNullBehavior> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "raise" => RangeNullBehavior::Raise,
+            "propagate" => RangeNullBehavior::Propagate,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`null_behavior` must be one of {{'raise', 'propagate'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<NullStrategy> {
     type Error = PyErr;
 
@@ -1409,6 +1426,23 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<SetOperation> {
     }
 }
 
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<PadSide> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "start" => PadSide::Start,
+            "end" => PadSide::End,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "pad side must be one of {{'start', 'end'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 // Conversion from ScanCastOptions class from the Python side.
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<CastColumnsPolicy> {
     type Error = PyErr;
@@ -1,10 +1,13 @@
 use polars::prelude::*;
+use polars_core::chunked_array::cast::CastOptions;
+use polars_ops::chunked_array::array::SortedRowsOptions;
 use polars_utils::python_function::PythonObject;
 use pyo3::prelude::*;
 use pyo3::pymethods;
 
 use crate::error::PyPolarsErr;
 use crate::expr::PyExpr;
+use crate::expr::datatype::PyDataTypeExpr;
 
 #[pymethods]
 impl PyExpr {
@@ -36,6 +39,38 @@ impl PyExpr {
         self.inner.clone().arr().mean().into()
     }
 
+    fn arr_leaf_sum(&self) -> Self {
+        self.inner.clone().arr().leaf_sum().into()
+    }
+
+    fn arr_leaf_mean(&self) -> Self {
+        self.inner.clone().arr().leaf_mean().into()
+    }
+
+    fn arr_leaf_min(&self) -> Self {
+        self.inner.clone().arr().leaf_min().into()
+    }
+
+    fn arr_leaf_max(&self) -> Self {
+        self.inner.clone().arr().leaf_max().into()
+    }
+
+    fn arr_cum_sum(&self, reverse: bool) -> Self {
+        self.inner.clone().arr().cum_sum(reverse).into()
+    }
+
+    fn arr_cum_prod(&self, reverse: bool) -> Self {
+        self.inner.clone().arr().cum_prod(reverse).into()
+    }
+
+    fn arr_cum_min(&self, reverse: bool) -> Self {
+        self.inner.clone().arr().cum_min(reverse).into()
+    }
+
+    fn arr_cum_max(&self, reverse: bool) -> Self {
+        self.inner.clone().arr().cum_max(reverse).into()
+    }
+
     fn arr_median(&self) -> Self {
         self.inner.clone().arr().median().into()
     }
@@ -96,26 +131,90 @@ impl PyExpr {
             .into()
     }
 
-    fn arr_join(&self, separator: PyExpr, ignore_nulls: bool) -> Self {
+    fn arr_scatter(&self, index: PyExpr, value: PyExpr, skip_oob: bool) -> Self {
         self.inner
             .clone()
             .arr()
-            .join(separator.inner, ignore_nulls)
+            .scatter(index.inner, value.inner, skip_oob)
             .into()
     }
 
-    #[cfg(feature = "is_in")]
-    fn arr_contains(&self, other: PyExpr, nulls_equal: bool) -> Self {
+    fn arr_set_at_idx_masked(&self, mask: PyExpr, value: PyExpr) -> Self {
         self.inner
             .clone()
             .arr()
-            .contains(other.inner, nulls_equal)
+            .set_at_idx_masked(mask.inner, value.inner)
             .into()
     }
 
+    fn arr_join(&self, separator: PyExpr, ignore_nulls: bool) -> Self {
+        self.inner
+            .clone()
+            .arr()
+            .join(separator.inner, ignore_nulls)
+            .into()
+    }
+
+    #[cfg(feature = "is_in")]
+    fn arr_contains(
+        &self,
+        other: PyExpr,
+        nulls_equal: bool,
+        assume_sorted_rows: bool,
+        descending: bool,
+        validate: bool,
+    ) -> Self {
+        let arr = self.inner.clone().arr();
+        if assume_sorted_rows {
+            let options = SortedRowsOptions {
+                descending,
+                validate,
+            };
+            arr.contains_sorted(other.inner, nulls_equal, options)
+                .into()
+        } else {
+            arr.contains(other.inner, nulls_equal).into()
+        }
+    }
+
     #[cfg(feature = "array_count")]
-    fn arr_count_matches(&self, expr: PyExpr) -> Self {
-        self.inner.clone().arr().count_matches(expr.inner).into()
+    fn arr_count_matches(
+        &self,
+        expr: PyExpr,
+        assume_sorted_rows: bool,
+        descending: bool,
+        validate: bool,
+    ) -> Self {
+        let arr = self.inner.clone().arr();
+        if assume_sorted_rows {
+            let options = SortedRowsOptions {
+                descending,
+                validate,
+            };
+            arr.count_matches_sorted(expr.inner, options).into()
+        } else {
+            arr.count_matches(expr.inner).into()
+        }
+    }
+
+    fn arr_rows_are_sorted(&self, descending: bool) -> Self {
+        self.inner.clone().arr().rows_are_sorted(descending).into()
+    }
+
+    fn arr_cast_inner(&self, dtype: PyDataTypeExpr, strict: bool, wrap_numerical: bool) -> Self {
+        let options = if wrap_numerical {
+            CastOptions::Overflowing
+        } else if strict {
+            CastOptions::Strict
+        } else {
+            CastOptions::NonStrict
+        };
+
+        self.inner
+            .clone()
+            .arr()
+            .cast_inner(dtype.inner, options)
+            .into()
     }
 
     #[pyo3(signature = (name_gen))]
@@ -183,6 +183,9 @@ impl PyExpr {
     fn implode(&self) -> Self {
         self.inner.clone().implode().into()
     }
+    fn implode_to_array(&self, width: usize) -> Self {
+        self.inner.clone().implode_to_array(width).into()
+    }
     fn quantile(&self, quantile: Self, interpolation: Wrap<QuantileMethod>) -> Self {
         self.inner
             .clone()
@@ -380,6 +383,7 @@ impl PyExpr {
                     nulls_last,
                     multithreaded,
                     maintain_order,
+                    deterministic: false,
                     limit: None,
                 },
             )
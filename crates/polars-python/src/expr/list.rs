@@ -202,6 +202,20 @@ impl PyExpr {
         self.inner.clone().list().to_array(width).into()
     }
 
+    fn list_pad_to_array(
+        &self,
+        width: usize,
+        side: Wrap<PadSide>,
+        fill: PyExpr,
+        truncate_side: Wrap<PadSide>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .pad_to_array(width, side.0, fill.inner, truncate_side.0)
+            .into()
+    }
+
     #[pyo3(signature = (names))]
     fn list_to_struct(&self, names: Bound<'_, PySequence>) -> PyResult<Self> {
         Ok(self
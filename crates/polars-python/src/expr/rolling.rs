@@ -193,7 +193,7 @@ impl PyExpr {
         Ok(self.inner.clone().rolling_std_by(by.inner, options).into())
     }
 
-    #[pyo3(signature = (window_size, weights, min_periods, center, ddof))]
+    #[pyo3(signature = (window_size, weights, min_periods, center, ddof, halflife))]
     fn rolling_var(
         &self,
         window_size: usize,
@@ -201,14 +201,19 @@ impl PyExpr {
         min_periods: Option<usize>,
         center: bool,
         ddof: u8,
+        halflife: Option<f64>,
     ) -> Self {
         let min_periods = min_periods.unwrap_or(window_size);
+        let fn_params = match halflife {
+            Some(halflife) => RollingFnParams::ExpWeightedVar { halflife, ddof },
+            None => RollingFnParams::Var(RollingVarParams { ddof }),
+        };
         let options = RollingOptionsFixedWindow {
             window_size,
             weights,
             min_periods,
             center,
-            fn_params: Some(RollingFnParams::Var(RollingVarParams { ddof })),
+            fn_params: Some(fn_params),
         };
 
         self.inner.clone().rolling_var(options).into()
@@ -322,7 +327,7 @@ impl PyExpr {
             .into())
     }
 
-    #[pyo3(signature = (window_size, method, seed, min_samples, center))]
+    #[pyo3(signature = (window_size, method, seed, min_samples, center, descending))]
     fn rolling_rank(
         &self,
         window_size: usize,
@@ -330,6 +335,7 @@ impl PyExpr {
         seed: Option<u64>,
         min_samples: Option<usize>,
         center: bool,
+        descending: bool,
     ) -> Self {
         let min_samples = min_samples.unwrap_or(window_size);
         let options = RollingOptionsFixedWindow {
@@ -340,13 +346,14 @@ impl PyExpr {
             fn_params: Some(RollingFnParams::Rank {
                 method: method.0,
                 seed,
+                descending,
             }),
         };
 
         self.inner.clone().rolling_rank(options).into()
     }
 
-    #[pyo3(signature = (by, window_size, method, seed, min_samples, closed))]
+    #[pyo3(signature = (by, window_size, method, seed, min_samples, closed, descending))]
     fn rolling_rank_by(
         &self,
         by: PyExpr,
@@ -355,6 +362,7 @@ impl PyExpr {
         seed: Option<u64>,
         min_samples: usize,
         closed: Wrap<ClosedWindow>,
+        descending: bool,
     ) -> PyResult<Self> {
         let options = RollingOptionsDynamicWindow {
             window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
@@ -363,6 +371,7 @@ impl PyExpr {
             fn_params: Some(RollingFnParams::Rank {
                 method: method.0,
                 seed,
+                descending,
             }),
         };
 
@@ -78,6 +78,7 @@ pub fn arg_sort_by(
             nulls_last,
             multithreaded,
             maintain_order,
+            deterministic: false,
             limit: None,
         },
     )
@@ -11,11 +11,17 @@ use crate::utils::EnterPolarsExt;
 use crate::{PyExpr, PySeries};
 
 #[pyfunction]
-pub fn int_range(start: PyExpr, end: PyExpr, step: i64, dtype: PyDataTypeExpr) -> PyExpr {
+pub fn int_range(
+    start: PyExpr,
+    end: PyExpr,
+    step: i64,
+    dtype: PyDataTypeExpr,
+    null_behavior: Wrap<dsl::RangeNullBehavior>,
+) -> PyExpr {
     let start = start.inner;
     let end = end.inner;
     let dtype = dtype.inner;
-    dsl::int_range(start, end, step, dtype).into()
+    dsl::int_range_with_null_behavior(start, end, step, dtype, null_behavior.0).into()
 }
 
 /// Eager version of `int_range` to avoid overhead from the expression engine.
@@ -61,6 +67,11 @@ pub fn int_ranges(
     Ok(dsl::int_ranges(start.inner, end.inner, step.inner, dtype).into())
 }
 
+#[pyfunction]
+pub fn int_range_array(start: PyExpr, len: usize, step: i64, dtype: PyDataTypeExpr) -> PyExpr {
+    dsl::int_range_array(start.inner, len, step, dtype.inner).into()
+}
+
 #[pyfunction]
 pub fn date_range(
     start: PyExpr,
@@ -518,6 +518,7 @@ impl PyLazyFrame {
         descending: bool,
         nulls_last: bool,
         maintain_order: bool,
+        deterministic: bool,
         multithreaded: bool,
     ) -> Self {
         let ldf = self.ldf.read().clone();
@@ -528,6 +529,7 @@ impl PyLazyFrame {
                 nulls_last: vec![nulls_last],
                 multithreaded,
                 maintain_order,
+                deterministic,
                 limit: None,
             },
         )
@@ -540,6 +542,7 @@ impl PyLazyFrame {
         descending: Vec<bool>,
         nulls_last: Vec<bool>,
         maintain_order: bool,
+        deterministic: bool,
         multithreaded: bool,
     ) -> Self {
         let ldf = self.ldf.read().clone();
@@ -550,6 +553,7 @@ impl PyLazyFrame {
                 descending,
                 nulls_last,
                 maintain_order,
+                deterministic,
                 multithreaded,
                 limit: None,
             },
@@ -1028,7 +1032,7 @@ impl PyLazyFrame {
             .into())
     }
 
-    #[pyo3(signature = (other, left_on, right_on, allow_parallel, force_parallel, nulls_equal, how, suffix, validate, maintain_order, coalesce=None))]
+    #[pyo3(signature = (other, left_on, right_on, allow_parallel, force_parallel, nulls_equal, how, suffix, validate, maintain_order, coalesce=None, bloom_filter=None))]
     fn join(
         &self,
         other: Self,
@@ -1042,6 +1046,7 @@ impl PyLazyFrame {
         validate: Wrap<JoinValidation>,
         maintain_order: Wrap<MaintainOrderJoin>,
         coalesce: Option<bool>,
+        bloom_filter: Option<bool>,
     ) -> PyResult<Self> {
         let coalesce = match coalesce {
             None => JoinCoalesce::JoinSpecific,
@@ -1066,6 +1071,7 @@ impl PyLazyFrame {
             .right_on(right_on)
             .allow_parallel(allow_parallel)
             .force_parallel(force_parallel)
+            .bloom_filter(bloom_filter)
             .join_nulls(nulls_equal)
             .how(how.0)
             .suffix(suffix)
@@ -1244,6 +1244,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::Reshape(_) => {
                     return Err(PyNotImplementedError::new_err("reshape"));
                 },
+                IRFunctionExpr::ImplodeToArray(width) => {
+                    ("implode_to_array", width).into_py_any(py)
+                },
                 #[cfg(feature = "repeat_by")]
                 IRFunctionExpr::RepeatBy => ("repeat_by",).into_py_any(py),
                 IRFunctionExpr::ArgUnique => ("arg_unique",).into_py_any(py),
@@ -0,0 +1,102 @@
+//! Indexing for the hot loops of the row encoding.
+//!
+//! The encoders and decoders rely on the row widths and offsets to index without bounds checks.
+//! With the `checked-kernels` feature the same code is bounds checked and validated instead, so a
+//! violated invariant panics rather than causing undefined behavior.
+
+use std::slice::SliceIndex;
+
+pub(crate) trait MaybeUnchecked<T> {
+    /// Like [`slice::get_unchecked`], but bounds checked with the `checked-kernels` feature.
+    ///
+    /// # Safety
+    /// `index` must be in bounds.
+    unsafe fn get_maybe_unchecked<I: SliceIndex<[T]>>(&self, index: I) -> &I::Output;
+
+    /// Like [`slice::get_unchecked_mut`], but bounds checked with the `checked-kernels` feature.
+    ///
+    /// # Safety
+    /// `index` must be in bounds.
+    unsafe fn get_maybe_unchecked_mut<I: SliceIndex<[T]>>(&mut self, index: I) -> &mut I::Output;
+
+    /// Like [`slice::split_at_unchecked`], but bounds checked with the `checked-kernels` feature.
+    ///
+    /// # Safety
+    /// `mid` must not be larger than the length of the slice.
+    unsafe fn split_at_maybe_unchecked(&self, mid: usize) -> (&[T], &[T]);
+}
+
+impl<T> MaybeUnchecked<T> for [T] {
+    #[inline(always)]
+    #[cfg_attr(feature = "checked-kernels", track_caller)]
+    unsafe fn get_maybe_unchecked<I: SliceIndex<[T]>>(&self, index: I) -> &I::Output {
+        #[cfg(feature = "checked-kernels")]
+        {
+            &self[index]
+        }
+        #[cfg(not(feature = "checked-kernels"))]
+        {
+            unsafe { self.get_unchecked(index) }
+        }
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "checked-kernels", track_caller)]
+    unsafe fn get_maybe_unchecked_mut<I: SliceIndex<[T]>>(&mut self, index: I) -> &mut I::Output {
+        #[cfg(feature = "checked-kernels")]
+        {
+            &mut self[index]
+        }
+        #[cfg(not(feature = "checked-kernels"))]
+        {
+            unsafe { self.get_unchecked_mut(index) }
+        }
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "checked-kernels", track_caller)]
+    unsafe fn split_at_maybe_unchecked(&self, mid: usize) -> (&[T], &[T]) {
+        #[cfg(feature = "checked-kernels")]
+        {
+            self.split_at(mid)
+        }
+        #[cfg(not(feature = "checked-kernels"))]
+        {
+            unsafe { self.split_at_unchecked(mid) }
+        }
+    }
+}
+
+/// Like [`std::str::from_utf8_unchecked`], but validated with the `checked-kernels` feature.
+///
+/// # Safety
+/// `bytes` must be valid UTF-8.
+#[inline(always)]
+#[cfg_attr(feature = "checked-kernels", track_caller)]
+pub(crate) unsafe fn str_from_utf8_maybe_unchecked(bytes: &[u8]) -> &str {
+    #[cfg(feature = "checked-kernels")]
+    {
+        std::str::from_utf8(bytes).expect("row encoded string is not valid UTF-8")
+    }
+    #[cfg(not(feature = "checked-kernels"))]
+    {
+        unsafe { std::str::from_utf8_unchecked(bytes) }
+    }
+}
+
+/// Like [`String::from_utf8_unchecked`], but validated with the `checked-kernels` feature.
+///
+/// # Safety
+/// `bytes` must be valid UTF-8.
+#[inline(always)]
+#[cfg_attr(feature = "checked-kernels", track_caller)]
+pub(crate) unsafe fn string_from_utf8_maybe_unchecked(bytes: Vec<u8>) -> String {
+    #[cfg(feature = "checked-kernels")]
+    {
+        String::from_utf8(bytes).expect("row encoded string is not valid UTF-8")
+    }
+    #[cfg(not(feature = "checked-kernels"))]
+    {
+        unsafe { String::from_utf8_unchecked(bytes) }
+    }
+}
@@ -1,17 +1,23 @@
 #![allow(unsafe_op_in_unsafe_fn)]
 use arrow::bitmap::{Bitmap, BitmapBuilder};
 use arrow::buffer::Buffer;
-use arrow::datatypes::ArrowDataType;
+use arrow::datatypes::{ArrowDataType, IdxArr};
 use arrow::offset::OffsetsBuffer;
 use arrow::types::NativeType;
 use polars_dtype::categorical::CatNative;
+use polars_utils::IdxSize;
 
-use self::encode::fixed_size;
-use self::row::{RowEncodingCategoricalContext, RowEncodingOptions};
+use self::checked::{
+    MaybeUnchecked, str_from_utf8_maybe_unchecked, string_from_utf8_maybe_unchecked,
+};
+use self::encode::{fixed_size, list_values_dict, struct_fields_opts_and_dicts};
+use self::row::{RowEncodingCategoricalContext, RowEncodingInlineDictionary, RowEncodingOptions};
 use self::variable::utf8::decode_str;
 use super::*;
+use crate::error::RowEncodeError;
 use crate::fixed::numeric::{FixedLengthEncoding, FromSlice};
-use crate::fixed::{boolean, decimal, numeric};
+use crate::fixed::{boolean, decimal, fixed_size_binary, numeric};
+use crate::validate::{check_dtypes, check_rows};
 use crate::variable::{binary, no_order, utf8};
 
 /// Decode `rows` into a arrow format
@@ -45,6 +51,22 @@ pub unsafe fn decode_rows(
     assert_eq!(opts.len(), dtypes.len());
     assert_eq!(dicts.len(), dtypes.len());
 
+    #[cfg(feature = "tracing")]
+    let span = tracing::debug_span!(
+        "decode_rows",
+        num_rows = rows.len(),
+        num_columns = dtypes.len(),
+        total_bytes = tracing::field::Empty,
+    )
+    .entered();
+    #[cfg(feature = "tracing")]
+    if !span.is_disabled() {
+        span.record(
+            "total_bytes",
+            rows.iter().map(|row| row.len()).sum::<usize>(),
+        );
+    }
+
     dtypes
         .iter()
         .zip(opts)
@@ -53,13 +75,94 @@ pub unsafe fn decode_rows(
         .collect()
 }
 
+/// Decode `arr` into arrow arrays, checking that the rows are valid encodings first.
+///
+/// Null rows are treated as empty rows.
+pub fn try_decode_rows_from_binary<'a>(
+    arr: &'a BinaryArray<i64>,
+    opts: &[RowEncodingOptions],
+    dicts: &[Option<RowEncodingContext>],
+    dtypes: &[ArrowDataType],
+    rows: &mut Vec<&'a [u8]>,
+) -> Result<Vec<ArrayRef>, RowEncodeError> {
+    rows.clear();
+    rows.extend(arr.iter().map(|row| row.unwrap_or_default()));
+    try_decode_rows(rows, opts, dicts, dtypes)
+}
+
+/// Decode `rows` into arrow arrays, checking that the rows are valid encodings first.
+///
+/// Like [`decode_rows`], every row is advanced past the decoded columns. Nothing is decoded and
+/// `rows` is left untouched if the dtypes, options and contexts don't fit together or if any row
+/// is not a valid encoding of the columns.
+pub fn try_decode_rows(
+    rows: &mut [&[u8]],
+    opts: &[RowEncodingOptions],
+    dicts: &[Option<RowEncodingContext>],
+    dtypes: &[ArrowDataType],
+) -> Result<Vec<ArrayRef>, RowEncodeError> {
+    check_dtypes(dtypes, opts, dicts)?;
+    check_rows(rows, opts, dicts, dtypes)?;
+    // SAFETY: We checked that every row starts with a valid encoding of every column.
+    Ok(unsafe { decode_rows(rows, opts, dicts, dtypes) })
+}
+
+/// Decode the primitive at the start of `row` without decoding into an array.
+///
+/// # Safety
+/// `row` must start with a valid encoding of a `T` with `opt`.
+pub unsafe fn decode_first_primitive<T: FixedLengthEncoding>(
+    row: &[u8],
+    opt: RowEncodingOptions,
+) -> Option<T> {
+    numeric::decode_value(row, opt)
+}
+
+/// Decode the boolean at the start of `row` without decoding into an array.
+///
+/// # Safety
+/// `row` must start with a valid encoding of a boolean with `opt`.
+pub unsafe fn decode_first_bool(row: &[u8], opt: RowEncodingOptions) -> Option<bool> {
+    boolean::decode_bool_value(row, opt)
+}
+
+/// Decode the decimal at the start of `row` without decoding into an array.
+///
+/// # Safety
+/// `row` must start with a valid encoding of a decimal with `precision` and `opt`.
+pub unsafe fn decode_first_decimal(
+    row: &[u8],
+    opt: RowEncodingOptions,
+    precision: usize,
+) -> Option<i128> {
+    decimal::decode_value(row, opt, precision)
+}
+
+/// Decode the string at the start of `row` without decoding into an array.
+///
+/// # Safety
+/// `row` must start with a valid encoding of a string with `opt`.
+pub unsafe fn decode_first_str(row: &[u8], opt: RowEncodingOptions) -> Option<String> {
+    if opt.contains(RowEncodingOptions::NO_ORDER) {
+        no_order::decode_variable_no_order_value(row, opt)
+            .map(|v| string_from_utf8_maybe_unchecked(v.to_vec()))
+    } else {
+        utf8::decode_str_value(row, opt)
+    }
+}
+
 unsafe fn decode_validity(rows: &mut [&[u8]], opt: RowEncodingOptions) -> Option<Bitmap> {
+    // Non-nullable fixed-size lists and structs have no validity byte.
+    if opt.contains(RowEncodingOptions::NON_NULLABLE) {
+        return None;
+    }
+
     // 2 loop system to avoid the overhead of allocating the bitmap if all the elements are valid.
 
     let null_sentinel = opt.null_sentinel();
     let first_null = (0..rows.len()).find(|&i| {
         let v;
-        (v, rows[i]) = rows[i].split_at_unchecked(1);
+        (v, rows[i]) = rows[i].split_at_maybe_unchecked(1);
         v[0] == null_sentinel
     });
 
@@ -72,7 +175,7 @@ unsafe fn decode_validity(rows: &mut [&[u8]], opt: RowEncodingOptions) -> Option
     bm.push(false);
     bm.extend_trusted_len_iter(rows[first_null + 1..].iter_mut().map(|row| {
         let v;
-        (v, *row) = row.split_at_unchecked(1);
+        (v, *row) = row.split_at_maybe_unchecked(1);
         v[0] != null_sentinel
     }));
     bm.into_opt_validity()
@@ -93,6 +196,16 @@ fn dtype_and_data_to_encoded_item_len(
 
     use ArrowDataType as D;
     match dtype {
+        // Categoricals that are not fixed size are encoded as their strings.
+        D::UInt8 | D::UInt16 | D::UInt32
+            if matches!(dict, Some(RowEncodingContext::Categorical(_))) =>
+        unsafe {
+            if opt.contains(RowEncodingOptions::NO_ORDER) {
+                no_order::len_from_buffer(data, opt)
+            } else {
+                utf8::len_from_buffer(data, opt)
+            }
+        },
         D::Binary | D::LargeBinary | D::BinaryView | D::Utf8 | D::LargeUtf8 | D::Utf8View
             if opt.contains(RowEncodingOptions::NO_ORDER) =>
         unsafe { no_order::len_from_buffer(data, opt) },
@@ -109,24 +222,28 @@ fn dtype_and_data_to_encoded_item_len(
 
             while data[0] == list_continuation_token {
                 data = &data[1..];
-                let len = dtype_and_data_to_encoded_item_len(list_field.dtype(), data, opt, dict);
+                let len = dtype_and_data_to_encoded_item_len(
+                    list_field.dtype(),
+                    data,
+                    opt.into_nested(),
+                    list_values_dict(dict),
+                );
                 data = &data[len..];
                 item_len += 1 + len;
             }
             1 + item_len
         },
 
-        D::FixedSizeBinary(_) => todo!(),
         D::FixedSizeList(fsl_field, width) => {
-            let mut data = &data[1..];
-            let mut item_len = 1; // validity byte
+            let mut item_len = opt.nested_validity_len();
+            let mut data = &data[item_len..];
 
             for _ in 0..*width {
                 let len = dtype_and_data_to_encoded_item_len(
                     fsl_field.dtype(),
                     data,
                     opt.into_nested(),
-                    dict,
+                    list_values_dict(dict),
                 );
                 data = &data[len..];
                 item_len += len;
@@ -134,16 +251,12 @@ fn dtype_and_data_to_encoded_item_len(
             item_len
         },
         D::Struct(struct_fields) => {
-            let mut data = &data[1..];
-            let mut item_len = 1; // validity byte
+            let mut item_len = opt.nested_validity_len();
+            let mut data = &data[item_len..];
 
-            for struct_field in struct_fields {
-                let len = dtype_and_data_to_encoded_item_len(
-                    struct_field.dtype(),
-                    data,
-                    opt.into_nested(),
-                    dict,
-                );
+            let fields = struct_fields_opts_and_dicts(opt, dict, dtype);
+            for (struct_field, (opt, dict)) in struct_fields.iter().zip(fields) {
+                let len = dtype_and_data_to_encoded_item_len(struct_field.dtype(), data, opt, dict);
                 data = &data[len..];
                 item_len += len;
             }
@@ -152,8 +265,6 @@ fn dtype_and_data_to_encoded_item_len(
 
         D::Union(_) => todo!(),
         D::Map(_, _) => todo!(),
-        D::Decimal32(_, _) => todo!(),
-        D::Decimal64(_, _) => todo!(),
         D::Decimal256(_, _) => todo!(),
         D::Extension(_) => todo!(),
         D::Unknown => todo!(),
@@ -203,13 +314,37 @@ unsafe fn decode_cat<T: NativeType + FixedLengthEncoding + CatNative>(
 where
     T::Encoded: FromSlice,
 {
-    if ctx.is_enum || !opt.is_ordered() {
+    if !ctx.encodes_strings(opt) {
         numeric::decode_primitive::<T>(rows, opt)
-    } else {
+    } else if opt.is_ordered() {
         variable::utf8::decode_str_as_cat::<T>(rows, opt, &ctx.mapping)
+    } else {
+        no_order::decode_variable_no_order(rows, opt)
+            .iter()
+            .map(|s| {
+                s.map(|s| {
+                    let s = unsafe { str_from_utf8_maybe_unchecked(s) };
+                    T::from_cat(ctx.mapping.insert_cat(s).unwrap())
+                })
+            })
+            .collect()
     }
 }
 
+/// Decode strings that were encoded as their ordinal in `dictionary`.
+unsafe fn decode_inline_dictionary(
+    rows: &mut [&[u8]],
+    opt: RowEncodingOptions,
+    dictionary: &RowEncodingInlineDictionary,
+) -> ArrayRef {
+    let ordinals = numeric::decode_primitive::<u32>(rows, opt);
+    let indices = ordinals
+        .iter()
+        .map(|o| o.map(|&o| o as IdxSize))
+        .collect::<IdxArr>();
+    polars_compute::gather::take_unchecked(&dictionary.values, &indices)
+}
+
 unsafe fn decode(
     rows: &mut [&[u8]],
     opt: RowEncodingOptions,
@@ -218,6 +353,11 @@ unsafe fn decode(
 ) -> ArrayRef {
     use ArrowDataType as D;
 
+    if let Some(RowEncodingContext::InlineDictionary(dictionary)) = dict {
+        debug_assert_eq!(dtype, &D::Utf8View);
+        return decode_inline_dictionary(rows, opt, dictionary);
+    }
+
     if let Some(RowEncodingContext::Categorical(ctx)) = dict {
         match dtype {
             D::UInt8 => return decode_cat::<u8>(rows, opt, ctx).to_boxed(),
@@ -247,27 +387,20 @@ unsafe fn decode(
         D::Binary | D::LargeBinary | D::BinaryView => binary::decode_binview(rows, opt).to_boxed(),
         D::Utf8 | D::LargeUtf8 | D::Utf8View => decode_str(rows, opt).boxed(),
 
+        D::FixedSizeBinary(size) => fixed_size_binary::decode(rows, opt, dtype, *size).to_boxed(),
         D::Struct(fields) => {
             let validity = decode_validity(rows, opt);
 
-            let values = match dict {
-                None => fields
-                    .iter()
-                    .map(|struct_fld| decode(rows, opt.into_nested(), None, struct_fld.dtype()))
-                    .collect(),
-                Some(RowEncodingContext::Struct(dicts)) => fields
-                    .iter()
-                    .zip(dicts)
-                    .map(|(struct_fld, dict)| {
-                        decode(rows, opt.into_nested(), dict.as_ref(), struct_fld.dtype())
-                    })
-                    .collect(),
-                _ => unreachable!(),
-            };
+            let values = fields
+                .iter()
+                .zip(struct_fields_opts_and_dicts(opt, dict, dtype))
+                .map(|(struct_fld, (opt, dict))| decode(rows, opt, dict, struct_fld.dtype()))
+                .collect();
             StructArray::new(dtype.clone(), rows.len(), values, validity).to_boxed()
         },
         D::FixedSizeList(fsl_field, width) => {
             let validity = decode_validity(rows, opt);
+            let dict = list_values_dict(dict);
 
             // @TODO: we could consider making this into a scratchpad
             let mut nested_rows = Vec::new();
@@ -286,6 +419,7 @@ unsafe fn decode(
         },
         D::List(list_field) | D::LargeList(list_field) => {
             let mut validity = BitmapBuilder::new();
+            let dict = list_values_dict(dict);
 
             // @TODO: we could consider making this into a scratchpad
             let num_rows = rows.len();
@@ -350,6 +484,20 @@ unsafe fn decode(
             .to_boxed()
         },
 
+        D::Decimal(precision, _) => decimal::decode(rows, opt, *precision)
+            .to(dtype.clone())
+            .to_boxed(),
+        D::Decimal32(precision, _) => {
+            let array = decimal::decode(rows, opt, *precision);
+            let values = array.values().iter().map(|v| *v as i32).collect();
+            PrimitiveArray::<i32>::new(dtype.clone(), values, array.validity().cloned()).to_boxed()
+        },
+        D::Decimal64(precision, _) => {
+            let array = decimal::decode(rows, opt, *precision);
+            let values = array.values().iter().map(|v| *v as i64).collect();
+            PrimitiveArray::<i64>::new(dtype.clone(), values, array.validity().cloned()).to_boxed()
+        },
+
         dt => {
             if matches!(dt, D::Int128) {
                 if let Some(dict) = dict {
@@ -1,31 +1,50 @@
 #![allow(unsafe_op_in_unsafe_fn)]
 use std::mem::MaybeUninit;
+use std::ops::Range;
 
 use arrow::array::{
-    Array, BinaryArray, BinaryViewArray, BooleanArray, FixedSizeListArray, ListArray,
-    PrimitiveArray, StructArray, UInt8Array, UInt16Array, UInt32Array, Utf8Array, Utf8ViewArray,
+    Array, BinaryArray, BinaryViewArray, BooleanArray, DictionaryArray, DictionaryKey,
+    FixedSizeBinaryArray, FixedSizeListArray, ListArray, PrimitiveArray, StructArray, UInt8Array,
+    UInt16Array, UInt32Array, Utf8Array, Utf8ViewArray,
 };
 use arrow::bitmap::Bitmap;
-use arrow::datatypes::ArrowDataType;
+use arrow::datatypes::{ArrowDataType, IdxArr};
+use arrow::match_integer_type;
 use arrow::types::{NativeType, Offset};
+use polars_compute::cast::{binary_to_binview, utf8_to_utf8view};
+use polars_compute::gather::take_unchecked;
 use polars_dtype::categorical::CatNative;
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+use polars_utils::IdxSize;
+use polars_utils::aliases::{InitHashMaps, PlHashMap, PlHashSet};
 use polars_utils::float16::pf16;
 
+use crate::error::RowEncodeError;
 use crate::fixed::numeric::FixedLengthEncoding;
-use crate::fixed::{boolean, decimal, numeric};
-use crate::row::{RowEncodingOptions, RowsEncoded};
+use crate::fixed::{boolean, decimal, fixed_size_binary, numeric};
+use crate::row::{Collation, RowEncodingInlineDictionary, RowEncodingOptions, RowsEncoded};
+use crate::validate::{check_chunked_columns, check_columns, check_num_fields};
 use crate::variable::{binary, no_order, utf8};
 use crate::widths::RowWidths;
 use crate::{
     ArrayRef, RowEncodingCategoricalContext, RowEncodingContext, with_match_arrow_primitive_type,
 };
 
+/// Encode `columns` into `num_rows` rows, with the options and context of each column.
+///
+/// The columns are assumed to be encodable, only the number of options and contexts is checked.
+/// Use [`try_convert_columns`] for columns that aren't known to be valid.
+///
+/// # Panics
+///
+/// Panics if there aren't exactly one set of options and one context per column.
 pub fn convert_columns(
     num_rows: usize,
     columns: &[ArrayRef],
     opts: &[RowEncodingOptions],
     dicts: &[Option<RowEncodingContext>],
 ) -> RowsEncoded {
+    expect_num_fields(columns.len(), opts.len(), dicts.len());
     let mut rows = RowsEncoded::new(vec![], vec![]);
     convert_columns_amortized(
         num_rows,
@@ -36,6 +55,151 @@ pub fn convert_columns(
     rows
 }
 
+/// Encode `columns` into `num_rows` rows, with the options and context of each column.
+///
+/// Fails if there aren't exactly one set of options and one context per column, if a column
+/// doesn't have `num_rows` values, if a (nested) dtype has no row encoding or if a context
+/// doesn't fit its dtype.
+pub fn try_convert_columns(
+    num_rows: usize,
+    columns: &[ArrayRef],
+    opts: &[RowEncodingOptions],
+    dicts: &[Option<RowEncodingContext>],
+) -> Result<RowsEncoded, RowEncodeError> {
+    check_num_fields(columns.len(), opts.len(), dicts.len())?;
+    let mut rows = RowsEncoded::new(vec![], vec![]);
+    try_convert_columns_amortized(
+        num_rows,
+        columns,
+        opts.iter().copied().zip(dicts.iter().map(|v| v.as_ref())),
+        &mut rows,
+    )?;
+    Ok(rows)
+}
+
+/// Encode `columns`, of which every column is split into chunks, into `num_rows` rows.
+///
+/// The columns are assumed to be encodable like for [`convert_columns`], use
+/// [`try_convert_columns_chunked`] for columns that aren't known to be valid.
+///
+/// # Panics
+///
+/// Panics if there aren't exactly one set of options and one context per column.
+pub fn convert_columns_chunked(
+    num_rows: usize,
+    columns: &[Vec<ArrayRef>],
+    opts: &[RowEncodingOptions],
+    dicts: &[Option<RowEncodingContext>],
+) -> RowsEncoded {
+    expect_num_fields(columns.len(), opts.len(), dicts.len());
+    let fields = opts.iter().copied().zip(dicts.iter().map(|v| v.as_ref()));
+    let columns = columns.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    let mut values = Vec::new();
+    let mut offsets = Vec::with_capacity(num_rows + 1);
+    let mut encoders = EncoderArena::default();
+    encode_columns_into(
+        num_rows,
+        &columns,
+        fields,
+        false,
+        &mut encoders,
+        &mut values,
+        &mut offsets,
+    );
+    RowsEncoded {
+        values,
+        offsets,
+        encoders,
+    }
+}
+
+/// Encode `columns`, of which every column is split into chunks, into `num_rows` rows.
+///
+/// The chunks of a column are encoded one after the other without concatenating them, so the
+/// rows are the same as those of [`try_convert_columns`] for the concatenated columns. The
+/// columns can be split at different rows. All chunks of a column must have the same dtype.
+///
+/// Fails in the same cases as [`try_convert_columns`], or if the chunks of a column together
+/// don't have `num_rows` values.
+pub fn try_convert_columns_chunked(
+    num_rows: usize,
+    columns: &[Vec<ArrayRef>],
+    opts: &[RowEncodingOptions],
+    dicts: &[Option<RowEncodingContext>],
+) -> Result<RowsEncoded, RowEncodeError> {
+    check_num_fields(columns.len(), opts.len(), dicts.len())?;
+    let fields = opts.iter().copied().zip(dicts.iter().map(|v| v.as_ref()));
+    check_chunked_columns(num_rows, columns, fields)?;
+    Ok(convert_columns_chunked(num_rows, columns, opts, dicts))
+}
+
+/// Encode `columns` into `num_rows` rows, encoding string columns with
+/// [`RowEncodingOptions::DEDUP_VARIABLE`] as ordinals into a dictionary of their distinct values.
+///
+/// The columns are assumed to be encodable like for [`convert_columns`], use
+/// [`try_convert_columns_dedup`] for columns that aren't known to be valid.
+///
+/// # Panics
+///
+/// Panics if there aren't exactly one set of options and one context per column.
+pub fn convert_columns_dedup(
+    num_rows: usize,
+    columns: &[ArrayRef],
+    opts: &[RowEncodingOptions],
+    dicts: &[Option<RowEncodingContext>],
+) -> (RowsEncoded, Vec<Option<RowEncodingContext>>) {
+    expect_num_fields(columns.len(), opts.len(), dicts.len());
+    let fields = opts.iter().copied().zip(dicts.iter().map(|v| v.as_ref()));
+    let mut values = Vec::new();
+    let mut offsets = Vec::with_capacity(num_rows + 1);
+    let inline_dictionaries = encode_columns_into(
+        num_rows,
+        &unchunked(columns),
+        fields,
+        true,
+        &mut EncoderArena::default(),
+        &mut values,
+        &mut offsets,
+    );
+    let dicts = dicts
+        .iter()
+        .zip(inline_dictionaries)
+        .map(|(dict, inline_dictionary)| match inline_dictionary {
+            Some(dictionary) => Some(RowEncodingContext::InlineDictionary(dictionary)),
+            None => dict.clone(),
+        })
+        .collect();
+    (RowsEncoded::new(values, offsets), dicts)
+}
+
+/// Encode `columns` into `num_rows` rows, encoding string columns with
+/// [`RowEncodingOptions::DEDUP_VARIABLE`] as ordinals into a dictionary of their distinct values.
+///
+/// A column is only deduplicated if few of its values are distinct. Returns the contexts to
+/// decode the rows with, which hold the [`RowEncodingContext::InlineDictionary`] of every
+/// deduplicated column and the given context of every other column.
+///
+/// Fails in the same cases as [`try_convert_columns`].
+pub fn try_convert_columns_dedup(
+    num_rows: usize,
+    columns: &[ArrayRef],
+    opts: &[RowEncodingOptions],
+    dicts: &[Option<RowEncodingContext>],
+) -> Result<(RowsEncoded, Vec<Option<RowEncodingContext>>), RowEncodeError> {
+    check_num_fields(columns.len(), opts.len(), dicts.len())?;
+    let fields = opts.iter().copied().zip(dicts.iter().map(|v| v.as_ref()));
+    check_columns(num_rows, columns, fields)?;
+    Ok(convert_columns_dedup(num_rows, columns, opts, dicts))
+}
+
+/// Encode `columns` into `num_rows` rows that only preserve equality, not order.
+///
+/// The columns are assumed to be encodable like for [`convert_columns`], use
+/// [`try_convert_columns_no_order`] for columns that aren't known to be valid.
+///
+/// # Panics
+///
+/// Panics if there isn't exactly one context per column.
 pub fn convert_columns_no_order(
     num_rows: usize,
     columns: &[ArrayRef],
@@ -46,12 +210,31 @@ pub fn convert_columns_no_order(
     rows
 }
 
+/// Encode `columns` into `num_rows` rows that only preserve equality, not order.
+///
+/// Fails in the same cases as [`try_convert_columns`].
+pub fn try_convert_columns_no_order(
+    num_rows: usize,
+    columns: &[ArrayRef],
+    dicts: &[Option<RowEncodingContext>],
+) -> Result<RowsEncoded, RowEncodeError> {
+    let mut rows = RowsEncoded::new(vec![], vec![]);
+    try_convert_columns_amortized_no_order(num_rows, columns, dicts, &mut rows)?;
+    Ok(rows)
+}
+
+/// Like [`convert_columns_no_order`], but reuses the allocations of `rows`.
+///
+/// # Panics
+///
+/// Panics if there isn't exactly one context per column.
 pub fn convert_columns_amortized_no_order(
     num_rows: usize,
     columns: &[ArrayRef],
     dicts: &[Option<RowEncodingContext>],
     rows: &mut RowsEncoded,
 ) {
+    expect_num_fields(columns.len(), columns.len(), dicts.len());
     convert_columns_amortized(
         num_rows,
         columns,
@@ -61,66 +244,424 @@ pub fn convert_columns_amortized_no_order(
     );
 }
 
+/// Like [`try_convert_columns_no_order`], but reuses the allocations of `rows`.
+///
+/// `rows` is left untouched if the columns can't be encoded.
+pub fn try_convert_columns_amortized_no_order(
+    num_rows: usize,
+    columns: &[ArrayRef],
+    dicts: &[Option<RowEncodingContext>],
+    rows: &mut RowsEncoded,
+) -> Result<(), RowEncodeError> {
+    check_num_fields(columns.len(), columns.len(), dicts.len())?;
+    try_convert_columns_amortized(
+        num_rows,
+        columns,
+        std::iter::repeat_n(RowEncodingOptions::default(), columns.len())
+            .zip(dicts.iter().map(|v| v.as_ref())),
+        rows,
+    )
+}
+
+/// Like [`convert_columns`], but reuses the allocations of `rows`.
+///
+/// # Panics
+///
+/// Panics if there isn't exactly one set of options and one context per column.
 pub fn convert_columns_amortized<'a>(
     num_rows: usize,
     columns: &[ArrayRef],
     fields: impl IntoIterator<Item = (RowEncodingOptions, Option<&'a RowEncodingContext>)> + Clone,
     rows: &mut RowsEncoded,
 ) {
-    let mut masked_out_max_length = 0;
-    let mut row_widths = RowWidths::new(num_rows);
-    let mut encoders = columns
+    let num_fields = fields.clone().into_iter().count();
+    expect_num_fields(columns.len(), num_fields, num_fields);
+
+    #[cfg(feature = "tracing")]
+    let span = tracing::debug_span!(
+        "convert_columns_amortized",
+        num_rows,
+        num_columns = columns.len(),
+        total_bytes = tracing::field::Empty,
+    )
+    .entered();
+
+    let mut values = Vec::new();
+    let mut offsets = Vec::with_capacity(num_rows + 1);
+    let mut encoders = std::mem::take(&mut rows.encoders);
+    encode_columns_into(
+        num_rows,
+        &unchunked(columns),
+        fields,
+        false,
+        &mut encoders,
+        &mut values,
+        &mut offsets,
+    );
+    #[cfg(feature = "tracing")]
+    span.record("total_bytes", values.len());
+
+    *rows = RowsEncoded {
+        values,
+        offsets,
+        encoders,
+    };
+}
+
+/// Like [`try_convert_columns`], but reuses the allocations of `rows`.
+///
+/// `rows` is left untouched if the columns can't be encoded.
+pub fn try_convert_columns_amortized<'a>(
+    num_rows: usize,
+    columns: &[ArrayRef],
+    fields: impl IntoIterator<Item = (RowEncodingOptions, Option<&'a RowEncodingContext>)> + Clone,
+    rows: &mut RowsEncoded,
+) -> Result<(), RowEncodeError> {
+    check_columns(num_rows, columns, fields.clone())?;
+    convert_columns_amortized(num_rows, columns, fields, rows);
+    Ok(())
+}
+
+impl RowsEncoded {
+    /// Encode `columns` and append their rows after the rows that are already encoded.
+    ///
+    /// The columns are assumed to be encodable like for [`convert_columns`], use
+    /// [`RowsEncoded::try_append_columns`] for columns that aren't known to be valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there isn't exactly one set of options and one context per column.
+    pub fn append_columns<'a>(
+        &mut self,
+        num_rows: usize,
+        columns: &[ArrayRef],
+        fields: impl IntoIterator<Item = (RowEncodingOptions, Option<&'a RowEncodingContext>)> + Clone,
+    ) {
+        let num_fields = fields.clone().into_iter().count();
+        expect_num_fields(columns.len(), num_fields, num_fields);
+        encode_columns_into(
+            num_rows,
+            &unchunked(columns),
+            fields,
+            false,
+            &mut self.encoders,
+            &mut self.values,
+            &mut self.offsets,
+        );
+    }
+
+    /// Encode `columns` and append their rows after the rows that are already encoded, extending
+    /// the existing buffers instead of allocating new ones.
+    ///
+    /// The columns of every appended chunk must have the same dtypes and `fields`, otherwise rows
+    /// of different chunks can't be compared or decoded together.
+    ///
+    /// `self` is left untouched if the columns can't be encoded.
+    pub fn try_append_columns<'a>(
+        &mut self,
+        num_rows: usize,
+        columns: &[ArrayRef],
+        fields: impl IntoIterator<Item = (RowEncodingOptions, Option<&'a RowEncodingContext>)> + Clone,
+    ) -> Result<(), RowEncodeError> {
+        check_columns(num_rows, columns, fields.clone())?;
+        self.append_columns(num_rows, columns, fields);
+        Ok(())
+    }
+}
+
+/// Panics unless there are exactly one set of options and one context per column. This is the
+/// only check of the infallible entry points, as validating the columns themselves walks their
+/// dtypes.
+fn expect_num_fields(num_columns: usize, num_opts: usize, num_dicts: usize) {
+    if let Err(err) = check_num_fields(num_columns, num_opts, num_dicts) {
+        panic!("{err}");
+    }
+}
+
+/// View every column as a single chunk.
+fn unchunked(columns: &[ArrayRef]) -> Vec<&[ArrayRef]> {
+    columns.iter().map(std::slice::from_ref).collect()
+}
+
+/// Encode the rows of `columns` after the end of `values` and push their end offsets to
+/// `offsets`. The leading `0` offset is only pushed if `offsets` is still empty.
+///
+/// Every column is given as its chunks, which are encoded into consecutive rows.
+///
+/// If `dedup` is set, unchunked columns with [`RowEncodingOptions::DEDUP_VARIABLE`] may be encoded
+/// as ordinals and the dictionary of every column is returned. Otherwise nothing is returned.
+///
+/// The columns must have been checked with [`check_columns`] or [`check_chunked_columns`].
+fn encode_columns_into<'a>(
+    num_rows: usize,
+    columns: &[&[ArrayRef]],
+    fields: impl IntoIterator<Item = (RowEncodingOptions, Option<&'a RowEncodingContext>)> + Clone,
+    dedup: bool,
+    encoders: &mut EncoderArena,
+    values: &mut Vec<u8>,
+    offsets: &mut Vec<usize>,
+) -> Vec<Option<RowEncodingInlineDictionary>> {
+    // Dictionary arrays can arrive here straight from Arrow interop without having been
+    // converted to a Categorical. Those are encoded as their values instead.
+    let materialized;
+    let materialized_columns;
+    let columns = if columns
         .iter()
-        .zip(fields.clone())
-        .map(|(column, (opt, dicts))| {
-            get_encoder(
+        .flat_map(|chunks| chunks.iter())
+        .any(|c| has_dictionary(c.dtype()))
+    {
+        materialized = columns
+            .iter()
+            .map(|chunks| {
+                chunks
+                    .iter()
+                    .map(|c| materialize_dictionaries(c.as_ref()))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        materialized_columns = materialized.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        materialized_columns.as_slice()
+    } else {
+        columns
+    };
+
+    let mut masked_out_max_length = 0;
+    encoders.clear();
+    let (row_widths_idx, mut row_widths) = encoders.take_row_widths(num_rows);
+    let mut inline_dictionaries = Vec::new();
+    // The encoders of all chunks of all columns, in order.
+    let mut chunk_encoders = Vec::with_capacity(columns.len());
+    for (chunks, (opt, dicts)) in columns.iter().zip(fields.clone()) {
+        if let [column] = chunks {
+            if dedup
+                && opt.contains(RowEncodingOptions::DEDUP_VARIABLE)
+                && dicts.is_none()
+                && let Some((encoder, dictionary)) =
+                    get_inline_dictionary_encoder(encoders, column.as_ref(), opt, &mut row_widths)
+            {
+                inline_dictionaries.push(Some(dictionary));
+                chunk_encoders.push(encoder);
+                continue;
+            }
+            if dedup {
+                inline_dictionaries.push(None);
+            }
+            chunk_encoders.push(get_encoder(
+                encoders,
                 column.as_ref(),
                 opt,
                 dicts,
                 &mut row_widths,
                 &mut masked_out_max_length,
-            )
-        })
-        .collect::<Vec<_>>();
+            ));
+            continue;
+        }
 
-    // Create an offsets array, we append 0 at the beginning here so it can serve as the final
-    // offset array.
-    let mut offsets = Vec::with_capacity(num_rows + 1);
-    offsets.push(0);
-    row_widths.extend_with_offsets(&mut offsets);
+        if dedup {
+            inline_dictionaries.push(None);
+        }
+        // The widths of the chunks are computed separately and then appended, so that they
+        // line up with the rows of the chunks.
+        let mut column_widths = RowWidths::default();
+        for chunk in chunks.iter() {
+            let mut chunk_widths = RowWidths::new(chunk.len());
+            chunk_encoders.push(get_encoder(
+                encoders,
+                chunk.as_ref(),
+                opt,
+                dicts,
+                &mut chunk_widths,
+                &mut masked_out_max_length,
+            ));
+            column_widths.append(&chunk_widths);
+        }
+        row_widths.push(&column_widths);
+    }
 
-    // Create a buffer without initializing everything to zero.
+    // The offsets start with a 0, so they can serve as the final offsets array. Rows of this chunk
+    // get the offsets of their start within the chunk, the encoders advance them to their end.
+    if offsets.is_empty() {
+        offsets.push(0);
+    }
+    let chunk_start = offsets.len();
+    offsets.reserve(num_rows);
+    row_widths.extend_with_offsets(offsets);
+
+    // Grow the buffer without initializing everything to zero.
     let total_num_bytes = row_widths.sum();
-    let mut out = Vec::<u8>::with_capacity(total_num_bytes + masked_out_max_length);
-    let buffer = &mut out.spare_capacity_mut()[..total_num_bytes + masked_out_max_length];
+    let values_start = values.len();
+    values.reserve(total_num_bytes + masked_out_max_length);
+    let buffer = &mut values.spare_capacity_mut()[..total_num_bytes + masked_out_max_length];
+    // An encoder that doesn't write all bytes of its rows would leave them uninitialized.
+    #[cfg(feature = "checked-kernels")]
+    buffer.fill(MaybeUninit::new(0));
 
     let masked_out_write_offset = total_num_bytes;
-    let mut scratches = EncodeScratches::default();
-    for (encoder, (opt, dict)) in encoders.iter_mut().zip(fields) {
-        unsafe {
-            encode_array(
-                buffer,
-                encoder,
-                opt,
-                dict,
-                &mut offsets[1..],
-                masked_out_write_offset,
-                &mut scratches,
-            )
-        };
+    let mut scratches = std::mem::take(&mut encoders.scratches);
+    let mut chunk_encoders = chunk_encoders.into_iter();
+    for (chunks, (opt, dict)) in columns.iter().zip(fields) {
+        // Every chunk of the column encodes into the offsets of its own rows.
+        let mut column_offsets = &mut offsets[chunk_start..];
+        for encoder in chunk_encoders.by_ref().take(chunks.len()) {
+            let (chunk_offsets, rest) =
+                std::mem::take(&mut column_offsets).split_at_mut(encoders.get(encoder).array.len());
+            unsafe {
+                encode_array(
+                    buffer,
+                    encoders,
+                    encoder,
+                    None,
+                    opt,
+                    dict,
+                    chunk_offsets,
+                    masked_out_write_offset,
+                    &mut scratches,
+                )
+            };
+            column_offsets = rest;
+        }
     }
-    // SAFETY: All the bytes in out up to total_num_bytes should now be initialized.
+    #[cfg(feature = "checked-kernels")]
+    check_row_ends(&row_widths, &offsets[chunk_start..]);
+    // Release the arrays, but keep the allocations of the arena for the next call.
+    encoders.scratches = scratches;
+    encoders.put_row_widths(row_widths_idx, row_widths);
+    encoders.clear();
+    // SAFETY: All the bytes of this chunk up to total_num_bytes should now be initialized.
     unsafe {
-        out.set_len(total_num_bytes);
+        values.set_len(values_start + total_num_bytes);
     }
 
-    *rows = RowsEncoded {
-        values: out,
-        offsets,
+    if values_start > 0 {
+        for offset in &mut offsets[chunk_start..] {
+            *offset += values_start;
+        }
+    }
+    inline_dictionaries
+}
+
+/// Check that the encoders advanced the offset of every row to exactly the end of that row, so
+/// that no row overlaps the next one or leaves a gap.
+#[cfg(feature = "checked-kernels")]
+fn check_row_ends(row_widths: &RowWidths, ends: &[usize]) {
+    let mut expected_end = 0;
+    for (i, &end) in ends.iter().enumerate() {
+        expected_end += row_widths.get(i);
+        assert_eq!(
+            end, expected_end,
+            "row {i} was encoded into a different number of bytes than its width"
+        );
+    }
+}
+
+fn has_dictionary(dtype: &ArrowDataType) -> bool {
+    use ArrowDataType as D;
+    match dtype {
+        D::Dictionary(..) => true,
+        D::List(field) | D::LargeList(field) | D::FixedSizeList(field, _) => {
+            has_dictionary(field.dtype())
+        },
+        D::Struct(fields) => fields.iter().any(|f| has_dictionary(f.dtype())),
+        _ => false,
+    }
+}
+
+/// Replace all (nested) dictionary arrays in `array` by the values they refer to.
+///
+/// This gives the same order and equality as the dictionary encoded array, but materializes the
+/// values. The rows decode to the value type of the dictionary.
+fn materialize_dictionaries(array: &dyn Array) -> ArrayRef {
+    use ArrowDataType as D;
+
+    if !has_dictionary(array.dtype()) {
+        return array.to_boxed();
+    }
+
+    match array.dtype() {
+        D::Dictionary(key_type, _, _) => {
+            let (keys, values) = match_integer_type!(key_type, |$T| {
+                let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                let keys = array
+                    .keys()
+                    .iter()
+                    .map(|k| k.map(|k| unsafe { k.as_usize() } as IdxSize))
+                    .collect::<IdxArr>();
+                (keys, array.values())
+            });
+
+            // Not all value types can be gathered from.
+            let values = match values.dtype() {
+                D::Utf8 => {
+                    let values = values.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+                    utf8_to_utf8view(values).boxed()
+                },
+                D::LargeUtf8 => {
+                    let values = values.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+                    utf8_to_utf8view(values).boxed()
+                },
+                D::Binary => {
+                    let values = values.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+                    binary_to_binview(values).boxed()
+                },
+                _ => materialize_dictionaries(values.as_ref()),
+            };
+
+            // SAFETY: The keys of a DictionaryArray are in bounds of its values.
+            unsafe { take_unchecked(values.as_ref(), &keys) }
+        },
+        D::List(_) => materialize_list_dictionaries::<i32>(array),
+        D::LargeList(_) => materialize_list_dictionaries::<i64>(array),
+        D::FixedSizeList(field, width) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = materialize_dictionaries(array.values().as_ref());
+            let field = field.with_dtype(values.dtype().clone());
+            let dtype = D::FixedSizeList(Box::new(field), *width);
+            FixedSizeListArray::new(dtype, array.len(), values, array.validity().cloned()).boxed()
+        },
+        D::Struct(fields) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let values = array
+                .values()
+                .iter()
+                .map(|v| materialize_dictionaries(v.as_ref()))
+                .collect::<Vec<_>>();
+            let fields = fields
+                .iter()
+                .zip(&values)
+                .map(|(f, v)| f.with_dtype(v.dtype().clone()))
+                .collect();
+            StructArray::new(
+                D::Struct(fields),
+                array.len(),
+                values,
+                array.validity().cloned(),
+            )
+            .boxed()
+        },
+        _ => unreachable!(),
+    }
+}
+
+fn materialize_list_dictionaries<O: Offset>(array: &dyn Array) -> ArrayRef {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let values = materialize_dictionaries(array.values().as_ref());
+    let field = ListArray::<O>::get_child_field(array.dtype()).with_dtype(values.dtype().clone());
+    let dtype = if O::IS_LARGE {
+        ArrowDataType::LargeList(Box::new(field))
+    } else {
+        ArrowDataType::List(Box::new(field))
     };
+    ListArray::<O>::new(
+        dtype,
+        array.offsets().clone(),
+        values,
+        array.validity().cloned(),
+    )
+    .boxed()
 }
 
 fn list_num_column_bytes<O: Offset>(
+    encoders: &mut EncoderArena,
     array: &dyn Array,
     opt: RowEncodingOptions,
     dicts: Option<&RowEncodingContext>,
@@ -130,15 +671,38 @@ fn list_num_column_bytes<O: Offset>(
     let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
     let values = array.values();
 
-    let mut list_row_widths = RowWidths::new(values.len());
+    let (list_widths_idx, mut list_row_widths) = encoders.take_row_widths(values.len());
     let encoder = get_encoder(
+        encoders,
         values.as_ref(),
         opt.into_nested(),
-        dicts,
+        list_values_dict(dicts),
         &mut list_row_widths,
         masked_out_max_width,
     );
 
+    // `Null` values take up no bytes, so only the tokens of the lists are encoded.
+    if values.dtype() == &ArrowDataType::Null {
+        row_widths.push_iter(
+            array
+                .offsets()
+                .lengths()
+                .enumerate()
+                .map(|(i, length)| if array.is_valid(i) { 1 + length } else { 1 }),
+        );
+        encoders.put_row_widths(list_widths_idx, list_row_widths);
+        return Encoder {
+            array: array.to_boxed(),
+            state: Some(EncoderState::List(
+                encoder,
+                list_widths_idx,
+                OffsetWidth::of::<O>(),
+            )),
+        };
+    }
+
+    let skip_masked = encoders.is_sliceable(encoder);
+
     match array.validity() {
         None => row_widths.push_iter(array.offsets().offset_and_length_iter().map(
             |(offset, length)| {
@@ -156,7 +720,9 @@ fn list_num_column_bytes<O: Offset>(
                 .zip(validity.iter())
                 .map(|((offset, length), is_valid)| {
                     if !is_valid {
-                        if length > 0 {
+                        // Masked out values of a sliceable encoder are skipped when encoding, so
+                        // they don't need to be written anywhere.
+                        if length > 0 && !skip_masked {
                             for i in offset..offset + length {
                                 *masked_out_max_width =
                                     (*masked_out_max_width).max(list_row_widths.get(i));
@@ -174,12 +740,14 @@ fn list_num_column_bytes<O: Offset>(
         ),
     };
 
+    encoders.put_row_widths(list_widths_idx, list_row_widths);
     Encoder {
         array: array.to_boxed(),
-        state: Some(Box::new(EncoderState::List(
-            Box::new(encoder),
-            list_row_widths,
-        ))),
+        state: Some(EncoderState::List(
+            encoder,
+            list_widths_idx,
+            OffsetWidth::of::<O>(),
+        )),
     }
 }
 
@@ -248,8 +816,92 @@ fn striter_num_column_bytes(
     }
 }
 
-/// Get the encoder for a specific array.
+/// The number of fields from which the encoders of a struct are computed in parallel.
+#[cfg(feature = "parallel")]
+const PARALLEL_STRUCT_MIN_FIELDS: usize = 64;
+
+/// Get the encoders for the fields of a struct on the current rayon pool.
+///
+/// This is only used when the caller already runs on a pool with several threads, e.g. the Polars
+/// thread pool sized by `POLARS_MAX_THREADS`, so the encoding never spawns threads of its own.
+///
+/// The fields are split into one consecutive group per thread and every group computes its own
+/// widths. The groups are merged in field order, so the byte layout doesn't depend on the thread
+/// scheduling.
+///
+/// Every group builds its encoders in an arena of its own, which are then moved into `encoders`.
+#[cfg(feature = "parallel")]
+fn get_struct_field_encoders_par<'a>(
+    encoders: &mut EncoderArena,
+    array: &StructArray,
+    fields: impl Iterator<Item = (RowEncodingOptions, Option<&'a RowEncodingContext>)>,
+    row_widths: &mut RowWidths,
+    masked_out_max_width: &mut usize,
+) -> Range<usize> {
+    use rayon::prelude::*;
+
+    let num_rows = row_widths.num_rows();
+    let fields = array.values().iter().zip(fields).collect::<Vec<_>>();
+    let chunk_size = fields.len().div_ceil(rayon::current_num_threads());
+
+    let groups = fields
+        .par_chunks(chunk_size)
+        .map(|fields| {
+            let mut group_encoders = EncoderArena::default();
+            let mut group_row_widths = RowWidths::new(num_rows);
+            let mut group_masked_out_max_width = 0;
+            let field_encoders = fields
+                .iter()
+                .map(|&(array, (opt, dict))| {
+                    get_encoder(
+                        &mut group_encoders,
+                        array.as_ref(),
+                        opt,
+                        dict,
+                        &mut group_row_widths,
+                        &mut group_masked_out_max_width,
+                    )
+                })
+                .collect::<Vec<_>>();
+            (
+                group_encoders,
+                field_encoders,
+                group_row_widths,
+                group_masked_out_max_width,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let struct_fields = encoders.reserve_struct_fields(fields.len());
+    let mut i = struct_fields.start;
+    for (group_encoders, field_encoders, group_row_widths, group_masked_out_max_width) in groups {
+        row_widths.push(&group_row_widths);
+        *masked_out_max_width = (*masked_out_max_width).max(group_masked_out_max_width);
+        let base = encoders.append(group_encoders);
+        for field_encoder in field_encoders {
+            encoders.struct_fields[i] = base + field_encoder;
+            i += 1;
+        }
+    }
+    struct_fields
+}
+
+/// Get the encoder for a specific array, which is added to `encoders` after the encoders of its
+/// nested arrays.
 fn get_encoder(
+    encoders: &mut EncoderArena,
+    array: &dyn Array,
+    opt: RowEncodingOptions,
+    dict: Option<&RowEncodingContext>,
+    row_widths: &mut RowWidths,
+    masked_out_max_width: &mut usize,
+) -> EncoderIdx {
+    let encoder = new_encoder(encoders, array, opt, dict, row_widths, masked_out_max_width);
+    encoders.push(encoder)
+}
+
+fn new_encoder(
+    encoders: &mut EncoderArena,
     array: &dyn Array,
     opt: RowEncodingOptions,
     dict: Option<&RowEncodingContext>,
@@ -267,58 +919,49 @@ fn get_encoder(
                 let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
 
                 debug_assert_eq!(array.values().len(), array.len() * width);
-                let mut nested_row_widths = RowWidths::new(array.values().len());
+                let (nested_widths_idx, mut nested_row_widths) =
+                    encoders.take_row_widths(array.values().len());
                 let nested_encoder = get_encoder(
+                    encoders,
                     array.values().as_ref(),
                     opt.into_nested(),
-                    dict,
+                    list_values_dict(dict),
                     &mut nested_row_widths,
                     masked_out_max_width,
                 );
+                encoders.put_row_widths(nested_widths_idx, nested_row_widths);
                 Some(EncoderState::FixedSizeList(
-                    Box::new(nested_encoder),
+                    nested_encoder,
                     *width,
-                    nested_row_widths,
+                    nested_widths_idx,
                 ))
             },
             D::Struct(_) => {
                 let struct_array = array.as_any().downcast_ref::<StructArray>().unwrap();
-
-                Some(EncoderState::Struct(match dict {
-                    None => struct_array
-                        .values()
-                        .iter()
-                        .map(|array| {
-                            get_encoder(
-                                array.as_ref(),
-                                opt.into_nested(),
-                                None,
-                                &mut RowWidths::new(row_widths.num_rows()),
-                                masked_out_max_width,
-                            )
-                        })
-                        .collect(),
-                    Some(RowEncodingContext::Struct(dicts)) => struct_array
-                        .values()
-                        .iter()
-                        .zip(dicts)
-                        .map(|(array, dict)| {
-                            get_encoder(
-                                array.as_ref(),
-                                opt,
-                                dict.as_ref(),
-                                &mut RowWidths::new(row_widths.num_rows()),
-                                masked_out_max_width,
-                            )
-                        })
-                        .collect(),
-                    _ => unreachable!(),
-                }))
+                let fields = struct_fields_opts_and_dicts(opt, dict, struct_array.dtype());
+
+                let struct_fields = encoders.reserve_struct_fields(struct_array.values().len());
+                for ((array, (opt, dict)), i) in struct_array
+                    .values()
+                    .iter()
+                    .zip(fields)
+                    .zip(struct_fields.clone())
+                {
+                    let field = get_encoder(
+                        encoders,
+                        array.as_ref(),
+                        opt,
+                        dict,
+                        &mut RowWidths::new(row_widths.num_rows()),
+                        masked_out_max_width,
+                    );
+                    encoders.struct_fields[i] = field;
+                }
+                Some(EncoderState::Struct(struct_fields))
             },
             _ => None,
         };
 
-        let state = state.map(Box::new);
         return Encoder {
             array: array.to_boxed(),
             state,
@@ -329,7 +972,7 @@ fn get_encoder(
     if let Some(RowEncodingContext::Categorical(ctx)) = dict {
         match dtype {
             D::UInt8 => {
-                assert!(opt.is_ordered() && !ctx.is_enum);
+                assert!(ctx.encodes_strings(opt));
                 let dc_array = array.as_any().downcast_ref::<UInt8Array>().unwrap();
                 return striter_num_column_bytes(
                     array,
@@ -345,7 +988,7 @@ fn get_encoder(
                 );
             },
             D::UInt16 => {
-                assert!(opt.is_ordered() && !ctx.is_enum);
+                assert!(ctx.encodes_strings(opt));
                 let dc_array = array.as_any().downcast_ref::<UInt16Array>().unwrap();
                 return striter_num_column_bytes(
                     array,
@@ -361,7 +1004,7 @@ fn get_encoder(
                 );
             },
             D::UInt32 => {
-                assert!(opt.is_ordered() && !ctx.is_enum);
+                assert!(ctx.encodes_strings(opt));
                 let dc_array = array.as_any().downcast_ref::<UInt32Array>().unwrap();
                 return striter_num_column_bytes(
                     array,
@@ -384,76 +1027,120 @@ fn get_encoder(
     }
 
     match dtype {
+        // Zero-width arrays only encode their validity byte (if any), see `encode_array`.
+        D::FixedSizeList(_, 0) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+
+            debug_assert!(array.values().is_empty());
+            let (nested_widths_idx, mut nested_row_widths) = encoders.take_row_widths(0);
+            let nested_encoder = get_encoder(
+                encoders,
+                array.values().as_ref(),
+                opt.into_nested(),
+                list_values_dict(dict),
+                &mut nested_row_widths,
+                masked_out_max_width,
+            );
+            encoders.put_row_widths(nested_widths_idx, nested_row_widths);
+
+            row_widths.push_constant(opt.nested_validity_len());
+            Encoder {
+                array: array.to_boxed(),
+                state: Some(EncoderState::FixedSizeList(
+                    nested_encoder,
+                    0,
+                    nested_widths_idx,
+                )),
+            }
+        },
         D::FixedSizeList(_, width) => {
             let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
 
             debug_assert_eq!(array.values().len(), array.len() * width);
-            let mut nested_row_widths = RowWidths::new(array.values().len());
+            let (nested_widths_idx, mut nested_row_widths) =
+                encoders.take_row_widths(array.values().len());
             let nested_encoder = get_encoder(
+                encoders,
                 array.values().as_ref(),
                 opt.into_nested(),
-                dict,
+                list_values_dict(dict),
                 &mut nested_row_widths,
                 masked_out_max_width,
             );
 
-            let mut fsl_row_widths = nested_row_widths.collapse_chunks(*width, array.len());
-            fsl_row_widths.push_constant(1); // validity byte
+            row_widths.push_collapsed(&nested_row_widths, *width);
+            row_widths.push_constant(opt.nested_validity_len());
+            encoders.put_row_widths(nested_widths_idx, nested_row_widths);
 
-            row_widths.push(&fsl_row_widths);
             Encoder {
                 array: array.to_boxed(),
-                state: Some(Box::new(EncoderState::FixedSizeList(
-                    Box::new(nested_encoder),
+                state: Some(EncoderState::FixedSizeList(
+                    nested_encoder,
                     *width,
-                    nested_row_widths,
-                ))),
+                    nested_widths_idx,
+                )),
             }
         },
         D::Struct(_) => {
             let array = array.as_any().downcast_ref::<StructArray>().unwrap();
 
-            let mut nested_encoders = Vec::with_capacity(array.values().len());
-            row_widths.push_constant(1); // validity byte
-            match dict {
-                None => {
-                    for array in array.values() {
-                        let encoder = get_encoder(
-                            array.as_ref(),
-                            opt.into_nested(),
-                            None,
-                            row_widths,
-                            masked_out_max_width,
-                        );
-                        nested_encoders.push(encoder);
-                    }
-                },
-                Some(RowEncodingContext::Struct(dicts)) => {
-                    for (array, dict) in array.values().iter().zip(dicts) {
-                        let encoder = get_encoder(
-                            array.as_ref(),
-                            opt.into_nested(),
-                            dict.as_ref(),
-                            row_widths,
-                            masked_out_max_width,
-                        );
-                        nested_encoders.push(encoder);
-                    }
-                },
-                _ => unreachable!(),
+            row_widths.push_constant(opt.nested_validity_len());
+            let fields = struct_fields_opts_and_dicts(opt, dict, array.dtype());
+
+            #[cfg(feature = "parallel")]
+            if array.values().len() >= PARALLEL_STRUCT_MIN_FIELDS
+                && rayon::current_thread_index().is_some()
+                && rayon::current_num_threads() > 1
+            {
+                let struct_fields = get_struct_field_encoders_par(
+                    encoders,
+                    array,
+                    fields,
+                    row_widths,
+                    masked_out_max_width,
+                );
+                return Encoder {
+                    array: array.to_boxed(),
+                    state: Some(EncoderState::Struct(struct_fields)),
+                };
+            }
+
+            let struct_fields = encoders.reserve_struct_fields(array.values().len());
+            for ((array, (opt, dict)), i) in
+                array.values().iter().zip(fields).zip(struct_fields.clone())
+            {
+                let field = get_encoder(
+                    encoders,
+                    array.as_ref(),
+                    opt,
+                    dict,
+                    row_widths,
+                    masked_out_max_width,
+                );
+                encoders.struct_fields[i] = field;
             }
             Encoder {
                 array: array.to_boxed(),
-                state: Some(Box::new(EncoderState::Struct(nested_encoders))),
+                state: Some(EncoderState::Struct(struct_fields)),
             }
         },
 
-        D::List(_) => {
-            list_num_column_bytes::<i32>(array, opt, dict, row_widths, masked_out_max_width)
-        },
-        D::LargeList(_) => {
-            list_num_column_bytes::<i64>(array, opt, dict, row_widths, masked_out_max_width)
-        },
+        D::List(_) => list_num_column_bytes::<i32>(
+            encoders,
+            array,
+            opt,
+            dict,
+            row_widths,
+            masked_out_max_width,
+        ),
+        D::LargeList(_) => list_num_column_bytes::<i64>(
+            encoders,
+            array,
+            opt,
+            dict,
+            row_widths,
+            masked_out_max_width,
+        ),
 
         D::BinaryView => {
             let dc_array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
@@ -541,17 +1228,239 @@ fn get_encoder(
     }
 }
 
-struct Encoder {
-    array: Box<dyn Array>,
-
-    /// State contains nested encoders and extra information needed to encode.
-    state: Option<Box<EncoderState>>,
-}
-
+/// The number of values sampled to estimate the ratio of distinct strings of a column with
+/// [`RowEncodingOptions::DEDUP_VARIABLE`].
+const DEDUP_SAMPLE_SIZE: usize = 1024;
+/// Strings are only deduplicated if at most one in this many of them is distinct.
+const DEDUP_MIN_VALUES_PER_DISTINCT: usize = 16;
+
+/// Get an encoder for the strings of `array` that encodes them as their ordinals in a sorted
+/// dictionary of the distinct strings, see [`RowEncodingOptions::DEDUP_VARIABLE`].
+///
+/// Returns `None` if the column can't be deduplicated or has too many distinct strings for it to
+/// pay off.
+fn get_inline_dictionary_encoder(
+    encoders: &mut EncoderArena,
+    array: &dyn Array,
+    opt: RowEncodingOptions,
+    row_widths: &mut RowWidths,
+) -> Option<(EncoderIdx, RowEncodingInlineDictionary)> {
+    // The dictionary is sorted by the bytes of the strings.
+    if array.dtype() != &ArrowDataType::Utf8View || opt.collation() != Collation::Binary {
+        return None;
+    }
+    let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+    let num_valid = array.len() - array.null_count();
+    // Ordinals are only smaller than strings that are longer than them.
+    if num_valid == 0 || array.total_bytes_len() / num_valid < u32::ENCODED_LEN {
+        return None;
+    }
+
+    // Estimate the ratio of distinct strings from evenly spaced values before hashing all of them.
+    let mut sample = PlHashSet::new();
+    let mut sample_size = 0;
+    for i in (0..array.len()).step_by(array.len().div_ceil(DEDUP_SAMPLE_SIZE)) {
+        if array.is_valid(i) {
+            sample.insert(array.value(i));
+            sample_size += 1;
+        }
+    }
+    if sample.len() * DEDUP_MIN_VALUES_PER_DISTINCT > sample_size {
+        return None;
+    }
+
+    let mut ordinals = PlHashMap::with_capacity(sample.len());
+    for s in array.iter().flatten() {
+        ordinals.entry(s).or_insert(0u32);
+    }
+    if ordinals.len() * DEDUP_MIN_VALUES_PER_DISTINCT > num_valid {
+        return None;
+    }
+
+    // Assign the ordinals in sorted order, so that they compare like the strings.
+    let mut values = ordinals.keys().copied().collect::<Vec<_>>();
+    values.sort_unstable();
+    for (ordinal, s) in values.iter().enumerate() {
+        *ordinals.get_mut(s).unwrap() = ordinal as u32;
+    }
+
+    // Masked out strings might not be in the dictionary, they get an arbitrary ordinal.
+    let keys = array
+        .values_iter()
+        .map(|s| ordinals.get(s).copied().unwrap_or(0))
+        .collect::<Vec<_>>();
+    let keys = PrimitiveArray::new(
+        ArrowDataType::UInt32,
+        keys.into(),
+        array.validity().cloned(),
+    );
+
+    row_widths.push_constant(u32::ENCODED_LEN);
+    let encoder = encoders.push(Encoder {
+        array: keys.boxed(),
+        state: None,
+    });
+    let dictionary = RowEncodingInlineDictionary {
+        values: Utf8ViewArray::from_slice_values(values),
+    };
+    Some((encoder, dictionary))
+}
+
+/// The index of an [`Encoder`] in an [`EncoderArena`].
+type EncoderIdx = usize;
+
+/// The index of the widths of the values of a list in [`EncoderArena::row_widths`].
+type RowWidthsIdx = usize;
+
+/// The encoders of all (nested) arrays of the columns that are encoded together.
+///
+/// Nested encoders refer to the encoders of their nested arrays by index instead of owning them,
+/// so building the encoders of a deeply nested column doesn't allocate at every level. The arena
+/// of [`RowsEncoded`] is kept between calls, so that encoding chunk after chunk into the same rows
+/// reuses its allocations.
+#[derive(Default)]
+pub(crate) struct EncoderArena {
+    encoders: Vec<Encoder>,
+    /// The encoders of the fields of all [`EncoderState::Struct`]s.
+    struct_fields: Vec<EncoderIdx>,
+    /// The widths of the rows and of the values of all lists, in the order they were taken. The
+    /// widths past `num_row_widths` are left over from an earlier call.
+    row_widths: Vec<RowWidths>,
+    num_row_widths: usize,
+    scratches: EncodeScratches,
+}
+
+impl Clone for EncoderArena {
+    /// The arena only holds encoders during an encoding call, so there is nothing to clone.
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl EncoderArena {
+    fn push(&mut self, encoder: Encoder) -> EncoderIdx {
+        self.encoders.push(encoder);
+        self.encoders.len() - 1
+    }
+
+    fn get(&self, idx: EncoderIdx) -> &Encoder {
+        &self.encoders[idx]
+    }
+
+    /// Reserve the entries for the encoders of the `num_fields` fields of a struct in
+    /// `struct_fields`. They must be set before the struct is encoded.
+    fn reserve_struct_fields(&mut self, num_fields: usize) -> Range<usize> {
+        let start = self.struct_fields.len();
+        self.struct_fields.resize(start + num_fields, 0);
+        start..start + num_fields
+    }
+
+    /// Whether the encoder `idx` can encode a slice of its array without re-computing its state.
+    ///
+    /// Lists and fixed-size lists keep the row widths of their values, so they cannot be sliced.
+    fn is_sliceable(&self, idx: EncoderIdx) -> bool {
+        match &self.get(idx).state {
+            None => true,
+            Some(EncoderState::Struct(fields)) => self.struct_fields[fields.clone()]
+                .iter()
+                .all(|&field| self.is_sliceable(field)),
+            Some(EncoderState::List(..) | EncoderState::FixedSizeList(..)) => false,
+        }
+    }
+
+    /// Move the encoders of `other` to the end of this arena. Returns the offset that is added to
+    /// their indices.
+    #[cfg(feature = "parallel")]
+    fn append(&mut self, other: EncoderArena) -> EncoderIdx {
+        let base = self.encoders.len();
+        let fields_base = self.struct_fields.len();
+        let widths_base = self.num_row_widths;
+        self.struct_fields
+            .extend(other.struct_fields.iter().map(|&field| base + field));
+        for row_widths in other.row_widths.into_iter().take(other.num_row_widths) {
+            let (idx, _) = self.take_row_widths(0);
+            self.put_row_widths(idx, row_widths);
+        }
+        self.encoders
+            .extend(other.encoders.into_iter().map(|mut encoder| {
+                match &mut encoder.state {
+                    None => {},
+                    Some(
+                        EncoderState::List(nested, widths, _)
+                        | EncoderState::FixedSizeList(nested, _, widths),
+                    ) => {
+                        *nested += base;
+                        *widths += widths_base;
+                    },
+                    Some(EncoderState::Struct(fields)) => {
+                        *fields = fields.start + fields_base..fields.end + fields_base
+                    },
+                }
+                encoder
+            }));
+        base
+    }
+
+    /// Take widths of `num_rows` rows of width zero, which must be put back under the returned
+    /// index with [`EncoderArena::put_row_widths`].
+    ///
+    /// The encoders of a schema take their widths in the same order on every call, so each level
+    /// reuses the allocation of its widths of the previous call.
+    fn take_row_widths(&mut self, num_rows: usize) -> (RowWidthsIdx, RowWidths) {
+        let idx = self.num_row_widths;
+        self.num_row_widths += 1;
+        if idx == self.row_widths.len() {
+            self.row_widths.push(RowWidths::default());
+        }
+        let mut row_widths = std::mem::take(&mut self.row_widths[idx]);
+        row_widths.reset(num_rows);
+        (idx, row_widths)
+    }
+
+    fn put_row_widths(&mut self, idx: RowWidthsIdx, row_widths: RowWidths) {
+        self.row_widths[idx] = row_widths;
+    }
+
+    fn row_widths(&self, idx: RowWidthsIdx) -> &RowWidths {
+        &self.row_widths[idx]
+    }
+
+    /// Remove all encoders, keeping the allocations for the next call.
+    fn clear(&mut self) {
+        self.encoders.clear();
+        self.struct_fields.clear();
+        self.num_row_widths = 0;
+    }
+}
+
+struct Encoder {
+    array: Box<dyn Array>,
+
+    /// State contains nested encoders and extra information needed to encode.
+    state: Option<EncoderState>,
+}
+
 enum EncoderState {
-    List(Box<Encoder>, RowWidths),
-    FixedSizeList(Box<Encoder>, usize, RowWidths),
-    Struct(Vec<Encoder>),
+    /// The encoder of the values and their widths in [`EncoderArena::row_widths`].
+    List(EncoderIdx, RowWidthsIdx, OffsetWidth),
+    /// The encoder of the values, the width of the lists and the widths of the values in
+    /// [`EncoderArena::row_widths`].
+    FixedSizeList(EncoderIdx, usize, RowWidthsIdx),
+    /// The range of the encoders of the fields in [`EncoderArena::struct_fields`].
+    Struct(Range<usize>),
+}
+
+/// The offset type of the `ListArray` of an [`EncoderState::List`].
+#[derive(Clone, Copy)]
+enum OffsetWidth {
+    I32,
+    I64,
+}
+
+impl OffsetWidth {
+    fn of<O: Offset>() -> Self {
+        if O::IS_LARGE { Self::I64 } else { Self::I32 }
+    }
 }
 
 unsafe fn encode_strs<'a>(
@@ -592,16 +1501,18 @@ unsafe fn encode_cat_array<T: NativeType + FixedLengthEncoding + CatNative>(
     ctx: &RowEncodingCategoricalContext,
     offsets: &mut [usize],
 ) {
-    if ctx.is_enum || !opt.is_ordered() {
+    if !ctx.encodes_strings(opt) {
         numeric::encode(buffer, keys, opt, offsets);
+        return;
+    }
+
+    let strs = keys
+        .iter()
+        .map(|k| k.map(|&cat| ctx.mapping.cat_to_str_unchecked(cat.as_cat())));
+    if opt.is_ordered() {
+        utf8::encode_str(buffer, strs, opt.with_collation(Collation::Binary), offsets);
     } else {
-        utf8::encode_str(
-            buffer,
-            keys.iter()
-                .map(|k| k.map(|&cat| ctx.mapping.cat_to_str_unchecked(cat.as_cat()))),
-            opt,
-            offsets,
-        );
+        encode_strs(buffer, strs, opt, offsets);
     }
 }
 
@@ -646,6 +1557,30 @@ unsafe fn encode_flat_array(
             boolean::encode_bool(buffer, array.iter(), opt, offsets);
         },
 
+        D::Decimal(precision, _) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .unwrap();
+            decimal::encode(buffer, array, opt, offsets, *precision);
+        },
+        D::Decimal32(precision, _) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap();
+            let values = array.iter().map(|v| v.map(|v| *v as i128));
+            decimal::encode_iter(buffer, values, opt, offsets, *precision);
+        },
+        D::Decimal64(precision, _) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            let values = array.iter().map(|v| v.map(|v| *v as i128));
+            decimal::encode_iter(buffer, values, opt, offsets, *precision);
+        },
+
         dt if dt.is_numeric() => {
             if matches!(dt, D::Int128) {
                 if let Some(RowEncodingContext::Decimal(precision)) = dict {
@@ -694,13 +1629,17 @@ unsafe fn encode_flat_array(
             encode_strs(buffer, array.iter(), opt, offsets);
         },
 
-        // Lexical ordered Categorical are cast to PrimitiveArray above.
-        D::Dictionary(_, _, _) => todo!(),
+        // Lexical ordered Categorical are cast to PrimitiveArray above and other dictionary
+        // arrays are materialized in `convert_columns_amortized`.
+        D::Dictionary(_, _, _) => unreachable!(),
 
-        D::FixedSizeBinary(_) => todo!(),
-        D::Decimal(_, _) => todo!(),
-        D::Decimal32(_, _) => todo!(),
-        D::Decimal64(_, _) => todo!(),
+        D::FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            fixed_size_binary::encode(buffer, array, opt, offsets);
+        },
         D::Decimal256(_, _) => todo!(),
 
         D::Union(_) => todo!(),
@@ -725,6 +1664,8 @@ unsafe fn encode_flat_array(
 struct EncodeScratches {
     nested_offsets: Vec<usize>,
     nested_buffer: Vec<u8>,
+    /// The scratches of the next nesting level, kept in the [`EncoderArena`] between calls.
+    nested: Option<Box<EncodeScratches>>,
 }
 
 impl EncodeScratches {
@@ -732,115 +1673,99 @@ impl EncodeScratches {
         self.nested_offsets.clear();
         self.nested_buffer.clear();
     }
+
+    /// Clear the scratches and return the offsets of the nested values together with the
+    /// scratches to encode them with.
+    fn split_nested(&mut self) -> (&mut Vec<usize>, &mut EncodeScratches) {
+        self.clear();
+        (
+            &mut self.nested_offsets,
+            self.nested.get_or_insert_default(),
+        )
+    }
 }
 
+/// Encode the array of the encoder `idx`, or only the rows `slice` (an offset and a length) of
+/// it. Only encoders that are [`EncoderArena::is_sliceable`] can encode a slice.
+#[allow(clippy::too_many_arguments)]
 unsafe fn encode_array(
     buffer: &mut [MaybeUninit<u8>],
-    encoder: &Encoder,
+    encoders: &EncoderArena,
+    idx: EncoderIdx,
+    slice: Option<(usize, usize)>,
     opt: RowEncodingOptions,
     dict: Option<&RowEncodingContext>,
     offsets: &mut [usize],
-    masked_out_write_offset: usize, // Masked out values need to be written somewhere. We just
-    // reserved space at the end and tell all values to write
-    // there.
+    masked_out_write_offset: usize, // Masked out values that cannot be skipped need to be
+    // written somewhere. We reserved space for the largest of
+    // them at the end and tell all of them to write there.
     scratches: &mut EncodeScratches,
 ) {
+    let encoder = encoders.get(idx);
     let Some(state) = &encoder.state else {
         // This is actually the main path.
         //
         // If no nested types or special types are needed, this path is taken.
-        return encode_flat_array(buffer, encoder.array.as_ref(), opt, dict, offsets);
+        return match slice {
+            None => encode_flat_array(buffer, encoder.array.as_ref(), opt, dict, offsets),
+            Some((offset, length)) => encode_flat_array(
+                buffer,
+                encoder.array.sliced(offset, length).as_ref(),
+                opt,
+                dict,
+                offsets,
+            ),
+        };
     };
 
-    match state.as_ref() {
-        EncoderState::List(nested_encoder, nested_row_widths) => {
-            // @TODO: make more general.
-            let array = encoder
-                .array
-                .as_any()
-                .downcast_ref::<ListArray<i64>>()
-                .unwrap();
-
-            scratches.clear();
-
-            scratches
-                .nested_offsets
-                .reserve(nested_row_widths.num_rows());
-            let nested_offsets = &mut scratches.nested_offsets;
-
-            let list_null_sentinel = opt.list_null_sentinel();
-            let list_continuation_token = opt.list_continuation_token();
-            let list_termination_token = opt.list_termination_token();
-
-            match array.validity() {
-                None => {
-                    for (i, (offset, length)) in
-                        array.offsets().offset_and_length_iter().enumerate()
-                    {
-                        for j in offset..offset + length {
-                            buffer[offsets[i]] = MaybeUninit::new(list_continuation_token);
-                            offsets[i] += 1;
-
-                            nested_offsets.push(offsets[i]);
-                            offsets[i] += nested_row_widths.get(j);
-                        }
-                        buffer[offsets[i]] = MaybeUninit::new(list_termination_token);
-                        offsets[i] += 1;
-                    }
-                },
-                Some(validity) => {
-                    for (i, ((offset, length), is_valid)) in array
-                        .offsets()
-                        .offset_and_length_iter()
-                        .zip(validity.iter())
-                        .enumerate()
-                    {
-                        if !is_valid {
-                            buffer[offsets[i]] = MaybeUninit::new(list_null_sentinel);
-                            offsets[i] += 1;
-
-                            // Values might have been masked out.
-                            if length > 0 {
-                                nested_offsets
-                                    .extend(std::iter::repeat_n(masked_out_write_offset, length));
-                            }
-
-                            continue;
-                        }
-
-                        for j in offset..offset + length {
-                            buffer[offsets[i]] = MaybeUninit::new(list_continuation_token);
-                            offsets[i] += 1;
-
-                            nested_offsets.push(offsets[i]);
-                            offsets[i] += nested_row_widths.get(j);
-                        }
-                        buffer[offsets[i]] = MaybeUninit::new(list_termination_token);
-                        offsets[i] += 1;
-                    }
-                },
-            }
-
-            unsafe {
-                encode_array(
+    match state {
+        EncoderState::List(nested_encoder, nested_widths_idx, offset_width) => {
+            let nested_row_widths = encoders.row_widths(*nested_widths_idx);
+            debug_assert!(slice.is_none(), "list encoders cannot be sliced");
+            let dict = list_values_dict(dict);
+            let array = encoder.array.as_any();
+            match offset_width {
+                OffsetWidth::I32 => encode_list_array(
                     buffer,
-                    nested_encoder,
-                    opt.into_nested(),
+                    array.downcast_ref::<ListArray<i32>>().unwrap(),
+                    encoders,
+                    *nested_encoder,
+                    nested_row_widths,
+                    opt,
                     dict,
-                    nested_offsets,
+                    offsets,
                     masked_out_write_offset,
-                    &mut EncodeScratches::default(),
-                )
-            };
+                    scratches,
+                ),
+                OffsetWidth::I64 => encode_list_array(
+                    buffer,
+                    array.downcast_ref::<ListArray<i64>>().unwrap(),
+                    encoders,
+                    *nested_encoder,
+                    nested_row_widths,
+                    opt,
+                    dict,
+                    offsets,
+                    masked_out_write_offset,
+                    scratches,
+                ),
+            }
         },
-        EncoderState::FixedSizeList(array, width, nested_row_widths) => {
-            encode_validity(buffer, encoder.array.validity(), opt, offsets);
+        EncoderState::FixedSizeList(nested_encoder, width, nested_widths_idx) => {
+            let nested_row_widths = encoders.row_widths(*nested_widths_idx);
+            debug_assert!(slice.is_none(), "list encoders cannot be sliced");
+            let dict = list_values_dict(dict);
+            if !opt.contains(RowEncodingOptions::NON_NULLABLE) {
+                encode_validity(buffer, encoder.array.validity(), opt, offsets);
+            }
 
-            if *width == 0 {
+            // `Null` values take up no bytes.
+            if *width == 0 || encoders.get(*nested_encoder).array.dtype() == &ArrowDataType::Null {
                 return;
             }
 
-            let mut child_offsets = Vec::with_capacity(offsets.len() * width);
+            let (child_offsets, scratches) = scratches.split_nested();
+            child_offsets.reserve(offsets.len() * width);
             for (i, offset) in offsets.iter_mut().enumerate() {
                 for j in 0..*width {
                     child_offsets.push(*offset);
@@ -850,10 +1775,12 @@ unsafe fn encode_array(
 
             encode_array(
                 buffer,
-                array.as_ref(),
+                encoders,
+                *nested_encoder,
+                None,
                 opt.into_nested(),
                 dict,
-                &mut child_offsets,
+                child_offsets,
                 masked_out_write_offset,
                 scratches,
             );
@@ -861,39 +1788,298 @@ unsafe fn encode_array(
                 *offset = child_offsets[(i + 1) * width - 1];
             }
         },
-        EncoderState::Struct(arrays) => {
-            encode_validity(buffer, encoder.array.validity(), opt, offsets);
-
-            match dict {
-                None => {
-                    for array in arrays {
-                        encode_array(
-                            buffer,
-                            array,
-                            opt.into_nested(),
-                            None,
-                            offsets,
-                            masked_out_write_offset,
-                            scratches,
-                        );
-                    }
-                },
-                Some(RowEncodingContext::Struct(dicts)) => {
-                    for (array, dict) in arrays.iter().zip(dicts) {
-                        encode_array(
-                            buffer,
-                            array,
-                            opt.into_nested(),
-                            dict.as_ref(),
-                            offsets,
-                            masked_out_write_offset,
-                            scratches,
-                        );
+        EncoderState::Struct(struct_fields) => {
+            if !opt.contains(RowEncodingOptions::NON_NULLABLE) {
+                let validity = encoder.array.validity();
+                match slice {
+                    None => encode_validity(buffer, validity, opt, offsets),
+                    Some((offset, length)) => {
+                        let validity = validity.map(|v| v.clone().sliced(offset, length));
+                        encode_validity(buffer, validity.as_ref(), opt, offsets)
+                    },
+                }
+            }
+
+            let fields = struct_fields_opts_and_dicts(opt, dict, encoder.array.dtype());
+            for (&field, (opt, dict)) in encoders.struct_fields[struct_fields.clone()]
+                .iter()
+                .zip(fields)
+            {
+                encode_array(
+                    buffer,
+                    encoders,
+                    field,
+                    slice,
+                    opt,
+                    dict,
+                    offsets,
+                    masked_out_write_offset,
+                    scratches,
+                );
+            }
+        },
+    }
+}
+
+/// Encode the lists of `array`, whose values are encoded by `nested_encoder`.
+#[allow(clippy::too_many_arguments)]
+unsafe fn encode_list_array<O: Offset>(
+    buffer: &mut [MaybeUninit<u8>],
+    array: &ListArray<O>,
+    encoders: &EncoderArena,
+    nested_encoder: EncoderIdx,
+    nested_row_widths: &RowWidths,
+    opt: RowEncodingOptions,
+    dict: Option<&RowEncodingContext>,
+    offsets: &mut [usize],
+    masked_out_write_offset: usize,
+    scratches: &mut EncodeScratches,
+) {
+    debug_assert!(array.offsets().last().to_usize() <= nested_row_widths.num_rows());
+
+    if encoders.get(nested_encoder).array.dtype() == &ArrowDataType::Null {
+        encode_null_lists(buffer, array, opt, offsets);
+        return;
+    }
+
+    let (nested_offsets, nested_scratches) = scratches.split_nested();
+    nested_offsets.reserve(nested_row_widths.num_rows());
+
+    let list_null_sentinel = opt.list_null_sentinel();
+    let list_continuation_token = opt.list_continuation_token();
+    let list_termination_token = opt.list_termination_token();
+
+    // If the values can be sliced, masked out values are not encoded at all. Instead, the
+    // runs of values in between them are encoded separately.
+    let skip_masked = encoders.is_sliceable(nested_encoder);
+    let mut runs = Vec::new();
+    let values_start = array.offsets().first().to_usize();
+    let mut run_start = values_start;
+
+    match array.validity() {
+        None => {
+            for (i, (offset, length)) in array.offsets().offset_and_length_iter().enumerate() {
+                for j in offset..offset + length {
+                    buffer[offsets[i]] = MaybeUninit::new(list_continuation_token);
+                    offsets[i] += 1;
+
+                    nested_offsets.push(offsets[i]);
+                    offsets[i] += nested_row_widths.get(j);
+                }
+                buffer[offsets[i]] = MaybeUninit::new(list_termination_token);
+                offsets[i] += 1;
+            }
+        },
+        Some(validity) => {
+            for (i, ((offset, length), is_valid)) in array
+                .offsets()
+                .offset_and_length_iter()
+                .zip(validity.iter())
+                .enumerate()
+            {
+                if !is_valid {
+                    buffer[offsets[i]] = MaybeUninit::new(list_null_sentinel);
+                    offsets[i] += 1;
+
+                    // Values might have been masked out.
+                    if length > 0 {
+                        if skip_masked {
+                            if run_start < offset {
+                                runs.push(run_start..offset);
+                            }
+                            run_start = offset + length;
+                        } else {
+                            nested_offsets
+                                .extend(std::iter::repeat_n(masked_out_write_offset, length));
+                        }
                     }
-                },
-                _ => unreachable!(),
+
+                    continue;
+                }
+
+                for j in offset..offset + length {
+                    buffer[offsets[i]] = MaybeUninit::new(list_continuation_token);
+                    offsets[i] += 1;
+
+                    nested_offsets.push(offsets[i]);
+                    offsets[i] += nested_row_widths.get(j);
+                }
+                buffer[offsets[i]] = MaybeUninit::new(list_termination_token);
+                offsets[i] += 1;
+            }
+        },
+    }
+
+    // If no values were skipped, the nested values are encoded as a whole. Otherwise, even a
+    // single run is a slice of them.
+    if run_start == values_start {
+        unsafe {
+            encode_array(
+                buffer,
+                encoders,
+                nested_encoder,
+                None,
+                opt.into_nested(),
+                dict,
+                nested_offsets,
+                masked_out_write_offset,
+                nested_scratches,
+            )
+        };
+        return;
+    }
+
+    let run_end = array.offsets().last().to_usize();
+    if run_start < run_end {
+        runs.push(run_start..run_end);
+    }
+    let mut nested_offsets = nested_offsets.as_mut_slice();
+    for run in runs {
+        let (run_offsets, rest) = nested_offsets.split_at_mut(run.len());
+        unsafe {
+            encode_array(
+                buffer,
+                encoders,
+                nested_encoder,
+                Some((run.start, run.len())),
+                opt.into_nested(),
+                dict,
+                run_offsets,
+                masked_out_write_offset,
+                nested_scratches,
+            )
+        };
+        nested_offsets = rest;
+    }
+    debug_assert!(nested_offsets.is_empty());
+}
+
+/// Encode lists of `Null` values. As the values take up no bytes, only the tokens are written.
+unsafe fn encode_null_lists<O: Offset>(
+    buffer: &mut [MaybeUninit<u8>],
+    array: &ListArray<O>,
+    opt: RowEncodingOptions,
+    offsets: &mut [usize],
+) {
+    let list_null_sentinel = opt.list_null_sentinel();
+    let list_continuation_token = opt.list_continuation_token();
+    let list_termination_token = opt.list_termination_token();
+
+    for (i, (offset, length)) in offsets
+        .iter_mut()
+        .zip(array.offsets().lengths())
+        .enumerate()
+    {
+        if !array.is_valid(i) {
+            buffer[*offset] = MaybeUninit::new(list_null_sentinel);
+            *offset += 1;
+            continue;
+        }
+
+        buffer[*offset..*offset + length].fill(MaybeUninit::new(list_continuation_token));
+        buffer[*offset + length] = MaybeUninit::new(list_termination_token);
+        *offset += length + 1;
+    }
+}
+
+/// Get the options and context that each of the fields of the struct `dtype` is encoded with.
+///
+/// By default, fields are encoded with the nested options of the struct column. A
+/// [`RowEncodingContext::StructWithOptions`] overrides these per field.
+///
+/// # Panics
+///
+/// Panics if the context doesn't have exactly one entry per field, see [`validate_contexts`].
+pub(crate) fn struct_fields_opts_and_dicts<'a>(
+    opt: RowEncodingOptions,
+    dict: Option<&'a RowEncodingContext>,
+    dtype: &ArrowDataType,
+) -> impl Iterator<Item = (RowEncodingOptions, Option<&'a RowEncodingContext>)> + use<'a> {
+    let ArrowDataType::Struct(fields) = dtype else {
+        unreachable!("expected a struct, got {dtype:?}");
+    };
+    let num_fields = fields.len();
+    if let Err(err) = check_struct_context(dtype, num_fields, dict) {
+        panic!("{err}");
+    }
+
+    (0..num_fields).map(move |i| match dict {
+        Some(RowEncodingContext::Struct(dicts)) => (opt.into_nested(), dicts[i].as_ref()),
+        Some(RowEncodingContext::StructWithOptions(fields)) => (fields[i].0, fields[i].1.as_ref()),
+        _ => (opt.into_nested(), None),
+    })
+}
+
+/// The context of the values of a list or fixed-size list with context `dict`.
+///
+/// Contexts other than [`RowEncodingContext::List`] are passed on to the values as is.
+pub(crate) fn list_values_dict(dict: Option<&RowEncodingContext>) -> Option<&RowEncodingContext> {
+    match dict {
+        Some(RowEncodingContext::List(values)) => values.as_ref().as_ref(),
+        dict => dict,
+    }
+}
+
+fn check_struct_context(
+    dtype: &ArrowDataType,
+    num_fields: usize,
+    dict: Option<&RowEncodingContext>,
+) -> PolarsResult<()> {
+    let num_entries = match dict {
+        None => return Ok(()),
+        Some(RowEncodingContext::Struct(dicts)) => dicts.len(),
+        Some(RowEncodingContext::StructWithOptions(fields)) => fields.len(),
+        Some(_) => {
+            polars_bail!(SchemaMismatch: "expected a struct row encoding context for {dtype:?}")
+        },
+    };
+    polars_ensure!(
+        num_entries == num_fields,
+        SchemaMismatch: "row encoding context for {dtype:?} has {num_entries} entries, but the struct has {num_fields} fields"
+    );
+    Ok(())
+}
+
+/// Check that `dicts` has a context for every column in `dtypes`, and that every (nested) struct
+/// context has exactly one entry per field.
+///
+/// Encoding or decoding with a mismatching context panics, so this allows validating the contexts
+/// once per schema instead.
+pub fn validate_contexts(
+    dtypes: &[ArrowDataType],
+    dicts: &[Option<RowEncodingContext>],
+) -> PolarsResult<()> {
+    polars_ensure!(
+        dtypes.len() == dicts.len(),
+        SchemaMismatch: "expected a row encoding context for each of the {} columns, got {}",
+        dtypes.len(), dicts.len()
+    );
+    dtypes
+        .iter()
+        .zip(dicts)
+        .try_for_each(|(dtype, dict)| validate_context(dtype, dict.as_ref()))
+}
+
+fn validate_context(dtype: &ArrowDataType, dict: Option<&RowEncodingContext>) -> PolarsResult<()> {
+    use ArrowDataType as D;
+
+    match dtype {
+        D::Struct(fields) => {
+            check_struct_context(dtype, fields.len(), dict)?;
+            let fields_dicts =
+                struct_fields_opts_and_dicts(RowEncodingOptions::default(), dict, dtype);
+            for (field, (_, dict)) in fields.iter().zip(fields_dicts) {
+                validate_context(field.dtype(), dict)?;
             }
+            Ok(())
+        },
+        D::List(field) | D::LargeList(field) | D::FixedSizeList(field, _) => {
+            validate_context(field.dtype(), list_values_dict(dict))
         },
+        _ if matches!(dict, Some(RowEncodingContext::List(_))) => {
+            polars_bail!(SchemaMismatch: "expected a row encoding context for {dtype:?}, got a list context")
+        },
+        _ => Ok(()),
     }
 }
 
@@ -935,7 +2121,7 @@ pub fn fixed_size(
 
     if let Some(RowEncodingContext::Categorical(ctx)) = dict {
         // If ordered categorical (non-enum) we encode strings, otherwise physical.
-        if !ctx.is_enum && opt.is_ordered() {
+        if ctx.encodes_strings(opt) {
             return None;
         }
     }
@@ -960,47 +2146,114 @@ pub fn fixed_size(
             _ => unreachable!(),
         },
 
+        D::Decimal(precision, _) | D::Decimal32(precision, _) | D::Decimal64(precision, _) => {
+            decimal::len_from_precision(*precision)
+        },
+
         D::Float16 => pf16::ENCODED_LEN,
         D::Float32 => f32::ENCODED_LEN,
         D::Float64 => f64::ENCODED_LEN,
-        D::FixedSizeList(f, width) => 1 + width * fixed_size(f.dtype(), opt, dict)?,
-        D::Struct(fs) => match dict {
-            None => {
-                let mut sum = 0;
-                for f in fs {
-                    sum += fixed_size(f.dtype(), opt, None)?;
-                }
-                1 + sum
-            },
-            Some(RowEncodingContext::Struct(dicts)) => {
-                let mut sum = 0;
-                for (f, dict) in fs.iter().zip(dicts) {
-                    sum += fixed_size(f.dtype(), opt, dict.as_ref())?;
-                }
-                1 + sum
-            },
-            _ => unreachable!(),
+        D::FixedSizeBinary(size) => 1 + size,
+        D::FixedSizeList(f, width) => {
+            opt.nested_validity_len() + width * fixed_size(f.dtype(), opt, list_values_dict(dict))?
         },
+        D::Struct(fs) => {
+            let mut sum = 0;
+            for (f, (opt, dict)) in fs
+                .iter()
+                .zip(struct_fields_opts_and_dicts(opt, dict, dtype))
+            {
+                sum += fixed_size(f.dtype(), opt, dict)?;
+            }
+            opt.nested_validity_len() + sum
+        },
+        // Strings with an inline dictionary are encoded as their ordinal.
+        D::Utf8View if matches!(dict, Some(RowEncodingContext::InlineDictionary(_))) => {
+            u32::ENCODED_LEN
+        },
+        // Dictionary arrays are encoded as their materialized values, which we don't know the
+        // width of here.
+        D::Dictionary(..) => return None,
         _ => return None,
     })
 }
 
-#[cfg(test)]
-mod tests {
-    use arrow::array::proptest::{
-        ArrayArbitraryOptions, ArrowDataTypeArbitraryOptions, ArrowDataTypeArbitrarySelection,
-        array_with_options,
+/// Upper bound on the number of bytes every row takes when encoding `columns`.
+///
+/// Returns `None` if any of the columns has a variable encoded width.
+pub fn row_width_upper_bound(
+    columns: &[ArrayRef],
+    opts: &[RowEncodingOptions],
+    dicts: &[Option<RowEncodingContext>],
+) -> Option<usize> {
+    assert_eq!(columns.len(), opts.len());
+    assert_eq!(columns.len(), dicts.len());
+
+    columns
+        .iter()
+        .zip(opts)
+        .zip(dicts)
+        .map(|((column, opt), dict)| fixed_size(column.dtype(), *opt, dict.as_ref()))
+        .sum()
+}
+
+/// Number of bytes the rows of `columns` take when encoded, without encoding them.
+///
+/// This is exactly the length of [`RowsEncoded::values`] after encoding the columns with
+/// [`convert_columns_amortized`]. Like the encoder, it doesn't count nested values that are
+/// masked out by a null parent.
+///
+/// # Panics
+///
+/// Panics if the columns can't be encoded, see [`try_convert_columns`].
+pub fn estimate_encoded_size(
+    columns: &[ArrayRef],
+    opts: &[RowEncodingOptions],
+    dicts: &[Option<RowEncodingContext>],
+) -> usize {
+    assert_eq!(columns.len(), opts.len());
+    assert_eq!(columns.len(), dicts.len());
+
+    let num_rows = columns.first().map_or(0, |c| c.len());
+    let fields = opts.iter().copied().zip(dicts.iter().map(Option::as_ref));
+    check_columns(num_rows, columns, fields.clone()).unwrap_or_else(|err| panic!("{err}"));
+
+    let mut encoders = EncoderArena::default();
+    let mut row_widths = RowWidths::new(num_rows);
+    let mut masked_out_max_length = 0;
+    for (column, (opt, dict)) in columns.iter().zip(fields) {
+        // Dictionary arrays are encoded as their values, see `encode_columns_into`.
+        let column = materialize_dictionaries(column.as_ref());
+        get_encoder(
+            &mut encoders,
+            column.as_ref(),
+            opt,
+            dict,
+            &mut row_widths,
+            &mut masked_out_max_length,
+        );
+    }
+    row_widths.sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::proptest::{
+        ArrayArbitraryOptions, ArrowDataTypeArbitraryOptions, ArrowDataTypeArbitrarySelection,
+        array_with_options,
     };
+    use polars_utils::total_ord::TotalOrd;
+    use proptest::prelude::{Just, Strategy};
 
     use super::*;
 
     proptest::prop_compose! {
-        fn arrays
-            ()
+        fn arrays_with_dtypes
+            (allowed_dtypes: ArrowDataTypeArbitrarySelection)
             (length in 0..100usize)
             (arrays in proptest::collection::vec(array_with_options(length, ArrayArbitraryOptions {
                 dtype: ArrowDataTypeArbitraryOptions {
-                    allowed_dtypes: ArrowDataTypeArbitrarySelection::all() & !ArrowDataTypeArbitrarySelection::BINARY,
+                    allowed_dtypes,
                     ..Default::default()
                 }
             }), 1..3))
@@ -1009,6 +2262,136 @@ mod tests {
         }
     }
 
+    /// Slice `array` with its lists trimmed to normalized offsets, as the encoders expect.
+    fn sliced_normalized(array: &dyn Array, offset: usize, length: usize) -> Box<dyn Array> {
+        use polars_compute::trim_lists_to_normalized_offsets::trim_lists_to_normalized_offsets;
+
+        let array = array.sliced(offset, length);
+        trim_lists_to_normalized_offsets(array.as_ref()).unwrap_or(array)
+    }
+
+    fn arrays() -> impl Strategy<Value = Vec<Box<dyn Array>>> {
+        arrays_with_dtypes(
+            ArrowDataTypeArbitrarySelection::all() & !ArrowDataTypeArbitrarySelection::BINARY,
+        )
+    }
+
+    fn row_encoding_options() -> impl Strategy<Value = RowEncodingOptions> {
+        proptest::prop_oneof![
+            Just(RowEncodingOptions::new_unsorted()),
+            (proptest::bool::ANY, proptest::bool::ANY).prop_map(|(descending, nulls_last)| {
+                RowEncodingOptions::new_sorted(descending, nulls_last)
+            }),
+        ]
+    }
+
+    proptest::prop_compose! {
+        fn fixed_size_binary_array
+            ()
+            (size in 1..8usize, length in 0..100usize)
+            (values in proptest::collection::vec(
+                proptest::option::of(proptest::collection::vec(proptest::num::u8::ANY, size)),
+                length,
+            ), size in proptest::strategy::Just(size))
+        -> arrow::array::FixedSizeBinaryArray {
+            arrow::array::FixedSizeBinaryArray::from_iter(values, size)
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_fixed_size_binary_roundtrip
+            (array in fixed_size_binary_array(), descending: bool, nulls_last: bool, ordered: bool)
+        {
+            let opt = if ordered {
+                RowEncodingOptions::new_sorted(descending, nulls_last)
+            } else {
+                RowEncodingOptions::new_unsorted()
+            };
+            let opts = [opt];
+            let dicts = [None];
+            let columns = [array.clone().boxed()];
+            let rows = convert_columns(array.len(), &columns, &opts, &dicts);
+
+            if ordered {
+                for (i, j) in (0..array.len()).zip(1..array.len()) {
+                    let expected = match (array.get(i), array.get(j)) {
+                        (None, None) => std::cmp::Ordering::Equal,
+                        (None, Some(_)) if nulls_last => std::cmp::Ordering::Greater,
+                        (None, Some(_)) => std::cmp::Ordering::Less,
+                        (Some(_), None) if nulls_last => std::cmp::Ordering::Less,
+                        (Some(_), None) => std::cmp::Ordering::Greater,
+                        (Some(a), Some(b)) if descending => b.cmp(a),
+                        (Some(a), Some(b)) => a.cmp(b),
+                    };
+                    proptest::prop_assert_eq!(rows.get(i).cmp(rows.get(j)), expected);
+                }
+            }
+
+            let mut rows = rows.iter().collect::<Vec<_>>();
+            let dtypes = [array.dtype().clone()];
+            let decoded = unsafe { crate::decode::decode_rows(&mut rows, &opts, &dicts, &dtypes) };
+            proptest::prop_assert_eq!(&decoded[0], &columns[0]);
+        }
+    }
+
+    proptest::prop_compose! {
+        fn decimal_array
+            ()
+            (max_precision in proptest::sample::select(vec![9usize, 18, 38]), length in 0..100usize)
+            (values in proptest::collection::vec(proptest::option::of(proptest::num::i128::ANY), length),
+             precision in 1..=max_precision,
+             max_precision in proptest::strategy::Just(max_precision))
+        -> (Box<dyn Array>, Vec<Option<i128>>) {
+            let bound = 10i128.pow(precision as u32);
+            let values = values.into_iter().map(|v| v.map(|v| v % bound)).collect::<Vec<_>>();
+            let array = match max_precision {
+                9 => PrimitiveArray::<i32>::from_iter(values.iter().map(|v| v.map(|v| v as i32)))
+                    .to(ArrowDataType::Decimal32(precision, 0))
+                    .boxed(),
+                18 => PrimitiveArray::<i64>::from_iter(values.iter().map(|v| v.map(|v| v as i64)))
+                    .to(ArrowDataType::Decimal64(precision, 0))
+                    .boxed(),
+                _ => PrimitiveArray::<i128>::from_iter(values.iter().copied())
+                    .to(ArrowDataType::Decimal(precision, 0))
+                    .boxed(),
+            };
+            (array, values)
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_decimal_order
+            ((array, values) in decimal_array(), descending: bool, nulls_last: bool)
+        {
+            let opts = [RowEncodingOptions::new_sorted(descending, nulls_last)];
+            let dicts = [None];
+            let columns = [array];
+            let rows = convert_columns(values.len(), &columns, &opts, &dicts);
+
+            for i in 0..values.len() {
+                for j in 0..values.len() {
+                    let expected = match (values[i], values[j]) {
+                        (None, None) => std::cmp::Ordering::Equal,
+                        (None, Some(_)) if nulls_last => std::cmp::Ordering::Greater,
+                        (None, Some(_)) => std::cmp::Ordering::Less,
+                        (Some(_), None) if nulls_last => std::cmp::Ordering::Less,
+                        (Some(_), None) => std::cmp::Ordering::Greater,
+                        (Some(a), Some(b)) if descending => b.cmp(&a),
+                        (Some(a), Some(b)) => a.cmp(&b),
+                    };
+                    proptest::prop_assert_eq!(rows.get(i).cmp(rows.get(j)), expected);
+                }
+            }
+
+            let mut rows = rows.iter().collect::<Vec<_>>();
+            let dtypes = [columns[0].dtype().clone()];
+            let decoded = unsafe { crate::decode::decode_rows(&mut rows, &opts, &dicts, &dtypes) };
+            proptest::prop_assert_eq!(&decoded[0], &columns[0]);
+        }
+    }
+
     proptest::proptest! {
         #[test]
         fn test_encode_arrays
@@ -1018,4 +2401,1692 @@ mod tests {
             convert_columns_no_order(arrays[0].len(), &arrays, &dicts);
         }
     }
+
+    proptest::proptest! {
+        #[test]
+        fn test_append_columns
+            (
+                arrays in arrays(),
+                opts in proptest::collection::vec(row_encoding_options(), 2),
+                splits in proptest::collection::vec(proptest::prelude::any::<u32>(), 0..4),
+            )
+        {
+            let opts = &opts[..arrays.len()];
+            let dicts: Vec<Option<RowEncodingContext>> = (0..arrays.len()).map(|_| None).collect();
+            let len = arrays[0].len();
+            let expected = convert_columns(len, &arrays, opts, &dicts);
+
+            let mut splits = splits
+                .iter()
+                .map(|&s| s as usize % (len + 1))
+                .chain([0, len])
+                .collect::<Vec<_>>();
+            splits.sort_unstable();
+
+            // Every chunk continues the offsets of the previous one, including empty chunks.
+            let mut rows = RowsEncoded::default();
+            for w in splits.windows(2) {
+                let chunk = arrays
+                    .iter()
+                    .map(|a| sliced_normalized(a.as_ref(), w[0], w[1] - w[0]))
+                    .collect::<Vec<_>>();
+                let fields = opts.iter().copied().zip(dicts.iter().map(|v| v.as_ref()));
+                rows.append_columns(w[1] - w[0], &chunk, fields);
+                proptest::prop_assert_eq!(rows.len(), w[1]);
+            }
+            proptest::prop_assert_eq!(&rows.values, &expected.values);
+            proptest::prop_assert_eq!(&rows.offsets, &expected.offsets);
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_convert_columns_chunked
+            (
+                arrays in arrays(),
+                opts in proptest::collection::vec(row_encoding_options(), 2),
+                splits in proptest::collection::vec(proptest::prelude::any::<u32>(), 0..8),
+            )
+        {
+            let opts = &opts[..arrays.len()];
+            let dicts: Vec<Option<RowEncodingContext>> = (0..arrays.len()).map(|_| None).collect();
+            let len = arrays[0].len();
+            let expected = convert_columns(len, &arrays, opts, &dicts);
+
+            // Every column is split at different rows.
+            let columns = arrays
+                .iter()
+                .zip(splits.chunks(4).chain(std::iter::repeat(&[][..])))
+                .map(|(array, splits)| {
+                    let mut splits = splits
+                        .iter()
+                        .map(|&s| s as usize % (len + 1))
+                        .chain([0, len])
+                        .collect::<Vec<_>>();
+                    splits.sort_unstable();
+                    splits
+                        .windows(2)
+                        .map(|w| sliced_normalized(array.as_ref(), w[0], w[1] - w[0]))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let rows = convert_columns_chunked(len, &columns, opts, &dicts);
+            proptest::prop_assert_eq!(&rows.values, &expected.values);
+            proptest::prop_assert_eq!(&rows.offsets, &expected.offsets);
+        }
+    }
+
+    #[test]
+    fn test_convert_columns_chunked_matches_rechunked() {
+        use arrow::array::{Int64Array, Utf8ViewArray};
+
+        let strs = [
+            Some("a"),
+            None,
+            Some("a string that doesn't fit in a single block"),
+            Some(""),
+            Some("b"),
+            Some("ba"),
+            None,
+        ];
+        let ints = [Some(3i64), None, Some(-1), Some(0), Some(7), None, Some(2)];
+        let str_chunks = vec![
+            Utf8ViewArray::from_slice(&strs[..2]).boxed(),
+            Utf8ViewArray::from_slice(&strs[2..3]).boxed(),
+            Utf8ViewArray::from_slice(&strs[3..]).boxed(),
+        ];
+        let int_chunks = vec![Int64Array::from(&ints).boxed()];
+
+        let opts = [
+            RowEncodingOptions::new_sorted(true, false),
+            RowEncodingOptions::new_sorted(false, true),
+        ];
+        let dicts = [None, None];
+        let rows = convert_columns_chunked(strs.len(), &[str_chunks, int_chunks], &opts, &dicts);
+
+        let rechunked = [
+            Utf8ViewArray::from_slice(strs).boxed(),
+            Int64Array::from(&ints).boxed(),
+        ];
+        let expected = convert_columns(strs.len(), &rechunked, &opts, &dicts);
+        assert_eq!(rows.values, expected.values);
+        assert_eq!(rows.offsets, expected.offsets);
+
+        let err = try_convert_columns_chunked(
+            strs.len(),
+            &[vec![rechunked[0].sliced(0, 3)], vec![rechunked[1].clone()]],
+            &opts,
+            &dicts,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err,
+            RowEncodeError::LengthMismatch {
+                column: 0,
+                expected: 7,
+                got: 3,
+            }
+        );
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_rows_parts_roundtrip
+            (arrays in arrays(), opts in proptest::collection::vec(row_encoding_options(), 2))
+        {
+            let opts = &opts[..arrays.len()];
+            let dicts: Vec<Option<RowEncodingContext>> = (0..arrays.len()).map(|_| None).collect();
+            let rows = convert_columns(arrays[0].len(), &arrays, opts, &dicts);
+
+            let (values, offsets) = rows.clone().into_parts();
+            let roundtrip = RowsEncoded::from_parts(values, offsets).unwrap();
+            proptest::prop_assert!(roundtrip.iter().eq(rows.iter()));
+
+            let array = rows.clone().into_array();
+            proptest::prop_assert!(rows.iter().eq(array.values_iter()));
+            proptest::prop_assert_eq!(rows.iter().count(), rows.len());
+
+            let (values, offsets) = rows.clone().into_binary_offsets();
+            proptest::prop_assert_eq!(values.as_slice(), rows.values.as_slice());
+            proptest::prop_assert_eq!(offsets.len_proxy(), rows.len());
+        }
+    }
+
+    #[test]
+    fn test_rows_from_invalid_parts() {
+        assert!(RowsEncoded::from_parts(vec![1, 2, 3], vec![0, 2, 3]).is_ok());
+        assert!(RowsEncoded::from_parts(vec![1, 2, 3], vec![1, 3]).is_ok());
+        assert!(RowsEncoded::from_parts(vec![], vec![]).is_ok());
+        assert!(RowsEncoded::from_parts(vec![1, 2, 3], vec![0, 2, 1, 3]).is_err());
+        assert!(RowsEncoded::from_parts(vec![1, 2, 3], vec![0, 2]).is_err());
+        assert!(RowsEncoded::from_parts(vec![1, 2, 3], vec![0, 4]).is_err());
+        assert!(RowsEncoded::from_parts(vec![1], vec![]).is_err());
+        assert_eq!(RowsEncoded::default().iter().count(), 0);
+    }
+
+    /// Check the row encoding of integers without nulls against encoding them one by one.
+    fn check_integer_encoding<T: NativeType + FixedLengthEncoding>(
+        values: Vec<T>,
+        opt: RowEncodingOptions,
+    ) -> Result<(), proptest::test_runner::TestCaseError> {
+        let array = PrimitiveArray::from_vec(values.clone()).boxed();
+        let rows = convert_columns(values.len(), &[array], &[opt], &[None]);
+
+        proptest::prop_assert_eq!(rows.iter().count(), values.len());
+        for (row, value) in rows.iter().zip(values) {
+            let mut encoded = value.encode();
+            if opt.contains(RowEncodingOptions::DESCENDING) {
+                encoded.as_mut().iter_mut().for_each(|v| *v = !*v);
+            }
+            proptest::prop_assert_eq!(row[0], 1);
+            proptest::prop_assert_eq!(&row[1..], encoded.as_ref());
+        }
+        Ok(())
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_encode_integer_blocks
+            (
+                i8s in proptest::collection::vec(proptest::prelude::any::<i8>(), 0..200),
+                u16s in proptest::collection::vec(proptest::prelude::any::<u16>(), 0..200),
+                i64s in proptest::collection::vec(proptest::prelude::any::<i64>(), 0..200),
+                i128s in proptest::collection::vec(proptest::prelude::any::<i128>(), 0..20),
+                opt in row_encoding_options(),
+            )
+        {
+            check_integer_encoding(i8s, opt)?;
+            check_integer_encoding(u16s, opt)?;
+            check_integer_encoding(i64s, opt)?;
+            check_integer_encoding(i128s, opt)?;
+        }
+    }
+
+    /// Check that the encoded rows order like the values, by only comparing the produced bytes.
+    fn check_numeric_order<T: NativeType + FixedLengthEncoding + TotalOrd>(
+        values: Vec<T>,
+        descending: bool,
+    ) -> Result<(), proptest::test_runner::TestCaseError> {
+        let opt = RowEncodingOptions::new_sorted(descending, false);
+        let array = PrimitiveArray::from_vec(values.clone()).boxed();
+        let rows = convert_columns(values.len(), &[array], &[opt], &[None]);
+
+        for i in 0..values.len() {
+            for j in 0..values.len() {
+                let expected = if descending {
+                    values[j].tot_cmp(&values[i])
+                } else {
+                    values[i].tot_cmp(&values[j])
+                };
+                proptest::prop_assert_eq!(rows.get(i).cmp(rows.get(j)), expected);
+            }
+        }
+        Ok(())
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_numeric_order
+            (
+                i8s in proptest::collection::vec(proptest::prelude::any::<i8>(), 0..20),
+                i16s in proptest::collection::vec(proptest::prelude::any::<i16>(), 0..20),
+                i32s in proptest::collection::vec(proptest::prelude::any::<i32>(), 0..20),
+                i64s in proptest::collection::vec(proptest::prelude::any::<i64>(), 0..20),
+                i128s in proptest::collection::vec(proptest::prelude::any::<i128>(), 0..20),
+                u32s in proptest::collection::vec(proptest::prelude::any::<u32>(), 0..20),
+                u64s in proptest::collection::vec(proptest::prelude::any::<u64>(), 0..20),
+                f32s in proptest::collection::vec(proptest::num::f32::ANY, 0..20),
+                f64s in proptest::collection::vec(proptest::num::f64::ANY, 0..20),
+                descending: bool,
+            )
+        {
+            check_numeric_order(i8s, descending)?;
+            check_numeric_order(i16s, descending)?;
+            check_numeric_order(i32s, descending)?;
+            check_numeric_order(i64s, descending)?;
+            check_numeric_order(i128s, descending)?;
+            check_numeric_order(u32s, descending)?;
+            check_numeric_order(u64s, descending)?;
+            check_numeric_order(f32s, descending)?;
+            check_numeric_order(f64s, descending)?;
+        }
+    }
+
+    #[test]
+    fn test_numeric_encoded_bytes() {
+        // The encodings are fixed by value, independent of the byte order of the host.
+        assert_eq!(0x0102u16.encode(), [0x01, 0x02]);
+        assert_eq!((-1i16).encode(), [0x7F, 0xFF]);
+        assert_eq!(1i32.encode(), [0x80, 0x00, 0x00, 0x01]);
+        assert_eq!(i64::MIN.encode(), [0x00; 8]);
+        assert_eq!(i128::MAX.encode(), [0xFF; 16]);
+        assert_eq!(1.0f32.encode(), [0xBF, 0x80, 0x00, 0x00]);
+        assert_eq!((-1.0f32).encode(), [0x40, 0x7F, 0xFF, 0xFF]);
+        assert_eq!((-0.0f64).encode(), 0.0f64.encode());
+
+        assert_eq!(i16::decode([0x7F, 0xFF]), -1);
+        assert_eq!(i32::decode([0x80, 0x00, 0x00, 0x01]), 1);
+        assert_eq!(f32::decode([0x40, 0x7F, 0xFF, 0xFF]), -1.0);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_row_width_upper_bound
+            (
+                arrays in arrays(),
+                opts in proptest::collection::vec(row_encoding_options(), 2),
+            )
+        {
+            let opts = &opts[..arrays.len()];
+            let dicts: Vec<Option<RowEncodingContext>> = (0..arrays.len()).map(|_| None).collect();
+            let rows = convert_columns(arrays[0].len(), &arrays, opts, &dicts);
+
+            let is_fixed = arrays
+                .iter()
+                .zip(opts)
+                .all(|(array, opt)| fixed_size(array.dtype(), *opt, None).is_some());
+            let width = row_width_upper_bound(&arrays, opts, &dicts);
+            proptest::prop_assert_eq!(width.is_some(), is_fixed);
+            if let Some(width) = width {
+                proptest::prop_assert!(rows.iter().all(|row| row.len() <= width));
+            }
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_estimate_encoded_size
+            (
+                arrays in arrays(),
+                opts in proptest::collection::vec(row_encoding_options(), 2),
+            )
+        {
+            let opts = &opts[..arrays.len()];
+            let dicts: Vec<Option<RowEncodingContext>> = (0..arrays.len()).map(|_| None).collect();
+            let mut rows = RowsEncoded::default();
+            convert_columns_amortized(
+                arrays[0].len(),
+                &arrays,
+                opts.iter().copied().zip(dicts.iter().map(|d| d.as_ref())),
+                &mut rows,
+            );
+            let estimate = estimate_encoded_size(&arrays, opts, &dicts);
+            proptest::prop_assert_eq!(estimate, rows.values.len());
+        }
+    }
+
+    #[test]
+    fn test_estimate_encoded_size_masked_out() {
+        use arrow::array::{ListArray, Utf8ViewArray};
+        use arrow::datatypes::Field;
+        use arrow::offset::OffsetsBuffer;
+
+        let strings = |values: &[&str]| Utf8ViewArray::from_slice_values(values).boxed();
+        let list = |offsets: Vec<i64>, values: ArrayRef, validity: Option<Bitmap>| {
+            let dtype = ListArray::<i64>::default_datatype(values.dtype().clone());
+            let offsets = OffsetsBuffer::try_from(offsets).unwrap();
+            ListArray::<i64>::new(dtype, offsets, values, validity).boxed()
+        };
+
+        // The null list in the middle spans values that are never encoded.
+        let validity = Some(Bitmap::from([true, false, true]));
+        let values = strings(&["a", "bb", "ccc", "dddd", "e"]);
+        let masked = list(vec![0, 2, 4, 5], values, validity);
+        let validity = Some(Bitmap::from([true, false, true]));
+        let unmasked = list(vec![0, 2, 2, 3], strings(&["a", "bb", "e"]), validity);
+
+        let s = strings(&["x", "yy", ""]);
+        let dtype = ArrowDataType::Struct(vec![
+            Field::new("s".into(), s.dtype().clone(), true),
+            Field::new("l".into(), masked.dtype().clone(), true),
+        ]);
+        let validity = Some(Bitmap::from([false, true, true]));
+        let st = StructArray::new(dtype, 3, vec![s, masked.clone()], validity).boxed();
+
+        let opts = [RowEncodingOptions::default(); 3];
+        let dicts: [Option<RowEncodingContext>; 3] = [None, None, None];
+        for columns in [
+            vec![masked.clone(), st.clone(), strings(&["p", "", "qq"])],
+            vec![unmasked.clone(), st, strings(&["p", "", "qq"])],
+        ] {
+            let mut rows = RowsEncoded::default();
+            let fields = opts.iter().copied().zip(dicts.iter().map(|d| d.as_ref()));
+            convert_columns_amortized(3, &columns, fields, &mut rows);
+            let estimate = estimate_encoded_size(&columns, &opts, &dicts);
+            assert_eq!(estimate, rows.values.len());
+        }
+        assert_eq!(
+            estimate_encoded_size(&[masked], &opts[..1], &dicts[..1]),
+            estimate_encoded_size(&[unmasked], &opts[..1], &dicts[..1]),
+        );
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_encode_decode_arrays
+            (
+                // Floats are left out as NaN never compares equal to itself.
+                arrays in arrays_with_dtypes(
+                    ArrowDataTypeArbitrarySelection::all()
+                        & !ArrowDataTypeArbitrarySelection::BINARY
+                        & !ArrowDataTypeArbitrarySelection::FLOAT32
+                        & !ArrowDataTypeArbitrarySelection::FLOAT64
+                ),
+                opts in proptest::collection::vec(row_encoding_options(), 2),
+            )
+        {
+            let opts = &opts[..arrays.len()];
+            let dicts: Vec<Option<RowEncodingContext>> = (0..arrays.len()).map(|_| None).collect();
+            let rows = convert_columns(arrays[0].len(), &arrays, opts, &dicts);
+
+            let mut rows = rows.iter().collect::<Vec<_>>();
+            let dtypes = arrays.iter().map(|array| array.dtype().clone()).collect::<Vec<_>>();
+            let mut checked_rows = rows.clone();
+            let checked = crate::decode::try_decode_rows(&mut checked_rows, opts, &dicts, &dtypes);
+            let decoded = unsafe { crate::decode::decode_rows(&mut rows, opts, &dicts, &dtypes) };
+            proptest::prop_assert_eq!(checked.as_ref(), Ok(&decoded));
+
+            proptest::prop_assert!(rows.iter().all(|row| row.is_empty()));
+            for (decoded, array) in decoded.iter().zip(&arrays) {
+                let is_null = |array: &dyn Array| (0..array.len()).map(|i| array.is_null(i)).collect::<Vec<_>>();
+                proptest::prop_assert_eq!(is_null(decoded.as_ref()), is_null(array.as_ref()));
+                proptest::prop_assert_eq!(decoded, array);
+            }
+        }
+    }
+
+    /// Struct with the given fields, followed by a `Null` field, a list of `Null`s with
+    /// `list_lengths` and a fixed-size list of two `Null`s.
+    fn struct_with_null_fields(
+        mut values: Vec<ArrayRef>,
+        list_lengths: &[i64],
+        validity: Option<Bitmap>,
+    ) -> ArrayRef {
+        use arrow::array::{ListArray, NullArray};
+        use arrow::datatypes::Field;
+        use arrow::offset::OffsetsBuffer;
+
+        let length = list_lengths.len();
+        let offsets = std::iter::once(0)
+            .chain(list_lengths.iter().scan(0, |offset, length| {
+                *offset += length;
+                Some(*offset)
+            }))
+            .collect::<Vec<_>>();
+        let num_list_values = *offsets.last().unwrap() as usize;
+        let null_list = ListArray::<i64>::new(
+            ListArray::<i64>::default_datatype(ArrowDataType::Null),
+            OffsetsBuffer::try_from(offsets).unwrap(),
+            NullArray::new(ArrowDataType::Null, num_list_values).boxed(),
+            None,
+        );
+        let fsl_field = Field::new("item".into(), ArrowDataType::Null, true);
+        let null_fsl = FixedSizeListArray::new(
+            ArrowDataType::FixedSizeList(Box::new(fsl_field), 2),
+            length,
+            NullArray::new(ArrowDataType::Null, 2 * length).boxed(),
+            None,
+        );
+        values.extend([
+            NullArray::new(ArrowDataType::Null, length).boxed(),
+            null_list.boxed(),
+            null_fsl.boxed(),
+        ]);
+
+        let fields = values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| Field::new(format!("f{i}").into(), v.dtype().clone(), true))
+            .collect();
+        StructArray::new(ArrowDataType::Struct(fields), length, values, validity).boxed()
+    }
+
+    proptest::prop_compose! {
+        fn struct_with_null_fields_array()
+            (arrays in arrays_with_dtypes(
+                ArrowDataTypeArbitrarySelection::all()
+                    & !ArrowDataTypeArbitrarySelection::BINARY
+                    & !ArrowDataTypeArbitrarySelection::FLOAT32
+                    & !ArrowDataTypeArbitrarySelection::FLOAT64
+            ))
+            (
+                list_lengths in proptest::collection::vec(0..4i64, arrays[0].len()),
+                validity in proptest::option::of(
+                    proptest::collection::vec(proptest::bool::ANY, arrays[0].len())
+                ),
+                arrays in Just(arrays),
+            )
+        -> (ArrayRef, ArrayRef, Vec<i64>) {
+            let validity = validity.map(Bitmap::from_iter);
+            let array = struct_with_null_fields(arrays.clone(), &list_lengths, validity.clone());
+
+            let ArrowDataType::Struct(fields) = array.dtype() else {
+                unreachable!()
+            };
+            let dtype = ArrowDataType::Struct(fields[..arrays.len()].to_vec());
+            let without_nulls =
+                StructArray::new(dtype, list_lengths.len(), arrays, validity).boxed();
+            (array, without_nulls, list_lengths)
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_encode_decode_struct_with_null_fields
+            (
+                (array, without_nulls, list_lengths) in struct_with_null_fields_array(),
+                opt in row_encoding_options(),
+            )
+        {
+            let num_rows = array.len();
+            let rows = convert_columns(num_rows, std::slice::from_ref(&array), &[opt], &[None]);
+            let rows_without_nulls =
+                convert_columns(num_rows, std::slice::from_ref(&without_nulls), &[opt], &[None]);
+
+            // The `Null` values take up no bytes, only the tokens of the list and the validity
+            // byte of the fixed-size list do.
+            for (i, length) in list_lengths.iter().enumerate() {
+                let framing_len = (1 + *length as usize) + 1;
+                proptest::prop_assert_eq!(
+                    rows.get(i).len(),
+                    rows_without_nulls.get(i).len() + framing_len
+                );
+            }
+
+            let mut rows = rows.iter().collect::<Vec<_>>();
+            let dtypes = [array.dtype().clone()];
+            let decoded = crate::decode::try_decode_rows(&mut rows, &[opt], &[None], &dtypes);
+            proptest::prop_assert!(rows.iter().all(|row| row.is_empty()));
+            proptest::prop_assert_eq!(&decoded.unwrap()[0], &array);
+        }
+    }
+
+    #[test]
+    fn test_nulls_last_independent_of_order() {
+        use arrow::array::{
+            BinaryViewArray, BooleanArray, Float64Array, Int32Array, Utf8ViewArray,
+        };
+
+        let arrays = [
+            Int32Array::from([Some(-3), None, Some(i32::MAX)]).boxed(),
+            Float64Array::from([Some(f64::NEG_INFINITY), None, Some(f64::NAN)]).boxed(),
+            BooleanArray::from([Some(false), None, Some(true)]).boxed(),
+            Utf8ViewArray::from_slice([Some(""), None, Some("\u{10FFFF}")]).boxed(),
+            BinaryViewArray::from_slice([Some(&[][..]), None, Some(&[0xFF, 0xFF][..])]).boxed(),
+        ];
+
+        for array in arrays {
+            for (descending, nulls_last) in
+                [(false, false), (false, true), (true, false), (true, true)]
+            {
+                let opt = RowEncodingOptions::new_sorted(descending, nulls_last);
+                let rows = convert_columns(3, std::slice::from_ref(&array), &[opt], &[None]);
+
+                for valid in [0, 2] {
+                    assert_eq!(
+                        rows.get(1) > rows.get(valid),
+                        nulls_last,
+                        "{:?} with descending={descending}, nulls_last={nulls_last}",
+                        array.dtype(),
+                    );
+                }
+                assert_eq!(
+                    fixed_size(array.dtype(), opt, None),
+                    fixed_size(
+                        array.dtype(),
+                        RowEncodingOptions::new_sorted(descending, false),
+                        None
+                    ),
+                );
+            }
+        }
+    }
+
+    /// `{a: i32, s: {b: i32, c: str}}`
+    fn nested_struct_array() -> ArrayRef {
+        use arrow::array::{Int32Array, StructArray, Utf8ViewArray};
+        use arrow::datatypes::Field;
+
+        let b = Int32Array::from([Some(1), None]).boxed();
+        let c = Utf8ViewArray::from_slice([Some("x"), Some("y")]).boxed();
+        let inner_dtype = ArrowDataType::Struct(vec![
+            Field::new("b".into(), b.dtype().clone(), true),
+            Field::new("c".into(), c.dtype().clone(), true),
+        ]);
+        let inner = StructArray::new(inner_dtype, 2, vec![b, c], None).boxed();
+
+        let a = Int32Array::from([Some(3), Some(4)]).boxed();
+        let dtype = ArrowDataType::Struct(vec![
+            Field::new("a".into(), a.dtype().clone(), true),
+            Field::new("s".into(), inner.dtype().clone(), true),
+        ]);
+        StructArray::new(dtype, 2, vec![a, inner], None).boxed()
+    }
+
+    fn nested_struct_context(num_inner_entries: usize) -> Option<RowEncodingContext> {
+        Some(RowEncodingContext::Struct(vec![
+            None,
+            Some(RowEncodingContext::Struct(vec![None; num_inner_entries])),
+        ]))
+    }
+
+    #[test]
+    fn test_validate_contexts() {
+        let dtype = nested_struct_array().dtype().clone();
+        let dtypes = std::slice::from_ref(&dtype);
+
+        assert!(validate_contexts(dtypes, &[None]).is_ok());
+        assert!(validate_contexts(dtypes, &[nested_struct_context(2)]).is_ok());
+
+        // Top level.
+        assert!(validate_contexts(dtypes, &[]).is_err());
+        assert!(validate_contexts(dtypes, &[None, None]).is_err());
+        let too_few = Some(RowEncodingContext::Struct(vec![None]));
+        assert!(validate_contexts(dtypes, &[too_few]).is_err());
+        let too_many = Some(RowEncodingContext::Struct(vec![None, None, None]));
+        assert!(validate_contexts(dtypes, &[too_many]).is_err());
+        let not_a_struct = Some(RowEncodingContext::Decimal(10));
+        assert!(validate_contexts(dtypes, &[not_a_struct]).is_err());
+
+        // Nested two levels deep, also within a list.
+        let list_dtype = ArrowDataType::LargeList(Box::new(arrow::datatypes::Field::new(
+            "item".into(),
+            dtype.clone(),
+            true,
+        )));
+        for dtypes in [dtypes, std::slice::from_ref(&list_dtype)] {
+            assert!(validate_contexts(dtypes, &[nested_struct_context(1)]).is_err());
+            assert!(validate_contexts(dtypes, &[nested_struct_context(3)]).is_err());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "missing row encoding options and context for column 0")]
+    fn test_encode_too_few_contexts() {
+        let array = nested_struct_array();
+        convert_columns_amortized(
+            2,
+            std::slice::from_ref(&array),
+            std::iter::empty(),
+            &mut RowsEncoded::default(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "has 1 entries, but the struct has 2 fields")]
+    fn test_encode_nested_struct_too_few_contexts() {
+        let array = nested_struct_array();
+        convert_columns(
+            2,
+            std::slice::from_ref(&array),
+            &[RowEncodingOptions::default()],
+            &[nested_struct_context(1)],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "has 3 entries, but the struct has 2 fields")]
+    fn test_encode_nested_struct_too_many_contexts() {
+        let array = nested_struct_array();
+        convert_columns(
+            2,
+            std::slice::from_ref(&array),
+            &[RowEncodingOptions::default()],
+            &[nested_struct_context(3)],
+        );
+    }
+
+    #[test]
+    fn test_struct_with_field_options() {
+        use arrow::array::{Int32Array, StructArray, Utf8ViewArray};
+        use arrow::datatypes::Field;
+
+        let a = Utf8ViewArray::from_slice([Some("a"), Some("b"), Some("b"), None, Some("a")]);
+        let b = Int32Array::from([Some(2), Some(1), Some(3), Some(1), None]);
+        let dtype = ArrowDataType::Struct(vec![
+            Field::new("a".into(), a.dtype().clone(), true),
+            Field::new("b".into(), b.dtype().clone(), true),
+        ]);
+        let array = StructArray::new(dtype.clone(), 5, vec![a.boxed(), b.boxed()], None).boxed();
+
+        // Sort `a` descending with nulls first and `b` ascending with nulls last.
+        let opts = [RowEncodingOptions::default()];
+        let dicts = [Some(RowEncodingContext::StructWithOptions(vec![
+            (RowEncodingOptions::DESCENDING, None),
+            (RowEncodingOptions::NULLS_LAST, None),
+        ]))];
+        let rows = convert_columns(5, std::slice::from_ref(&array), &opts, &dicts);
+
+        let mut idxs = (0..5).collect::<Vec<_>>();
+        idxs.sort_by_key(|&i| rows.get(i));
+        assert_eq!(idxs, [3, 1, 2, 0, 4]);
+
+        let mut rows = rows.iter().collect::<Vec<_>>();
+        let decoded = unsafe { crate::decode::decode_rows(&mut rows, &opts, &dicts, &[dtype]) };
+        assert_eq!(decoded[0], array);
+    }
+
+    #[test]
+    fn test_encode_dictionary() {
+        use arrow::array::{Int8Array, Int64Array, UInt32Array};
+
+        let keys = UInt32Array::from([Some(0), None, Some(1), Some(2), Some(3), Some(0), Some(2)]);
+        let values = Utf8Array::<i32>::from([Some("b"), None, Some("a"), Some("c")]);
+        let strs = DictionaryArray::try_from_keys(keys, values.boxed()).unwrap();
+
+        let keys = Int8Array::from([Some(1), None, Some(1), Some(2), Some(0), Some(1), Some(0)]);
+        let values = Int64Array::from([Some(10), None, Some(-5)]);
+        let ints = DictionaryArray::try_from_keys(keys, values.boxed()).unwrap();
+
+        let opt = RowEncodingOptions::default();
+        assert_eq!(fixed_size(ints.dtype(), opt, None), None);
+
+        let columns = [strs.boxed(), ints.boxed()];
+        let expected = [
+            Utf8ViewArray::from_slice([
+                Some("b"),
+                None,
+                None,
+                Some("a"),
+                Some("c"),
+                Some("b"),
+                Some("a"),
+            ])
+            .boxed(),
+            Int64Array::from([None, None, None, Some(-5), Some(10), None, Some(10)]).boxed(),
+        ];
+        let dicts = [None, None];
+        let dtypes = [ArrowDataType::Utf8View, ArrowDataType::Int64];
+
+        // Sorting
+        for (descending, nulls_last) in [(false, false), (false, true), (true, false), (true, true)]
+        {
+            let opts = [RowEncodingOptions::new_sorted(descending, nulls_last); 2];
+            let rows = convert_columns(7, &columns, &opts, &dicts);
+            let expected_rows = convert_columns(7, &expected, &opts, &dicts);
+            assert!(rows.iter().eq(expected_rows.iter()));
+
+            let mut rows = rows.iter().collect::<Vec<_>>();
+            let decoded = unsafe { crate::decode::decode_rows(&mut rows, &opts, &dicts, &dtypes) };
+            assert_eq!(decoded, expected);
+        }
+
+        // Grouping, where null keys and null values are the same group.
+        let opts = [RowEncodingOptions::new_unsorted(); 2];
+        let rows = convert_columns(7, &columns, &opts, &dicts);
+        let expected_rows = convert_columns(7, &expected, &opts, &dicts);
+        assert!(rows.iter().eq(expected_rows.iter()));
+        assert_eq!(rows.get(0), rows.get(5));
+        assert_eq!(rows.get(1), rows.get(2));
+        assert_ne!(rows.get(3), rows.get(6));
+    }
+
+    #[test]
+    fn test_categorical_encode_strings_unordered() {
+        use arrow::array::{ListArray, UInt32Array};
+        use polars_dtype::categorical::CategoricalMapping;
+
+        let mapping = std::sync::Arc::new(CategoricalMapping::new(u32::MAX as usize));
+        let a = mapping.insert_cat("a").unwrap();
+        let bb = mapping.insert_cat("bb").unwrap();
+        let context = |encode_strings| {
+            Some(RowEncodingContext::Categorical(
+                RowEncodingCategoricalContext {
+                    is_enum: false,
+                    encode_strings,
+                    mapping: mapping.clone(),
+                },
+            ))
+        };
+
+        let cats = UInt32Array::from([Some(bb), None, Some(a), Some(bb)]).boxed();
+        let strs = Utf8ViewArray::from_slice([Some("bb"), None, Some("a"), Some("bb")]).boxed();
+        let opts = [RowEncodingOptions::new_unsorted()];
+
+        // The categories are encoded as their strings, so the rows equal those of the strings.
+        let dicts = [context(true)];
+        assert_eq!(fixed_size(cats.dtype(), opts[0], dicts[0].as_ref()), None);
+        let rows = convert_columns(4, std::slice::from_ref(&cats), &opts, &dicts);
+        let str_rows = convert_columns(4, &[strs], &opts, &[None]);
+        assert!(rows.iter().eq(str_rows.iter()));
+
+        let mut decode_rows = rows.iter().collect::<Vec<_>>();
+        let decoded = unsafe {
+            crate::decode::decode_rows(&mut decode_rows, &opts, &dicts, &[ArrowDataType::UInt32])
+        };
+        assert_eq!(decoded[0], cats);
+
+        // Without the flag the physical keys are encoded.
+        let key_rows = convert_columns(4, std::slice::from_ref(&cats), &opts, &[context(false)]);
+        assert!(!key_rows.iter().eq(str_rows.iter()));
+
+        // The length of the strings in nested values is found from the encoded rows.
+        let dtype = ArrowDataType::LargeList(Box::new(arrow::datatypes::Field::new(
+            "item".into(),
+            ArrowDataType::UInt32,
+            true,
+        )));
+        let list =
+            ListArray::<i64>::new(dtype.clone(), vec![0, 2, 4].try_into().unwrap(), cats, None)
+                .boxed();
+        let rows = convert_columns(2, std::slice::from_ref(&list), &opts, &dicts);
+        let mut rows = rows.iter().collect::<Vec<_>>();
+        let decoded = crate::decode::try_decode_rows(&mut rows, &opts, &dicts, &[dtype]).unwrap();
+        assert_eq!(decoded[0], list);
+    }
+
+    #[test]
+    fn test_encode_masked_out_list_values() {
+        use arrow::array::{Int32Array, ListArray, StructArray, Utf8ViewArray};
+        use arrow::bitmap::Bitmap;
+        use arrow::datatypes::Field;
+        use arrow::offset::OffsetsBuffer;
+
+        // `list[struct[a: i32, b: str]]` where 90% of the rows are null, but still have values.
+        let list_array = |value_idxs: &[i32], lengths: &[i64], validity: Option<Bitmap>| {
+            let a = Int32Array::from_vec(value_idxs.to_vec()).boxed();
+            let b = value_idxs
+                .iter()
+                .map(|i| format!("value {i}"))
+                .collect::<Vec<_>>();
+            let b = Utf8ViewArray::from_slice_values(b).boxed();
+            let struct_dtype = ArrowDataType::Struct(vec![
+                Field::new("a".into(), a.dtype().clone(), true),
+                Field::new("b".into(), b.dtype().clone(), true),
+            ]);
+            let values = StructArray::new(struct_dtype.clone(), a.len(), vec![a, b], None).boxed();
+
+            let offsets = std::iter::once(0)
+                .chain(lengths.iter().scan(0, |offset, length| {
+                    *offset += length;
+                    Some(*offset)
+                }))
+                .collect::<Vec<_>>();
+            let dtype = ListArray::<i64>::default_datatype(struct_dtype);
+            let offsets = OffsetsBuffer::try_from(offsets).unwrap();
+            ListArray::<i64>::new(dtype, offsets, values, validity).boxed()
+        };
+
+        let num_rows = 100;
+        // The masked out rows are in between the valid rows, or all in front of them.
+        let is_valid_fns: [fn(usize) -> bool; 2] = [|i| i % 10 == 0, |i| i >= 90];
+        for is_valid in is_valid_fns {
+            let validity = (0..num_rows).map(is_valid).collect::<Bitmap>();
+            let masked = list_array(
+                &(0..3 * num_rows as i32).collect::<Vec<_>>(),
+                &[3; 100],
+                Some(validity.clone()),
+            );
+            let value_idxs = (0..3 * num_rows)
+                .filter(|i| is_valid(i / 3))
+                .map(|i| i as i32)
+                .collect::<Vec<_>>();
+            let lengths = (0..num_rows)
+                .map(|i| if is_valid(i) { 3 } else { 0 })
+                .collect::<Vec<_>>();
+            let trimmed = list_array(&value_idxs, &lengths, Some(validity));
+
+            for opt in [
+                RowEncodingOptions::new_sorted(false, false),
+                RowEncodingOptions::new_sorted(true, true),
+                RowEncodingOptions::new_unsorted(),
+            ] {
+                let rows =
+                    convert_columns(num_rows, std::slice::from_ref(&masked), &[opt], &[None]);
+                let expected =
+                    convert_columns(num_rows, std::slice::from_ref(&trimmed), &[opt], &[None]);
+                assert!(rows.iter().eq(expected.iter()));
+
+                // The masked out values are never written, so no space is reserved for them.
+                assert_eq!(rows.values.capacity(), rows.values.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_empty() {
+        use arrow::array::{Int32Array, ListArray, Utf8ViewArray};
+
+        let list_dtype = ListArray::<i64>::default_datatype(ArrowDataType::Int32);
+        let arrays = [
+            Int32Array::from_slice([]).boxed(),
+            Utf8ViewArray::new_empty(ArrowDataType::Utf8View).boxed(),
+            ListArray::<i64>::new_empty(list_dtype).boxed(),
+        ];
+        let dicts = [None, None, None];
+
+        for array in &arrays {
+            let rows = convert_columns_no_order(0, std::slice::from_ref(array), &dicts[..1]);
+            assert_eq!(rows.offsets, [0]);
+            assert!(rows.values.is_empty());
+        }
+
+        let rows = convert_columns_no_order(0, &arrays, &dicts);
+        assert_eq!(rows.offsets, [0]);
+        assert!(rows.values.is_empty());
+        assert_eq!(rows.iter().count(), 0);
+        assert_eq!(rows.into_array().len(), 0);
+    }
+
+    #[test]
+    fn test_try_convert_unsupported_dtype() {
+        use arrow::array::{Int32Array, StructArray};
+        use arrow::datatypes::Field;
+
+        let date = Int32Array::from_slice([1, 2])
+            .to(ArrowDataType::Date32)
+            .boxed();
+        let dtype = ArrowDataType::Struct(vec![Field::new("d".into(), date.dtype().clone(), true)]);
+        let nested = StructArray::new(dtype, 2, vec![date.clone()], None).boxed();
+        let opts = [RowEncodingOptions::default(); 2];
+
+        let err = try_convert_columns(2, &[date.clone(), nested.clone()], &opts, &[None, None]);
+        assert_eq!(
+            err.err().unwrap(),
+            RowEncodeError::UnsupportedDtype {
+                dtype: ArrowDataType::Date32,
+                path: "0".into(),
+            }
+        );
+        let err = try_convert_columns_no_order(2, &[nested], &[None]);
+        assert!(matches!(err, Err(RowEncodeError::UnsupportedDtype { path, .. }) if path == "0.d"));
+        let err = try_convert_columns(2, &[nested_struct_array(), date], &opts, &[None, None]);
+        assert!(matches!(err, Err(RowEncodeError::UnsupportedDtype { path, .. }) if path == "1"));
+
+        let dtypes = [ArrowDataType::Int32, ArrowDataType::Decimal(39, 0)];
+        let err = crate::decode::try_decode_rows(&mut [], &opts, &[None, None], &dtypes);
+        assert_eq!(
+            err.unwrap_err(),
+            RowEncodeError::UnsupportedDtype {
+                dtype: ArrowDataType::Decimal(39, 0),
+                path: "1".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_try_convert_unsupported_nested_dtype() {
+        use arrow::array::{Int32Array, ListArray, StructArray};
+        use arrow::datatypes::Field;
+        use arrow::offset::OffsetsBuffer;
+
+        let date = Int32Array::from_slice([1, 2])
+            .to(ArrowDataType::Date32)
+            .boxed();
+        let list_dtype = ListArray::<i64>::default_datatype(ArrowDataType::Date32);
+        let offsets = OffsetsBuffer::try_from(vec![0i64, 2]).unwrap();
+        let list = ListArray::<i64>::new(list_dtype, offsets, date, None).boxed();
+        let dtype = ArrowDataType::Struct(vec![Field::new("l".into(), list.dtype().clone(), true)]);
+        let array = StructArray::new(dtype, 1, vec![list], None).boxed();
+
+        let err = try_convert_columns(1, &[array], &[RowEncodingOptions::default()], &[None]);
+        assert!(
+            matches!(err, Err(RowEncodeError::UnsupportedDtype { path, .. }) if path == "0.l[]")
+        );
+    }
+
+    #[test]
+    fn test_try_convert_missing_context() {
+        let array = nested_struct_array();
+        let columns = [array.clone(), array];
+        let opts = [RowEncodingOptions::default(); 2];
+
+        let err = try_convert_columns(2, &columns, &opts[..1], &[None, None]);
+        assert_eq!(
+            err.err().unwrap(),
+            RowEncodeError::MissingContext { column: 1 }
+        );
+        let err = try_convert_columns_no_order(2, &columns, &[]);
+        assert_eq!(
+            err.err().unwrap(),
+            RowEncodeError::MissingContext { column: 0 }
+        );
+        let mut rows = RowsEncoded::default();
+        let fields = std::iter::once((RowEncodingOptions::default(), None));
+        let err = try_convert_columns_amortized(2, &columns, fields, &mut rows);
+        assert_eq!(
+            err.unwrap_err(),
+            RowEncodeError::MissingContext { column: 1 }
+        );
+        assert!(rows.values.is_empty());
+
+        let dtypes = [ArrowDataType::Int32, ArrowDataType::Int32];
+        let err = crate::decode::try_decode_rows(&mut [], &opts, &[None], &dtypes);
+        assert_eq!(
+            err.unwrap_err(),
+            RowEncodeError::MissingContext { column: 1 }
+        );
+
+        // Too many contexts don't belong to any column.
+        let err = try_convert_columns(2, &columns[..1], &opts, &[None, None]);
+        assert!(matches!(err, Err(RowEncodeError::ContextMismatch { path, .. }) if path == "1"));
+    }
+
+    #[test]
+    fn test_try_convert_context_mismatch() {
+        use arrow::array::{Int128Array, Utf8ViewArray};
+        use polars_dtype::categorical::CategoricalMapping;
+
+        let opts = [RowEncodingOptions::default()];
+        let array = nested_struct_array();
+        let err = try_convert_columns(
+            2,
+            std::slice::from_ref(&array),
+            &opts,
+            &[nested_struct_context(1)],
+        );
+        let Err(RowEncodeError::ContextMismatch { path, reason }) = err else {
+            panic!("expected a context mismatch, got {:?}", err.as_ref().err());
+        };
+        assert_eq!(path, "0.s");
+        assert!(reason.ends_with("has 1 entries, but the struct has 2 fields"));
+
+        let not_a_struct = Some(RowEncodingContext::Decimal(10));
+        let err = try_convert_columns(2, &[array], &opts, &[not_a_struct]);
+        assert!(matches!(err, Err(RowEncodeError::ContextMismatch { path, .. }) if path == "0"));
+
+        let categorical = Some(RowEncodingContext::Categorical(
+            RowEncodingCategoricalContext {
+                is_enum: false,
+                encode_strings: false,
+                mapping: std::sync::Arc::new(CategoricalMapping::new(u32::MAX as usize)),
+            },
+        ));
+        let strings = Utf8ViewArray::from_slice([Some("a")]).boxed();
+        let err = try_convert_columns(1, &[strings], &opts, std::slice::from_ref(&categorical));
+        assert!(matches!(err, Err(RowEncodeError::ContextMismatch { .. })));
+
+        let dtypes = [ArrowDataType::Int128];
+        let err = crate::decode::try_decode_rows(&mut [], &opts, &[categorical], &dtypes);
+        assert!(matches!(err, Err(RowEncodeError::ContextMismatch { .. })));
+        let decimal = Some(RowEncodingContext::Decimal(39));
+        let decimals = Int128Array::from_slice([1]).boxed();
+        let err = try_convert_columns(1, &[decimals], &opts, &[decimal]);
+        assert!(matches!(err, Err(RowEncodeError::ContextMismatch { .. })));
+    }
+
+    #[test]
+    fn test_try_decode_overflow() {
+        use arrow::datatypes::Field;
+
+        let field = Field::new("item".into(), ArrowDataType::Int64, true);
+        let dtype = ArrowDataType::FixedSizeList(Box::new(field), usize::MAX / 4);
+        let opts = [RowEncodingOptions::default()];
+
+        let err = crate::decode::try_decode_rows(&mut [], &opts, &[None], &[dtype]);
+        assert_eq!(
+            err.unwrap_err(),
+            RowEncodeError::Overflow { path: "0".into() }
+        );
+
+        let dtype = ArrowDataType::FixedSizeBinary(usize::MAX);
+        let err = crate::decode::try_decode_rows(&mut [], &opts, &[None], &[dtype]);
+        assert_eq!(
+            err.unwrap_err(),
+            RowEncodeError::Overflow { path: "0".into() }
+        );
+    }
+
+    #[test]
+    fn test_try_decode_truncated() {
+        use arrow::array::{Int32Array, Utf8ViewArray};
+
+        let columns = [
+            Int32Array::from_slice([1, 2]).boxed(),
+            Utf8ViewArray::from_slice([Some("abc"), Some("def")]).boxed(),
+        ];
+        let dtypes = columns
+            .iter()
+            .map(|c| c.dtype().clone())
+            .collect::<Vec<_>>();
+        let dicts = [None, None];
+
+        for opt in [
+            RowEncodingOptions::new_sorted(false, false),
+            RowEncodingOptions::new_sorted(true, true),
+            RowEncodingOptions::new_unsorted(),
+        ] {
+            let opts = [opt; 2];
+            let encoded = try_convert_columns(2, &columns, &opts, &dicts).unwrap();
+            let mut rows = encoded.iter().collect::<Vec<_>>();
+            let decoded = crate::decode::try_decode_rows(&mut rows.clone(), &opts, &dicts, &dtypes);
+            assert_eq!(decoded.unwrap()[0], columns[0]);
+
+            rows[1] = &rows[1][..rows[1].len() - 1];
+            let err = crate::decode::try_decode_rows(&mut rows, &opts, &dicts, &dtypes);
+            assert_eq!(
+                err.unwrap_err(),
+                RowEncodeError::Truncated { row: 1, column: 1 }
+            );
+
+            rows[0] = &rows[0][..3];
+            let err = crate::decode::try_decode_rows(&mut rows, &opts, &dicts, &dtypes);
+            assert_eq!(
+                err.unwrap_err(),
+                RowEncodeError::Truncated { row: 0, column: 0 }
+            );
+        }
+
+        // Null rows have no bytes.
+        let array = BinaryArray::<i64>::from_iter([Some(encoded_row(&columns)), None]);
+        let err = crate::decode::try_decode_rows_from_binary(
+            &array,
+            &[RowEncodingOptions::default(); 2],
+            &dicts,
+            &dtypes,
+            &mut Vec::new(),
+        );
+        assert_eq!(
+            err.unwrap_err(),
+            RowEncodeError::Truncated { row: 1, column: 0 }
+        );
+    }
+
+    fn encoded_row(columns: &[ArrayRef]) -> Vec<u8> {
+        let opts = vec![RowEncodingOptions::default(); columns.len()];
+        let dicts = vec![None; columns.len()];
+        let rows = convert_columns(columns[0].len(), columns, &opts, &dicts);
+        rows.get(0).to_vec()
+    }
+
+    #[test]
+    fn test_try_decode_malformed() {
+        use arrow::array::ListArray;
+
+        let opts = [RowEncodingOptions::default()];
+        let decode = |row: &[u8], dtype: ArrowDataType| {
+            crate::decode::try_decode_rows(&mut [row], &opts, &[None], &[dtype])
+        };
+        let malformed = |reason| RowEncodeError::Malformed {
+            row: 0,
+            column: 0,
+            reason,
+        };
+
+        // `0xC3` on its own is not valid UTF-8, strings are encoded with 2 added to every byte.
+        assert_eq!(
+            decode(&[0xC5, 0x01], ArrowDataType::Utf8View).unwrap_err(),
+            malformed("invalid UTF-8")
+        );
+        assert_eq!(
+            decode(&[0x62, 0x00, 0x01], ArrowDataType::Utf8View).unwrap_err(),
+            malformed("invalid string byte")
+        );
+        assert_eq!(
+            decode(&[0x62], ArrowDataType::Utf8View).unwrap_err(),
+            RowEncodeError::Truncated { row: 0, column: 0 },
+        );
+
+        let list_dtype = ListArray::<i64>::default_datatype(ArrowDataType::Int32);
+        assert_eq!(
+            decode(&[0x42], list_dtype.clone()).unwrap_err(),
+            malformed("invalid list token")
+        );
+        assert!(decode(&[0x01], list_dtype).is_ok());
+
+        let mut block = vec![0x02];
+        block.extend([0; 32]);
+        block.push(33);
+        assert_eq!(
+            decode(&block, ArrowDataType::BinaryView).unwrap_err(),
+            malformed("invalid binary block length")
+        );
+        *block.last_mut().unwrap() = 32;
+        assert!(decode(&block, ArrowDataType::BinaryView).is_ok());
+    }
+
+    #[test]
+    fn test_try_convert_length_mismatch() {
+        use arrow::array::Int32Array;
+
+        let columns = [
+            Int32Array::from_slice([1, 2, 3]).boxed(),
+            Int32Array::from_slice([1, 2]).boxed(),
+        ];
+        let opts = [RowEncodingOptions::default(); 2];
+        let err = try_convert_columns(3, &columns, &opts, &[None, None]);
+        assert_eq!(
+            err.err().unwrap(),
+            RowEncodeError::LengthMismatch {
+                column: 1,
+                expected: 3,
+                got: 2,
+            }
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_struct_encoders_match_sequential() {
+        use arrow::array::{Int32Array, StructArray, Utf8ViewArray};
+        use arrow::datatypes::Field;
+
+        // A struct that is wide enough to compute the encoders of its fields in parallel, with
+        // fields of a variable and a fixed width.
+        let num_fields = 4 * PARALLEL_STRUCT_MIN_FIELDS;
+        let (fields, values): (Vec<_>, Vec<_>) = (0..num_fields)
+            .map(|i| {
+                let values = if i % 3 == 0 {
+                    Int32Array::from([Some(i as i32), None, Some(-1)]).boxed()
+                } else {
+                    let s = "x".repeat(i % 40);
+                    Utf8ViewArray::from_slice([Some(s.as_str()), Some(""), None]).boxed()
+                };
+                let field = Field::new(format!("f{i}").into(), values.dtype().clone(), true);
+                (field, values)
+            })
+            .unzip();
+        let array = StructArray::new(ArrowDataType::Struct(fields), 3, values, None).boxed();
+
+        let encode = |num_threads| {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap();
+            pool.install(|| {
+                convert_columns(
+                    3,
+                    std::slice::from_ref(&array),
+                    &[RowEncodingOptions::default()],
+                    &[None],
+                )
+            })
+        };
+        let sequential = encode(1);
+        for num_threads in [2, 3, 8] {
+            let parallel = encode(num_threads);
+            assert_eq!(parallel.offsets, sequential.offsets);
+            assert_eq!(parallel.values, sequential.values);
+        }
+    }
+
+    #[test]
+    fn test_encode_small_offset_lists() {
+        use arrow::array::{Int32Array, ListArray, NullArray, Utf8ViewArray};
+        use arrow::offset::OffsetsBuffer;
+
+        // The third list is null and masks out three values.
+        let validity = Bitmap::from([true, true, false, true]);
+        let values = [
+            Int32Array::from([Some(1), None, Some(3), Some(4), Some(5), Some(6)]).boxed(),
+            Utf8ViewArray::from_slice_values(["a", "bc", "", "d", "e", "fgh"]).boxed(),
+            NullArray::new(ArrowDataType::Null, 6).boxed(),
+        ];
+        for values in values {
+            let list = ListArray::<i32>::new(
+                ListArray::<i32>::default_datatype(values.dtype().clone()),
+                OffsetsBuffer::try_from(vec![0i32, 2, 2, 5, 6]).unwrap(),
+                values.clone(),
+                Some(validity.clone()),
+            )
+            .boxed();
+            let large_list = ListArray::<i64>::new(
+                ListArray::<i64>::default_datatype(values.dtype().clone()),
+                OffsetsBuffer::try_from(vec![0i64, 2, 2, 5, 6]).unwrap(),
+                values,
+                Some(validity.clone()),
+            )
+            .boxed();
+
+            for opt in [
+                RowEncodingOptions::default(),
+                RowEncodingOptions::DESCENDING | RowEncodingOptions::NULLS_LAST,
+                RowEncodingOptions::new_unsorted(),
+            ] {
+                let rows = convert_columns(4, std::slice::from_ref(&list), &[opt], &[None]);
+                let expected =
+                    convert_columns(4, std::slice::from_ref(&large_list), &[opt], &[None]);
+                assert_eq!(rows.offsets, expected.offsets);
+                assert_eq!(rows.values, expected.values);
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_list_in_struct_with_field_options() {
+        use arrow::array::{FixedSizeListArray, ListArray, StructArray, Utf8ViewArray};
+        use arrow::datatypes::Field;
+        use arrow::offset::OffsetsBuffer;
+
+        // `array[struct[a: list[str]], 1]` where `a` sorts its nulls last, but its strings don't.
+        let strings = Utf8ViewArray::from_slice([None, Some("x"), Some("y")]).boxed();
+        let list_dtype = ListArray::<i64>::default_datatype(ArrowDataType::Utf8View);
+        let offsets = OffsetsBuffer::try_from(vec![0i64, 2, 3]).unwrap();
+        let list = ListArray::<i64>::new(list_dtype, offsets, strings, None).boxed();
+        let struct_dtype =
+            ArrowDataType::Struct(vec![Field::new("a".into(), list.dtype().clone(), true)]);
+        let values = StructArray::new(struct_dtype.clone(), 2, vec![list], None).boxed();
+        let field = Field::new("item".into(), struct_dtype, true);
+        let dtype = ArrowDataType::FixedSizeList(Box::new(field), 1);
+        let array = FixedSizeListArray::new(dtype.clone(), 2, values, None).boxed();
+
+        let opts = [RowEncodingOptions::default()];
+        let dicts = [Some(RowEncodingContext::StructWithOptions(vec![(
+            RowEncodingOptions::NULLS_LAST,
+            None,
+        )]))];
+        let rows = try_convert_columns(2, std::slice::from_ref(&array), &opts, &dicts).unwrap();
+        let mut rows = rows.iter().collect::<Vec<_>>();
+        let decoded = crate::decode::try_decode_rows(&mut rows, &opts, &dicts, &[dtype]).unwrap();
+        assert_eq!(decoded[0], array);
+        assert!(rows.iter().all(|row| row.is_empty()));
+    }
+
+    #[test]
+    fn test_encode_decode_zero_width_arrays() {
+        use arrow::array::{FixedSizeListArray, Int32Array, Utf8ViewArray};
+        use arrow::bitmap::Bitmap;
+        use arrow::datatypes::Field;
+
+        let validity = Bitmap::from([true, false, true, false]);
+        let values = [
+            Int32Array::from_slice([]).boxed(),
+            Utf8ViewArray::new_empty(ArrowDataType::Utf8View).boxed(),
+        ];
+        for values in values {
+            let field = Field::new("item".into(), values.dtype().clone(), true);
+            let dtype = ArrowDataType::FixedSizeList(Box::new(field), 0);
+            let array =
+                FixedSizeListArray::new(dtype.clone(), 4, values, Some(validity.clone())).boxed();
+
+            for opt in [
+                RowEncodingOptions::default(),
+                RowEncodingOptions::DESCENDING | RowEncodingOptions::NULLS_LAST,
+            ] {
+                let rows = convert_columns(4, std::slice::from_ref(&array), &[opt], &[None]);
+                assert_eq!(rows.offsets, [0, 1, 2, 3, 4]);
+
+                let mut rows = rows.iter().collect::<Vec<_>>();
+                let decoded = crate::decode::try_decode_rows(
+                    &mut rows,
+                    &[opt],
+                    &[None],
+                    std::slice::from_ref(&dtype),
+                )
+                .unwrap();
+                assert_eq!(decoded[0], array);
+                assert!(rows.iter().all(|row| row.is_empty()));
+            }
+        }
+    }
+
+    /// The order of two optional values sorted with `descending` and `nulls_last`.
+    fn value_order<T: Ord>(
+        a: Option<T>,
+        b: Option<T>,
+        descending: bool,
+        nulls_last: bool,
+    ) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+
+        match (a, b) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) if nulls_last => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) if nulls_last => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (Some(a), Some(b)) if descending => b.cmp(&a),
+            (Some(a), Some(b)) => a.cmp(&b),
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_compare_rows_matches_value_order
+            (
+                values in proptest::collection::vec(
+                    (proptest::option::of(-3..3i32), proptest::option::of(0..6usize)),
+                    0..30,
+                ),
+                sort_opts in proptest::collection::vec(
+                    (proptest::bool::ANY, proptest::bool::ANY),
+                    2,
+                ),
+            )
+        {
+            use arrow::array::{Int32Array, Utf8ViewArray};
+
+            const STRS: [&str; 6] = ["", "a", "ab", "b", "ba", "\u{10FFFF}"];
+            let ints = values.iter().map(|(i, _)| *i).collect::<Vec<_>>();
+            let strs = values.iter().map(|(_, s)| s.map(|s| STRS[s])).collect::<Vec<_>>();
+            let columns = [
+                Int32Array::from(&ints).boxed(),
+                Utf8ViewArray::from_slice(&strs).boxed(),
+            ];
+            let opts = sort_opts
+                .iter()
+                .map(|&(desc, nulls_last)| RowEncodingOptions::new_sorted(desc, nulls_last))
+                .collect::<Vec<_>>();
+            let rows = convert_columns(values.len(), &columns, &opts, &[None, None]);
+
+            for i in 0..values.len() {
+                for j in 0..values.len() {
+                    let (d0, n0) = sort_opts[0];
+                    let (d1, n1) = sort_opts[1];
+                    let expected = value_order(ints[i], ints[j], d0, n0)
+                        .then_with(|| value_order(strs[i], strs[j], d1, n1));
+                    proptest::prop_assert_eq!(crate::compare_rows_at(&rows, i, j), expected);
+                    let order = crate::compare_rows(rows.get(i), rows.get(j));
+                    proptest::prop_assert_eq!(order, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_into_nested_preserves_collation() {
+        for (descending, nulls_last) in [(false, false), (false, true), (true, false), (true, true)]
+        {
+            let opt = RowEncodingOptions::new_sorted(descending, nulls_last)
+                .with_collation(Collation::AsciiCaseInsensitive);
+            assert_eq!(
+                opt.into_nested().collation(),
+                Collation::AsciiCaseInsensitive
+            );
+        }
+        let opt = RowEncodingOptions::new_sorted(true, false);
+        assert_eq!(opt.into_nested().collation(), Collation::Binary);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_ascii_case_insensitive_order
+            (
+                values in proptest::collection::vec(proptest::option::of(0..10usize), 0..30),
+                descending in proptest::bool::ANY,
+                nulls_last in proptest::bool::ANY,
+            )
+        {
+            use arrow::array::{StructArray, Utf8ViewArray};
+            use arrow::datatypes::Field;
+
+            const STRS: [&str; 10] = ["", "a", "A", "ab", "aB", "Ab", "b", "B", "\u{e9}", "\u{c9}"];
+            let strs = values.iter().map(|s| s.map(|s| STRS[s])).collect::<Vec<_>>();
+            let lower = strs
+                .iter()
+                .map(|s| s.map(|s| s.to_ascii_lowercase()))
+                .collect::<Vec<_>>();
+
+            // The struct column nests the same strings, so the collation has to be kept when
+            // encoding its field.
+            let field_values = strs.iter().map(|s| s.unwrap_or("")).collect::<Vec<_>>();
+            let field = Utf8ViewArray::from_slice_values(field_values);
+            let validity = Bitmap::from_iter(strs.iter().map(|s| s.is_some()));
+            let dtype = ArrowDataType::Struct(vec![Field::new(
+                "s".into(),
+                field.dtype().clone(),
+                true,
+            )]);
+            let columns = [
+                Utf8ViewArray::from_slice(&strs).boxed(),
+                StructArray::new(dtype, strs.len(), vec![field.boxed()], Some(validity)).boxed(),
+            ];
+            let opt = RowEncodingOptions::new_sorted(descending, nulls_last)
+                .with_collation(Collation::AsciiCaseInsensitive);
+            let dicts = [None, Some(RowEncodingContext::Struct(vec![None]))];
+            let rows = convert_columns(strs.len(), &columns, &[opt, opt], &dicts);
+
+            for i in 0..strs.len() {
+                for j in 0..strs.len() {
+                    let expected = value_order(
+                        lower[i].as_deref(),
+                        lower[j].as_deref(),
+                        descending,
+                        nulls_last,
+                    );
+                    let order = crate::compare_rows(rows.get(i), rows.get(j));
+                    proptest::prop_assert_eq!(order, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dedup_variable_roundtrip_and_order() {
+        use arrow::array::{Int32Array, Utf8ViewArray};
+
+        const NUM_ROWS: usize = 1_000_000;
+        let urls = [
+            "https://example.com/products/category/electronics/phones?sort=price",
+            "https://example.com/products/category/electronics/laptops?sort=rating",
+            "https://example.com/account/settings/notifications/email/preferences",
+            "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko)",
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15",
+        ];
+        let strs = (0..NUM_ROWS)
+            .map(|i| (i % 11 != 0).then(|| urls[(i * 7) % urls.len()]))
+            .collect::<Vec<_>>();
+        let ints = (0..NUM_ROWS)
+            .map(|i| Some((i % 3) as i32))
+            .collect::<Vec<_>>();
+        let columns = [
+            Utf8ViewArray::from_slice(&strs).boxed(),
+            Int32Array::from(&ints).boxed(),
+        ];
+        let dtypes = [ArrowDataType::Utf8View, ArrowDataType::Int32];
+
+        for (descending, nulls_last) in [(false, false), (true, true)] {
+            let opt = RowEncodingOptions::new_sorted(descending, nulls_last);
+            let opts = [opt | RowEncodingOptions::DEDUP_VARIABLE, opt];
+            let (rows, dicts) = convert_columns_dedup(NUM_ROWS, &columns, &opts, &[None, None]);
+            let Some(RowEncodingContext::InlineDictionary(dictionary)) = &dicts[0] else {
+                panic!("expected an inline dictionary, got {:?}", dicts[0]);
+            };
+            assert_eq!(dictionary.values.len(), urls.len());
+            assert!(dicts[1].is_none());
+
+            // The ordinals are much smaller than the strings.
+            let plain = convert_columns(NUM_ROWS, &columns, &[opt, opt], &[None, None]);
+            assert_eq!(
+                rows.values.len(),
+                NUM_ROWS * (u32::ENCODED_LEN + i32::ENCODED_LEN)
+            );
+            assert!(rows.values.len() * 4 < plain.values.len());
+
+            for i in (0..NUM_ROWS).step_by(97) {
+                let j = (i * 7919 + 13) % NUM_ROWS;
+                let expected = value_order(strs[i], strs[j], descending, nulls_last)
+                    .then_with(|| value_order(ints[i], ints[j], descending, nulls_last));
+                assert_eq!(crate::compare_rows(rows.get(i), rows.get(j)), expected);
+            }
+
+            let mut rows = rows.iter().collect::<Vec<_>>();
+            let decoded =
+                crate::decode::try_decode_rows(&mut rows, &opts, &dicts, &dtypes).unwrap();
+            assert_eq!(decoded, columns);
+        }
+    }
+
+    #[test]
+    fn test_dedup_variable_skips_distinct_strings() {
+        use arrow::array::Utf8ViewArray;
+
+        let strs = (0..1000)
+            .map(|i| format!("a string that is long enough to deduplicate {i}"))
+            .collect::<Vec<_>>();
+        let columns = [Utf8ViewArray::from_slice_values(&strs).boxed()];
+        let opt = RowEncodingOptions::default() | RowEncodingOptions::DEDUP_VARIABLE;
+
+        let (rows, dicts) = convert_columns_dedup(strs.len(), &columns, &[opt], &[None]);
+        assert!(dicts[0].is_none());
+        let plain = convert_columns(strs.len(), &columns, &[opt], &[None]);
+        assert_eq!(rows.values, plain.values);
+
+        // Inline dictionaries can only be used to decode.
+        let dictionary = RowEncodingContext::InlineDictionary(RowEncodingInlineDictionary {
+            values: Utf8ViewArray::from_slice_values(&strs[..1]),
+        });
+        let err = try_convert_columns(strs.len(), &columns, &[opt], &[Some(dictionary)]);
+        assert!(matches!(err, Err(RowEncodeError::ContextMismatch { .. })));
+    }
+
+    #[test]
+    fn test_non_nullable_skips_validity_bytes() {
+        use arrow::array::{FixedSizeListArray, Float32Array, StructArray, Utf8ViewArray};
+        use arrow::datatypes::Field;
+
+        let fsl = |values: Box<dyn Array>, width: usize, validity: Option<Bitmap>| {
+            let field = Field::new("item".into(), values.dtype().clone(), true);
+            let dtype = ArrowDataType::FixedSizeList(Box::new(field), width);
+            FixedSizeListArray::new(dtype, values.len() / width, values, validity).boxed()
+        };
+        let strct = |values: Vec<Box<dyn Array>>, validity: Option<Bitmap>| {
+            let fields = values
+                .iter()
+                .enumerate()
+                .map(|(i, v)| Field::new(format!("f{i}").into(), v.dtype().clone(), true))
+                .collect();
+            let len = values[0].len();
+            StructArray::new(ArrowDataType::Struct(fields), len, values, validity).boxed()
+        };
+
+        let floats = Float32Array::from_iter((0..24).map(|i| (i % 5 != 0).then_some(i as f32)));
+        let strs = Utf8ViewArray::from_slice_values(["a", "bc", "", "def"]).boxed();
+        // Each column with the number of fixed-size lists and structs in every row.
+        let columns = [
+            (fsl(floats.clone().boxed(), 6, None), 1),
+            (fsl(fsl(floats.clone().boxed(), 2, None), 3, None), 4),
+            (strct(vec![fsl(floats.boxed(), 6, None), strs], None), 2),
+        ];
+
+        for (column, num_nested) in columns {
+            let dtype = column.dtype().clone();
+            for opt in [
+                RowEncodingOptions::default(),
+                RowEncodingOptions::DESCENDING | RowEncodingOptions::NULLS_LAST,
+                RowEncodingOptions::NO_ORDER,
+            ] {
+                let columns = [column.clone()];
+                let nullable = convert_columns(4, &columns, &[opt], &[None]);
+                let opt = opt | RowEncodingOptions::NON_NULLABLE;
+                let rows = convert_columns(4, &columns, &[opt], &[None]);
+                for i in 0..4 {
+                    assert_eq!(rows.get(i).len() + num_nested, nullable.get(i).len());
+                }
+
+                let mut rows = rows.iter().collect::<Vec<_>>();
+                let decoded = crate::decode::try_decode_rows(
+                    &mut rows,
+                    &[opt],
+                    &[None],
+                    std::slice::from_ref(&dtype),
+                )
+                .unwrap();
+                assert_eq!(decoded[0], column);
+            }
+        }
+
+        let opts = [RowEncodingOptions::NON_NULLABLE];
+        let validity = Some(Bitmap::from([true, false, true, true]));
+        let inner = fsl(Float32Array::from_slice([1.0; 8]).boxed(), 2, validity);
+        let column = strct(vec![inner], None);
+        let err = try_convert_columns(4, &[column], &opts, &[None]);
+        assert_eq!(
+            err.err().unwrap(),
+            RowEncodeError::UnexpectedNull {
+                path: "0.f0".to_string()
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "checked-kernels")]
+    #[should_panic(expected = "out of range for slice of length 2")]
+    fn test_checked_kernels_truncated_row() {
+        let columns = [PrimitiveArray::from_slice([1i32, 2]).boxed()];
+        let opts = [RowEncodingOptions::default()];
+        let rows = convert_columns(2, &columns, &opts, &[None]);
+
+        let mut rows = rows.iter().map(|row| &row[..2]).collect::<Vec<_>>();
+        let dtypes = [ArrowDataType::Int32];
+        unsafe { crate::decode::decode_rows(&mut rows, &opts, &[None], &dtypes) };
+    }
+
+    #[cfg(feature = "checked-kernels")]
+    proptest::proptest! {
+        #[test]
+        fn test_checked_kernels_decode_truncated_rows
+            (
+                arrays in arrays(),
+                opts in proptest::collection::vec(row_encoding_options(), 2),
+                cuts in proptest::collection::vec(proptest::prelude::any::<u32>(), 100),
+            )
+        {
+            let opts = &opts[..arrays.len()];
+            let dicts: Vec<Option<RowEncodingContext>> = (0..arrays.len()).map(|_| None).collect();
+            let rows = convert_columns(arrays[0].len(), &arrays, opts, &dicts);
+
+            // Decoding rows that end early has to panic instead of reading past their end.
+            let mut truncated = false;
+            let mut rows = rows
+                .iter()
+                .zip(&cuts)
+                .map(|(row, &cut)| {
+                    let len = cut as usize % (row.len() + 1);
+                    truncated |= len < row.len();
+                    &row[..len]
+                })
+                .collect::<Vec<_>>();
+            let dtypes = arrays.iter().map(|array| array.dtype().clone()).collect::<Vec<_>>();
+            let decoded = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unsafe {
+                crate::decode::decode_rows(&mut rows, opts, &dicts, &dtypes)
+            }));
+            if !truncated {
+                proptest::prop_assert!(decoded.is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_append_columns_reuses_encoders() {
+        use arrow::array::Int64Array;
+        use arrow::datatypes::Field;
+        use arrow::offset::OffsetsBuffer;
+
+        // `{x: list[{x: list[{x: list[i64], y: i64}], y: i64}], y: i64}`
+        let mut array =
+            Int64Array::from_iter((0..64i64).map(|i| (i % 5 != 0).then_some(i))).boxed();
+        for _ in 0..3 {
+            let offsets = (0..=array.len() as i64).step_by(2).collect::<Vec<_>>();
+            let list = ListArray::<i64>::new(
+                ListArray::<i64>::default_datatype(array.dtype().clone()),
+                OffsetsBuffer::try_from(offsets).unwrap(),
+                array,
+                None,
+            )
+            .boxed();
+            let y = Int64Array::from_iter((0..list.len() as i64).map(Some)).boxed();
+            let dtype = ArrowDataType::Struct(vec![
+                Field::new("x".into(), list.dtype().clone(), true),
+                Field::new("y".into(), y.dtype().clone(), true),
+            ]);
+            array = StructArray::new(dtype, list.len(), vec![list, y], None).boxed();
+        }
+
+        let opts = [RowEncodingOptions::new_sorted(false, false)];
+        let dicts = [None];
+        let len = array.len();
+        let expected = convert_columns(len, std::slice::from_ref(&array), &opts, &dicts);
+
+        // The encoders of every chunk are built in the same allocations as those of the first.
+        let mut rows = RowsEncoded::default();
+        let mut allocations = None;
+        for offset in (0..len).step_by(2) {
+            let fields = opts.iter().copied().zip(dicts.iter().map(|v| v.as_ref()));
+            rows.append_columns(2, &[sliced_normalized(array.as_ref(), offset, 2)], fields);
+
+            let arena = &rows.encoders;
+            assert!(arena.encoders.is_empty() && arena.struct_fields.is_empty());
+            let current = (
+                arena.encoders.as_ptr(),
+                arena.encoders.capacity(),
+                arena.struct_fields.as_ptr(),
+                arena.struct_fields.capacity(),
+            );
+            assert_eq!(*allocations.get_or_insert(current), current);
+        }
+        assert_eq!(rows.values, expected.values);
+        assert_eq!(rows.offsets, expected.offsets);
+    }
 }
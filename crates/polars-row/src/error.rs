@@ -0,0 +1,101 @@
+use std::fmt;
+
+use arrow::datatypes::ArrowDataType;
+use polars_error::{PolarsError, polars_err};
+
+/// Error returned by the fallible row encoding entry points, such as
+/// [`try_convert_columns`](crate::try_convert_columns) and
+/// [`try_decode_rows`](crate::decode::try_decode_rows).
+///
+/// Paths point to the (nested) value that caused the error. They start with the index of the
+/// column, followed by `.name` for every struct field and `[]` for the elements of every
+/// (fixed-size) list, e.g. `1.a[]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RowEncodeError {
+    /// The (nested) dtype has no row encoding.
+    UnsupportedDtype { dtype: ArrowDataType, path: String },
+    /// No row encoding options and context were given for the column.
+    MissingContext { column: usize },
+    /// The row encoding context doesn't fit the (nested) dtype it is given for.
+    ContextMismatch { path: String, reason: String },
+    /// The encoded size of the (nested) dtype doesn't fit in a `usize`.
+    Overflow { path: String },
+    /// The row ends before the encoded value of the column does.
+    Truncated { row: usize, column: usize },
+    /// The row contains bytes that can't occur in an encoded value of the column.
+    Malformed {
+        row: usize,
+        column: usize,
+        reason: &'static str,
+    },
+    /// A (nested) fixed-size list or struct of a column encoded with
+    /// [`RowEncodingOptions::NON_NULLABLE`](crate::RowEncodingOptions::NON_NULLABLE) is null.
+    UnexpectedNull { path: String },
+    /// The column doesn't have a value for every row.
+    LengthMismatch {
+        column: usize,
+        expected: usize,
+        got: usize,
+    },
+}
+
+impl fmt::Display for RowEncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedDtype { dtype, path } => {
+                write!(f, "row encoding is not supported for {dtype:?} at {path}")
+            },
+            Self::MissingContext { column } => write!(
+                f,
+                "missing row encoding options and context for column {column}"
+            ),
+            Self::ContextMismatch { path, reason } => {
+                write!(f, "invalid row encoding context at {path}: {reason}")
+            },
+            Self::Overflow { path } => {
+                write!(f, "row encoded size overflows at {path}")
+            },
+            Self::Truncated { row, column } => {
+                write!(f, "row {row} ends within the encoding of column {column}")
+            },
+            Self::Malformed {
+                row,
+                column,
+                reason,
+            } => write!(
+                f,
+                "row {row} has an invalid encoding of column {column}: {reason}"
+            ),
+            Self::UnexpectedNull { path } => {
+                write!(f, "null value at {path} of a non-nullable column")
+            },
+            Self::LengthMismatch {
+                column,
+                expected,
+                got,
+            } => write!(
+                f,
+                "expected {expected} rows for column {column}, got {got} values"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RowEncodeError {}
+
+impl From<RowEncodeError> for PolarsError {
+    fn from(err: RowEncodeError) -> Self {
+        match err {
+            RowEncodeError::UnsupportedDtype { .. } => polars_err!(InvalidOperation: "{err}"),
+            RowEncodeError::MissingContext { .. } | RowEncodeError::ContextMismatch { .. } => {
+                polars_err!(SchemaMismatch: "{err}")
+            },
+            RowEncodeError::LengthMismatch { .. } => polars_err!(ShapeMismatch: "{err}"),
+            RowEncodeError::Overflow { .. }
+            | RowEncodeError::UnexpectedNull { .. }
+            | RowEncodeError::Truncated { .. }
+            | RowEncodeError::Malformed { .. } => polars_err!(ComputeError: "{err}"),
+        }
+    }
+}
@@ -15,6 +15,7 @@ use arrow::array::BooleanArray;
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::ArrowDataType;
 
+use crate::checked::MaybeUnchecked;
 use crate::row::RowEncodingOptions;
 
 pub(crate) unsafe fn encode_bool<I: Iterator<Item = Option<bool>>>(
@@ -34,31 +35,37 @@ pub(crate) unsafe fn encode_bool<I: Iterator<Item = Option<bool>>>(
             Some(true) => true_sentinel,
         };
 
-        *buffer.get_unchecked_mut(*offset) = MaybeUninit::new(b);
+        *buffer.get_maybe_unchecked_mut(*offset) = MaybeUninit::new(b);
         *offset += 1;
     }
 }
 
+/// Decode the value at the start of `row` without decoding into an array.
+pub unsafe fn decode_bool_value(row: &[u8], opt: RowEncodingOptions) -> Option<bool> {
+    let b = *row.get_maybe_unchecked(0);
+    (b != opt.null_sentinel()).then_some(b == opt.bool_true_sentinel())
+}
+
 pub(crate) unsafe fn decode_bool(rows: &mut [&[u8]], opt: RowEncodingOptions) -> BooleanArray {
     let mut has_nulls = false;
     let null_sentinel = opt.null_sentinel();
     let true_sentinel = opt.bool_true_sentinel();
 
     let values = Bitmap::from_trusted_len_iter_unchecked(rows.iter().map(|row| {
-        let b = *row.get_unchecked(0);
+        let b = *row.get_maybe_unchecked(0);
         has_nulls |= b == null_sentinel;
         b == true_sentinel
     }));
 
     if !has_nulls {
         rows.iter_mut()
-            .for_each(|row| *row = row.get_unchecked(1..));
+            .for_each(|row| *row = row.get_maybe_unchecked(1..));
         return BooleanArray::new(ArrowDataType::Boolean, values, None);
     }
 
     let validity = Bitmap::from_trusted_len_iter_unchecked(rows.iter_mut().map(|row| {
-        let v = *row.get_unchecked(0) != null_sentinel;
-        *row = row.get_unchecked(1..);
+        let v = *row.get_maybe_unchecked(0) != null_sentinel;
+        *row = row.get_maybe_unchecked(1..);
         v
     }));
     BooleanArray::new(ArrowDataType::Boolean, values, Some(validity))
@@ -0,0 +1,121 @@
+//! Byte order of the order-preserving fixed-size encodings.
+//!
+//! Fixed-size values are stored most significant byte first, so that comparing the encoded bytes
+//! lexicographically compares the (transformed) values. These are the only places where integers
+//! are turned into encoded bytes or back. They are defined on values rather than on their memory
+//! representation, so they give the same bytes on little- and big-endian hosts.
+
+/// Conversion of an integer from and to its big-endian encoded bytes.
+pub(crate) trait BigEndianBytes: Copy {
+    type Bytes: Copy + Default + AsRef<[u8]> + AsMut<[u8]>;
+
+    fn to_be_row_bytes(self) -> Self::Bytes;
+
+    fn from_be_row_bytes(bytes: Self::Bytes) -> Self;
+}
+
+macro_rules! impl_big_endian_bytes {
+    ($t:ty, $n:literal) => {
+        impl BigEndianBytes for $t {
+            type Bytes = [u8; $n];
+
+            #[inline(always)]
+            fn to_be_row_bytes(self) -> [u8; $n] {
+                self.to_be_bytes()
+            }
+
+            #[inline(always)]
+            fn from_be_row_bytes(bytes: [u8; $n]) -> Self {
+                <$t>::from_be_bytes(bytes)
+            }
+        }
+    };
+}
+
+impl_big_endian_bytes!(u8, 1);
+impl_big_endian_bytes!(u16, 2);
+impl_big_endian_bytes!(u32, 4);
+impl_big_endian_bytes!(u64, 8);
+impl_big_endian_bytes!(u128, 16);
+impl_big_endian_bytes!(i8, 1);
+impl_big_endian_bytes!(i16, 2);
+impl_big_endian_bytes!(i32, 4);
+impl_big_endian_bytes!(i64, 8);
+impl_big_endian_bytes!(i128, 16);
+
+/// Write the `out.len()` least significant bytes of `value`, most significant byte first.
+///
+/// This is used for the truncated decimal encoding.
+#[inline(always)]
+pub(crate) fn write_be_suffix(value: u128, out: &mut [u8]) {
+    let bytes = value.to_be_row_bytes();
+    out.copy_from_slice(&bytes[16 - out.len()..]);
+}
+
+/// Read the value written by [`write_be_suffix`], with the missing most significant bytes set to
+/// zero.
+#[inline(always)]
+pub(crate) fn read_be_suffix(bytes: &[u8]) -> u128 {
+    let mut padded = [0u8; 16];
+    padded[16 - bytes.len()..].copy_from_slice(bytes);
+    u128::from_be_row_bytes(padded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_byte_patterns() {
+        assert_eq!(0xABu8.to_be_row_bytes(), [0xAB]);
+        assert_eq!(0x0102u16.to_be_row_bytes(), [0x01, 0x02]);
+        assert_eq!(0x0102_0304u32.to_be_row_bytes(), [1, 2, 3, 4]);
+        assert_eq!(
+            0x0102_0304_0506_0708u64.to_be_row_bytes(),
+            [1, 2, 3, 4, 5, 6, 7, 8]
+        );
+        assert_eq!(
+            0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10u128.to_be_row_bytes(),
+            [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]
+        );
+
+        assert_eq!((-2i16).to_be_row_bytes(), [0xFF, 0xFE]);
+        assert_eq!(i32::MIN.to_be_row_bytes(), [0x80, 0, 0, 0]);
+
+        assert_eq!(u16::from_be_row_bytes([0x01, 0x02]), 0x0102);
+        assert_eq!(i16::from_be_row_bytes([0xFF, 0xFE]), -2);
+        assert_eq!(u32::from_be_row_bytes([1, 2, 3, 4]), 0x0102_0304);
+        assert_eq!(
+            u64::from_be_row_bytes([1, 2, 3, 4, 5, 6, 7, 8]),
+            0x0102_0304_0506_0708
+        );
+        assert_eq!(
+            u128::from_be_row_bytes([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]),
+            0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10
+        );
+    }
+
+    #[test]
+    fn test_be_suffix() {
+        let mut out = [0u8; 3];
+        write_be_suffix(0xFF01_0203, &mut out);
+        assert_eq!(out, [0x01, 0x02, 0x03]);
+        assert_eq!(read_be_suffix(&out), 0x0001_0203);
+
+        let mut out = [0u8; 16];
+        write_be_suffix(u128::MAX - 1, &mut out);
+        assert_eq!(read_be_suffix(&out), u128::MAX - 1);
+    }
+
+    proptest::proptest! {
+        // Only compares the produced bytes, so a byte order bug fails on any host.
+        #[test]
+        fn test_be_bytes_order(a: u64, b: u64, c: u128, d: u128, e: u16, f: u16) {
+            proptest::prop_assert_eq!(a.to_be_row_bytes().cmp(&b.to_be_row_bytes()), a.cmp(&b));
+            proptest::prop_assert_eq!(c.to_be_row_bytes().cmp(&d.to_be_row_bytes()), c.cmp(&d));
+            proptest::prop_assert_eq!(e.to_be_row_bytes().cmp(&f.to_be_row_bytes()), e.cmp(&f));
+            proptest::prop_assert_eq!(u64::from_be_row_bytes(a.to_be_row_bytes()), a);
+            proptest::prop_assert_eq!(u128::from_be_row_bytes(c.to_be_row_bytes()), c);
+        }
+    }
+}
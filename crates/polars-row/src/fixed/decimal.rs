@@ -11,6 +11,8 @@ use arrow::bitmap::BitmapBuilder;
 use arrow::datatypes::ArrowDataType;
 use polars_utils::slice::Slice2Uninit;
 
+use super::byte_order::{read_be_suffix, write_be_suffix};
+use crate::checked::MaybeUnchecked;
 use crate::row::RowEncodingOptions;
 
 macro_rules! with_constant_num_bytes {
@@ -96,8 +98,10 @@ pub unsafe fn encode_slice(
             v ^= invert_mask; // Invert for descending
             v |= valid_mask; // Add valid indicator
 
-            unsafe { buffer.get_unchecked_mut(*offset..*offset + num_bytes) }
-                .copy_from_slice(v.to_be_bytes()[16 - num_bytes..].as_uninit());
+            let mut bytes = [0u8; num_bytes];
+            write_be_suffix(v as u128, &mut bytes);
+            unsafe { buffer.get_maybe_unchecked_mut(*offset..*offset + num_bytes) }
+                .copy_from_slice(bytes.as_uninit());
             *offset += num_bytes;
         }
     });
@@ -130,27 +134,58 @@ pub unsafe fn encode_iter(
 
     with_constant_num_bytes!(num_bytes, {
         for (offset, v) in offsets.iter_mut().zip(input) {
-            match v {
-                None => {
-                    unsafe { buffer.get_unchecked_mut(*offset..*offset + num_bytes) }
-                        .copy_from_slice(null_value.to_be_bytes()[16 - num_bytes..].as_uninit());
-                },
+            let v = match v {
+                None => null_value,
                 Some(mut v) => {
                     v &= mask; // Mask out higher sign extension bits
                     v ^= sign_mask; // Flip sign-bit to maintain order
                     v ^= invert_mask; // Invert for descending
                     v |= valid_mask; // Add valid indicator
-
-                    unsafe { buffer.get_unchecked_mut(*offset..*offset + num_bytes) }
-                        .copy_from_slice(v.to_be_bytes()[16 - num_bytes..].as_uninit());
+                    v
                 },
-            }
+            };
+
+            let mut bytes = [0u8; num_bytes];
+            write_be_suffix(v as u128, &mut bytes);
+            unsafe { buffer.get_maybe_unchecked_mut(*offset..*offset + num_bytes) }
+                .copy_from_slice(bytes.as_uninit());
 
             *offset += num_bytes;
         }
     });
 }
 
+/// Decode the value at the start of `row` without decoding into an array.
+pub unsafe fn decode_value(row: &[u8], opt: RowEncodingOptions, precision: usize) -> Option<i128> {
+    let num_bits = num_bits_from_precision(precision);
+    // If the input does not fit in less bytes, it used the normal i128 encoding kernel.
+    if num_bits >= 127 {
+        return super::numeric::decode_value(row, opt);
+    }
+
+    if *row.get_maybe_unchecked(0) == opt.null_sentinel() {
+        return None;
+    }
+
+    let num_bytes = len_from_num_bits(num_bits);
+    let mask = (1 << (num_bits + 1)) - 1;
+    let sign_mask = 1 << num_bits;
+    let invert_mask = if opt.contains(RowEncodingOptions::DESCENDING) {
+        mask
+    } else {
+        0
+    };
+
+    let mut value = read_be_suffix(row.get_maybe_unchecked(..num_bytes)) as i128;
+    value ^= invert_mask; // Invert for descending
+    value ^= sign_mask; // Flip sign bit to maintain order
+
+    // Sign extend. This also masks out the valid bit.
+    value <<= i128::BITS - num_bits as u32 - 1;
+    value >>= i128::BITS - num_bits as u32 - 1;
+    Some(value)
+}
+
 pub unsafe fn decode(
     rows: &mut [&[u8]],
     opt: RowEncodingOptions,
@@ -178,18 +213,11 @@ pub unsafe fn decode(
     with_constant_num_bytes!(num_bytes, {
         values.extend(
             rows.iter_mut()
-                .take_while(|row| *unsafe { row.get_unchecked(0) } != null_sentinel)
+                .take_while(|row| *unsafe { row.get_maybe_unchecked(0) } != null_sentinel)
                 .map(|row| {
-                    let mut value = 0i128;
-                    let value_ref: &mut [u8; 16] = bytemuck::cast_mut(&mut value);
-                    value_ref[16 - num_bytes..].copy_from_slice(row.get_unchecked(..num_bytes));
+                    let mut value = read_be_suffix(row.get_maybe_unchecked(..num_bytes)) as i128;
                     *row = &row[num_bytes..];
 
-                    if cfg!(target_endian = "little") {
-                        // Big-Endian -> Little-Endian
-                        value = value.swap_bytes();
-                    }
-
                     value ^= invert_mask; // Invert for descending
                     value ^= sign_mask; // Flip sign bit to maintain order
 
@@ -213,18 +241,11 @@ pub unsafe fn decode(
 
     with_constant_num_bytes!(num_bytes, {
         values.extend(rows[start_len..].iter_mut().map(|row| {
-            validity.push(*unsafe { row.get_unchecked(0) } != null_sentinel);
+            validity.push(*unsafe { row.get_maybe_unchecked(0) } != null_sentinel);
 
-            let mut value = 0i128;
-            let value_ref: &mut [u8; 16] = bytemuck::cast_mut(&mut value);
-            value_ref[16 - num_bytes..].copy_from_slice(row.get_unchecked(..num_bytes));
+            let mut value = read_be_suffix(row.get_maybe_unchecked(..num_bytes)) as i128;
             *row = &row[num_bytes..];
 
-            if cfg!(target_endian = "little") {
-                // Big-Endian -> Little-Endian
-                value = value.swap_bytes();
-            }
-
             value ^= invert_mask; // Invert for descending
             value ^= sign_mask; // Flip sign bit to maintain order
 
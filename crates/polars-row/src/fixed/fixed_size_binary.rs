@@ -0,0 +1,77 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+//! Row encoding for FixedSizeBinary
+//!
+//! Each value is encoded as a validity byte followed by the `size` raw bytes, which are inverted
+//! for descending order. The bytes of a null value are zeroed.
+
+use std::mem::MaybeUninit;
+
+use arrow::array::FixedSizeBinaryArray;
+use arrow::buffer::Buffer;
+use arrow::datatypes::ArrowDataType;
+use polars_utils::slice::Slice2Uninit;
+
+use super::numeric::decode_nulls;
+use crate::checked::MaybeUnchecked;
+use crate::row::RowEncodingOptions;
+
+pub(crate) unsafe fn encode(
+    buffer: &mut [MaybeUninit<u8>],
+    array: &FixedSizeBinaryArray,
+    opt: RowEncodingOptions,
+    offsets: &mut [usize],
+) {
+    let size = array.size();
+    let descending = opt.contains(RowEncodingOptions::DESCENDING);
+    let null_sentinel = opt.null_sentinel();
+
+    for (offset, value) in offsets.iter_mut().zip(array.iter()) {
+        let dst = buffer.get_maybe_unchecked_mut(*offset..*offset + 1 + size);
+        match value {
+            None => {
+                dst[0] = MaybeUninit::new(null_sentinel);
+                dst[1..].fill(MaybeUninit::new(0));
+            },
+            Some(value) if descending => {
+                dst[0] = MaybeUninit::new(1);
+                for (d, &b) in dst[1..].iter_mut().zip(value) {
+                    *d = MaybeUninit::new(!b);
+                }
+            },
+            Some(value) => {
+                dst[0] = MaybeUninit::new(1);
+                dst[1..].copy_from_slice(value.as_uninit());
+            },
+        }
+        *offset += 1 + size;
+    }
+}
+
+pub(crate) unsafe fn decode(
+    rows: &mut [&[u8]],
+    opt: RowEncodingOptions,
+    dtype: &ArrowDataType,
+    size: usize,
+) -> FixedSizeBinaryArray {
+    let descending = opt.contains(RowEncodingOptions::DESCENDING);
+    let null_sentinel = opt.null_sentinel();
+
+    let mut has_nulls = false;
+    let mut values = Vec::with_capacity(rows.len() * size);
+    for row in rows.iter() {
+        has_nulls |= *row.get_maybe_unchecked(0) == null_sentinel;
+        let value = row.get_maybe_unchecked(1..1 + size);
+        if descending {
+            values.extend(value.iter().map(|b| !b));
+        } else {
+            values.extend_from_slice(value);
+        }
+    }
+
+    let validity = has_nulls.then(|| decode_nulls(rows, null_sentinel));
+    for row in rows.iter_mut() {
+        *row = row.get_maybe_unchecked(1 + size..);
+    }
+
+    FixedSizeBinaryArray::new(dtype.clone(), Buffer::from(values), validity)
+}
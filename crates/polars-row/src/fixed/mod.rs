@@ -14,5 +14,7 @@ macro_rules! with_arms {
 }
 
 pub mod boolean;
+pub(crate) mod byte_order;
 pub mod decimal;
+pub mod fixed_size_binary;
 pub mod numeric;
@@ -1,6 +1,10 @@
 #![allow(unsafe_op_in_unsafe_fn)]
 use std::fmt::Debug;
 use std::mem::MaybeUninit;
+#[cfg(all(feature = "simd", target_endian = "little"))]
+use std::simd::Simd;
+#[cfg(all(feature = "simd", target_endian = "little"))]
+use std::simd::num::{SimdInt, SimdUint};
 
 use arrow::array::{Array, PrimitiveArray};
 use arrow::bitmap::Bitmap;
@@ -10,6 +14,8 @@ use polars_utils::float16::pf16;
 use polars_utils::slice::*;
 use polars_utils::total_ord::{canonical_f16, canonical_f32, canonical_f64};
 
+use super::byte_order::BigEndianBytes;
+use crate::checked::MaybeUnchecked;
 use crate::row::RowEncodingOptions;
 pub(crate) trait FromSlice {
     fn from_slice(slice: &[u8]) -> Self;
@@ -28,10 +34,24 @@ pub trait FixedLengthEncoding: Copy + Debug {
     // bit repr of encoding
     const ENCODED_LEN: usize = 1 + size_of::<Self::Encoded>();
 
-    type Encoded: Sized + Copy + AsRef<[u8]> + AsMut<[u8]>;
+    type Encoded: Sized + Copy + Default + AsRef<[u8]> + AsMut<[u8]>;
 
     fn encode(self) -> Self::Encoded;
 
+    /// Encode a block of values at once, inverting the bits if `descending`.
+    ///
+    /// Must give the same result as encoding the values one by one.
+    fn encode_block(values: &[Self], descending: bool, out: &mut [Self::Encoded]) {
+        for (value, out) in values.iter().zip(out) {
+            *out = value.encode();
+            if descending {
+                for v in out.as_mut() {
+                    *v = !*v
+                }
+            }
+        }
+    }
+
     fn decode(encoded: Self::Encoded) -> Self;
 
     fn decode_reverse(mut encoded: Self::Encoded) -> Self {
@@ -42,60 +62,95 @@ pub trait FixedLengthEncoding: Copy + Debug {
     }
 }
 
+/// Number of values in a SIMD vector when encoding integers.
+#[cfg(all(feature = "simd", target_endian = "little"))]
+const INT_LANES: usize = 16;
+
+// Integers are encoded as the big endian bytes of `value ^ mask`, where `mask` toggles the sign
+// bit for signed integers and inverts all bits when descending. This is done with SIMD for the
+// full vectors in a block. 128-bit integers are not supported by SIMD and use the default.
+//
+// The SIMD kernel swaps the bytes of every lane to get the big endian bytes, which is only
+// correct on little endian hosts. Big endian hosts always use the scalar loop.
+macro_rules! encode_int_block {
+    (simd, $n:expr, $t:ty, $sign_bit:expr) => {
+        fn encode_block(values: &[Self], descending: bool, out: &mut [[u8; $n]]) {
+            let mask: $t = if descending { !$sign_bit } else { $sign_bit };
+
+            #[cfg(all(feature = "simd", target_endian = "little"))]
+            let (values, out) = {
+                let num_full = values.len().min(out.len()) / INT_LANES * INT_LANES;
+                let (full_values, values) = values.split_at(num_full);
+                let (full_out, out) = out.split_at_mut(num_full);
+                let mask = Simd::<$t, INT_LANES>::splat(mask);
+                for (values, out) in full_values
+                    .chunks_exact(INT_LANES)
+                    .zip(full_out.chunks_exact_mut(INT_LANES))
+                {
+                    let encoded = (Simd::<$t, INT_LANES>::from_slice(values) ^ mask).swap_bytes();
+                    let encoded: [[u8; $n]; INT_LANES] = bytemuck::cast(encoded.to_array());
+                    out.copy_from_slice(&encoded);
+                }
+                (values, out)
+            };
+
+            for (value, out) in values.iter().zip(out) {
+                *out = (value ^ mask).to_be_row_bytes();
+            }
+        }
+    };
+}
+
 // encode as big endian
 macro_rules! encode_unsigned {
-    ($n:expr, $t:ty) => {
+    ($n:expr, $t:ty $(, $simd:ident)?) => {
         impl FixedLengthEncoding for $t {
             type Encoded = [u8; $n];
 
             fn encode(self) -> [u8; $n] {
-                self.to_be_bytes()
+                self.to_be_row_bytes()
             }
 
+            $(encode_int_block!($simd, $n, $t, 0);)?
+
             fn decode(encoded: Self::Encoded) -> Self {
-                Self::from_be_bytes(encoded)
+                Self::from_be_row_bytes(encoded)
             }
         }
     };
 }
 
-encode_unsigned!(1, u8);
-encode_unsigned!(2, u16);
-encode_unsigned!(4, u32);
-encode_unsigned!(8, u64);
+encode_unsigned!(1, u8, simd);
+encode_unsigned!(2, u16, simd);
+encode_unsigned!(4, u32, simd);
+encode_unsigned!(8, u64, simd);
 encode_unsigned!(16, u128);
 
-// toggle the sign bit and then encode as big indian
+// toggle the sign bit and then encode as big endian
 macro_rules! encode_signed {
-    ($n:expr, $t:ty) => {
+    ($n:expr, $t:ty $(, $simd:ident)?) => {
         impl FixedLengthEncoding for $t {
             type Encoded = [u8; $n];
 
             fn encode(self) -> [u8; $n] {
-                #[cfg(target_endian = "big")]
-                {
-                    todo!()
-                }
-
-                let mut b = self.to_be_bytes();
                 // Toggle top "sign" bit to ensure consistent sort order
-                b[0] ^= 0x80;
-                b
+                (self ^ <$t>::MIN).to_be_row_bytes()
             }
 
-            fn decode(mut encoded: Self::Encoded) -> Self {
+            fn decode(encoded: Self::Encoded) -> Self {
                 // Toggle top "sign" bit
-                encoded[0] ^= 0x80;
-                Self::from_be_bytes(encoded)
+                Self::from_be_row_bytes(encoded) ^ <$t>::MIN
             }
+
+            $(encode_int_block!($simd, $n, $t, <$t>::MIN);)?
         }
     };
 }
 
-encode_signed!(1, i8);
-encode_signed!(2, i16);
-encode_signed!(4, i32);
-encode_signed!(8, i64);
+encode_signed!(1, i8, simd);
+encode_signed!(2, i16, simd);
+encode_signed!(4, i32, simd);
+encode_signed!(8, i64, simd);
 encode_signed!(16, i128);
 
 impl FixedLengthEncoding for pf16 {
@@ -174,7 +229,7 @@ unsafe fn encode_value<T: FixedLengthEncoding>(
     buf: &mut [MaybeUninit<u8>],
 ) {
     let end_offset = *offset + T::ENCODED_LEN;
-    let dst = unsafe { buf.get_unchecked_mut(*offset..end_offset) };
+    let dst = unsafe { buf.get_maybe_unchecked_mut(*offset..end_offset) };
     // set valid
     dst[0] = MaybeUninit::new(1);
     let mut encoded = value.encode();
@@ -200,17 +255,20 @@ unsafe fn encode_opt_value<T: FixedLengthEncoding>(
     if let Some(value) = opt_value {
         encode_value(&value, offset, descending, buffer);
     } else {
-        unsafe { *buffer.get_unchecked_mut(*offset) = MaybeUninit::new(opt.null_sentinel()) };
+        unsafe { *buffer.get_maybe_unchecked_mut(*offset) = MaybeUninit::new(opt.null_sentinel()) };
         let end_offset = *offset + T::ENCODED_LEN;
 
         // initialize remaining bytes
-        let remainder = unsafe { buffer.get_unchecked_mut(*offset + 1..end_offset) };
+        let remainder = unsafe { buffer.get_maybe_unchecked_mut(*offset + 1..end_offset) };
         remainder.fill(MaybeUninit::new(0));
 
         *offset = end_offset;
     }
 }
 
+/// Number of values that are encoded at once before being written to their rows.
+const BLOCK_SIZE: usize = 64;
+
 pub(crate) unsafe fn encode_slice<T: FixedLengthEncoding>(
     buffer: &mut [MaybeUninit<u8>],
     input: &[T],
@@ -218,8 +276,22 @@ pub(crate) unsafe fn encode_slice<T: FixedLengthEncoding>(
     row_starts: &mut [usize],
 ) {
     let descending = opt.contains(RowEncodingOptions::DESCENDING);
-    for (offset, value) in row_starts.iter_mut().zip(input) {
-        encode_value(value, offset, descending, buffer);
+    let mut block = [T::Encoded::default(); BLOCK_SIZE];
+    for (values, row_starts) in input
+        .chunks(BLOCK_SIZE)
+        .zip(row_starts.chunks_mut(BLOCK_SIZE))
+    {
+        let block = &mut block[..values.len()];
+        T::encode_block(values, descending, block);
+
+        for (offset, encoded) in row_starts.iter_mut().zip(block.iter()) {
+            let end_offset = *offset + T::ENCODED_LEN;
+            let dst = unsafe { buffer.get_maybe_unchecked_mut(*offset..end_offset) };
+            // set valid
+            dst[0] = MaybeUninit::new(1);
+            dst[1..].copy_from_slice(encoded.as_ref().as_uninit());
+            *offset = end_offset;
+        }
     }
 }
 
@@ -249,11 +321,11 @@ where
     let values = rows
         .iter()
         .map(|row| {
-            has_nulls |= *row.get_unchecked(0) == null_sentinel;
+            has_nulls |= *row.get_maybe_unchecked(0) == null_sentinel;
             // skip null sentinel
             let start = 1;
             let end = start + T::ENCODED_LEN - 1;
-            let slice = row.get_unchecked(start..end);
+            let slice = row.get_maybe_unchecked(start..end);
             let bytes = T::Encoded::from_slice(slice);
 
             if descending {
@@ -278,14 +350,36 @@ where
     PrimitiveArray::new(dtype, values.into(), validity)
 }
 
+/// Decode the value at the start of `row` without decoding into an array.
+pub unsafe fn decode_value<T: FixedLengthEncoding>(
+    row: &[u8],
+    opt: RowEncodingOptions,
+) -> Option<T> {
+    if *row.get_maybe_unchecked(0) == opt.null_sentinel() {
+        return None;
+    }
+
+    debug_assert!(row.len() >= T::ENCODED_LEN);
+    let bytes = row
+        .get_maybe_unchecked(1..T::ENCODED_LEN)
+        .as_ptr()
+        .cast::<T::Encoded>()
+        .read_unaligned();
+    Some(if opt.contains(RowEncodingOptions::DESCENDING) {
+        T::decode_reverse(bytes)
+    } else {
+        T::decode(bytes)
+    })
+}
+
 unsafe fn increment_row_counter(rows: &mut [&[u8]], fixed_size: usize) {
     for row in rows {
-        *row = row.get_unchecked(fixed_size..);
+        *row = row.get_maybe_unchecked(fixed_size..);
     }
 }
 
 pub(super) unsafe fn decode_nulls(rows: &[&[u8]], null_sentinel: u8) -> Bitmap {
     rows.iter()
-        .map(|row| *row.get_unchecked(0) != null_sentinel)
+        .map(|row| *row.get_maybe_unchecked(0) != null_sentinel)
         .collect()
 }
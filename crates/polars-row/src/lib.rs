@@ -180,6 +180,9 @@
 //!
 //! A valid value is encoded as `1_u8` followed by the row encoding of each child.
 //!
+//! With [`RowEncodingOptions::NON_NULLABLE`], the leading byte is omitted for structs and
+//! fixed-size lists, which can then not be null.
+//!
 //! This encoding effectively flattens the schema in a depth-first fashion.
 //!
 //! For example
@@ -258,7 +261,8 @@
 //! ## Null Ordering
 //!
 //! The encoding described above will order nulls first, this can be inverted by representing
-//! nulls as `0xFF_u8` instead of `0_u8`
+//! nulls as `0xFF_u8` instead of `0_u8`. As reversing the column order below leaves the null
+//! sentinel untouched, the null ordering is independent of the column order.
 //!
 //! ## Reverse Column Ordering
 //!
@@ -267,13 +271,20 @@
 //! [COBS]: https://en.wikipedia.org/wiki/Consistent_Overhead_Byte_Stuffing
 //! [byte stuffing]: https://en.wikipedia.org/wiki/High-Level_Data_Link_Control#Asynchronous_framing
 
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
 extern crate core;
 
+mod checked;
 pub mod decode;
 pub mod encode;
+mod error;
 pub(crate) mod fixed;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 mod row;
 mod utils;
+mod validate;
 pub(crate) mod variable;
 mod widths;
 
@@ -282,6 +293,15 @@ pub type ArrayRef = Box<dyn Array>;
 
 pub use encode::{
     convert_columns, convert_columns_amortized, convert_columns_amortized_no_order,
-    convert_columns_no_order,
+    convert_columns_chunked, convert_columns_dedup, convert_columns_no_order,
+    estimate_encoded_size, fixed_size, row_width_upper_bound, try_convert_columns,
+    try_convert_columns_amortized, try_convert_columns_amortized_no_order,
+    try_convert_columns_chunked, try_convert_columns_dedup, try_convert_columns_no_order,
+    validate_contexts,
+};
+pub use error::RowEncodeError;
+pub use fixed::numeric::FixedLengthEncoding;
+pub use row::{
+    Collation, RowEncodingCategoricalContext, RowEncodingContext, RowEncodingInlineDictionary,
+    RowEncodingOptions, RowsEncoded, compare_rows, compare_rows_at,
 };
-pub use row::{RowEncodingCategoricalContext, RowEncodingContext, RowEncodingOptions, RowsEncoded};
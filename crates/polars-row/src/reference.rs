@@ -0,0 +1,818 @@
+//! A reference implementation of the stable subset of the row encoding.
+//!
+//! The encoders and decoders of this crate work on whole columns at once and use unchecked
+//! indexing. This module encodes and decodes the same bytes one value at a time in safe Rust, so
+//! that it can be read as executable documentation of the format and be used by external readers
+//! to validate their implementations. The tests check it against the optimized encoder and against
+//! a set of golden rows.
+//!
+//! The stable subset consists of the order-preserving encodings of booleans, integers, `f32`,
+//! `f64`, UTF-8 strings, and lists and structs of these. The only options that are part of it are
+//! [`RowEncodingOptions::DESCENDING`] and [`RowEncodingOptions::NULLS_LAST`].
+//!
+//! A row is the concatenation of the encoded values of every column. Every encoded value starts
+//! with a byte that tells whether it is null, and no encoded value is a prefix of another. Hence
+//! comparing two rows bytewise compares their values column by column.
+//!
+//! # Null sentinel
+//!
+//! A null value starts with the null sentinel, which is `0x00`, or `0xFF` with
+//! [`RowEncodingOptions::NULLS_LAST`]. All bytes that a valid value starts with lie in between.
+//!
+//! # Descending
+//!
+//! With [`RowEncodingOptions::DESCENDING`], all bytes of valid values are inverted, except for
+//! the validity byte of numerics and structs. The null sentinel is never inverted, so the position
+//! of nulls only depends on [`RowEncodingOptions::NULLS_LAST`].
+//!
+//! # Nested values
+//!
+//! The elements of lists and the fields of structs are encoded with
+//! [`RowEncodingOptions::into_nested`]: in the same direction as the column, with nulls first when
+//! ascending and nulls last when descending.
+#![forbid(unsafe_code)]
+
+use crate::error::RowEncodeError;
+use crate::row::RowEncodingOptions;
+
+/// Validity byte of valid numerics and structs.
+const VALID: u8 = 0x01;
+/// Encoded `false`, before inverting it for a descending order.
+const FALSE: u8 = 0x02;
+/// Encoded `true`, before inverting it for a descending order.
+const TRUE: u8 = 0x03;
+/// Ends a string, before inverting it for a descending order.
+const STRING_TERMINATOR: u8 = 0x01;
+/// Precedes every element of a list, before inverting it for a descending order.
+const LIST_CONTINUATION: u8 = 0xFE;
+/// Ends a list, before inverting it for a descending order.
+const LIST_TERMINATOR: u8 = 0x01;
+
+/// A dtype of the stable subset of the row encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Dtype {
+    Boolean,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    Int128,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    UInt128,
+    Float32,
+    Float64,
+    String,
+    List(Box<Dtype>),
+    Struct(Vec<Dtype>),
+}
+
+impl Dtype {
+    /// The number of bytes of an encoded numeric after its validity byte.
+    fn numeric_width(&self) -> Option<usize> {
+        Some(match self {
+            Dtype::Int8 | Dtype::UInt8 => 1,
+            Dtype::Int16 | Dtype::UInt16 => 2,
+            Dtype::Int32 | Dtype::UInt32 | Dtype::Float32 => 4,
+            Dtype::Int64 | Dtype::UInt64 | Dtype::Float64 => 8,
+            Dtype::Int128 | Dtype::UInt128 => 16,
+            _ => return None,
+        })
+    }
+}
+
+/// A value of a [`Dtype`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A null value of any dtype. For a struct, this is a null struct with null fields.
+    Null,
+    Boolean(bool),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Int128(i128),
+    UInt8(u8),
+    UInt16(u16),
+    UInt32(u32),
+    UInt64(u64),
+    UInt128(u128),
+    Float32(f32),
+    Float64(f64),
+    String(String),
+    List(Vec<Value>),
+    /// A struct and the values of its fields.
+    ///
+    /// The fields of a null struct are encoded as well, so it has values for its fields too.
+    Struct {
+        is_valid: bool,
+        fields: Vec<Value>,
+    },
+}
+
+fn check_options(opt: RowEncodingOptions) {
+    let stable = RowEncodingOptions::DESCENDING | RowEncodingOptions::NULLS_LAST;
+    assert!(
+        stable.contains(opt),
+        "{opt:?} are not part of the stable row encoding"
+    );
+}
+
+/// Invert `byte` for a descending order.
+fn order(byte: u8, opt: RowEncodingOptions) -> u8 {
+    if opt.contains(RowEncodingOptions::DESCENDING) {
+        !byte
+    } else {
+        byte
+    }
+}
+
+/// Encode every row of `rows`, which have a value for every column of `dtypes`.
+///
+/// # Panics
+///
+/// Panics if a value doesn't have the dtype of its column, or if the options are not part of the
+/// stable subset.
+pub fn encode_rows(
+    rows: &[Vec<Value>],
+    dtypes: &[Dtype],
+    opts: &[RowEncodingOptions],
+) -> Vec<Vec<u8>> {
+    assert_eq!(dtypes.len(), opts.len());
+    opts.iter().copied().for_each(check_options);
+
+    rows.iter()
+        .map(|values| {
+            assert_eq!(values.len(), dtypes.len());
+            let mut row = Vec::new();
+            for ((value, dtype), opt) in values.iter().zip(dtypes).zip(opts) {
+                encode_value(&mut row, value, dtype, *opt);
+            }
+            row
+        })
+        .collect()
+}
+
+fn encode_value(out: &mut Vec<u8>, value: &Value, dtype: &Dtype, opt: RowEncodingOptions) {
+    let null_sentinel = opt.null_sentinel();
+    match dtype {
+        // A single byte: `0x02` for false and `0x03` for true.
+        Dtype::Boolean => match value {
+            Value::Null => out.push(null_sentinel),
+            Value::Boolean(false) => out.push(order(FALSE, opt)),
+            Value::Boolean(true) => out.push(order(TRUE, opt)),
+            _ => panic!("{value:?} is not a {dtype:?}"),
+        },
+
+        // Every byte `b` of the UTF-8 string is written as `b + 2`, followed by `0x01`. Bytes
+        // `0xFE` and `0xFF` never occur in UTF-8, so the encoded bytes lie in `0x02..=0xFF`. A
+        // shorter string is a prefix of a longer one, but its terminator orders before any byte.
+        Dtype::String => match value {
+            Value::Null => out.push(null_sentinel),
+            Value::String(s) => {
+                out.extend(s.bytes().map(|b| order(b + 2, opt)));
+                out.push(order(STRING_TERMINATOR, opt));
+            },
+            _ => panic!("{value:?} is not a {dtype:?}"),
+        },
+
+        // Every element is preceded by `0xFE`, and the list ends with `0x01`. Hence a list that
+        // ends orders before a list that continues, whatever its next element is.
+        Dtype::List(inner) => match value {
+            Value::Null => out.push(null_sentinel),
+            Value::List(values) => {
+                for value in values {
+                    out.push(order(LIST_CONTINUATION, opt));
+                    encode_value(out, value, inner, opt.into_nested());
+                }
+                out.push(order(LIST_TERMINATOR, opt));
+            },
+            _ => panic!("{value:?} is not a {dtype:?}"),
+        },
+
+        // The validity byte, followed by the encoded values of the fields.
+        Dtype::Struct(field_dtypes) => {
+            let (is_valid, nulls, fields) = match value {
+                Value::Null => (false, vec![Value::Null; field_dtypes.len()], None),
+                Value::Struct { is_valid, fields } => (*is_valid, Vec::new(), Some(fields)),
+                _ => panic!("{value:?} is not a {dtype:?}"),
+            };
+            let fields = fields.unwrap_or(&nulls);
+            assert_eq!(fields.len(), field_dtypes.len());
+
+            out.push(if is_valid { VALID } else { null_sentinel });
+            for (field, field_dtype) in fields.iter().zip(field_dtypes) {
+                encode_value(out, field, field_dtype, opt.into_nested());
+            }
+        },
+
+        // The validity byte, followed by the big-endian bytes of the value that are made to order
+        // like unsigned integers. A null is padded with zeros to the same width.
+        _ => {
+            let width = dtype.numeric_width().unwrap();
+            if let Value::Null = value {
+                out.push(null_sentinel);
+                out.extend(std::iter::repeat_n(0, width));
+                return;
+            }
+            let Some(bytes) = encode_numeric(value, dtype) else {
+                panic!("{value:?} is not a {dtype:?}")
+            };
+            debug_assert_eq!(bytes.len(), width);
+            out.push(VALID);
+            out.extend(bytes.into_iter().map(|b| order(b, opt)));
+        },
+    }
+}
+
+/// Flip the sign bit of a signed integer, which makes it order like an unsigned integer.
+macro_rules! flip_sign {
+    ($v:expr, $unsigned:ty) => {
+        (($v as $unsigned) ^ (1 << (<$unsigned>::BITS - 1)))
+            .to_be_bytes()
+            .to_vec()
+    };
+}
+
+/// The big-endian bytes of a numeric, in an order that matches the order of the values.
+fn encode_numeric(value: &Value, dtype: &Dtype) -> Option<Vec<u8>> {
+    Some(match (value, dtype) {
+        (Value::Int8(v), Dtype::Int8) => flip_sign!(*v, u8),
+        (Value::Int16(v), Dtype::Int16) => flip_sign!(*v, u16),
+        (Value::Int32(v), Dtype::Int32) => flip_sign!(*v, u32),
+        (Value::Int64(v), Dtype::Int64) => flip_sign!(*v, u64),
+        (Value::Int128(v), Dtype::Int128) => flip_sign!(*v, u128),
+        (Value::UInt8(v), Dtype::UInt8) => v.to_be_bytes().to_vec(),
+        (Value::UInt16(v), Dtype::UInt16) => v.to_be_bytes().to_vec(),
+        (Value::UInt32(v), Dtype::UInt32) => v.to_be_bytes().to_vec(),
+        (Value::UInt64(v), Dtype::UInt64) => v.to_be_bytes().to_vec(),
+        (Value::UInt128(v), Dtype::UInt128) => v.to_be_bytes().to_vec(),
+        (Value::Float32(v), Dtype::Float32) => flip_sign!(f32_to_ordered_bits(*v), u32),
+        (Value::Float64(v), Dtype::Float64) => flip_sign!(f64_to_ordered_bits(*v), u64),
+        _ => return None,
+    })
+}
+
+/// Map a float to a signed integer with the same order.
+///
+/// `-0.0` is mapped like `0.0`, and every NaN like the canonical quiet NaN, which orders after
+/// infinity. The bits of positive floats already order like signed integers. For negative floats,
+/// all bits but the sign bit are flipped, so that a larger magnitude orders lower.
+fn f32_to_ordered_bits(v: f32) -> i32 {
+    let v = if v.is_nan() {
+        f32::from_bits(0x7FC0_0000)
+    } else {
+        v + 0.0
+    };
+    let bits = v.to_bits() as i32;
+    if bits < 0 { bits ^ i32::MAX } else { bits }
+}
+
+fn f32_from_ordered_bits(bits: i32) -> f32 {
+    let bits = if bits < 0 { bits ^ i32::MAX } else { bits };
+    f32::from_bits(bits as u32)
+}
+
+/// See [`f32_to_ordered_bits`].
+fn f64_to_ordered_bits(v: f64) -> i64 {
+    let v = if v.is_nan() {
+        f64::from_bits(0x7FF8_0000_0000_0000)
+    } else {
+        v + 0.0
+    };
+    let bits = v.to_bits() as i64;
+    if bits < 0 { bits ^ i64::MAX } else { bits }
+}
+
+fn f64_from_ordered_bits(bits: i64) -> f64 {
+    let bits = if bits < 0 { bits ^ i64::MAX } else { bits };
+    f64::from_bits(bits as u64)
+}
+
+/// Why a row can't be decoded.
+enum Invalid {
+    Truncated,
+    Malformed(&'static str),
+}
+
+/// Decode every row of `rows` into a value for every column of `dtypes`.
+///
+/// Every byte of the rows has to be part of an encoded value. Floats are decoded canonically, see
+/// [`Value`]: `-0.0` as `0.0` and every NaN as the canonical quiet NaN.
+///
+/// # Panics
+///
+/// Panics if the options are not part of the stable subset.
+pub fn decode_rows(
+    rows: &[&[u8]],
+    dtypes: &[Dtype],
+    opts: &[RowEncodingOptions],
+) -> Result<Vec<Vec<Value>>, RowEncodeError> {
+    assert_eq!(dtypes.len(), opts.len());
+    opts.iter().copied().for_each(check_options);
+
+    rows.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut rest = *row;
+            let values = dtypes
+                .iter()
+                .zip(opts)
+                .enumerate()
+                .map(|(column, (dtype, opt))| {
+                    decode_value(&mut rest, dtype, *opt).map_err(|invalid| match invalid {
+                        Invalid::Truncated => RowEncodeError::Truncated { row: i, column },
+                        Invalid::Malformed(reason) => RowEncodeError::Malformed {
+                            row: i,
+                            column,
+                            reason,
+                        },
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if !rest.is_empty() {
+                return Err(RowEncodeError::Malformed {
+                    row: i,
+                    column: dtypes.len() - 1,
+                    reason: "row continues after the last column",
+                });
+            }
+            Ok(values)
+        })
+        .collect()
+}
+
+fn take<'a>(row: &mut &'a [u8], n: usize) -> Result<&'a [u8], Invalid> {
+    if row.len() < n {
+        return Err(Invalid::Truncated);
+    }
+    let (head, tail) = row.split_at(n);
+    *row = tail;
+    Ok(head)
+}
+
+fn take_byte(row: &mut &[u8]) -> Result<u8, Invalid> {
+    Ok(take(row, 1)?[0])
+}
+
+fn decode_value(row: &mut &[u8], dtype: &Dtype, opt: RowEncodingOptions) -> Result<Value, Invalid> {
+    let null_sentinel = opt.null_sentinel();
+    match dtype {
+        Dtype::Boolean => match take_byte(row)? {
+            b if b == null_sentinel => Ok(Value::Null),
+            b if b == order(FALSE, opt) => Ok(Value::Boolean(false)),
+            b if b == order(TRUE, opt) => Ok(Value::Boolean(true)),
+            _ => Err(Invalid::Malformed("invalid boolean")),
+        },
+
+        Dtype::String => {
+            if row.first() == Some(&null_sentinel) {
+                take_byte(row)?;
+                return Ok(Value::Null);
+            }
+            let mut bytes = Vec::new();
+            loop {
+                match order(take_byte(row)?, opt) {
+                    STRING_TERMINATOR => break,
+                    b if b < 2 => return Err(Invalid::Malformed("invalid string byte")),
+                    b => bytes.push(b - 2),
+                }
+            }
+            String::from_utf8(bytes)
+                .map(Value::String)
+                .map_err(|_| Invalid::Malformed("invalid UTF-8"))
+        },
+
+        Dtype::List(inner) => {
+            if row.first() == Some(&null_sentinel) {
+                take_byte(row)?;
+                return Ok(Value::Null);
+            }
+            let mut values = Vec::new();
+            loop {
+                match order(take_byte(row)?, opt) {
+                    LIST_TERMINATOR => return Ok(Value::List(values)),
+                    LIST_CONTINUATION => values.push(decode_value(row, inner, opt.into_nested())?),
+                    _ => return Err(Invalid::Malformed("invalid list token")),
+                }
+            }
+        },
+
+        Dtype::Struct(field_dtypes) => {
+            let is_valid = match take_byte(row)? {
+                VALID => true,
+                b if b == null_sentinel => false,
+                _ => return Err(Invalid::Malformed("invalid struct validity")),
+            };
+            let fields = field_dtypes
+                .iter()
+                .map(|field_dtype| decode_value(row, field_dtype, opt.into_nested()))
+                .collect::<Result<_, _>>()?;
+            Ok(Value::Struct { is_valid, fields })
+        },
+
+        _ => {
+            let width = dtype.numeric_width().unwrap();
+            let validity = take_byte(row)?;
+            let bytes = take(row, width)?;
+            if validity == null_sentinel {
+                return match bytes.iter().all(|b| *b == 0) {
+                    true => Ok(Value::Null),
+                    false => Err(Invalid::Malformed("null numeric with non-zero bytes")),
+                };
+            }
+            if validity != VALID {
+                return Err(Invalid::Malformed("invalid numeric validity"));
+            }
+            let bytes = bytes.iter().map(|b| order(*b, opt)).collect::<Vec<_>>();
+            Ok(decode_numeric(&bytes, dtype))
+        },
+    }
+}
+
+/// Flip the sign bit of the big-endian bytes of a signed integer back.
+macro_rules! unflip_sign {
+    ($bytes:expr, $unsigned:ty, $signed:ty) => {
+        (<$unsigned>::from_be_bytes($bytes.try_into().unwrap()) ^ (1 << (<$unsigned>::BITS - 1)))
+            as $signed
+    };
+}
+
+/// Inverse of [`encode_numeric`].
+fn decode_numeric(bytes: &[u8], dtype: &Dtype) -> Value {
+    match dtype {
+        Dtype::Int8 => Value::Int8(unflip_sign!(bytes, u8, i8)),
+        Dtype::Int16 => Value::Int16(unflip_sign!(bytes, u16, i16)),
+        Dtype::Int32 => Value::Int32(unflip_sign!(bytes, u32, i32)),
+        Dtype::Int64 => Value::Int64(unflip_sign!(bytes, u64, i64)),
+        Dtype::Int128 => Value::Int128(unflip_sign!(bytes, u128, i128)),
+        Dtype::UInt8 => Value::UInt8(u8::from_be_bytes(bytes.try_into().unwrap())),
+        Dtype::UInt16 => Value::UInt16(u16::from_be_bytes(bytes.try_into().unwrap())),
+        Dtype::UInt32 => Value::UInt32(u32::from_be_bytes(bytes.try_into().unwrap())),
+        Dtype::UInt64 => Value::UInt64(u64::from_be_bytes(bytes.try_into().unwrap())),
+        Dtype::UInt128 => Value::UInt128(u128::from_be_bytes(bytes.try_into().unwrap())),
+        Dtype::Float32 => Value::Float32(f32_from_ordered_bits(unflip_sign!(bytes, u32, i32))),
+        Dtype::Float64 => Value::Float64(f64_from_ordered_bits(unflip_sign!(bytes, u64, i64))),
+        _ => unreachable!("{dtype:?} is not a numeric"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::proptest::{
+        ArrayArbitraryOptions, ArrowDataTypeArbitraryOptions, ArrowDataTypeArbitrarySelection,
+        array_with_options,
+    };
+    use arrow::array::{
+        Array, BooleanArray, ListArray, PrimitiveArray, StructArray, Utf8ViewArray,
+    };
+    use arrow::datatypes::ArrowDataType;
+    use arrow::types::NativeType;
+    use proptest::prelude::Strategy;
+
+    use super::*;
+
+    fn hex(s: &str) -> Vec<u8> {
+        s.split_whitespace()
+            .map(|b| u8::from_str_radix(b, 16).unwrap())
+            .collect()
+    }
+
+    fn asc() -> RowEncodingOptions {
+        RowEncodingOptions::new_sorted(false, false)
+    }
+
+    fn desc() -> RowEncodingOptions {
+        RowEncodingOptions::new_sorted(true, false)
+    }
+
+    fn nulls_last() -> RowEncodingOptions {
+        RowEncodingOptions::new_sorted(false, true)
+    }
+
+    /// Check that `values` encode to the golden row `expected`, and decode from it again.
+    fn check_golden(
+        values: Vec<Value>,
+        dtypes: &[Dtype],
+        opts: &[RowEncodingOptions],
+        expected: &str,
+    ) {
+        let expected = hex(expected);
+        let rows = encode_rows(&[values], dtypes, opts);
+        assert_eq!(rows[0], expected);
+
+        let decoded = decode_rows(&[&expected], dtypes, opts).unwrap();
+        assert_eq!(encode_rows(&decoded, dtypes, opts)[0], expected);
+    }
+
+    #[test]
+    fn test_golden_numeric() {
+        check_golden(
+            vec![Value::Int32(3)],
+            &[Dtype::Int32],
+            &[asc()],
+            "01 80 00 00 03",
+        );
+        check_golden(
+            vec![Value::Int32(-5)],
+            &[Dtype::Int32],
+            &[asc()],
+            "01 7f ff ff fb",
+        );
+        check_golden(
+            vec![Value::Int32(3)],
+            &[Dtype::Int32],
+            &[desc()],
+            "01 7f ff ff fc",
+        );
+        check_golden(
+            vec![Value::Null],
+            &[Dtype::Int32],
+            &[asc()],
+            "00 00 00 00 00",
+        );
+        check_golden(
+            vec![Value::Null],
+            &[Dtype::Int32],
+            &[nulls_last()],
+            "ff 00 00 00 00",
+        );
+        check_golden(
+            vec![Value::UInt16(258)],
+            &[Dtype::UInt16],
+            &[asc()],
+            "01 01 02",
+        );
+        check_golden(
+            vec![Value::Float64(1.0)],
+            &[Dtype::Float64],
+            &[asc()],
+            "01 bf f0 00 00 00 00 00 00",
+        );
+        check_golden(
+            vec![Value::Float64(-1.0)],
+            &[Dtype::Float64],
+            &[asc()],
+            "01 40 0f ff ff ff ff ff ff",
+        );
+        check_golden(
+            vec![Value::Float32(-0.0)],
+            &[Dtype::Float32],
+            &[asc()],
+            "01 80 00 00 00",
+        );
+        check_golden(
+            vec![Value::Float32(f32::NAN)],
+            &[Dtype::Float32],
+            &[asc()],
+            "01 ff c0 00 00",
+        );
+    }
+
+    #[test]
+    fn test_golden_boolean() {
+        check_golden(
+            vec![Value::Boolean(true)],
+            &[Dtype::Boolean],
+            &[asc()],
+            "03",
+        );
+        check_golden(
+            vec![Value::Boolean(false)],
+            &[Dtype::Boolean],
+            &[desc()],
+            "fd",
+        );
+        check_golden(vec![Value::Null], &[Dtype::Boolean], &[nulls_last()], "ff");
+    }
+
+    #[test]
+    fn test_golden_string() {
+        let string = |s: &str| vec![Value::String(s.into())];
+        check_golden(string("ab"), &[Dtype::String], &[asc()], "63 64 01");
+        check_golden(string("ab"), &[Dtype::String], &[desc()], "9c 9b fe");
+        check_golden(string(""), &[Dtype::String], &[asc()], "01");
+        check_golden(string("é"), &[Dtype::String], &[asc()], "c5 ab 01");
+        check_golden(vec![Value::Null], &[Dtype::String], &[asc()], "00");
+    }
+
+    #[test]
+    fn test_golden_nested() {
+        let list = Dtype::List(Box::new(Dtype::Int8));
+        let values = vec![Value::List(vec![Value::Int8(1), Value::Null])];
+        check_golden(
+            values.clone(),
+            std::slice::from_ref(&list),
+            &[asc()],
+            "fe 01 81 fe 00 00 01",
+        );
+        check_golden(values, &[list], &[desc()], "01 01 7e 01 ff 00 fe");
+
+        let strct = Dtype::Struct(vec![Dtype::Boolean, Dtype::String]);
+        let values = vec![Value::Struct {
+            is_valid: true,
+            fields: vec![Value::Boolean(true), Value::String("a".into())],
+        }];
+        check_golden(
+            values,
+            std::slice::from_ref(&strct),
+            &[asc()],
+            "01 03 63 01",
+        );
+        check_golden(
+            vec![Value::Null],
+            std::slice::from_ref(&strct),
+            &[asc()],
+            "00 00 00",
+        );
+        let values = vec![Value::Struct {
+            is_valid: true,
+            fields: vec![Value::Boolean(false), Value::Null],
+        }];
+        check_golden(values, &[strct], &[desc()], "01 fd ff");
+    }
+
+    #[test]
+    fn test_golden_multiple_columns() {
+        check_golden(
+            vec![Value::Int8(1), Value::String("a".into())],
+            &[Dtype::Int8, Dtype::String],
+            &[asc(), desc()],
+            "01 81 9c fe",
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        let dtypes = [Dtype::Int32, Dtype::String];
+        let opts = [asc(), asc()];
+        assert_eq!(
+            decode_rows(&[&hex("01 80 00")], &dtypes, &opts),
+            Err(RowEncodeError::Truncated { row: 0, column: 0 })
+        );
+        assert_eq!(
+            decode_rows(&[&hex("01 80 00 00 03 63")], &dtypes, &opts),
+            Err(RowEncodeError::Truncated { row: 0, column: 1 })
+        );
+        assert!(matches!(
+            decode_rows(&[&hex("00 00 00 00 01 01")], &dtypes, &opts),
+            Err(RowEncodeError::Malformed {
+                row: 0,
+                column: 0,
+                ..
+            })
+        ));
+        assert!(matches!(
+            decode_rows(&[&hex("01 80 00 00 03 01 01")], &dtypes, &opts),
+            Err(RowEncodeError::Malformed {
+                row: 0,
+                column: 1,
+                ..
+            })
+        ));
+    }
+
+    fn dtype_of(dtype: &ArrowDataType) -> Dtype {
+        match dtype {
+            ArrowDataType::Boolean => Dtype::Boolean,
+            ArrowDataType::Int8 => Dtype::Int8,
+            ArrowDataType::Int16 => Dtype::Int16,
+            ArrowDataType::Int32 => Dtype::Int32,
+            ArrowDataType::Int64 => Dtype::Int64,
+            ArrowDataType::Int128 => Dtype::Int128,
+            ArrowDataType::UInt8 => Dtype::UInt8,
+            ArrowDataType::UInt16 => Dtype::UInt16,
+            ArrowDataType::UInt32 => Dtype::UInt32,
+            ArrowDataType::UInt64 => Dtype::UInt64,
+            ArrowDataType::Float32 => Dtype::Float32,
+            ArrowDataType::Float64 => Dtype::Float64,
+            ArrowDataType::Utf8View => Dtype::String,
+            ArrowDataType::LargeList(field) => Dtype::List(Box::new(dtype_of(field.dtype()))),
+            ArrowDataType::Struct(fields) => {
+                Dtype::Struct(fields.iter().map(|f| dtype_of(f.dtype())).collect())
+            },
+            dtype => unreachable!("{dtype:?} is not part of the stable row encoding"),
+        }
+    }
+
+    fn primitive_at<T: NativeType>(array: &dyn Array, i: usize) -> T {
+        array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T>>()
+            .unwrap()
+            .value(i)
+    }
+
+    fn value_at(array: &dyn Array, i: usize) -> Value {
+        if let ArrowDataType::Struct(_) = array.dtype() {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            return Value::Struct {
+                is_valid: array.is_valid(i),
+                fields: array
+                    .values()
+                    .iter()
+                    .map(|f| value_at(f.as_ref(), i))
+                    .collect(),
+            };
+        }
+        if array.is_null(i) {
+            return Value::Null;
+        }
+        match array.dtype() {
+            ArrowDataType::Boolean => Value::Boolean(
+                array
+                    .as_any()
+                    .downcast_ref::<BooleanArray>()
+                    .unwrap()
+                    .value(i),
+            ),
+            ArrowDataType::Int8 => Value::Int8(primitive_at(array, i)),
+            ArrowDataType::Int16 => Value::Int16(primitive_at(array, i)),
+            ArrowDataType::Int32 => Value::Int32(primitive_at(array, i)),
+            ArrowDataType::Int64 => Value::Int64(primitive_at(array, i)),
+            ArrowDataType::Int128 => Value::Int128(primitive_at(array, i)),
+            ArrowDataType::UInt8 => Value::UInt8(primitive_at(array, i)),
+            ArrowDataType::UInt16 => Value::UInt16(primitive_at(array, i)),
+            ArrowDataType::UInt32 => Value::UInt32(primitive_at(array, i)),
+            ArrowDataType::UInt64 => Value::UInt64(primitive_at(array, i)),
+            ArrowDataType::Float32 => Value::Float32(primitive_at(array, i)),
+            ArrowDataType::Float64 => Value::Float64(primitive_at(array, i)),
+            ArrowDataType::Utf8View => Value::String(
+                array
+                    .as_any()
+                    .downcast_ref::<Utf8ViewArray>()
+                    .unwrap()
+                    .value(i)
+                    .into(),
+            ),
+            ArrowDataType::LargeList(_) => {
+                let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+                let (start, end) = array.offsets().start_end(i);
+                let values = array.values().as_ref();
+                Value::List((start..end).map(|j| value_at(values, j)).collect())
+            },
+            dtype => unreachable!("{dtype:?} is not part of the stable row encoding"),
+        }
+    }
+
+    proptest::prop_compose! {
+        fn stable_arrays
+            ()
+            (length in 0..100usize)
+            (arrays in proptest::collection::vec(array_with_options(length, ArrayArbitraryOptions {
+                dtype: ArrowDataTypeArbitraryOptions {
+                    allowed_dtypes: ArrowDataTypeArbitrarySelection::BOOLEAN
+                        | ArrowDataTypeArbitrarySelection::INT8
+                        | ArrowDataTypeArbitrarySelection::INT16
+                        | ArrowDataTypeArbitrarySelection::INT32
+                        | ArrowDataTypeArbitrarySelection::INT64
+                        | ArrowDataTypeArbitrarySelection::INT128
+                        | ArrowDataTypeArbitrarySelection::UINT8
+                        | ArrowDataTypeArbitrarySelection::UINT16
+                        | ArrowDataTypeArbitrarySelection::UINT32
+                        | ArrowDataTypeArbitrarySelection::UINT64
+                        | ArrowDataTypeArbitrarySelection::FLOAT32
+                        | ArrowDataTypeArbitrarySelection::FLOAT64
+                        | ArrowDataTypeArbitrarySelection::STRVIEW
+                        | ArrowDataTypeArbitrarySelection::LIST
+                        | ArrowDataTypeArbitrarySelection::STRUCT,
+                    ..Default::default()
+                }
+            }), 1..3))
+        -> Vec<Box<dyn Array>> {
+            arrays
+        }
+    }
+
+    fn stable_options() -> impl Strategy<Value = RowEncodingOptions> {
+        (proptest::bool::ANY, proptest::bool::ANY).prop_map(|(descending, nulls_last)| {
+            RowEncodingOptions::new_sorted(descending, nulls_last)
+        })
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_reference_matches_encoder
+            (arrays in stable_arrays(), opts in proptest::collection::vec(stable_options(), 3))
+        {
+            let num_rows = arrays[0].len();
+            let opts = &opts[..arrays.len()];
+            let dicts = vec![None; arrays.len()];
+            let rows = crate::convert_columns(num_rows, &arrays, opts, &dicts);
+
+            let dtypes = arrays.iter().map(|a| dtype_of(a.dtype())).collect::<Vec<_>>();
+            let values = (0..num_rows)
+                .map(|i| arrays.iter().map(|a| value_at(a.as_ref(), i)).collect())
+                .collect::<Vec<_>>();
+            let expected = encode_rows(&values, &dtypes, opts);
+            let rows = rows.iter().collect::<Vec<_>>();
+            proptest::prop_assert_eq!(&rows, &expected.iter().map(Vec::as_slice).collect::<Vec<_>>());
+
+            let decoded = decode_rows(&rows, &dtypes, opts).unwrap();
+            proptest::prop_assert_eq!(encode_rows(&decoded, &dtypes, opts), expected);
+        }
+    }
+}
@@ -1,12 +1,17 @@
 #![allow(unsafe_op_in_unsafe_fn)]
 use std::sync::Arc;
 
-use arrow::array::{BinaryArray, BinaryViewArray};
+use arrow::array::{BinaryArray, BinaryViewArray, Utf8ViewArray};
+use arrow::buffer::Buffer;
 use arrow::datatypes::ArrowDataType;
 use arrow::ffi::mmap;
 use arrow::offset::{Offsets, OffsetsBuffer};
 use polars_compute::cast::binary_to_binview;
 use polars_dtype::categorical::CategoricalMapping;
+use polars_error::{PolarsResult, polars_ensure};
+
+use crate::checked::MaybeUnchecked;
+use crate::encode::EncoderArena;
 
 const BOOLEAN_TRUE_SENTINEL: u8 = 0x03;
 const BOOLEAN_FALSE_SENTINEL: u8 = 0x02;
@@ -18,18 +23,50 @@ const BOOLEAN_FALSE_SENTINEL: u8 = 0x02;
 #[derive(Debug, Clone)]
 pub enum RowEncodingContext {
     Struct(Vec<Option<RowEncodingContext>>),
+    /// Struct where each field is encoded with its own [`RowEncodingOptions`] instead of the
+    /// nested options of the struct column itself.
+    ///
+    /// This allows e.g. sorting one field descending and another field ascending. Pairing the
+    /// options with the context of each field ensures there is exactly one of each per field.
+    StructWithOptions(Vec<(RowEncodingOptions, Option<RowEncodingContext>)>),
+    /// List or array with the given context for its values.
+    List(Box<Option<RowEncodingContext>>),
     /// Categorical / Enum
     Categorical(RowEncodingCategoricalContext),
     /// Decimal with given precision
     Decimal(usize),
+    /// Strings encoded as their ordinal in a dictionary, see [`RowEncodingOptions::DEDUP_VARIABLE`].
+    InlineDictionary(RowEncodingInlineDictionary),
 }
 
 #[derive(Debug, Clone)]
 pub struct RowEncodingCategoricalContext {
     pub is_enum: bool,
+    /// Encode the categories as their strings even if the encoding is unordered.
+    ///
+    /// The rows are then equal to the rows of the same strings in a `String` column encoded with
+    /// the same options, which allows e.g. joining a `Categorical` with a `String` key without
+    /// casting. This is ignored for enums.
+    pub encode_strings: bool,
     pub mapping: Arc<CategoricalMapping>,
 }
 
+impl RowEncodingCategoricalContext {
+    /// Whether the categories are encoded as their strings instead of their physical keys.
+    ///
+    /// Ordered lexical categoricals are always encoded as their strings.
+    pub fn encodes_strings(&self, opt: RowEncodingOptions) -> bool {
+        !self.is_enum && (opt.is_ordered() || self.encode_strings)
+    }
+}
+
+/// The dictionary of a string column encoded with [`RowEncodingOptions::DEDUP_VARIABLE`].
+#[derive(Debug, Clone)]
+pub struct RowEncodingInlineDictionary {
+    /// The distinct valid strings of the encoded batch in ascending order.
+    pub values: Utf8ViewArray,
+}
+
 bitflags::bitflags! {
     /// Options for the Polars Row Encoding.
     ///
@@ -42,6 +79,9 @@ bitflags::bitflags! {
         /// Sort in descending order instead of ascending order
         const DESCENDING               = 0x01;
         /// Sort such that nulls / missing values are last
+        ///
+        /// This is independent of [`Self::DESCENDING`], as only the encoded bytes of valid values
+        /// are negated for a descending order.
         const NULLS_LAST               = 0x02;
 
         /// Ignore all order-related flags and don't encode order-preserving. This will keep
@@ -49,9 +89,44 @@ bitflags::bitflags! {
         ///
         /// This is faster for several encodings
         const NO_ORDER                 = 0x04;
+
+        /// Order strings with [`Collation::AsciiCaseInsensitive`] instead of by their bytes.
+        const ASCII_CASE_INSENSITIVE   = 0x08;
+
+        /// Encode a string column with few distinct values as the ordinals of its values in a
+        /// sorted dictionary of the distinct strings, instead of copying the strings into every
+        /// row.
+        ///
+        /// This only applies to top-level `Utf8View` columns without a context that are encoded
+        /// with [`convert_columns_dedup`](crate::convert_columns_dedup), which returns the
+        /// [`RowEncodingContext::InlineDictionary`] needed to decode them. Rows with ordinals can
+        /// only be compared to rows of the same batch.
+        const DEDUP_VARIABLE           = 0x10;
+
+        /// The column contains no null fixed-size lists or structs, so these are encoded without
+        /// their validity byte.
+        ///
+        /// This applies at every nesting level, including values masked out by null lists.
+        /// Encoding a column that has such nulls fails, and rows have to be decoded with the same
+        /// options.
+        const NON_NULLABLE             = 0x20;
     }
 }
 
+/// How the order-preserving row encoding compares strings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Collation {
+    /// Compare the UTF-8 bytes.
+    #[default]
+    Binary,
+    /// Compare the UTF-8 bytes after folding ASCII letters to lowercase.
+    ///
+    /// The strings are folded before they are encoded, so they are decoded in lowercase as well.
+    /// Categoricals are always compared by their bytes, as their categories need to be decoded
+    /// exactly.
+    AsciiCaseInsensitive,
+}
+
 const LIST_CONTINUATION_TOKEN: u8 = 0xFE;
 const EMPTY_STR_TOKEN: u8 = 0x01;
 
@@ -71,6 +146,31 @@ impl RowEncodingOptions {
         !self.contains(Self::NO_ORDER)
     }
 
+    pub fn collation(self) -> Collation {
+        if self.contains(Self::ASCII_CASE_INSENSITIVE) {
+            Collation::AsciiCaseInsensitive
+        } else {
+            Collation::Binary
+        }
+    }
+
+    pub fn with_collation(mut self, collation: Collation) -> Self {
+        self.set(
+            Self::ASCII_CASE_INSENSITIVE,
+            collation == Collation::AsciiCaseInsensitive,
+        );
+        self
+    }
+
+    /// The number of bytes that encode the validity of a fixed-size list or struct.
+    pub(crate) fn nested_validity_len(self) -> usize {
+        if self.contains(Self::NON_NULLABLE) {
+            0
+        } else {
+            1
+        }
+    }
+
     pub fn null_sentinel(self) -> u8 {
         if self.contains(Self::NULLS_LAST) {
             0xFF
@@ -119,6 +219,10 @@ impl RowEncodingOptions {
         }
     }
 
+    /// The options for the values nested in lists and structs.
+    ///
+    /// All flags but [`Self::NULLS_LAST`] are kept, so e.g. the [`Collation`] applies to nested
+    /// strings as well.
     pub fn into_nested(mut self) -> RowEncodingOptions {
         // Correct nested ordering (see #22557)
         self.set(
@@ -133,6 +237,8 @@ impl RowEncodingOptions {
 pub struct RowsEncoded {
     pub(crate) values: Vec<u8>,
     pub(crate) offsets: Vec<usize>,
+    /// Reused by every chunk that is encoded into these rows.
+    pub(crate) encoders: EncoderArena,
 }
 
 unsafe fn rows_to_array(buf: Vec<u8>, offsets: Vec<usize>) -> BinaryArray<i64> {
@@ -156,12 +262,53 @@ unsafe fn rows_to_array(buf: Vec<u8>, offsets: Vec<usize>) -> BinaryArray<i64> {
 
 impl RowsEncoded {
     pub(crate) fn new(values: Vec<u8>, offsets: Vec<usize>) -> Self {
-        RowsEncoded { values, offsets }
+        RowsEncoded {
+            values,
+            offsets,
+            encoders: EncoderArena::default(),
+        }
+    }
+
+    /// Create rows from the buffer of their encoded bytes and the offsets of the rows into it, as
+    /// returned by [`RowsEncoded::into_parts`].
+    ///
+    /// Row `i` is `values[offsets[i]..offsets[i + 1]]`. Fails if the offsets aren't monotonically
+    /// increasing or don't end at `values.len()`. Empty `offsets` are only allowed for empty
+    /// `values` and hold no rows.
+    pub fn from_parts(values: Vec<u8>, offsets: Vec<usize>) -> PolarsResult<Self> {
+        polars_ensure!(
+            offsets.windows(2).all(|w| w[0] <= w[1]),
+            ComputeError: "row offsets must be monotonically increasing"
+        );
+        let end = offsets.last().copied().unwrap_or(0);
+        polars_ensure!(
+            end == values.len(),
+            ComputeError: "row offsets end at {end}, but there are {} encoded bytes", values.len()
+        );
+        Ok(RowsEncoded::new(values, offsets))
+    }
+
+    /// Take the buffer of encoded bytes and the offsets of the rows into it without copying, see
+    /// [`RowsEncoded::from_parts`].
+    pub fn into_parts(self) -> (Vec<u8>, Vec<usize>) {
+        (self.values, self.offsets)
     }
 
+    /// Take the buffer of encoded bytes and the offsets of the rows into it as the buffers of a
+    /// [`BinaryArray`].
+    ///
+    /// This conversion is free on 64-bit platforms.
+    pub fn into_binary_offsets(self) -> (Buffer<u8>, OffsetsBuffer<i64>) {
+        let array = self.into_array();
+        (array.values().clone(), array.offsets().clone())
+    }
+
+    /// Iterate over the encoded bytes of every row.
     pub fn iter(&self) -> RowsEncodedIter<'_> {
-        let iter = self.offsets[1..].iter();
-        let offset = self.offsets[0];
+        let (offset, iter) = match self.offsets.split_first() {
+            Some((offset, end)) => (*offset, end.iter()),
+            None => (0, [].iter()),
+        };
         RowsEncodedIter {
             offset,
             end: iter,
@@ -203,7 +350,16 @@ impl RowsEncoded {
         binary_to_binview(&self.into_array())
     }
 
-    #[cfg(test)]
+    /// The number of encoded rows.
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The encoded bytes of row `i`.
     pub fn get(&self, i: usize) -> &[u8] {
         let start = self.offsets[i];
         let end = self.offsets[i + 1];
@@ -211,6 +367,24 @@ impl RowsEncoded {
     }
 }
 
+/// Compare two encoded rows by their bytes.
+///
+/// For rows encoded with ordered [`RowEncodingOptions`], this gives the same order as comparing
+/// the values they encode column by column. This is only valid if both rows were encoded with the
+/// same options and contexts for every column. Columns encoded with
+/// [`RowEncodingOptions::NO_ORDER`] only preserve equality, so rows that differ in such a column
+/// compare in an arbitrary order.
+#[inline]
+pub fn compare_rows(a: &[u8], b: &[u8]) -> std::cmp::Ordering {
+    a.cmp(b)
+}
+
+/// Compare rows `i` and `j` of `rows`, see [`compare_rows`].
+#[inline]
+pub fn compare_rows_at(rows: &RowsEncoded, i: usize, j: usize) -> std::cmp::Ordering {
+    compare_rows(rows.get(i), rows.get(j))
+}
+
 pub struct RowsEncodedIter<'a> {
     offset: usize,
     end: std::slice::Iter<'a, usize>,
@@ -222,7 +396,7 @@ impl<'a> Iterator for RowsEncodedIter<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let new_offset = *self.end.next()?;
-        let payload = unsafe { self.values.get_unchecked(self.offset..new_offset) };
+        let payload = unsafe { self.values.get_maybe_unchecked(self.offset..new_offset) };
         self.offset = new_offset;
         Some(payload)
     }
@@ -1,6 +1,8 @@
 #![allow(unsafe_op_in_unsafe_fn)]
 use arrow::bitmap::{Bitmap, BitmapBuilder};
 
+use crate::checked::MaybeUnchecked;
+
 #[macro_export]
 macro_rules! with_match_arrow_primitive_type {(
     $key_type:expr, | $_:tt $T:ident | $($body:tt)*
@@ -29,7 +31,7 @@ macro_rules! with_match_arrow_primitive_type {(
 pub(crate) unsafe fn decode_opt_nulls(rows: &[&[u8]], null_sentinel: u8) -> Option<Bitmap> {
     let first_null = rows
         .iter()
-        .position(|row| *row.get_unchecked(0) == null_sentinel)?;
+        .position(|row| *row.get_maybe_unchecked(0) == null_sentinel)?;
 
     let mut bm = BitmapBuilder::with_capacity(rows.len());
     bm.extend_constant(first_null, true);
@@ -38,7 +40,7 @@ pub(crate) unsafe fn decode_opt_nulls(rows: &[&[u8]], null_sentinel: u8) -> Opti
     bm.extend_trusted_len_iter(
         rows[first_null + 1..]
             .iter()
-            .map(|row| *row.get_unchecked(0) != null_sentinel),
+            .map(|row| *row.get_maybe_unchecked(0) != null_sentinel),
     );
 
     bm.into_opt_validity()
@@ -0,0 +1,588 @@
+//! Validation for the fallible row encoding entry points.
+//!
+//! The encoders and decoders themselves assume that the dtypes, options and contexts fit together
+//! and that the rows are valid encodings. These checks make sure they do before handing over.
+use std::fmt::Write;
+
+use arrow::array::{Array, FixedSizeListArray, ListArray, StructArray};
+use arrow::datatypes::ArrowDataType;
+
+use crate::ArrayRef;
+use crate::encode::{fixed_size, list_values_dict, struct_fields_opts_and_dicts};
+use crate::error::RowEncodeError;
+use crate::fixed::numeric::FixedLengthEncoding;
+use crate::fixed::{decimal, numeric};
+use crate::row::{RowEncodingContext, RowEncodingOptions};
+use crate::variable::binary::{BLOCK_CONTINUATION_TOKEN, BLOCK_SIZE, NON_EMPTY_SENTINEL};
+
+/// Check that there are exactly one set of options and one context per column.
+pub(crate) fn check_num_fields(
+    num_columns: usize,
+    num_opts: usize,
+    num_dicts: usize,
+) -> Result<(), RowEncodeError> {
+    let num_fields = num_opts.min(num_dicts);
+    if num_fields < num_columns {
+        return Err(RowEncodeError::MissingContext { column: num_fields });
+    }
+    if num_opts.max(num_dicts) > num_columns {
+        return Err(RowEncodeError::ContextMismatch {
+            path: num_columns.to_string(),
+            reason: format!(
+                "got {num_opts} row encoding options and {num_dicts} contexts for {num_columns} columns"
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Check that `columns` can be encoded into `num_rows` rows with `fields`.
+pub(crate) fn check_columns<'a>(
+    num_rows: usize,
+    columns: &[ArrayRef],
+    fields: impl IntoIterator<Item = (RowEncodingOptions, Option<&'a RowEncodingContext>)> + Clone,
+) -> Result<(), RowEncodeError> {
+    let num_fields = fields.clone().into_iter().count();
+    check_num_fields(columns.len(), num_fields, num_fields)?;
+
+    let mut total_width = 0usize;
+    for (column, (array, (opt, dict))) in columns.iter().zip(fields).enumerate() {
+        let chunks = std::slice::from_ref(array);
+        check_column_chunks(num_rows, column, chunks, opt, dict, &mut total_width)?;
+    }
+    Ok(())
+}
+
+/// Check that the chunks of every column of `columns` can be encoded into `num_rows` rows, like
+/// [`check_columns`] does for unchunked columns.
+pub(crate) fn check_chunked_columns<'a>(
+    num_rows: usize,
+    columns: &[Vec<ArrayRef>],
+    fields: impl IntoIterator<Item = (RowEncodingOptions, Option<&'a RowEncodingContext>)> + Clone,
+) -> Result<(), RowEncodeError> {
+    let num_fields = fields.clone().into_iter().count();
+    check_num_fields(columns.len(), num_fields, num_fields)?;
+
+    let mut total_width = 0usize;
+    for (column, (chunks, (opt, dict))) in columns.iter().zip(fields).enumerate() {
+        check_column_chunks(num_rows, column, chunks, opt, dict, &mut total_width)?;
+    }
+    Ok(())
+}
+
+/// Check that the chunks of `column` together have `num_rows` values that can be encoded, and add
+/// the width of the column to `total_width` if it has a fixed size.
+fn check_column_chunks(
+    num_rows: usize,
+    column: usize,
+    chunks: &[ArrayRef],
+    opt: RowEncodingOptions,
+    dict: Option<&RowEncodingContext>,
+    total_width: &mut usize,
+) -> Result<(), RowEncodeError> {
+    let len = chunks.iter().map(|chunk| chunk.len()).sum::<usize>();
+    if len != num_rows {
+        return Err(RowEncodeError::LengthMismatch {
+            column,
+            expected: num_rows,
+            got: len,
+        });
+    }
+
+    let mut path = column.to_string();
+    let mut width = None;
+    for chunk in chunks {
+        width = check_dtype(chunk.dtype(), opt, dict, false, &mut path)?;
+        if opt.contains(RowEncodingOptions::NON_NULLABLE) {
+            check_non_nullable(chunk.as_ref(), opt, dict, &mut path)?;
+        }
+    }
+
+    // Fixed size columns take up their width in every row.
+    if let Some(width) = width {
+        *total_width = width
+            .checked_mul(num_rows)
+            .and_then(|width| width.checked_add(*total_width))
+            .ok_or(RowEncodeError::Overflow { path })?;
+    }
+    Ok(())
+}
+
+/// Check that `array` has no null (nested) fixed-size lists or structs, which can't be encoded
+/// with [`RowEncodingOptions::NON_NULLABLE`].
+///
+/// The dtype of `array` should have been checked with [`check_dtype`].
+fn check_non_nullable(
+    array: &dyn Array,
+    opt: RowEncodingOptions,
+    dict: Option<&RowEncodingContext>,
+    path: &mut String,
+) -> Result<(), RowEncodeError> {
+    use ArrowDataType as D;
+
+    // Fields of structs may be encoded with options without the flag.
+    if !opt.contains(RowEncodingOptions::NON_NULLABLE) {
+        return Ok(());
+    }
+
+    let dtype = array.dtype();
+    if matches!(dtype, D::FixedSizeList(..) | D::Struct(_)) && array.null_count() > 0 {
+        return Err(RowEncodeError::UnexpectedNull { path: path.clone() });
+    }
+
+    match dtype {
+        D::List(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            with_segment(path, "[]", |path| {
+                check_non_nullable(
+                    array.values().as_ref(),
+                    opt.into_nested(),
+                    list_values_dict(dict),
+                    path,
+                )
+            })
+        },
+        D::LargeList(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            with_segment(path, "[]", |path| {
+                check_non_nullable(
+                    array.values().as_ref(),
+                    opt.into_nested(),
+                    list_values_dict(dict),
+                    path,
+                )
+            })
+        },
+        D::FixedSizeList(..) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            with_segment(path, "[]", |path| {
+                check_non_nullable(
+                    array.values().as_ref(),
+                    opt.into_nested(),
+                    list_values_dict(dict),
+                    path,
+                )
+            })
+        },
+        D::Struct(fields) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            for ((field, values), (opt, dict)) in fields
+                .iter()
+                .zip(array.values())
+                .zip(struct_fields_opts_and_dicts(opt, dict, dtype))
+            {
+                with_segment(path, format_args!(".{}", field.name), |path| {
+                    check_non_nullable(values.as_ref(), opt, dict, path)
+                })?;
+            }
+            Ok(())
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Check that columns of `dtypes` can be decoded with `opts` and `dicts`.
+pub(crate) fn check_dtypes(
+    dtypes: &[ArrowDataType],
+    opts: &[RowEncodingOptions],
+    dicts: &[Option<RowEncodingContext>],
+) -> Result<(), RowEncodeError> {
+    check_num_fields(dtypes.len(), opts.len(), dicts.len())?;
+    for (column, ((dtype, opt), dict)) in dtypes.iter().zip(opts).zip(dicts).enumerate() {
+        check_dtype(dtype, *opt, dict.as_ref(), true, &mut column.to_string())?;
+    }
+    Ok(())
+}
+
+fn context_mismatch(path: &str, reason: impl Into<String>) -> RowEncodeError {
+    RowEncodeError::ContextMismatch {
+        path: path.to_string(),
+        reason: reason.into(),
+    }
+}
+
+fn with_segment<T>(
+    path: &mut String,
+    segment: impl std::fmt::Display,
+    f: impl FnOnce(&mut String) -> T,
+) -> T {
+    let len = path.len();
+    write!(path, "{segment}").unwrap();
+    let out = f(path);
+    path.truncate(len);
+    out
+}
+
+/// Check that values of `dtype` can be encoded (or decoded) with `opt` and `dict`.
+///
+/// Returns the size of the encoded values if it is fixed, like [`fixed_size`].
+fn check_dtype(
+    dtype: &ArrowDataType,
+    opt: RowEncodingOptions,
+    dict: Option<&RowEncodingContext>,
+    decoding: bool,
+    path: &mut String,
+) -> Result<Option<usize>, RowEncodeError> {
+    use ArrowDataType as D;
+
+    if let Some(RowEncodingContext::InlineDictionary(_)) = dict {
+        if !decoding {
+            return Err(context_mismatch(
+                path,
+                "inline dictionaries are built while encoding",
+            ));
+        }
+        if dtype != &D::Utf8View {
+            return Err(context_mismatch(
+                path,
+                format!("inline dictionary context for {dtype:?}"),
+            ));
+        }
+        return Ok(fixed_size(dtype, opt, dict));
+    }
+
+    if let Some(RowEncodingContext::List(_)) = dict {
+        if !matches!(dtype, D::List(_) | D::LargeList(_) | D::FixedSizeList(_, _)) {
+            return Err(context_mismatch(
+                path,
+                format!("list context for {dtype:?}"),
+            ));
+        }
+    }
+
+    if let Some(RowEncodingContext::Categorical(_)) = dict {
+        match dtype {
+            D::UInt8 | D::UInt16 | D::UInt32 => return Ok(fixed_size(dtype, opt, dict)),
+            // Lists pass the context on to their values.
+            D::List(_) | D::LargeList(_) | D::FixedSizeList(_, _) => {},
+            _ => {
+                return Err(context_mismatch(
+                    path,
+                    format!("categorical context for {dtype:?}"),
+                ));
+            },
+        }
+    }
+
+    match dtype {
+        D::Null
+        | D::Boolean
+        | D::Int8
+        | D::Int16
+        | D::Int32
+        | D::Int64
+        | D::UInt8
+        | D::UInt16
+        | D::UInt32
+        | D::UInt64
+        | D::UInt128
+        | D::Float16
+        | D::Float32
+        | D::Float64 => Ok(fixed_size(dtype, opt, dict)),
+        D::Int128 => match dict {
+            None => Ok(fixed_size(dtype, opt, dict)),
+            Some(RowEncodingContext::Decimal(precision)) if *precision <= 38 => {
+                Ok(Some(decimal::len_from_precision(*precision)))
+            },
+            Some(RowEncodingContext::Decimal(precision)) => Err(context_mismatch(
+                path,
+                format!("decimal precision {precision} is larger than 38"),
+            )),
+            Some(_) => Err(context_mismatch(path, "expected a decimal context")),
+        },
+        D::Decimal(precision, _) | D::Decimal32(precision, _) | D::Decimal64(precision, _) => {
+            if *precision > 38 {
+                return Err(RowEncodeError::UnsupportedDtype {
+                    dtype: dtype.clone(),
+                    path: path.clone(),
+                });
+            }
+            Ok(Some(decimal::len_from_precision(*precision)))
+        },
+        D::FixedSizeBinary(size) => size
+            .checked_add(1)
+            .map(Some)
+            .ok_or_else(|| RowEncodeError::Overflow { path: path.clone() }),
+
+        D::Binary | D::LargeBinary | D::BinaryView | D::Utf8 | D::LargeUtf8 | D::Utf8View => {
+            Ok(None)
+        },
+
+        D::List(field) | D::LargeList(field) => {
+            with_segment(path, "[]", |path| {
+                let dict = list_values_dict(dict);
+                check_dtype(field.dtype(), opt.into_nested(), dict, decoding, path)
+            })?;
+            Ok(None)
+        },
+        D::FixedSizeList(field, width) => {
+            let size = with_segment(path, "[]", |path| {
+                let dict = list_values_dict(dict);
+                check_dtype(field.dtype(), opt.into_nested(), dict, decoding, path)
+            })?;
+            let Some(size) = size else {
+                return Ok(None);
+            };
+            size.checked_mul(*width)
+                .and_then(|size| size.checked_add(opt.nested_validity_len()))
+                .map(Some)
+                .ok_or_else(|| RowEncodeError::Overflow { path: path.clone() })
+        },
+        D::Struct(fields) => {
+            let num_entries = match dict {
+                None => fields.len(),
+                Some(RowEncodingContext::Struct(dicts)) => dicts.len(),
+                Some(RowEncodingContext::StructWithOptions(fields)) => fields.len(),
+                Some(_) => return Err(context_mismatch(path, "expected a struct context")),
+            };
+            if num_entries != fields.len() {
+                return Err(context_mismatch(
+                    path,
+                    format!(
+                        "row encoding context for {dtype:?} has {num_entries} entries, but the struct has {} fields",
+                        fields.len()
+                    ),
+                ));
+            }
+
+            let mut size = Some(opt.nested_validity_len());
+            for (field, (opt, dict)) in fields
+                .iter()
+                .zip(struct_fields_opts_and_dicts(opt, dict, dtype))
+            {
+                let field_size = with_segment(path, format_args!(".{}", field.name), |path| {
+                    check_dtype(field.dtype(), opt, dict, decoding, path)
+                })?;
+                size = match (size, field_size) {
+                    (Some(size), Some(field_size)) => Some(
+                        size.checked_add(field_size)
+                            .ok_or_else(|| RowEncodeError::Overflow { path: path.clone() })?,
+                    ),
+                    _ => None,
+                };
+            }
+            Ok(size)
+        },
+
+        // Dictionary arrays are encoded as their values, but always decode to the values.
+        D::Dictionary(_, values, _) if !decoding => check_dtype(values, opt, dict, decoding, path),
+
+        _ => Err(RowEncodeError::UnsupportedDtype {
+            dtype: dtype.clone(),
+            path: path.clone(),
+        }),
+    }
+}
+
+/// An invalid encoded value, without the position of the value.
+enum InvalidValue {
+    Truncated,
+    Malformed(&'static str),
+}
+
+impl InvalidValue {
+    fn at(self, row: usize, column: usize) -> RowEncodeError {
+        match self {
+            Self::Truncated => RowEncodeError::Truncated { row, column },
+            Self::Malformed(reason) => RowEncodeError::Malformed {
+                row,
+                column,
+                reason,
+            },
+        }
+    }
+}
+
+/// Check that every row in `rows` consists of valid encodings of a value for each of the columns.
+///
+/// Rows may have bytes left after the last column. The dtypes, options and contexts should have
+/// been checked with [`check_dtypes`].
+pub(crate) fn check_rows(
+    rows: &[&[u8]],
+    opts: &[RowEncodingOptions],
+    dicts: &[Option<RowEncodingContext>],
+    dtypes: &[ArrowDataType],
+) -> Result<(), RowEncodeError> {
+    let mut checker = RowChecker::default();
+    for (row_idx, row) in rows.iter().enumerate() {
+        let mut row = *row;
+        for (column, ((dtype, opt), dict)) in dtypes.iter().zip(opts).zip(dicts).enumerate() {
+            checker
+                .check_value(&mut row, *opt, dict.as_ref(), dtype)
+                .map_err(|err| err.at(row_idx, column))?;
+        }
+    }
+    Ok(())
+}
+
+fn take<'a>(row: &mut &'a [u8], length: usize) -> Result<&'a [u8], InvalidValue> {
+    if row.len() < length {
+        return Err(InvalidValue::Truncated);
+    }
+    let value;
+    (value, *row) = row.split_at(length);
+    Ok(value)
+}
+
+#[derive(Default)]
+struct RowChecker {
+    scratch: Vec<u8>,
+}
+
+impl RowChecker {
+    /// Check the encoded value at the start of `row` and advance `row` past it.
+    fn check_value(
+        &mut self,
+        row: &mut &[u8],
+        opt: RowEncodingOptions,
+        dict: Option<&RowEncodingContext>,
+        dtype: &ArrowDataType,
+    ) -> Result<(), InvalidValue> {
+        use ArrowDataType as D;
+
+        if let Some(RowEncodingContext::InlineDictionary(dictionary)) = dict {
+            let value = take(row, u32::ENCODED_LEN)?;
+            // SAFETY: We took an encoded u32.
+            let ordinal = unsafe { numeric::decode_value::<u32>(value, opt) };
+            if ordinal.is_some_and(|o| o as usize >= dictionary.values.len()) {
+                return Err(InvalidValue::Malformed(
+                    "inline dictionary ordinal out of bounds",
+                ));
+            }
+            return Ok(());
+        }
+
+        if let Some(size) = fixed_size(dtype, opt, dict) {
+            take(row, size)?;
+            return Ok(());
+        }
+
+        // Categoricals that are not fixed size are encoded as their strings.
+        match dtype {
+            D::Binary
+            | D::LargeBinary
+            | D::BinaryView
+            | D::Utf8
+            | D::LargeUtf8
+            | D::Utf8View
+            | D::UInt8
+            | D::UInt16
+            | D::UInt32
+                if opt.contains(RowEncodingOptions::NO_ORDER) =>
+            {
+                let value = check_no_order(row)?;
+                let is_utf8 = !matches!(dtype, D::Binary | D::LargeBinary | D::BinaryView);
+                if is_utf8 && value.is_some_and(|v| std::str::from_utf8(v).is_err()) {
+                    return Err(InvalidValue::Malformed("invalid UTF-8"));
+                }
+                Ok(())
+            },
+            D::Binary | D::LargeBinary | D::BinaryView => check_binary(row, opt),
+            D::Utf8 | D::LargeUtf8 | D::Utf8View | D::UInt8 | D::UInt16 | D::UInt32 => {
+                self.check_str(row, opt)
+            },
+
+            D::List(field) | D::LargeList(field) => {
+                let dict = list_values_dict(dict);
+                loop {
+                    let token = take(row, 1)?[0];
+                    if token == opt.list_continuation_token() {
+                        self.check_value(row, opt.into_nested(), dict, field.dtype())?;
+                    } else if token == opt.list_null_sentinel()
+                        || token == opt.list_termination_token()
+                    {
+                        return Ok(());
+                    } else {
+                        return Err(InvalidValue::Malformed("invalid list token"));
+                    }
+                }
+            },
+            D::FixedSizeList(field, width) => {
+                take(row, opt.nested_validity_len())?;
+                let dict = list_values_dict(dict);
+                for _ in 0..*width {
+                    self.check_value(row, opt.into_nested(), dict, field.dtype())?;
+                }
+                Ok(())
+            },
+            D::Struct(fields) => {
+                take(row, opt.nested_validity_len())?;
+                for (field, (opt, dict)) in fields
+                    .iter()
+                    .zip(struct_fields_opts_and_dicts(opt, dict, dtype))
+                {
+                    self.check_value(row, opt, dict, field.dtype())?;
+                }
+                Ok(())
+            },
+
+            // All other dtypes are either fixed size or rejected by `check_dtype`.
+            _ => unreachable!("unexpected dtype {dtype:?} when checking rows"),
+        }
+    }
+
+    fn check_str(&mut self, row: &mut &[u8], opt: RowEncodingOptions) -> Result<(), InvalidValue> {
+        let Some(&first) = row.first() else {
+            return Err(InvalidValue::Truncated);
+        };
+        if first == opt.null_sentinel() {
+            *row = &row[1..];
+            return Ok(());
+        }
+
+        let descending = opt.contains(RowEncodingOptions::DESCENDING);
+        let terminator = if descending { 0xFE } else { 0x01 };
+        let Some(end) = row.iter().position(|&b| b == terminator) else {
+            return Err(InvalidValue::Truncated);
+        };
+
+        self.scratch.clear();
+        for &b in &row[..end] {
+            let b = if descending { !b } else { b };
+            if b < 2 {
+                return Err(InvalidValue::Malformed("invalid string byte"));
+            }
+            self.scratch.push(b - 2);
+        }
+        if std::str::from_utf8(&self.scratch).is_err() {
+            return Err(InvalidValue::Malformed("invalid UTF-8"));
+        }
+        *row = &row[end + 1..];
+        Ok(())
+    }
+}
+
+fn check_binary(row: &mut &[u8], opt: RowEncodingOptions) -> Result<(), InvalidValue> {
+    let descending = opt.contains(RowEncodingOptions::DESCENDING);
+    let (non_empty_sentinel, continuation_token) = if descending {
+        (!NON_EMPTY_SENTINEL, !BLOCK_CONTINUATION_TOKEN)
+    } else {
+        (NON_EMPTY_SENTINEL, BLOCK_CONTINUATION_TOKEN)
+    };
+
+    // Null or empty.
+    if take(row, 1)?[0] != non_empty_sentinel {
+        return Ok(());
+    }
+
+    loop {
+        let sentinel = take(row, BLOCK_SIZE + 1)?[BLOCK_SIZE];
+        if sentinel == continuation_token {
+            continue;
+        }
+        let block_length = if descending { !sentinel } else { sentinel } as usize;
+        if !(1..=BLOCK_SIZE).contains(&block_length) {
+            return Err(InvalidValue::Malformed("invalid binary block length"));
+        }
+        return Ok(());
+    }
+}
+
+fn check_no_order<'a>(row: &mut &'a [u8]) -> Result<Option<&'a [u8]>, InvalidValue> {
+    let length = match take(row, 1)?[0] {
+        0xFF => return Ok(None),
+        0xFE => u32::from_le_bytes(take(row, 4)?.try_into().unwrap()) as usize,
+        length => length as usize,
+    };
+    take(row, length).map(Some)
+}
@@ -15,6 +15,7 @@ use std::mem::MaybeUninit;
 use arrow::array::{BinaryViewArray, MutableBinaryViewArray};
 use polars_utils::slice::Slice2Uninit;
 
+use crate::checked::MaybeUnchecked;
 use crate::row::RowEncodingOptions;
 use crate::utils::decode_opt_nulls;
 
@@ -65,29 +66,32 @@ unsafe fn encode_one(
             } else {
                 EMPTY_SENTINEL
             };
-            *out.get_unchecked_mut(0) = MaybeUninit::new(byte);
+            *out.get_maybe_unchecked_mut(0) = MaybeUninit::new(byte);
             1
         },
         Some(val) => {
             let block_count = ceil(val.len(), BLOCK_SIZE);
             let end_offset = 1 + block_count * (BLOCK_SIZE + 1);
 
-            let dst = out.get_unchecked_mut(..end_offset);
+            let dst = out.get_maybe_unchecked_mut(..end_offset);
 
             // Write `2_u8` to demarcate as non-empty, non-null string
-            *dst.get_unchecked_mut(0) = MaybeUninit::new(NON_EMPTY_SENTINEL);
+            *dst.get_maybe_unchecked_mut(0) = MaybeUninit::new(NON_EMPTY_SENTINEL);
 
             let src_chunks = val.chunks_exact(BLOCK_SIZE);
             let src_remainder = src_chunks.remainder();
 
             // + 1 is for the BLOCK CONTINUATION TOKEN
-            let dst_chunks = dst.get_unchecked_mut(1..).chunks_exact_mut(BLOCK_SIZE + 1);
+            let dst_chunks = dst
+                .get_maybe_unchecked_mut(1..)
+                .chunks_exact_mut(BLOCK_SIZE + 1);
 
             for (src, dst) in src_chunks.zip(dst_chunks) {
                 // we copy src.len() that leaves 1 bytes for the continuation tkn.
                 std::ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), src.len());
                 // Indicate that there are further blocks to follow
-                *dst.get_unchecked_mut(BLOCK_SIZE) = MaybeUninit::new(BLOCK_CONTINUATION_TOKEN);
+                *dst.get_maybe_unchecked_mut(BLOCK_SIZE) =
+                    MaybeUninit::new(BLOCK_CONTINUATION_TOKEN);
             }
 
             // exactly BLOCK_SIZE bytes
@@ -103,17 +107,19 @@ unsafe fn encode_one(
             else {
                 // get the last block
                 let start_offset = 1 + (block_count - 1) * (BLOCK_SIZE + 1);
-                let last_dst = dst.get_unchecked_mut(start_offset..);
+                let last_dst = dst.get_maybe_unchecked_mut(start_offset..);
                 let n_bytes_to_write = src_remainder.len();
 
                 std::ptr::copy_nonoverlapping(
                     src_remainder.as_ptr(),
-                    last_dst.as_mut_ptr(),
+                    last_dst
+                        .get_maybe_unchecked_mut(..n_bytes_to_write)
+                        .as_mut_ptr(),
                     n_bytes_to_write,
                 );
                 // write remainder as zeros
                 last_dst
-                    .get_unchecked_mut(n_bytes_to_write..last_dst.len() - 1)
+                    .get_maybe_unchecked_mut(n_bytes_to_write..last_dst.len() - 1)
                     .fill(MaybeUninit::new(0));
                 *dst.last_mut().unwrap_unchecked() = MaybeUninit::new(src_remainder.len() as u8);
             }
@@ -126,7 +132,7 @@ unsafe fn encode_one(
             end_offset
         },
         None => {
-            *out.get_unchecked_mut(0) = MaybeUninit::new(opt.null_sentinel());
+            *out.get_maybe_unchecked_mut(0) = MaybeUninit::new(opt.null_sentinel());
             // // write remainder as zeros
             // out.get_unchecked_mut(1..).fill(MaybeUninit::new(0));
             1
@@ -141,7 +147,7 @@ pub(crate) unsafe fn encode_iter<'a, I: Iterator<Item = Option<&'a [u8]>>>(
     row_starts: &mut [usize],
 ) {
     for (offset, opt_value) in row_starts.iter_mut().zip(input) {
-        let dst = buffer.get_unchecked_mut(*offset..);
+        let dst = buffer.get_maybe_unchecked_mut(*offset..);
         let written_len = encode_one(dst, opt_value.map(|v| v.as_uninit()), opt);
         *offset += written_len;
     }
@@ -156,13 +162,13 @@ pub(crate) unsafe fn encoded_item_len(row: &[u8], opt: RowEncodingOptions) -> us
     };
 
     // empty or null
-    if *row.get_unchecked(0) != non_empty_sentinel {
+    if *row.get_maybe_unchecked(0) != non_empty_sentinel {
         return 1;
     }
 
     let mut idx = 1;
     loop {
-        let sentinel = *row.get_unchecked(idx + BLOCK_SIZE);
+        let sentinel = *row.get_maybe_unchecked(idx + BLOCK_SIZE);
         if sentinel == continuation_token {
             idx += BLOCK_SIZE + 1;
             continue;
@@ -178,14 +184,14 @@ unsafe fn decoded_len(
     descending: bool,
 ) -> usize {
     // empty or null
-    if *row.get_unchecked(0) != non_empty_sentinel {
+    if *row.get_maybe_unchecked(0) != non_empty_sentinel {
         return 0;
     }
 
     let mut str_len = 0;
     let mut idx = 1;
     loop {
-        let sentinel = *row.get_unchecked(idx + BLOCK_SIZE);
+        let sentinel = *row.get_maybe_unchecked(idx + BLOCK_SIZE);
         if sentinel == continuation_token {
             idx += BLOCK_SIZE + 1;
             str_len += BLOCK_SIZE;
@@ -229,15 +235,15 @@ pub(crate) unsafe fn decode_binview(
 
         while to_read >= BLOCK_SIZE {
             to_read -= BLOCK_SIZE;
-            scratch.extend_from_slice(row.get_unchecked(offset..offset + BLOCK_SIZE));
+            scratch.extend_from_slice(row.get_maybe_unchecked(offset..offset + BLOCK_SIZE));
             offset += BLOCK_SIZE + 1;
         }
 
         if to_read != 0 {
-            scratch.extend_from_slice(row.get_unchecked(offset..offset + to_read));
+            scratch.extend_from_slice(row.get_maybe_unchecked(offset..offset + to_read));
             offset += BLOCK_SIZE + 1;
         }
-        *row = row.get_unchecked(offset..);
+        *row = row.get_maybe_unchecked(offset..);
 
         if descending {
             scratch.iter_mut().for_each(|o| *o = !*o)
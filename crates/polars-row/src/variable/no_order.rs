@@ -14,6 +14,7 @@ use arrow::array::{BinaryViewArray, MutableBinaryViewArray};
 use arrow::bitmap::BitmapBuilder;
 use polars_utils::slice::Slice2Uninit;
 
+use crate::checked::MaybeUnchecked;
 use crate::row::RowEncodingOptions;
 
 pub fn len_from_item(value: Option<usize>, opt: RowEncodingOptions) -> usize {
@@ -29,13 +30,16 @@ pub fn len_from_item(value: Option<usize>, opt: RowEncodingOptions) -> usize {
 pub unsafe fn len_from_buffer(buffer: &[u8], opt: RowEncodingOptions) -> usize {
     debug_assert!(opt.contains(RowEncodingOptions::NO_ORDER));
 
-    let sentinel = *unsafe { buffer.get_unchecked(0) };
+    let sentinel = *unsafe { buffer.get_maybe_unchecked(0) };
 
     match sentinel {
         0xFF => 1,
         0xFE => {
-            5 + u32::from_le_bytes(unsafe { buffer.get_unchecked(1..5) }.try_into().unwrap())
-                as usize
+            5 + u32::from_le_bytes(
+                unsafe { buffer.get_maybe_unchecked(1..5) }
+                    .try_into()
+                    .unwrap(),
+            ) as usize
         },
         length => 1 + length as usize,
     }
@@ -50,29 +54,29 @@ pub unsafe fn encode_variable_no_order<'a, I: Iterator<Item = Option<&'a [u8]>>>
     debug_assert!(opt.contains(RowEncodingOptions::NO_ORDER));
 
     for (offset, opt_value) in offsets.iter_mut().zip(input) {
-        let buffer = unsafe { buffer.get_unchecked_mut(*offset..) };
+        let buffer = unsafe { buffer.get_maybe_unchecked_mut(*offset..) };
         match opt_value {
             None => {
-                *unsafe { buffer.get_unchecked_mut(0) } = MaybeUninit::new(0xFF);
+                *unsafe { buffer.get_maybe_unchecked_mut(0) } = MaybeUninit::new(0xFF);
                 *offset += 1;
             },
             Some(v) => {
                 if v.len() >= 254 {
                     unsafe {
-                        *buffer.get_unchecked_mut(0) = MaybeUninit::new(0xFE);
+                        *buffer.get_maybe_unchecked_mut(0) = MaybeUninit::new(0xFE);
                         buffer
-                            .get_unchecked_mut(1..5)
+                            .get_maybe_unchecked_mut(1..5)
                             .copy_from_slice((v.len() as u32).to_le_bytes().as_uninit());
                         buffer
-                            .get_unchecked_mut(5..5 + v.len())
+                            .get_maybe_unchecked_mut(5..5 + v.len())
                             .copy_from_slice(v.as_uninit());
                     }
                     *offset += 5 + v.len();
                 } else {
                     unsafe {
-                        *buffer.get_unchecked_mut(0) = MaybeUninit::new(v.len() as u8);
+                        *buffer.get_maybe_unchecked_mut(0) = MaybeUninit::new(v.len() as u8);
                         buffer
-                            .get_unchecked_mut(1..1 + v.len())
+                            .get_maybe_unchecked_mut(1..1 + v.len())
                             .copy_from_slice(v.as_uninit());
                     }
                     *offset += 1 + v.len();
@@ -82,6 +86,25 @@ pub unsafe fn encode_variable_no_order<'a, I: Iterator<Item = Option<&'a [u8]>>>
     }
 }
 
+/// Decode the value at the start of `row` without decoding into an array.
+pub unsafe fn decode_variable_no_order_value(row: &[u8], opt: RowEncodingOptions) -> Option<&[u8]> {
+    debug_assert!(opt.contains(RowEncodingOptions::NO_ORDER));
+
+    let sentinel = *unsafe { row.get_maybe_unchecked(0) };
+    if sentinel == 0xFF {
+        return None;
+    }
+
+    let (length, row) = if sentinel < 0xFE {
+        (sentinel as usize, unsafe { row.get_maybe_unchecked(1..) })
+    } else {
+        let length =
+            u32::from_le_bytes(unsafe { row.get_maybe_unchecked(1..5) }.try_into().unwrap());
+        (length as usize, unsafe { row.get_maybe_unchecked(5..) })
+    };
+    Some(unsafe { row.get_maybe_unchecked(..length) })
+}
+
 pub unsafe fn decode_variable_no_order(
     rows: &mut [&[u8]],
     opt: RowEncodingOptions,
@@ -93,8 +116,8 @@ pub unsafe fn decode_variable_no_order(
     let mut validity = BitmapBuilder::new();
 
     for row in rows.iter_mut() {
-        let sentinel = *unsafe { row.get_unchecked(0) };
-        *row = unsafe { row.get_unchecked(1..) };
+        let sentinel = *unsafe { row.get_maybe_unchecked(0) };
+        *row = unsafe { row.get_maybe_unchecked(1..) };
         if sentinel == 0xFF {
             validity.reserve(num_rows);
             validity.extend_constant(array.len(), true);
@@ -106,13 +129,14 @@ pub unsafe fn decode_variable_no_order(
         let length = if sentinel < 0xFE {
             sentinel as usize
         } else {
-            let length = u32::from_le_bytes(unsafe { row.get_unchecked(..4) }.try_into().unwrap());
-            *row = unsafe { row.get_unchecked(4..) };
+            let length =
+                u32::from_le_bytes(unsafe { row.get_maybe_unchecked(..4) }.try_into().unwrap());
+            *row = unsafe { row.get_maybe_unchecked(4..) };
             length as usize
         };
 
-        array.push_value_ignore_validity(unsafe { row.get_unchecked(..length) });
-        *row = unsafe { row.get_unchecked(length..) };
+        array.push_value_ignore_validity(unsafe { row.get_maybe_unchecked(..length) });
+        *row = unsafe { row.get_maybe_unchecked(length..) };
     }
 
     if validity.is_empty() {
@@ -120,8 +144,8 @@ pub unsafe fn decode_variable_no_order(
     }
 
     for row in rows[array.len()..].iter_mut() {
-        let sentinel = *unsafe { row.get_unchecked(0) };
-        *row = unsafe { row.get_unchecked(1..) };
+        let sentinel = *unsafe { row.get_maybe_unchecked(0) };
+        *row = unsafe { row.get_maybe_unchecked(1..) };
 
         validity.push(sentinel != 0xFF);
         if sentinel == 0xFF {
@@ -132,13 +156,14 @@ pub unsafe fn decode_variable_no_order(
         let length = if sentinel < 0xFE {
             sentinel as usize
         } else {
-            let length = u32::from_le_bytes(unsafe { row.get_unchecked(..4) }.try_into().unwrap());
-            *row = unsafe { row.get_unchecked(4..) };
+            let length =
+                u32::from_le_bytes(unsafe { row.get_maybe_unchecked(..4) }.try_into().unwrap());
+            *row = unsafe { row.get_maybe_unchecked(4..) };
             length as usize
         };
 
-        array.push_value_ignore_validity(unsafe { row.get_unchecked(..length) });
-        *row = unsafe { row.get_unchecked(length..) };
+        array.push_value_ignore_validity(unsafe { row.get_maybe_unchecked(..length) });
+        *row = unsafe { row.get_maybe_unchecked(length..) };
     }
 
     let array = array.freeze();
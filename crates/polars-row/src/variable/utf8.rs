@@ -15,7 +15,10 @@ use arrow::bitmap::BitmapBuilder;
 use arrow::types::NativeType;
 use polars_dtype::categorical::{CatNative, CategoricalMapping};
 
-use crate::row::RowEncodingOptions;
+use crate::checked::{
+    MaybeUnchecked, str_from_utf8_maybe_unchecked, string_from_utf8_maybe_unchecked,
+};
+use crate::row::{Collation, RowEncodingOptions};
 
 #[inline]
 pub fn len_from_item(a: Option<usize>, _opt: RowEncodingOptions) -> usize {
@@ -26,7 +29,7 @@ pub fn len_from_item(a: Option<usize>, _opt: RowEncodingOptions) -> usize {
 
 pub unsafe fn len_from_buffer(row: &[u8], opt: RowEncodingOptions) -> usize {
     // null
-    if *row.get_unchecked(0) == opt.null_sentinel() {
+    if *row.get_maybe_unchecked(0) == opt.null_sentinel() {
         return 1;
     }
 
@@ -53,24 +56,57 @@ pub unsafe fn encode_str<'a, I: Iterator<Item = Option<&'a str>>>(
     };
 
     for (offset, opt_value) in offsets.iter_mut().zip(input) {
-        let dst = buffer.get_unchecked_mut(*offset..);
+        let dst = buffer.get_maybe_unchecked_mut(*offset..);
 
         match opt_value {
             None => {
-                *unsafe { dst.get_unchecked_mut(0) } = MaybeUninit::new(null_sentinel);
+                *unsafe { dst.get_maybe_unchecked_mut(0) } = MaybeUninit::new(null_sentinel);
                 *offset += 1;
             },
             Some(s) => {
-                for (i, &b) in s.as_bytes().iter().enumerate() {
-                    *unsafe { dst.get_unchecked_mut(i) } = MaybeUninit::new(t ^ (b + 2));
+                match opt.collation() {
+                    Collation::Binary => {
+                        for (i, &b) in s.as_bytes().iter().enumerate() {
+                            *unsafe { dst.get_maybe_unchecked_mut(i) } =
+                                MaybeUninit::new(t ^ (b + 2));
+                        }
+                    },
+                    // Folding ASCII letters keeps the string valid UTF-8, as multi-byte
+                    // characters never contain ASCII bytes.
+                    Collation::AsciiCaseInsensitive => {
+                        for (i, &b) in s.as_bytes().iter().enumerate() {
+                            *unsafe { dst.get_maybe_unchecked_mut(i) } =
+                                MaybeUninit::new(t ^ (b.to_ascii_lowercase() + 2));
+                        }
+                    },
                 }
-                *unsafe { dst.get_unchecked_mut(s.len()) } = MaybeUninit::new(t ^ 0x01);
+                *unsafe { dst.get_maybe_unchecked_mut(s.len()) } = MaybeUninit::new(t ^ 0x01);
                 *offset += 1 + s.len();
             },
         }
     }
 }
 
+/// Decode the value at the start of `row` without decoding into an array.
+pub unsafe fn decode_str_value(row: &[u8], opt: RowEncodingOptions) -> Option<String> {
+    if *row.get_maybe_unchecked(0) == opt.null_sentinel() {
+        return None;
+    }
+
+    let bytes = if opt.contains(RowEncodingOptions::DESCENDING) {
+        row.iter()
+            .take_while(|&b| *b != 0xFE)
+            .map(|&v| !v - 2)
+            .collect()
+    } else {
+        row.iter()
+            .take_while(|&b| *b != 0x01)
+            .map(|&v| v - 2)
+            .collect()
+    };
+    Some(string_from_utf8_maybe_unchecked(bytes))
+}
+
 pub unsafe fn decode_str(rows: &mut [&[u8]], opt: RowEncodingOptions) -> Utf8ViewArray {
     let null_sentinel = opt.null_sentinel();
     let descending = opt.contains(RowEncodingOptions::DESCENDING);
@@ -80,9 +116,9 @@ pub unsafe fn decode_str(rows: &mut [&[u8]], opt: RowEncodingOptions) -> Utf8Vie
 
     let mut scratch = Vec::new();
     for row in rows.iter_mut() {
-        let sentinel = *unsafe { row.get_unchecked(0) };
+        let sentinel = *unsafe { row.get_maybe_unchecked(0) };
         if sentinel == null_sentinel {
-            *row = unsafe { row.get_unchecked(1..) };
+            *row = unsafe { row.get_maybe_unchecked(1..) };
             break;
         }
 
@@ -93,8 +129,8 @@ pub unsafe fn decode_str(rows: &mut [&[u8]], opt: RowEncodingOptions) -> Utf8Vie
             scratch.extend(row.iter().take_while(|&b| *b != 0x01).map(|&v| v - 2));
         }
 
-        *row = row.get_unchecked(1 + scratch.len()..);
-        array.push_value_ignore_validity(unsafe { std::str::from_utf8_unchecked(&scratch) });
+        *row = row.get_maybe_unchecked(1 + scratch.len()..);
+        array.push_value_ignore_validity(unsafe { str_from_utf8_maybe_unchecked(&scratch) });
     }
 
     if array.len() == num_rows {
@@ -107,10 +143,10 @@ pub unsafe fn decode_str(rows: &mut [&[u8]], opt: RowEncodingOptions) -> Utf8Vie
     array.push_value_ignore_validity("");
 
     for row in rows[array.len()..].iter_mut() {
-        let sentinel = *unsafe { row.get_unchecked(0) };
+        let sentinel = *unsafe { row.get_maybe_unchecked(0) };
         validity.push(sentinel != null_sentinel);
         if sentinel == null_sentinel {
-            *row = unsafe { row.get_unchecked(1..) };
+            *row = unsafe { row.get_maybe_unchecked(1..) };
             array.push_value_ignore_validity("");
             continue;
         }
@@ -122,8 +158,8 @@ pub unsafe fn decode_str(rows: &mut [&[u8]], opt: RowEncodingOptions) -> Utf8Vie
             scratch.extend(row.iter().take_while(|&b| *b != 0x01).map(|&v| v - 2));
         }
 
-        *row = row.get_unchecked(1 + scratch.len()..);
-        array.push_value_ignore_validity(unsafe { std::str::from_utf8_unchecked(&scratch) });
+        *row = row.get_maybe_unchecked(1 + scratch.len()..);
+        array.push_value_ignore_validity(unsafe { str_from_utf8_maybe_unchecked(&scratch) });
     }
 
     let out: Utf8ViewArray = array.into();
@@ -145,9 +181,9 @@ pub unsafe fn decode_str_as_cat<T: NativeType + CatNative>(
 
     let mut scratch = Vec::new();
     for row in rows.iter_mut() {
-        let sentinel = *unsafe { row.get_unchecked(0) };
+        let sentinel = *unsafe { row.get_maybe_unchecked(0) };
         if sentinel == null_sentinel {
-            *row = unsafe { row.get_unchecked(1..) };
+            *row = unsafe { row.get_maybe_unchecked(1..) };
             break;
         }
 
@@ -158,8 +194,8 @@ pub unsafe fn decode_str_as_cat<T: NativeType + CatNative>(
             scratch.extend(row.iter().take_while(|&b| *b != 0x01).map(|&v| v - 2));
         }
 
-        *row = row.get_unchecked(1 + scratch.len()..);
-        let s = unsafe { std::str::from_utf8_unchecked(&scratch) };
+        *row = row.get_maybe_unchecked(1 + scratch.len()..);
+        let s = unsafe { str_from_utf8_maybe_unchecked(&scratch) };
         out.push(T::from_cat(mapping.insert_cat(s).unwrap()));
     }
 
@@ -173,10 +209,10 @@ pub unsafe fn decode_str_as_cat<T: NativeType + CatNative>(
     out.push(T::zeroed());
 
     for row in rows[out.len()..].iter_mut() {
-        let sentinel = *unsafe { row.get_unchecked(0) };
+        let sentinel = *unsafe { row.get_maybe_unchecked(0) };
         validity.push(sentinel != null_sentinel);
         if sentinel == null_sentinel {
-            *row = unsafe { row.get_unchecked(1..) };
+            *row = unsafe { row.get_maybe_unchecked(1..) };
             out.push(T::zeroed());
             continue;
         }
@@ -188,8 +224,8 @@ pub unsafe fn decode_str_as_cat<T: NativeType + CatNative>(
             scratch.extend(row.iter().take_while(|&b| *b != 0x01).map(|&v| v - 2));
         }
 
-        *row = row.get_unchecked(1 + scratch.len()..);
-        let s = unsafe { std::str::from_utf8_unchecked(&scratch) };
+        *row = row.get_maybe_unchecked(1 + scratch.len()..);
+        let s = unsafe { str_from_utf8_maybe_unchecked(&scratch) };
         out.push(T::from_cat(mapping.insert_cat(s).unwrap()));
     }
 
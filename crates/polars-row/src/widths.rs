@@ -67,26 +67,72 @@ impl RowWidths {
         }
     }
 
-    /// Create a [`RowWidths`] with the chunked sum with a certain `chunk_size`.
-    pub fn collapse_chunks(&self, chunk_size: usize, output_num_rows: usize) -> RowWidths {
-        if chunk_size == 0 {
-            assert_eq!(self.num_rows(), 0);
-            return RowWidths::new(output_num_rows);
+    /// Append the widths of the rows of `other` after the rows of `self`.
+    pub fn append(&mut self, other: &Self) {
+        if other.num_rows() == 0 {
+            return;
+        }
+        if self.num_rows() == 0 {
+            *self = other.clone();
+            return;
+        }
+        if let (
+            Self::Constant { num_rows, width },
+            Self::Constant {
+                num_rows: other_num_rows,
+                width: other_width,
+            },
+        ) = (&mut *self, other)
+            && *width == *other_width
+        {
+            *num_rows += *other_num_rows;
+            return;
         }
 
-        assert_eq!(self.num_rows() % chunk_size, 0);
-        assert_eq!(self.num_rows() / chunk_size, output_num_rows);
-        match self {
-            Self::Constant { num_rows, width } => Self::Constant {
-                num_rows: num_rows / chunk_size,
-                width: width * chunk_size,
+        let sum = self.sum() + other.sum();
+        let mut widths = match std::mem::take(self) {
+            Self::Constant { num_rows, width } => vec![width; num_rows],
+            Self::Variable { widths, sum: _ } => widths,
+        };
+        match other {
+            Self::Constant { num_rows, width } => {
+                widths.extend(std::iter::repeat_n(*width, *num_rows))
             },
-            Self::Variable { widths, sum } => Self::Variable {
-                widths: widths
+            Self::Variable {
+                widths: other_widths,
+                sum: _,
+            } => widths.extend_from_slice(other_widths),
+        }
+        *self = Self::Variable { widths, sum };
+    }
+
+    /// Push the sums of every `chunk_size` consecutive widths of `other`, which has
+    /// `chunk_size` rows for every row of `self`.
+    pub fn push_collapsed(&mut self, other: &Self, chunk_size: usize) {
+        if chunk_size == 0 {
+            assert_eq!(other.num_rows(), 0);
+            return;
+        }
+
+        assert_eq!(other.num_rows(), self.num_rows() * chunk_size);
+        match other {
+            Self::Constant { num_rows: _, width } => self.push_constant(width * chunk_size),
+            Self::Variable { widths, sum: _ } => self.push_iter(
+                widths
                     .chunks_exact(chunk_size)
-                    .map(|chunk| chunk.iter().copied().sum())
-                    .collect(),
-                sum: *sum,
+                    .map(|chunk| chunk.iter().copied().sum()),
+            ),
+        }
+    }
+
+    /// Reset to `num_rows` rows of width zero, keeping the allocation of variable widths.
+    pub fn reset(&mut self, num_rows: usize) {
+        match self {
+            Self::Constant { .. } => *self = Self::new(num_rows),
+            Self::Variable { widths, sum } => {
+                widths.clear();
+                widths.resize(num_rows, 0);
+                *sum = 0;
             },
         }
     }
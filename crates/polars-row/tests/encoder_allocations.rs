@@ -0,0 +1,120 @@
+//! Allocation tests for encoding chunk after chunk into the same rows.
+//!
+//! These live in their own test binary, as they install a counting global allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use arrow::array::{ArrayRef, Int32Array, ListArray, StructArray, Utf8ViewArray};
+use arrow::datatypes::{ArrowDataType, Field};
+use arrow::offset::OffsetsBuffer;
+use polars_row::{RowEncodingOptions, RowsEncoded};
+
+struct CountingAllocator;
+
+static NUM_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+fn track_alloc(size: usize) {
+    NUM_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        track_alloc(layout.size());
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        track_alloc(new_size);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations of `f` and the bytes they requested.
+fn measure(f: impl FnOnce()) -> (usize, usize) {
+    let num_allocations = NUM_ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    f();
+    (
+        NUM_ALLOCATIONS.load(Ordering::Relaxed) - num_allocations,
+        ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes,
+    )
+}
+
+/// A `list[struct[list[str], i32]]` column of `num_rows` rows with lists of varying lengths, so
+/// that the widths of every level are variable.
+fn nested_column(num_rows: usize) -> ArrayRef {
+    let list = |values: ArrayRef, num_rows: usize| {
+        let offsets = (0..=num_rows)
+            .map(|i| (i / 2 * 3 + i % 2) as i64)
+            .collect::<Vec<_>>();
+        let num_values = *offsets.last().unwrap() as usize;
+        let values = values.sliced(0, num_values);
+        let dtype = ArrowDataType::LargeList(Box::new(Field::new(
+            "item".into(),
+            values.dtype().clone(),
+            true,
+        )));
+        let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+        ListArray::<i64>::new(dtype, offsets, values, None).boxed()
+    };
+
+    // Every list has at most 3 values, so this is enough values for every level.
+    let num_values = 9 * num_rows;
+    let strings = (0..num_values)
+        .map(|i| "x".repeat(i % 5))
+        .collect::<Vec<_>>();
+    let strings = Utf8ViewArray::from_slice_values(&strings).boxed();
+    let ints = Int32Array::from_vec((0..num_values as i32).collect()).boxed();
+
+    let num_struct_rows = 3 * num_rows;
+    let fields = vec![
+        list(strings, num_struct_rows),
+        ints.sliced(0, num_struct_rows),
+    ];
+    let dtype = ArrowDataType::Struct(
+        fields
+            .iter()
+            .zip(["a", "b"])
+            .map(|(field, name)| Field::new(name.into(), field.dtype().clone(), true))
+            .collect(),
+    );
+    let structs = StructArray::new(dtype, num_struct_rows, fields, None).boxed();
+    list(structs, num_rows)
+}
+
+#[test]
+fn test_append_columns_reuses_allocations() {
+    const SMALL: usize = 1 << 10;
+    const LARGE: usize = 1 << 14;
+
+    let small = [nested_column(SMALL)];
+    let large = [nested_column(LARGE)];
+    let fields = [(RowEncodingOptions::default(), None)];
+
+    // Reserve the rows up front, so that only the allocations of the encoding are measured.
+    let mut rows =
+        RowsEncoded::from_parts(Vec::with_capacity(1 << 28), Vec::with_capacity(1 << 20)).unwrap();
+    rows.append_columns(LARGE, &large, fields);
+
+    let (small_allocations, small_bytes) = measure(|| rows.append_columns(SMALL, &small, fields));
+    let (large_allocations, large_bytes) = measure(|| rows.append_columns(LARGE, &large, fields));
+
+    // The widths of every level are kept between calls, so the encoders only allocate a fixed
+    // number of small buffers that doesn't depend on the number of rows.
+    assert_eq!(small_allocations, large_allocations);
+    assert!(
+        large_bytes < LARGE,
+        "allocated {large_bytes} bytes to encode {LARGE} rows"
+    );
+    assert!(small_bytes <= large_bytes);
+}
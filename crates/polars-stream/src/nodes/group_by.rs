@@ -27,6 +27,10 @@ const DEFAULT_HOT_TABLE_SIZE: usize = 4;
 #[cfg(not(debug_assertions))]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4096;
 
+// The number of bytes the evicted keys of a local hot table may use before we
+// flush them, even if there are fewer evicted rows than a morsel.
+const EVICTION_FLUSH_THRESHOLD: usize = 16 * 1024 * 1024;
+
 struct LocalGroupBySinkState {
     hot_grouper: Box<dyn HotGrouper>,
     hot_grouped_reductions: Vec<Box<dyn GroupedReduction>>,
@@ -55,7 +59,8 @@ impl LocalGroupBySinkState {
         hot_table_size: usize,
         num_partitions: usize,
     ) -> Self {
-        let hot_grouper = new_hash_hot_grouper(key_schema, hot_table_size);
+        let mut hot_grouper = new_hash_hot_grouper(key_schema, hot_table_size);
+        hot_grouper.set_eviction_flush_threshold(EVICTION_FLUSH_THRESHOLD);
         Self {
             hot_grouper,
             hot_grouped_reductions: reductions,
@@ -203,8 +208,11 @@ impl GroupBySinkState {
                         }
                     }
 
-                    // If we have too many evicted rows, flush them.
-                    if local.hot_grouper.num_evictions() >= get_ideal_morsel_size() {
+                    // If we have too many evicted rows, or they use too much
+                    // memory, flush them.
+                    if local.hot_grouper.num_evictions() >= get_ideal_morsel_size()
+                        || local.hot_grouper.should_flush_evictions()
+                    {
                         local.flush_evictions(&partitioner);
                     }
                 }
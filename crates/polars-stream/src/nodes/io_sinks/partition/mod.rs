@@ -288,6 +288,7 @@ async fn open_new_sink(
                     nulls_last,
                     multithreaded: false,
                     maintain_order,
+                    deterministic: false,
                     limit: None,
                 },
             )?;
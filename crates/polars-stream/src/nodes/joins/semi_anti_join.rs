@@ -2,12 +2,14 @@ use std::sync::Arc;
 
 use arrow::array::BooleanArray;
 use arrow::bitmap::BitmapBuilder;
+use polars_core::config;
 use polars_core::prelude::*;
 use polars_core::schema::Schema;
 use polars_expr::groups::{Grouper, new_hash_grouper};
 use polars_expr::hash_keys::HashKeys;
 use polars_ops::frame::{JoinArgs, JoinType};
 use polars_utils::IdxSize;
+use polars_utils::bloom_filter::BloomFilter;
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::hashing::HashPartitioner;
 use polars_utils::itertools::Itertools;
@@ -17,6 +19,22 @@ use crate::async_executor;
 use crate::expression::StreamExpr;
 use crate::nodes::compute_node_prelude::*;
 
+/// Below this estimated number of unique build keys the hash table is small enough that probing
+/// it directly is about as fast as probing a bloom filter first.
+const MIN_BLOOM_FILTER_BUILD_KEYS: usize = 1 << 14;
+
+/// Whether to build bloom filters over the build keys, which are checked before probing the hash
+/// tables. Unless `forced` either way, this is decided based on the number of build keys.
+///
+/// Only row-encoded keys are filtered, as for those a hash table probe is the most expensive.
+fn should_use_bloom_filter(
+    forced: Option<bool>,
+    row_encoded: bool,
+    estimate_build_keys: impl FnOnce() -> usize,
+) -> bool {
+    row_encoded && forced.unwrap_or_else(|| estimate_build_keys() >= MIN_BLOOM_FILTER_BUILD_KEYS)
+}
+
 async fn select_keys(
     df: &DataFrame,
     key_selectors: &[StreamExpr],
@@ -44,6 +62,7 @@ struct SemiAntiJoinParams {
     is_anti: bool,
     return_bool: bool,
     random_state: PlRandomState,
+    bloom_filter: Option<bool>,
 }
 
 pub struct SemiAntiJoinNode {
@@ -59,6 +78,7 @@ impl SemiAntiJoinNode {
         right_key_selectors: Vec<StreamExpr>,
         args: JoinArgs,
         return_bool: bool,
+        bloom_filter: Option<bool>,
         num_pipelines: usize,
     ) -> PolarsResult<Self> {
         let left_is_build = false;
@@ -76,6 +96,7 @@ impl SemiAntiJoinNode {
                 nulls_equal: args.nulls_equal,
                 return_bool,
                 is_anti,
+                bloom_filter,
             },
             grouper: new_hash_grouper(unique_key_schema),
         })
@@ -158,7 +179,36 @@ impl BuildState {
         Ok(())
     }
 
-    fn finalize(&mut self, grouper: &dyn Grouper) -> ProbeState {
+    fn use_bloom_filter(&self, params: &SemiAntiJoinParams) -> bool {
+        // The filters are only used when returning the matching rows.
+        if params.return_bool {
+            return false;
+        }
+        let Some(keys) = self.local_builders.iter().find_map(|l| l.keys.first()) else {
+            return false;
+        };
+
+        should_use_bloom_filter(
+            params.bloom_filter,
+            matches!(keys, HashKeys::RowEncoded(_)),
+            || {
+                let mut sketch = CardinalitySketch::new();
+                for l in &self.local_builders {
+                    for p_sketch in &l.sketch_per_p {
+                        sketch.combine(p_sketch);
+                    }
+                }
+                sketch.estimate()
+            },
+        )
+    }
+
+    fn finalize(&mut self, grouper: &dyn Grouper, params: &SemiAntiJoinParams) -> ProbeState {
+        let use_bloom_filter = self.use_bloom_filter(params);
+        if config::verbose() {
+            eprintln!("semi/anti join uses bloom filter: {use_bloom_filter}");
+        }
+
         // To reduce maximum memory usage we want to drop the original keys
         // as soon as they're processed, so we move into Arcs. The drops might
         // also be expensive, so instead of directly dropping we put that on
@@ -174,6 +224,7 @@ impl BuildState {
         let local_builders = &self.local_builders;
         let groupers: SparseInitVec<Box<dyn Grouper>> =
             SparseInitVec::with_capacity(num_partitions);
+        let filters: SparseInitVec<BloomFilter> = SparseInitVec::with_capacity(num_partitions);
 
         async_executor::task_scope(|s| {
             // Wrap in outer Arc to move to each thread, performing the
@@ -185,6 +236,7 @@ impl BuildState {
                 let key_drop_q_send = key_drop_q_send.clone();
                 let key_drop_q_recv = key_drop_q_recv.clone();
                 let groupers = &groupers;
+                let filters = &filters;
                 join_handles.push(s.spawn_task(TaskPriority::High, async move {
                     // Extract from outer arc and drop outer arc.
                     let keys_per_local_builder = Arc::unwrap_or_clone(arc_keys_per_local_builder);
@@ -198,6 +250,8 @@ impl BuildState {
                     // Allocate hash table.
                     let mut p_grouper = grouper.new_empty();
                     p_grouper.reserve(sketch.estimate() * 5 / 4);
+                    let mut p_filter =
+                        use_bloom_filter.then(|| BloomFilter::with_capacity(sketch.estimate()));
 
                     // Build.
                     let mut skip_drop_attempt = false;
@@ -216,6 +270,13 @@ impl BuildState {
                                 let p_key_idxs =
                                     &l.key_idxs_values_per_p[p][p_key_idxs_start..p_key_idxs_stop];
                                 p_grouper.insert_keys_subset(keys, p_key_idxs, None);
+                                if let Some(p_filter) = &mut p_filter {
+                                    keys.for_each_hash_subset(p_key_idxs, |_idx, opt_h| {
+                                        if let Some(h) = opt_h {
+                                            p_filter.insert(h);
+                                        }
+                                    });
+                                }
                             }
                         }
 
@@ -237,6 +298,9 @@ impl BuildState {
                     }
 
                     groupers.try_set(p, p_grouper).ok().unwrap();
+                    if let Some(p_filter) = p_filter {
+                        filters.try_set(p, p_filter).ok().unwrap();
+                    }
                 }));
             }
 
@@ -256,12 +320,14 @@ impl BuildState {
 
         ProbeState {
             grouper_per_partition: groupers.try_assume_init().ok().unwrap(),
+            filter_per_partition: use_bloom_filter.then(|| filters.try_assume_init().ok().unwrap()),
         }
     }
 }
 
 struct ProbeState {
     grouper_per_partition: Vec<Box<dyn Grouper>>,
+    filter_per_partition: Option<Vec<BloomFilter>>,
 }
 
 impl ProbeState {
@@ -270,6 +336,7 @@ impl ProbeState {
         mut recv: PortReceiver,
         mut send: PortSender,
         partitions: &[Box<dyn Grouper>],
+        filters: Option<&[BloomFilter]>,
         partitioner: HashPartitioner,
         params: &SemiAntiJoinParams,
         state: &StreamingExecutionState,
@@ -308,13 +375,24 @@ impl ProbeState {
                     DataFrame::new(vec![Column::from(s)])?
                 } else {
                     probe_match.clear();
-                    partitions[0].probe_partitioned_groupers(
-                        partitions,
-                        &hash_keys,
-                        &partitioner,
-                        params.is_anti,
-                        &mut probe_match,
-                    );
+                    if let Some(filters) = filters {
+                        partitions[0].probe_partitioned_groupers_with_filters(
+                            partitions,
+                            filters,
+                            &hash_keys,
+                            &partitioner,
+                            params.is_anti,
+                            &mut probe_match,
+                        );
+                    } else {
+                        partitions[0].probe_partitioned_groupers(
+                            partitions,
+                            &hash_keys,
+                            &partitioner,
+                            params.is_anti,
+                            &mut probe_match,
+                        );
+                    }
                     if probe_match.is_empty() {
                         continue;
                     }
@@ -364,7 +442,7 @@ impl ComputeNode for SemiAntiJoinNode {
         // If we are building and the build input is done, transition to probing.
         if let SemiAntiJoinState::Build(build_state) = &mut self.state {
             if recv[build_idx] == PortState::Done {
-                let probe_state = build_state.finalize(&*self.grouper);
+                let probe_state = build_state.finalize(&*self.grouper, &self.params);
                 self.state = SemiAntiJoinState::Probe(probe_state);
             }
         }
@@ -454,6 +532,7 @@ impl ComputeNode for SemiAntiJoinNode {
                             recv,
                             send,
                             &probe_state.grouper_per_partition,
+                            probe_state.filter_per_partition.as_deref(),
                             partitioner.clone(),
                             &self.params,
                             state,
@@ -465,3 +544,24 @@ impl ComputeNode for SemiAntiJoinNode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{MIN_BLOOM_FILTER_BUILD_KEYS, should_use_bloom_filter};
+
+    #[test]
+    fn test_should_use_bloom_filter() {
+        let tiny = || 10;
+        let large = || MIN_BLOOM_FILTER_BUILD_KEYS;
+
+        // Tiny build sides aren't filtered unless forced.
+        assert!(!should_use_bloom_filter(None, true, tiny));
+        assert!(should_use_bloom_filter(None, true, large));
+        assert!(should_use_bloom_filter(Some(true), true, tiny));
+        assert!(!should_use_bloom_filter(Some(false), true, large));
+
+        // Keys that aren't row-encoded are never filtered.
+        assert!(!should_use_bloom_filter(None, false, large));
+        assert!(!should_use_bloom_filter(Some(true), false, large));
+    }
+}
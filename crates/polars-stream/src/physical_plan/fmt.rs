@@ -655,6 +655,7 @@ fn visualize_plan_rec(
             right_on,
             args,
             output_bool: _,
+            bloom_filter: _,
         } => {
             let label = match phys_sm[node_key].kind {
                 PhysNodeKind::EquiJoin { .. } => "equi-join",
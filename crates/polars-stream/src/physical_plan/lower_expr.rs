@@ -1110,6 +1110,7 @@ fn lower_exprs_with_ctx(
                         maintain_order: Default::default(),
                     },
                     output_bool: true,
+                    bloom_filter: None,
                 };
 
                 // SemiAntiJoin with output_bool returns a column with the same name as the first
@@ -1347,7 +1348,7 @@ fn lower_exprs_with_ctx(
             #[cfg(feature = "range")]
             AExpr::Function {
                 input: ref inner_exprs,
-                function: IRFunctionExpr::Range(IRRangeFunction::IntRange { step: 1, dtype }),
+                function: IRFunctionExpr::Range(IRRangeFunction::IntRange { step: 1, dtype, .. }),
                 options: _,
             } if {
                 let start_is_zero = match ctx.expr_arena.get(inner_exprs[0].node()) {
@@ -1375,7 +1376,7 @@ fn lower_exprs_with_ctx(
             #[cfg(feature = "range")]
             AExpr::Function {
                 input: ref inner_exprs,
-                function: IRFunctionExpr::Range(IRRangeFunction::IntRange { step: 1, dtype }),
+                function: IRFunctionExpr::Range(IRRangeFunction::IntRange { step: 1, dtype, .. }),
                 options: _,
             } if {
                 let start_is_zero = match ctx.expr_arena.get(inner_exprs[0].node()) {
@@ -599,8 +599,9 @@ pub fn lower_ir(
 
             let mut stream = phys_input;
             if limit < u64::MAX {
-                // If we need to maintain order augment with row index.
-                if sort_options.maintain_order {
+                // If we need to maintain order or break ties deterministically augment with row
+                // index.
+                if sort_options.maintain_order || sort_options.deterministic {
                     let row_idx_name = unique_column_name();
                     stream = build_row_idx_stream(stream, row_idx_name.clone(), None, phys_sm);
 
@@ -615,6 +616,7 @@ pub fn lower_ir(
 
                     // No longer needed for the actual sort itself, handled by row index.
                     sort_options.maintain_order = false;
+                    sort_options.deterministic = false;
                 }
 
                 let k_node =
@@ -1160,10 +1162,27 @@ pub fn lower_ir(
             let left_on = left_on.clone();
             let right_on = right_on.clone();
             let args = options.args.clone();
+            let bloom_filter = options.bloom_filter;
             let options = options.options.clone();
             let phys_left = lower_ir!(input_left)?;
             let phys_right = lower_ir!(input_right)?;
-            if (args.how.is_equi() || args.how.is_semi_anti()) && !args.validation.needs_checks() {
+
+            // A `Categorical` key joined with a `String` key is only supported by the in-memory
+            // join, which joins on the strings of the categories.
+            let left_schema = &phys_sm[phys_left.node].output_schema;
+            let right_schema = &phys_sm[phys_right.node].output_schema;
+            let mut categorical_string_keys = false;
+            for (l, r) in left_on.iter().zip(&right_on) {
+                let ltype = l.dtype(left_schema, expr_arena)?;
+                let rtype = r.dtype(right_schema, expr_arena)?;
+                categorical_string_keys |= (ltype.is_categorical() && rtype.is_string())
+                    || (ltype.is_string() && rtype.is_categorical());
+            }
+
+            if (args.how.is_equi() || args.how.is_semi_anti())
+                && !args.validation.needs_checks()
+                && !categorical_string_keys
+            {
                 // When lowering the expressions for the keys we need to ensure we keep around the
                 // payload columns, otherwise the input nodes can get replaced by input-independent
                 // nodes since the lowering code does not see we access any non-literal expressions.
@@ -1218,6 +1237,7 @@ pub fn lower_ir(
                             right_on: trans_right_on,
                             args: args.clone(),
                             output_bool: false,
+                            bloom_filter,
                         },
                     ))
                 };
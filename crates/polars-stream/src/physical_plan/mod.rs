@@ -378,6 +378,9 @@ pub enum PhysNodeKind {
         right_on: Vec<ExprIR>,
         args: JoinArgs,
         output_bool: bool,
+        /// Whether to build a bloom filter over the build keys, `None` to decide based on the
+        /// size of the build side.
+        bloom_filter: Option<bool>,
     },
 
     CrossJoin {
@@ -544,6 +544,7 @@ fn to_graph_rec<'a>(
                             nulls_last,
                             multithreaded: false,
                             maintain_order: true,
+                            deterministic: false,
                             limit: None,
                         };
 
@@ -1147,6 +1148,7 @@ fn to_graph_rec<'a>(
                 options: Arc::new(JoinOptionsIR {
                     allow_parallel: true,
                     force_parallel: false,
+                    bloom_filter: None,
                     args: args.clone(),
                     options: options.clone(),
                 }),
@@ -1191,6 +1193,7 @@ fn to_graph_rec<'a>(
             right_on,
             args,
             output_bool: _,
+            bloom_filter: _,
         } => {
             let args = args.clone();
             let left_input_key = to_graph_rec(input_left.node, ctx)?;
@@ -1242,13 +1245,18 @@ fn to_graph_rec<'a>(
 
             match node.kind {
                 #[cfg(feature = "semi_anti_join")]
-                SemiAntiJoin { output_bool, .. } => ctx.graph.add_node(
+                SemiAntiJoin {
+                    output_bool,
+                    bloom_filter,
+                    ..
+                } => ctx.graph.add_node(
                     nodes::joins::semi_anti_join::SemiAntiJoinNode::new(
                         unique_key_schema,
                         left_key_selectors,
                         right_key_selectors,
                         args,
                         output_bool,
+                        bloom_filter,
                         ctx.num_pipelines,
                     )?,
                     [
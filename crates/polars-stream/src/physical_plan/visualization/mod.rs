@@ -979,6 +979,7 @@ impl PhysicalPlanVisualizationDataGenerator<'_> {
                         nulls_last,
                         multithreaded,
                         maintain_order,
+                        deterministic,
                         limit,
                     },
             } => {
@@ -991,6 +992,7 @@ impl PhysicalPlanVisualizationDataGenerator<'_> {
                     nulls_last: nulls_last.clone(),
                     multithreaded: *multithreaded,
                     maintain_order: *maintain_order,
+                    deterministic: *deterministic,
                     #[allow(clippy::useless_conversion)]
                     limit: limit.map(|x| x.into()),
                 };
@@ -1130,6 +1132,7 @@ impl PhysicalPlanVisualizationDataGenerator<'_> {
                 right_on,
                 args,
                 output_bool,
+                bloom_filter,
             } => {
                 phys_node_inputs.push(input_left.node);
                 phys_node_inputs.push(input_right.node);
@@ -1139,6 +1142,7 @@ impl PhysicalPlanVisualizationDataGenerator<'_> {
                     right_on: expr_list(right_on, self.expr_arena),
                     nulls_equal: args.nulls_equal,
                     output_as_bool: *output_bool,
+                    bloom_filter: *bloom_filter,
                 };
 
                 PhysNodeInfo {
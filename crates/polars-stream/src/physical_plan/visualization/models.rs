@@ -273,6 +273,7 @@ pub enum PhysNodeProperties {
         nulls_last: Vec<bool>,
         multithreaded: bool,
         maintain_order: bool,
+        deterministic: bool,
         limit: Option<u64>,
     },
     Slice {
@@ -314,6 +315,7 @@ pub enum PhysNodeProperties {
         right_on: Vec<PlSmallStr>,
         nulls_equal: bool,
         output_as_bool: bool,
+        bloom_filter: Option<bool>,
     },
     #[cfg(feature = "merge_sorted")]
     MergeSorted,
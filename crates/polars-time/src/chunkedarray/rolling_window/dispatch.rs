@@ -25,7 +25,7 @@ fn rolling_agg<T>(
         bool,
         Option<&[f64]>,
         Option<RollingFnParams>,
-    ) -> ArrayRef,
+    ) -> PolarsResult<ArrayRef>,
 ) -> PolarsResult<Series>
 where
     T: PolarsNumericType,
@@ -53,7 +53,7 @@ where
             options.center,
             options.weights.as_deref(),
             options.fn_params,
-        ),
+        )?,
     };
     Series::try_from((ca.name().clone(), arr))
 }
@@ -184,7 +184,16 @@ pub trait SeriesOpsTime: AsSeries {
                 ca,
                 options,
                 &rolling::no_nulls::rolling_mean,
-                &rolling::nulls::rolling_mean,
+                &|arr, window_size, min_periods, center, weights, params| {
+                    Ok(rolling::nulls::rolling_mean(
+                        arr,
+                        window_size,
+                        min_periods,
+                        center,
+                        weights,
+                        params,
+                    ))
+                },
             )
         })
     }
@@ -250,7 +259,16 @@ pub trait SeriesOpsTime: AsSeries {
                 ca,
                 options,
                 &rolling::no_nulls::rolling_sum,
-                &rolling::nulls::rolling_sum,
+                &|arr, window_size, min_periods, center, weights, params| {
+                    Ok(rolling::nulls::rolling_sum(
+                        arr,
+                        window_size,
+                        min_periods,
+                        center,
+                        weights,
+                        params,
+                    ))
+                },
             )
         })
     }
@@ -284,7 +302,16 @@ pub trait SeriesOpsTime: AsSeries {
             ca,
             options,
             &rolling::no_nulls::rolling_quantile,
-            &rolling::nulls::rolling_quantile,
+            &|arr, window_size, min_periods, center, weights, params| {
+                Ok(rolling::nulls::rolling_quantile(
+                    arr,
+                    window_size,
+                    min_periods,
+                    center,
+                    weights,
+                    params,
+                ))
+            },
         )
         })
     }
@@ -365,7 +392,16 @@ pub trait SeriesOpsTime: AsSeries {
                 ca,
                 options,
                 &rolling::no_nulls::rolling_min,
-                &rolling::nulls::rolling_min,
+                &|arr, window_size, min_periods, center, weights, params| {
+                    Ok(rolling::nulls::rolling_min(
+                        arr,
+                        window_size,
+                        min_periods,
+                        center,
+                        weights,
+                        params,
+                    ))
+                },
             )
         })
     }
@@ -446,7 +482,16 @@ pub trait SeriesOpsTime: AsSeries {
                 ca,
                 options,
                 &rolling::no_nulls::rolling_max,
-                &rolling::nulls::rolling_max,
+                &|arr, window_size, min_periods, center, weights, params| {
+                    Ok(rolling::nulls::rolling_max(
+                        arr,
+                        window_size,
+                        min_periods,
+                        center,
+                        weights,
+                        params,
+                    ))
+                },
             )
         })
     }
@@ -606,7 +651,16 @@ pub trait SeriesOpsTime: AsSeries {
                 &ca,
                 options,
                 &rolling::no_nulls::rolling_rank,
-                &rolling::nulls::rolling_rank,
+                &|arr, window_size, min_periods, center, weights, params| {
+                    Ok(rolling::nulls::rolling_rank(
+                        arr,
+                        window_size,
+                        min_periods,
+                        center,
+                        weights,
+                        params,
+                    ))
+                },
             )
         })
     }
@@ -203,4 +203,59 @@ impl<T: Default> Arena<T> {
         self.replace(idx, f(val)?);
         Ok(())
     }
+
+    /// Rewrite the item at `idx` with `f`, which gets the item and the arena.
+    ///
+    /// The item is taken out of the arena while `f` runs, so reads of `idx` from within `f` see a
+    /// placeholder. If `f` fails it must hand the item back, which is then put back in place.
+    pub fn try_substitute<E, F>(&mut self, idx: Node, f: F) -> std::result::Result<(), E>
+    where
+        F: FnOnce(T, &mut Self) -> std::result::Result<T, (T, E)>,
+    {
+        let item = self.take(idx);
+        let (item, out) = match f(item, self) {
+            Ok(item) => (item, Ok(())),
+            Err((item, err)) => (item, Err(err)),
+        };
+        self.replace(idx, item);
+        out
+    }
+
+    /// Infallible version of [`Arena::try_substitute`].
+    pub fn substitute<F>(&mut self, idx: Node, f: F)
+    where
+        F: FnOnce(T, &mut Self) -> T,
+    {
+        let item = self.take(idx);
+        let item = f(item, self);
+        self.replace(idx, item);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_substitute() {
+        let mut arena: Arena<Vec<Node>> = Arena::new();
+        let leaf = arena.add(vec![]);
+        let parent = arena.add(vec![leaf]);
+
+        // The item is taken out of the arena while it is rewritten.
+        arena.substitute(parent, |mut item, arena| {
+            assert!(arena.get(parent).is_empty());
+            item.push(arena.add(vec![]));
+            item
+        });
+        assert_eq!(arena.get(parent).len(), 2);
+
+        // A failing rewrite hands the item back.
+        let out = arena.try_substitute(parent, |mut item, _| {
+            item.pop();
+            Err((item, ()))
+        });
+        assert!(out.is_err());
+        assert_eq!(arena.get(parent), &[leaf]);
+    }
 }
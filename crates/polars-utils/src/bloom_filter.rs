@@ -0,0 +1,96 @@
+use crate::hashing::folded_multiply;
+
+/// A blocked bloom filter over 64-bit hashes.
+///
+/// Every hash sets [`BloomFilter::NUM_BITS_PER_KEY`] bits in a single word, so both inserting and
+/// probing touch a single cache line. The filter never returns false negatives, a false positive
+/// only means the caller has to do the exact check it would have done without the filter.
+#[derive(Clone, Debug)]
+pub struct BloomFilter {
+    words: Vec<u64>,
+    mask: usize,
+}
+
+impl BloomFilter {
+    const NUM_BITS_PER_KEY: u32 = 4;
+    // With ~16 bits of space per key and 4 bits per key the false positive rate is ~1-2%.
+    const NUM_KEYS_PER_WORD: usize = 4;
+
+    /// Creates a bloom filter sized for the given estimated number of keys.
+    pub fn with_capacity(num_keys: usize) -> Self {
+        let num_words = num_keys
+            .div_ceil(Self::NUM_KEYS_PER_WORD)
+            .max(1)
+            .next_power_of_two();
+        Self {
+            words: vec![0; num_words],
+            mask: num_words - 1,
+        }
+    }
+
+    #[inline(always)]
+    fn word_and_bits(&self, h: u64) -> (usize, u64) {
+        // The hash is likely also used for partitioning and hash table lookups, so we shuffle it
+        // to make sure the filter is influenced by all bits of the hash.
+        const ARBITRARY1: u64 = 0x2e8d9d6b3a4c1f57;
+        const ARBITRARY2: u64 = 0x9e3779b97f4a7c15;
+        let h = folded_multiply(h ^ ARBITRARY1, ARBITRARY2);
+        let word = (h as usize) & self.mask;
+        let mut bits = 0;
+        for i in 0..Self::NUM_BITS_PER_KEY {
+            bits |= 1 << ((h >> (40 + 6 * i)) & 63);
+        }
+        (word, bits)
+    }
+
+    /// Add a new hash to the filter.
+    #[inline]
+    pub fn insert(&mut self, h: u64) {
+        let (word, bits) = self.word_and_bits(h);
+        // SAFETY: the mask keeps the index in-bounds.
+        unsafe { *self.words.get_unchecked_mut(word) |= bits }
+    }
+
+    /// Returns false if the hash was definitely never inserted.
+    #[inline]
+    pub fn may_contain(&self, h: u64) -> bool {
+        let (word, bits) = self.word_and_bits(h);
+        // SAFETY: the mask keeps the index in-bounds.
+        unsafe { *self.words.get_unchecked(word) & bits == bits }
+    }
+
+    /// The size of the filter in bytes.
+    pub fn size_in_bytes(&self) -> usize {
+        self.words.len() * size_of::<u64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasher;
+
+    use super::*;
+    use crate::aliases::PlRandomState;
+
+    #[test]
+    fn test_bloom_filter() {
+        let random_state = PlRandomState::default();
+        let n = 10_000u64;
+        let mut filter = BloomFilter::with_capacity(n as usize);
+        for i in 0..n {
+            filter.insert(random_state.hash_one(i));
+        }
+
+        // No false negatives.
+        assert!((0..n).all(|i| filter.may_contain(random_state.hash_one(i))));
+
+        // Few false positives.
+        let false_positives = (n..11 * n)
+            .filter(|i| filter.may_contain(random_state.hash_one(i)))
+            .count();
+        assert!(
+            false_positives < (10 * n / 20) as usize,
+            "{false_positives}"
+        );
+    }
+}
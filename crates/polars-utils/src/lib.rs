@@ -9,6 +9,7 @@ pub mod abs_diff;
 pub mod algebraic_ops;
 pub mod arena;
 pub mod binary_search;
+pub mod bloom_filter;
 pub mod cache;
 pub mod cardinality_sketch;
 pub mod cell;
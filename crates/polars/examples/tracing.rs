@@ -0,0 +1,46 @@
+//! Print the `tracing` spans of the optimizer passes and the row encoding of a small query.
+//!
+//! ```sh
+//! cargo run -p polars --example tracing --features lazy,tracing
+//! ```
+//!
+//! Every span is printed when it closes, with its fields and the time spent in it:
+//!
+//! ```text
+//! DEBUG slice_pushdown{nodes_before=5 nodes_after=5}: close time.busy=12.1µs time.idle=1.2µs
+//! DEBUG convert_columns_amortized{num_rows=4 num_columns=2 total_bytes=28}: close time.busy=...
+//! ```
+//!
+//! The optimizer pass spans are named after the pass and record the number of IR nodes in the
+//! plan before and after the pass. `set_cache_states` records the number of caches and the number
+//! of consumers of every cache. The row encoding spans record the number of rows, columns and
+//! encoded bytes. `time.busy` is the time spent inside the span.
+use polars::prelude::*;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+fn main() -> PolarsResult<()> {
+    // The spans are emitted at the `DEBUG` level.
+    tracing_subscriber::fmt()
+        .with_max_level(LevelFilter::DEBUG)
+        .with_span_events(FmtSpan::CLOSE)
+        .init();
+
+    let lf = df![
+        "a" => [3, 1, 2, 1],
+        "b" => ["x", "y", "x", "z"],
+    ]?
+    .lazy();
+
+    // Sorting by multiple columns with nulls last row encodes the sort keys.
+    let sorted = lf.clone().sort(
+        ["a", "b"],
+        SortMultipleOptions::default().with_nulls_last(true),
+    );
+    // Using the same input twice lets the optimizer insert a cache.
+    let filtered = lf.filter(col("a").gt(lit(1)));
+    let out = concat([sorted, filtered], UnionArgs::default())?.collect()?;
+
+    println!("{out}");
+    Ok(())
+}
@@ -167,6 +167,7 @@ from polars.functions import (
     head,
     implode,
     int_range,
+    int_range_array,
     int_ranges,
     last,
     len,
@@ -447,6 +448,7 @@ __all__ = [
     "head",
     "implode",
     "int_range",
+    "int_range_array",
     "int_ranges",
     "last",
     "linear_space",
@@ -66,6 +66,7 @@ IndexOrder: TypeAlias = Literal["fortran", "c"]
 QuantileMethod: TypeAlias = Literal[
     "lower", "higher", "nearest", "linear", "midpoint", "equiprobable"
 ]
+RangeNullBehavior: TypeAlias = Literal["raise", "propagate"]
 RankMethod: TypeAlias = Literal["min", "max", "average", "dense", "ordinal", "random"]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
 TimeUnit: TypeAlias = Literal["ns", "us", "ms"]
@@ -914,6 +915,7 @@ class PyLazyFrame:
         descending: bool,
         nulls_last: bool,
         maintain_order: bool,
+        deterministic: bool,
         multithreaded: bool,
     ) -> PyLazyFrame: ...
     def sort_by_exprs(
@@ -922,6 +924,7 @@ class PyLazyFrame:
         descending: Sequence[bool],
         nulls_last: Sequence[bool],
         maintain_order: bool,
+        deterministic: bool,
         multithreaded: bool,
     ) -> PyLazyFrame: ...
     def top_k(
@@ -1045,6 +1048,7 @@ class PyLazyFrame:
         validate: JoinValidation,
         maintain_order: MaintainOrderJoin,
         coalesce: bool | None,
+        bloom_filter: bool | None = None,
     ) -> PyLazyFrame: ...
     def join_where(
         self, other: PyLazyFrame, predicates: Sequence[PyExpr], suffix: str
@@ -1438,9 +1442,28 @@ class PyExpr:
     def arr_arg_min(self) -> PyExpr: ...
     def arr_arg_max(self) -> PyExpr: ...
     def arr_get(self, index: PyExpr, null_on_oob: bool) -> PyExpr: ...
+    def arr_scatter(self, index: PyExpr, value: PyExpr, skip_oob: bool) -> PyExpr: ...
+    def arr_set_at_idx_masked(self, mask: PyExpr, value: PyExpr) -> PyExpr: ...
     def arr_join(self, separator: PyExpr, ignore_nulls: bool) -> PyExpr: ...
-    def arr_contains(self, other: PyExpr, nulls_equal: bool) -> PyExpr: ...
-    def arr_count_matches(self, expr: PyExpr) -> PyExpr: ...
+    def arr_contains(
+        self,
+        other: PyExpr,
+        nulls_equal: bool,
+        assume_sorted_rows: bool,
+        descending: bool,
+        validate: bool,
+    ) -> PyExpr: ...
+    def arr_count_matches(
+        self,
+        expr: PyExpr,
+        assume_sorted_rows: bool,
+        descending: bool,
+        validate: bool,
+    ) -> PyExpr: ...
+    def arr_rows_are_sorted(self, descending: bool) -> PyExpr: ...
+    def arr_cast_inner(
+        self, dtype: PyDataTypeExpr, strict: bool, wrap_numerical: bool
+    ) -> PyExpr: ...
     def arr_to_struct(self, name_gen: Any | None = None) -> PyExpr: ...
     def arr_slice(
         self, offset: PyExpr, length: PyExpr | None = None, as_array: bool = False
@@ -1590,6 +1613,9 @@ class PyExpr:
     def list_gather(self, index: PyExpr, null_on_oob: bool) -> PyExpr: ...
     def list_gather_every(self, n: PyExpr, offset: PyExpr) -> PyExpr: ...
     def list_to_array(self, width: int) -> PyExpr: ...
+    def list_pad_to_array(
+        self, width: int, side: Any, fill: PyExpr, truncate_side: Any
+    ) -> PyExpr: ...
     def list_to_struct(self, names: Sequence[str]) -> PyExpr: ...
     def list_to_struct_fixed_width(self, names: Sequence[str]) -> PyExpr: ...
     def list_n_unique(self) -> PyExpr: ...
@@ -2244,7 +2270,11 @@ def set_random_seed(seed: int) -> None: ...
 
 # functions.range
 def int_range(
-    start: PyExpr, end: PyExpr, step: int, dtype: PyDataTypeExpr
+    start: PyExpr,
+    end: PyExpr,
+    step: int,
+    dtype: PyDataTypeExpr,
+    null_behavior: RangeNullBehavior,
 ) -> PyExpr: ...
 def eager_int_range(
     lower: Any, upper: Any, step: Any, dtype: PyDataTypeExpr
@@ -2252,6 +2282,9 @@ def eager_int_range(
 def int_ranges(
     start: PyExpr, end: PyExpr, step: PyExpr, dtype: PyDataTypeExpr
 ) -> PyExpr: ...
+def int_range_array(
+    start: PyExpr, len: int, step: int, dtype: PyDataTypeExpr
+) -> PyExpr: ...
 def date_range(
     start: PyExpr, end: PyExpr, interval: str, closed: ClosedWindow
 ) -> PyExpr: ...
@@ -2470,7 +2503,14 @@ def prepare_cloud_plan(
     lf: PyLazyFrame,
     *,
     allow_local_scans: bool,
+    max_inline_dataframe_bytes: int | None = None,
 ) -> bytes: ...
+def validate_cloud_plan(
+    lf: PyLazyFrame,
+    *,
+    allow_local_scans: bool,
+    max_inline_dataframe_bytes: int | None = None,
+) -> list[str]: ...
 
 # cloud_server
 def _execute_ir_plan_with_gpu(ir_plan_ser: Sequence[int]) -> PyDataFrame: ...
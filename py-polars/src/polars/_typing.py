@@ -146,6 +146,7 @@ PivotAgg: TypeAlias = Literal[
 QuantileMethod: TypeAlias = Literal[
     "nearest", "higher", "lower", "midpoint", "linear", "equiprobable"
 ]
+RangeNullBehavior: TypeAlias = Literal["raise", "propagate"]
 RankMethod: TypeAlias = Literal["average", "min", "max", "dense", "ordinal", "random"]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
 RoundMode: TypeAlias = Literal["half_to_even", "half_away_from_zero"]
@@ -210,6 +211,7 @@ DbWriteMode: TypeAlias = Literal["replace", "append", "fail"]
 EpochTimeUnit = Literal["ns", "us", "ms", "s", "d"]
 JaxExportType: TypeAlias = Literal["array", "dict"]
 Orientation: TypeAlias = Literal["col", "row"]
+PadSide: TypeAlias = Literal["start", "end"]
 SearchSortedSide: TypeAlias = Literal["any", "left", "right"]
 TorchExportType: TypeAlias = Literal["tensor", "dataset", "dict"]
 TransferEncoding: TypeAlias = Literal["hex", "base64"]
@@ -414,6 +416,7 @@ __all__ = [
     "NumericLiteral",
     "OneOrMoreDataTypes",
     "Orientation",
+    "PadSide",
     "ParallelStrategy",
     "ParametricProfileNames",
     "ParquetCompression",
@@ -425,6 +428,7 @@ __all__ = [
     "PythonDataType",
     "PythonLiteral",
     "QuantileMethod",
+    "RangeNullBehavior",
     "RankMethod",
     "Roll",
     "RowTotalsDefinition",
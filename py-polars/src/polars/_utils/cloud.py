@@ -13,6 +13,7 @@ def prepare_cloud_plan(
     lf: LazyFrame,
     *,
     allow_local_scans: bool,
+    max_inline_dataframe_bytes: int | None = None,
     optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
 ) -> bytes:
     """
@@ -24,6 +25,9 @@ def prepare_cloud_plan(
         The LazyFrame to prepare.
     allow_local_scans
         Whether or not to allow local scans in the plan.
+    max_inline_dataframe_bytes
+        The maximum estimated size in bytes of an in-memory DataFrame that is
+        serialized into the plan. By default there is no limit.
     optimizations
         Optimizations to enable or disable in the query optimizer.
 
@@ -35,9 +39,55 @@ def prepare_cloud_plan(
 
         - Contains a user-defined function
         - Scans or sinks to a local filesystem
+        - Contains an in-memory DataFrame larger than `max_inline_dataframe_bytes`
     ComputeError
         If the given LazyFrame cannot be serialized.
     """
     optimizations = optimizations.__copy__()
     pylf = lf._ldf.with_optimizations(optimizations._pyoptflags)
-    return plr.prepare_cloud_plan(pylf, allow_local_scans=allow_local_scans)
+    return plr.prepare_cloud_plan(
+        pylf,
+        allow_local_scans=allow_local_scans,
+        max_inline_dataframe_bytes=max_inline_dataframe_bytes,
+    )
+
+
+def validate_cloud_plan(
+    lf: LazyFrame,
+    *,
+    allow_local_scans: bool,
+    max_inline_dataframe_bytes: int | None = None,
+    optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+) -> list[str]:
+    """
+    Check whether the given LazyFrame is eligible for execution on Polars Cloud.
+
+    Unlike :func:`prepare_cloud_plan`, this reports every reason why the LazyFrame
+    is ineligible instead of raising on the first one.
+
+    Parameters
+    ----------
+    lf
+        The LazyFrame to check.
+    allow_local_scans
+        Whether or not to allow local scans in the plan.
+    max_inline_dataframe_bytes
+        The maximum estimated size in bytes of an in-memory DataFrame that is
+        serialized into the plan. By default there is no limit.
+    optimizations
+        Optimizations to enable or disable in the query optimizer.
+
+    Returns
+    -------
+    list of str
+        A short reason for every violation. Empty if the LazyFrame is eligible.
+        Scans of the local file system also list the nodes that lead from the
+        root of the plan to the scan, so that the scan can be located.
+    """
+    optimizations = optimizations.__copy__()
+    pylf = lf._ldf.with_optimizations(optimizations._pyoptflags)
+    return plr.validate_cloud_plan(
+        pylf,
+        allow_local_scans=allow_local_scans,
+        max_inline_dataframe_bytes=max_inline_dataframe_bytes,
+    )
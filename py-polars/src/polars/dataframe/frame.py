@@ -5851,6 +5851,7 @@ class DataFrame:
         nulls_last: bool | Sequence[bool] = False,
         multithreaded: bool = True,
         maintain_order: bool = False,
+        deterministic: bool = False,
     ) -> DataFrame:
         """
         Sort the dataframe by the given columns.
@@ -5872,6 +5873,12 @@ class DataFrame:
             Sort using multiple threads.
         maintain_order
             Whether the order should be maintained if elements are equal.
+        deterministic
+            Break ties between equal elements on their position in the input, so
+            the result does not change between runs and equals the result of
+            `maintain_order=True`. This adds the row index as an extra sort key,
+            which is typically cheaper than the stable sort of `maintain_order`.
+            Ignored if `maintain_order` is set.
 
         Examples
         --------
@@ -5949,6 +5956,7 @@ class DataFrame:
                 nulls_last=nulls_last,
                 multithreaded=multithreaded,
                 maintain_order=maintain_order,
+                deterministic=deterministic,
             )
             .collect(optimizations=QueryOptFlags._eager())
         )
@@ -1,14 +1,16 @@
 from __future__ import annotations
 
 from collections.abc import Sequence
-from typing import TYPE_CHECKING, Callable
+from typing import TYPE_CHECKING, Any, Callable
 
 from polars._utils.parse import parse_into_expression
 from polars._utils.wrap import wrap_expr
+from polars.datatypes import parse_into_datatype_expr
 
 if TYPE_CHECKING:
+    import polars as pl
     from polars import Expr
-    from polars._typing import IntoExpr, IntoExprColumn
+    from polars._typing import IntoExpr, IntoExprColumn, PolarsDataType
 
 
 class ExprArrayNameSpace:
@@ -350,6 +352,260 @@ class ExprArrayNameSpace:
         """
         return wrap_expr(self._pyexpr.arr_median())
 
+    def leaf_sum(self) -> Expr:
+        """
+        Compute the sum of all leaf values of the (nested) sub-arrays.
+
+        Unlike chaining reductions of every nesting level, this reduces over the
+        values of all nesting levels at once. Null values and the values of null
+        inner arrays are skipped.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[[1, 2], [3, 4]], [[5, None], [7, 8]]]},
+        ...     schema={"a": pl.Array(pl.Array(pl.Int64, 2), 2)},
+        ... )
+        >>> df.select(pl.col("a").arr.leaf_sum())
+        shape: (2, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 10  │
+        │ 20  │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.arr_leaf_sum())
+
+    def leaf_mean(self) -> Expr:
+        """
+        Compute the mean of all leaf values of the (nested) sub-arrays.
+
+        Unlike chaining reductions of every nesting level, this reduces over the
+        values of all nesting levels at once. Null values and the values of null
+        inner arrays are skipped.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[[1, 2], [3, 4]], [[5, None], [7, 8]]]},
+        ...     schema={"a": pl.Array(pl.Array(pl.Int64, 2), 2)},
+        ... )
+        >>> df.select(pl.col("a").arr.leaf_mean())
+        shape: (2, 1)
+        ┌──────────┐
+        │ a        │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ 2.5      │
+        │ 6.666667 │
+        └──────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_leaf_mean())
+
+    def leaf_min(self) -> Expr:
+        """
+        Compute the min of all leaf values of the (nested) sub-arrays.
+
+        Unlike chaining reductions of every nesting level, this reduces over the
+        values of all nesting levels at once. Null values and the values of null
+        inner arrays are skipped.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[[1, 2], [3, 4]], [[5, None], [7, 8]]]},
+        ...     schema={"a": pl.Array(pl.Array(pl.Int64, 2), 2)},
+        ... )
+        >>> df.select(pl.col("a").arr.leaf_min())
+        shape: (2, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        │ 5   │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.arr_leaf_min())
+
+    def leaf_max(self) -> Expr:
+        """
+        Compute the max of all leaf values of the (nested) sub-arrays.
+
+        Unlike chaining reductions of every nesting level, this reduces over the
+        values of all nesting levels at once. Null values and the values of null
+        inner arrays are skipped.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[[1, 2], [3, 4]], [[5, None], [7, 8]]]},
+        ...     schema={"a": pl.Array(pl.Array(pl.Int64, 2), 2)},
+        ... )
+        >>> df.select(pl.col("a").arr.leaf_max())
+        shape: (2, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 4   │
+        │ 8   │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.arr_leaf_max())
+
+    def cum_sum(self, *, reverse: bool = False) -> Expr:
+        """
+        Compute the cumulative sum of the values in every sub-array.
+
+        Null values are kept as null and skipped by the cumulative sum, like
+        :meth:`Expr.cum_sum`.
+
+        Parameters
+        ----------
+        reverse
+            Compute the cumulative sum from the end of every sub-array.
+
+        Notes
+        -----
+        Dtypes in {Int8, UInt8, Int16, UInt16} are cast to
+        Int64 before summing to prevent overflow issues.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [4, None, 6]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(
+        ...     cum_sum=pl.col("a").arr.cum_sum(),
+        ...     cum_sum_reverse=pl.col("a").arr.cum_sum(reverse=True),
+        ... )
+        shape: (2, 3)
+        ┌───────────────┬───────────────┬─────────────────┐
+        │ a             ┆ cum_sum       ┆ cum_sum_reverse │
+        │ ---           ┆ ---           ┆ ---             │
+        │ array[i64, 3] ┆ array[i64, 3] ┆ array[i64, 3]   │
+        ╞═══════════════╪═══════════════╪═════════════════╡
+        │ [1, 2, 3]     ┆ [1, 3, 6]     ┆ [6, 5, 3]       │
+        │ [4, null, 6]  ┆ [4, null, 10] ┆ [10, null, 6]   │
+        └───────────────┴───────────────┴─────────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_cum_sum(reverse))
+
+    def cum_prod(self, *, reverse: bool = False) -> Expr:
+        """
+        Compute the cumulative product of the values in every sub-array.
+
+        Null values are kept as null and skipped by the cumulative product, like
+        :meth:`Expr.cum_prod`.
+
+        Parameters
+        ----------
+        reverse
+            Compute the cumulative product from the end of every sub-array.
+
+        Notes
+        -----
+        Dtypes in {Int8, UInt8, Int16, UInt16, Int32, UInt32} are cast to
+        Int64 before multiplying to prevent overflow issues.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [4, None, 6]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(
+        ...     cum_prod=pl.col("a").arr.cum_prod(),
+        ...     cum_prod_reverse=pl.col("a").arr.cum_prod(reverse=True),
+        ... )
+        shape: (2, 3)
+        ┌───────────────┬───────────────┬──────────────────┐
+        │ a             ┆ cum_prod      ┆ cum_prod_reverse │
+        │ ---           ┆ ---           ┆ ---              │
+        │ array[i64, 3] ┆ array[i64, 3] ┆ array[i64, 3]    │
+        ╞═══════════════╪═══════════════╪══════════════════╡
+        │ [1, 2, 3]     ┆ [1, 2, 6]     ┆ [6, 6, 3]        │
+        │ [4, null, 6]  ┆ [4, null, 24] ┆ [24, null, 6]    │
+        └───────────────┴───────────────┴──────────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_cum_prod(reverse))
+
+    def cum_min(self, *, reverse: bool = False) -> Expr:
+        """
+        Compute the cumulative minimum of the values in every sub-array.
+
+        Null values are kept as null and skipped by the cumulative minimum, like
+        :meth:`Expr.cum_min`.
+
+        Parameters
+        ----------
+        reverse
+            Compute the cumulative minimum from the end of every sub-array.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[3, 1, 2], [4, None, 6]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(
+        ...     cum_min=pl.col("a").arr.cum_min(),
+        ...     cum_min_reverse=pl.col("a").arr.cum_min(reverse=True),
+        ... )
+        shape: (2, 3)
+        ┌───────────────┬───────────────┬─────────────────┐
+        │ a             ┆ cum_min       ┆ cum_min_reverse │
+        │ ---           ┆ ---           ┆ ---             │
+        │ array[i64, 3] ┆ array[i64, 3] ┆ array[i64, 3]   │
+        ╞═══════════════╪═══════════════╪═════════════════╡
+        │ [3, 1, 2]     ┆ [3, 1, 1]     ┆ [1, 1, 2]       │
+        │ [4, null, 6]  ┆ [4, null, 4]  ┆ [4, null, 6]    │
+        └───────────────┴───────────────┴─────────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_cum_min(reverse))
+
+    def cum_max(self, *, reverse: bool = False) -> Expr:
+        """
+        Compute the cumulative maximum of the values in every sub-array.
+
+        Null values are kept as null and skipped by the cumulative maximum, like
+        :meth:`Expr.cum_max`.
+
+        Parameters
+        ----------
+        reverse
+            Compute the cumulative maximum from the end of every sub-array.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[3, 1, 2], [4, None, 6]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(
+        ...     cum_max=pl.col("a").arr.cum_max(),
+        ...     cum_max_reverse=pl.col("a").arr.cum_max(reverse=True),
+        ... )
+        shape: (2, 3)
+        ┌───────────────┬───────────────┬─────────────────┐
+        │ a             ┆ cum_max       ┆ cum_max_reverse │
+        │ ---           ┆ ---           ┆ ---             │
+        │ array[i64, 3] ┆ array[i64, 3] ┆ array[i64, 3]   │
+        ╞═══════════════╪═══════════════╪═════════════════╡
+        │ [3, 1, 2]     ┆ [3, 3, 3]     ┆ [3, 2, 2]       │
+        │ [4, null, 6]  ┆ [4, null, 6]  ┆ [6, null, 6]    │
+        └───────────────┴───────────────┴─────────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_cum_max(reverse))
+
     def unique(self, *, maintain_order: bool = False) -> Expr:
         """
         Get the unique/distinct values in the array.
@@ -667,6 +923,102 @@ class ExprArrayNameSpace:
         index_pyexpr = parse_into_expression(index)
         return wrap_expr(self._pyexpr.arr_get(index_pyexpr, null_on_oob))
 
+    def scatter(
+        self,
+        index: int | IntoExprColumn,
+        value: IntoExpr,
+        *,
+        skip_oob: bool = False,
+    ) -> Expr:
+        """
+        Set the value at the given index in every sub-array.
+
+        Negative indices count from the end of the sub-arrays. Rows with a null index
+        are left unchanged.
+
+        Parameters
+        ----------
+        index
+            Index to set per sub-array.
+        value
+            Value to write per sub-array. Accepts expression input. Strings are parsed
+            as literals. The inner data type of the result is the supertype of the
+            inner data type of the arrays and the data type of the value.
+        skip_oob
+            Behavior if an index is out of bounds:
+            True -> leave the sub-array unchanged
+            False -> raise an error
+
+        See Also
+        --------
+        set_at_idx_masked
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"arr": [[1, 2, 3], [4, 5, 6], [7, 8, 9]], "idx": [0, -1, None]},
+        ...     schema={"arr": pl.Array(pl.Int64, 3), "idx": pl.Int64},
+        ... )
+        >>> df.with_columns(scattered=pl.col("arr").arr.scatter("idx", 0))
+        shape: (3, 3)
+        ┌───────────────┬──────┬───────────────┐
+        │ arr           ┆ idx  ┆ scattered     │
+        │ ---           ┆ ---  ┆ ---           │
+        │ array[i64, 3] ┆ i64  ┆ array[i64, 3] │
+        ╞═══════════════╪══════╪═══════════════╡
+        │ [1, 2, 3]     ┆ 0    ┆ [0, 2, 3]     │
+        │ [4, 5, 6]     ┆ -1   ┆ [4, 5, 0]     │
+        │ [7, 8, 9]     ┆ null ┆ [7, 8, 9]     │
+        └───────────────┴──────┴───────────────┘
+        """
+        index_pyexpr = parse_into_expression(index)
+        value_pyexpr = parse_into_expression(value, str_as_lit=True)
+        return wrap_expr(
+            self._pyexpr.arr_scatter(index_pyexpr, value_pyexpr, skip_oob)
+        )
+
+    def set_at_idx_masked(self, mask: IntoExprColumn, value: IntoExpr) -> Expr:
+        """
+        Set the values of every sub-array where the mask is true.
+
+        Parameters
+        ----------
+        mask
+            Boolean arrays of the same width, which select the values to set. Null
+            masks and null values in the mask leave the values unchanged.
+        value
+            Value to write per sub-array. Accepts expression input. Strings are parsed
+            as literals. The inner data type of the result is the supertype of the
+            inner data type of the arrays and the data type of the value.
+
+        See Also
+        --------
+        scatter
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "arr": [[1, 2, 3], [4, 5, 6]],
+        ...         "mask": [[True, False, True], [False, False, True]],
+        ...     },
+        ...     schema={"arr": pl.Array(pl.Int64, 3), "mask": pl.Array(pl.Boolean, 3)},
+        ... )
+        >>> df.with_columns(pl.col("arr").arr.set_at_idx_masked("mask", -1))
+        shape: (2, 2)
+        ┌───────────────┬──────────────────────┐
+        │ arr           ┆ mask                 │
+        │ ---           ┆ ---                  │
+        │ array[i64, 3] ┆ array[bool, 3]       │
+        ╞═══════════════╪══════════════════════╡
+        │ [-1, 2, -1]   ┆ [true, false, true]  │
+        │ [4, 5, -1]    ┆ [false, false, true] │
+        └───────────────┴──────────────────────┘
+        """
+        mask_pyexpr = parse_into_expression(mask)
+        value_pyexpr = parse_into_expression(value, str_as_lit=True)
+        return wrap_expr(self._pyexpr.arr_set_at_idx_masked(mask_pyexpr, value_pyexpr))
+
     def first(self) -> Expr:
         """
         Get the first value of the sub-arrays.
@@ -799,7 +1151,15 @@ class ExprArrayNameSpace:
             self._pyexpr.arr_explode(empty_as_null=empty_as_null, keep_nulls=keep_nulls)
         )
 
-    def contains(self, item: IntoExpr, *, nulls_equal: bool = True) -> Expr:
+    def contains(
+        self,
+        item: IntoExpr,
+        *,
+        nulls_equal: bool = True,
+        assume_sorted_rows: bool = False,
+        descending: bool = False,
+        validate: bool = False,
+    ) -> Expr:
         """
         Check if sub-arrays contain the given item.
 
@@ -809,6 +1169,21 @@ class ExprArrayNameSpace:
             Item that will be checked for membership
         nulls_equal : bool, default True
             If True, treat null as a distinct value. Null values will not propagate.
+        assume_sorted_rows
+            Assume that the values in every sub-array are sorted, so that the
+            sub-arrays can be binary searched instead of scanned. Only numeric inner
+            data types are binary searched, other data types are always scanned.
+
+            .. warning::
+                If a sub-array that does not contain nulls is not sorted, the
+                result for that sub-array is wrong. Use `validate=True` or
+                :meth:`rows_are_sorted` if the sort order is not guaranteed.
+        descending
+            Whether the sub-arrays are sorted in descending order. Only used if
+            `assume_sorted_rows=True`.
+        validate
+            Check that every sub-array is sorted and scan the sub-arrays that are
+            not. Only used if `assume_sorted_rows=True`.
 
         Returns
         -------
@@ -834,9 +1209,20 @@ class ExprArrayNameSpace:
         └───────────────┴──────────┘
         """
         item_pyexpr = parse_into_expression(item, str_as_lit=True)
-        return wrap_expr(self._pyexpr.arr_contains(item_pyexpr, nulls_equal))
+        return wrap_expr(
+            self._pyexpr.arr_contains(
+                item_pyexpr, nulls_equal, assume_sorted_rows, descending, validate
+            )
+        )
 
-    def count_matches(self, element: IntoExpr) -> Expr:
+    def count_matches(
+        self,
+        element: IntoExpr,
+        *,
+        assume_sorted_rows: bool = False,
+        descending: bool = False,
+        validate: bool = False,
+    ) -> Expr:
         """
         Count how often the value produced by `element` occurs.
 
@@ -844,6 +1230,21 @@ class ExprArrayNameSpace:
         ----------
         element
             An expression that produces a single value
+        assume_sorted_rows
+            Assume that the values in every sub-array are sorted, so that the
+            sub-arrays can be binary searched instead of scanned. Only numeric inner
+            data types are binary searched, other data types are always scanned.
+
+            .. warning::
+                If a sub-array that does not contain nulls is not sorted, the
+                result for that sub-array is wrong. Use `validate=True` or
+                :meth:`rows_are_sorted` if the sort order is not guaranteed.
+        descending
+            Whether the sub-arrays are sorted in descending order. Only used if
+            `assume_sorted_rows=True`.
+        validate
+            Check that every sub-array is sorted and scan the sub-arrays that are
+            not. Only used if `assume_sorted_rows=True`.
 
         Examples
         --------
@@ -863,7 +1264,98 @@ class ExprArrayNameSpace:
         └───────────────┴────────────────┘
         """
         element_pyexpr = parse_into_expression(element, str_as_lit=True)
-        return wrap_expr(self._pyexpr.arr_count_matches(element_pyexpr))
+        return wrap_expr(
+            self._pyexpr.arr_count_matches(
+                element_pyexpr, assume_sorted_rows, descending, validate
+            )
+        )
+
+    def rows_are_sorted(self, *, descending: bool = False) -> Expr:
+        """
+        Check whether the values in every sub-array are sorted.
+
+        Sub-arrays that contain null values are not considered sorted. This is
+        only supported for numeric inner data types.
+
+        Parameters
+        ----------
+        descending
+            Check for descending instead of ascending order.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [3, 1, 2], [1, None, 3]]},
+        ...     schema={"a": pl.Array(pl.Int64, 3)},
+        ... )
+        >>> df.with_columns(sorted=pl.col("a").arr.rows_are_sorted())
+        shape: (3, 2)
+        ┌───────────────┬────────┐
+        │ a             ┆ sorted │
+        │ ---           ┆ ---    │
+        │ array[i64, 3] ┆ bool   │
+        ╞═══════════════╪════════╡
+        │ [1, 2, 3]     ┆ true   │
+        │ [3, 1, 2]     ┆ false  │
+        │ [1, null, 3]  ┆ false  │
+        └───────────────┴────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_rows_are_sorted(descending))
+
+    def cast_inner(
+        self,
+        dtype: PolarsDataType | pl.DataTypeExpr | type[Any],
+        *,
+        strict: bool = True,
+        wrap_numerical: bool = False,
+    ) -> Expr:
+        """
+        Cast the values in every sub-array to another data type.
+
+        The width of the arrays is kept.
+
+        Parameters
+        ----------
+        dtype
+            DataType to cast the inner values to.
+        strict
+            Raise if the cast of any inner value is invalid. The error names the
+            first row that failed. If `False`, invalid casts produce null values.
+        wrap_numerical
+            If True numeric casts wrap overflowing values instead of
+            marking the cast as invalid.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Array`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 300], [2, 3]]},
+        ...     schema={"a": pl.Array(pl.Int64, 2)},
+        ... )
+        >>> df.with_columns(b=pl.col("a").arr.cast_inner(pl.UInt8, strict=False))
+        shape: (2, 2)
+        ┌───────────────┬──────────────┐
+        │ a             ┆ b            │
+        │ ---           ┆ ---          │
+        │ array[i64, 2] ┆ array[u8, 2] │
+        ╞═══════════════╪══════════════╡
+        │ [1, 300]      ┆ [1, null]    │
+        │ [2, 3]        ┆ [2, 3]       │
+        └───────────────┴──────────────┘
+        """
+        dtype = parse_into_datatype_expr(dtype)
+        return wrap_expr(
+            self._pyexpr.arr_cast_inner(dtype._pydatatype_expr, strict, wrap_numerical)
+        )
 
     def to_struct(
         self, fields: Sequence[str] | Callable[[int], str] | None = None
@@ -5116,6 +5116,45 @@ Consider using {self}.implode() instead"""
         """
         return wrap_expr(self._pyexpr.implode())
 
+    @unstable()
+    def implode_to_array(self, width: int) -> Expr:
+        """
+        Aggregate values into an array of `width` values.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The returned array itself is a scalar value of `array` dtype. In a group-by
+        context, every group must have exactly `width` values, which keep their order
+        within the group.
+
+        Parameters
+        ----------
+        width
+            The number of values of the array.
+
+        Raises
+        ------
+        ShapeError
+            If there aren't exactly `width` values to aggregate.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"g": [1, 2, 1, 2], "a": [1, 2, 3, 4]})
+        >>> df.group_by("g", maintain_order=True).agg(pl.col("a").implode_to_array(2))
+        shape: (2, 2)
+        ┌─────┬───────────────┐
+        │ g   ┆ a             │
+        │ --- ┆ ---           │
+        │ i64 ┆ array[i64, 2] │
+        ╞═════╪═══════════════╡
+        │ 1   ┆ [1, 3]        │
+        │ 2   ┆ [2, 4]        │
+        └─────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.implode_to_array(width))
+
     def gather_every(self, n: int, offset: int = 0) -> Expr:
         """
         Take every nth value in the Series and return as a new Series.
@@ -7762,6 +7801,7 @@ Consider using {self}.implode() instead"""
         seed: int | None = None,
         min_samples: int = 1,
         closed: ClosedInterval = "right",
+        descending: bool = False,
     ) -> Expr:
         """
         Compute a rolling rank based on another column.
@@ -7829,6 +7869,8 @@ Consider using {self}.implode() instead"""
         closed : {'left', 'right', 'both', 'none'}
             Define which sides of the temporal interval are closed (inclusive),
             defaults to `'right'`.
+        descending
+            Assign the smallest rank to the largest value in the window.
 
         Returns
         -------
@@ -7846,6 +7888,7 @@ Consider using {self}.implode() instead"""
                 seed,
                 min_samples,
                 closed,
+                descending,
             )
         )
 
@@ -8416,6 +8459,7 @@ Consider using {self}.implode() instead"""
         min_samples: int | None = None,
         center: bool = False,
         ddof: int = 1,
+        halflife: float | None = None,
     ) -> Expr:
         """
         Compute a rolling variance.
@@ -8446,6 +8490,10 @@ Consider using {self}.implode() instead"""
             Set the labels at the center of the window.
         ddof
             "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
+        halflife
+            Weigh the values in the window by their distance to the end of the window,
+            such that the weight halves every `halflife` elements. Null values keep
+            their position in the window. Cannot be combined with `weights`.
 
         Notes
         -----
@@ -8513,6 +8561,9 @@ Consider using {self}.implode() instead"""
         │ 6.0 ┆ null        │
         └─────┴─────────────┘
         """
+        if halflife is not None and weights is not None:
+            msg = "`halflife` cannot be combined with `weights`"
+            raise ValueError(msg)
         return wrap_expr(
             self._pyexpr.rolling_var(
                 window_size,
@@ -8520,6 +8571,7 @@ Consider using {self}.implode() instead"""
                 min_samples,
                 center=center,
                 ddof=ddof,
+                halflife=halflife,
             )
         )
 
@@ -8788,6 +8840,7 @@ Consider using {self}.implode() instead"""
         seed: int | None = None,
         min_samples: int | None = None,
         center: bool = False,
+        descending: bool = False,
     ) -> Expr:
         """
         Compute a rolling rank.
@@ -8828,6 +8881,8 @@ Consider using {self}.implode() instead"""
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
+        descending
+            Assign the smallest rank to the largest value in the window.
 
         Returns
         -------
@@ -8859,6 +8914,7 @@ Consider using {self}.implode() instead"""
                 seed,
                 min_samples,
                 center,
+                descending,
             )
         )
 
@@ -19,6 +19,7 @@ if TYPE_CHECKING:
         IntoExprColumn,
         ListToStructWidthStrategy,
         NullBehavior,
+        PadSide,
     )
 
 
@@ -1174,6 +1175,57 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_to_array(width))
 
+    def pad_to_array(
+        self,
+        width: int,
+        *,
+        side: PadSide = "start",
+        fill_value: IntoExpr = None,
+        truncate_side: PadSide = "start",
+    ) -> Expr:
+        """
+        Pad or truncate every sublist to `width` values and convert it into an Array.
+
+        Parameters
+        ----------
+        width
+            Width of the resulting Array column.
+        side : {'start', 'end'}
+            Side at which sublists shorter than `width` are padded.
+        fill_value
+            Scalar value to pad with. By default the padded values are null.
+        truncate_side : {'start', 'end'}
+            Side at which values are dropped from sublists longer than `width`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Array`, with the supertype of the sublist
+            values and `fill_value` as inner data type.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3], [4], [], None]})
+        >>> df.with_columns(padded=pl.col("a").list.pad_to_array(2, fill_value=0))
+        shape: (4, 2)
+        ┌───────────┬───────────────┐
+        │ a         ┆ padded        │
+        │ ---       ┆ ---           │
+        │ list[i64] ┆ array[i64, 2] │
+        ╞═══════════╪═══════════════╡
+        │ [1, 2, 3] ┆ [2, 3]        │
+        │ [4]       ┆ [0, 4]        │
+        │ []        ┆ [0, 0]        │
+        │ null      ┆ null          │
+        └───────────┴───────────────┘
+        """
+        fill_value_pyexpr = parse_into_expression(fill_value, str_as_lit=True)
+        return wrap_expr(
+            self._pyexpr.list_pad_to_array(
+                width, side, fill_value_pyexpr, truncate_side
+            )
+        )
+
     def to_struct(
         self,
         n_field_strategy: ListToStructWidthStrategy | None = None,
@@ -83,6 +83,7 @@ from polars.functions.range import (
     datetime_range,
     datetime_ranges,
     int_range,
+    int_range_array,
     int_ranges,
     linear_space,
     linear_spaces,
@@ -160,6 +161,7 @@ __all__ = [
     "head",
     "implode",
     "int_range",
+    "int_range_array",
     "int_ranges",
     "last",
     "linear_space",
@@ -1,6 +1,11 @@
 from polars.functions.range.date_range import date_range, date_ranges
 from polars.functions.range.datetime_range import datetime_range, datetime_ranges
-from polars.functions.range.int_range import arange, int_range, int_ranges
+from polars.functions.range.int_range import (
+    arange,
+    int_range,
+    int_range_array,
+    int_ranges,
+)
 from polars.functions.range.linear_space import linear_space, linear_spaces
 from polars.functions.range.time_range import time_range, time_ranges
 
@@ -11,6 +16,7 @@ __all__ = [
     "datetime_range",
     "datetime_ranges",
     "int_range",
+    "int_range_array",
     "int_ranges",
     "linear_space",
     "linear_spaces",
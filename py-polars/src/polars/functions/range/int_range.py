@@ -16,7 +16,7 @@ if TYPE_CHECKING:
     from typing import Literal
 
     from polars import DataTypeExpr, Expr, Series
-    from polars._typing import IntoExprColumn, PolarsIntegerType
+    from polars._typing import IntoExprColumn, PolarsIntegerType, RangeNullBehavior
 
 
 @overload
@@ -110,6 +110,7 @@ def int_range(
     step: int = ...,
     *,
     dtype: PolarsIntegerType | DataTypeExpr = ...,
+    null_behavior: RangeNullBehavior = ...,
     eager: Literal[False] = ...,
 ) -> Expr: ...
 
@@ -121,6 +122,7 @@ def int_range(
     step: int = ...,
     *,
     dtype: PolarsIntegerType | DataTypeExpr = ...,
+    null_behavior: RangeNullBehavior = ...,
     eager: Literal[True],
 ) -> Series: ...
 
@@ -132,6 +134,7 @@ def int_range(
     step: int = ...,
     *,
     dtype: PolarsIntegerType | DataTypeExpr = ...,
+    null_behavior: RangeNullBehavior = ...,
     eager: bool,
 ) -> Expr | Series: ...
 
@@ -142,6 +145,7 @@ def int_range(
     step: int = 1,
     *,
     dtype: PolarsIntegerType | DataTypeExpr = Int64,
+    null_behavior: RangeNullBehavior = "raise",
     eager: bool = False,
 ) -> Expr | Series:
     """
@@ -158,6 +162,11 @@ def int_range(
         Step size of the range.
     dtype
         Data type of the range.
+    null_behavior : {'raise', 'propagate'}
+        What to do if `start` or `end` is null.
+
+        - 'raise': Raise an error.
+        - 'propagate': Return an empty range.
     eager
         Evaluate immediately and return a `Series`.
         If set to `False` (default), return an expression instead.
@@ -224,7 +233,13 @@ def int_range(
     start_pyexpr = parse_into_expression(start)
     end_pyexpr = parse_into_expression(end)
     result = wrap_expr(
-        plr.int_range(start_pyexpr, end_pyexpr, step, dtype_expr._pydatatype_expr)
+        plr.int_range(
+            start_pyexpr,
+            end_pyexpr,
+            step,
+            dtype_expr._pydatatype_expr,
+            null_behavior,
+        )
     )
 
     if eager:
@@ -346,3 +361,118 @@ def int_ranges(
         return F.select(result).to_series()
 
     return result
+
+
+@overload
+def int_range_array(
+    start: int | IntoExprColumn,
+    length: int,
+    step: int = ...,
+    *,
+    dtype: PolarsIntegerType | DataTypeExpr = ...,
+    eager: Literal[False] = ...,
+) -> Expr: ...
+
+
+@overload
+def int_range_array(
+    start: int | IntoExprColumn,
+    length: int,
+    step: int = ...,
+    *,
+    dtype: PolarsIntegerType | DataTypeExpr = ...,
+    eager: Literal[True],
+) -> Series: ...
+
+
+@overload
+def int_range_array(
+    start: int | IntoExprColumn,
+    length: int,
+    step: int = ...,
+    *,
+    dtype: PolarsIntegerType | DataTypeExpr = ...,
+    eager: bool,
+) -> Expr | Series: ...
+
+
+def int_range_array(
+    start: int | IntoExprColumn,
+    length: int,
+    step: int = 1,
+    *,
+    dtype: PolarsIntegerType | DataTypeExpr = Int64,
+    eager: bool = False,
+) -> Expr | Series:
+    """
+    Generate a range of integers of a fixed length for each row of the input column.
+
+    As all ranges have the same length, the result is an `Array` instead of the
+    `List` of :func:`int_ranges`.
+
+    Parameters
+    ----------
+    start
+        Start of the ranges (inclusive). A null start produces a null array.
+    length
+        Number of integers in every range.
+    step
+        Step size of the ranges.
+    dtype
+        Integer data type of the ranges. Defaults to `Int64`.
+    eager
+        Evaluate immediately and return a `Series`.
+        If set to `False` (default), return an expression instead.
+
+    Returns
+    -------
+    Expr or Series
+        Column of data type `Array(dtype, length)`.
+
+    See Also
+    --------
+    int_ranges : Generate a range of integers for each row, with variable lengths.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"start": [1, None, 10]})
+    >>> df.with_columns(range=pl.int_range_array("start", 3))
+    shape: (3, 2)
+    ┌───────┬───────────────┐
+    │ start ┆ range         │
+    │ ---   ┆ ---           │
+    │ i64   ┆ array[i64, 3] │
+    ╞═══════╪═══════════════╡
+    │ 1     ┆ [1, 2, 3]     │
+    │ null  ┆ null          │
+    │ 10    ┆ [10, 11, 12]  │
+    └───────┴───────────────┘
+
+    The step can be negative.
+
+    >>> df.select(range=pl.int_range_array("start", 2, step=-2, dtype=pl.Int8))
+    shape: (3, 1)
+    ┌──────────────┐
+    │ range        │
+    │ ---          │
+    │ array[i8, 2] │
+    ╞══════════════╡
+    │ [1, -1]      │
+    │ null         │
+    │ [10, 8]      │
+    └──────────────┘
+    """
+    if length < 0:
+        msg = f"`length` must be non-negative, got {length}"
+        raise ValueError(msg)
+
+    dtype_expr = parse_into_datatype_expr(dtype)
+    start_pyexpr = parse_into_expression(start)
+    result = wrap_expr(
+        plr.int_range_array(start_pyexpr, length, step, dtype_expr._pydatatype_expr)
+    )
+
+    if eager:
+        return F.select(result).to_series()
+
+    return result
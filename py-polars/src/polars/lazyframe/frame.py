@@ -1593,6 +1593,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         nulls_last: bool | Sequence[bool] = False,
         maintain_order: bool = False,
         multithreaded: bool = True,
+        deterministic: bool = False,
     ) -> LazyFrame:
         """
         Sort the LazyFrame by the given columns.
@@ -1616,6 +1617,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             worse since this requires a stable search.
         multithreaded
             Sort using multiple threads.
+        deterministic
+            Break ties between equal elements on their position in the input, so
+            the result does not change between runs and equals the result of
+            `maintain_order=True`. This adds the row index as an extra sort key,
+            which is typically cheaper than the stable sort of `maintain_order`.
+            Ignored if `maintain_order` is set.
 
         Examples
         --------
@@ -1691,7 +1698,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         ):
             return self._from_pyldf(
                 self._ldf.sort(
-                    by, descending, nulls_last, maintain_order, multithreaded
+                    by,
+                    descending,
+                    nulls_last,
+                    maintain_order,
+                    deterministic,
+                    multithreaded,
                 )
             )
 
@@ -1701,7 +1713,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         return self._from_pyldf(
             self._ldf.sort_by_exprs(
-                by, descending, nulls_last, maintain_order, multithreaded
+                by,
+                descending,
+                nulls_last,
+                maintain_order,
+                deterministic,
+                multithreaded,
             )
         )
 
@@ -5576,6 +5593,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order: MaintainOrderJoin | None = None,
         allow_parallel: bool = True,
         force_parallel: bool = False,
+        bloom_filter: bool | None = None,
     ) -> LazyFrame:
         """
         Add a join operation to the Logical Plan.
@@ -5684,6 +5702,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         force_parallel
             Force the physical plan to evaluate the computation of both DataFrames up to
             the join in parallel.
+        bloom_filter
+            Whether the streaming engine builds a bloom filter over the join keys of the
+            `other` DataFrame for semi and anti joins, which lets it skip most hash
+            table lookups for keys that don't match. Only used for composite keys, such
+            as keys of multiple columns. By default this is decided based on the number
+            of keys in `other`.
 
         See Also
         --------
@@ -5848,6 +5872,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 validate,
                 maintain_order,
                 coalesce,
+                bloom_filter=bloom_filter,
             )
         )
 
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, Callable
+from typing import TYPE_CHECKING, Any, Callable
 
 from polars import functions as F
 from polars._utils.wrap import wrap_s
@@ -9,9 +9,10 @@ from polars.series.utils import expr_dispatch
 if TYPE_CHECKING:
     from collections.abc import Sequence
 
+    import polars as pl
     from polars import Series
     from polars._plr import PySeries
-    from polars._typing import IntoExpr, IntoExprColumn
+    from polars._typing import IntoExpr, IntoExprColumn, PolarsDataType
     from polars.expr.expr import Expr
 
 
@@ -140,6 +141,200 @@ class ArrayNameSpace:
         ]
         """
 
+    def leaf_sum(self) -> Series:
+        """
+        Compute the sum of all leaf values of the (nested) sub-arrays.
+
+        Null values and the values of null inner arrays are skipped.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a",
+        ...     [[[1, 2], [3, 4]], [[5, None], [7, 8]]],
+        ...     dtype=pl.Array(pl.Array(pl.Int64, 2), 2),
+        ... )
+        >>> s.arr.leaf_sum()
+        shape: (2,)
+        Series: 'a' [i64]
+        [
+            10
+            20
+        ]
+        """
+
+    def leaf_mean(self) -> Series:
+        """
+        Compute the mean of all leaf values of the (nested) sub-arrays.
+
+        Null values and the values of null inner arrays are skipped.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a",
+        ...     [[[1, 2], [3, 4]], [[5, None], [7, 8]]],
+        ...     dtype=pl.Array(pl.Array(pl.Int64, 2), 2),
+        ... )
+        >>> s.arr.leaf_mean()
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            2.5
+            6.666667
+        ]
+        """
+
+    def leaf_min(self) -> Series:
+        """
+        Compute the min of all leaf values of the (nested) sub-arrays.
+
+        Null values and the values of null inner arrays are skipped.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a",
+        ...     [[[1, 2], [3, 4]], [[5, None], [7, 8]]],
+        ...     dtype=pl.Array(pl.Array(pl.Int64, 2), 2),
+        ... )
+        >>> s.arr.leaf_min()
+        shape: (2,)
+        Series: 'a' [i64]
+        [
+            1
+            5
+        ]
+        """
+
+    def leaf_max(self) -> Series:
+        """
+        Compute the max of all leaf values of the (nested) sub-arrays.
+
+        Null values and the values of null inner arrays are skipped.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a",
+        ...     [[[1, 2], [3, 4]], [[5, None], [7, 8]]],
+        ...     dtype=pl.Array(pl.Array(pl.Int64, 2), 2),
+        ... )
+        >>> s.arr.leaf_max()
+        shape: (2,)
+        Series: 'a' [i64]
+        [
+            4
+            8
+        ]
+        """
+
+    def cum_sum(self, *, reverse: bool = False) -> Series:
+        """
+        Compute the cumulative sum of the values in every sub-array.
+
+        Null values are kept as null and skipped by the cumulative sum, like
+        :meth:`Series.cum_sum`.
+
+        Parameters
+        ----------
+        reverse
+            Compute the cumulative sum from the end of every sub-array.
+
+        Notes
+        -----
+        Dtypes in {Int8, UInt8, Int16, UInt16} are cast to
+        Int64 before summing to prevent overflow issues.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, None, 6]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.cum_sum()
+        shape: (2,)
+        Series: 'a' [array[i64, 3]]
+        [
+            [1, 3, 6]
+            [4, null, 10]
+        ]
+        """
+
+    def cum_prod(self, *, reverse: bool = False) -> Series:
+        """
+        Compute the cumulative product of the values in every sub-array.
+
+        Null values are kept as null and skipped by the cumulative product, like
+        :meth:`Series.cum_prod`.
+
+        Parameters
+        ----------
+        reverse
+            Compute the cumulative product from the end of every sub-array.
+
+        Notes
+        -----
+        Dtypes in {Int8, UInt8, Int16, UInt16, Int32, UInt32} are cast to
+        Int64 before multiplying to prevent overflow issues.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, None, 6]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.cum_prod()
+        shape: (2,)
+        Series: 'a' [array[i64, 3]]
+        [
+            [1, 2, 6]
+            [4, null, 24]
+        ]
+        """
+
+    def cum_min(self, *, reverse: bool = False) -> Series:
+        """
+        Compute the cumulative minimum of the values in every sub-array.
+
+        Null values are kept as null and skipped by the cumulative minimum, like
+        :meth:`Series.cum_min`.
+
+        Parameters
+        ----------
+        reverse
+            Compute the cumulative minimum from the end of every sub-array.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 1, 2], [4, None, 6]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.cum_min()
+        shape: (2,)
+        Series: 'a' [array[i64, 3]]
+        [
+            [3, 1, 1]
+            [4, null, 4]
+        ]
+        """
+
+    def cum_max(self, *, reverse: bool = False) -> Series:
+        """
+        Compute the cumulative maximum of the values in every sub-array.
+
+        Null values are kept as null and skipped by the cumulative maximum, like
+        :meth:`Series.cum_max`.
+
+        Parameters
+        ----------
+        reverse
+            Compute the cumulative maximum from the end of every sub-array.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 1, 2], [4, None, 6]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.cum_max()
+        shape: (2,)
+        Series: 'a' [array[i64, 3]]
+        [
+            [3, 3, 3]
+            [4, null, 6]
+        ]
+        """
+
     def unique(self, *, maintain_order: bool = False) -> Series:
         """
         Get the unique/distinct values in the array.
@@ -547,6 +742,76 @@ class ArrayNameSpace:
 
         """
 
+    def scatter(
+        self,
+        index: int | IntoExprColumn,
+        value: IntoExpr,
+        *,
+        skip_oob: bool = False,
+    ) -> Series:
+        """
+        Set the value at the given index in every sub-array.
+
+        Negative indices count from the end of the sub-arrays. Rows with a null index
+        are left unchanged.
+
+        Parameters
+        ----------
+        index
+            Index to set per sub-array.
+        value
+            Value to write per sub-array. The inner data type of the result is the
+            supertype of the inner data type of the arrays and the data type of the
+            value.
+        skip_oob
+            Behavior if an index is out of bounds:
+            True -> leave the sub-array unchanged
+            False -> raise an error
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a", [[1, 2, 3], [4, 5, 6], [7, 8, 9]], dtype=pl.Array(pl.Int64, 3)
+        ... )
+        >>> s.arr.scatter(1, pl.Series([10, 20, 30]))
+        shape: (3,)
+        Series: 'a' [array[i64, 3]]
+        [
+            [1, 10, 3]
+            [4, 20, 6]
+            [7, 30, 9]
+        ]
+        """
+
+    def set_at_idx_masked(self, mask: IntoExprColumn, value: IntoExpr) -> Series:
+        """
+        Set the values of every sub-array where the mask is true.
+
+        Parameters
+        ----------
+        mask
+            Boolean arrays of the same width, which select the values to set. Null
+            masks and null values in the mask leave the values unchanged.
+        value
+            Value to write per sub-array. The inner data type of the result is the
+            supertype of the inner data type of the arrays and the data type of the
+            value.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2], [3, 4]], dtype=pl.Array(pl.Int64, 2))
+        >>> mask = pl.Series(
+        ...     [[True, False], [True, True]], dtype=pl.Array(pl.Boolean, 2)
+        ... )
+        >>> s.arr.set_at_idx_masked(mask, 0)
+        shape: (2,)
+        Series: 'a' [array[i64, 2]]
+        [
+            [0, 2]
+            [0, 0]
+        ]
+        """
+
     def first(self) -> Series:
         """
         Get the first value of the sub-arrays.
@@ -653,7 +918,15 @@ class ArrayNameSpace:
         ]
         """
 
-    def contains(self, item: IntoExpr, *, nulls_equal: bool = True) -> Series:
+    def contains(
+        self,
+        item: IntoExpr,
+        *,
+        nulls_equal: bool = True,
+        assume_sorted_rows: bool = False,
+        descending: bool = False,
+        validate: bool = False,
+    ) -> Series:
         """
         Check if sub-arrays contain the given item.
 
@@ -663,6 +936,21 @@ class ArrayNameSpace:
             Item that will be checked for membership
         nulls_equal : bool, default True
             If True, treat null as a distinct value. Null values will not propagate.
+        assume_sorted_rows
+            Assume that the values in every sub-array are sorted, so that the
+            sub-arrays can be binary searched instead of scanned. Only numeric inner
+            data types are binary searched, other data types are always scanned.
+
+            .. warning::
+                If a sub-array that does not contain nulls is not sorted, the
+                result for that sub-array is wrong. Use `validate=True` or
+                :meth:`rows_are_sorted` if the sort order is not guaranteed.
+        descending
+            Whether the sub-arrays are sorted in descending order. Only used if
+            `assume_sorted_rows=True`.
+        validate
+            Check that every sub-array is sorted and scan the sub-arrays that are
+            not. Only used if `assume_sorted_rows=True`.
 
         Returns
         -------
@@ -685,7 +973,14 @@ class ArrayNameSpace:
 
         """
 
-    def count_matches(self, element: IntoExpr) -> Series:
+    def count_matches(
+        self,
+        element: IntoExpr,
+        *,
+        assume_sorted_rows: bool = False,
+        descending: bool = False,
+        validate: bool = False,
+    ) -> Series:
         """
         Count how often the value produced by `element` occurs.
 
@@ -693,6 +988,21 @@ class ArrayNameSpace:
         ----------
         element
             An expression that produces a single value
+        assume_sorted_rows
+            Assume that the values in every sub-array are sorted, so that the
+            sub-arrays can be binary searched instead of scanned. Only numeric inner
+            data types are binary searched, other data types are always scanned.
+
+            .. warning::
+                If a sub-array that does not contain nulls is not sorted, the
+                result for that sub-array is wrong. Use `validate=True` or
+                :meth:`rows_are_sorted` if the sort order is not guaranteed.
+        descending
+            Whether the sub-arrays are sorted in descending order. Only used if
+            `assume_sorted_rows=True`.
+        validate
+            Check that every sub-array is sorted and scan the sub-arrays that are
+            not. Only used if `assume_sorted_rows=True`.
 
         Examples
         --------
@@ -707,6 +1017,80 @@ class ArrayNameSpace:
 
         """
 
+    def rows_are_sorted(self, *, descending: bool = False) -> Series:
+        """
+        Check whether the values in every sub-array are sorted.
+
+        Sub-arrays that contain null values are not considered sorted. This is
+        only supported for numeric inner data types.
+
+        Parameters
+        ----------
+        descending
+            Check for descending instead of ascending order.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a", [[1, 2, 3], [3, 2, 1], [1, None, 3]], dtype=pl.Array(pl.Int64, 3)
+        ... )
+        >>> s.arr.rows_are_sorted()
+        shape: (3,)
+        Series: 'a' [bool]
+        [
+            true
+            false
+            false
+        ]
+
+        """
+
+    def cast_inner(
+        self,
+        dtype: PolarsDataType | pl.DataTypeExpr | type[Any],
+        *,
+        strict: bool = True,
+        wrap_numerical: bool = False,
+    ) -> Series:
+        """
+        Cast the values in every sub-array to another data type.
+
+        The width of the arrays is kept.
+
+        Parameters
+        ----------
+        dtype
+            DataType to cast the inner values to.
+        strict
+            Raise if the cast of any inner value is invalid. The error names the
+            first row that failed. If `False`, invalid casts produce null values.
+        wrap_numerical
+            If True numeric casts wrap overflowing values instead of
+            marking the cast as invalid.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Array`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 300], [2, 3]], dtype=pl.Array(pl.Int64, 2))
+        >>> s.arr.cast_inner(pl.UInt8, strict=False)
+        shape: (2,)
+        Series: 'a' [array[u8, 2]]
+        [
+            [1, null]
+            [2, 3]
+        ]
+
+        """
+
     def to_struct(
         self,
         fields: Callable[[int], str] | Sequence[str] | None = None,
@@ -18,6 +18,7 @@ if TYPE_CHECKING:
         IntoExprColumn,
         ListToStructWidthStrategy,
         NullBehavior,
+        PadSide,
     )
 
 
@@ -903,6 +904,46 @@ class ListNameSpace:
         ]
         """
 
+    def pad_to_array(
+        self,
+        width: int,
+        *,
+        side: PadSide = "start",
+        fill_value: IntoExpr = None,
+        truncate_side: PadSide = "start",
+    ) -> Series:
+        """
+        Pad or truncate every sublist to `width` values and convert it into an Array.
+
+        Parameters
+        ----------
+        width
+            Width of the resulting Array column.
+        side : {'start', 'end'}
+            Side at which sublists shorter than `width` are padded.
+        fill_value
+            Scalar value to pad with. By default the padded values are null.
+        truncate_side : {'start', 'end'}
+            Side at which values are dropped from sublists longer than `width`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Array`, with the supertype of the sublist
+            values and `fill_value` as inner data type.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1, 2, 3], [4]])
+        >>> s.list.pad_to_array(2, side="end", fill_value=0)
+        shape: (2,)
+        Series: '' [array[i64, 2]]
+        [
+                [2, 3]
+                [4, 0]
+        ]
+        """
+
     def to_struct(
         self,
         n_field_strategy: ListToStructWidthStrategy = "first_non_null",
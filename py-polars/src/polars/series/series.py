@@ -7122,6 +7122,7 @@ class Series:
         min_samples: int | None = None,
         center: bool = False,
         ddof: int = 1,
+        halflife: float | None = None,
     ) -> Series:
         """
         Compute a rolling variance.
@@ -7150,6 +7151,10 @@ class Series:
             Set the labels at the center of the window.
         ddof
             "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
+        halflife
+            Weigh the values in the window by their distance to the end of the window,
+            such that the weight halves every `halflife` elements. Null values keep
+            their position in the window. Cannot be combined with `weights`.
 
         Examples
         --------
@@ -7620,6 +7625,7 @@ class Series:
         seed: int | None = None,
         min_samples: int = 1,
         closed: ClosedInterval = "right",
+        descending: bool = False,
     ) -> Series:
         """
         Compute a rolling rank based on another column.
@@ -7687,6 +7693,8 @@ class Series:
         closed : {'left', 'right', 'both', 'none'}
             Define which sides of the temporal interval are closed (inclusive),
             defaults to `'right'`.
+        descending
+            Assign the smallest rank to the largest value in the window.
 
         Returns
         -------
@@ -7704,6 +7712,7 @@ class Series:
         seed: int | None = None,
         min_samples: int | None = None,
         center: bool = False,
+        descending: bool = False,
     ) -> Series:
         """
         Compute a rolling rank.
@@ -7744,6 +7753,8 @@ class Series:
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
+        descending
+            Assign the smallest rank to the largest value in the window.
 
         Returns
         -------
@@ -0,0 +1,51 @@
+"""Benchmark tests for the array kernels that assume sorted rows."""
+
+from __future__ import annotations
+
+import numpy as np
+import pytest
+
+import polars as pl
+
+pytestmark = pytest.mark.benchmark()
+
+
+@pytest.fixture(scope="module")
+def sorted_id_rows() -> pl.DataFrame:
+    # Tokenized ID lists of k=128 sorted IDs each.
+    num_rows = 100_000
+    width = 128
+    rng = np.random.default_rng(0)
+    ids = np.sort(rng.integers(0, 50_000, (num_rows, width)), axis=1)
+    return pl.DataFrame(
+        {
+            "ids": pl.Series(ids, dtype=pl.Array(pl.Int64, width)),
+            "id": rng.integers(0, 50_000, num_rows),
+        }
+    )
+
+
+@pytest.mark.parametrize(
+    ("assume_sorted_rows", "validate"), [(False, False), (True, False), (True, True)]
+)
+def test_arr_contains_sorted_rows_k128(
+    sorted_id_rows: pl.DataFrame, assume_sorted_rows: bool, validate: bool
+) -> None:
+    sorted_id_rows.select(
+        pl.col("ids").arr.contains(
+            pl.col("id"), assume_sorted_rows=assume_sorted_rows, validate=validate
+        )
+    )
+
+
+@pytest.mark.parametrize(
+    ("assume_sorted_rows", "validate"), [(False, False), (True, False), (True, True)]
+)
+def test_arr_count_matches_sorted_rows_k128(
+    sorted_id_rows: pl.DataFrame, assume_sorted_rows: bool, validate: bool
+) -> None:
+    sorted_id_rows.select(
+        pl.col("ids").arr.count_matches(
+            pl.col("id"), assume_sorted_rows=assume_sorted_rows, validate=validate
+        )
+    )
@@ -2,6 +2,7 @@
 
 from __future__ import annotations
 
+import numpy as np
 import pytest
 
 import polars as pl
@@ -31,3 +32,29 @@ def test_filter2(groupby_data: pl.DataFrame) -> None:
         )
         .collect()
     )
+
+
+@pytest.fixture(scope="module")
+def array_data() -> pl.DataFrame:
+    rng = np.random.default_rng(0)
+    n = 100_000
+    return pl.DataFrame(
+        {
+            "emb": pl.Series(
+                rng.uniform(size=(n, 128)), dtype=pl.Array(pl.Float64, 128)
+            ),
+            "key": rng.uniform(size=n),
+        }
+    )
+
+
+@pytest.mark.parametrize("selectivity", [0.01, 0.5, 0.99])
+def test_filter_array(array_data: pl.DataFrame, selectivity: float) -> None:
+    array_data.filter(pl.col("key") < selectivity)
+
+
+@pytest.mark.parametrize("selectivity", [0.01, 0.5, 0.99])
+def test_filter_array_as_list(array_data: pl.DataFrame, selectivity: float) -> None:
+    # The generic filter path, for comparison with `test_filter_array`.
+    df = array_data.with_columns(pl.col("emb").cast(pl.List(pl.Float64)))
+    df.filter(pl.col("key") < selectivity)
@@ -0,0 +1,31 @@
+"""Benchmark tests for the group-by operation with wide keys."""
+
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+
+pytestmark = pytest.mark.benchmark()
+
+NUM_ROWS = 1_000_000
+
+
+def wide_key_data(cardinality: int) -> pl.DataFrame:
+    # 12 key columns which row-encode to about 300 bytes, with long common prefixes.
+    group = pl.int_range(NUM_ROWS).mul(7919).mod(cardinality)
+    prefix = "x" * 120
+    return pl.select(
+        *(group.add(i).alias(f"k{i}") for i in range(10)),
+        pl.format(f"{prefix}{{}}", group).alias("s0"),
+        pl.format(f"{{}}{prefix}", group).alias("s1"),
+        pl.int_range(NUM_ROWS).alias("v"),
+    )
+
+
+@pytest.mark.parametrize("cardinality", [100, 10_000, 1_000_000])
+def test_group_by_wide_keys_streaming(cardinality: int) -> None:
+    df = wide_key_data(cardinality)
+    keys = [c for c in df.columns if c != "v"]
+    out = df.lazy().group_by(keys).agg(pl.sum("v")).collect(engine="streaming")
+    assert out.height == cardinality
@@ -0,0 +1,45 @@
+"""Benchmark tests for streaming semi/anti joins with a bloom filter on the keys."""
+
+from __future__ import annotations
+
+from time import perf_counter
+
+import numpy as np
+import pytest
+
+import polars as pl
+
+pytestmark = pytest.mark.benchmark()
+
+
+def test_anti_join_one_percent_match_bloom_filter() -> None:
+    n = 10_000_000
+    rng = np.random.default_rng(0)
+    build = pl.LazyFrame({"a": np.arange(n // 10), "b": np.arange(n // 10) % 13})
+
+    # Only ~1% of the probe keys fall into the key range of the build side.
+    probe_a = rng.integers(0, 100 * (n // 10), n)
+    probe = pl.LazyFrame({"a": probe_a, "b": probe_a % 13})
+
+    def run(bloom_filter: bool) -> tuple[float, int]:
+        q = probe.join(build, on=["a", "b"], how="anti", bloom_filter=bloom_filter)
+        t = perf_counter()
+        out = q.select(pl.len()).collect(engine="streaming").item()
+        return perf_counter() - t, out
+
+    # Take the best of a few runs to reduce noise.
+    times_off, times_on = [], []
+    for _ in range(3):
+        time_off, out_off = run(bloom_filter=False)
+        time_on, out_on = run(bloom_filter=True)
+        assert out_off == out_on
+        times_off.append(time_off)
+        times_on.append(time_on)
+
+    ratio = min(times_off) / min(times_on)
+
+    # Assert the relative rather than exact runtime to avoid flakiness in CI. Most
+    # probe keys skip the hash table lookup, so the filter must not make the join
+    # slower.
+    if ratio < 1.0:
+        raise AssertionError(ratio)
@@ -0,0 +1,57 @@
+"""Benchmark tests for joining Categorical keys with String keys."""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import numpy as np
+import pytest
+
+import polars as pl
+
+if TYPE_CHECKING:
+    from polars._typing import EngineType, JoinStrategy
+
+pytestmark = pytest.mark.benchmark()
+
+
+@pytest.fixture(scope="module")
+def categorical_string() -> tuple[pl.DataFrame, pl.DataFrame]:
+    num_rows = 10_000_000
+    num_categories = 100_000
+    rng = np.random.default_rng(0)
+
+    categories = pl.Series([f"category_{i}" for i in range(num_categories)])
+    left = pl.DataFrame(
+        {
+            "key": categories.gather(rng.integers(0, num_categories, num_rows)).cast(
+                pl.Categorical
+            ),
+            "a": np.arange(num_rows),
+        }
+    )
+    # Half of the keys on the right are not a category of the left.
+    right = pl.DataFrame(
+        {
+            "key": [f"category_{2 * i}" for i in range(num_categories)],
+            "b": np.arange(num_categories),
+        }
+    )
+    return left, right
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.parametrize("how", ["inner", "left"])
+@pytest.mark.parametrize("cast", [False, True])
+def test_join_categorical_string_10m(
+    categorical_string: tuple[pl.DataFrame, pl.DataFrame],
+    engine: EngineType,
+    how: JoinStrategy,
+    cast: bool,
+) -> None:
+    left, right = categorical_string
+    lf = left.lazy()
+    if cast:
+        # Materialize the categorical keys as strings, as the join used to.
+        lf = lf.with_columns(pl.col("key").cast(pl.String))
+    lf.join(right.lazy(), on="key", how=how).collect(engine=engine)
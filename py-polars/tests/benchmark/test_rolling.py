@@ -0,0 +1,30 @@
+"""Benchmark tests for rolling window functions."""
+
+from __future__ import annotations
+
+import numpy as np
+import pytest
+
+import polars as pl
+
+pytestmark = pytest.mark.benchmark()
+
+
+@pytest.fixture(scope="module")
+def rolling_data() -> pl.DataFrame:
+    rng = np.random.default_rng(0)
+    n = 10_000_000
+    return pl.DataFrame(
+        {
+            "g": rng.integers(0, 1_000_000, n),
+            "x": rng.standard_normal(n),
+        }
+    )
+
+
+def test_rolling_mean_over_many_groups(rolling_data: pl.DataFrame) -> None:
+    rolling_data.select(pl.col("x").rolling_mean(5).over("g"))
+
+
+def test_rolling_var_over_many_groups(rolling_data: pl.DataFrame) -> None:
+    rolling_data.select(pl.col("x").rolling_var(5).over("g"))
@@ -0,0 +1,40 @@
+"""Benchmark tests for the row encoding."""
+
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+
+pytestmark = pytest.mark.benchmark()
+
+
+@pytest.fixture(scope="module")
+def wide_struct() -> pl.Series:
+    num_fields = 256
+    num_rows = 100_000
+    df = pl.DataFrame(
+        {
+            f"f{i}": pl.int_range(num_rows, eager=True).cast(pl.String) + "x" * (i % 16)
+            for i in range(num_fields)
+        }
+    )
+    return df.to_struct("s")
+
+
+def test_row_encode_wide_struct(wide_struct: pl.Series) -> None:
+    wide_struct._row_encode()
+
+
+def test_sort_wide_struct(wide_struct: pl.Series) -> None:
+    wide_struct.sort()
+
+
+@pytest.fixture(scope="module")
+def int64_no_nulls() -> pl.Series:
+    return pl.int_range(-5_000_000, 5_000_000, eager=True).shuffle(seed=0)
+
+
+@pytest.mark.parametrize("descending", [False, True])
+def test_row_encode_int64_no_nulls(int64_no_nulls: pl.Series, descending: bool) -> None:
+    int64_no_nulls._row_encode(descending=descending)
@@ -4,7 +4,7 @@ from pathlib import Path
 import pytest
 
 import polars as pl
-from polars._utils.cloud import prepare_cloud_plan
+from polars._utils.cloud import prepare_cloud_plan, validate_cloud_plan
 from polars.exceptions import InvalidOperationError
 
 CLOUD_SOURCE = "s3://my-nonexistent-bucket/dataset"
@@ -104,3 +104,46 @@ def test_prepare_cloud_plan_fail_on_local_data_source(lf: pl.LazyFrame) -> None:
 def test_prepare_cloud_plan_succeed_on_local_data_source(lf: pl.LazyFrame) -> None:
     result = prepare_cloud_plan(lf, allow_local_scans=True)
     assert isinstance(result, bytes)
+
+
+def test_prepare_cloud_plan_max_inline_dataframe_bytes() -> None:
+    lf = pl.LazyFrame({"a": range(1000)}, schema={"a": pl.Int64}).sink_parquet(
+        DST, lazy=True
+    )
+
+    result = prepare_cloud_plan(
+        lf, allow_local_scans=False, max_inline_dataframe_bytes=8000
+    )
+    deserialized = pl.LazyFrame.deserialize(BytesIO(result))
+    assert isinstance(deserialized, pl.LazyFrame)
+
+    with pytest.raises(
+        InvalidOperationError,
+        match="contains an in-memory DataFrame of 8000 bytes, which exceeds the limit",
+    ):
+        prepare_cloud_plan(lf, allow_local_scans=False, max_inline_dataframe_bytes=7999)
+
+
+def test_validate_cloud_plan() -> None:
+    lf = pl.concat([pl.scan_parquet("data-1.parquet"), pl.scan_csv("data-2.csv")])
+    with pytest.deprecated_call():
+        lf = lf.with_context(pl.LazyFrame({"b": [1]}))
+    lf = lf.sink_parquet(DST, lazy=True)
+
+    violations = validate_cloud_plan(lf, allow_local_scans=False)
+    assert sorted(violations) == [
+        "contains Csv scan of local file system: [data-2.csv]\n"
+        "    at: sink > with_context > input > union > input 1 > scan",
+        "contains Parquet scan of local file system: [data-1.parquet]\n"
+        "    at: sink > with_context > input > union > input 0 > scan",
+        "contains with_context",
+    ]
+    assert validate_cloud_plan(lf, allow_local_scans=True) == ["contains with_context"]
+
+    with pytest.raises(InvalidOperationError) as exc:
+        prepare_cloud_plan(lf, allow_local_scans=False)
+    for violation in violations:
+        assert f"\n- {violation}" in str(exc.value)
+
+    lf = pl.scan_parquet(CLOUD_SOURCE).sink_parquet(DST, lazy=True)
+    assert validate_cloud_plan(lf, allow_local_scans=False) == []
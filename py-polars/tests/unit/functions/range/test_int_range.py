@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import Any
+from typing import TYPE_CHECKING, Any
 
 import pytest
 
@@ -13,6 +13,9 @@ from polars.exceptions import (
 )
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from polars._typing import PolarsIntegerType
+
 
 def test_int_range() -> None:
     result = pl.int_range(0, 3)
@@ -165,6 +168,87 @@ def test_int_ranges_schema_dtype_arg() -> None:
     assert result.collect().schema == expected_schema
 
 
+def test_int_ranges_dtype_uint8() -> None:
+    start = pl.Series("start", [0, 250, 10])
+    end = pl.Series("end", [3, 256, 0])
+    result = pl.int_ranges(start, end, dtype=pl.UInt8, eager=True)
+    expected = pl.Series(
+        "start",
+        [[0, 1, 2], [250, 251, 252, 253, 254, 255], []],
+        dtype=pl.List(pl.UInt8),
+    )
+    assert_series_equal(result, expected)
+
+    # The end does not need to fit, as long as all values of the range do.
+    result = pl.int_ranges(0, 300, 255, dtype=pl.UInt8, eager=True)
+    expected = pl.Series("literal", [[0, 255]], dtype=pl.List(pl.UInt8))
+    assert_series_equal(result, expected)
+
+
+def test_int_ranges_dtype_overflow() -> None:
+    with pytest.raises(
+        ComputeError,
+        match="range from 32760 to 32770 with step 1 cannot be represented as i16",
+    ):
+        pl.int_ranges(32760, 32770, dtype=pl.Int16, eager=True)
+
+
+def test_int_ranges_dtype_negative_step() -> None:
+    df = pl.DataFrame({"start": [5, 3, 0], "end": [0, -4, 1]})
+    result = df.select(
+        pl.int_ranges("start", "end", -2, dtype=pl.Int16),
+        pl.int_ranges("start", "end", -1, dtype=pl.Int8).alias("step_1"),
+    )
+    expected = pl.DataFrame(
+        {
+            "start": [[5, 3, 1], [3, 1, -1, -3], []],
+            "step_1": [[5, 4, 3, 2, 1], [3, 2, 1, 0, -1, -2, -3], []],
+        },
+        schema={"start": pl.List(pl.Int16), "step_1": pl.List(pl.Int8)},
+    )
+    assert_frame_equal(result, expected)
+
+    # A negative range cannot be represented as an unsigned integer.
+    with pytest.raises(ComputeError, match="cannot be represented as u16"):
+        pl.int_ranges(2, -2, -1, dtype=pl.UInt16, eager=True)
+
+
+def test_int_ranges_mixed_sign_steps() -> None:
+    df = pl.DataFrame(
+        {
+            "start": [0, 5, 0, 5, 3, None, 2],
+            "end": [5, 0, 5, 0, 3, 1, 8],
+            "step": [2, -2, -1, 1, -1, 0, None],
+        }
+    )
+    result = df.select(pl.int_ranges("start", "end", "step"))
+    expected = pl.DataFrame(
+        {"start": [[0, 2, 4], [5, 3, 1], [], [], [], None, None]},
+        schema={"start": pl.List(pl.Int64)},
+    )
+    assert_frame_equal(result, expected)
+
+    # Broadcast start and end with per-row steps.
+    result = df.head(5).select(r=pl.int_ranges(1, 4, "step"))
+    expected = pl.DataFrame(
+        {"r": [[1, 3], [], [], [1, 2, 3], []]},
+        schema={"r": pl.List(pl.Int64)},
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_int_ranges_zero_step() -> None:
+    df = pl.DataFrame({"start": [0, 1, 2], "step": [1, 1, 0]})
+    with pytest.raises(InvalidOperationError, match="zero step at row 2"):
+        df.select(pl.int_ranges("start", 10, "step"))
+
+    # A zero step is allowed if the range is null anyway.
+    df = df.with_columns(start=pl.Series([0, 1, None]))
+    result = df.select(pl.int_ranges("start", 3, "step"))
+    expected = pl.Series("start", [[0, 1, 2], [1, 2], None], pl.List(pl.Int64))
+    assert_series_equal(result.to_series(), expected)
+
+
 def test_int_range_input_shape_empty() -> None:
     empty = pl.Series(dtype=pl.Time)
     single = pl.Series([5])
@@ -201,6 +285,31 @@ def test_int_range_null_input() -> None:
         pl.select(pl.int_range(3, pl.lit(None), -1, dtype=pl.UInt32))
 
 
+@pytest.mark.parametrize(
+    ("start", "end"),
+    [(pl.lit(None), 3), (0, pl.lit(None)), (pl.lit(None), pl.lit(None))],
+)
+def test_int_range_null_input_propagate(start: Any, end: Any) -> None:
+    with pytest.raises(ComputeError, match="invalid null input for `int_range`"):
+        pl.select(pl.int_range(start, end, dtype=pl.UInt32))
+
+    result = pl.select(
+        pl.int_range(start, end, dtype=pl.UInt32, null_behavior="propagate")
+    )
+    assert result.schema == pl.Schema({"literal": pl.UInt32})
+    assert result.height == 0
+
+    lf = pl.LazyFrame({"a": [1, 2]})
+    q = lf.select(pl.int_range(start, end, null_behavior="propagate").alias("r"))
+    assert q.collect_schema() == pl.Schema({"r": pl.Int64})
+    assert_frame_equal(q.collect(), pl.DataFrame(schema={"r": pl.Int64}))
+
+
+def test_int_range_invalid_null_behavior() -> None:
+    with pytest.raises(ValueError, match="`null_behavior` must be one of"):
+        pl.int_range(0, 3, null_behavior="drop")  # type: ignore[call-overload]
+
+
 def test_int_range_invalid_conversion() -> None:
     with pytest.raises(
         InvalidOperationError, match="conversion from `i128` to `u32` failed"
@@ -215,6 +324,18 @@ def test_int_range_non_integer_dtype() -> None:
         pl.select(pl.int_range(3, -1, -1, dtype=pl.Float64))  # type: ignore[arg-type]
 
 
+def test_int_ranges_null_input() -> None:
+    df = pl.DataFrame(
+        {"start": [None, 1, None, 0], "end": [3, None, None, 2]},
+        schema={"start": pl.Int64, "end": pl.Int64},
+    )
+    q = df.lazy().select(pl.int_ranges("start", "end", dtype=pl.UInt8))
+    assert q.collect_schema() == pl.Schema({"start": pl.List(pl.UInt8)})
+
+    expected = pl.Series("start", [None, None, None, [0, 1]], dtype=pl.List(pl.UInt8))
+    assert_series_equal(q.collect().to_series(), expected)
+
+
 def test_int_ranges_broadcasting() -> None:
     df = pl.DataFrame({"int": [1, 2, 3]})
     result = df.select(
@@ -325,3 +446,68 @@ def test_int_range_len_count() -> None:
         q.collect().to_series(),
         pl.Series("r", [0, 1, 2, 3], pl.get_index_type()),
     )
+
+
+def test_int_range_array() -> None:
+    lf = pl.LazyFrame({"start": [0, 5, -3, None]})
+    q = lf.select(
+        pl.int_range_array("start", 3, 2),
+        scalar=pl.int_range_array(1, 2, dtype=pl.UInt8),
+    )
+    assert q.collect_schema() == pl.Schema(
+        {"start": pl.Array(pl.Int64, 3), "scalar": pl.Array(pl.UInt8, 2)}
+    )
+
+    expected = pl.DataFrame(
+        {
+            "start": [[0, 2, 4], [5, 7, 9], [-3, -1, 1], None],
+            "scalar": [[1, 2]] * 4,
+        },
+        schema={"start": pl.Array(pl.Int64, 3), "scalar": pl.Array(pl.UInt8, 2)},
+    )
+    assert_frame_equal(q.collect(), expected)
+
+
+@pytest.mark.parametrize("dtype", [pl.Int8, pl.UInt16, pl.Int64])
+@pytest.mark.parametrize("step", [1, 3, -1])
+def test_int_range_array_matches_int_ranges(
+    dtype: PolarsIntegerType, step: int
+) -> None:
+    df = pl.DataFrame({"start": pl.Series([10, None, 20, None], dtype=pl.Int16)})
+    result = df.select(pl.int_range_array("start", 4, step, dtype=dtype))
+
+    expected = df.select(
+        pl.int_ranges("start", pl.col("start") + 4 * step, step, dtype=dtype)
+    )
+    assert_frame_equal(result.select(pl.col("start").arr.to_list()), expected)
+
+    # Exploding gives a single null for a null start.
+    assert_series_equal(
+        result.select(pl.col("start").explode()).to_series(),
+        expected.select(pl.col("start").explode()).to_series(),
+    )
+    assert result.select(pl.col("start").explode()).height == 10
+
+
+def test_int_range_array_empty() -> None:
+    result = pl.int_range_array(pl.Series("a", [1, None]), 0, eager=True)
+    expected = pl.Series("a", [[], None], dtype=pl.Array(pl.Int64, 0))
+    assert_series_equal(result, expected)
+
+
+def test_int_range_array_out_of_bounds() -> None:
+    with pytest.raises(ComputeError, match="cannot be represented as u8"):
+        pl.int_range_array(250, 10, dtype=pl.UInt8, eager=True)
+
+    with pytest.raises(ComputeError, match="cannot be represented as i8"):
+        pl.int_range_array(0, 2, step=-200, dtype=pl.Int8, eager=True)
+
+
+def test_int_range_array_invalid_input() -> None:
+    with pytest.raises(
+        SchemaError, match="non-integer `dtype` passed to `int_range_array`: 'str'"
+    ):
+        pl.int_range_array(0, 3, dtype=pl.String, eager=True)  # type: ignore[arg-type]
+
+    with pytest.raises(ValueError, match="`length` must be non-negative"):
+        pl.int_range_array(0, -1)
@@ -107,3 +107,25 @@ def test_no_cse_in_with_context() -> None:
         ],
         "label": [0, 1, 1],
     }
+
+
+@pytest.mark.may_fail_cloud  # reason: with_context
+def test_with_context_plan_display() -> None:
+    lf = pl.LazyFrame({"a": [1, 2]})
+    context_1 = pl.LazyFrame({"b": [3, 4], "a": [5, 6]})
+    context_2 = pl.LazyFrame({"c": ["x", "y"], "d": [7.0, 8.0]})
+    with pytest.deprecated_call():
+        q = lf.with_context([context_1, context_2]).select(pl.all())
+
+    # Both contexts are drawn as inputs of the node, with the columns they add.
+    dot = q.show_graph(raw_output=True, optimized=False)
+    assert isinstance(dot, str)
+    assert '[label="context 0"]' in dot
+    assert '[label="context 1"]' in dot
+    assert "context 0: 1 column [b: i64]" in dot
+    assert "context 1: 2 columns [c: str, d: f64]" in dot
+    assert dot.count("TABLE") == 3
+
+    plan = q.explain(optimized=False)
+    assert "CONTEXT 0 ADDS 1 column [b: i64]:" in plan
+    assert "CONTEXT 1 ADDS 2 columns [c: str, d: f64]:" in plan
@@ -1,7 +1,9 @@
 from __future__ import annotations
 
 import datetime
+import operator
 from collections import OrderedDict
+from functools import reduce
 from typing import TYPE_CHECKING, Any
 
 import pytest
@@ -668,3 +670,104 @@ def test_raise_invalid_types_21835() -> None:
         match=r"cannot compare string with numeric type \(i64\)",
     ):
         df.select(pl.min_horizontal("x", "y"))
+
+
+@pytest.mark.parametrize("n_cols", [2, 7])
+@pytest.mark.parametrize(
+    "horizontal_func",
+    [pl.sum_horizontal, pl.mean_horizontal, pl.min_horizontal, pl.max_horizontal],
+)
+def test_horizontal_array(n_cols: int, horizontal_func: Any) -> None:
+    dtypes = [pl.Int32, pl.Float32, pl.Int64, pl.UInt8, pl.Int16, pl.Float32, pl.Int8]
+    df = pl.DataFrame(
+        [
+            pl.Series(
+                f"a{i}",
+                [
+                    [i + 1, None if i % 3 == 1 else 2 * i, 5 - i],
+                    [None, None if i > 0 else 3, i % 4],
+                    [7, i * i, None],
+                    [0, 1, 2],
+                ],
+                dtype=pl.Array(dtypes[i], 3),
+            )
+            for i in range(n_cols)
+        ]
+    )
+    columns = df.columns
+
+    result = df.select(horizontal_func(columns))
+
+    # Reference: the scalar horizontal function applied to every element position.
+    expected = df.select(
+        horizontal_func([pl.col(c).arr.explode().cast(pl.Float64) for c in columns])
+        .reshape((-1, 3))
+        .alias("a0")
+    )
+    assert result.schema == pl.Schema({"a0": pl.Array(pl.Float64, 3)})
+    assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("n_cols", [2, 7])
+def test_horizontal_array_sum_chained(n_cols: int) -> None:
+    dtypes = [pl.Int32, pl.Float32, pl.Int64, pl.UInt8, pl.Int16, pl.Float32, pl.Int8]
+    df = pl.DataFrame(
+        [
+            pl.Series(
+                f"a{i}",
+                [[i, i + 1, i + 2], [2 * i, 0, 1], [1, 1, 1]],
+                dtype=pl.Array(dtypes[i], 3),
+            )
+            for i in range(n_cols)
+        ]
+    )
+    expected = df.select(
+        reduce(
+            operator.add,
+            [pl.col(c).cast(pl.Array(pl.Float64, 3)) for c in df.columns],
+        ).alias("a0")
+    )
+    assert_frame_equal(df.select(pl.sum_horizontal(pl.all())), expected)
+    assert_frame_equal(
+        df.select(pl.mean_horizontal(pl.all())),
+        expected.select(pl.col("a0") / n_cols),
+    )
+
+
+def test_horizontal_array_nulls() -> None:
+    dtype = pl.Array(pl.Int64, 2)
+    df = pl.DataFrame(
+        [
+            pl.Series("a", [[1, None], None, None, [4, 5]], dtype=dtype),
+            pl.Series("b", [[2, None], [3, 3], None, None], dtype=pl.Array(pl.Int8, 2)),
+        ]
+    )
+
+    result = df.select(pl.sum_horizontal("a", "b"))
+    expected = pl.Series("a", [[3, 0], [3, 3], None, [4, 5]], dtype=dtype)
+    assert_series_equal(result.to_series(), expected)
+
+    result = df.select(pl.sum_horizontal("a", "b", ignore_nulls=False))
+    expected = pl.Series("a", [[3, None], None, None, None], dtype=dtype)
+    assert_series_equal(result.to_series(), expected)
+
+    result = df.select(pl.min_horizontal("a", "b"))
+    expected = pl.Series("a", [[1, None], [3, 3], None, [4, 5]], dtype=dtype)
+    assert_series_equal(result.to_series(), expected)
+
+    result = df.select(pl.mean_horizontal("a", "b"))
+    expected = pl.Series(
+        "a", [[1.5, None], [3.0, 3.0], None, [4.0, 5.0]], dtype=pl.Array(pl.Float64, 2)
+    )
+    assert_series_equal(result.to_series(), expected)
+
+
+def test_horizontal_array_width_mismatch() -> None:
+    df = pl.DataFrame(
+        [
+            pl.Series("a", [[1, 2]], dtype=pl.Array(pl.Int64, 2)),
+            pl.Series("b", [[1, 2, 3]], dtype=pl.Array(pl.Int64, 3)),
+        ]
+    )
+    with pytest.raises(PolarsError):
+        df.select(pl.sum_horizontal("a", "b"))
@@ -1,4 +1,7 @@
+import pytest
+
 import polars as pl
+from polars.exceptions import ShapeError
 from polars.testing import assert_frame_equal
 
 
@@ -28,3 +31,65 @@ def test_implode_explode_agg() -> None:
         .agg(pl.col.a.implode().explode().sum()),
         pl.DataFrame({"literal": [1], "a": [3]}),
     )
+
+
+def test_implode_to_array_group_by() -> None:
+    df = pl.DataFrame({"g": ["b", "a", "b", "a", "b", "a"], "x": [1, 2, 3, 4, 5, None]})
+
+    # The values keep their order within the group.
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col.x.implode_to_array(3),
+        y=pl.col.x.reverse().implode_to_array(3),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["b", "a"],
+            "x": [[1, 3, 5], [2, 4, None]],
+            "y": [[5, 3, 1], [None, 4, 2]],
+        },
+        schema={
+            "g": pl.String,
+            "x": pl.Array(pl.Int64, 3),
+            "y": pl.Array(pl.Int64, 3),
+        },
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.group_by("g").agg(pl.col.x.implode_to_array(3)).sort("g")
+    imploded = df.group_by("g").agg(pl.col.x.implode()).sort("g")
+    assert_frame_equal(out, imploded.with_columns(pl.col.x.list.to_array(3)))
+
+
+def test_implode_to_array_nested() -> None:
+    df = pl.DataFrame({"g": [1, 1, 2, 2], "x": [[1], [2, 3], None, []]})
+    out = df.group_by("g", maintain_order=True).agg(pl.col.x.implode_to_array(2))
+    assert out.schema["x"] == pl.Array(pl.List(pl.Int64), 2)
+    assert out["x"].to_list() == [[[1], [2, 3]], [None, []]]
+
+
+def test_implode_to_array_group_by_dynamic() -> None:
+    df = pl.DataFrame({"t": [0, 1, 2, 3, 4, 5], "x": [1, 2, 3, 4, 5, 6]})
+    out = df.with_columns(pl.col.t.set_sorted()).group_by_dynamic("t", every="2i")
+    assert out.agg(pl.col.x.implode_to_array(2))["x"].to_list() == [
+        [1, 2],
+        [3, 4],
+        [5, 6],
+    ]
+
+
+def test_implode_to_array_select() -> None:
+    df = pl.DataFrame({"x": [1, None, 3]})
+    out = df.select(pl.col.x.implode_to_array(3))
+    assert out.schema["x"] == pl.Array(pl.Int64, 3)
+    assert out["x"].to_list() == [[1, None, 3]]
+
+    with pytest.raises(ShapeError, match="into an array of width 2"):
+        df.select(pl.col.x.implode_to_array(2))
+
+
+def test_implode_to_array_length_mismatch() -> None:
+    df = pl.DataFrame({"g": [1, 1, 2, 3, 3], "x": [1, 2, 3, 4, 5]})
+    with pytest.raises(
+        ShapeError, match="of 1 values at row 2 into an array of width 2"
+    ):
+        df.group_by("g", maintain_order=True).agg(pl.col.x.implode_to_array(2))
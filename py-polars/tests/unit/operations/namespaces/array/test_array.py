@@ -6,7 +6,11 @@ from typing import Any
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError, InvalidOperationError
+from polars.exceptions import (
+    ComputeError,
+    InvalidOperationError,
+    OutOfBoundsError,
+)
 from polars.testing import assert_frame_equal, assert_series_equal
 
 
@@ -77,6 +81,185 @@ def test_arr_sum(
     assert s.arr.sum().to_list() == expected_sum
 
 
+@pytest.mark.parametrize("dtype", [pl.Int32, pl.UInt8, pl.Float32, pl.Float64])
+def test_arr_aggregations_with_nulls(dtype: pl.DataType) -> None:
+    s = pl.Series(
+        "a",
+        [[1, None, 3], None, [None, None, None], [4, 5, 6]],
+        dtype=pl.Array(dtype, 3),
+    )
+    assert s.arr.sum().to_list() == [4, None, 0, 15]
+    assert s.arr.mean().to_list() == [2.0, None, None, 5.0]
+    assert s.arr.min().to_list() == [1, None, None, 4]
+    assert s.arr.max().to_list() == [3, None, None, 6]
+
+    lf = s.to_frame().lazy()
+    q = lf.select(
+        sum=pl.col("a").arr.sum(),
+        mean=pl.col("a").arr.mean(),
+        min=pl.col("a").arr.min(),
+        max=pl.col("a").arr.max(),
+    )
+    mean_dtype = pl.Float32 if dtype == pl.Float32 else pl.Float64
+    assert q.collect_schema()["mean"] == mean_dtype
+    assert q.collect_schema() == q.collect().schema
+
+
+def test_arr_aggregations_width_zero() -> None:
+    s = pl.Series("a", [[], None], dtype=pl.Array(pl.Int64, 0))
+    assert s.arr.sum().to_list() == [0, None]
+    assert s.arr.mean().to_list() == [None, None]
+    assert s.arr.min().to_list() == [None, None]
+    assert s.arr.max().to_list() == [None, None]
+
+
+def _leaf_values(value: Any) -> list[Any] | None:
+    # Reduce level by level: null arrays and null values contribute nothing.
+    if value is None:
+        return None
+    if not isinstance(value, list):
+        return [value]
+    return [v for inner in value for v in _leaf_values(inner) or []]
+
+
+@pytest.mark.parametrize(
+    ("values", "dtype"),
+    [
+        (
+            [[[1, None], [3, 4], None], None, [None, None, [None, None]], [[7, 8]] * 3],
+            pl.Array(pl.Array(pl.Int32, 2), 3),
+        ),
+        (
+            [
+                [[[1.5, None], None], [[3.0, -4.0], [5.0, 6.0]]],
+                None,
+                [None, [None, [None, 2.5]]],
+                [[None, None], [None, None]],
+            ],
+            pl.Array(pl.Array(pl.Array(pl.Float64, 2), 2), 2),
+        ),
+    ],
+)
+def test_arr_leaf_aggregations(values: list[Any], dtype: pl.DataType) -> None:
+    s = pl.Series("a", values, dtype=dtype)
+    leaves = [_leaf_values(v) for v in values]
+
+    assert s.arr.leaf_sum().to_list() == [
+        None if v is None else sum(v) for v in leaves
+    ]
+    assert s.arr.leaf_mean().to_list() == [
+        sum(v) / len(v) if v else None for v in leaves
+    ]
+    assert s.arr.leaf_min().to_list() == [min(v) if v else None for v in leaves]
+    assert s.arr.leaf_max().to_list() == [max(v) if v else None for v in leaves]
+
+    lf = s.to_frame().lazy()
+    q = lf.select(
+        sum=pl.col("a").arr.leaf_sum(),
+        mean=pl.col("a").arr.leaf_mean(),
+        min=pl.col("a").arr.leaf_min(),
+        max=pl.col("a").arr.leaf_max(),
+    )
+    leaf_dtype: Any = dtype
+    while isinstance(leaf_dtype, pl.Array):
+        leaf_dtype = leaf_dtype.inner
+    assert q.collect_schema() == {
+        "sum": leaf_dtype,
+        "mean": pl.Float64,
+        "min": leaf_dtype,
+        "max": leaf_dtype,
+    }
+    assert q.collect_schema() == q.collect().schema
+
+
+def test_arr_leaf_aggregations_sliced() -> None:
+    s = pl.Series(
+        "a",
+        [[[1, 2], None], [[3, None], [5, 6]], [[7, 8], [9, 10]]],
+        dtype=pl.Array(pl.Array(pl.Int8, 2), 2),
+    )[1:]
+    assert s.arr.leaf_sum().to_list() == [14, 34]
+    assert s.arr.leaf_sum().dtype == pl.Int64
+    assert s.arr.leaf_max().to_list() == [6, 10]
+
+
+def test_arr_leaf_aggregations_invalid_dtype() -> None:
+    s = pl.Series("a", [[["x"]]], dtype=pl.Array(pl.Array(pl.String, 1), 1))
+    with pytest.raises(InvalidOperationError):
+        s.to_frame().lazy().select(pl.col("a").arr.leaf_sum()).collect_schema()
+
+
+def _cum_reference(s: pl.Series, op: str, *, reverse: bool) -> pl.Series:
+    # Run the cumulative aggregation over the exploded elements of every row.
+    width = s.dtype.size  # type: ignore[attr-defined]
+    return (
+        s.to_frame()
+        .with_row_index()
+        .filter(pl.col("a").is_not_null())
+        .explode("a")
+        .select("index", getattr(pl.col("a"), op)(reverse=reverse).over("index"))
+        .group_by("index", maintain_order=True)
+        .agg("a")
+        .get_column("a")
+        .list.to_array(width)
+    )
+
+
+@pytest.mark.parametrize("op", ["cum_sum", "cum_prod", "cum_min", "cum_max"])
+@pytest.mark.parametrize("reverse", [False, True])
+@pytest.mark.parametrize(
+    ("values", "inner"),
+    [
+        ([[100, None, 100], None, [3, 7, 2], [None, None, None]], pl.Int8),
+        ([[100, None, 100], None, [3, 7, 2], [None, None, None]], pl.Int32),
+        ([[100, None, 100], None, [3, 7, 2], [None, None, None]], pl.UInt16),
+        ([[1.5, None, -2.0], None, [3.0, 7.0, 2.0], [None, None, None]], pl.Float64),
+        ([[True, None, False], None, [False, True, True], [None] * 3], pl.Boolean),
+    ],
+)
+def test_arr_cumulative_aggregations(
+    values: list[Any], inner: pl.DataType, op: str, reverse: bool
+) -> None:
+    s = pl.Series("a", values, dtype=pl.Array(inner, 3))
+    result = getattr(s.arr, op)(reverse=reverse)
+    expected = _cum_reference(s, op, reverse=reverse)
+
+    assert_series_equal(result.is_null(), s.is_null())
+    assert_series_equal(result.drop_nulls(), expected, check_names=False)
+
+    q = s.to_frame().lazy().select(getattr(pl.col("a").arr, op)(reverse=reverse))
+    assert q.collect_schema() == {"a": expected.dtype}
+    assert_series_equal(q.collect().to_series(), result)
+
+
+def test_arr_cumulative_aggregations_promotion() -> None:
+    s = pl.Series("a", [[100, 100, 100]], dtype=pl.Array(pl.Int8, 3))
+    assert s.arr.cum_sum().to_list() == [[100, 200, 300]]
+    assert s.arr.cum_sum().dtype == pl.Array(pl.Int64, 3)
+    assert s.arr.cum_max().dtype == pl.Array(pl.Int8, 3)
+
+    s = pl.Series("a", [[1000, 1000, 1000]], dtype=pl.Array(pl.Int32, 3))
+    assert s.arr.cum_prod().to_list() == [[1000, 1000000, 1000000000]]
+    assert s.arr.cum_prod().dtype == pl.Array(pl.Int64, 3)
+
+    s = pl.Series("a", [[True, False, True]], dtype=pl.Array(pl.Boolean, 3))
+    assert s.arr.cum_sum().to_list() == [[1, 1, 2]]
+    assert s.arr.cum_sum().dtype == pl.Array(pl.UInt32, 3)
+
+
+def test_arr_cumulative_aggregations_sliced() -> None:
+    s = pl.Series("a", [[1, 2], [3, None], [5, 6]], dtype=pl.Array(pl.Int64, 2))[1:]
+    assert s.arr.cum_sum().to_list() == [[3, None], [5, 11]]
+    assert s.arr.cum_sum(reverse=True).to_list() == [[3, None], [11, 6]]
+    assert s.arr.cum_min(reverse=True).to_list() == [[3, None], [5, 6]]
+
+
+def test_arr_cumulative_aggregations_invalid_dtype() -> None:
+    s = pl.Series("a", [["x", "y"]], dtype=pl.Array(pl.String, 2))
+    with pytest.raises(InvalidOperationError):
+        s.to_frame().lazy().select(pl.col("a").arr.cum_sum()).collect_schema()
+
+
 @pytest.mark.may_fail_cloud
 def test_array_lengths_zwa() -> None:
     assert pl.Series("a", [[], []], pl.Array(pl.Null, 0)).arr.len().to_list() == [0, 0]
@@ -329,6 +512,158 @@ def test_array_get_null_on_oob() -> None:
     assert_series_equal(out, expected)
 
 
+def test_array_scatter() -> None:
+    s = pl.Series(
+        "a",
+        [[1, 2, 3], [4, 5, 6], None, [7, None, 9]],
+        dtype=pl.Array(pl.Int64, 3),
+    )
+
+    # Index and value literals.
+    out = s.arr.scatter(-1, 0)
+    expected = pl.Series(
+        "a",
+        [[1, 2, 0], [4, 5, 0], None, [7, None, 0]],
+        dtype=pl.Array(pl.Int64, 3),
+    )
+    assert_series_equal(out, expected)
+
+    # Index and value per row, including null indices and null values.
+    out = s.arr.scatter(pl.Series([0, None, 1, 1]), pl.Series([10, 20, 30, None]))
+    expected = pl.Series(
+        "a",
+        [[10, 2, 3], [4, 5, 6], None, [7, None, 9]],
+        dtype=pl.Array(pl.Int64, 3),
+    )
+    assert_series_equal(out, expected)
+
+    out = s.arr.scatter(pl.Series([2, 0, 0, 1]), pl.Series([None, 20, 30, 8]))
+    expected = pl.Series(
+        "a",
+        [[1, 2, None], [20, 5, 6], None, [7, 8, 9]],
+        dtype=pl.Array(pl.Int64, 3),
+    )
+    assert_series_equal(out, expected)
+
+    # The input is left untouched.
+    assert s.to_list() == [[1, 2, 3], [4, 5, 6], None, [7, None, 9]]
+
+
+def test_array_scatter_out_of_bounds() -> None:
+    s = pl.Series("a", [[1, 2], [3, 4]], dtype=pl.Array(pl.Int64, 2))
+
+    with pytest.raises(OutOfBoundsError, match="out of bounds"):
+        s.arr.scatter(pl.Series([0, 2]), 0)
+    with pytest.raises(OutOfBoundsError, match="out of bounds"):
+        s.arr.scatter(-3, 0)
+
+    out = s.arr.scatter(pl.Series([0, 2]), 0, skip_oob=True)
+    expected = pl.Series("a", [[0, 2], [3, 4]], dtype=pl.Array(pl.Int64, 2))
+    assert_series_equal(out, expected)
+
+
+def test_array_scatter_dtypes() -> None:
+    # The inner dtype is promoted to fit the value.
+    s = pl.Series("a", [[1, 2], [3, 4]], dtype=pl.Array(pl.Int8, 2))
+    out = s.arr.scatter(0, 300)
+    assert out.dtype == pl.Array(pl.Int16, 2)
+    assert out.to_list() == [[300, 2], [300, 4]]
+
+    out = s.arr.scatter(1, 1.5)
+    assert out.dtype == pl.Array(pl.Float64, 2)
+    assert out.to_list() == [[1.0, 1.5], [3.0, 1.5]]
+
+    s = pl.Series("a", [["x", "y"], ["z", None]], dtype=pl.Array(pl.String, 2))
+    out = s.arr.scatter(pl.Series([1, 0]), "w")
+    assert out.to_list() == [["x", "w"], ["w", None]]
+
+    s = pl.Series(
+        "a",
+        [[datetime.date(2000, 1, 1), None], [None, datetime.date(2001, 1, 1)]],
+        dtype=pl.Array(pl.Date, 2),
+    )
+    out = s.arr.scatter(-1, datetime.date(1999, 1, 1))
+    expected = pl.Series(
+        "a",
+        [
+            [datetime.date(2000, 1, 1), datetime.date(1999, 1, 1)],
+            [None, datetime.date(1999, 1, 1)],
+        ],
+        dtype=pl.Array(pl.Date, 2),
+    )
+    assert_series_equal(out, expected)
+
+    s = pl.Series("a", [[[1], [2]], [[3], None]], dtype=pl.Array(pl.List(pl.Int64), 2))
+    out = s.arr.scatter(1, pl.Series([[5, 6], [7]]))
+    assert out.to_list() == [[[1], [5, 6]], [[3], [7]]]
+
+
+def test_array_scatter_expr() -> None:
+    df = pl.DataFrame(
+        {"arr": [[1, 2, 3], [4, 5, 6]], "idx": [0, 2], "value": [-1, -2]},
+        schema={"arr": pl.Array(pl.Int64, 3), "idx": pl.UInt32, "value": pl.Int64},
+    )
+    out = df.select(pl.col("arr").arr.scatter("idx", pl.col("value")))
+    expected = pl.DataFrame(
+        {"arr": [[-1, 2, 3], [4, 5, -2]]},
+        schema={"arr": pl.Array(pl.Int64, 3)},
+    )
+    assert_frame_equal(out, expected)
+    lf = df.lazy().select(pl.col("arr").arr.scatter("idx", 0.5))
+    assert lf.collect_schema() == {"arr": pl.Array(pl.Float64, 3)}
+
+    with pytest.raises(InvalidOperationError):
+        df.select(pl.col("arr").arr.scatter(pl.lit("a"), 0))
+
+
+def test_array_set_at_idx_masked() -> None:
+    s = pl.Series(
+        "a",
+        [[1, 2, 3], [4, 5, 6], [7, 8, 9], None],
+        dtype=pl.Array(pl.Int64, 3),
+    )
+    mask = pl.Series(
+        [[True, False, True], None, [False, None, True], [True, True, True]],
+        dtype=pl.Array(pl.Boolean, 3),
+    )
+
+    out = s.arr.set_at_idx_masked(mask, 0)
+    expected = pl.Series(
+        "a",
+        [[0, 2, 0], [4, 5, 6], [7, 8, 0], None],
+        dtype=pl.Array(pl.Int64, 3),
+    )
+    assert_series_equal(out, expected)
+
+    out = s.arr.set_at_idx_masked(mask, pl.Series([None, 1, 2, 3]))
+    expected = pl.Series(
+        "a",
+        [[None, 2, None], [4, 5, 6], [7, 8, 2], None],
+        dtype=pl.Array(pl.Int64, 3),
+    )
+    assert_series_equal(out, expected)
+
+    # A single mask is applied to every row.
+    out = s.arr.set_at_idx_masked(
+        pl.Series([[False, True, False]], dtype=pl.Array(pl.Boolean, 3)), 0
+    )
+    expected = pl.Series(
+        "a",
+        [[1, 0, 3], [4, 0, 6], [7, 0, 9], None],
+        dtype=pl.Array(pl.Int64, 3),
+    )
+    assert_series_equal(out, expected)
+
+    with pytest.raises(InvalidOperationError):
+        s.arr.set_at_idx_masked(
+            pl.Series([[True, False]] * 4, dtype=pl.Array(pl.Boolean, 2)), 0
+        )
+    with pytest.raises(InvalidOperationError):
+        s.arr.set_at_idx_masked(
+            pl.Series([[1, 0, 1]] * 4, dtype=pl.Array(pl.Int64, 3)), 0
+        )
+
+
 def test_arr_first_last() -> None:
     s = pl.Series(
         "a",
@@ -672,6 +1007,7 @@ def test_arr_contains() -> None:
         pl.col("a").arr.min(),
         pl.col("a").arr.n_unique(),
         pl.col("a").arr.reverse(),
+        pl.col("a").arr.cast_inner(pl.Float64),
         pl.col("a").arr.shift(1),
         pl.col("a").arr.sort(),
         pl.col("a").arr.std(),
@@ -686,3 +1022,52 @@ def test_schema_non_array(expr: pl.Expr) -> None:
         match="expected Array datatype for array operation, got: String",
     ):
         lf.select(expr).collect_schema()
+
+
+def test_arr_cast_inner() -> None:
+    s = pl.Series("a", [[1, 2], None, [3, 300]], dtype=pl.Array(pl.Int64, 2))
+
+    out = s.arr.cast_inner(pl.Float64)
+    assert out.dtype == pl.Array(pl.Float64, 2)
+    assert out.to_list() == [[1.0, 2.0], None, [3.0, 300.0]]
+
+    with pytest.raises(InvalidOperationError, match="first failure is in row 2"):
+        s.arr.cast_inner(pl.UInt8)
+
+    out = s.arr.cast_inner(pl.UInt8, strict=False)
+    assert out.to_list() == [[1, 2], None, [3, None]]
+
+    out = s.arr.cast_inner(pl.UInt8, wrap_numerical=True)
+    assert out.to_list() == [[1, 2], None, [3, 44]]
+
+
+def test_arr_cast_inner_temporal() -> None:
+    s = pl.Series("a", [[0, 1_000]], dtype=pl.Array(pl.Int64, 2))
+    out = s.arr.cast_inner(pl.Datetime("ms", "UTC"))
+    assert out.dtype == pl.Array(pl.Datetime("ms", "UTC"), 2)
+    assert out.to_list() == [
+        [
+            datetime.datetime(1970, 1, 1, tzinfo=datetime.timezone.utc),
+            datetime.datetime(1970, 1, 1, 0, 0, 1, tzinfo=datetime.timezone.utc),
+        ]
+    ]
+
+
+def test_arr_cast_inner_nested() -> None:
+    s = pl.Series("a", [[[1, 2], [3, 4]]], dtype=pl.Array(pl.Array(pl.Int64, 2), 2))
+    out = s.arr.cast_inner(pl.Array(pl.Float32, 2))
+    assert out.dtype == pl.Array(pl.Array(pl.Float32, 2), 2)
+    assert out.to_list() == [[[1.0, 2.0], [3.0, 4.0]]]
+
+    with pytest.raises(InvalidOperationError, match="nested inner dtype"):
+        s.arr.cast_inner(pl.Int64)
+
+
+def test_arr_cast_inner_schema() -> None:
+    lf = pl.LazyFrame({"a": [[1, 2]]}, schema={"a": pl.Array(pl.Int32, 2)})
+    q = lf.select(pl.col("a").arr.cast_inner(pl.String))
+    assert q.collect_schema() == {"a": pl.Array(pl.String, 2)}
+    assert_frame_equal(
+        q.collect(),
+        pl.DataFrame({"a": [["1", "2"]]}, schema={"a": pl.Array(pl.String, 2)}),
+    )
@@ -1,12 +1,13 @@
 from __future__ import annotations
 
+import itertools
 from typing import Any
 
 import pytest
 
 import polars as pl
 from polars.exceptions import InvalidOperationError
-from polars.testing import assert_series_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 @pytest.mark.parametrize(
@@ -74,3 +75,82 @@ def test_array_contains_invalid_datatype() -> None:
         match=r"expected Array datatype for array operation, got: List\(Int8\)",
     ):
         df.select(pl.col("a").arr.contains(2))
+
+
+@pytest.mark.parametrize("descending", [False, True])
+@pytest.mark.parametrize("dtype", [pl.Int8, pl.UInt32, pl.Int64, pl.Float64])
+def test_array_contains_sorted_rows(descending: bool, dtype: pl.DataType) -> None:
+    rows = [[1, 1, 3, 5], [2, 4, 4, 4], [0, 1, 2, 3], None, [1, None, 3, 5]]
+    rows = [
+        sorted(r, reverse=descending) if r is not None and None not in r else r
+        for r in rows
+    ]
+    df = pl.DataFrame(
+        {"a": rows, "item": [1, 4, None, 2, 3]},
+        schema={"a": pl.Array(dtype, 4), "item": dtype},
+    )
+
+    for item in [pl.col("item"), pl.lit(1), pl.lit(4), pl.lit(6), pl.lit(None)]:
+        for nulls_equal, validate in itertools.product([False, True], repeat=2):
+            expected = df.select(
+                pl.col("a").arr.contains(item, nulls_equal=nulls_equal)
+            )
+            result = df.select(
+                pl.col("a").arr.contains(
+                    item,
+                    nulls_equal=nulls_equal,
+                    assume_sorted_rows=True,
+                    descending=descending,
+                    validate=validate,
+                )
+            )
+            assert_frame_equal(result, expected)
+
+    for element in [1, 4, 6, None]:
+        expected = df.select(pl.col("a").arr.count_matches(element))
+        result = df.select(
+            pl.col("a").arr.count_matches(
+                element, assume_sorted_rows=True, descending=descending
+            )
+        )
+        assert_frame_equal(result, expected)
+
+def test_array_contains_sorted_rows_validate() -> None:
+    s = pl.Series("a", [[3, 1, 2], [1, 2, 3], [2, 2, 1]], dtype=pl.Array(pl.Int64, 3))
+
+    result = s.arr.contains(2, assume_sorted_rows=True, validate=True)
+    assert_series_equal(result, pl.Series("a", [True, True, True]))
+    result = s.arr.count_matches(2, assume_sorted_rows=True, validate=True)
+    assert_series_equal(result, pl.Series("a", [1, 1, 2], dtype=pl.get_index_type()))
+
+
+def test_array_contains_sorted_rows_fallback() -> None:
+    s = pl.Series("a", [["a", "b"], ["c", "d"]], dtype=pl.Array(pl.String, 2))
+    result = s.arr.contains("c", assume_sorted_rows=True)
+    assert_series_equal(result, pl.Series("a", [False, True]))
+
+    s = pl.Series("a", [[1, 2], [3, 300]], dtype=pl.Array(pl.UInt8, 2))
+    result = s.arr.contains(300, assume_sorted_rows=True)
+    assert_series_equal(result, s.arr.contains(300))
+
+
+@pytest.mark.parametrize("dtype", [pl.Int32, pl.Float32])
+def test_array_rows_are_sorted(dtype: pl.DataType) -> None:
+    s = pl.Series(
+        "a",
+        [[1, 2, 2], [3, 2, 1], [1, None, 3], None, [1, 1, 1]],
+        dtype=pl.Array(dtype, 3),
+    )
+    assert_series_equal(
+        s.arr.rows_are_sorted(), pl.Series("a", [True, False, False, None, True])
+    )
+    assert_series_equal(
+        s.arr.rows_are_sorted(descending=True),
+        pl.Series("a", [False, True, False, None, True]),
+    )
+
+
+def test_array_rows_are_sorted_invalid_dtype() -> None:
+    s = pl.Series("a", [["a", "b"]], dtype=pl.Array(pl.String, 2))
+    with pytest.raises(InvalidOperationError):
+        s.arr.rows_are_sorted()
@@ -13,6 +13,7 @@ from polars.exceptions import (
     ComputeError,
     InvalidOperationError,
     OutOfBoundsError,
+    ShapeError,
 )
 from polars.testing import assert_frame_equal, assert_series_equal
 from tests.unit.conftest import time_func
@@ -847,6 +848,64 @@ def test_list_to_array_wrong_dtype() -> None:
         s.list.to_array(2)
 
 
+@pytest.mark.parametrize("width", [0, 2, 5])
+@pytest.mark.parametrize("side", ["start", "end"])
+@pytest.mark.parametrize("truncate_side", ["start", "end"])
+@pytest.mark.parametrize("fill_value", [None, 0, 2.5])
+def test_list_pad_to_array(
+    width: int, side: Any, truncate_side: Any, fill_value: Any
+) -> None:
+    df = pl.DataFrame({"a": [[1, 2, 3], [4], [], None, [5, None, 6, 7, 8, 9]]})
+
+    a = pl.col("a")
+    kept = a.list.tail(width) if truncate_side == "start" else a.list.head(width)
+    pad = pl.int_ranges(width - kept.list.len()).list.eval(
+        pl.lit(fill_value) + pl.element() * 0
+    )
+    parts = [pad, kept] if side == "start" else [kept, pad]
+    expected = df.select(a=pl.concat_list(parts).list.to_array(width))
+
+    result = df.select(
+        a.list.pad_to_array(
+            width, side=side, fill_value=fill_value, truncate_side=truncate_side
+        )
+    )
+    assert_frame_equal(result, expected)
+    assert result.schema == df.lazy().select(
+        a.list.pad_to_array(width, fill_value=fill_value)
+    ).collect_schema()
+
+
+def test_list_pad_to_array_dtype() -> None:
+    s = pl.Series([[1, None], [2, 3, 4]], dtype=pl.List(pl.Int8))
+
+    # An integer literal that fits must not widen the values.
+    result = s.list.pad_to_array(3, fill_value=0)
+    expected = pl.Series([[0, 1, None], [2, 3, 4]], dtype=pl.Array(pl.Int8, 3))
+    assert_series_equal(result, expected)
+
+    result = s.list.pad_to_array(3, side="end", fill_value=pl.lit(1, pl.Int64))
+    expected = pl.Series([[1, None, 1], [2, 3, 4]], dtype=pl.Array(pl.Int64, 3))
+    assert_series_equal(result, expected)
+
+    s = pl.Series([["a"], ["b", "c"]])
+    result = s.list.pad_to_array(2, fill_value="-")
+    expected = pl.Series([["-", "a"], ["b", "c"]], dtype=pl.Array(pl.String, 2))
+    assert_series_equal(result, expected)
+
+
+def test_list_pad_to_array_invalid() -> None:
+    df = pl.DataFrame({"a": [[1], [2, 3]], "b": [0, 1]})
+    with pytest.raises(ShapeError, match="'fill' must be a scalar value"):
+        df.select(pl.col("a").list.pad_to_array(2, fill_value=pl.col("b")))
+    with pytest.raises(ValueError, match="pad side must be one of"):
+        df.select(pl.col("a").list.pad_to_array(2, side="left"))  # type: ignore[arg-type]
+    with pytest.raises(
+        InvalidOperationError, match="expected List data type for list operation"
+    ):
+        df.select(pl.col("b").list.pad_to_array(2))
+
+
 def test_list_lengths() -> None:
     s = pl.Series([[1, 2, None], [5]])
     result = s.list.len()
@@ -2044,6 +2044,24 @@ def test_rolling_rank_method_random(
     )
 
 
+@pytest.mark.parametrize("method", ["average", "min", "max", "dense"])
+@pytest.mark.parametrize("center", [False, True])
+def test_rolling_rank_descending(method: RankMethod, center: bool) -> None:
+    s = pl.Series("a", [3, 1, None, 4, 1, 5, 9, 2, 6, 5, 3, 5])
+
+    # Ranking descending is ranking the negated values ascending.
+    result = s.rolling_rank(
+        4, method=method, min_samples=2, center=center, descending=True
+    )
+    expected = (-s).rolling_rank(4, method=method, min_samples=2, center=center)
+    assert_series_equal(result, expected)
+
+    by = pl.Series("by", range(len(s)))
+    result = s.rolling_rank_by(by, "3i", method=method, descending=True)
+    expected = (-s).rolling_rank_by(by, "3i", method=method)
+    assert_series_equal(result, expected)
+
+
 @pytest.mark.parametrize("op", [pl.Expr.rolling_mean, pl.Expr.rolling_median])
 def test_rolling_mean_median_temporals(op: Callable[..., pl.Expr]) -> None:
     tz = ZoneInfo("Asia/Tokyo")
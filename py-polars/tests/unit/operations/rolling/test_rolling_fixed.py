@@ -1,7 +1,129 @@
+from __future__ import annotations
+
+import random
+
+import pytest
+
 import polars as pl
+from polars.exceptions import ComputeError
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 def test_rolling_var_stability_12905() -> None:
     s1 = pl.Series("a", [36743.6 for _ in range(10)])
     assert s1.rolling_var(window_size=12, min_samples=2).sum() == 0.0
     assert s1.rolling_std(window_size=12, min_samples=2).sum() == 0.0
+
+
+def rolling_exprs(
+    column: str, window_size: int, min_samples: int | None, center: bool
+) -> list[pl.Expr]:
+    options = {"window_size": window_size, "min_samples": min_samples, "center": center}
+    x = pl.col(column)
+    return [
+        x.rolling_min(**options).alias("min"),
+        x.rolling_max(**options).alias("max"),
+        x.rolling_sum(**options).alias("sum"),
+        x.rolling_mean(**options).alias("mean"),
+        x.rolling_var(**options).alias("var"),
+        x.rolling_std(**options, ddof=0).alias("std"),
+        x.rolling_quantile(0.3, "linear", **options).alias("quantile"),
+    ]
+
+
+@pytest.mark.parametrize("column", ["x", "y"])
+@pytest.mark.parametrize("window_size", [1, 2, 3, 5])
+@pytest.mark.parametrize("min_samples", [None, 1])
+@pytest.mark.parametrize("center", [False, True])
+def test_rolling_over_matches_per_group(
+    column: str, window_size: int, min_samples: int | None, center: bool
+) -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 2, 1, 1, 3, 2, 1, 2, 2, 1, 3, 1, 4],
+            "x": [1.0, None, 3.0, 4.0, 5.0, 6.0, None, 8.0, 9.0, 1.0, 2.0, 2.5, 7.0],
+            "y": [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, None],
+        }
+    ).with_row_index()
+    exprs = rolling_exprs(column, window_size, min_samples, center)
+
+    expected = (
+        pl.concat([part.select("index", *exprs) for part in df.partition_by("g")])
+        .sort("index")
+        .drop("index")
+    )
+    assert_frame_equal(df.select(e.over("g") for e in exprs), expected)
+
+    expected = pl.concat(
+        [
+            part.select(pl.first("g"), *(e.implode() for e in exprs))
+            for part in df.partition_by("g", maintain_order=True)
+        ]
+    )
+    result = df.group_by("g", maintain_order=True).agg(exprs)
+    assert_frame_equal(result, expected)
+
+
+def test_rolling_over_fallback_dtype() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 2, 1, 2, 1],
+            "x": [True, False, None, True, True],
+        }
+    )
+    result = df.select(pl.col("x").rolling_sum(2, min_samples=1).over("g"))
+    expected = pl.DataFrame({"x": [1, 0, 1, 1, 1]}, schema={"x": pl.get_index_type()})
+    assert_frame_equal(result, expected)
+
+
+def rolling_var_halflife_reference(
+    values: list[float | None],
+    window_size: int,
+    min_samples: int,
+    halflife: float,
+    ddof: int,
+) -> list[float | None]:
+    out: list[float | None] = []
+    for i in range(len(values)):
+        window = values[max(0, i + 1 - window_size) : i + 1]
+        points = [
+            (x, 0.5 ** ((len(window) - 1 - j) / halflife))
+            for j, x in enumerate(window)
+            if x is not None
+        ]
+        if len(points) < min_samples:
+            out.append(None)
+            continue
+        total = sum(w for _, w in points)
+        mean = sum(w * x for x, w in points) / total
+        denom = total - ddof * sum(w * w for _, w in points) / total
+        dp = sum(w * (x - mean) ** 2 for x, w in points)
+        out.append(dp / denom if denom > 0 else None)
+    return out
+
+
+@pytest.mark.parametrize("halflife", [0.5, 2.0])
+@pytest.mark.parametrize("ddof", [0, 1])
+@pytest.mark.parametrize("with_nulls", [False, True])
+def test_rolling_var_halflife(halflife: float, ddof: int, with_nulls: bool) -> None:
+    rng = random.Random(0)
+    values: list[float | None] = [rng.uniform(-10, 10) for _ in range(50)]
+    if with_nulls:
+        values = [None if rng.random() < 0.2 else x for x in values]
+
+    s = pl.Series("a", values, dtype=pl.Float64)
+    result = s.rolling_var(4, min_samples=2, ddof=ddof, halflife=halflife)
+    expected = pl.Series(
+        "a",
+        rolling_var_halflife_reference(values, 4, 2, halflife, ddof),
+        dtype=pl.Float64,
+    )
+    assert_series_equal(result, expected)
+
+
+def test_rolling_var_halflife_invalid() -> None:
+    s = pl.Series([1.0, None, 3.0])
+    with pytest.raises(ValueError, match="halflife"):
+        s.rolling_var(2, weights=[1.0, 1.0], halflife=1.0)
+    with pytest.raises(ComputeError, match="halflife"):
+        s.rolling_var(2, halflife=0.0)
@@ -286,6 +286,31 @@ def test_filter(dtype: PolarsDataType, size: int, selectivity: float) -> None:
     assert_series_equal(reference, result)
 
 
+@pytest.mark.parametrize("width", [0, 1, 3, 128])
+@pytest.mark.parametrize("selectivity", [0.01, 0.5, 0.99])
+def test_filter_array(width: int, selectivity: float) -> None:
+    rng = np.random.default_rng(width * 100 + int(100 * selectivity))
+    dtype = pl.Array(pl.Float64, width)
+    rows = [
+        None
+        if rng.uniform() < 0.1
+        else [None if rng.uniform() < 0.1 else rng.uniform() for _ in range(width)]
+        for _ in range(1000)
+    ]
+    s = pl.Series("a", rows, dtype=dtype)
+    # Chunks that are slices of a larger array.
+    s = pl.concat([s[3:500], s[500:997]], rechunk=False)
+    mask = pl.Series(rng.uniform(size=len(s)) < selectivity)
+
+    # The list filter goes through the generic path.
+    expected = s.cast(pl.List(pl.Float64)).filter(mask).cast(dtype)
+    assert_series_equal(s.filter(mask), expected)
+    assert_frame_equal(s.to_frame().filter(mask), expected.to_frame())
+    assert_frame_equal(
+        s.to_frame().select(pl.col("a").filter(mask)), expected.to_frame()
+    )
+
+
 def test_filter_group_aware_17030() -> None:
     df = pl.DataFrame({"foo": ["1", "2", "1", "2", "1", "2"]})
 
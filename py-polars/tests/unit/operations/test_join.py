@@ -21,7 +21,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 from tests.unit.conftest import time_func
 
 if TYPE_CHECKING:
-    from polars._typing import JoinStrategy, PolarsDataType
+    from polars._typing import EngineType, JoinStrategy, PolarsDataType
 
 
 def test_semi_anti_join() -> None:
@@ -3971,3 +3971,62 @@ def test_join_lazyframe_with_itself_after_sort_25395() -> None:
     result = lf.sort("a").join(lf, on="a").collect()
 
     assert_frame_equal(result, pl.DataFrame({"a": [1]}))
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "right", "full", "semi", "anti"])
+@pytest.mark.parametrize("nulls_equal", [False, True])
+@pytest.mark.parametrize("swap", [False, True])
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_join_categorical_with_string_key(
+    how: JoinStrategy, nulls_equal: bool, swap: bool, engine: EngineType
+) -> None:
+    cat = pl.LazyFrame(
+        {
+            "a": pl.Series(["x", "y", None, "z", "x", "v"], dtype=pl.Categorical),
+            "k": [1, 1, 2, 1, 2, 1],
+            "l": range(6),
+        }
+    )
+    # "w" and "u" are no categories of the left key, so they never match.
+    string = pl.LazyFrame(
+        {
+            "a": ["x", "w", None, "z", "y", "x", "u"],
+            "k": [1, 1, 2, 1, 1, 2, 1],
+            "r": range(7),
+        }
+    )
+    left, right = (string, cat) if swap else (cat, string)
+    coalesce = None if how != "full" else False
+
+    for on in ["a", ["a", "k"]]:
+        q = left.join(right, on=on, how=how, nulls_equal=nulls_equal, coalesce=coalesce)
+        # The reference casts the categorical key to a string before joining.
+        expected = left.with_columns(pl.col("a").cast(pl.String)).join(
+            right.with_columns(pl.col("a").cast(pl.String)),
+            on=on,
+            how=how,
+            nulls_equal=nulls_equal,
+            coalesce=coalesce,
+        )
+
+        out = q.collect(engine=engine)
+        assert out.schema == q.collect_schema()
+        assert_frame_equal(
+            out.with_columns(pl.col(pl.Categorical).cast(pl.String)),
+            expected.collect(),
+            check_row_order=False,
+        )
+
+
+def test_join_categorical_with_string_key_unsupported() -> None:
+    cat = pl.LazyFrame({"a": pl.Series(["x", "y"], dtype=pl.Categorical)})
+    string = pl.LazyFrame({"a": ["x", "z"]})
+
+    with pytest.raises(SchemaError, match="coalescing full join"):
+        cat.join(string, on="a", how="full", coalesce=True).collect()
+
+    with pytest.raises(SchemaError, match="datatypes of join keys don't match"):
+        cat.join(string.cast(pl.Binary), on="a").collect()
+
+    with pytest.raises(SchemaError, match="datatypes of join keys don't match"):
+        cat.cast(pl.Enum(["x", "y"])).join(string, on="a").collect()
@@ -11,7 +11,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 from polars.testing.parametric import dataframes, series
 
 if TYPE_CHECKING:
-    from polars._typing import PolarsDataType
+    from polars._typing import EngineType, PolarsDataType
 
 
 @given(
@@ -1263,3 +1263,39 @@ def test_sort_by_empty_list_eval_25433() -> None:
     out = df.select(pl.col.a.list.eval(pl.element().sort_by(pl.element())))
     expected = pl.DataFrame({"a": [sorted(some_list), []]})
     assert_frame_equal(out, expected)
+
+
+def _frame_with_ties(n: int = 10_000) -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "a": [i % 7 for i in range(n)],
+            "b": [None if i % 5 == 0 else i % 3 for i in range(n)],
+            "payload": range(n),
+        }
+    )
+
+
+@pytest.mark.parametrize("descending", [False, True, [False, True], [True, False]])
+@pytest.mark.parametrize("nulls_last", [False, True, [True, False]])
+def test_sort_deterministic_matches_maintain_order(
+    descending: bool | list[bool], nulls_last: bool | list[bool]
+) -> None:
+    df = _frame_with_ties()
+    expected = df.sort(
+        "a", "b", descending=descending, nulls_last=nulls_last, maintain_order=True
+    )
+    out = df.sort(
+        "a", "b", descending=descending, nulls_last=nulls_last, deterministic=True
+    )
+    assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_sort_deterministic_repeated(engine: EngineType) -> None:
+    lf = _frame_with_ties().lazy()
+    expected = lf.sort("a", maintain_order=True).collect()
+    for multithreaded in [True, False]:
+        for _ in range(3):
+            q = lf.sort("a", multithreaded=multithreaded, deterministic=True)
+            assert_frame_equal(q.collect(engine=engine), expected)
+            assert_frame_equal(q.head(100).collect(engine=engine), expected.head(100))
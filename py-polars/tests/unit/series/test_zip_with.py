@@ -1,3 +1,5 @@
+from __future__ import annotations
+
 import pytest
 
 import polars as pl
@@ -51,6 +53,41 @@ def test_zip_with_null_values() -> None:
     assert_series_equal(result, expected)
 
 
+@pytest.mark.parametrize("inner", [pl.Int64, pl.String])
+def test_zip_with_array(inner: pl.DataType) -> None:
+    def array(name: str, values: list[list[int | None] | None]) -> pl.Series:
+        return pl.Series(name, values, dtype=pl.Array(pl.Int64, 2)).cast(dtype)
+
+    dtype = pl.Array(inner, 2)
+    s1 = pl.concat(
+        [array("a", [[1, 2], None]), array("a", [[5, None], [7, 8], [9, 10]])],
+        rechunk=False,
+    )
+    s2 = array("b", [[10, 20], [30, 40], None, [None, 80], [90, 100]])
+    mask = pl.Series([True, True, True, False, None])
+
+    result = s1.zip_with(mask, s2)
+    expected = array("a", [[1, 2], None, [5, None], [None, 80], [90, 100]])
+    assert_series_equal(result, expected)
+
+    df = pl.DataFrame({"a": s1, "b": s2, "mask": mask})
+    out = df.lazy().select(pl.when("mask").then("a").otherwise("b"))
+    assert out.collect_schema()["a"] == dtype
+    assert_series_equal(out.collect().to_series(), expected)
+
+
+def test_zip_with_array_width_mismatch() -> None:
+    s1 = pl.Series("a", [[1, 2]], dtype=pl.Array(pl.Int64, 2))
+    s2 = pl.Series("b", [[1, 2, 3]], dtype=pl.Array(pl.Int64, 3))
+
+    with pytest.raises(pl.exceptions.ShapeError, match="widths: 2 and 3"):
+        s1.zip_with(pl.Series([True]), s2)
+
+    df = pl.DataFrame({"a": s1, "b": s2})
+    with pytest.raises(pl.exceptions.ShapeError, match="widths: 2 and 3"):
+        df.select(pl.when(pl.lit(True)).then("a").otherwise("b"))
+
+
 def test_zip_with_length_mismatch() -> None:
     s1 = pl.Series([1, 2, 3])
     s2 = pl.Series([4, 5])
@@ -401,3 +401,22 @@ def test_cross_join_with_literal_column_25544() -> None:
 
     assert_frame_equal(streaming_result, in_memory_result)
     assert streaming_result.item() == 0
+
+
+@pytest.mark.parametrize("how", ["semi", "anti"])
+@pytest.mark.parametrize("bloom_filter", [None, False, True])
+def test_streaming_semi_anti_join_bloom_filter(
+    how: JoinStrategy, bloom_filter: bool | None
+) -> None:
+    n = 50_000
+    left = pl.LazyFrame({"a": np.arange(n), "b": np.arange(n) % 7})
+    right = pl.LazyFrame(
+        {"a": np.arange(0, 2 * n, 100), "b": np.arange(0, 2 * n, 100) % 7}
+    )
+    q = left.join(right, on=["a", "b"], how=how, bloom_filter=bloom_filter)
+
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
@@ -1,7 +1,14 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
 import pytest
 
 import polars as pl
 
+if TYPE_CHECKING:
+    from pathlib import Path
+
 
 @pytest.fixture
 def query() -> pl.LazyFrame:
@@ -45,3 +52,19 @@ def test_show_graph_phys_not_streaming(query: pl.LazyFrame) -> None:
 def test_show_graph_invalid_stage(query: pl.LazyFrame) -> None:
     with pytest.raises(TypeError, match="invalid plan stage 'invalid-stage'"):
         query.show_graph(raw_output=True, plan_stage="invalid-stage")  # type: ignore[arg-type]
+
+
+def test_show_graph_pushed_predicate(tmp_path: Path) -> None:
+    path = tmp_path / "data.parquet"
+    pl.DataFrame({"a": [1, 2, 3]}).write_parquet(path)
+    q = pl.scan_parquet(path).filter(pl.col("a") > 1)
+
+    out = q.show_graph(raw_output=True)
+    assert isinstance(out, str)
+    assert "σ (pushed)" in out
+    assert "FILTER BY" not in out
+
+    out = q.show_graph(raw_output=True, optimized=False)
+    assert isinstance(out, str)
+    assert "σ (pushed)" not in out
+    assert "FILTER BY" in out